@@ -56,3 +56,18 @@ for PALLET in "${CUSTOM_PALLETS[@]}"; do
     --template="./.maintain/frame-weight-template.hbs" \
     --output="./pallets/${PALLET#pallet-}/src/weights.rs"
 done
+
+# Asset rewards are only available on the solo runtime, so the staking pallet is benchmarked there
+# to measure its asset reward entries as well.
+cargo build-ajuna-solo-benchmarks
+./target/release/ajuna-solo benchmark pallet \
+  --chain=dev \
+  --steps=50 \
+  --repeat=20 \
+  --pallet="pallet-ajuna-nft-staking" \
+  --extrinsic="*" \
+  --execution=wasm \
+  --wasm-execution=compiled \
+  --heap-pages=4096 \
+  --template="./.maintain/frame-weight-template.hbs" \
+  --output="./pallets/ajuna-nft-staking/src/weights.rs"
@@ -420,7 +420,7 @@ pub fn run() -> Result<()> {
 						.map_err(Into::into),
 					#[cfg(feature = "bajun")]
 					spec if spec.id().starts_with("bajun") =>
-						service::start_parachain_node::<BajunRuntimeApi, BajunRuntimeExecutor>(
+						service::start_avatars_parachain_node::<BajunRuntimeApi, BajunRuntimeExecutor>(
 							config,
 							polkadot_config,
 							collator_options,
@@ -14,7 +14,9 @@
 // You should have received a copy of the GNU Affero General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::chain_spec::{get_account_id_from_seed, get_collator_keys_from_seed, Extensions};
+use crate::chain_spec::{
+	dev_avatars_season, get_account_id_from_seed, get_collator_keys_from_seed, Extensions,
+};
 use ajuna_primitives::AccountId;
 use bajun_runtime::AuraId;
 use sc_service::ChainType;
@@ -167,6 +169,21 @@ fn testnet_genesis(
 		aura_ext: Default::default(),
 		parachain_system: Default::default(),
 		polkadot_xcm: bajun_runtime::PolkadotXcmConfig { safe_xcm_version: Some(SAFE_XCM_VERSION) },
-		awesome_avatars: Default::default(),
+		awesome_avatars: bajun_runtime::AwesomeAvatarsConfig {
+			organizer: Some(get_account_id_from_seed::<sr25519::Public>("Alice")),
+			treasurers: vec![(1, get_account_id_from_seed::<sr25519::Public>("Alice"))],
+			seasons: vec![(1, dev_avatars_season(10, 1_000_000))],
+			service_account: Some(get_account_id_from_seed::<sr25519::Public>("Alice")),
+			free_mints: endowed_accounts.into_iter().map(|k| (k, 10)).collect(),
+			// no collection exists at genesis, so the organizer creates one for the avatars and
+			// sets it with `set_collection_id` once the network is live
+			collection_id: None,
+			..Default::default()
+		},
+		nft_staking: bajun_runtime::NftStakingConfig {
+			organizer: Some(get_account_id_from_seed::<sr25519::Public>("Alice")),
+			create_contract_collection: true,
+			treasury_funds: 1_000 * bajun_runtime::BAJUN,
+		},
 	}
 }
@@ -20,6 +20,11 @@ use serde::{Deserialize, Serialize};
 use sp_core::{sr25519, Pair, Public};
 use sp_runtime::traits::IdentifyAccount;
 
+#[cfg(any(feature = "solo", feature = "bajun"))]
+use ajuna_primitives::BlockNumber;
+#[cfg(any(feature = "solo", feature = "bajun"))]
+use pallet_ajuna_awesome_avatars::types::Season;
+
 #[cfg(feature = "solo")]
 pub mod solo;
 
@@ -121,6 +126,36 @@ pub fn get_well_known_accounts() -> WellKnownAccounts {
 	}
 }
 
+/// A playable avatars season to bootstrap development and test networks with.
+#[cfg(any(feature = "solo", feature = "bajun"))]
+pub fn dev_avatars_season(start: BlockNumber, end: BlockNumber) -> Season<BlockNumber> {
+	use pallet_ajuna_awesome_avatars::types::RarityTier::*;
+
+	Season {
+		name: b"Genesis Season".to_vec().try_into().expect("name fits; qed"),
+		description: b"The first season of a development network"
+			.to_vec()
+			.try_into()
+			.expect("description fits; qed"),
+		early_start: 0,
+		start,
+		end,
+		max_tier_forges: 100,
+		max_variations: 6,
+		max_components: 11,
+		min_sacrifices: 1,
+		max_sacrifices: 4,
+		tiers: vec![Common, Uncommon, Rare, Epic, Legendary, Mythical]
+			.try_into()
+			.expect("tiers fit; qed"),
+		single_mint_probs: vec![70, 20, 5, 4, 1].try_into().expect("probabilities fit; qed"),
+		batch_mint_probs: vec![40, 30, 15, 10, 5].try_into().expect("probabilities fit; qed"),
+		base_prob: 0,
+		per_period: 10,
+		periods: 12,
+	}
+}
+
 // Configure chain specification metadata properties.
 pub fn chain_spec_properties(
 	symbol: &str,
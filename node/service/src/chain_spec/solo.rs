@@ -14,11 +14,12 @@
 // You should have received a copy of the GNU Affero General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::chain_spec::{chain_spec_properties, get_well_known_accounts};
+use crate::chain_spec::{chain_spec_properties, dev_avatars_season, get_well_known_accounts};
 use ajuna_primitives::Balance;
 use ajuna_solo_runtime::{
-	currency::AJUNS, AssetsConfig, AuraConfig, BalancesConfig, CouncilConfig, GenesisConfig,
-	GrandpaConfig, SudoConfig, SystemConfig, VestingConfig, WASM_BINARY,
+	currency::AJUNS, AssetsConfig, AuraConfig, AwesomeAvatarsConfig, BalancesConfig, CouncilConfig,
+	GenesisConfig, GrandpaConfig, NftStakingConfig, SudoConfig, SystemConfig, VestingConfig,
+	WASM_BINARY,
 };
 use sc_service::ChainType;
 
@@ -74,6 +75,8 @@ struct Config {
 	balances: BalancesConfig,
 	assets: AssetsConfig,
 	vesting: VestingConfig,
+	awesome_avatars: AwesomeAvatarsConfig,
+	nft_staking: NftStakingConfig,
 }
 
 fn development_config_genesis() -> GenesisConfig {
@@ -89,6 +92,26 @@ fn development_config_genesis() -> GenesisConfig {
 	let cliff_vest_dave_at_10 = (accounts.dave.clone(), 9, 10, 1, VEST_BALANCE);
 	let cliff_vest_eve_at_20 = (accounts.eve.clone(), 19, 20, 1, VEST_BALANCE);
 	let cliff_vest_ferdie_at_30 = (accounts.ferdie.clone(), 29, 30, 1, VEST_BALANCE);
+	let awesome_avatars = AwesomeAvatarsConfig {
+		organizer: Some(accounts.alice.clone()),
+		treasurers: vec![(1, accounts.alice.clone())],
+		seasons: vec![(1, dev_avatars_season(10, 1_000_000))],
+		free_mints: vec![
+			(accounts.alice.clone(), 10),
+			(accounts.bob.clone(), 10),
+			(accounts.charlie.clone(), 10),
+		],
+		service_account: Some(accounts.alice.clone()),
+		// no collection exists at genesis, so the organizer creates one for the avatars and sets
+		// it with `set_collection_id` once the network is live
+		collection_id: None,
+		..Default::default()
+	};
+	let nft_staking = NftStakingConfig {
+		organizer: Some(accounts.alice.clone()),
+		create_contract_collection: true,
+		treasury_funds: 1_000 * AJUNS,
+	};
 
 	compose_genesis_config(Config {
 		aura: AuraConfig { authorities: aura_authorities },
@@ -129,6 +152,8 @@ fn development_config_genesis() -> GenesisConfig {
 				cliff_vest_ferdie_at_30,
 			],
 		},
+		awesome_avatars,
+		nft_staking,
 	})
 }
 
@@ -139,6 +164,13 @@ fn testnet_config_genesis() -> GenesisConfig {
 	let accounts = get_well_known_accounts();
 
 	const INITIAL_BALANCE: Balance = 1_000_000_000 * AJUNS;
+	// the organizers, treasurer and service account are left to be set once the network is live,
+	// as the well-known accounts are only fit for development
+	let awesome_avatars = AwesomeAvatarsConfig {
+		seasons: vec![(1, dev_avatars_season(10, 1_000_000))],
+		..Default::default()
+	};
+	let nft_staking = NftStakingConfig::default();
 
 	compose_genesis_config(Config {
 		aura: AuraConfig {
@@ -178,6 +210,8 @@ fn testnet_config_genesis() -> GenesisConfig {
 		},
 		assets: AssetsConfig::default(),
 		vesting: VestingConfig::default(),
+		awesome_avatars,
+		nft_staking,
 	})
 }
 
@@ -186,7 +220,17 @@ fn compose_genesis_config(config: Config) -> GenesisConfig {
 	let wasm_binary = WASM_BINARY.expect(
 		"Development wasm binary is not available. Please rebuild with SKIP_WASM_BUILD disabled.",
 	);
-	let Config { aura, grandpa, sudo, council, balances, assets, vesting } = config;
+	let Config {
+		aura,
+		grandpa,
+		sudo,
+		council,
+		balances,
+		assets,
+		vesting,
+		awesome_avatars,
+		nft_staking,
+	} = config;
 	GenesisConfig {
 		// overridden config
 		aura,
@@ -196,12 +240,13 @@ fn compose_genesis_config(config: Config) -> GenesisConfig {
 		balances,
 		assets,
 		vesting,
+		awesome_avatars,
+		nft_staking,
 		// default config
 		system: SystemConfig { code: wasm_binary.to_vec() },
 		transaction_payment: Default::default(),
 		council_membership: Default::default(),
 		treasury: Default::default(),
 		democracy: Default::default(),
-		awesome_avatars: Default::default(),
 	}
 }
@@ -16,7 +16,7 @@
 
 use std::{sync::Arc, time::Duration};
 
-use ajuna_primitives::{AccountId, Balance, Block, Hash, Index as Nonce};
+use ajuna_primitives::{AccountId, Balance, Block, BlockNumber, Hash, Index as Nonce};
 
 use cumulus_client_cli::CollatorOptions;
 use cumulus_client_consensus_aura::{AuraConsensus, BuildAuraConsensusParams, SlotProportion};
@@ -78,6 +78,9 @@ type ParachainClient<RuntimeApi, Executor> =
 
 type ParachainBackend = TFullBackend<Block>;
 
+type ParachainPool<RuntimeApi, Executor> =
+	sc_transaction_pool::FullPool<Block, ParachainClient<RuntimeApi, Executor>>;
+
 type ParachainBlockImport<RuntimeApi, Executor> =
 	TParachainBlockImport<Block, Arc<ParachainClient<RuntimeApi, Executor>>, ParachainBackend>;
 
@@ -188,14 +191,22 @@ where
 ///
 /// This is the actual implementation that is abstract over the executor and the runtime api.
 #[sc_tracing::logging::prefix_logs_with("Parachain")]
-async fn start_node_impl<RuntimeApi, Executor>(
+async fn start_node_impl<RuntimeApi, Executor, RB>(
 	parachain_config: Configuration,
 	polkadot_config: Configuration,
 	collator_options: CollatorOptions,
 	para_id: ParaId,
 	hwbench: Option<sc_sysinfo::HwBench>,
+	rpc_ext_builder: RB,
 ) -> sc_service::error::Result<(TaskManager, Arc<ParachainClient<RuntimeApi, Executor>>)>
 where
+	RB: Fn(
+			ajuna_rpc::FullDeps<
+				ParachainClient<RuntimeApi, Executor>,
+				ParachainPool<RuntimeApi, Executor>,
+			>,
+		) -> Result<ajuna_rpc::RpcExtension, sc_service::Error>
+		+ 'static,
 	RuntimeApi:
 		ConstructRuntimeApi<Block, ParachainClient<RuntimeApi, Executor>> + Send + Sync + 'static,
 	RuntimeApi::RuntimeApi: sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block>
@@ -278,7 +289,7 @@ where
 				deny_unsafe,
 			};
 
-			ajuna_rpc::create_full(deps).map_err(Into::into)
+			rpc_ext_builder(deps)
 		})
 	};
 
@@ -537,12 +548,50 @@ where
 		+ sp_consensus_aura::AuraApi<Block, sp_consensus_aura::sr25519::AuthorityId>,
 	Executor: sc_executor::NativeExecutionDispatch + 'static,
 {
-	crate::para::start_node_impl::<RuntimeApi, Executor>(
+	crate::para::start_node_impl::<RuntimeApi, Executor, _>(
+		parachain_config,
+		polkadot_config,
+		collator_options,
+		para_id,
+		hwbench,
+		|deps| ajuna_rpc::create_common(deps).map_err(Into::into),
+	)
+	.await
+}
+
+/// Start a parachain node whose runtime hosts the Awesome Avatars pallet.
+pub async fn start_avatars_parachain_node<RuntimeApi, Executor>(
+	parachain_config: Configuration,
+	polkadot_config: Configuration,
+	collator_options: CollatorOptions,
+	para_id: ParaId,
+	hwbench: Option<sc_sysinfo::HwBench>,
+) -> sc_service::error::Result<(TaskManager, Arc<ParachainClient<RuntimeApi, Executor>>)>
+where
+	RuntimeApi:
+		ConstructRuntimeApi<Block, ParachainClient<RuntimeApi, Executor>> + Send + Sync + 'static,
+	RuntimeApi::RuntimeApi: sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block>
+		+ sp_api::Metadata<Block>
+		+ sp_session::SessionKeys<Block>
+		+ sp_api::ApiExt<
+			Block,
+			StateBackend = sc_client_api::StateBackendFor<ParachainBackend, Block>,
+		> + sp_offchain::OffchainWorkerApi<Block>
+		+ sp_block_builder::BlockBuilder<Block>
+		+ cumulus_primitives_core::CollectCollationInfo<Block>
+		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+		+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
+		+ sp_consensus_aura::AuraApi<Block, sp_consensus_aura::sr25519::AuthorityId>
+		+ ajuna_rpc::AwesomeAvatarsRuntimeApi<Block, AccountId, Hash, Balance, BlockNumber>,
+	Executor: sc_executor::NativeExecutionDispatch + 'static,
+{
+	crate::para::start_node_impl::<RuntimeApi, Executor, _>(
 		parachain_config,
 		polkadot_config,
 		collator_options,
 		para_id,
 		hwbench,
+		|deps| ajuna_rpc::create_full(deps).map_err(Into::into),
 	)
 	.await
 }
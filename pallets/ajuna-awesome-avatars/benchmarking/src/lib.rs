@@ -20,20 +20,25 @@
 
 mod mock;
 
-use frame_benchmarking::{benchmarks, vec};
+use codec::Decode;
+use frame_benchmarking::{benchmarks, vec, BenchmarkError};
 use frame_support::{
 	pallet_prelude::{DispatchError, DispatchResult},
-	traits::{Currency, Get},
+	traits::{Currency, EnsureOrigin, Get, ReservableCurrency},
+	BoundedVec,
 };
 use frame_system::RawOrigin;
 use pallet_ajuna_awesome_avatars::{
+	traits::AssetPayment,
 	types::{RarityTier::*, *},
 	Config as AvatarsConfig, Pallet as AAvatars, *,
 };
 use pallet_ajuna_nft_transfer::traits::NftHandler;
 use sp_runtime::traits::{
-	Saturating, StaticLookup, UniqueSaturatedFrom, UniqueSaturatedInto, Zero,
+	Hash, Saturating, StaticLookup, TrailingZeroInput, UniqueSaturatedFrom, UniqueSaturatedInto,
+	Zero,
 };
+use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
 
 pub struct Pallet<T: Config>(pallet_ajuna_awesome_avatars::Pallet<T>);
 pub trait Config: AvatarsConfig + pallet_nfts::Config + pallet_balances::Config {}
@@ -42,6 +47,8 @@ type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 type AvatarIdOf<T> = <T as frame_system::Config>::Hash;
 type BalanceOf<T> = <CurrencyOf<T> as Currency<AccountIdOf<T>>>::Balance;
 type CurrencyOf<T> = <T as AvatarsConfig>::Currency;
+type AssetIdOf<T> =
+	<<T as AvatarsConfig>::AssetPayment as AssetPayment<AccountIdOf<T>, BalanceOf<T>>>::AssetId;
 type CollectionIdOf<T> = <<T as AvatarsConfig>::NftHandler as NftHandler<
 	AccountIdOf<T>,
 	AvatarIdOf<T>,
@@ -158,6 +165,76 @@ fn create_service_account_and_prepare_avatar<T: Config>(
 	Ok(service_account)
 }
 
+fn payment_asset<T: Config>() -> AssetIdOf<T> {
+	AssetIdOf::<T>::decode(&mut TrailingZeroInput::zeroes()).unwrap()
+}
+
+/// A patch setting every field of the global configuration.
+fn full_config_patch<T: Config>() -> GlobalConfigPatch<BalanceOf<T>, T::BlockNumber> {
+	let fee = BalanceOf::<T>::unique_saturated_from(u128::MAX);
+	GlobalConfigPatch {
+		mint_open: Some(true),
+		mint_fees: Some(MintFees { one: fee, three: fee, six: fee }),
+		mint_cooldown: Some(T::BlockNumber::from(u32::MAX)),
+		free_mint_fee_multiplier: Some(MintCount::MAX),
+		forge_open: Some(true),
+		transfer_open: Some(true),
+		free_mint_transfer_fee: Some(MintCount::MAX),
+		min_free_mint_transfer: Some(MintCount::MAX),
+		avatar_transfer_fee: Some(fee),
+		trade_open: Some(true),
+		trade_min_fee: Some(fee),
+		trade_percent_fee: Some(u8::MAX),
+		storage_upgrade_fee: Some(fee),
+		nft_transfer_open: Some(true),
+		prepare_fee: Some(fee),
+	}
+}
+
+fn create_offers<T: Config>(avatar_id: AvatarIdOf<T>, n: u32) -> Result<(), &'static str> {
+	let amount = BalanceOf::<T>::unique_saturated_from(1_000_u32);
+	for i in 0..n {
+		let buyer = frame_benchmarking::account::<T::AccountId>("offerer", i, 0);
+		CurrencyOf::<T>::make_free_balance_be(&buyer, amount + amount);
+		CurrencyOf::<T>::reserve(&buyer, amount)?;
+		Offers::<T>::insert(
+			avatar_id,
+			&buyer,
+			Offer { amount, fee: Zero::zero(), fee_asset: None },
+		);
+	}
+	Ok(())
+}
+
+/// Makes `players` pay their fees in an asset funded by the treasury, when the runtime supports
+/// asset payments, so that trades go through their most expensive path.
+fn pay_fees_in_asset<T: Config>(
+	players: &[&T::AccountId],
+) -> Result<Option<AssetIdOf<T>>, &'static str> {
+	if !T::AssetPayment::is_available() {
+		return Ok(None)
+	}
+	let asset_id = payment_asset::<T>();
+	let amount = BalanceOf::<T>::unique_saturated_from(u64::MAX);
+	let treasury_account = AAvatars::<T>::treasury_account_id();
+	T::AssetPayment::create_asset(asset_id, &treasury_account, amount + amount)?;
+	let rate = ConversionRate {
+		native: 1_u64.unique_saturated_into(),
+		asset: 1_u64.unique_saturated_into(),
+	};
+	PaymentAssets::<T>::insert(asset_id, rate);
+	for player in players {
+		FeeAssets::<T>::insert(player, asset_id);
+		T::AssetPayment::transfer(
+			asset_id,
+			&treasury_account,
+			player,
+			amount / (players.len() as u32).into(),
+		)?;
+	}
+	Ok(Some(asset_id))
+}
+
 fn assert_last_event<T: Config>(avatars_event: Event<T>) {
 	let event = <T as AvatarsConfig>::RuntimeEvent::from(avatars_event);
 	frame_system::Pallet::<T>::assert_last_event(event.into());
@@ -166,46 +243,51 @@ fn assert_last_event<T: Config>(avatars_event: Event<T>) {
 benchmarks! {
 	mint_free {
 		let name = "player";
-		let n in 0 .. (MaxAvatarsPerPlayer::get() - 6);
-		create_avatars::<T>(name, n)?;
+		create_avatars::<T>(name, 0)?;
 
 		let caller = account::<T>(name);
 		Accounts::<T>::mutate(&caller, |account| account.free_mints =  MintCount::MAX);
+		let owned_avatar_ids = AAvatars::<T>::owners(&caller);
 
 		let mint_option = MintOption { mint_type: MintType::Free, count: MintPackSize::Six };
 	}: mint(RawOrigin::Signed(caller.clone()), mint_option)
 	verify {
-		let n = n as usize;
-		let avatar_ids = AAvatars::<T>::owners(caller)[n..(n + 6)].to_vec();
-		assert_last_event::<T>(Event::AvatarsMinted { avatar_ids })
+		let mut avatar_ids = AAvatars::<T>::owners(&caller);
+		avatar_ids.retain(|avatar_id| !owned_avatar_ids.contains(avatar_id));
+		assert_eq!(avatar_ids.len(), 6);
+		assert_eq!(AAvatars::<T>::owned_avatar_count(&caller), 6);
 	}
 
 	mint_normal {
 		let name = "player";
-		let n in 0 .. (MaxAvatarsPerPlayer::get() - 6);
-		create_avatars::<T>(name, n)?;
+		create_avatars::<T>(name, 0)?;
 
 		let caller = account::<T>(name);
 		let mint_fee = AAvatars::<T>::global_configs().mint.fees.fee_for(&MintPackSize::Six);
 		CurrencyOf::<T>::make_free_balance_be(&caller, mint_fee);
+		let owned_avatar_ids = AAvatars::<T>::owners(&caller);
 
 		let mint_option = MintOption { mint_type: MintType::Normal, count: MintPackSize::Six };
 	}: mint(RawOrigin::Signed(caller.clone()), mint_option)
 	verify {
-		let n = n as usize;
-		let avatar_ids = AAvatars::<T>::owners(caller)[n..(n + 6)].to_vec();
-		assert_last_event::<T>(Event::AvatarsMinted { avatar_ids })
+		let mut avatar_ids = AAvatars::<T>::owners(&caller);
+		avatar_ids.retain(|avatar_id| !owned_avatar_ids.contains(avatar_id));
+		assert_eq!(avatar_ids.len(), 6);
+		assert_eq!(AAvatars::<T>::owned_avatar_count(&caller), 6);
 	}
 
 	forge {
 		let name = "player";
-		let n in 5 .. MaxAvatarsPerPlayer::get();
-		create_avatars::<T>(name, n)?;
+		let o in 0 .. (4 * <T as AvatarsConfig>::MaxOffersPerAvatar::get());
+		create_avatars::<T>(name, 5)?;
 
 		let player = account::<T>(name);
 		let avatar_ids = AAvatars::<T>::owners(&player);
 		let avatar_id = avatar_ids[0];
 		let (_owner, original_avatar) = AAvatars::<T>::avatars(avatar_id).unwrap();
+		for (i, sacrifice_id) in avatar_ids[1..5].iter().enumerate() {
+			create_offers::<T>(*sacrifice_id, o / 4 + u32::from((i as u32) < o % 4))?;
+		}
 	}: _(RawOrigin::Signed(player), avatar_id, avatar_ids[1..5].to_vec())
 	verify {
 		let (_owner, upgraded_avatar) = AAvatars::<T>::avatars(avatar_id).unwrap();
@@ -225,10 +307,8 @@ benchmarks! {
 	transfer_avatar_normal {
 		let from = account::<T>("from");
 		let to = account::<T>("to");
-		let n in 1 .. MaxAvatarsPerPlayer::get();
-		create_avatars::<T>("from", MaxAvatarsPerPlayer::get())?;
-		create_avatars::<T>("to", MaxAvatarsPerPlayer::get() - n)?;
-		let avatar_id = AAvatars::<T>::owners(&from)[n as usize - 1];
+		create_avatars::<T>("from", 1)?;
+		let avatar_id = AAvatars::<T>::owners(&from)[0];
 
 		let GlobalConfig { transfer, .. } = AAvatars::<T>::global_configs();
 		<T as AvatarsConfig>::Currency::make_free_balance_be(&from, transfer.avatar_transfer_fee);
@@ -240,10 +320,8 @@ benchmarks! {
 	transfer_avatar_organizer {
 		let organizer = account::<T>("organizer");
 		let to = account::<T>("to");
-		let n in 1 .. MaxAvatarsPerPlayer::get();
-		create_avatars::<T>("organizer", MaxAvatarsPerPlayer::get())?;
-		create_avatars::<T>("to", MaxAvatarsPerPlayer::get() - n)?;
-		let avatar_id = AAvatars::<T>::owners(&organizer)[n as usize - 1];
+		create_avatars::<T>("organizer", 1)?;
+		let avatar_id = AAvatars::<T>::owners(&organizer)[0];
 
 		let GlobalConfig { transfer, .. } = AAvatars::<T>::global_configs();
 		CurrencyOf::<T>::make_free_balance_be(&organizer, transfer.avatar_transfer_fee);
@@ -266,7 +344,7 @@ benchmarks! {
 
 	set_price {
 		let name = "player";
-		create_avatars::<T>(name, MaxAvatarsPerPlayer::get())?;
+		create_avatars::<T>(name, 1)?;
 		let caller = account::<T>(name);
 		let avatar_id = AAvatars::<T>::owners(&caller)[0];
 		let price = BalanceOf::<T>::unique_saturated_from(u128::MAX);
@@ -277,7 +355,7 @@ benchmarks! {
 
 	remove_price {
 		let name = "player";
-		create_avatars::<T>(name, MaxAvatarsPerPlayer::get())?;
+		create_avatars::<T>(name, 1)?;
 		let caller = account::<T>(name);
 		let avatar_id = AAvatars::<T>::owners(&caller)[0];
 		Trade::<T>::insert(avatar_id, BalanceOf::<T>::unique_saturated_from(u128::MAX));
@@ -289,9 +367,7 @@ benchmarks! {
 	buy {
 		let (buyer_name, seller_name) = ("buyer", "seller");
 		let (buyer, seller) = (account::<T>(buyer_name), account::<T>(seller_name));
-		let n in 1 .. MaxAvatarsPerPlayer::get();
-		create_avatars::<T>(buyer_name, n- 1)?;
-		create_avatars::<T>(seller_name, n)?;
+		create_avatars::<T>(seller_name, 1)?;
 
 		let min_fee = AAvatars::<T>::global_configs().trade.min_fee;
 		let sell_fee = BalanceOf::<T>::unique_saturated_from(u64::MAX / 2);
@@ -325,8 +401,9 @@ benchmarks! {
 	set_collection_id {
 		let organizer = account::<T>("organizer");
 		Organizer::<T>::put(&organizer);
+		let origin = T::ConfigOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
 		let collection_id = CollectionIdOf::<T>::from(u32::MAX);
-	}: _(RawOrigin::Signed(organizer), collection_id.clone())
+	}: _<T::RuntimeOrigin>(origin, collection_id.clone())
 	verify {
 		assert_last_event::<T>(Event::CollectionIdSet { collection_id })
 	}
@@ -384,6 +461,7 @@ benchmarks! {
 	update_global_config {
 		let organizer = account::<T>("organizer");
 		Organizer::<T>::put(&organizer);
+		let origin = T::ConfigOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
 
 		let config = GlobalConfig {
 			mint: MintConfig {
@@ -416,7 +494,7 @@ benchmarks! {
 				prepare_fee: BalanceOf::<T>::unique_saturated_from(u128::MAX),
 			},
 		};
-	}: _(RawOrigin::Signed(organizer), config.clone())
+	}: _<T::RuntimeOrigin>(origin, config.clone())
 	verify {
 		assert_last_event::<T>(Event::UpdatedGlobalConfig(config))
 	}
@@ -434,12 +512,10 @@ benchmarks! {
 
 	lock_avatar {
 		let name = "player";
-		let n in 1 .. MaxAvatarsPerPlayer::get();
-		create_avatars::<T>(name, n)?;
+		create_avatars::<T>(name, 1)?;
 
 		let player = account::<T>(name);
-		let avatar_ids = AAvatars::<T>::owners(&player);
-		let avatar_id = avatar_ids[avatar_ids.len() - 1];
+		let avatar_id = AAvatars::<T>::owners(&player)[0];
 
 		let organizer = account::<T>("organizer");
 		CurrencyOf::<T>::make_free_balance_be(&organizer, CurrencyOf::<T>::minimum_balance());
@@ -455,12 +531,10 @@ benchmarks! {
 
 	unlock_avatar {
 		let name = "player";
-		let n in 1 .. MaxAvatarsPerPlayer::get();
-		create_avatars::<T>(name, n)?;
+		create_avatars::<T>(name, 1)?;
 
 		let player = account::<T>(name);
-		let avatar_ids = AAvatars::<T>::owners(&player);
-		let avatar_id = avatar_ids[avatar_ids.len() - 1];
+		let avatar_id = AAvatars::<T>::owners(&player)[0];
 
 		let organizer = account::<T>("organizer");
 		CurrencyOf::<T>::make_free_balance_be(&organizer, CurrencyOf::<T>::minimum_balance());
@@ -533,6 +607,581 @@ benchmarks! {
 		assert_last_event::<T>(Event::<T>::PreparedIpfsUrl { url })
 	}
 
+	create_auction {
+		let name = "player";
+		create_avatars::<T>(name, 1)?;
+		let caller = account::<T>(name);
+		let avatar_id = AAvatars::<T>::owners(&caller)[0];
+		let reserve_price = BalanceOf::<T>::unique_saturated_from(u128::MAX);
+		let end = frame_system::Pallet::<T>::block_number() + 10_u32.into();
+	}: _(RawOrigin::Signed(caller.clone()), avatar_id, reserve_price, end)
+	verify {
+		assert_last_event::<T>(Event::AuctionCreated { avatar_id, seller: caller, reserve_price, end })
+	}
+
+	bid {
+		let (bidder_name, outbid_name, seller_name) = ("bidder", "outbid", "seller");
+		let (bidder, outbid, seller) =
+			(account::<T>(bidder_name), account::<T>(outbid_name), account::<T>(seller_name));
+		create_avatars::<T>(seller_name, 1)?;
+
+		let previous_amount = BalanceOf::<T>::unique_saturated_from(u64::MAX / 4);
+		let amount = BalanceOf::<T>::unique_saturated_from(u64::MAX / 2);
+		CurrencyOf::<T>::make_free_balance_be(&outbid, previous_amount);
+		CurrencyOf::<T>::make_free_balance_be(&bidder, amount + amount);
+		CurrencyOf::<T>::reserve(&outbid, previous_amount)?;
+		let fee_asset = pay_fees_in_asset::<T>(&[&bidder, &outbid])?;
+		let previous_fee = previous_amount / BalanceOf::<T>::unique_saturated_from(100_u8);
+
+		let avatar_id = AAvatars::<T>::owners(&seller)[0];
+		let end = frame_system::Pallet::<T>::block_number() + 10_u32.into();
+		let highest_bid = Some(Bid { bidder: outbid, amount: previous_amount, fee: previous_fee, fee_asset });
+		Auctions::<T>::insert(avatar_id, Auction { seller, reserve_price: Zero::zero(), end, highest_bid });
+	}: _(RawOrigin::Signed(bidder.clone()), avatar_id, amount)
+	verify {
+		assert_last_event::<T>(Event::AuctionBid { avatar_id, bidder, amount })
+	}
+
+	settle_auction {
+		let (bidder_name, seller_name) = ("bidder", "seller");
+		let (bidder, seller) = (account::<T>(bidder_name), account::<T>(seller_name));
+		create_avatars::<T>(seller_name, 1)?;
+
+		let amount = BalanceOf::<T>::unique_saturated_from(u64::MAX / 2);
+		let fee = amount / BalanceOf::<T>::unique_saturated_from(100_u8);
+		CurrencyOf::<T>::make_free_balance_be(&bidder, amount + fee);
+		CurrencyOf::<T>::make_free_balance_be(&seller, amount);
+		CurrencyOf::<T>::reserve(&bidder, amount + fee)?;
+
+		let avatar_id = AAvatars::<T>::owners(&seller)[0];
+		let end = frame_system::Pallet::<T>::block_number();
+		let highest_bid = Some(Bid { bidder: bidder.clone(), amount, fee, fee_asset: None });
+		Auctions::<T>::insert(
+			avatar_id,
+			Auction { seller: seller.clone(), reserve_price: Zero::zero(), end, highest_bid },
+		);
+	}: _(RawOrigin::Signed(bidder.clone()), avatar_id)
+	verify {
+		assert_last_event::<T>(Event::AuctionSettled {
+			avatar_id,
+			seller,
+			winner: Some(bidder),
+			price: Some(amount),
+		})
+	}
+
+	cancel_auction {
+		let name = "player";
+		create_avatars::<T>(name, 1)?;
+		let caller = account::<T>(name);
+		let avatar_id = AAvatars::<T>::owners(&caller)[0];
+		let end = frame_system::Pallet::<T>::block_number() + 10_u32.into();
+		Auctions::<T>::insert(
+			avatar_id,
+			Auction { seller: caller.clone(), reserve_price: Zero::zero(), end, highest_bid: None },
+		);
+	}: _(RawOrigin::Signed(caller), avatar_id)
+	verify {
+		assert_last_event::<T>(Event::AuctionCancelled { avatar_id })
+	}
+
+	make_offer {
+		let (buyer_name, seller_name) = ("buyer", "seller");
+		let (buyer, seller) = (account::<T>(buyer_name), account::<T>(seller_name));
+		create_avatars::<T>(seller_name, 1)?;
+
+		let amount = BalanceOf::<T>::unique_saturated_from(u64::MAX / 2);
+		CurrencyOf::<T>::make_free_balance_be(&buyer, amount + amount);
+		pay_fees_in_asset::<T>(&[&buyer])?;
+
+		let avatar_id = AAvatars::<T>::owners(&seller)[0];
+		create_offers::<T>(avatar_id, <T as AvatarsConfig>::MaxOffersPerAvatar::get() - 1)?;
+	}: _(RawOrigin::Signed(buyer.clone()), avatar_id, amount)
+	verify {
+		assert_last_event::<T>(Event::OfferMade { avatar_id, buyer, amount })
+	}
+
+	withdraw_offer {
+		let (buyer_name, seller_name) = ("buyer", "seller");
+		let (buyer, seller) = (account::<T>(buyer_name), account::<T>(seller_name));
+		create_avatars::<T>(seller_name, 1)?;
+
+		let amount = BalanceOf::<T>::unique_saturated_from(u64::MAX / 2);
+		CurrencyOf::<T>::make_free_balance_be(&buyer, amount);
+		CurrencyOf::<T>::reserve(&buyer, amount)?;
+		let fee = amount / BalanceOf::<T>::unique_saturated_from(100_u8);
+		let fee_asset = pay_fees_in_asset::<T>(&[&buyer])?;
+
+		let avatar_id = AAvatars::<T>::owners(&seller)[0];
+		Offers::<T>::insert(avatar_id, &buyer, Offer { amount, fee, fee_asset });
+	}: _(RawOrigin::Signed(buyer.clone()), avatar_id)
+	verify {
+		assert_last_event::<T>(Event::OfferWithdrawn { avatar_id, buyer })
+	}
+
+	accept_offer {
+		let (buyer_name, seller_name) = ("buyer", "seller");
+		let (buyer, seller) = (account::<T>(buyer_name), account::<T>(seller_name));
+		create_avatars::<T>(seller_name, 1)?;
+
+		let amount = BalanceOf::<T>::unique_saturated_from(u64::MAX / 2);
+		let fee = amount / BalanceOf::<T>::unique_saturated_from(100_u8);
+		CurrencyOf::<T>::make_free_balance_be(&buyer, amount + fee);
+		CurrencyOf::<T>::make_free_balance_be(&seller, amount);
+		CurrencyOf::<T>::reserve(&buyer, amount + fee)?;
+
+		let avatar_id = AAvatars::<T>::owners(&seller)[0];
+		Offers::<T>::insert(avatar_id, &buyer, Offer { amount, fee, fee_asset: None });
+	}: _(RawOrigin::Signed(seller.clone()), avatar_id, buyer.clone())
+	verify {
+		assert_last_event::<T>(Event::OfferAccepted { avatar_id, from: seller, to: buyer, amount })
+	}
+
+	list_for_rent {
+		let name = "player";
+		create_avatars::<T>(name, 1)?;
+		let caller = account::<T>(name);
+		let avatar_id = AAvatars::<T>::owners(&caller)[0];
+		let price = BalanceOf::<T>::unique_saturated_from(u128::MAX);
+		let duration = T::BlockNumber::from(100_u32);
+	}: _(RawOrigin::Signed(caller), avatar_id, price, duration)
+	verify {
+		assert_last_event::<T>(Event::AvatarListedForRent { avatar_id, price, duration })
+	}
+
+	unlist_from_rent {
+		let name = "player";
+		create_avatars::<T>(name, 1)?;
+		let caller = account::<T>(name);
+		let avatar_id = AAvatars::<T>::owners(&caller)[0];
+		let price = BalanceOf::<T>::unique_saturated_from(u128::MAX);
+		RentalListings::<T>::insert(avatar_id, RentalListing { price, duration: 100_u32.into() });
+	}: _(RawOrigin::Signed(caller), avatar_id)
+	verify {
+		assert_last_event::<T>(Event::AvatarUnlistedFromRent { avatar_id })
+	}
+
+	rent {
+		let (renter_name, owner_name) = ("renter", "owner");
+		let (renter, owner) = (account::<T>(renter_name), account::<T>(owner_name));
+		create_avatars::<T>(owner_name, 1)?;
+
+		let price = BalanceOf::<T>::unique_saturated_from(u64::MAX / 2);
+		let trade_fee = price / BalanceOf::<T>::unique_saturated_from(100_u8);
+		CurrencyOf::<T>::make_free_balance_be(&renter, price + trade_fee);
+		CurrencyOf::<T>::make_free_balance_be(&owner, price);
+
+		let avatar_id = AAvatars::<T>::owners(&owner)[0];
+		let duration = T::BlockNumber::from(100_u32);
+		RentalListings::<T>::insert(avatar_id, RentalListing { price, duration });
+		let expiry = frame_system::Pallet::<T>::block_number() + duration;
+	}: _(RawOrigin::Signed(renter.clone()), avatar_id)
+	verify {
+		assert_last_event::<T>(Event::AvatarRented { avatar_id, owner, renter, expiry })
+	}
+
+	set_season_rewards {
+		let organizer = account::<T>("organizer");
+		Organizer::<T>::put(&organizer);
+
+		let season_id = 1;
+		let shares = vec![5; MaxLeaderboardSize::get() as usize];
+		let rewards = SeasonRewards {
+			forge_shares: shares.clone().try_into().unwrap(),
+			max_tier_shares: shares.try_into().unwrap(),
+		};
+	}: _(RawOrigin::Signed(organizer), season_id, rewards.clone())
+	verify {
+		assert_last_event::<T>(Event::SeasonRewardsSet { season_id, rewards })
+	}
+
+	claim_season_reward {
+		create_seasons::<T>(3)?;
+		let season_id = 1;
+		let player = account::<T>("player");
+		let amount = 1_000_000_000_000_u64.unique_saturated_into();
+		UnclaimedRewards::<T>::insert(season_id, &player, amount);
+		CurrencyOf::<T>::deposit_creating(&AAvatars::<T>::treasury_account_id(), amount);
+		CurrencyOf::<T>::make_free_balance_be(&player, CurrencyOf::<T>::minimum_balance());
+	}: _(RawOrigin::Signed(player.clone()), season_id)
+	verify {
+		assert_last_event::<T>(Event::SeasonRewardClaimed { season_id, player, amount })
+	}
+
+	set_payment_asset {
+		let organizer = account::<T>("organizer");
+		Organizer::<T>::put(&organizer);
+		let origin = T::ConfigOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		let asset_id = payment_asset::<T>();
+		let rate = Some(ConversionRate {
+			native: 1_000_u64.unique_saturated_into(),
+			asset: 3_u64.unique_saturated_into(),
+		});
+	}: _<T::RuntimeOrigin>(origin, asset_id, rate.clone())
+	verify {
+		assert_last_event::<T>(Event::PaymentAssetSet { asset_id, rate })
+	}
+
+	set_fee_asset {
+		let player = account::<T>("player");
+		let asset_id = payment_asset::<T>();
+		let rate = ConversionRate {
+			native: 1_000_u64.unique_saturated_into(),
+			asset: 3_u64.unique_saturated_into(),
+		};
+		PaymentAssets::<T>::insert(asset_id, rate);
+	}: _(RawOrigin::Signed(player.clone()), Some(asset_id))
+	verify {
+		assert_last_event::<T>(Event::FeeAssetSet { player, asset_id: Some(asset_id) })
+	}
+
+	claim_asset_treasury {
+		create_seasons::<T>(3)?;
+		let season_id = 1;
+		let treasurer = account::<T>("treasurer");
+		let asset_id = payment_asset::<T>();
+		let amount = 1_000_000_000_000_u64.unique_saturated_into();
+		T::AssetPayment::create_asset(asset_id, &AAvatars::<T>::treasury_account_id(), amount)
+			.map_err(|_| BenchmarkError::Skip)?;
+		Treasurer::<T>::insert(season_id, treasurer.clone());
+		AssetTreasury::<T>::insert(season_id, asset_id, amount);
+		CurrencyOf::<T>::make_free_balance_be(&treasurer, CurrencyOf::<T>::minimum_balance());
+	}: _(RawOrigin::Signed(treasurer.clone()), season_id, asset_id)
+	verify {
+		assert_last_event::<T>(Event::AssetTreasuryClaimed { season_id, asset_id, treasurer, amount })
+	}
+
+	forge_batch {
+		let name = "player";
+		let n in 1 .. (MaxForgesPerBatch::get() / 2);
+		let s in 0 .. MaxForgesPerBatch::get();
+		let o in 0 .. <T as AvatarsConfig>::MaxOffersPerAvatar::get();
+		create_avatars::<T>(name, 2 * n + s)?;
+		let season_id = AAvatars::<T>::current_season_id();
+		Seasons::<T>::mutate(season_id, |season| {
+			season.as_mut().unwrap().max_sacrifices = SacrificeCount::MAX
+		});
+
+		// every forge has one sacrifice, with the remaining `s` spread over them
+		let player = account::<T>(name);
+		let avatar_ids = AAvatars::<T>::owners(&player);
+		let (leader_ids, sacrifice_ids) = avatar_ids.split_at(n as usize);
+		let mut sacrifice_ids = sacrifice_ids.iter().copied();
+		let forges = leader_ids
+			.iter()
+			.enumerate()
+			.map(|(i, leader_id)| {
+				let count = 1 + s / n + u32::from((i as u32) < s % n);
+				(*leader_id, sacrifice_ids.by_ref().take(count as usize).collect::<Vec<_>>())
+			})
+			.collect::<Vec<_>>();
+		let all_sacrifice_ids = forges.iter().flat_map(|(_, ids)| ids.clone()).collect::<Vec<_>>();
+		let sacrifice_count = all_sacrifice_ids.len() as u32;
+		for (i, sacrifice_id) in all_sacrifice_ids.iter().enumerate() {
+			let offers = o / sacrifice_count + u32::from((i as u32) < o % sacrifice_count);
+			create_offers::<T>(*sacrifice_id, offers)?;
+		}
+		let batch: BoundedVec<_, MaxForgesPerBatch> = forges.clone().try_into().unwrap();
+	}: _(RawOrigin::Signed(player), batch)
+	verify {
+		for (leader, sacrifices) in forges {
+			assert!(AAvatars::<T>::avatars(leader).is_some());
+			assert!(sacrifices.iter().all(|id| AAvatars::<T>::avatars(id).is_none()));
+		}
+	}
+
+	set_carry_over {
+		let organizer = account::<T>("organizer");
+		Organizer::<T>::put(&organizer);
+
+		let max_seasons = MaxCarryOverSeasons::get();
+		let season_id = max_seasons as SeasonId + 1;
+		let seasons = (1..=max_seasons as SeasonId).collect::<BTreeSet<_>>();
+		let carry_over = CarryOver {
+			sacrifice_seasons: seasons.clone().try_into().unwrap(),
+			ascension_seasons: seasons.try_into().unwrap(),
+			ascension_fee: 1_000_000_000_000_u64.unique_saturated_into(),
+		};
+	}: _(RawOrigin::Signed(organizer), season_id, carry_over.clone())
+	verify {
+		assert_last_event::<T>(Event::CarryOverSet { season_id, carry_over })
+	}
+
+	ascend_avatar {
+		let name = "player";
+		create_avatars::<T>(name, 1)?;
+		let player = account::<T>(name);
+		let avatar_id = AAvatars::<T>::owners(&player)[0];
+		let from_season_id = 1;
+		Avatars::<T>::mutate(avatar_id, |maybe_avatar| {
+			if let Some((_, avatar)) = maybe_avatar {
+				avatar.season_id = from_season_id;
+			}
+		});
+
+		let season_id = AAvatars::<T>::current_season_id();
+		let ascension_fee: BalanceOf<T> = 1_000_000_000_000_u64.unique_saturated_into();
+		CarryOvers::<T>::insert(
+			season_id,
+			CarryOver {
+				sacrifice_seasons: Default::default(),
+				ascension_seasons: BTreeSet::from([from_season_id]).try_into().unwrap(),
+				ascension_fee,
+			},
+		);
+		CurrencyOf::<T>::make_free_balance_be(
+			&player,
+			ascension_fee + CurrencyOf::<T>::minimum_balance(),
+		);
+	}: _(RawOrigin::Signed(player), avatar_id)
+	verify {
+		assert_last_event::<T>(Event::AvatarAscended { avatar_id, from_season_id, season_id })
+	}
+
+	set_soul_exchange {
+		let organizer = account::<T>("organizer");
+		Organizer::<T>::put(&organizer);
+
+		let season_id = 1;
+		let exchange = SoulExchange {
+			reroll_price: Some(100),
+			free_mint_price: Some(100),
+			forge_boost_price: Some(100),
+			forge_boost: 10,
+		};
+	}: _(RawOrigin::Signed(organizer), season_id, exchange.clone())
+	verify {
+		assert_last_event::<T>(Event::SoulExchangeSet { season_id, exchange })
+	}
+
+	extract_souls {
+		let name = "player";
+		let o in 0 .. <T as AvatarsConfig>::MaxOffersPerAvatar::get();
+		create_avatars::<T>(name, 1)?;
+
+		let player = account::<T>(name);
+		let avatar_id = AAvatars::<T>::owners(&player)[0];
+		let (_, Avatar { souls, .. }) = AAvatars::<T>::avatars(avatar_id).unwrap();
+		create_offers::<T>(avatar_id, o)?;
+	}: _(RawOrigin::Signed(player.clone()), avatar_id)
+	verify {
+		assert_last_event::<T>(Event::SoulsExtracted { avatar_id, player, souls })
+	}
+
+	reroll_component {
+		let name = "player";
+		create_avatars::<T>(name, 1)?;
+		let player = account::<T>(name);
+		let avatar_id = AAvatars::<T>::owners(&player)[0];
+
+		let souls = 100;
+		let season_id = AAvatars::<T>::current_season_id();
+		SoulExchanges::<T>::insert(
+			season_id,
+			SoulExchange { reroll_price: Some(souls), ..Default::default() },
+		);
+		Souls::<T>::insert(&player, souls);
+		let component = 0;
+	}: _(RawOrigin::Signed(player), avatar_id, component)
+	verify {
+		assert_last_event::<T>(Event::ComponentRerolled { avatar_id, component, souls })
+	}
+
+	buy_free_mints {
+		create_seasons::<T>(3)?;
+		let player = account::<T>("player");
+		let how_many = MintCount::MAX;
+		let season_id = AAvatars::<T>::current_season_id();
+		SoulExchanges::<T>::insert(
+			season_id,
+			SoulExchange { free_mint_price: Some(1), ..Default::default() },
+		);
+		let souls = how_many as SoulCount;
+		Souls::<T>::insert(&player, souls);
+	}: _(RawOrigin::Signed(player.clone()), how_many)
+	verify {
+		assert_last_event::<T>(Event::FreeMintsBought { player, how_many, souls })
+	}
+
+	buy_forge_boost {
+		create_seasons::<T>(3)?;
+		let player = account::<T>("player");
+		let souls = 100;
+		let boost = 10;
+		let season_id = AAvatars::<T>::current_season_id();
+		SoulExchanges::<T>::insert(
+			season_id,
+			SoulExchange { forge_boost_price: Some(souls), forge_boost: boost, ..Default::default() },
+		);
+		Souls::<T>::insert(&player, souls);
+	}: _(RawOrigin::Signed(player.clone()))
+	verify {
+		assert_last_event::<T>(Event::ForgeBoostBought { player, boost, souls })
+	}
+
+	patch_global_config {
+		let organizer = account::<T>("organizer");
+		Organizer::<T>::put(&organizer);
+		let origin = T::ConfigOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let patch = full_config_patch::<T>();
+	}: _<T::RuntimeOrigin>(origin, patch)
+	verify {
+		assert_last_event::<T>(Event::UpdatedGlobalConfig(AAvatars::<T>::global_configs()))
+	}
+
+	schedule_config_patch {
+		let organizer = account::<T>("organizer");
+		Organizer::<T>::put(&organizer);
+		let origin = T::ConfigOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		let at = <frame_system::Pallet<T>>::block_number() + T::BlockNumber::from(10_u32);
+		let patch = full_config_patch::<T>();
+		for _ in 1..MaxConfigPatchesPerBlock::get() {
+			AAvatars::<T>::schedule_config_patch(
+				origin.clone(),
+				at,
+				patch.clone(),
+			)?;
+		}
+		let patch_id = NextConfigPatchId::<T>::get();
+	}: _<T::RuntimeOrigin>(origin, at, patch.clone())
+	verify {
+		assert_last_event::<T>(Event::ConfigPatchScheduled { patch_id, at, patch })
+	}
+
+	cancel_config_patch {
+		let organizer = account::<T>("organizer");
+		Organizer::<T>::put(&organizer);
+		let origin = T::ConfigOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		let at = <frame_system::Pallet<T>>::block_number() + T::BlockNumber::from(10_u32);
+		for _ in 0..MaxConfigPatchesPerBlock::get() {
+			AAvatars::<T>::schedule_config_patch(
+				origin.clone(),
+				at,
+				full_config_patch::<T>(),
+			)?;
+		}
+		let patch_id = NextConfigPatchId::<T>::get() - 1;
+	}: _<T::RuntimeOrigin>(origin, at, patch_id)
+	verify {
+		assert_last_event::<T>(Event::ConfigPatchCancelled { patch_id, at })
+	}
+
+	add_to_allowlist {
+		let n in 1 .. MaxAllowlistBatch::get();
+		let organizer = account::<T>("organizer");
+		Organizer::<T>::put(&organizer);
+
+		let season_id = 1;
+		let accounts = (0..n)
+			.map(|i| frame_benchmarking::account("player", i, 0))
+			.collect::<Vec<T::AccountId>>();
+		let early_mints = MintCount::MAX;
+	}: _(RawOrigin::Signed(organizer), season_id, accounts.clone().try_into().unwrap(), early_mints)
+	verify {
+		assert_last_event::<T>(Event::AddedToAllowlist { season_id, accounts, early_mints })
+	}
+
+	remove_from_allowlist {
+		let n in 1 .. MaxAllowlistBatch::get();
+		let organizer = account::<T>("organizer");
+		Organizer::<T>::put(&organizer);
+
+		let season_id = 1;
+		let accounts = (0..n)
+			.map(|i| frame_benchmarking::account("player", i, 0))
+			.collect::<Vec<T::AccountId>>();
+		for account in accounts.iter() {
+			Allowlists::<T>::insert(season_id, account, MintCount::MAX);
+		}
+	}: _(RawOrigin::Signed(organizer), season_id, accounts.clone().try_into().unwrap())
+	verify {
+		assert_last_event::<T>(Event::RemovedFromAllowlist { season_id, accounts })
+	}
+
+	set_airdrop_budget {
+		let organizer = account::<T>("organizer");
+		Organizer::<T>::put(&organizer);
+		let season_id = 1;
+		let budget = u32::MAX;
+	}: _(RawOrigin::Signed(organizer), season_id, budget)
+	verify {
+		assert_last_event::<T>(Event::AirdropBudgetSet { season_id, budget })
+	}
+
+	airdrop_free_mints {
+		let n in 1 .. <T as AvatarsConfig>::MaxAirdropBatch::get();
+		let organizer = account::<T>("organizer");
+		Organizer::<T>::put(&organizer);
+
+		let season_id = AAvatars::<T>::current_season_id();
+		AirdropBudgets::<T>::insert(season_id, u32::MAX);
+		let how_many = MintCount::MAX;
+		let airdrops = (0..n)
+			.map(|i| (frame_benchmarking::account("player", i, 0), how_many))
+			.collect::<Vec<(T::AccountId, MintCount)>>();
+		let mode = AirdropMode::Add;
+	}: _(RawOrigin::Signed(organizer), airdrops.try_into().unwrap(), mode)
+	verify {
+		let spent = n * how_many as u32;
+		assert_last_event::<T>(Event::FreeMintsAirdropped { season_id, recipients: n, spent, mode })
+	}
+
+	commit_randomness {
+		let organizer = account::<T>("organizer");
+		Organizer::<T>::put(&organizer);
+		let origin = T::ConfigOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let commitment = T::Hashing::hash(b"commitment");
+	}: _<T::RuntimeOrigin>(origin, commitment)
+	verify {
+		assert_last_event::<T>(Event::RandomnessCommitted { commitment })
+	}
+
+	reveal_randomness {
+		let service_account = create_service_account::<T>();
+		let value = T::Hashing::hash(b"value");
+		RandomnessCommitment::<T>::put(T::Hashing::hash(value.as_ref()));
+		RevealedRandomness::<T>::put((T::Hashing::hash(b"seed"), T::BlockNumber::from(1_u32)));
+	}: _(RawOrigin::Signed(service_account), value)
+	verify {
+		assert_last_event::<T>(Event::RandomnessRevealed { value })
+	}
+
+	upload_avatar {
+		let name = "player";
+		create_avatars::<T>(name, 1)?;
+		let player = account::<T>(name);
+		let avatar_id = AAvatars::<T>::owners(&player)[0];
+
+		let organizer = account::<T>("organizer");
+		CurrencyOf::<T>::make_free_balance_be(&organizer, CurrencyOf::<T>::minimum_balance());
+		create_collection::<T>(organizer)?;
+
+		let service_account = create_service_account_and_prepare_avatar::<T>(&player, &avatar_id)?;
+		let url = IpfsUrl::try_from(b"ipfs://test".to_vec()).unwrap();
+		AAvatars::<T>::prepare_ipfs(RawOrigin::Signed(service_account).into(), avatar_id, url)?;
+		AAvatars::<T>::lock_avatar(RawOrigin::Signed(player.clone()).into(), avatar_id)?;
+	}: _(RawOrigin::Signed(player.clone()), avatar_id)
+	verify {
+		// the NFT is now outside of the chain, so it can't be scheduled again
+		assert!(AAvatars::<T>::upload_avatar(RawOrigin::Signed(player).into(), avatar_id).is_err());
+	}
+
+	confirm_upload {
+		let name = "player";
+		create_avatars::<T>(name, 1)?;
+		let player = account::<T>(name);
+		let avatar_id = AAvatars::<T>::owners(&player)[0];
+		let service_account = create_service_account::<T>();
+		PendingUploads::<T>::insert(avatar_id, player);
+		let url = IpfsUrl::try_from(b"ipfs://test".to_vec()).unwrap();
+	}: _(RawOrigin::Signed(service_account), avatar_id, url.clone())
+	verify {
+		assert_last_event::<T>(Event::AvatarUploaded { avatar_id, url })
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::new_test_ext(),
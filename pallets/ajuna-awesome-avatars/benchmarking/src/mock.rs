@@ -16,18 +16,22 @@
 
 #![cfg(test)]
 
+use codec::{Decode, Encode};
 use frame_support::{
 	parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU16, ConstU64},
+	traits::{AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64},
 	PalletId,
 };
 use frame_system::{
 	mocking::{MockBlock, MockUncheckedExtrinsic},
+	offchain::{AppCrypto, CreateSignedTransaction, SendTransactionTypes, SigningTypes},
 	EnsureRoot, EnsureSigned,
 };
+use pallet_ajuna_awesome_avatars::{traits::FungiblesPayment, EnsureOrganizer};
+use scale_info::TypeInfo;
 use sp_runtime::{
-	testing::{Header, H256},
-	traits::{BlakeTwo256, IdentityLookup},
+	testing::{Header, TestSignature, TestXt, UintAuthorityId, H256},
+	traits::{BlakeTwo256, Extrinsic as ExtrinsicT, IdentifyAccount, IdentityLookup},
 };
 
 pub type MockAccountId = u32;
@@ -35,6 +39,7 @@ pub type MockBlockNumber = u64;
 pub type MockBalance = u64;
 pub type MockIndex = u64;
 pub type MockCollectionId = u32;
+pub type MockAssetId = u32;
 
 impl crate::Config for Runtime {}
 
@@ -46,6 +51,7 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system,
 		Balances: pallet_balances,
+		Assets: pallet_assets,
 		Randomness: pallet_randomness_collective_flip,
 		Nft: pallet_nfts,
 		AAvatars: pallet_ajuna_awesome_avatars,
@@ -96,6 +102,27 @@ impl pallet_balances::Config for Runtime {
 	type ReserveIdentifier = [u8; 8];
 }
 
+impl pallet_assets::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = MockBalance;
+	type RemoveItemsLimit = ConstU32<1_000>;
+	type AssetId = MockAssetId;
+	type AssetIdParameter = MockAssetId;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<MockAccountId>>;
+	type ForceOrigin = EnsureRoot<MockAccountId>;
+	type AssetDeposit = ConstU64<1>;
+	type AssetAccountDeposit = ConstU64<1>;
+	type MetadataDepositBase = ConstU64<1>;
+	type MetadataDepositPerByte = ConstU64<1>;
+	type ApprovalDeposit = ConstU64<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+}
+
 impl pallet_randomness_collective_flip::Config for Runtime {}
 
 parameter_types! {
@@ -167,11 +194,79 @@ impl pallet_ajuna_awesome_avatars::Config for Runtime {
 	type PalletId = AwesomeAvatarsPalletId;
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
-	type Randomness = Randomness;
+	type AssetPayment = FungiblesPayment<Assets>;
+	type Randomness =
+		pallet_ajuna_awesome_avatars::randomness::RandomnessWithNonce<Self, Randomness>;
 	type NftHandler = NftTransfer;
+	type AuthorityId = MockAuthorityId;
+	type SeasonOrigin = EnsureOrganizer<Runtime>;
+	type ConfigOrigin = EnsureOrganizer<Runtime>;
+	type FreeMintOrigin = EnsureOrganizer<Runtime>;
+	type MaxAirdropBatch = ConstU32<1_000>;
+	type MaxOffersPerAvatar = ConstU32<20>;
+	type MaxRentalsPerBlock = ConstU32<100>;
 	type WeightInfo = ();
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, TypeInfo)]
+pub struct MockPublic(u64);
+
+impl IdentifyAccount for MockPublic {
+	type AccountId = MockAccountId;
+	fn into_account(self) -> Self::AccountId {
+		self.0 as MockAccountId
+	}
+}
+
+impl From<UintAuthorityId> for MockPublic {
+	fn from(id: UintAuthorityId) -> Self {
+		Self(id.0)
+	}
+}
+
+impl From<MockPublic> for UintAuthorityId {
+	fn from(public: MockPublic) -> Self {
+		Self(public.0)
+	}
+}
+
+pub struct MockAuthorityId;
+
+impl AppCrypto<MockPublic, TestSignature> for MockAuthorityId {
+	type RuntimeAppPublic = UintAuthorityId;
+	type GenericPublic = UintAuthorityId;
+	type GenericSignature = TestSignature;
+}
+
+pub type MockExtrinsic = TestXt<RuntimeCall, ()>;
+
+impl SigningTypes for Runtime {
+	type Public = MockPublic;
+	type Signature = TestSignature;
+}
+
+impl<C> SendTransactionTypes<C> for Runtime
+where
+	RuntimeCall: From<C>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = MockExtrinsic;
+}
+
+impl<C> CreateSignedTransaction<C> for Runtime
+where
+	RuntimeCall: From<C>,
+{
+	fn create_transaction<A: AppCrypto<Self::Public, Self::Signature>>(
+		call: RuntimeCall,
+		public: MockPublic,
+		_account: MockAccountId,
+		_nonce: MockIndex,
+	) -> Option<(RuntimeCall, <MockExtrinsic as ExtrinsicT>::SignaturePayload)> {
+		Some((call, (public.0, ())))
+	}
+}
+
 parameter_types! {
 	pub const NftTransferPalletId: PalletId = PalletId(*b"aj/nfttr");
 }
@@ -183,6 +278,10 @@ impl pallet_ajuna_nft_transfer::Config for Runtime {
 	type ItemId = H256;
 	type ItemConfig = pallet_nfts::ItemConfig;
 	type NftHelper = Nft;
+	type NftUploader = AAvatars;
+	type MaxUploadsPerBlock = frame_support::traits::ConstU32<1>;
+	type UploadOrigin = EnsureRoot<MockAccountId>;
+	type WeightInfo = ();
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
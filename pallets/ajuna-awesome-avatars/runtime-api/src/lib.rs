@@ -0,0 +1,55 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for the Awesome Avatars pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use pallet_ajuna_awesome_avatars::types::{
+	AvatarInfo, CurrentSeason, ForgePreview, ListedAvatar, SeasonId, SeasonInfo,
+};
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait AwesomeAvatarsApi<AccountId, AvatarId, Balance, BlockNumber>
+	where
+		AccountId: Codec,
+		AvatarId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// Get the avatars owned by an account.
+		fn avatars_of(account: AccountId) -> Vec<AvatarInfo<AvatarId, Balance>>;
+
+		/// Get the current season and its status.
+		fn current_season() -> Option<CurrentSeason<BlockNumber>>;
+
+		/// Get the avatars listed for trade along with their prices.
+		fn listed_avatars() -> Vec<ListedAvatar<AccountId, AvatarId, Balance>>;
+
+		/// Get the stats of an account for a season.
+		fn player_season_stats(account: AccountId, season_id: SeasonId) -> SeasonInfo;
+
+		/// Preview the outcome of forging a leader with the given sacrifices.
+		fn preview_forge(
+			account: AccountId,
+			leader_id: AvatarId,
+			sacrifice_ids: Vec<AvatarId>,
+		) -> Result<ForgePreview, DispatchError>;
+	}
+}
@@ -31,26 +31,71 @@
 //!
 //! ### Dispatchable Functions
 //!
-//! Some dispatchable functions can be called only from the organizer.
+//! Some dispatchable functions can be called only from the admin origins of the pallet:
+//! `SeasonOrigin` sets up seasons, `ConfigOrigin` updates the game configuration and
+//! `FreeMintOrigin` issues free mints.
 //!
 //! * `mint` - Create a new AAA.
 //! * `forge` - Sacrifice a batch of avatars in order to improve a leader.
+//! * `forge_batch` - Forge several leaders, each with its own sacrifices, at once.
+//! * `ascend_avatar` - Re-roll an avatar of an earlier season into the current season.
+//! * `extract_souls` - Burn an avatar to add its souls to the souls of the player.
+//! * `reroll_component` - Spend souls to reroll a single DNA component of an avatar.
+//! * `buy_free_mints` - Spend souls on free mints.
+//! * `buy_forge_boost` - Spend souls to raise the probability of the next forge.
 //! * `transfer_free_mints` - Send free mints to another player.
 //! * `set_price` - Assign a price to an avatar.
 //! * `remove_price` - Remove the price of an avatar.
 //! * `buy` - Buy an avatar.
+//! * `create_auction` - Auction an avatar off to the highest bidder.
+//! * `bid` - Bid for an auctioned avatar.
+//! * `settle_auction` - Hand an auctioned avatar over to the highest bidder once it has ended.
+//! * `cancel_auction` - Cancel an auction without bids.
+//! * `make_offer` - Offer to buy an avatar.
+//! * `withdraw_offer` - Withdraw an offer to buy an avatar.
+//! * `accept_offer` - Sell an avatar to a buyer that made an offer for it.
+//! * `list_for_rent` - Offer to lend an avatar out for a price and a number of blocks.
+//! * `unlist_from_rent` - Withdraw an avatar from rent.
+//! * `rent` - Borrow an avatar, which can then be used as a forge sacrifice without consuming it.
 //! * `upgrade_storage` - Upgrade the capacity to hold avatars.
 //! * `set_organizer` - Set the game organizer.
 //! * `set_treasurer` - Set the treasurer.
 //! * `set_season` - Add a new season.
+//! * `set_carry_over` - Set which avatars of earlier seasons a season takes in.
+//! * `set_soul_exchange` - Set what players can spend their souls on during a season.
+//! * `add_to_allowlist` - Give accounts early access to a season, up to a number of mints.
+//! * `remove_from_allowlist` - Take early access to a season away from accounts.
 //! * `update_global_config` - Update the configuration.
+//! * `patch_global_config` - Update only some fields of the configuration.
+//! * `schedule_config_patch` - Schedule a configuration patch to take effect at a given block.
+//! * `cancel_config_patch` - Cancel a scheduled configuration patch.
 //! * `set_free_mints` - Set a number of free mints to a player.
+//! * `set_airdrop_budget` - Set the number of free mints that can be airdropped during a season.
+//! * `airdrop_free_mints` - Add or set free mints to several players at once.
+//! * `set_season_rewards` - Set the shares of a season's treasury awarded to its leaderboards.
+//! * `claim_season_reward` - Claim the reward earned on the leaderboards of a finished season.
+//! * `set_payment_asset` - Accept an asset for the payment of fees at a given conversion rate.
+//! * `set_fee_asset` - Choose the asset a player pays mint, trade and storage upgrade fees in.
+//! * `claim_asset_treasury` - Claim the fees a season collected in an asset.
+//! * `commit_randomness` - Commit to the values the service account reveals to seed randomness.
+//! * `reveal_randomness` - Reveal the next committed value to seed randomness.
 //!
 //! ### Public Functions
 //!
 //! * `do_forge` - Forge avatar.
 //! * `do_mint` - Mint avatar.
 //! * `ensure_season` - Given a season id and a season, validate them.
+//!
+//! ### Offchain Worker
+//!
+//! Pins the metadata of prepared avatars to IPFS and submits their URLs via `prepare_ipfs`,
+//! see [`offchain`].
+//!
+//! ### Migrations
+//!
+//! Migrations are carried out in `on_idle` over as many blocks as they need, one after another
+//! up to the current storage version. Calls fail with `MigrationInProgress` in the meantime, see
+//! [`migration::MultiBlockMigration`].
 
 #![feature(map_first_last, variant_count)]
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -64,19 +109,28 @@ mod mock;
 mod tests;
 
 pub mod migration;
+pub mod offchain;
+pub mod randomness;
+pub mod traits;
 pub mod types;
 pub mod weights;
 
-use crate::{types::*, weights::WeightInfo};
+use crate::{randomness::RandomnessSource, traits::AssetPayment, types::*, weights::WeightInfo};
 use frame_support::{
 	pallet_prelude::*,
-	traits::{Currency, ExistenceRequirement::AllowDeath, Randomness, WithdrawReasons},
+	storage::with_storage_layer,
+	traits::{Currency, ExistenceRequirement::AllowDeath, ReservableCurrency, WithdrawReasons},
 	PalletId,
 };
-use frame_system::{ensure_root, ensure_signed, pallet_prelude::*};
-use pallet_ajuna_nft_transfer::traits::NftHandler;
+use frame_system::{
+	ensure_root, ensure_signed,
+	offchain::{AppCrypto, CreateSignedTransaction},
+	pallet_prelude::*,
+};
+use pallet_ajuna_nft_staking::traits::NftDelegation;
+use pallet_ajuna_nft_transfer::traits::{NftHandler, NftUploader};
 use sp_runtime::{
-	traits::{AccountIdConversion, Hash, Saturating, TrailingZeroInput, UniqueSaturatedInto, Zero},
+	traits::{AccountIdConversion, Hash, Saturating, UniqueSaturatedInto, Zero},
 	ArithmeticError,
 };
 use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
@@ -89,8 +143,27 @@ pub mod pallet {
 	pub(crate) type SeasonOf<T> = Season<BlockNumberFor<T>>;
 	pub(crate) type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
 	pub(crate) type AvatarIdOf<T> = <T as frame_system::Config>::Hash;
-	pub(crate) type BoundedAvatarIdsOf<T> = BoundedVec<AvatarIdOf<T>, MaxAvatarsPerPlayer>;
 	pub(crate) type GlobalConfigOf<T> = GlobalConfig<BalanceOf<T>, BlockNumberFor<T>>;
+	pub(crate) type GlobalConfigPatchOf<T> = GlobalConfigPatch<BalanceOf<T>, BlockNumberFor<T>>;
+	pub(crate) type BoundedConfigPatchesOf<T> =
+		BoundedVec<(ConfigPatchId, GlobalConfigPatchOf<T>), MaxConfigPatchesPerBlock>;
+	pub(crate) type AuctionOf<T> =
+		Auction<AccountIdOf<T>, BalanceOf<T>, BlockNumberFor<T>, AssetIdOf<T>>;
+	pub(crate) type OfferOf<T> = Offer<BalanceOf<T>, AssetIdOf<T>>;
+	pub(crate) type RentalListingOf<T> = RentalListing<BalanceOf<T>, BlockNumberFor<T>>;
+	pub(crate) type RentalOf<T> = Rental<AccountIdOf<T>, BlockNumberFor<T>>;
+	pub(crate) type BoundedRentalsOf<T> =
+		BoundedVec<AvatarIdOf<T>, <T as Config>::MaxRentalsPerBlock>;
+	pub(crate) type ForgeLeaderboardOf<T> = BoundedVec<(AccountIdOf<T>, Stat), MaxLeaderboardSize>;
+	pub(crate) type MaxTierLeaderboardOf<T> =
+		BoundedVec<(AccountIdOf<T>, BlockNumberFor<T>), MaxLeaderboardSize>;
+	pub(crate) type AssetIdOf<T> =
+		<<T as Config>::AssetPayment as AssetPayment<AccountIdOf<T>, BalanceOf<T>>>::AssetId;
+	pub(crate) type ConversionRateOf<T> = ConversionRate<BalanceOf<T>>;
+	pub(crate) type CarryOverOf<T> = CarryOver<BalanceOf<T>>;
+	pub(crate) type AllowlistBatchOf<T> = BoundedVec<AccountIdOf<T>, MaxAllowlistBatch>;
+	pub(crate) type AirdropBatchOf<T> =
+		BoundedVec<(AccountIdOf<T>, MintCount), <T as Config>::MaxAirdropBatch>;
 	pub(crate) type CollectionIdOf<T> = <<T as Config>::NftHandler as NftHandler<
 		AccountIdOf<T>,
 		AvatarIdOf<T>,
@@ -105,18 +178,47 @@ pub mod pallet {
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
+	pub trait Config: frame_system::Config + CreateSignedTransaction<Call<Self>> {
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
 
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
-		type Currency: Currency<Self::AccountId>;
+		type Currency: ReservableCurrency<Self::AccountId>;
 
-		type Randomness: Randomness<Self::Hash, Self::BlockNumber>;
+		/// Means of paying fees in assets other than `Currency`.
+		type AssetPayment: AssetPayment<Self::AccountId, BalanceOf<Self>>;
+
+		/// Source of the entropy used to mint and forge avatars.
+		type Randomness: RandomnessSource<Self::AccountId, Self::Hash>;
 
 		type NftHandler: NftHandler<Self::AccountId, Self::Hash, Avatar>;
 
+		/// Identity of the service account signing the transactions of the offchain worker.
+		type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+
+		/// The origin allowed to set up seasons, along with their rewards and rules.
+		type SeasonOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The origin allowed to update the global configuration, collection and payment assets.
+		type ConfigOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The origin allowed to issue free mints.
+		type FreeMintOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The maximum number of accounts that free mints are airdropped to at once.
+		#[pallet::constant]
+		type MaxAirdropBatch: Get<u32>;
+
+		/// The maximum number of pending offers for a single avatar.
+		#[pallet::constant]
+		type MaxOffersPerAvatar: Get<u32>;
+
+		/// The maximum number of rentals expiring at the same block, which bounds the work done
+		/// when they expire.
+		#[pallet::constant]
+		type MaxRentalsPerBlock: Get<u32>;
+
 		type WeightInfo: WeightInfo;
 	}
 
@@ -146,18 +248,55 @@ pub mod pallet {
 	#[pallet::getter(fn treasury)]
 	pub type Treasury<T: Config> = StorageMap<_, Identity, SeasonId, BalanceOf<T>, ValueQuery>;
 
+	/// Fees collected in assets other than the native currency, by season.
+	#[pallet::storage]
+	#[pallet::getter(fn asset_treasury)]
+	pub type AssetTreasury<T: Config> =
+		StorageDoubleMap<_, Identity, SeasonId, Identity, AssetIdOf<T>, BalanceOf<T>, ValueQuery>;
+
+	/// Assets accepted for the payment of fees, along with their conversion rates.
+	#[pallet::storage]
+	#[pallet::getter(fn payment_assets)]
+	pub type PaymentAssets<T: Config> =
+		StorageMap<_, Identity, AssetIdOf<T>, ConversionRateOf<T>, OptionQuery>;
+
+	/// Assets players have chosen to pay fees in instead of the native currency.
+	#[pallet::storage]
+	#[pallet::getter(fn fee_assets)]
+	pub type FeeAssets<T: Config> =
+		StorageMap<_, Identity, T::AccountId, AssetIdOf<T>, OptionQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn global_configs)]
 	pub type GlobalConfigs<T: Config> = StorageValue<_, GlobalConfigOf<T>, ValueQuery>;
 
+	#[pallet::storage]
+	pub type NextConfigPatchId<T: Config> = StorageValue<_, ConfigPatchId, ValueQuery>;
+
+	/// Config patches by the block they're applied at, in the order they were scheduled.
+	#[pallet::storage]
+	#[pallet::getter(fn scheduled_config_patches)]
+	pub type ScheduledConfigPatches<T: Config> =
+		StorageMap<_, Identity, T::BlockNumber, BoundedConfigPatchesOf<T>, ValueQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn avatars)]
 	pub type Avatars<T: Config> = StorageMap<_, Identity, AvatarIdOf<T>, (T::AccountId, Avatar)>;
 
+	/// Avatars owned by each account.
+	#[pallet::storage]
+	pub type OwnedAvatars<T: Config> =
+		StorageDoubleMap<_, Identity, T::AccountId, Identity, AvatarIdOf<T>, (), OptionQuery>;
+
+	/// Number of avatars owned by each account, checked against the quota of its storage tier.
+	#[pallet::storage]
+	#[pallet::getter(fn owned_avatar_count)]
+	pub type OwnedAvatarCounts<T: Config> = StorageMap<_, Identity, T::AccountId, u32, ValueQuery>;
+
+	/// Where the ongoing multi-block migration resumes, if any. Calls are paused while it's set.
 	#[pallet::storage]
-	#[pallet::getter(fn owners)]
-	pub type Owners<T: Config> =
-		StorageMap<_, Identity, T::AccountId, BoundedAvatarIdsOf<T>, ValueQuery>;
+	#[pallet::getter(fn ongoing_migration)]
+	pub type OngoingMigration<T: Config> = StorageValue<_, migration::MigrationCursor, OptionQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn locked_avatars)]
@@ -177,10 +316,104 @@ pub mod pallet {
 	pub type SeasonStats<T: Config> =
 		StorageDoubleMap<_, Identity, SeasonId, Identity, T::AccountId, SeasonInfo, ValueQuery>;
 
+	/// Shares of the season treasury awarded to the players ranked on the season leaderboards.
+	#[pallet::storage]
+	#[pallet::getter(fn season_rewards)]
+	pub type SeasonRewardConfigs<T: Config> =
+		StorageMap<_, Identity, SeasonId, SeasonRewards, OptionQuery>;
+
+	/// Rules under which the avatars of earlier seasons take part in a season. Seasons without
+	/// rules don't take in any avatars of earlier seasons.
+	#[pallet::storage]
+	#[pallet::getter(fn carry_overs)]
+	pub type CarryOvers<T: Config> = StorageMap<_, Identity, SeasonId, CarryOverOf<T>, ValueQuery>;
+
+	/// Accounts with early access to a season, along with the number of avatars each of them can
+	/// still mint normally before the season starts.
+	#[pallet::storage]
+	#[pallet::getter(fn allowlist)]
+	pub type Allowlists<T: Config> =
+		StorageDoubleMap<_, Identity, SeasonId, Identity, T::AccountId, MintCount, OptionQuery>;
+
+	/// Free mints that can still be airdropped during a season.
+	#[pallet::storage]
+	#[pallet::getter(fn airdrop_budget)]
+	pub type AirdropBudgets<T: Config> = StorageMap<_, Identity, SeasonId, u32, ValueQuery>;
+
+	/// Prices in souls of what players can spend their souls on during a season.
+	#[pallet::storage]
+	#[pallet::getter(fn soul_exchanges)]
+	pub type SoulExchanges<T: Config> = StorageMap<_, Identity, SeasonId, SoulExchange, ValueQuery>;
+
+	/// Souls extracted from avatars that players can spend.
+	#[pallet::storage]
+	#[pallet::getter(fn souls)]
+	pub type Souls<T: Config> = StorageMap<_, Identity, T::AccountId, SoulCount, ValueQuery>;
+
+	/// Percentage points added to the probability of the next forge of a player.
+	#[pallet::storage]
+	#[pallet::getter(fn forge_boosts)]
+	pub type ForgeBoosts<T: Config> =
+		StorageMap<_, Identity, T::AccountId, RarityPercent, ValueQuery>;
+
+	/// Players that forged the most in a season, by number of forges.
+	#[pallet::storage]
+	#[pallet::getter(fn forge_leaderboard)]
+	pub type ForgeLeaderboards<T: Config> =
+		StorageMap<_, Identity, SeasonId, ForgeLeaderboardOf<T>, ValueQuery>;
+
+	/// Players that first reached a max tier avatar in a season, by the block they did so.
+	#[pallet::storage]
+	#[pallet::getter(fn max_tier_leaderboard)]
+	pub type MaxTierLeaderboards<T: Config> =
+		StorageMap<_, Identity, SeasonId, MaxTierLeaderboardOf<T>, ValueQuery>;
+
+	/// Rewards awarded to players at the end of a season, not claimed yet.
+	#[pallet::storage]
+	#[pallet::getter(fn unclaimed_rewards)]
+	pub type UnclaimedRewards<T: Config> =
+		StorageDoubleMap<_, Identity, SeasonId, Identity, T::AccountId, BalanceOf<T>, ValueQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn trade)]
 	pub type Trade<T: Config> = StorageMap<_, Identity, AvatarIdOf<T>, BalanceOf<T>, OptionQuery>;
 
+	/// Avatars being auctioned off.
+	#[pallet::storage]
+	#[pallet::getter(fn auctions)]
+	pub type Auctions<T: Config> =
+		StorageMap<_, Identity, AvatarIdOf<T>, AuctionOf<T>, OptionQuery>;
+
+	/// Offers made by buyers for avatars.
+	#[pallet::storage]
+	#[pallet::getter(fn offers)]
+	pub type Offers<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		AvatarIdOf<T>,
+		Identity,
+		T::AccountId,
+		OfferOf<T>,
+		OptionQuery,
+	>;
+
+	/// Avatars their owners are willing to lend out.
+	#[pallet::storage]
+	#[pallet::getter(fn rental_listings)]
+	pub type RentalListings<T: Config> =
+		StorageMap<_, Identity, AvatarIdOf<T>, RentalListingOf<T>, OptionQuery>;
+
+	/// Avatars currently lent out.
+	#[pallet::storage]
+	#[pallet::getter(fn rentals)]
+	pub type Rentals<T: Config> = StorageMap<_, Identity, AvatarIdOf<T>, RentalOf<T>, OptionQuery>;
+
+	/// Rented avatars by the block their rental expires at.
+	#[pallet::storage]
+	#[pallet::getter(fn rental_expiries)]
+	pub type RentalExpiries<T: Config> =
+		StorageMap<_, Identity, T::BlockNumber, BoundedRentalsOf<T>, ValueQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn service_account)]
 	pub type ServiceAccount<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
@@ -189,15 +422,80 @@ pub mod pallet {
 	#[pallet::getter(fn preparation)]
 	pub type Preparation<T: Config> = StorageMap<_, Identity, AvatarIdOf<T>, IpfsUrl, OptionQuery>;
 
+	/// Locked avatars handed over for upload, along with their owner, waiting for the offchain
+	/// worker to pin them.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_uploads)]
+	pub type PendingUploads<T: Config> =
+		StorageMap<_, Identity, AvatarIdOf<T>, T::AccountId, OptionQuery>;
+
+	/// The hash of the next value the service account reveals, see
+	/// [`randomness::CommitRevealRandomness`].
+	#[pallet::storage]
+	#[pallet::getter(fn randomness_commitment)]
+	pub type RandomnessCommitment<T: Config> = StorageValue<_, T::Hash, OptionQuery>;
+
+	/// Seed accumulated from the values revealed so far, along with the block of the last reveal.
+	#[pallet::storage]
+	#[pallet::getter(fn revealed_randomness)]
+	pub type RevealedRandomness<T: Config> =
+		StorageValue<_, (T::Hash, T::BlockNumber), OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
-		_phantom: sp_std::marker::PhantomData<T>,
+		pub organizer: Option<T::AccountId>,
+		pub service_account: Option<T::AccountId>,
+		pub treasurers: Vec<(SeasonId, T::AccountId)>,
+		pub seasons: Vec<(SeasonId, SeasonOf<T>)>,
+		pub global_config: GlobalConfigOf<T>,
+		pub free_mints: Vec<(T::AccountId, MintCount)>,
+		pub collection_id: Option<CollectionIdOf<T>>,
 	}
 
 	#[cfg(feature = "std")]
 	impl<T: Config> Default for GenesisConfig<T> {
 		fn default() -> Self {
-			GenesisConfig { _phantom: Default::default() }
+			GenesisConfig {
+				organizer: None,
+				service_account: None,
+				treasurers: Vec::new(),
+				seasons: Vec::new(),
+				global_config: GlobalConfig {
+					mint: MintConfig {
+						open: true,
+						fees: MintFees {
+							one: 550_000_000_000_u64.unique_saturated_into(), // 0.55 BAJU
+							three: 500_000_000_000_u64.unique_saturated_into(), // 0.5 BAJU
+							six: 450_000_000_000_u64.unique_saturated_into(), // 0.45 BAJU
+						},
+						cooldown: 5_u8.into(),
+						free_mint_fee_multiplier: 1,
+					},
+					forge: ForgeConfig { open: true },
+					transfer: TransferConfig {
+						open: true,
+						free_mint_transfer_fee: 1,
+						min_free_mint_transfer: 1,
+						// 1 BAJU
+						avatar_transfer_fee: 1_000_000_000_000_u64.unique_saturated_into(),
+					},
+					trade: TradeConfig {
+						open: true,
+						min_fee: 1_000_000_000_u64.unique_saturated_into(), // 0.01 BAJU
+						percent_fee: 1,                                     // 1% of sales price
+					},
+					account: AccountConfig {
+						// 1 BAJU
+						storage_upgrade_fee: 1_000_000_000_000_u64.unique_saturated_into(),
+					},
+					nft_transfer: NftTransferConfig {
+						open: true,
+						prepare_fee: 5_000_000_000_000_u64.unique_saturated_into(), // 5 BAJU
+					},
+				},
+				free_mints: Vec::new(),
+				collection_id: None,
+			}
 		}
 	}
 
@@ -205,37 +503,33 @@ pub mod pallet {
 	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
 		fn build(&self) {
 			CurrentSeasonId::<T>::put(1);
-			GlobalConfigs::<T>::put(GlobalConfig {
-				mint: MintConfig {
-					open: true,
-					fees: MintFees {
-						one: 550_000_000_000_u64.unique_saturated_into(), // 0.55 BAJU
-						three: 500_000_000_000_u64.unique_saturated_into(), // 0.5 BAJU
-						six: 450_000_000_000_u64.unique_saturated_into(), // 0.45 BAJU
-					},
-					cooldown: 5_u8.into(),
-					free_mint_fee_multiplier: 1,
-				},
-				forge: ForgeConfig { open: true },
-				transfer: TransferConfig {
-					open: true,
-					free_mint_transfer_fee: 1,
-					min_free_mint_transfer: 1,
-					avatar_transfer_fee: 1_000_000_000_000_u64.unique_saturated_into(), // 1 BAJU
-				},
-				trade: TradeConfig {
-					open: true,
-					min_fee: 1_000_000_000_u64.unique_saturated_into(), // 0.01 BAJU
-					percent_fee: 1,                                     // 1% of sales price
-				},
-				account: AccountConfig {
-					storage_upgrade_fee: 1_000_000_000_000_u64.unique_saturated_into(), // 1 BAJU
-				},
-				nft_transfer: NftTransferConfig {
-					open: true,
-					prepare_fee: 5_000_000_000_000_u64.unique_saturated_into(), // 5 BAJU
-				},
-			});
+			GlobalConfigs::<T>::put(&self.global_config);
+
+			if let Some(organizer) = &self.organizer {
+				Organizer::<T>::put(organizer);
+			}
+			if let Some(service_account) = &self.service_account {
+				ServiceAccount::<T>::put(service_account);
+			}
+			for (season_id, treasurer) in &self.treasurers {
+				Treasurer::<T>::insert(season_id, treasurer);
+			}
+
+			// seasons are validated in order, as each one is checked against the previous one
+			let mut seasons = self.seasons.clone();
+			seasons.sort_by_key(|(season_id, _)| *season_id);
+			for (season_id, season) in seasons {
+				let season = Pallet::<T>::ensure_season(&season_id, season)
+					.unwrap_or_else(|e| panic!("Invalid genesis season {}: {:?}", season_id, e));
+				Seasons::<T>::insert(season_id, season);
+			}
+
+			for (account, free_mints) in &self.free_mints {
+				Accounts::<T>::mutate(account, |info| info.free_mints = *free_mints);
+			}
+			if let Some(collection_id) = &self.collection_id {
+				CollectionId::<T>::put(collection_id);
+			}
 		}
 	}
 
@@ -256,6 +550,29 @@ pub mod pallet {
 		UpdatedSeason { season_id: SeasonId, season: SeasonOf<T> },
 		/// Global configuration updated.
 		UpdatedGlobalConfig(GlobalConfigOf<T>),
+		/// A config patch has been scheduled.
+		ConfigPatchScheduled {
+			patch_id: ConfigPatchId,
+			at: T::BlockNumber,
+			patch: GlobalConfigPatchOf<T>,
+		},
+		/// A scheduled config patch has been cancelled.
+		ConfigPatchCancelled { patch_id: ConfigPatchId, at: T::BlockNumber },
+		/// A scheduled config patch has taken effect.
+		ConfigPatchApplied { patch_id: ConfigPatchId, config: GlobalConfigOf<T> },
+		/// Accounts have been given early access to a season.
+		AddedToAllowlist {
+			season_id: SeasonId,
+			accounts: Vec<T::AccountId>,
+			early_mints: MintCount,
+		},
+		/// Accounts have lost early access to a season.
+		RemovedFromAllowlist { season_id: SeasonId, accounts: Vec<T::AccountId> },
+		/// The airdrop budget of a season has been set.
+		AirdropBudgetSet { season_id: SeasonId, budget: u32 },
+		/// Free mints have been airdropped to a number of accounts, spending `spent` of the
+		/// airdrop budget of the season.
+		FreeMintsAirdropped { season_id: SeasonId, recipients: u32, spent: u32, mode: AirdropMode },
 		/// Avatars minted.
 		AvatarsMinted { avatar_ids: Vec<AvatarIdOf<T>> },
 		/// Avatar forged.
@@ -274,8 +591,87 @@ pub mod pallet {
 		AvatarPriceSet { avatar_id: AvatarIdOf<T>, price: BalanceOf<T> },
 		/// Avatar has price removed for trade.
 		AvatarPriceUnset { avatar_id: AvatarIdOf<T> },
+		/// The rewards of a season have been set.
+		SeasonRewardsSet { season_id: SeasonId, rewards: SeasonRewards },
+		/// The rewards of a finished season have been awarded to its leaderboards.
+		SeasonRewardsAwarded { season_id: SeasonId, amount: BalanceOf<T> },
+		/// A player has claimed a season reward.
+		SeasonRewardClaimed { season_id: SeasonId, player: T::AccountId, amount: BalanceOf<T> },
+		/// An asset has been accepted for, or withdrawn from, the payment of fees.
+		PaymentAssetSet { asset_id: AssetIdOf<T>, rate: Option<ConversionRateOf<T>> },
+		/// A player has chosen the asset to pay fees in.
+		FeeAssetSet { player: T::AccountId, asset_id: Option<AssetIdOf<T>> },
+		/// The fees a season collected in an asset have been claimed.
+		AssetTreasuryClaimed {
+			season_id: SeasonId,
+			asset_id: AssetIdOf<T>,
+			treasurer: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// The carry-over rules of a season have been set.
+		CarryOverSet { season_id: SeasonId, carry_over: CarryOverOf<T> },
+		/// An avatar of an earlier season has ascended into the current season.
+		AvatarAscended { avatar_id: AvatarIdOf<T>, from_season_id: SeasonId, season_id: SeasonId },
+		/// The soul prices of a season have been set.
+		SoulExchangeSet { season_id: SeasonId, exchange: SoulExchange },
+		/// An avatar has been burned for its souls.
+		SoulsExtracted { avatar_id: AvatarIdOf<T>, player: T::AccountId, souls: SoulCount },
+		/// A component of an avatar has been rerolled for souls.
+		ComponentRerolled { avatar_id: AvatarIdOf<T>, component: u8, souls: SoulCount },
+		/// Free mints have been bought for souls.
+		FreeMintsBought { player: T::AccountId, how_many: MintCount, souls: SoulCount },
+		/// A forge boost has been bought for souls.
+		ForgeBoostBought { player: T::AccountId, boost: RarityPercent, souls: SoulCount },
 		/// Avatar has been traded.
 		AvatarTraded { avatar_id: AvatarIdOf<T>, from: T::AccountId, to: T::AccountId },
+		/// Avatar has been put up for auction.
+		AuctionCreated {
+			avatar_id: AvatarIdOf<T>,
+			seller: T::AccountId,
+			reserve_price: BalanceOf<T>,
+			end: T::BlockNumber,
+		},
+		/// A bid has been placed for an auctioned avatar.
+		AuctionBid { avatar_id: AvatarIdOf<T>, bidder: T::AccountId, amount: BalanceOf<T> },
+		/// An auction has ended, with the avatar handed over to the winner if there is one.
+		AuctionSettled {
+			avatar_id: AvatarIdOf<T>,
+			seller: T::AccountId,
+			winner: Option<T::AccountId>,
+			price: Option<BalanceOf<T>>,
+		},
+		/// An auction has been cancelled.
+		AuctionCancelled { avatar_id: AvatarIdOf<T> },
+		/// The highest bid of an auction has been refunded as the avatar couldn't be handed over.
+		AuctionBidRefunded { avatar_id: AvatarIdOf<T>, bidder: T::AccountId, amount: BalanceOf<T> },
+		/// An offer has been made for an avatar.
+		OfferMade { avatar_id: AvatarIdOf<T>, buyer: T::AccountId, amount: BalanceOf<T> },
+		/// An offer has been withdrawn.
+		OfferWithdrawn { avatar_id: AvatarIdOf<T>, buyer: T::AccountId },
+		/// An offer has been accepted and the avatar sold.
+		OfferAccepted {
+			avatar_id: AvatarIdOf<T>,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// Avatar has been listed for rent.
+		AvatarListedForRent {
+			avatar_id: AvatarIdOf<T>,
+			price: BalanceOf<T>,
+			duration: T::BlockNumber,
+		},
+		/// Avatar has been withdrawn from rent.
+		AvatarUnlistedFromRent { avatar_id: AvatarIdOf<T> },
+		/// Avatar has been lent out until the given block.
+		AvatarRented {
+			avatar_id: AvatarIdOf<T>,
+			owner: T::AccountId,
+			renter: T::AccountId,
+			expiry: T::BlockNumber,
+		},
+		/// A rental has expired and the avatar is back with its owner.
+		RentalExpired { avatar_id: AvatarIdOf<T>, renter: T::AccountId },
 		/// Avatar locked.
 		AvatarLocked { avatar_id: AvatarIdOf<T> },
 		/// Avatar unlocked.
@@ -288,6 +684,12 @@ pub mod pallet {
 		UnpreparedAvatar { avatar_id: AvatarIdOf<T> },
 		/// IPFS URL prepared.
 		PreparedIpfsUrl { url: IpfsUrl },
+		/// Avatar has been uploaded outside of the chain, under the given IPFS URL.
+		AvatarUploaded { avatar_id: AvatarIdOf<T>, url: IpfsUrl },
+		/// The service account has been committed to a new chain of values to reveal.
+		RandomnessCommitted { commitment: T::Hash },
+		/// The service account has revealed a committed value, updating the randomness seed.
+		RandomnessRevealed { value: T::Hash },
 	}
 
 	#[pallet::error]
@@ -403,6 +805,78 @@ pub mod pallet {
 		NoServiceAccount,
 		/// Tried to prepare an IPFS URL for an avatar with an empty URL.
 		EmptyIpfsUrl,
+		/// The NFT to upload isn't part of the avatar collection.
+		UnknownCollection,
+		/// The avatar isn't waiting to be uploaded.
+		UnknownUpload,
+		/// The auction doesn't exist.
+		UnknownAuction,
+		/// The end of an auction must be in the future.
+		InvalidAuctionEnd,
+		/// Tried to bid for an auction that has ended.
+		AuctionEnded,
+		/// Tried to settle an auction that has not ended yet.
+		AuctionNotEnded,
+		/// Tried to cancel an auction that already has bids.
+		AuctionHasBids,
+		/// The avatar has reached the maximum number of pending offers.
+		TooManyOffers,
+		/// The bid is below the reserve price or the highest bid.
+		BidTooLow,
+		/// The offer doesn't exist.
+		UnknownOffer,
+		/// Tried to make an offer of zero.
+		InvalidOffer,
+		/// The avatar is listed for rent or lent out.
+		AvatarInRental,
+		/// The avatar isn't listed for rent.
+		UnknownRentalListing,
+		/// Tried to list an avatar for rent for zero blocks.
+		InvalidRentalDuration,
+		/// Too many rentals expire at the same block.
+		TooManyRentals,
+		/// The reward shares add up to more than 100 percent.
+		IncorrectRewardShares,
+		/// Tried to set the rewards of a season that has already finished.
+		SeasonAlreadyFinished,
+		/// The asset isn't accepted for the payment of fees.
+		UnsupportedPaymentAsset,
+		/// The conversion rate of a payment asset can't be zero.
+		InvalidConversionRate,
+		/// Fees can't be paid in assets in this runtime.
+		AssetPaymentUnavailable,
+		/// A forge batch must contain at least one forge.
+		EmptyForgeBatch,
+		/// An avatar is used in more than one forge of a batch.
+		OverlappingForges,
+		/// Carry-over rules can only refer to seasons before the one they're set for.
+		IncorrectCarryOverSeason,
+		/// The current season doesn't take in avatars of the season of the avatar.
+		AscensionNotAllowed,
+		/// A forge boost can't exceed 100 percent.
+		IncorrectForgeBoost,
+		/// The purchase isn't available for souls in the current season.
+		UnavailableForSouls,
+		/// The player doesn't have enough souls.
+		InsufficientSouls,
+		/// The avatar doesn't have the DNA component.
+		UnknownComponent,
+		/// Calls are paused while the storage of the pallet is being migrated.
+		MigrationInProgress,
+		/// Config patches can only be scheduled for a future block.
+		ConfigPatchInThePast,
+		/// Too many config patches are scheduled for the same block.
+		TooManyConfigPatches,
+		/// The scheduled config patch doesn't exist.
+		UnknownConfigPatch,
+		/// The account has minted as many avatars as its early access allows.
+		InsufficientEarlyAccessMints,
+		/// The airdrop hands out more free mints than the season has left in its budget.
+		AirdropBudgetExceeded,
+		/// No value has been committed to for the service account to reveal.
+		NoRandomnessCommitment,
+		/// The revealed value doesn't hash to the commitment.
+		InvalidRandomnessReveal,
 	}
 
 	#[pallet::hooks]
@@ -421,8 +895,19 @@ pub mod pallet {
 				}
 			}
 
+			Self::expire_rentals(&mut weight, now);
+			Self::apply_config_patches(&mut weight, now);
 			weight
 		}
+
+		fn on_idle(_now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			migration::step::<T>(remaining_weight)
+		}
+
+		fn offchain_worker(now: T::BlockNumber) {
+			Self::pin_prepared_avatars(now);
+			Self::pin_pending_uploads(now);
+		}
 	}
 
 	#[pallet::call]
@@ -431,16 +916,11 @@ pub mod pallet {
 		///
 		/// Emits `AvatarsMinted` event when successful.
 		///
-		/// Weight: `O(n)` where:
-		/// - `n = max avatars per player`
+		/// Weight: `O(1)`
 		#[pallet::call_index(0)]
-		#[pallet::weight({
-			let n = MaxAvatarsPerPlayer::get();
-			T::WeightInfo::mint_normal(n)
-				.max(T::WeightInfo::mint_free(n))
-		})]
+		#[pallet::weight(T::WeightInfo::mint_normal().max(T::WeightInfo::mint_free()))]
 		pub fn mint(origin: OriginFor<T>, mint_option: MintOption) -> DispatchResult {
-			let player = ensure_signed(origin)?;
+			let player = Self::ensure_signed_and_migrated(origin)?;
 			Self::do_mint(&player, &mint_option)
 		}
 
@@ -452,24 +932,25 @@ pub mod pallet {
 		///
 		/// Emits `AvatarForged` event when successful.
 		///
-		/// Weight: `O(1)`
+		/// Weight: `O(o)` where:
+		/// - `o = sacrifices * max offers per avatar`
 		#[pallet::call_index(1)]
-		#[pallet::weight(T::WeightInfo::forge(MaxAvatarsPerPlayer::get()))]
+		#[pallet::weight(T::WeightInfo::forge(
+			(sacrifices.len() as u32).saturating_mul(T::MaxOffersPerAvatar::get())
+		))]
 		pub fn forge(
 			origin: OriginFor<T>,
 			leader: AvatarIdOf<T>,
 			sacrifices: Vec<AvatarIdOf<T>>,
 		) -> DispatchResult {
-			let player = ensure_signed(origin)?;
+			let player = Self::ensure_signed_and_migrated(origin)?;
 			Self::do_forge(&player, &leader, &sacrifices)
 		}
 
 		#[pallet::call_index(2)]
-		#[pallet::weight({
-			let n = MaxAvatarsPerPlayer::get();
-			T::WeightInfo::transfer_avatar_normal(n)
-				.max(T::WeightInfo::transfer_avatar_organizer(n))
-		})]
+		#[pallet::weight(
+			T::WeightInfo::transfer_avatar_normal().max(T::WeightInfo::transfer_avatar_organizer())
+		)]
 		pub fn transfer_avatar(
 			origin: OriginFor<T>,
 			to: T::AccountId,
@@ -480,11 +961,11 @@ pub mod pallet {
 				Ok(organizer) => organizer,
 				_ => {
 					ensure!(transfer.open, Error::<T>::TransferClosed);
-					ensure_signed(origin)?
+					Self::ensure_signed_and_migrated(origin)?
 				},
 			};
 			ensure!(from != to, Error::<T>::CannotTransferToSelf);
-			ensure!(Self::ensure_for_trade(&avatar_id).is_err(), Error::<T>::AvatarInTrade);
+			Self::ensure_not_in_trade(&avatar_id)?;
 			Self::ensure_unlocked(&avatar_id)?;
 			Self::ensure_unprepared(&avatar_id)?;
 
@@ -510,7 +991,7 @@ pub mod pallet {
 			to: T::AccountId,
 			how_many: MintCount,
 		) -> DispatchResult {
-			let from = ensure_signed(origin)?;
+			let from = Self::ensure_signed_and_migrated(origin)?;
 			ensure!(from != to, Error::<T>::CannotTransferToSelf);
 
 			let GlobalConfig { transfer, .. } = Self::global_configs();
@@ -549,9 +1030,11 @@ pub mod pallet {
 			avatar_id: AvatarIdOf<T>,
 			#[pallet::compact] price: BalanceOf<T>,
 		) -> DispatchResult {
-			let seller = ensure_signed(origin)?;
+			let seller = Self::ensure_signed_and_migrated(origin)?;
 			ensure!(Self::global_configs().trade.open, Error::<T>::TradeClosed);
 			Self::ensure_ownership(&seller, &avatar_id)?;
+			ensure!(!Auctions::<T>::contains_key(avatar_id), Error::<T>::AvatarInTrade);
+			Self::ensure_not_in_rental(&avatar_id)?;
 			Self::ensure_unlocked(&avatar_id)?;
 			Self::ensure_unprepared(&avatar_id)?;
 			Trade::<T>::insert(avatar_id, price);
@@ -569,7 +1052,7 @@ pub mod pallet {
 		#[pallet::call_index(5)]
 		#[pallet::weight(T::WeightInfo::remove_price())]
 		pub fn remove_price(origin: OriginFor<T>, avatar_id: AvatarIdOf<T>) -> DispatchResult {
-			let seller = ensure_signed(origin)?;
+			let seller = Self::ensure_signed_and_migrated(origin)?;
 			ensure!(Self::global_configs().trade.open, Error::<T>::TradeClosed);
 			Self::ensure_for_trade(&avatar_id)?;
 			Self::ensure_ownership(&seller, &avatar_id)?;
@@ -589,9 +1072,9 @@ pub mod pallet {
 		///
 		/// Weight: `O(1)`
 		#[pallet::call_index(6)]
-		#[pallet::weight(T::WeightInfo::buy(MaxAvatarsPerPlayer::get()))]
+		#[pallet::weight(T::WeightInfo::buy())]
 		pub fn buy(origin: OriginFor<T>, avatar_id: AvatarIdOf<T>) -> DispatchResult {
-			let buyer = ensure_signed(origin)?;
+			let buyer = Self::ensure_signed_and_migrated(origin)?;
 			let GlobalConfig { trade, .. } = Self::global_configs();
 			ensure!(trade.open, Error::<T>::TradeClosed);
 
@@ -599,14 +1082,9 @@ pub mod pallet {
 			ensure!(buyer != seller, Error::<T>::AlreadyOwned);
 			T::Currency::transfer(&buyer, &seller, price, AllowDeath)?;
 
-			let trade_fee = trade.min_fee.max(
-				price.saturating_mul(trade.percent_fee.unique_saturated_into()) /
-					MAX_PERCENTAGE.unique_saturated_into(),
-			);
-			T::Currency::withdraw(&buyer, trade_fee, WithdrawReasons::FEE, AllowDeath)?;
-
+			let trade_fee = Self::trade_fee(&trade, price);
 			let avatar = Self::ensure_ownership(&seller, &avatar_id)?;
-			Self::deposit_into_treasury(&avatar.season_id, trade_fee);
+			Self::pay_fee(&buyer, trade_fee, &avatar.season_id)?;
 
 			Self::do_transfer_avatar(&seller, &buyer, &avatar_id)?;
 			Trade::<T>::remove(avatar_id);
@@ -618,7 +1096,8 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Upgrade storage.
+		/// Upgrade storage, raising the number of avatars the player can own. The top storage tier
+		/// lifts the limit altogether.
 		///
 		/// Emits `StorageTierUpgraded` event when successful.
 		///
@@ -626,15 +1105,13 @@ pub mod pallet {
 		#[pallet::call_index(7)]
 		#[pallet::weight(T::WeightInfo::upgrade_storage())]
 		pub fn upgrade_storage(origin: OriginFor<T>) -> DispatchResult {
-			let player = ensure_signed(origin)?;
+			let player = Self::ensure_signed_and_migrated(origin)?;
 			let storage_tier = Self::accounts(&player).storage_tier;
 			ensure!(storage_tier != StorageTier::Max, Error::<T>::MaxStorageTierReached);
 
 			let upgrade_fee = Self::global_configs().account.storage_upgrade_fee;
-			T::Currency::withdraw(&player, upgrade_fee, WithdrawReasons::FEE, AllowDeath)?;
-
 			let season_id = Self::current_season_id();
-			Self::deposit_into_treasury(&season_id, upgrade_fee);
+			Self::pay_fee(&player, upgrade_fee, &season_id)?;
 
 			Accounts::<T>::mutate(&player, |account| account.storage_tier = storage_tier.upgrade());
 			Self::deposit_event(Event::StorageTierUpgraded);
@@ -643,9 +1120,8 @@ pub mod pallet {
 
 		/// Set game organizer.
 		///
-		/// The organizer account is like an admin, allowed to perform certain operations
-		/// related with the game configuration like `set_season`, `ensure_free_mint` and
-		/// `update_global_config`.
+		/// The organizer account transfers avatars regardless of whether transfers are open, and
+		/// runtimes may allow it to configure the game through `EnsureOrganizer`.
 		///
 		/// It can only be set by a root account.
 		///
@@ -686,17 +1162,11 @@ pub mod pallet {
 		#[pallet::call_index(10)]
 		#[pallet::weight(T::WeightInfo::claim_treasury())]
 		pub fn claim_treasury(origin: OriginFor<T>, season_id: SeasonId) -> DispatchResult {
-			let maybe_treasurer = ensure_signed(origin)?;
+			let maybe_treasurer = Self::ensure_signed_and_migrated(origin)?;
 			let treasurer = Self::treasurer(season_id).ok_or(Error::<T>::UnknownTreasurer)?;
 			ensure!(maybe_treasurer == treasurer, DispatchError::BadOrigin);
 
-			let (current_season_id, season) = Self::current_season_with_id()?;
-			ensure!(
-				season_id < current_season_id ||
-					(season_id == current_season_id &&
-						<frame_system::Pallet<T>>::block_number() > season.end),
-				Error::<T>::CannotClaimDuringSeason
-			);
+			Self::ensure_season_ended(season_id)?;
 
 			let amount = Treasury::<T>::take(season_id);
 			ensure!(!amount.is_zero(), Error::<T>::CannotClaimZero);
@@ -710,7 +1180,7 @@ pub mod pallet {
 		///
 		/// Creates a new season. The new season can overlap with the already existing.
 		///
-		/// It can only be set by an organizer account.
+		/// It can only be set by `SeasonOrigin`.
 		///
 		/// Emits `UpdatedSeason` event when successful.
 		///
@@ -722,7 +1192,7 @@ pub mod pallet {
 			season_id: SeasonId,
 			season: SeasonOf<T>,
 		) -> DispatchResult {
-			Self::ensure_organizer(origin)?;
+			Self::ensure_admin::<T::SeasonOrigin>(origin)?;
 			let season = Self::ensure_season(&season_id, season)?;
 			Seasons::<T>::insert(season_id, &season);
 			Self::deposit_event(Event::UpdatedSeason { season_id, season });
@@ -731,7 +1201,7 @@ pub mod pallet {
 
 		/// Update global configuration.
 		///
-		/// It can only be called by an organizer account.
+		/// It can only be called by `ConfigOrigin`.
 		///
 		/// Emits `UpdatedGlobalConfig` event when successful.
 		///
@@ -742,20 +1212,15 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			new_global_config: GlobalConfigOf<T>,
 		) -> DispatchResult {
-			Self::ensure_organizer(origin)?;
-			ensure!(
-				[
-					new_global_config.mint.fees.one,
-					new_global_config.mint.fees.three,
-					new_global_config.mint.fees.six,
-					new_global_config.transfer.avatar_transfer_fee,
-					new_global_config.trade.min_fee,
-					new_global_config.account.storage_upgrade_fee
-				]
-				.iter()
-				.all(|x| x > &T::Currency::minimum_balance()),
-				Error::<T>::TooLowFees
-			);
+			Self::ensure_admin::<T::ConfigOrigin>(origin)?;
+			Self::ensure_fees([
+				new_global_config.mint.fees.one,
+				new_global_config.mint.fees.three,
+				new_global_config.mint.fees.six,
+				new_global_config.transfer.avatar_transfer_fee,
+				new_global_config.trade.min_fee,
+				new_global_config.account.storage_upgrade_fee,
+			])?;
 			GlobalConfigs::<T>::put(&new_global_config);
 			Self::deposit_event(Event::UpdatedGlobalConfig(new_global_config));
 			Ok(())
@@ -763,7 +1228,7 @@ pub mod pallet {
 
 		/// Set free mints.
 		///
-		/// It can only be called by an organizer account.
+		/// It can only be called by `FreeMintOrigin`.
 		///
 		/// Emits `FreeMintSet` event when successful.
 		///
@@ -775,7 +1240,7 @@ pub mod pallet {
 			target: T::AccountId,
 			how_many: MintCount,
 		) -> DispatchResult {
-			Self::ensure_organizer(origin)?;
+			Self::ensure_admin::<T::FreeMintOrigin>(origin)?;
 			Accounts::<T>::mutate(&target, |account| account.free_mints = how_many);
 			Self::deposit_event(Event::FreeMintsSet { target, how_many });
 			Ok(())
@@ -787,18 +1252,18 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			collection_id: CollectionIdOf<T>,
 		) -> DispatchResult {
-			Self::ensure_organizer(origin)?;
+			Self::ensure_admin::<T::ConfigOrigin>(origin)?;
 			CollectionId::<T>::put(&collection_id);
 			Self::deposit_event(Event::CollectionIdSet { collection_id });
 			Ok(())
 		}
 
 		#[pallet::call_index(15)]
-		#[pallet::weight(T::WeightInfo::lock_avatar(MaxAvatarsPerPlayer::get()))]
+		#[pallet::weight(T::WeightInfo::lock_avatar())]
 		pub fn lock_avatar(origin: OriginFor<T>, avatar_id: AvatarIdOf<T>) -> DispatchResult {
-			let player = ensure_signed(origin)?;
+			let player = Self::ensure_signed_and_migrated(origin)?;
 			let avatar = Self::ensure_ownership(&player, &avatar_id)?;
-			ensure!(Self::ensure_for_trade(&avatar_id).is_err(), Error::<T>::AvatarInTrade);
+			Self::ensure_not_in_trade(&avatar_id)?;
 			ensure!(Self::global_configs().nft_transfer.open, Error::<T>::NftTransferClosed);
 			Self::ensure_unlocked(&avatar_id)?;
 			ensure!(Preparation::<T>::contains_key(avatar_id), Error::<T>::NotPrepared);
@@ -815,11 +1280,11 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(16)]
-		#[pallet::weight(T::WeightInfo::unlock_avatar(MaxAvatarsPerPlayer::get()))]
+		#[pallet::weight(T::WeightInfo::unlock_avatar())]
 		pub fn unlock_avatar(origin: OriginFor<T>, avatar_id: AvatarIdOf<T>) -> DispatchResult {
-			let player = ensure_signed(origin)?;
+			let player = Self::ensure_signed_and_migrated(origin)?;
 			let _ = Self::ensure_ownership(&Self::technical_account_id(), &avatar_id)?;
-			ensure!(Self::ensure_for_trade(&avatar_id).is_err(), Error::<T>::AvatarInTrade);
+			Self::ensure_not_in_trade(&avatar_id)?;
 			ensure!(Self::global_configs().nft_transfer.open, Error::<T>::NftTransferClosed);
 			ensure!(LockedAvatars::<T>::contains_key(avatar_id), Error::<T>::AvatarUnlocked);
 
@@ -835,7 +1300,7 @@ pub mod pallet {
 		#[pallet::call_index(17)]
 		#[pallet::weight(T::WeightInfo::fix_variation())]
 		pub fn fix_variation(origin: OriginFor<T>, avatar_id: AvatarIdOf<T>) -> DispatchResult {
-			let account = ensure_signed(origin)?;
+			let account = Self::ensure_signed_and_migrated(origin)?;
 			let mut avatar = Self::ensure_ownership(&account, &avatar_id)?;
 
 			// Update the variation of the 3nd component to be the same as that of the 2nd by
@@ -862,9 +1327,9 @@ pub mod pallet {
 		#[pallet::call_index(19)]
 		#[pallet::weight(T::WeightInfo::prepare_avatar())]
 		pub fn prepare_avatar(origin: OriginFor<T>, avatar_id: AvatarIdOf<T>) -> DispatchResult {
-			let player = ensure_signed(origin)?;
+			let player = Self::ensure_signed_and_migrated(origin)?;
 			let _ = Self::ensure_ownership(&player, &avatar_id)?;
-			ensure!(Self::ensure_for_trade(&avatar_id).is_err(), Error::<T>::AvatarInTrade);
+			Self::ensure_not_in_trade(&avatar_id)?;
 			ensure!(Self::global_configs().nft_transfer.open, Error::<T>::NftTransferClosed);
 			Self::ensure_unlocked(&avatar_id)?;
 			Self::ensure_unprepared(&avatar_id)?;
@@ -881,7 +1346,7 @@ pub mod pallet {
 		#[pallet::call_index(20)]
 		#[pallet::weight(T::WeightInfo::unprepare_avatar())]
 		pub fn unprepare_avatar(origin: OriginFor<T>, avatar_id: AvatarIdOf<T>) -> DispatchResult {
-			let player = ensure_signed(origin)?;
+			let player = Self::ensure_signed_and_migrated(origin)?;
 			let _ = Self::ensure_ownership(&player, &avatar_id)?;
 			ensure!(Self::global_configs().nft_transfer.open, Error::<T>::NftTransferClosed);
 			ensure!(Preparation::<T>::contains_key(avatar_id), Error::<T>::NotPrepared);
@@ -906,124 +1371,1324 @@ pub mod pallet {
 			Self::deposit_event(Event::PreparedIpfsUrl { url });
 			Ok(())
 		}
-	}
-
-	impl<T: Config> Pallet<T> {
-		/// The account ID of the treasury.
-		pub fn treasury_account_id() -> T::AccountId {
-			T::PalletId::get().into_account_truncating()
-		}
-
-		/// The account ID of the treasury.
-		pub fn technical_account_id() -> T::AccountId {
-			T::PalletId::get().into_sub_account_truncating(b"technical")
-		}
-
-		pub(crate) fn deposit_into_treasury(season_id: &SeasonId, amount: BalanceOf<T>) {
-			Treasury::<T>::mutate(season_id, |bal| bal.saturating_accrue(amount));
-			T::Currency::deposit_creating(&Self::treasury_account_id(), amount);
-		}
 
-		/// Check that the origin is an organizer account.
-		pub(crate) fn ensure_organizer(
+		/// Put an avatar up for an English auction ending at block `end`.
+		///
+		/// Only allowed while trade period is open.
+		///
+		/// Emits `AuctionCreated` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::create_auction())]
+		pub fn create_auction(
 			origin: OriginFor<T>,
-		) -> Result<T::AccountId, DispatchError> {
-			let maybe_organizer = ensure_signed(origin)?;
-			let existing_organizer = Self::organizer().ok_or(Error::<T>::OrganizerNotSet)?;
-			ensure!(maybe_organizer == existing_organizer, DispatchError::BadOrigin);
-			Ok(maybe_organizer)
-		}
+			avatar_id: AvatarIdOf<T>,
+			#[pallet::compact] reserve_price: BalanceOf<T>,
+			end: T::BlockNumber,
+		) -> DispatchResult {
+			let seller = Self::ensure_signed_and_migrated(origin)?;
+			ensure!(Self::global_configs().trade.open, Error::<T>::TradeClosed);
+			Self::ensure_ownership(&seller, &avatar_id)?;
+			Self::ensure_not_in_trade(&avatar_id)?;
+			Self::ensure_unlocked(&avatar_id)?;
+			Self::ensure_unprepared(&avatar_id)?;
+			ensure!(end > <frame_system::Pallet<T>>::block_number(), Error::<T>::InvalidAuctionEnd);
 
-		pub(crate) fn ensure_service_account(
-			origin: OriginFor<T>,
-		) -> Result<T::AccountId, DispatchError> {
-			let maybe_sa = ensure_signed(origin)?;
-			let existing_sa = Self::service_account().ok_or(Error::<T>::OrganizerNotSet)?;
-			ensure!(maybe_sa == existing_sa, DispatchError::BadOrigin);
-			Ok(maybe_sa)
+			let auction = Auction { seller: seller.clone(), reserve_price, end, highest_bid: None };
+			Auctions::<T>::insert(avatar_id, auction);
+			Self::deposit_event(Event::AuctionCreated { avatar_id, seller, reserve_price, end });
+			Ok(())
 		}
 
-		/// Validates a new season.
-		pub(crate) fn ensure_season(
-			season_id: &SeasonId,
-			mut season: SeasonOf<T>,
-		) -> Result<SeasonOf<T>, DispatchError> {
-			season.validate::<T>()?;
-
-			let prev_season_id = season_id.checked_sub(1).ok_or(ArithmeticError::Underflow)?;
-			let next_season_id = season_id.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+		/// Bid for an auctioned avatar.
+		///
+		/// The bid and its trade fee, fixed at the time of bidding, are reserved until the bid is
+		/// outbid or the auction settled. A fee paid in the fee asset of the bidder is held by the
+		/// treasury instead. The bidder must have room in their storage tier for the avatar.
+		///
+		/// Only allowed while trade period is open.
+		///
+		/// Emits `AuctionBid` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::bid())]
+		pub fn bid(
+			origin: OriginFor<T>,
+			avatar_id: AvatarIdOf<T>,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let bidder = Self::ensure_signed_and_migrated(origin)?;
+			let GlobalConfig { trade, .. } = Self::global_configs();
+			ensure!(trade.open, Error::<T>::TradeClosed);
 
-			if prev_season_id > 0 {
-				let prev_season =
-					Self::seasons(prev_season_id).ok_or(Error::<T>::NonSequentialSeasonId)?;
-				ensure!(prev_season.end < season.early_start, Error::<T>::EarlyStartTooEarly);
+			let mut auction = Self::auctions(avatar_id).ok_or(Error::<T>::UnknownAuction)?;
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() < auction.end,
+				Error::<T>::AuctionEnded
+			);
+			ensure!(bidder != auction.seller, Error::<T>::AlreadyOwned);
+			ensure!(amount >= auction.reserve_price, Error::<T>::BidTooLow);
+			if let Some(highest_bid) = &auction.highest_bid {
+				ensure!(amount > highest_bid.amount, Error::<T>::BidTooLow);
 			}
-			if let Some(next_season) = Self::seasons(next_season_id) {
-				ensure!(season.end < next_season.early_start, Error::<T>::SeasonEndTooLate);
+			Self::ensure_storage_quota(&bidder, 1)?;
+
+			let (fee, fee_asset) = Self::hold_fee(&bidder, Self::trade_fee(&trade, amount))?;
+			let bid = Bid { bidder: bidder.clone(), amount, fee, fee_asset };
+			T::Currency::reserve(&bidder, bid.reserved())?;
+			if let Some(outbid) = auction.highest_bid.take() {
+				Self::release_funds(
+					&outbid.bidder,
+					outbid.reserved(),
+					outbid.fee,
+					outbid.fee_asset,
+				)?;
 			}
-			Ok(season)
-		}
 
-		#[inline]
-		fn random_hash(phrase: &[u8], who: &T::AccountId) -> T::Hash {
-			let (seed, _) = T::Randomness::random(phrase);
-			let seed = T::Hash::decode(&mut TrailingZeroInput::new(seed.as_ref()))
-				.expect("input is padded with zeroes; qed");
-			let nonce = frame_system::Pallet::<T>::account_nonce(who);
-			frame_system::Pallet::<T>::inc_account_nonce(who);
-			(seed, &who, nonce.encode()).using_encoded(T::Hashing::hash)
+			auction.highest_bid = Some(bid);
+			Auctions::<T>::insert(avatar_id, auction);
+			Self::deposit_event(Event::AuctionBid { avatar_id, bidder, amount });
+			Ok(())
 		}
 
-		#[inline]
-		fn random_component(
-			season: &SeasonOf<T>,
-			hash: &T::Hash,
-			index: usize,
-			batched_mint: bool,
-		) -> (u8, u8) {
-			let hash = hash.as_ref();
-			let random_tier = {
-				let random_prob = hash[index] % MAX_PERCENTAGE;
-				let probs =
-					if batched_mint { &season.batch_mint_probs } else { &season.single_mint_probs };
-				let mut cumulative_sum = 0;
-				let mut random_tier = season.tiers[0].clone() as u8;
-				for i in 0..probs.len() {
-					let new_cumulative_sum = cumulative_sum + probs[i];
-					if random_prob >= cumulative_sum && random_prob < new_cumulative_sum {
-						random_tier = season.tiers[i].clone() as u8;
-						break
+		/// Settle an auction that has ended, handing the avatar over to the highest bidder.
+		///
+		/// If the avatar can't be handed over, e.g. because the storage of the highest bidder is
+		/// full by then, the bid is refunded and the avatar stays with the seller.
+		///
+		/// Can be called by anyone.
+		///
+		/// Emits `AuctionSettled` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::settle_auction())]
+		pub fn settle_auction(origin: OriginFor<T>, avatar_id: AvatarIdOf<T>) -> DispatchResult {
+			let _ = Self::ensure_signed_and_migrated(origin)?;
+			let auction = Self::auctions(avatar_id).ok_or(Error::<T>::UnknownAuction)?;
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() >= auction.end,
+				Error::<T>::AuctionNotEnded
+			);
+
+			Auctions::<T>::remove(avatar_id);
+			let Auction { seller, highest_bid, .. } = auction;
+			let (winner, price) = match highest_bid {
+				Some(bid) => {
+					let sold = with_storage_layer(|| {
+						Self::do_sell_avatar(
+							&seller,
+							&bid.bidder,
+							&avatar_id,
+							bid.amount,
+							bid.fee,
+							bid.fee_asset,
+						)
+					});
+					match sold {
+						Ok(()) => (Some(bid.bidder), Some(bid.amount)),
+						Err(_) => {
+							Self::release_funds(
+								&bid.bidder,
+								bid.reserved(),
+								bid.fee,
+								bid.fee_asset,
+							)?;
+							Self::deposit_event(Event::AuctionBidRefunded {
+								avatar_id,
+								bidder: bid.bidder,
+								amount: bid.amount,
+							});
+							(None, None)
+						},
 					}
-					cumulative_sum = new_cumulative_sum;
-				}
-				random_tier
+				},
+				None => (None, None),
 			};
-			let random_variation = hash[index + 1] % season.max_variations;
-			(random_tier, random_variation)
-		}
 
-		fn random_dna(
-			hash: &T::Hash,
-			season: &SeasonOf<T>,
-			batched_mint: bool,
-		) -> Result<Dna, DispatchError> {
-			let dna = (0..season.max_components)
-				.map(|i| {
-					let (random_tier, random_variation) =
-						Self::random_component(season, hash, i as usize * 2, batched_mint);
-					((random_tier << 4) | random_variation) as u8
-				})
-				.collect::<Vec<_>>();
-			Dna::try_from(dna).map_err(|_| Error::<T>::IncorrectDna.into())
+			Self::deposit_event(Event::AuctionSettled { avatar_id, seller, winner, price });
+			Ok(())
 		}
 
-		/// Mint a new avatar.
-		pub(crate) fn do_mint(player: &T::AccountId, mint_option: &MintOption) -> DispatchResult {
-			let GlobalConfig { mint, .. } = Self::global_configs();
-			ensure!(mint.open, Error::<T>::MintClosed);
-			let free_mints = Self::ensure_for_mint(player, &mint_option.mint_type)?;
+		/// Cancel an auction that has no bids.
+		///
+		/// Emits `AuctionCancelled` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::cancel_auction())]
+		pub fn cancel_auction(origin: OriginFor<T>, avatar_id: AvatarIdOf<T>) -> DispatchResult {
+			let seller = Self::ensure_signed_and_migrated(origin)?;
+			let auction = Self::auctions(avatar_id).ok_or(Error::<T>::UnknownAuction)?;
+			ensure!(seller == auction.seller, Error::<T>::Ownership);
+			ensure!(auction.highest_bid.is_none(), Error::<T>::AuctionHasBids);
+
+			Auctions::<T>::remove(avatar_id);
+			Self::deposit_event(Event::AuctionCancelled { avatar_id });
+			Ok(())
+		}
 
-			let current_block = <frame_system::Pallet<T>>::block_number();
+		/// Offer to buy an avatar, replacing any previous offer of the buyer for it.
+		///
+		/// The offer and its trade fee, fixed at the time of the offer, are reserved until the
+		/// offer is withdrawn or accepted, or the avatar is burned. A fee paid in the fee asset of
+		/// the buyer is held by the treasury instead.
+		///
+		/// Only allowed while trade period is open.
+		///
+		/// Emits `OfferMade` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::make_offer())]
+		pub fn make_offer(
+			origin: OriginFor<T>,
+			avatar_id: AvatarIdOf<T>,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let buyer = Self::ensure_signed_and_migrated(origin)?;
+			let GlobalConfig { trade, .. } = Self::global_configs();
+			ensure!(trade.open, Error::<T>::TradeClosed);
+			ensure!(!amount.is_zero(), Error::<T>::InvalidOffer);
+			let (owner, _) = Self::avatars(avatar_id).ok_or(Error::<T>::UnknownAvatar)?;
+			ensure!(buyer != owner, Error::<T>::AlreadyOwned);
+
+			match Self::offers(avatar_id, &buyer) {
+				Some(previous_offer) => {
+					let Offer { fee, fee_asset, .. } = previous_offer;
+					Self::release_funds(&buyer, previous_offer.reserved(), fee, fee_asset)?;
+				},
+				None => ensure!(
+					(Offers::<T>::iter_prefix(avatar_id).count() as u32) <
+						T::MaxOffersPerAvatar::get(),
+					Error::<T>::TooManyOffers
+				),
+			}
+			let (fee, fee_asset) = Self::hold_fee(&buyer, Self::trade_fee(&trade, amount))?;
+			let offer = Offer { amount, fee, fee_asset };
+			T::Currency::reserve(&buyer, offer.reserved())?;
+
+			Offers::<T>::insert(avatar_id, &buyer, offer);
+			Self::deposit_event(Event::OfferMade { avatar_id, buyer, amount });
+			Ok(())
+		}
+
+		/// Withdraw an offer, releasing its reserved balance.
+		///
+		/// Emits `OfferWithdrawn` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::withdraw_offer())]
+		pub fn withdraw_offer(origin: OriginFor<T>, avatar_id: AvatarIdOf<T>) -> DispatchResult {
+			let buyer = Self::ensure_signed_and_migrated(origin)?;
+			let offer = Offers::<T>::take(avatar_id, &buyer).ok_or(Error::<T>::UnknownOffer)?;
+			Self::release_funds(&buyer, offer.reserved(), offer.fee, offer.fee_asset)?;
+			Self::deposit_event(Event::OfferWithdrawn { avatar_id, buyer });
+			Ok(())
+		}
+
+		/// Accept the offer of a buyer, selling the avatar to it.
+		///
+		/// Any fixed price listing of the avatar is removed.
+		///
+		/// Only allowed while trade period is open.
+		///
+		/// Emits `OfferAccepted` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::accept_offer())]
+		pub fn accept_offer(
+			origin: OriginFor<T>,
+			avatar_id: AvatarIdOf<T>,
+			buyer: T::AccountId,
+		) -> DispatchResult {
+			let seller = Self::ensure_signed_and_migrated(origin)?;
+			ensure!(Self::global_configs().trade.open, Error::<T>::TradeClosed);
+			Self::ensure_ownership(&seller, &avatar_id)?;
+			ensure!(!Auctions::<T>::contains_key(avatar_id), Error::<T>::AvatarInTrade);
+			Self::ensure_not_in_rental(&avatar_id)?;
+			Self::ensure_unlocked(&avatar_id)?;
+			Self::ensure_unprepared(&avatar_id)?;
+
+			let Offer { amount, fee, fee_asset } =
+				Offers::<T>::take(avatar_id, &buyer).ok_or(Error::<T>::UnknownOffer)?;
+			Self::do_sell_avatar(&seller, &buyer, &avatar_id, amount, fee, fee_asset)?;
+
+			Self::deposit_event(Event::OfferAccepted {
+				avatar_id,
+				from: seller,
+				to: buyer,
+				amount,
+			});
+			Ok(())
+		}
+
+		/// Offer to lend an avatar out for `duration` blocks at `price`.
+		///
+		/// Unlocked avatars are lent out to be forged against, while locked ones are lent out to
+		/// be staked as NFTs, in which case the holder of the NFT lends it out.
+		///
+		/// Only allowed while trade period is open.
+		///
+		/// Emits `AvatarListedForRent` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::list_for_rent())]
+		pub fn list_for_rent(
+			origin: OriginFor<T>,
+			avatar_id: AvatarIdOf<T>,
+			#[pallet::compact] price: BalanceOf<T>,
+			duration: T::BlockNumber,
+		) -> DispatchResult {
+			let owner = Self::ensure_signed_and_migrated(origin)?;
+			ensure!(Self::global_configs().trade.open, Error::<T>::TradeClosed);
+			ensure!(Self::lender_of(&avatar_id)? == owner, Error::<T>::Ownership);
+			Self::ensure_not_in_trade(&avatar_id)?;
+			Self::ensure_unprepared(&avatar_id)?;
+			ensure!(!duration.is_zero(), Error::<T>::InvalidRentalDuration);
+
+			RentalListings::<T>::insert(avatar_id, RentalListing { price, duration });
+			Self::deposit_event(Event::AvatarListedForRent { avatar_id, price, duration });
+			Ok(())
+		}
+
+		/// Withdraw an avatar from rent.
+		///
+		/// Emits `AvatarUnlistedFromRent` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::unlist_from_rent())]
+		pub fn unlist_from_rent(origin: OriginFor<T>, avatar_id: AvatarIdOf<T>) -> DispatchResult {
+			let owner = Self::ensure_signed_and_migrated(origin)?;
+			ensure!(Self::lender_of(&avatar_id)? == owner, Error::<T>::Ownership);
+			ensure!(
+				RentalListings::<T>::take(avatar_id).is_some(),
+				Error::<T>::UnknownRentalListing
+			);
+			Self::deposit_event(Event::AvatarUnlistedFromRent { avatar_id });
+			Ok(())
+		}
+
+		/// Borrow an avatar listed for rent, paying its price to the owner.
+		///
+		/// Until the rental expires, the renter can use the avatar as a forge sacrifice without
+		/// consuming it, while the owner can't trade, transfer, lock or forge it.
+		///
+		/// Rented locked avatars can instead be staked by the renter in contracts finishing before
+		/// the rental expires, while their owner can neither stake, unlock nor upload them. Staked
+		/// avatars return to their owner once the contract ends.
+		///
+		/// Only allowed while trade period is open.
+		///
+		/// Emits `AvatarRented` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::rent())]
+		pub fn rent(origin: OriginFor<T>, avatar_id: AvatarIdOf<T>) -> DispatchResult {
+			let renter = Self::ensure_signed_and_migrated(origin)?;
+			let GlobalConfig { trade, .. } = Self::global_configs();
+			ensure!(trade.open, Error::<T>::TradeClosed);
+
+			let RentalListing { price, duration } =
+				Self::rental_listings(avatar_id).ok_or(Error::<T>::UnknownRentalListing)?;
+			let (_, avatar) = Self::avatars(avatar_id).ok_or(Error::<T>::UnknownAvatar)?;
+			let owner = Self::lender_of(&avatar_id)?;
+			ensure!(renter != owner, Error::<T>::AlreadyOwned);
+
+			T::Currency::transfer(&renter, &owner, price, AllowDeath)?;
+			Self::pay_fee(&renter, Self::trade_fee(&trade, price), &avatar.season_id)?;
+
+			let expiry = <frame_system::Pallet<T>>::block_number().saturating_add(duration);
+			RentalExpiries::<T>::try_append(expiry, avatar_id)
+				.map_err(|_| Error::<T>::TooManyRentals)?;
+			RentalListings::<T>::remove(avatar_id);
+			Rentals::<T>::insert(avatar_id, Rental { renter: renter.clone(), expiry });
+
+			Self::deposit_event(Event::AvatarRented { avatar_id, owner, renter, expiry });
+			Ok(())
+		}
+
+		/// Set the shares of a season's treasury awarded to the players on its leaderboards once
+		/// the season finishes.
+		///
+		/// It can only be called by `SeasonOrigin`.
+		///
+		/// Emits `SeasonRewardsSet` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::set_season_rewards())]
+		pub fn set_season_rewards(
+			origin: OriginFor<T>,
+			season_id: SeasonId,
+			rewards: SeasonRewards,
+		) -> DispatchResult {
+			Self::ensure_admin::<T::SeasonOrigin>(origin)?;
+			ensure!(season_id >= Self::current_season_id(), Error::<T>::SeasonAlreadyFinished);
+			rewards.validate::<T>()?;
+			SeasonRewardConfigs::<T>::insert(season_id, &rewards);
+			Self::deposit_event(Event::SeasonRewardsSet { season_id, rewards });
+			Ok(())
+		}
+
+		/// Claim the reward awarded to a player on the leaderboards of a finished season.
+		///
+		/// Emits `SeasonRewardClaimed` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::claim_season_reward())]
+		pub fn claim_season_reward(origin: OriginFor<T>, season_id: SeasonId) -> DispatchResult {
+			let player = Self::ensure_signed_and_migrated(origin)?;
+			Self::ensure_season_ended(season_id)?;
+
+			let amount = UnclaimedRewards::<T>::take(season_id, &player);
+			ensure!(!amount.is_zero(), Error::<T>::CannotClaimZero);
+
+			T::Currency::transfer(&Self::treasury_account_id(), &player, amount, AllowDeath)?;
+			Self::deposit_event(Event::SeasonRewardClaimed { season_id, player, amount });
+			Ok(())
+		}
+
+		/// Accept an asset for the payment of fees at the given conversion rate, or stop accepting
+		/// it when no rate is given.
+		///
+		/// It can only be called by `ConfigOrigin`.
+		///
+		/// Emits `PaymentAssetSet` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::set_payment_asset())]
+		pub fn set_payment_asset(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+			rate: Option<ConversionRateOf<T>>,
+		) -> DispatchResult {
+			Self::ensure_admin::<T::ConfigOrigin>(origin)?;
+			match rate {
+				Some(rate) => {
+					ensure!(T::AssetPayment::is_available(), Error::<T>::AssetPaymentUnavailable);
+					ensure!(rate.is_valid(), Error::<T>::InvalidConversionRate);
+					PaymentAssets::<T>::insert(asset_id, rate);
+				},
+				None => PaymentAssets::<T>::remove(asset_id),
+			}
+			Self::deposit_event(Event::PaymentAssetSet { asset_id, rate });
+			Ok(())
+		}
+
+		/// Choose an accepted asset to pay mint, trade and storage upgrade fees in, or go back to
+		/// paying them in the native currency when no asset is given.
+		///
+		/// Emits `FeeAssetSet` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::set_fee_asset())]
+		pub fn set_fee_asset(
+			origin: OriginFor<T>,
+			asset_id: Option<AssetIdOf<T>>,
+		) -> DispatchResult {
+			let player = Self::ensure_signed_and_migrated(origin)?;
+			match asset_id {
+				Some(asset_id) => {
+					ensure!(T::AssetPayment::is_available(), Error::<T>::AssetPaymentUnavailable);
+					ensure!(
+						PaymentAssets::<T>::contains_key(asset_id),
+						Error::<T>::UnsupportedPaymentAsset
+					);
+					FeeAssets::<T>::insert(&player, asset_id);
+				},
+				None => FeeAssets::<T>::remove(&player),
+			}
+			Self::deposit_event(Event::FeeAssetSet { player, asset_id });
+			Ok(())
+		}
+
+		/// Claim the fees a season collected in an asset.
+		///
+		/// It can only be called by the treasurer of the season once it has finished.
+		///
+		/// Emits `AssetTreasuryClaimed` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(36)]
+		#[pallet::weight(T::WeightInfo::claim_asset_treasury())]
+		pub fn claim_asset_treasury(
+			origin: OriginFor<T>,
+			season_id: SeasonId,
+			asset_id: AssetIdOf<T>,
+		) -> DispatchResult {
+			let maybe_treasurer = Self::ensure_signed_and_migrated(origin)?;
+			let treasurer = Self::treasurer(season_id).ok_or(Error::<T>::UnknownTreasurer)?;
+			ensure!(maybe_treasurer == treasurer, DispatchError::BadOrigin);
+			Self::ensure_season_ended(season_id)?;
+
+			let amount = AssetTreasury::<T>::take(season_id, asset_id);
+			ensure!(!amount.is_zero(), Error::<T>::CannotClaimZero);
+
+			T::AssetPayment::transfer(asset_id, &Self::treasury_account_id(), &treasurer, amount)?;
+			Self::deposit_event(Event::AssetTreasuryClaimed {
+				season_id,
+				asset_id,
+				treasurer,
+				amount,
+			});
+			Ok(())
+		}
+
+		/// Forge several leaders at once.
+		///
+		/// Each forge of the batch consists of a leader and its sacrifices. All forges are
+		/// validated before any of them is executed, and an avatar can only be used once
+		/// throughout the batch. Each forge is rolled independently, as in `forge`.
+		///
+		/// Emits `AvatarForged` event for each forge when successful.
+		///
+		/// Weight: `O(n + s + o)` where:
+		/// - `n = number of forges`
+		/// - `s = number of sacrifices beyond the first of each forge`
+		/// - `o = sacrifices * max offers per avatar`
+		#[pallet::call_index(37)]
+		#[pallet::weight({
+			let forge_count = forges.len() as u32;
+			let sacrifice_count = forges
+				.iter()
+				.fold(0_u32, |count, (_, sacrifices)| count.saturating_add(sacrifices.len() as u32));
+			T::WeightInfo::forge_batch(
+				forge_count,
+				sacrifice_count.saturating_sub(forge_count),
+				sacrifice_count.saturating_mul(T::MaxOffersPerAvatar::get()),
+			)
+		})]
+		pub fn forge_batch(
+			origin: OriginFor<T>,
+			forges: BoundedVec<(AvatarIdOf<T>, Vec<AvatarIdOf<T>>), MaxForgesPerBatch>,
+		) -> DispatchResult {
+			let player = Self::ensure_signed_and_migrated(origin)?;
+			Self::ensure_for_forge_batch(&player, &forges)?;
+			forges
+				.iter()
+				.try_for_each(|(leader, sacrifices)| Self::do_forge(&player, leader, sacrifices))
+		}
+
+		/// Set the carry-over rules of a season.
+		///
+		/// The rules define which earlier seasons' avatars can be sacrificed when forging in the
+		/// season, and which of them can ascend into it for a fee.
+		///
+		/// It can only be called by `SeasonOrigin`.
+		///
+		/// Emits `CarryOverSet` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(38)]
+		#[pallet::weight(T::WeightInfo::set_carry_over())]
+		pub fn set_carry_over(
+			origin: OriginFor<T>,
+			season_id: SeasonId,
+			carry_over: CarryOverOf<T>,
+		) -> DispatchResult {
+			Self::ensure_admin::<T::SeasonOrigin>(origin)?;
+			carry_over.validate::<T>(&season_id)?;
+			CarryOvers::<T>::insert(season_id, &carry_over);
+			Self::deposit_event(Event::CarryOverSet { season_id, carry_over });
+			Ok(())
+		}
+
+		/// Ascend an avatar of an earlier season into the current season.
+		///
+		/// The DNA of the avatar is re-rolled with the tiers and variations of the current
+		/// season, while its souls are kept. The current season must take in avatars of the
+		/// avatar's season and the ascension fee of the season is charged.
+		///
+		/// Emits `AvatarAscended` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::ascend_avatar())]
+		pub fn ascend_avatar(origin: OriginFor<T>, avatar_id: AvatarIdOf<T>) -> DispatchResult {
+			let player = Self::ensure_signed_and_migrated(origin)?;
+			ensure!(Self::current_season_status().is_in_season(), Error::<T>::SeasonClosed);
+			let season_id = Self::current_season_id();
+			let season = Self::seasons(season_id).ok_or(Error::<T>::UnknownSeason)?;
+
+			let mut avatar = Self::ensure_ownership(&player, &avatar_id)?;
+			Self::ensure_not_in_trade(&avatar_id)?;
+			Self::ensure_unlocked(&avatar_id)?;
+			Self::ensure_unprepared(&avatar_id)?;
+
+			let from_season_id = avatar.season_id;
+			let carry_over = Self::carry_overs(season_id);
+			ensure!(
+				carry_over.ascension_seasons.contains(&from_season_id),
+				Error::<T>::AscensionNotAllowed
+			);
+			Self::pay_fee(&player, carry_over.ascension_fee, &season_id)?;
+
+			let random_hash = T::Randomness::random_hash(b"ascending avatar", &player);
+			avatar.dna = Self::random_dna(&random_hash, &season, false)?;
+			avatar.season_id = season_id;
+			Avatars::<T>::insert(avatar_id, (&player, avatar));
+
+			Self::deposit_event(Event::AvatarAscended { avatar_id, from_season_id, season_id });
+			Ok(())
+		}
+
+		/// Set the soul prices of a season.
+		///
+		/// It can only be called by `SeasonOrigin`.
+		///
+		/// Emits `SoulExchangeSet` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::set_soul_exchange())]
+		pub fn set_soul_exchange(
+			origin: OriginFor<T>,
+			season_id: SeasonId,
+			exchange: SoulExchange,
+		) -> DispatchResult {
+			Self::ensure_admin::<T::SeasonOrigin>(origin)?;
+			exchange.validate::<T>()?;
+			SoulExchanges::<T>::insert(season_id, &exchange);
+			Self::deposit_event(Event::SoulExchangeSet { season_id, exchange });
+			Ok(())
+		}
+
+		/// Burn an avatar, adding its souls to the souls of the player.
+		///
+		/// Emits `SoulsExtracted` event when successful.
+		///
+		/// Weight: `O(o)` where:
+		/// - `o = max offers per avatar`
+		#[pallet::call_index(41)]
+		#[pallet::weight(T::WeightInfo::extract_souls(T::MaxOffersPerAvatar::get()))]
+		pub fn extract_souls(origin: OriginFor<T>, avatar_id: AvatarIdOf<T>) -> DispatchResult {
+			let player = Self::ensure_signed_and_migrated(origin)?;
+			let Avatar { souls, .. } = Self::ensure_ownership(&player, &avatar_id)?;
+			Self::ensure_not_in_trade(&avatar_id)?;
+			Self::ensure_unlocked(&avatar_id)?;
+			Self::ensure_unprepared(&avatar_id)?;
+
+			Souls::<T>::try_mutate(&player, |balance| -> DispatchResult {
+				*balance = balance.checked_add(souls).ok_or(ArithmeticError::Overflow)?;
+				Ok(())
+			})?;
+			Avatars::<T>::remove(avatar_id);
+			Self::remove_ownership(&player, &avatar_id);
+			Self::clear_offers(&avatar_id)?;
+
+			Self::deposit_event(Event::SoulsExtracted { avatar_id, player, souls });
+			Ok(())
+		}
+
+		/// Spend souls to reroll a single DNA component of an avatar of the current season.
+		///
+		/// Only the variation of the component is rolled, its tier is kept.
+		///
+		/// Emits `ComponentRerolled` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(42)]
+		#[pallet::weight(T::WeightInfo::reroll_component())]
+		pub fn reroll_component(
+			origin: OriginFor<T>,
+			avatar_id: AvatarIdOf<T>,
+			component: u8,
+		) -> DispatchResult {
+			let player = Self::ensure_signed_and_migrated(origin)?;
+			let (season_id, season) = Self::current_season_with_id()?;
+			let mut avatar = Self::ensure_ownership(&player, &avatar_id)?;
+			ensure!(avatar.season_id == season_id, Error::<T>::IncorrectAvatarSeason);
+			ensure!((component as usize) < avatar.dna.len(), Error::<T>::UnknownComponent);
+			Self::ensure_not_in_trade(&avatar_id)?;
+			Self::ensure_unlocked(&avatar_id)?;
+			Self::ensure_unprepared(&avatar_id)?;
+
+			let souls = Self::soul_exchanges(season_id)
+				.reroll_price
+				.ok_or(Error::<T>::UnavailableForSouls)?;
+			Self::spend_souls(&player, souls)?;
+
+			let random_hash = T::Randomness::random_hash(b"rerolling component", &player);
+			let variation = Self::random_below(&random_hash, 0, season.max_variations);
+			let nucleotide = &mut avatar.dna[component as usize];
+			*nucleotide = (*nucleotide & 0b1111_0000) | variation;
+			Avatars::<T>::insert(avatar_id, (&player, avatar));
+
+			Self::deposit_event(Event::ComponentRerolled { avatar_id, component, souls });
+			Ok(())
+		}
+
+		/// Spend souls on free mints.
+		///
+		/// Emits `FreeMintsBought` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(43)]
+		#[pallet::weight(T::WeightInfo::buy_free_mints())]
+		pub fn buy_free_mints(origin: OriginFor<T>, how_many: MintCount) -> DispatchResult {
+			let player = Self::ensure_signed_and_migrated(origin)?;
+			let (season_id, _) = Self::current_season_with_id()?;
+			let price = Self::soul_exchanges(season_id)
+				.free_mint_price
+				.ok_or(Error::<T>::UnavailableForSouls)?;
+			let souls =
+				price.checked_mul(how_many as SoulCount).ok_or(ArithmeticError::Overflow)?;
+			Self::spend_souls(&player, souls)?;
+
+			Accounts::<T>::try_mutate(&player, |account| -> DispatchResult {
+				account.free_mints =
+					account.free_mints.checked_add(how_many).ok_or(ArithmeticError::Overflow)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::FreeMintsBought { player, how_many, souls });
+			Ok(())
+		}
+
+		/// Spend souls to raise the probability of the next forge.
+		///
+		/// Boosts bought before the next forge add up, to at most 100 percentage points.
+		///
+		/// Emits `ForgeBoostBought` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(44)]
+		#[pallet::weight(T::WeightInfo::buy_forge_boost())]
+		pub fn buy_forge_boost(origin: OriginFor<T>) -> DispatchResult {
+			let player = Self::ensure_signed_and_migrated(origin)?;
+			let (season_id, _) = Self::current_season_with_id()?;
+			let SoulExchange { forge_boost_price, forge_boost: boost, .. } =
+				Self::soul_exchanges(season_id);
+			let souls = forge_boost_price.ok_or(Error::<T>::UnavailableForSouls)?;
+			Self::spend_souls(&player, souls)?;
+
+			ForgeBoosts::<T>::mutate(&player, |total| {
+				*total = total.saturating_add(boost).min(MAX_PERCENTAGE)
+			});
+
+			Self::deposit_event(Event::ForgeBoostBought { player, boost, souls });
+			Ok(())
+		}
+
+		/// Update only some fields of the global configuration.
+		///
+		/// It can only be called by `ConfigOrigin`.
+		///
+		/// Emits `UpdatedGlobalConfig` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(45)]
+		#[pallet::weight(T::WeightInfo::patch_global_config())]
+		pub fn patch_global_config(
+			origin: OriginFor<T>,
+			patch: GlobalConfigPatchOf<T>,
+		) -> DispatchResult {
+			Self::ensure_admin::<T::ConfigOrigin>(origin)?;
+			Self::ensure_fees(patch.fees())?;
+			let config = GlobalConfigs::<T>::mutate(|config| {
+				patch.apply(config);
+				config.clone()
+			});
+			Self::deposit_event(Event::UpdatedGlobalConfig(config));
+			Ok(())
+		}
+
+		/// Schedule a patch of the global configuration to take effect at block `at`.
+		///
+		/// Patches scheduled for the same block are applied in the order they were scheduled.
+		///
+		/// It can only be called by `ConfigOrigin`.
+		///
+		/// Emits `ConfigPatchScheduled` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(46)]
+		#[pallet::weight(T::WeightInfo::schedule_config_patch())]
+		pub fn schedule_config_patch(
+			origin: OriginFor<T>,
+			at: T::BlockNumber,
+			patch: GlobalConfigPatchOf<T>,
+		) -> DispatchResult {
+			Self::ensure_admin::<T::ConfigOrigin>(origin)?;
+			ensure!(
+				at > <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::ConfigPatchInThePast
+			);
+			Self::ensure_fees(patch.fees())?;
+
+			let patch_id = NextConfigPatchId::<T>::get();
+			ScheduledConfigPatches::<T>::try_append(at, (patch_id, patch.clone()))
+				.map_err(|_| Error::<T>::TooManyConfigPatches)?;
+			NextConfigPatchId::<T>::put(patch_id.wrapping_add(1));
+
+			Self::deposit_event(Event::ConfigPatchScheduled { patch_id, at, patch });
+			Ok(())
+		}
+
+		/// Cancel a config patch scheduled for block `at`.
+		///
+		/// It can only be called by `ConfigOrigin`.
+		///
+		/// Emits `ConfigPatchCancelled` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(47)]
+		#[pallet::weight(T::WeightInfo::cancel_config_patch())]
+		pub fn cancel_config_patch(
+			origin: OriginFor<T>,
+			at: T::BlockNumber,
+			patch_id: ConfigPatchId,
+		) -> DispatchResult {
+			Self::ensure_admin::<T::ConfigOrigin>(origin)?;
+			ScheduledConfigPatches::<T>::try_mutate_exists(at, |maybe_patches| {
+				let patches = maybe_patches.as_mut().ok_or(Error::<T>::UnknownConfigPatch)?;
+				let index = patches
+					.iter()
+					.position(|(id, _)| *id == patch_id)
+					.ok_or(Error::<T>::UnknownConfigPatch)?;
+				patches.remove(index);
+				if patches.is_empty() {
+					*maybe_patches = None;
+				}
+				Ok::<_, DispatchError>(())
+			})?;
+
+			Self::deposit_event(Event::ConfigPatchCancelled { patch_id, at });
+			Ok(())
+		}
+
+		/// Give accounts early access to a season.
+		///
+		/// Each account can mint up to `early_mints` avatars normally before the season starts,
+		/// replacing what it had left from earlier additions. Free mints remain available during
+		/// early access regardless of the allowlist.
+		///
+		/// It can only be called by `SeasonOrigin`.
+		///
+		/// Emits `AddedToAllowlist` event when successful.
+		///
+		/// Weight: `O(n)` where:
+		/// - `n = accounts.len()`
+		#[pallet::call_index(48)]
+		#[pallet::weight(T::WeightInfo::add_to_allowlist(accounts.len() as u32))]
+		pub fn add_to_allowlist(
+			origin: OriginFor<T>,
+			season_id: SeasonId,
+			accounts: AllowlistBatchOf<T>,
+			early_mints: MintCount,
+		) -> DispatchResult {
+			Self::ensure_admin::<T::SeasonOrigin>(origin)?;
+			for account in accounts.iter() {
+				Allowlists::<T>::insert(season_id, account, early_mints);
+			}
+			let accounts = accounts.into_inner();
+			Self::deposit_event(Event::AddedToAllowlist { season_id, accounts, early_mints });
+			Ok(())
+		}
+
+		/// Take early access to a season away from accounts.
+		///
+		/// It can only be called by `SeasonOrigin`.
+		///
+		/// Emits `RemovedFromAllowlist` event when successful.
+		///
+		/// Weight: `O(n)` where:
+		/// - `n = accounts.len()`
+		#[pallet::call_index(49)]
+		#[pallet::weight(T::WeightInfo::remove_from_allowlist(accounts.len() as u32))]
+		pub fn remove_from_allowlist(
+			origin: OriginFor<T>,
+			season_id: SeasonId,
+			accounts: AllowlistBatchOf<T>,
+		) -> DispatchResult {
+			Self::ensure_admin::<T::SeasonOrigin>(origin)?;
+			for account in accounts.iter() {
+				Allowlists::<T>::remove(season_id, account);
+			}
+			let accounts = accounts.into_inner();
+			Self::deposit_event(Event::RemovedFromAllowlist { season_id, accounts });
+			Ok(())
+		}
+
+		/// Set the number of free mints that can be airdropped during a season.
+		///
+		/// It can only be called by `FreeMintOrigin`.
+		///
+		/// Emits `AirdropBudgetSet` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(50)]
+		#[pallet::weight(T::WeightInfo::set_airdrop_budget())]
+		pub fn set_airdrop_budget(
+			origin: OriginFor<T>,
+			season_id: SeasonId,
+			budget: u32,
+		) -> DispatchResult {
+			Self::ensure_admin::<T::FreeMintOrigin>(origin)?;
+			AirdropBudgets::<T>::insert(season_id, budget);
+			Self::deposit_event(Event::AirdropBudgetSet { season_id, budget });
+			Ok(())
+		}
+
+		/// Airdrop free mints to several accounts at once.
+		///
+		/// The free mints each account gains are deducted from the airdrop budget of the current
+		/// season, so overwriting free mints with fewer than an account has costs nothing.
+		///
+		/// It can only be called by `FreeMintOrigin`.
+		///
+		/// Emits `FreeMintsAirdropped` event when successful.
+		///
+		/// Weight: `O(n)` where:
+		/// - `n = airdrops.len()`
+		#[pallet::call_index(51)]
+		#[pallet::weight(T::WeightInfo::airdrop_free_mints(airdrops.len() as u32))]
+		pub fn airdrop_free_mints(
+			origin: OriginFor<T>,
+			airdrops: AirdropBatchOf<T>,
+			mode: AirdropMode,
+		) -> DispatchResult {
+			Self::ensure_admin::<T::FreeMintOrigin>(origin)?;
+			let season_id = Self::current_season_id();
+			let recipients = airdrops.len() as u32;
+
+			let mut spent: u32 = 0;
+			for (account, how_many) in airdrops {
+				Accounts::<T>::mutate(&account, |AccountInfo { free_mints, .. }| {
+					let new_free_mints = match mode {
+						AirdropMode::Add => free_mints.saturating_add(how_many),
+						AirdropMode::Overwrite => how_many,
+					};
+					spent.saturating_accrue(new_free_mints.saturating_sub(*free_mints) as u32);
+					*free_mints = new_free_mints;
+				});
+			}
+			AirdropBudgets::<T>::try_mutate(season_id, |budget| {
+				*budget = budget.checked_sub(spent).ok_or(Error::<T>::AirdropBudgetExceeded)?;
+				Ok::<_, DispatchError>(())
+			})?;
+
+			Self::deposit_event(Event::FreeMintsAirdropped { season_id, recipients, spent, mode });
+			Ok(())
+		}
+
+		/// Commit the service account to the chain of values it reveals to seed randomness.
+		///
+		/// `commitment` is the last hash of a chain `hash(hash(..hash(secret)))` whose values the
+		/// service account reveals in reverse order through `reveal_randomness`. Committing again
+		/// discards the rest of the previous chain.
+		///
+		/// It can only be called by `ConfigOrigin`.
+		///
+		/// Emits `RandomnessCommitted` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(52)]
+		#[pallet::weight(T::WeightInfo::commit_randomness())]
+		pub fn commit_randomness(origin: OriginFor<T>, commitment: T::Hash) -> DispatchResult {
+			Self::ensure_admin::<T::ConfigOrigin>(origin)?;
+			RandomnessCommitment::<T>::put(commitment);
+			Self::deposit_event(Event::RandomnessCommitted { commitment });
+			Ok(())
+		}
+
+		/// Reveal the value hashing to the current commitment, which then becomes the commitment
+		/// for the next reveal.
+		///
+		/// It can only be called by the service account.
+		///
+		/// Emits `RandomnessRevealed` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(53)]
+		#[pallet::weight(T::WeightInfo::reveal_randomness())]
+		pub fn reveal_randomness(origin: OriginFor<T>, value: T::Hash) -> DispatchResult {
+			let _ = Self::ensure_service_account(origin)?;
+			let commitment =
+				Self::randomness_commitment().ok_or(Error::<T>::NoRandomnessCommitment)?;
+			ensure!(
+				T::Hashing::hash(value.as_ref()) == commitment,
+				Error::<T>::InvalidRandomnessReveal
+			);
+
+			let (seed, _) = Self::revealed_randomness().unwrap_or_default();
+			let seed = (seed, value).using_encoded(T::Hashing::hash);
+			RevealedRandomness::<T>::put((seed, <frame_system::Pallet<T>>::block_number()));
+			RandomnessCommitment::<T>::put(value);
+
+			Self::deposit_event(Event::RandomnessRevealed { value });
+			Ok(())
+		}
+
+		/// Upload a locked avatar outside of the chain.
+		///
+		/// The NFT of the avatar is queued by the NFT transfer pallet, which hands it over to the
+		/// offchain worker once processed. The NFT stays locked until its return is confirmed.
+		///
+		/// Emits `AvatarUploaded` event once the offchain worker has pinned the avatar.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(54)]
+		#[pallet::weight(T::WeightInfo::upload_avatar())]
+		pub fn upload_avatar(origin: OriginFor<T>, avatar_id: AvatarIdOf<T>) -> DispatchResult {
+			let player = Self::ensure_signed_and_migrated(origin)?;
+			ensure!(Self::global_configs().nft_transfer.open, Error::<T>::NftTransferClosed);
+			ensure!(LockedAvatars::<T>::contains_key(avatar_id), Error::<T>::AvatarUnlocked);
+			Self::ensure_not_in_rental(&avatar_id)?;
+
+			let collection_id = Self::collection_id().ok_or(Error::<T>::CollectionIdNotSet)?;
+			T::NftHandler::schedule_upload(player, collection_id, avatar_id)
+		}
+
+		/// Confirm the upload of an avatar, pinned under the given IPFS URL.
+		///
+		/// It can only be called by the service account.
+		///
+		/// Emits `AvatarUploaded` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(55)]
+		#[pallet::weight(T::WeightInfo::confirm_upload())]
+		pub fn confirm_upload(
+			origin: OriginFor<T>,
+			avatar_id: AvatarIdOf<T>,
+			url: IpfsUrl,
+		) -> DispatchResult {
+			let _ = Self::ensure_service_account(origin)?;
+			ensure!(!url.is_empty(), Error::<T>::EmptyIpfsUrl);
+			ensure!(PendingUploads::<T>::contains_key(avatar_id), Error::<T>::UnknownUpload);
+
+			PendingUploads::<T>::remove(avatar_id);
+			Self::deposit_event(Event::AvatarUploaded { avatar_id, url });
+			Ok(())
+		}
+	}
+
+	/// Uploads avatars by handing them over to the offchain worker, which pins them to IPFS and
+	/// confirms the upload through the service account.
+	impl<T: Config> NftUploader<T::AccountId, CollectionIdOf<T>, AvatarIdOf<T>> for Pallet<T> {
+		fn upload(
+			owner: &T::AccountId,
+			collection_id: &CollectionIdOf<T>,
+			avatar_id: &AvatarIdOf<T>,
+		) -> DispatchResult {
+			ensure!(Self::is_avatar_collection(collection_id), Error::<T>::UnknownCollection);
+			ensure!(LockedAvatars::<T>::contains_key(avatar_id), Error::<T>::AvatarUnlocked);
+			PendingUploads::<T>::insert(avatar_id, owner);
+			Ok(())
+		}
+	}
+
+	/// Lets renters stake the locked avatars they rent, as long as their rental lasts.
+	impl<T: Config> NftDelegation<T::AccountId, CollectionIdOf<T>, AvatarIdOf<T>, T::BlockNumber>
+		for Pallet<T>
+	{
+		fn delegated_until(
+			who: &T::AccountId,
+			collection_id: &CollectionIdOf<T>,
+			avatar_id: &AvatarIdOf<T>,
+		) -> Option<T::BlockNumber> {
+			Self::is_avatar_collection(collection_id)
+				.then(|| Self::rentals(avatar_id))
+				.flatten()
+				.filter(|rental| rental.renter == *who)
+				.map(|rental| rental.expiry)
+		}
+
+		fn is_lent(collection_id: &CollectionIdOf<T>, avatar_id: &AvatarIdOf<T>) -> bool {
+			Self::is_avatar_collection(collection_id) &&
+				Self::ensure_not_in_rental(avatar_id).is_err()
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The account ID of the treasury.
+		pub fn treasury_account_id() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		/// The account ID of the treasury.
+		pub fn technical_account_id() -> T::AccountId {
+			T::PalletId::get().into_sub_account_truncating(b"technical")
+		}
+
+		/// Whether the given collection is the one avatars are stored in as NFTs.
+		///
+		/// Items of this collection are owned through this pallet, so runtimes should only let
+		/// them change hands through it or through pallets handing them back, e.g. when staked.
+		pub fn is_avatar_collection(collection_id: &CollectionIdOf<T>) -> bool {
+			Self::collection_id().as_ref() == Some(collection_id)
+		}
+
+		/// The fee charged to the buyer of an avatar sold for `price`.
+		pub(crate) fn trade_fee(
+			trade: &TradeConfig<BalanceOf<T>>,
+			price: BalanceOf<T>,
+		) -> BalanceOf<T> {
+			trade.min_fee.max(
+				price.saturating_mul(trade.percent_fee.unique_saturated_into()) /
+					MAX_PERCENTAGE.unique_saturated_into(),
+			)
+		}
+
+		/// Hands an avatar over to a buyer whose funds have been reserved, paying the seller and
+		/// charging the trade fee fixed along with the reservation into the treasury.
+		///
+		/// A fee paid in an asset is already held by the treasury, so it only needs to be
+		/// accounted to the season.
+		fn do_sell_avatar(
+			seller: &T::AccountId,
+			buyer: &T::AccountId,
+			avatar_id: &AvatarIdOf<T>,
+			price: BalanceOf<T>,
+			fee: BalanceOf<T>,
+			fee_asset: Option<AssetIdOf<T>>,
+		) -> DispatchResult {
+			let avatar = Self::ensure_ownership(seller, avatar_id)?;
+			match fee_asset {
+				Some(asset_id) => {
+					T::Currency::unreserve(buyer, price);
+					AssetTreasury::<T>::mutate(avatar.season_id, asset_id, |bal| {
+						bal.saturating_accrue(fee)
+					});
+				},
+				None => {
+					T::Currency::unreserve(buyer, price.saturating_add(fee));
+					T::Currency::withdraw(buyer, fee, WithdrawReasons::FEE, AllowDeath)?;
+					Self::deposit_into_treasury(&avatar.season_id, fee);
+				},
+			}
+			T::Currency::transfer(buyer, seller, price, AllowDeath)?;
+
+			Self::do_transfer_avatar(seller, buyer, avatar_id)?;
+			Trade::<T>::remove(avatar_id);
+
+			Accounts::<T>::mutate(buyer, |account| account.stats.trade.bought.saturating_inc());
+			Accounts::<T>::mutate(seller, |account| account.stats.trade.sold.saturating_inc());
+			Ok(())
+		}
+
+		pub(crate) fn deposit_into_treasury(season_id: &SeasonId, amount: BalanceOf<T>) {
+			Treasury::<T>::mutate(season_id, |bal| bal.saturating_accrue(amount));
+			T::Currency::deposit_creating(&Self::treasury_account_id(), amount);
+		}
+
+		/// Charges a fee into the treasury of a season, in the asset chosen by the payer if any.
+		pub(crate) fn pay_fee(
+			payer: &T::AccountId,
+			fee: BalanceOf<T>,
+			season_id: &SeasonId,
+		) -> DispatchResult {
+			match Self::fee_assets(payer) {
+				Some(asset_id) => {
+					let rate = Self::payment_assets(asset_id)
+						.ok_or(Error::<T>::UnsupportedPaymentAsset)?;
+					let amount = rate.convert(fee);
+					if !amount.is_zero() {
+						T::AssetPayment::transfer(
+							asset_id,
+							payer,
+							&Self::treasury_account_id(),
+							amount,
+						)?;
+						AssetTreasury::<T>::mutate(season_id, asset_id, |bal| {
+							bal.saturating_accrue(amount)
+						});
+					}
+				},
+				None => {
+					T::Currency::withdraw(payer, fee, WithdrawReasons::FEE, AllowDeath)?;
+					Self::deposit_into_treasury(season_id, fee);
+				},
+			}
+			Ok(())
+		}
+
+		/// Holds the trade fee of a bid or offer in the treasury when the payer pays fees in an
+		/// asset, returning the amount held and the asset. Otherwise the fee is left to be reserved
+		/// in the native currency along with the bid or offer.
+		fn hold_fee(
+			payer: &T::AccountId,
+			fee: BalanceOf<T>,
+		) -> Result<(BalanceOf<T>, Option<AssetIdOf<T>>), DispatchError> {
+			match Self::fee_assets(payer) {
+				Some(asset_id) => {
+					let rate = Self::payment_assets(asset_id)
+						.ok_or(Error::<T>::UnsupportedPaymentAsset)?;
+					let amount = rate.convert(fee);
+					if !amount.is_zero() {
+						T::AssetPayment::transfer(
+							asset_id,
+							payer,
+							&Self::treasury_account_id(),
+							amount,
+						)?;
+					}
+					Ok((amount, Some(asset_id)))
+				},
+				None => Ok((fee, None)),
+			}
+		}
+
+		/// Releases the funds of a bid or offer that won't go through: its reserved balance, along
+		/// with its fee if held in an asset.
+		fn release_funds(
+			payer: &T::AccountId,
+			reserved: BalanceOf<T>,
+			fee: BalanceOf<T>,
+			fee_asset: Option<AssetIdOf<T>>,
+		) -> DispatchResult {
+			T::Currency::unreserve(payer, reserved);
+			match fee_asset {
+				Some(asset_id) if !fee.is_zero() =>
+					T::AssetPayment::transfer(asset_id, &Self::treasury_account_id(), payer, fee),
+				_ => Ok(()),
+			}
+		}
+
+		fn spend_souls(player: &T::AccountId, souls: SoulCount) -> DispatchResult {
+			Souls::<T>::try_mutate(player, |balance| -> DispatchResult {
+				*balance = balance.checked_sub(souls).ok_or(Error::<T>::InsufficientSouls)?;
+				Ok(())
+			})
+		}
+
+		/// Check that the origin is signed and that no migration is in progress, as calls are
+		/// paused until the storage of the pallet is migrated.
+		pub(crate) fn ensure_signed_and_migrated(
+			origin: OriginFor<T>,
+		) -> Result<T::AccountId, DispatchError> {
+			let account = ensure_signed(origin)?;
+			ensure!(!OngoingMigration::<T>::exists(), Error::<T>::MigrationInProgress);
+			Ok(account)
+		}
+
+		/// Check that the origin is an organizer account.
+		pub(crate) fn ensure_organizer(
+			origin: OriginFor<T>,
+		) -> Result<T::AccountId, DispatchError> {
+			let maybe_organizer = Self::ensure_signed_and_migrated(origin)?;
+			let existing_organizer = Self::organizer().ok_or(Error::<T>::OrganizerNotSet)?;
+			ensure!(maybe_organizer == existing_organizer, DispatchError::BadOrigin);
+			Ok(maybe_organizer)
+		}
+
+		/// Check that the origin is allowed by the admin origin `O`, such as `T::SeasonOrigin`.
+		pub(crate) fn ensure_admin<O: EnsureOrigin<OriginFor<T>>>(
+			origin: OriginFor<T>,
+		) -> DispatchResult {
+			O::ensure_origin(origin)?;
+			ensure!(!OngoingMigration::<T>::exists(), Error::<T>::MigrationInProgress);
+			Ok(())
+		}
+
+		pub(crate) fn ensure_service_account(
+			origin: OriginFor<T>,
+		) -> Result<T::AccountId, DispatchError> {
+			let maybe_sa = Self::ensure_signed_and_migrated(origin)?;
+			let existing_sa = Self::service_account().ok_or(Error::<T>::OrganizerNotSet)?;
+			ensure!(maybe_sa == existing_sa, DispatchError::BadOrigin);
+			Ok(maybe_sa)
+		}
+
+		/// Validates a new season.
+		pub(crate) fn ensure_season(
+			season_id: &SeasonId,
+			mut season: SeasonOf<T>,
+		) -> Result<SeasonOf<T>, DispatchError> {
+			season.validate::<T>()?;
+
+			let prev_season_id = season_id.checked_sub(1).ok_or(ArithmeticError::Underflow)?;
+			let next_season_id = season_id.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+
+			if prev_season_id > 0 {
+				let prev_season =
+					Self::seasons(prev_season_id).ok_or(Error::<T>::NonSequentialSeasonId)?;
+				ensure!(prev_season.end < season.early_start, Error::<T>::EarlyStartTooEarly);
+			}
+			if let Some(next_season) = Self::seasons(next_season_id) {
+				ensure!(season.end < next_season.early_start, Error::<T>::SeasonEndTooLate);
+			}
+			Ok(season)
+		}
+
+		/// Draws a uniformly distributed value in `0..bound` from the byte of `hash` at `index`.
+		///
+		/// Bytes beyond the largest multiple of `bound` would favour the lowest values, so they are
+		/// rejected and replaced with the first byte of `hash` rehashed together with `index` and
+		/// the attempt count until one is accepted. Less than half of all bytes are rejected for
+		/// any `bound`, so each draw needs fewer than two hashes on average.
+		pub(crate) fn random_below(hash: &T::Hash, index: usize, bound: u8) -> u8 {
+			let zone = 256_u16 - 256_u16 % bound as u16;
+			let mut byte = hash.as_ref()[index];
+			let mut attempt = 0_u32;
+			while byte as u16 >= zone {
+				byte = (hash, index as u32, attempt).using_encoded(T::Hashing::hash).as_ref()[0];
+				attempt.saturating_inc();
+			}
+			byte % bound
+		}
+
+		#[inline]
+		pub(crate) fn random_component(
+			season: &SeasonOf<T>,
+			hash: &T::Hash,
+			index: usize,
+			batched_mint: bool,
+		) -> (u8, u8) {
+			let random_tier = {
+				let random_prob = Self::random_below(hash, index, MAX_PERCENTAGE);
+				let probs =
+					if batched_mint { &season.batch_mint_probs } else { &season.single_mint_probs };
+				let mut cumulative_sum = 0;
+				let mut random_tier = season.tiers[0].clone() as u8;
+				for i in 0..probs.len() {
+					let new_cumulative_sum = cumulative_sum + probs[i];
+					if random_prob >= cumulative_sum && random_prob < new_cumulative_sum {
+						random_tier = season.tiers[i].clone() as u8;
+						break
+					}
+					cumulative_sum = new_cumulative_sum;
+				}
+				random_tier
+			};
+			let random_variation = Self::random_below(hash, index + 1, season.max_variations);
+			(random_tier, random_variation)
+		}
+
+		fn random_dna(
+			hash: &T::Hash,
+			season: &SeasonOf<T>,
+			batched_mint: bool,
+		) -> Result<Dna, DispatchError> {
+			let dna = (0..season.max_components)
+				.map(|i| {
+					let (random_tier, random_variation) =
+						Self::random_component(season, hash, i as usize * 2, batched_mint);
+					((random_tier << 4) | random_variation) as u8
+				})
+				.collect::<Vec<_>>();
+			Dna::try_from(dna).map_err(|_| Error::<T>::IncorrectDna.into())
+		}
+
+		/// Mint a new avatar.
+		pub(crate) fn do_mint(player: &T::AccountId, mint_option: &MintOption) -> DispatchResult {
+			let GlobalConfig { mint, .. } = Self::global_configs();
+			ensure!(mint.open, Error::<T>::MintClosed);
+			let free_mints = Self::ensure_for_mint(player, &mint_option.mint_type)?;
+
+			let current_block = <frame_system::Pallet<T>>::block_number();
 			let last_block = Self::accounts(player).stats.mint.last;
 			if !last_block.is_zero() {
 				ensure!(current_block >= last_block + mint.cooldown, Error::<T>::MintCooldown);
@@ -1034,27 +2699,29 @@ pub mod pallet {
 			let is_batched = mint_option.count.is_batched();
 			let generated_avatar_ids = (0..mint_option.count as usize)
 				.map(|_| {
-					let avatar_id = Self::random_hash(b"create_avatar", player);
+					let avatar_id = T::Randomness::random_hash(b"create_avatar", player);
 					let dna = Self::random_dna(&avatar_id, &season, is_batched)?;
 					let souls = (dna.iter().map(|x| *x as SoulCount).sum::<SoulCount>() % 100) + 1;
 					let avatar = Avatar { season_id, dna, souls };
 					Avatars::<T>::insert(avatar_id, (&player, avatar));
-					Owners::<T>::try_append(&player, avatar_id)
-						.map_err(|_| Error::<T>::MaxOwnershipReached)?;
+					Self::add_ownership(player, &avatar_id);
 					Ok(avatar_id)
 				})
 				.collect::<Result<Vec<AvatarIdOf<T>>, DispatchError>>()?;
 
-			ensure!(
-				Self::owners(player).len() <= Self::accounts(player).storage_tier as usize,
-				Error::<T>::MaxOwnershipReached
-			);
+			Self::ensure_storage_quota(player, 0)?;
 
 			match mint_option.mint_type {
 				MintType::Normal => {
+					if !Self::current_season_status().active {
+						Self::spend_early_mints(
+							player,
+							&season_id,
+							mint_option.count as MintCount,
+						)?;
+					}
 					let fee = mint.fees.fee_for(&mint_option.count);
-					T::Currency::withdraw(player, fee, WithdrawReasons::FEE, AllowDeath)?;
-					Self::deposit_into_treasury(&season_id, fee);
+					Self::pay_fee(player, fee, &season_id)?;
 				},
 				MintType::Free => {
 					let fee = (mint_option.count as MintCount)
@@ -1087,6 +2754,21 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Deduct mints made before the start of a season from the early access of the player.
+		fn spend_early_mints(
+			player: &T::AccountId,
+			season_id: &SeasonId,
+			count: MintCount,
+		) -> DispatchResult {
+			Allowlists::<T>::try_mutate(season_id, player, |maybe_early_mints| {
+				let early_mints = maybe_early_mints.as_mut().ok_or(Error::<T>::SeasonClosed)?;
+				*early_mints = early_mints
+					.checked_sub(count)
+					.ok_or(Error::<T>::InsufficientEarlyAccessMints)?;
+				Ok(())
+			})
+		}
+
 		/// Enhance an avatar using a batch of avatars.
 		pub(crate) fn do_forge(
 			player: &T::AccountId,
@@ -1097,24 +2779,33 @@ pub mod pallet {
 			ensure!(forge.open, Error::<T>::ForgeClosed);
 
 			let (season_id, season) = Self::current_season_with_id()?;
-			let (mut leader, sacrifice_ids, sacrifices) =
+			let (mut leader, sacrifice_ids, sacrifices, consumed_souls) =
 				Self::ensure_for_forge(player, leader_id, sacrifice_ids, &season_id, &season)?;
 			let prev_leader_tier = leader.min_tier();
 
 			let max_tier = season.tiers.iter().max().ok_or(Error::<T>::UnknownTier)?.clone() as u8;
 			let (mut unique_matched_indexes, matches) =
 				leader.compare_all::<T>(&sacrifices, season.max_variations, max_tier)?;
+			leader.souls.saturating_accrue(consumed_souls);
 
-			let random_hash = Self::random_hash(b"forging avatar", player);
-			let random_hash = random_hash.as_ref();
+			let random_hash = T::Randomness::random_hash(b"forging avatar", player);
 			let mut upgraded_components = 0;
 
+			// the boost is only used up by forges that can upgrade a component
+			let boost = if unique_matched_indexes.is_empty() {
+				Zero::zero()
+			} else {
+				ForgeBoosts::<T>::take(player)
+			};
 			let current_block = <frame_system::Pallet<T>>::block_number();
-			let prob = leader.forge_probability::<T>(&season, &current_block, matches);
+			let prob = leader
+				.forge_probability::<T>(&season, &current_block, matches)
+				.saturating_add(boost)
+				.min(MAX_PERCENTAGE);
 
 			let rolls = sacrifices.len();
-			for hash in random_hash.iter().take(rolls) {
-				let roll = hash % MAX_PERCENTAGE;
+			for index in 0..rolls.min(random_hash.as_ref().len()) {
+				let roll = Self::random_below(&random_hash, index, MAX_PERCENTAGE);
 				if roll <= prob {
 					if let Some(first_matched_index) = unique_matched_indexes.pop_first() {
 						let nucleotide = leader.dna[first_matched_index];
@@ -1137,6 +2828,7 @@ pub mod pallet {
 
 			let after_leader_tier = leader.min_tier();
 			if prev_leader_tier != max_tier && after_leader_tier == max_tier {
+				Self::rank_max_tier_forger(season_id, player, current_block);
 				CurrentSeasonStatus::<T>::mutate(|status| {
 					status.max_tier_avatars.saturating_inc();
 					if status.max_tier_avatars == season.max_tier_forges {
@@ -1146,14 +2838,11 @@ pub mod pallet {
 			}
 
 			Avatars::<T>::insert(leader_id, (player, leader));
-			sacrifice_ids.iter().for_each(Avatars::<T>::remove);
-			let remaining_avatar_ids: BoundedAvatarIdsOf<T> = Owners::<T>::take(player)
-				.into_iter()
-				.filter(|avatar_id| !sacrifice_ids.contains(avatar_id))
-				.collect::<Vec<_>>()
-				.try_into()
-				.map_err(|_| Error::<T>::IncorrectAvatarId)?;
-			Owners::<T>::insert(player, remaining_avatar_ids);
+			sacrifice_ids.iter().try_for_each(|sacrifice_id| {
+				Avatars::<T>::remove(sacrifice_id);
+				Self::remove_ownership(player, sacrifice_id);
+				Self::clear_offers(sacrifice_id)
+			})?;
 
 			Accounts::<T>::try_mutate(player, |AccountInfo { stats, .. }| -> DispatchResult {
 				if stats.forge.first.is_zero() {
@@ -1167,7 +2856,11 @@ pub mod pallet {
 					.map_err(|_| Error::<T>::IncorrectSeasonId)?;
 				Ok(())
 			})?;
-			SeasonStats::<T>::mutate(season_id, player, |info| info.forged.saturating_inc());
+			let forged = SeasonStats::<T>::mutate(season_id, player, |info| {
+				info.forged.saturating_inc();
+				info.forged
+			});
+			Self::rank_forger(season_id, player, forged);
 
 			Self::deposit_event(Event::AvatarForged { avatar_id: *leader_id, upgraded_components });
 			Ok(())
@@ -1178,20 +2871,13 @@ pub mod pallet {
 			to: &T::AccountId,
 			avatar_id: &AvatarIdOf<T>,
 		) -> DispatchResult {
-			let mut from_avatar_ids = Self::owners(from);
-			from_avatar_ids.retain(|existing_avatar_id| existing_avatar_id != avatar_id);
+			Self::ensure_storage_quota(to, 1)?;
 
-			let mut to_avatar_ids = Self::owners(to);
-			to_avatar_ids
-				.try_push(*avatar_id)
-				.map_err(|_| Error::<T>::MaxOwnershipReached)?;
-			ensure!(
-				to_avatar_ids.len() <= Self::accounts(to).storage_tier as usize,
-				Error::<T>::MaxOwnershipReached
-			);
-
-			Owners::<T>::mutate(from, |avatar_ids| *avatar_ids = from_avatar_ids);
-			Owners::<T>::mutate(to, |avatar_ids| *avatar_ids = to_avatar_ids);
+			Self::remove_ownership(from, avatar_id);
+			Self::add_ownership(to, avatar_id);
+			if let Some(offer) = Offers::<T>::take(avatar_id, to) {
+				Self::release_funds(to, offer.reserved(), offer.fee, offer.fee_asset)?;
+			}
 			Avatars::<T>::try_mutate(avatar_id, |maybe_avatar| -> DispatchResult {
 				let (from_owner, _) = maybe_avatar.as_mut().ok_or(Error::<T>::UnknownAvatar)?;
 				*from_owner = to.clone();
@@ -1199,6 +2885,13 @@ pub mod pallet {
 			})
 		}
 
+		/// Removes all offers for a burned avatar, releasing the funds held for them.
+		fn clear_offers(avatar_id: &AvatarIdOf<T>) -> DispatchResult {
+			Offers::<T>::drain_prefix(avatar_id).try_for_each(|(buyer, offer)| {
+				Self::release_funds(&buyer, offer.reserved(), offer.fee, offer.fee_asset)
+			})
+		}
+
 		fn current_season_with_id() -> Result<(SeasonId, SeasonOf<T>), DispatchError> {
 			let mut season_id = Self::current_season_id();
 			let season = match Self::seasons(season_id) {
@@ -1228,41 +2921,59 @@ pub mod pallet {
 		) -> Result<MintCount, DispatchError> {
 			let SeasonStatus { active, early, early_ended, .. } = Self::current_season_status();
 			let free_mints = Self::accounts(player).free_mints;
-			let is_whitelisted = free_mints > Zero::zero();
+			let is_allowlisted = Allowlists::<T>::contains_key(Self::current_season_id(), player);
 			let is_free_mint = mint_type == &MintType::Free;
 			ensure!(!early_ended || is_free_mint, Error::<T>::PrematureSeasonEnd);
 			ensure!(
-				active || early && is_whitelisted || early && is_free_mint,
+				active || early && is_allowlisted || early && is_free_mint,
 				Error::<T>::SeasonClosed
 			);
 			Ok(free_mints)
 		}
 
+		/// Validates a forge, returning the leader, the ids of the sacrifices to consume, the
+		/// sacrifices themselves and the souls of those consumed. Sacrifices rented by the player
+		/// are used but neither consumed nor drained of their souls, and sacrifices of the earlier
+		/// seasons carried over by the season are accepted.
 		fn ensure_for_forge(
 			player: &T::AccountId,
 			leader_id: &AvatarIdOf<T>,
 			sacrifice_ids: &[AvatarIdOf<T>],
 			season_id: &SeasonId,
 			season: &SeasonOf<T>,
-		) -> Result<(Avatar, BTreeSet<AvatarIdOf<T>>, Vec<Avatar>), DispatchError> {
+		) -> Result<(Avatar, BTreeSet<AvatarIdOf<T>>, Vec<Avatar>, SoulCount), DispatchError> {
 			let sacrifice_count = sacrifice_ids.len() as u8;
 			ensure!(sacrifice_count >= season.min_sacrifices, Error::<T>::TooFewSacrifices);
 			ensure!(sacrifice_count <= season.max_sacrifices, Error::<T>::TooManySacrifices);
 			ensure!(!sacrifice_ids.contains(leader_id), Error::<T>::LeaderSacrificed);
-			ensure!(
-				sacrifice_ids.iter().all(|id| Self::ensure_for_trade(id).is_err()),
-				Error::<T>::AvatarInTrade
-			);
-			ensure!(Self::ensure_for_trade(leader_id).is_err(), Error::<T>::AvatarInTrade);
+			sacrifice_ids
+				.iter()
+				.filter(|id| !Self::is_rented_by(player, id))
+				.try_for_each(Self::ensure_not_in_trade)?;
+			Self::ensure_not_in_trade(leader_id)?;
 			Self::ensure_unlocked(leader_id)?;
 			Self::ensure_unprepared(leader_id)?;
 
+			let CarryOver { sacrifice_seasons, .. } = Self::carry_overs(season_id);
 			let deduplicated_sacrifice_ids = sacrifice_ids.iter().copied().collect::<BTreeSet<_>>();
+			let mut consumed_souls: SoulCount = 0;
 			let sacrifices = deduplicated_sacrifice_ids
 				.iter()
 				.map(|id| {
-					let avatar = Self::ensure_ownership(player, id)?;
-					ensure!(avatar.season_id == *season_id, Error::<T>::IncorrectAvatarSeason);
+					let avatar = if Self::is_rented_by(player, id) {
+						Self::avatars(id)
+							.map(|(_, avatar)| avatar)
+							.ok_or(Error::<T>::UnknownAvatar)?
+					} else {
+						let avatar = Self::ensure_ownership(player, id)?;
+						consumed_souls.saturating_accrue(avatar.souls);
+						avatar
+					};
+					ensure!(
+						avatar.season_id == *season_id ||
+							sacrifice_seasons.contains(&avatar.season_id),
+						Error::<T>::IncorrectAvatarSeason
+					);
 					Self::ensure_unlocked(id)?;
 					Self::ensure_unprepared(id)?;
 					Ok(avatar)
@@ -1272,7 +2983,37 @@ pub mod pallet {
 			let leader = Self::ensure_ownership(player, leader_id)?;
 			ensure!(leader.season_id == *season_id, Error::<T>::IncorrectAvatarSeason);
 
-			Ok((leader, deduplicated_sacrifice_ids, sacrifices))
+			let consumed_sacrifice_ids = deduplicated_sacrifice_ids
+				.into_iter()
+				.filter(|id| !Self::is_rented_by(player, id))
+				.collect();
+			Ok((leader, consumed_sacrifice_ids, sacrifices, consumed_souls))
+		}
+
+		/// Validates every forge of a batch against the current state, ensuring no avatar is used
+		/// by more than one of them.
+		fn ensure_for_forge_batch(
+			player: &T::AccountId,
+			forges: &[(AvatarIdOf<T>, Vec<AvatarIdOf<T>>)],
+		) -> DispatchResult {
+			ensure!(!forges.is_empty(), Error::<T>::EmptyForgeBatch);
+			let GlobalConfig { forge, .. } = Self::global_configs();
+			ensure!(forge.open, Error::<T>::ForgeClosed);
+
+			let (season_id, season) = Self::current_season_with_id()?;
+			let mut used_avatar_ids = BTreeSet::new();
+			forges.iter().try_for_each(|(leader_id, sacrifice_ids)| {
+				Self::ensure_for_forge(player, leader_id, sacrifice_ids, &season_id, &season)?;
+				let sacrifice_ids = sacrifice_ids.iter().copied().collect::<BTreeSet<_>>();
+				ensure!(
+					sacrifice_ids
+						.into_iter()
+						.chain(sp_std::iter::once(*leader_id))
+						.all(|avatar_id| used_avatar_ids.insert(avatar_id)),
+					Error::<T>::OverlappingForges
+				);
+				Ok(())
+			})
 		}
 
 		fn ensure_for_trade(
@@ -1283,6 +3024,89 @@ pub mod pallet {
 			Ok((seller, price))
 		}
 
+		/// Ensures an avatar is neither listed for a fixed price, auctioned off nor rented out.
+		fn ensure_not_in_trade(avatar_id: &AvatarIdOf<T>) -> Result<(), DispatchError> {
+			ensure!(
+				Self::ensure_for_trade(avatar_id).is_err() &&
+					!Auctions::<T>::contains_key(avatar_id),
+				Error::<T>::AvatarInTrade
+			);
+			Self::ensure_not_in_rental(avatar_id)
+		}
+
+		/// Ensures a player would own no more avatars than the quota of their storage tier after
+		/// gaining `extra` ones.
+		fn ensure_storage_quota(player: &T::AccountId, extra: u32) -> DispatchResult {
+			if let Some(quota) = Self::accounts(player).storage_tier.quota() {
+				ensure!(
+					Self::owned_avatar_count(player).saturating_add(extra) <= quota,
+					Error::<T>::MaxOwnershipReached
+				);
+			}
+			Ok(())
+		}
+
+		fn ensure_not_in_rental(avatar_id: &AvatarIdOf<T>) -> Result<(), DispatchError> {
+			ensure!(
+				!RentalListings::<T>::contains_key(avatar_id) &&
+					!Rentals::<T>::contains_key(avatar_id),
+				Error::<T>::AvatarInRental
+			);
+			Ok(())
+		}
+
+		/// Returns the account lending out an avatar: the holder of its NFT while locked, or else
+		/// its owner.
+		fn lender_of(avatar_id: &AvatarIdOf<T>) -> Result<T::AccountId, DispatchError> {
+			if LockedAvatars::<T>::contains_key(avatar_id) {
+				let collection_id = Self::collection_id().ok_or(Error::<T>::CollectionIdNotSet)?;
+				T::NftHandler::owner(collection_id, *avatar_id)
+					.ok_or_else(|| Error::<T>::UnknownAvatar.into())
+			} else {
+				Self::avatars(avatar_id)
+					.map(|(owner, _)| owner)
+					.ok_or_else(|| Error::<T>::UnknownAvatar.into())
+			}
+		}
+
+		fn is_rented_by(renter: &T::AccountId, avatar_id: &AvatarIdOf<T>) -> bool {
+			Self::rentals(avatar_id).map_or(false, |rental| rental.renter == *renter)
+		}
+
+		/// Hands the avatars whose rental expires at `now` back to their owners.
+		fn expire_rentals(weight: &mut Weight, now: T::BlockNumber) {
+			let avatar_ids = RentalExpiries::<T>::take(now);
+			weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+
+			for avatar_id in avatar_ids {
+				if let Some(Rental { renter, .. }) = Rentals::<T>::take(avatar_id) {
+					Self::deposit_event(Event::RentalExpired { avatar_id, renter });
+				}
+				weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+			}
+		}
+
+		fn apply_config_patches(weight: &mut Weight, now: T::BlockNumber) {
+			let patches = ScheduledConfigPatches::<T>::take(now);
+			weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+
+			for (patch_id, patch) in patches {
+				let config = GlobalConfigs::<T>::mutate(|config| {
+					patch.apply(config);
+					config.clone()
+				});
+				Self::deposit_event(Event::ConfigPatchApplied { patch_id, config });
+				weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+			}
+		}
+
+		/// Check that the fees of a global configuration are above the existential deposit.
+		fn ensure_fees(fees: impl IntoIterator<Item = BalanceOf<T>>) -> DispatchResult {
+			let minimum_balance = T::Currency::minimum_balance();
+			ensure!(fees.into_iter().all(|fee| fee > minimum_balance), Error::<T>::TooLowFees);
+			Ok(())
+		}
+
 		fn ensure_unlocked(avatar_id: &AvatarIdOf<T>) -> Result<(), DispatchError> {
 			ensure!(!LockedAvatars::<T>::contains_key(avatar_id), Error::<T>::AvatarLocked);
 			Ok(())
@@ -1329,9 +3153,200 @@ pub mod pallet {
 			Self::deposit_event(Event::SeasonFinished(season_id));
 			weight.saturating_accrue(T::DbWeight::get().writes(1));
 
+			Self::award_season_rewards(weight, season_id);
+
 			if let Some(next_season) = Self::seasons(next_season_id) {
 				Self::start_season(weight, block_number, next_season_id, &next_season);
 			}
 		}
+
+		/// Ranks a player on the forge leaderboard of a season, given its number of forges.
+		pub(crate) fn rank_forger(season_id: SeasonId, player: &T::AccountId, forged: Stat) {
+			ForgeLeaderboards::<T>::mutate(season_id, |leaderboard| {
+				leaderboard.retain(|(account, _)| account != player);
+				let rank = leaderboard
+					.iter()
+					.position(|(_, count)| *count < forged)
+					.unwrap_or(leaderboard.len());
+				// players ranked below the leaderboard size are left out
+				let _ = leaderboard.force_insert_keep_left(rank, (player.clone(), forged));
+			});
+		}
+
+		/// Ranks a player on the max tier leaderboard of a season, unless it's already on it.
+		pub(crate) fn rank_max_tier_forger(
+			season_id: SeasonId,
+			player: &T::AccountId,
+			now: T::BlockNumber,
+		) {
+			MaxTierLeaderboards::<T>::mutate(season_id, |leaderboard| {
+				if !leaderboard.iter().any(|(account, _)| account == player) {
+					// players reaching max tier once the leaderboard is full are left out
+					let _ = leaderboard.try_push((player.clone(), now));
+				}
+			});
+		}
+
+		/// Sets aside the shares of a season's treasury awarded to the players on its leaderboards.
+		fn award_season_rewards(weight: &mut Weight, season_id: SeasonId) {
+			let rewards = Self::season_rewards(season_id);
+			weight.saturating_accrue(T::DbWeight::get().reads(1));
+
+			if let Some(SeasonRewards { forge_shares, max_tier_shares }) = rewards {
+				let pot = Self::treasury(season_id);
+				let forgers = Self::forge_leaderboard(season_id).into_iter().map(|(a, _)| a);
+				let max_tier_forgers =
+					Self::max_tier_leaderboard(season_id).into_iter().map(|(a, _)| a);
+				weight.saturating_accrue(T::DbWeight::get().reads(3));
+
+				let mut awarded = BalanceOf::<T>::zero();
+				for (player, share) in forgers
+					.zip(forge_shares.into_iter())
+					.chain(max_tier_forgers.zip(max_tier_shares.into_iter()))
+				{
+					let amount = pot.saturating_mul(share.unique_saturated_into()) /
+						MAX_PERCENTAGE.unique_saturated_into();
+					UnclaimedRewards::<T>::mutate(season_id, player, |reward| {
+						reward.saturating_accrue(amount)
+					});
+					awarded.saturating_accrue(amount);
+					weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+				}
+
+				Treasury::<T>::mutate(season_id, |bal| bal.saturating_reduce(awarded));
+				weight.saturating_accrue(T::DbWeight::get().writes(1));
+				Self::deposit_event(Event::SeasonRewardsAwarded { season_id, amount: awarded });
+			}
+		}
+
+		fn ensure_season_ended(season_id: SeasonId) -> DispatchResult {
+			let (current_season_id, season) = Self::current_season_with_id()?;
+			ensure!(
+				season_id < current_season_id ||
+					(season_id == current_season_id &&
+						<frame_system::Pallet<T>>::block_number() > season.end),
+				Error::<T>::CannotClaimDuringSeason
+			);
+			Ok(())
+		}
+
+		/// Returns the ids of the avatars owned by a player.
+		///
+		/// While the v5 migration is ongoing, avatars of accounts it has not converted yet are
+		/// read from their previous location.
+		pub fn owners(player: &T::AccountId) -> Vec<AvatarIdOf<T>> {
+			let mut avatar_ids = OwnedAvatars::<T>::iter_key_prefix(player).collect::<Vec<_>>();
+			if OngoingMigration::<T>::exists() {
+				let unconverted_ids = migration::v5::Owners::<T>::get(player)
+					.into_iter()
+					.filter(|avatar_id| !avatar_ids.contains(avatar_id))
+					.filter(
+						|avatar_id| matches!(Self::avatars(avatar_id), Some((owner, _)) if owner == *player),
+					)
+					.collect::<Vec<_>>();
+				avatar_ids.extend(unconverted_ids);
+			}
+			avatar_ids
+		}
+
+		/// Records `player` as the owner of an avatar.
+		pub(crate) fn add_ownership(player: &T::AccountId, avatar_id: &AvatarIdOf<T>) {
+			if !OwnedAvatars::<T>::contains_key(player, avatar_id) {
+				OwnedAvatars::<T>::insert(player, avatar_id, ());
+				OwnedAvatarCounts::<T>::mutate(player, |count| count.saturating_inc());
+			}
+		}
+
+		/// Removes `player` as the owner of an avatar.
+		pub(crate) fn remove_ownership(player: &T::AccountId, avatar_id: &AvatarIdOf<T>) {
+			if OwnedAvatars::<T>::take(player, avatar_id).is_some() {
+				OwnedAvatarCounts::<T>::mutate(player, |count| count.saturating_dec());
+			}
+		}
+
+		/// Returns the avatars owned by a player.
+		pub fn avatars_of(player: &T::AccountId) -> Vec<AvatarInfo<AvatarIdOf<T>, BalanceOf<T>>> {
+			Self::owners(player)
+				.into_iter()
+				.filter_map(|avatar_id| {
+					let (_, avatar) = Self::avatars(avatar_id)?;
+					let price = Self::trade(avatar_id);
+					let locked = LockedAvatars::<T>::contains_key(avatar_id);
+					Some(AvatarInfo::new(avatar_id, avatar, price, locked))
+				})
+				.collect()
+		}
+
+		/// Returns the season currently in use along with its status.
+		pub fn current_season() -> Option<CurrentSeason<T::BlockNumber>> {
+			let (season_id, season) = Self::current_season_with_id().ok()?;
+			Some(CurrentSeason { season_id, season, status: Self::current_season_status() })
+		}
+
+		/// Dry-runs a forge and returns the rolls it would make, without writing to storage.
+		pub fn preview_forge(
+			player: &T::AccountId,
+			leader_id: &AvatarIdOf<T>,
+			sacrifice_ids: &[AvatarIdOf<T>],
+		) -> Result<ForgePreview, DispatchError> {
+			let GlobalConfig { forge, .. } = Self::global_configs();
+			ensure!(forge.open, Error::<T>::ForgeClosed);
+
+			let (season_id, season) = Self::current_season_with_id()?;
+			let (mut leader, _, sacrifices, _) =
+				Self::ensure_for_forge(player, leader_id, sacrifice_ids, &season_id, &season)?;
+
+			let max_tier = season.tiers.iter().max().ok_or(Error::<T>::UnknownTier)?.clone() as u8;
+			let (matched_indexes, matches) =
+				leader.compare_all::<T>(&sacrifices, season.max_variations, max_tier)?;
+
+			let boost =
+				if matched_indexes.is_empty() { Zero::zero() } else { Self::forge_boosts(player) };
+			let current_block = <frame_system::Pallet<T>>::block_number();
+			Ok(ForgePreview {
+				matched_components: matched_indexes.into_iter().map(|index| index as u8).collect(),
+				matches,
+				rolls: sacrifices.len() as u8,
+				probability: leader
+					.forge_probability::<T>(&season, &current_block, matches)
+					.saturating_add(boost)
+					.min(MAX_PERCENTAGE),
+				period_multiplier: leader.forge_multiplier::<T>(&season, &current_block),
+			})
+		}
+
+		/// Returns all avatars listed for trade.
+		pub fn listed_avatars() -> Vec<ListedAvatar<T::AccountId, AvatarIdOf<T>, BalanceOf<T>>> {
+			Trade::<T>::iter()
+				.filter_map(|(avatar_id, price)| {
+					let (owner, _) = Self::avatars(avatar_id)?;
+					Some(ListedAvatar { avatar_id, owner, price })
+				})
+				.collect()
+		}
+	}
+}
+
+/// Ensures that the origin is signed by the [`Organizer`] account, which lets runtimes keep the
+/// organizer alongside governance as [`Config::SeasonOrigin`] and the other admin origins.
+pub struct EnsureOrganizer<T>(sp_std::marker::PhantomData<T>);
+impl<T: Config> EnsureOrigin<OriginFor<T>> for EnsureOrganizer<T> {
+	type Success = T::AccountId;
+
+	fn try_origin(origin: OriginFor<T>) -> Result<Self::Success, OriginFor<T>> {
+		let raw_origin: Result<frame_system::RawOrigin<T::AccountId>, OriginFor<T>> = origin.into();
+		raw_origin.and_then(|raw_origin| match raw_origin {
+			frame_system::RawOrigin::Signed(who)
+				if Organizer::<T>::get().as_ref() == Some(&who) =>
+				Ok(who),
+			raw_origin => Err(raw_origin.into()),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<OriginFor<T>, ()> {
+		Organizer::<T>::get()
+			.map(|who| frame_system::RawOrigin::Signed(who).into())
+			.ok_or(())
 	}
 }
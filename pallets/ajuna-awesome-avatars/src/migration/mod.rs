@@ -18,11 +18,156 @@ pub mod v1;
 pub mod v2;
 pub mod v3;
 pub mod v4;
+pub mod v5;
 
 use super::*;
 use frame_support::traits::OnRuntimeUpgrade;
 
 // The current storage version.
-pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
 
 const LOG_TARGET: &str = "runtime::ajuna-awesome-avatars";
+
+/// Position from which a multi-block migration resumes. It's empty when the migration starts.
+pub type MigrationCursor = BoundedVec<u8, ConstU32<1_024>>;
+
+/// A migration that is carried out over several blocks, as it doesn't fit in a single one.
+pub trait MultiBlockMigration<T: Config> {
+	/// The storage version the migration upgrades from, to the following one.
+	const FROM_VERSION: u16;
+
+	/// Migrates as much as fits in `limit` from `cursor` on, returning the weight used and the
+	/// cursor to resume from, or `None` once the migration has completed.
+	fn step(cursor: &MigrationCursor, limit: Weight) -> (Weight, Option<MigrationCursor>);
+
+	/// Captures the state to check the outcome of the migration against.
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		Ok(Vec::new())
+	}
+
+	/// Checks the outcome of the migration once it has completed.
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+		Ok(())
+	}
+}
+
+/// Runs the next step of the ongoing migration within `limit`, upgrading the storage version once
+/// the migration completes.
+pub(crate) fn step<T: Config>(limit: Weight) -> Weight {
+	let db_weight = T::DbWeight::get();
+	let cursor = match OngoingMigration::<T>::get() {
+		Some(cursor) => cursor,
+		None => return db_weight.reads(1),
+	};
+
+	let bookkeeping = db_weight.reads_writes(2, 2);
+	let limit = limit.saturating_sub(bookkeeping);
+	let weight = match Pallet::<T>::on_chain_storage_version() {
+		version if version == v1::MigrateTradeConfig::<T>::FROM_VERSION =>
+			run::<T, v1::MigrateTradeConfig<T>>(&cursor, limit),
+		version if version == v2::MigrateTransferConfig::<T>::FROM_VERSION =>
+			run::<T, v2::MigrateTransferConfig<T>>(&cursor, limit),
+		version if version == v3::MigrateStorageTiers::<T>::FROM_VERSION =>
+			run::<T, v3::MigrateStorageTiers<T>>(&cursor, limit),
+		version if version == v4::MigrateNftTransferConfig::<T>::FROM_VERSION =>
+			run::<T, v4::MigrateNftTransferConfig<T>>(&cursor, limit),
+		version if version == v5::MigrateOwners::<T>::FROM_VERSION =>
+			run::<T, v5::MigrateOwners<T>>(&cursor, limit),
+		version => {
+			log::error!(
+				target: LOG_TARGET,
+				"No migration from storage version {:?}, resuming calls",
+				version
+			);
+			OngoingMigration::<T>::kill();
+			Weight::zero()
+		},
+	};
+	weight.saturating_add(bookkeeping)
+}
+
+fn run<T: Config, M: MultiBlockMigration<T>>(cursor: &MigrationCursor, limit: Weight) -> Weight {
+	let (weight, next_cursor) = M::step(cursor, limit);
+	match next_cursor {
+		Some(next_cursor) => OngoingMigration::<T>::put(next_cursor),
+		None => {
+			let version = StorageVersion::new(M::FROM_VERSION + 1);
+			version.put::<Pallet<T>>();
+			log::info!(target: LOG_TARGET, "Upgraded storage to version {:?}", version);
+
+			// the following migration, if any, starts in the next step
+			if version < Pallet::<T>::current_storage_version() {
+				OngoingMigration::<T>::put(MigrationCursor::default());
+			} else {
+				OngoingMigration::<T>::kill();
+			}
+		},
+	}
+	weight
+}
+
+/// Runs the checks of the migration from `version` around carrying it out in full.
+#[cfg(feature = "try-runtime")]
+fn try_run<T: Config>(version: StorageVersion) -> Result<(), &'static str> {
+	fn checked<T: Config, M: MultiBlockMigration<T>>() -> Result<(), &'static str> {
+		let state = M::pre_upgrade()?;
+		while Pallet::<T>::on_chain_storage_version() == M::FROM_VERSION {
+			step::<T>(Weight::MAX);
+		}
+		M::post_upgrade(state)
+	}
+
+	match version {
+		version if version == v1::MigrateTradeConfig::<T>::FROM_VERSION =>
+			checked::<T, v1::MigrateTradeConfig<T>>(),
+		version if version == v2::MigrateTransferConfig::<T>::FROM_VERSION =>
+			checked::<T, v2::MigrateTransferConfig<T>>(),
+		version if version == v3::MigrateStorageTiers::<T>::FROM_VERSION =>
+			checked::<T, v3::MigrateStorageTiers<T>>(),
+		version if version == v4::MigrateNftTransferConfig::<T>::FROM_VERSION =>
+			checked::<T, v4::MigrateNftTransferConfig<T>>(),
+		version if version == v5::MigrateOwners::<T>::FROM_VERSION =>
+			checked::<T, v5::MigrateOwners<T>>(),
+		_ => Err("no migration from the on-chain storage version"),
+	}
+}
+
+/// Starts the multi-block migration `M` on runtime upgrade, pausing calls until it and the
+/// migrations following it have brought storage to the current version.
+pub struct StartMigration<T, M>(sp_std::marker::PhantomData<(T, M)>);
+impl<T: Config, M: MultiBlockMigration<T>> OnRuntimeUpgrade for StartMigration<T, M> {
+	fn on_runtime_upgrade() -> Weight {
+		let current_version = Pallet::<T>::current_storage_version();
+		let onchain_version = Pallet::<T>::on_chain_storage_version();
+		if onchain_version == M::FROM_VERSION &&
+			current_version > onchain_version &&
+			!OngoingMigration::<T>::exists()
+		{
+			OngoingMigration::<T>::put(MigrationCursor::default());
+			log::info!(
+				target: LOG_TARGET,
+				"Migrating storage from version {:?} over the next blocks",
+				onchain_version
+			);
+			T::DbWeight::get().reads_writes(2, 1)
+		} else {
+			log::info!(
+				target: LOG_TARGET,
+				"Migration did not execute. This probably should be removed"
+			);
+			T::DbWeight::get().reads(2)
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+		// carry out all remaining migrations at once, checking the outcome of each one
+		while OngoingMigration::<T>::exists() {
+			try_run::<T>(Pallet::<T>::on_chain_storage_version())?;
+		}
+		assert_eq!(Pallet::<T>::on_chain_storage_version(), Pallet::<T>::current_storage_version());
+		Ok(())
+	}
+}
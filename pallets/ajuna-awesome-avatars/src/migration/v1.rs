@@ -66,38 +66,36 @@ impl<Balance, BlockNumber> OldGlobalConfig<Balance, BlockNumber> {
 pub(crate) type GlobalConfigs<T: Config> =
 	StorageValue<Pallet<T>, GlobalConfigV1<BalanceOf<T>, BlockNumberFor<T>>, ValueQuery>;
 
-pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
-impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
-	fn on_runtime_upgrade() -> Weight {
-		let current_version = Pallet::<T>::current_storage_version();
-		let onchain_version = Pallet::<T>::on_chain_storage_version();
-		if onchain_version == 0 && current_version == 1 {
-			let _ = GlobalConfigs::<T>::translate::<OldGlobalConfig<BalanceOf<T>, T::BlockNumber>, _>(
-				|maybe_old_value| {
-					maybe_old_value.map(|old_value| {
-						log::info!(target: LOG_TARGET, "migrated global config");
-						old_value.migrate_to_v1()
-					})
-				},
-			);
-			current_version.put::<Pallet<T>>();
-			log::info!(target: LOG_TARGET, "Upgraded storage to version {:?}", current_version);
-			T::DbWeight::get().reads_writes(2, 2)
-		} else {
-			log::info!(
-				target: LOG_TARGET,
-				"Migration did not execute. This probably should be removed"
-			);
-			T::DbWeight::get().reads(1)
+/// Replaces the buy fee of the trade config with a minimum and a percentage fee.
+pub struct MigrateTradeConfig<T>(sp_std::marker::PhantomData<T>);
+impl<T: Config> MultiBlockMigration<T> for MigrateTradeConfig<T> {
+	const FROM_VERSION: u16 = 0;
+
+	fn step(cursor: &MigrationCursor, limit: Weight) -> (Weight, Option<MigrationCursor>) {
+		let weight = T::DbWeight::get().reads_writes(1, 1);
+		if !weight.all_lte(limit) {
+			return (Weight::zero(), Some(cursor.clone()))
 		}
+
+		let _ = GlobalConfigs::<T>::translate::<OldGlobalConfig<BalanceOf<T>, T::BlockNumber>, _>(
+			|maybe_old_value| {
+				maybe_old_value.map(|old_value| {
+					log::info!(target: LOG_TARGET, "migrated global config");
+					old_value.migrate_to_v1()
+				})
+			},
+		);
+		(weight, None)
 	}
 
 	#[cfg(feature = "try-runtime")]
 	fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
-		assert_eq!(Pallet::<T>::on_chain_storage_version(), 1);
 		let global_configs = GlobalConfigs::<T>::get();
 		assert_eq!(global_configs.trade.min_fee, 1_000_000_000_u64.unique_saturated_into());
 		assert_eq!(global_configs.trade.percent_fee, 1);
 		Ok(())
 	}
 }
+
+/// Starts migrating the trade config, which [`MigrateTradeConfig`] carries out.
+pub type MigrateToV1<T> = StartMigration<T, MigrateTradeConfig<T>>;
@@ -81,36 +81,34 @@ impl<T: Config> OldGlobalConfig<T> {
 pub(crate) type GlobalConfigs<T: Config> =
 	StorageValue<Pallet<T>, GlobalConfigV2<BalanceOf<T>, BlockNumberFor<T>>, ValueQuery>;
 
-pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
-impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
-	fn on_runtime_upgrade() -> Weight {
-		let current_version = Pallet::<T>::current_storage_version();
-		let onchain_version = Pallet::<T>::on_chain_storage_version();
-		if onchain_version == 1 && current_version == 2 {
-			let _ = GlobalConfigs::<T>::translate::<OldGlobalConfig<T>, _>(|maybe_old_value| {
-				maybe_old_value.map(|old_value| {
-					log::info!(target: LOG_TARGET, "Migrated global config");
-					old_value.migrate_to_v2()
-				})
-			});
-			current_version.put::<Pallet<T>>();
-			log::info!(target: LOG_TARGET, "Upgraded storage to version {:?}", current_version);
-			T::DbWeight::get().reads_writes(2, 2)
-		} else {
-			log::info!(
-				target: LOG_TARGET,
-				"Migration did not execute. This probably should be removed"
-			);
-			T::DbWeight::get().reads(1)
+/// Moves the transfer settings of the mint config into a transfer config of their own.
+pub struct MigrateTransferConfig<T>(sp_std::marker::PhantomData<T>);
+impl<T: Config> MultiBlockMigration<T> for MigrateTransferConfig<T> {
+	const FROM_VERSION: u16 = 1;
+
+	fn step(cursor: &MigrationCursor, limit: Weight) -> (Weight, Option<MigrationCursor>) {
+		let weight = T::DbWeight::get().reads_writes(1, 1);
+		if !weight.all_lte(limit) {
+			return (Weight::zero(), Some(cursor.clone()))
 		}
+
+		let _ = GlobalConfigs::<T>::translate::<OldGlobalConfig<T>, _>(|maybe_old_value| {
+			maybe_old_value.map(|old_value| {
+				log::info!(target: LOG_TARGET, "Migrated global config");
+				old_value.migrate_to_v2()
+			})
+		});
+		(weight, None)
 	}
 
 	#[cfg(feature = "try-runtime")]
 	fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
-		assert_eq!(Pallet::<T>::on_chain_storage_version(), 2);
 		let GlobalConfigV2 { transfer, .. } = GlobalConfigs::<T>::get();
 		assert!(transfer.open);
 		assert_eq!(transfer.avatar_transfer_fee, 1_000_000_000_000_u64.unique_saturated_into());
 		Ok(())
 	}
 }
+
+/// Starts migrating the transfer config, which [`MigrateTransferConfig`] carries out.
+pub type MigrateToV2<T> = StartMigration<T, MigrateTransferConfig<T>>;
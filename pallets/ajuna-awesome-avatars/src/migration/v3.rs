@@ -16,7 +16,7 @@
 
 use super::*;
 
-#[derive(Decode)]
+#[derive(Decode, Encode)]
 pub enum OldStorageTier {
 	One = 25,
 	Two = 50,
@@ -35,7 +35,7 @@ impl OldStorageTier {
 	}
 }
 
-#[derive(Decode)]
+#[derive(Decode, Encode)]
 pub struct OldAccountInfo<BlockNumber> {
 	pub free_mints: MintCount,
 	pub storage_tier: OldStorageTier,
@@ -52,37 +52,58 @@ impl<BlockNumber> OldAccountInfo<BlockNumber> {
 	}
 }
 
-pub struct MigrateToV3<T>(sp_std::marker::PhantomData<T>);
-impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
-	fn on_runtime_upgrade() -> Weight {
-		let current_version = Pallet::<T>::current_storage_version();
-		let onchain_version = Pallet::<T>::on_chain_storage_version();
-		if onchain_version == 2 && current_version == 3 {
-			let mut translated = 0_u64;
-			Accounts::<T>::translate::<OldAccountInfo<T::BlockNumber>, _>(|_key, old_value| {
-				translated.saturating_inc();
-				Some(old_value.migrate_to_v3())
-			});
-			current_version.put::<Pallet<T>>();
-			log::info!(
-				target: LOG_TARGET,
-				"Upgraded {} pools, storage to version {:?}",
-				translated,
-				current_version
-			);
-			T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+/// Accounts as stored before storage tiers were encoded by index.
+#[frame_support::storage_alias]
+pub(crate) type Accounts<T: Config> = StorageMap<
+	Pallet<T>,
+	Identity,
+	<T as frame_system::Config>::AccountId,
+	OldAccountInfo<<T as frame_system::Config>::BlockNumber>,
+	OptionQuery,
+>;
+
+/// Re-encodes the storage tier of each account, as many accounts per block as fit.
+pub struct MigrateStorageTiers<T>(sp_std::marker::PhantomData<T>);
+impl<T: Config> MultiBlockMigration<T> for MigrateStorageTiers<T> {
+	const FROM_VERSION: u16 = 2;
+
+	fn step(cursor: &MigrationCursor, limit: Weight) -> (Weight, Option<MigrationCursor>) {
+		let entry_weight = T::DbWeight::get().reads_writes(1, 1);
+
+		let mut weight = Weight::zero();
+		let mut old_entries = if cursor.is_empty() {
+			Accounts::<T>::iter()
 		} else {
-			log::info!(
-				target: LOG_TARGET,
-				"Migration did not execute. This probably should be removed"
-			);
-			T::DbWeight::get().reads(1)
+			Accounts::<T>::iter_from(cursor.to_vec())
+		};
+		while weight.saturating_add(entry_weight).all_lte(limit) {
+			let (player, old_account) = match old_entries.next() {
+				Some(entry) => entry,
+				None => return (weight, None),
+			};
+			crate::Accounts::<T>::insert(player, old_account.migrate_to_v3());
+			weight.saturating_accrue(entry_weight);
 		}
+
+		// keys of accounts are far shorter than a cursor can be
+		let next_cursor = old_entries.last_raw_key().to_vec().try_into().unwrap_or_default();
+		(weight, Some(next_cursor))
 	}
 
 	#[cfg(feature = "try-runtime")]
-	fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
-		assert_eq!(Pallet::<T>::on_chain_storage_version(), 3);
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		Ok((Accounts::<T>::iter_keys().count() as u32).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		let accounts = u32::decode(&mut &state[..]).map_err(|_| "failed to decode accounts")?;
+		// entries that fail to decode are skipped, so all accounts have been re-encoded
+		assert_eq!(crate::Accounts::<T>::iter().count() as u32, accounts);
 		Ok(())
 	}
 }
+
+/// Starts re-encoding storage tiers, which [`MigrateStorageTiers`] carries out over the
+/// following blocks.
+pub type MigrateToV3<T> = StartMigration<T, MigrateStorageTiers<T>>;
@@ -41,37 +41,34 @@ impl<T: Config> OldGlobalConfig<T> {
 	}
 }
 
-pub struct MigrateToV4<T>(sp_std::marker::PhantomData<T>);
-impl<T: Config> OnRuntimeUpgrade for MigrateToV4<T> {
-	fn on_runtime_upgrade() -> Weight {
-		let current_version = Pallet::<T>::current_storage_version();
-		let onchain_version = Pallet::<T>::on_chain_storage_version();
-		if onchain_version == 3 && current_version == 4 {
-			let _ = GlobalConfigs::<T>::translate::<OldGlobalConfig<T>, _>(|maybe_old_value| {
-				maybe_old_value.map(|old_value| {
-					log::info!(target: LOG_TARGET, "Migrated global config");
-					old_value.migrate_to_v4()
-				})
-			});
+/// Adds the NFT transfer config to the global config.
+pub struct MigrateNftTransferConfig<T>(sp_std::marker::PhantomData<T>);
+impl<T: Config> MultiBlockMigration<T> for MigrateNftTransferConfig<T> {
+	const FROM_VERSION: u16 = 3;
 
-			current_version.put::<Pallet<T>>();
-			log::info!(target: LOG_TARGET, "Upgraded storage to version {:?}", current_version);
-			T::DbWeight::get().reads_writes(2, 2)
-		} else {
-			log::info!(
-				target: LOG_TARGET,
-				"Migration did not execute. This probably should be removed"
-			);
-			T::DbWeight::get().reads(1)
+	fn step(cursor: &MigrationCursor, limit: Weight) -> (Weight, Option<MigrationCursor>) {
+		let weight = T::DbWeight::get().reads_writes(1, 1);
+		if !weight.all_lte(limit) {
+			return (Weight::zero(), Some(cursor.clone()))
 		}
+
+		let _ = GlobalConfigs::<T>::translate::<OldGlobalConfig<T>, _>(|maybe_old_value| {
+			maybe_old_value.map(|old_value| {
+				log::info!(target: LOG_TARGET, "Migrated global config");
+				old_value.migrate_to_v4()
+			})
+		});
+		(weight, None)
 	}
 
 	#[cfg(feature = "try-runtime")]
 	fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
-		assert_eq!(Pallet::<T>::on_chain_storage_version(), 4);
 		let GlobalConfig { nft_transfer, .. } = GlobalConfigs::<T>::get();
 		assert!(nft_transfer.open);
 		assert_eq!(nft_transfer.prepare_fee, 5_000_000_000_000_u64.unique_saturated_into());
 		Ok(())
 	}
 }
+
+/// Starts adding the NFT transfer config, which [`MigrateNftTransferConfig`] carries out.
+pub type MigrateToV4<T> = StartMigration<T, MigrateNftTransferConfig<T>>;
@@ -0,0 +1,102 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The most avatars an account could own before ownership was kept in [`OwnedAvatars`].
+pub(crate) struct MaxAvatarsPerPlayer;
+impl Get<u32> for MaxAvatarsPerPlayer {
+	fn get() -> u32 {
+		200
+	}
+}
+
+/// Avatars owned by each account, before ownership was kept in [`OwnedAvatars`].
+#[frame_support::storage_alias]
+pub(crate) type Owners<T: Config> = StorageMap<
+	Pallet<T>,
+	Identity,
+	<T as frame_system::Config>::AccountId,
+	BoundedVec<AvatarIdOf<T>, MaxAvatarsPerPlayer>,
+	ValueQuery,
+>;
+
+/// Moves the entries of `Owners` into [`OwnedAvatars`], one account at a time.
+///
+/// Converted entries are removed, so an account is never converted twice. Avatars that changed
+/// hands while the migration was pending are skipped, as their new ownership has already been
+/// recorded by the transfer.
+pub struct MigrateOwners<T>(sp_std::marker::PhantomData<T>);
+impl<T: Config> MultiBlockMigration<T> for MigrateOwners<T> {
+	const FROM_VERSION: u16 = 4;
+
+	fn step(cursor: &MigrationCursor, limit: Weight) -> (Weight, Option<MigrationCursor>) {
+		let db_weight = T::DbWeight::get();
+		let max_avatars = MaxAvatarsPerPlayer::get() as u64;
+		let max_entry_weight = db_weight.reads_writes(1 + 2 * max_avatars, 1 + 2 * max_avatars);
+
+		let mut weight = Weight::zero();
+		let mut old_entries = if cursor.is_empty() {
+			Owners::<T>::iter()
+		} else {
+			Owners::<T>::iter_from(cursor.to_vec())
+		};
+		while weight.saturating_add(max_entry_weight).all_lte(limit) {
+			let (player, avatar_ids) = match old_entries.next() {
+				Some(entry) => entry,
+				None => return (weight, None),
+			};
+			weight.saturating_accrue(db_weight.reads_writes(1, 1));
+			for avatar_id in avatar_ids {
+				if matches!(Pallet::<T>::avatars(avatar_id), Some((owner, _)) if owner == player) {
+					Pallet::<T>::add_ownership(&player, &avatar_id);
+				}
+				weight.saturating_accrue(db_weight.reads_writes(2, 2));
+			}
+			Owners::<T>::remove(&player);
+		}
+
+		// converted entries are removed, so starting over from the first entry is also correct
+		let next_cursor = old_entries.last_raw_key().to_vec().try_into().unwrap_or_default();
+		(weight, Some(next_cursor))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		let owned_avatars = Owners::<T>::iter()
+			.flat_map(|(player, avatar_ids)| avatar_ids.into_iter().map(move |id| (player.clone(), id)))
+			.filter(|(player, avatar_id)| {
+				matches!(Pallet::<T>::avatars(avatar_id), Some((owner, _)) if owner == *player)
+			})
+			.count() as u32;
+		Ok(owned_avatars.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		let owned_avatars =
+			u32::decode(&mut &state[..]).map_err(|_| "failed to decode owned avatars")?;
+		assert_eq!(Owners::<T>::iter().count(), 0);
+		assert!(OwnedAvatarCounts::<T>::iter_values().sum::<u32>() >= owned_avatars);
+		assert!(OwnedAvatars::<T>::iter_keys().all(|(player, avatar_id)| {
+			matches!(Pallet::<T>::avatars(avatar_id), Some((owner, _)) if owner == player)
+		}));
+		Ok(())
+	}
+}
+
+/// Starts converting `Owners`, which [`MigrateOwners`] carries out over the following blocks.
+pub type MigrateToV5<T> = StartMigration<T, MigrateOwners<T>>;
@@ -14,22 +14,28 @@
 // You should have received a copy of the GNU Affero General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{self as pallet_ajuna_awesome_avatars, types::*, *};
+use crate::{
+	self as pallet_ajuna_awesome_avatars, randomness::RandomnessWithNonce,
+	traits::FungiblesPayment, types::*, *,
+};
 use frame_support::{
 	parameter_types,
 	traits::{
-		tokens::nonfungibles_v2::Create, AsEnsureOriginWithArg, ConstU16, ConstU64, GenesisBuild,
-		Hooks,
+		tokens::nonfungibles_v2::Create, AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64,
+		EitherOfDiverse, GenesisBuild, Hooks,
 	},
+	weights::RuntimeDbWeight,
 	PalletId,
 };
 use frame_system::{
 	mocking::{MockBlock, MockUncheckedExtrinsic},
+	offchain::{AppCrypto, CreateSignedTransaction, SendTransactionTypes, SigningTypes},
 	EnsureRoot, EnsureSigned,
 };
+use scale_info::TypeInfo;
 use sp_runtime::{
-	testing::{Header, H256},
-	traits::{BlakeTwo256, IdentityLookup},
+	testing::{Header, TestSignature, TestXt, UintAuthorityId, H256},
+	traits::{BlakeTwo256, Extrinsic as ExtrinsicT, IdentifyAccount, IdentityLookup},
 };
 
 pub type MockAccountId = u128;
@@ -37,6 +43,7 @@ pub type MockBlockNumber = u64;
 pub type MockBalance = u64;
 pub type MockIndex = u64;
 pub type MockCollectionId = u32;
+pub type MockAssetId = u32;
 
 pub const ALICE: MockAccountId = 1;
 pub const BOB: MockAccountId = 2;
@@ -51,6 +58,7 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system,
 		Balances: pallet_balances,
+		Assets: pallet_assets,
 		Randomness: pallet_randomness_collective_flip,
 		Nft: pallet_nfts,
 		AAvatars: pallet_ajuna_awesome_avatars,
@@ -62,7 +70,7 @@ impl frame_system::Config for Test {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = ();
 	type BlockLength = ();
-	type DbWeight = ();
+	type DbWeight = MockDbWeight;
 	type RuntimeOrigin = RuntimeOrigin;
 	type RuntimeCall = RuntimeCall;
 	type Index = MockIndex;
@@ -85,6 +93,10 @@ impl frame_system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+parameter_types! {
+	pub static MockDbWeight: RuntimeDbWeight = RuntimeDbWeight { read: 0, write: 0 };
+}
+
 parameter_types! {
 	pub static MockExistentialDeposit: MockBalance = 321;
 }
@@ -101,6 +113,27 @@ impl pallet_balances::Config for Test {
 	type ReserveIdentifier = [u8; 8];
 }
 
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = MockBalance;
+	type RemoveItemsLimit = ConstU32<1_000>;
+	type AssetId = MockAssetId;
+	type AssetIdParameter = MockAssetId;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<MockAccountId>>;
+	type ForceOrigin = EnsureRoot<MockAccountId>;
+	type AssetDeposit = ConstU64<1>;
+	type AssetAccountDeposit = ConstU64<1>;
+	type MetadataDepositBase = ConstU64<1>;
+	type MetadataDepositPerByte = ConstU64<1>;
+	type ApprovalDeposit = ConstU64<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+}
+
 impl pallet_randomness_collective_flip::Config for Test {}
 
 parameter_types! {
@@ -172,11 +205,82 @@ impl pallet_ajuna_awesome_avatars::Config for Test {
 	type PalletId = AwesomeAvatarsPalletId;
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
-	type Randomness = Randomness;
+	type AssetPayment = FungiblesPayment<Assets>;
+	type Randomness = RandomnessWithNonce<Self, Randomness>;
 	type NftHandler = NftTransfer;
+	type AuthorityId = MockAuthorityId;
+	type SeasonOrigin = EnsureOrganizerOrRoot;
+	type ConfigOrigin = EnsureOrganizerOrRoot;
+	type FreeMintOrigin = EnsureOrganizerOrRoot;
+	type MaxAirdropBatch = ConstU32<100>;
+	type MaxOffersPerAvatar = ConstU32<3>;
+	type MaxRentalsPerBlock = ConstU32<2>;
 	type WeightInfo = ();
 }
 
+/// Stands in for the governance origins of a runtime, next to the organizer.
+pub type EnsureOrganizerOrRoot = EitherOfDiverse<EnsureOrganizer<Test>, EnsureRoot<MockAccountId>>;
+
+/// Public key of the accounts signing offchain transactions, mapped from the test keys.
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, TypeInfo)]
+pub struct MockPublic(u64);
+
+impl IdentifyAccount for MockPublic {
+	type AccountId = MockAccountId;
+	fn into_account(self) -> Self::AccountId {
+		self.0.into()
+	}
+}
+
+impl From<UintAuthorityId> for MockPublic {
+	fn from(id: UintAuthorityId) -> Self {
+		Self(id.0)
+	}
+}
+
+impl From<MockPublic> for UintAuthorityId {
+	fn from(public: MockPublic) -> Self {
+		Self(public.0)
+	}
+}
+
+pub struct MockAuthorityId;
+
+impl AppCrypto<MockPublic, TestSignature> for MockAuthorityId {
+	type RuntimeAppPublic = UintAuthorityId;
+	type GenericPublic = UintAuthorityId;
+	type GenericSignature = TestSignature;
+}
+
+pub type MockExtrinsic = TestXt<RuntimeCall, ()>;
+
+impl SigningTypes for Test {
+	type Public = MockPublic;
+	type Signature = TestSignature;
+}
+
+impl<C> SendTransactionTypes<C> for Test
+where
+	RuntimeCall: From<C>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = MockExtrinsic;
+}
+
+impl<C> CreateSignedTransaction<C> for Test
+where
+	RuntimeCall: From<C>,
+{
+	fn create_transaction<A: AppCrypto<Self::Public, Self::Signature>>(
+		call: RuntimeCall,
+		public: MockPublic,
+		_account: MockAccountId,
+		_nonce: MockIndex,
+	) -> Option<(RuntimeCall, <MockExtrinsic as ExtrinsicT>::SignaturePayload)> {
+		Some((call, (public.0, ())))
+	}
+}
+
 parameter_types! {
 	pub const NftTransferPalletId: PalletId = PalletId(*b"aj/nfttr");
 }
@@ -188,6 +292,10 @@ impl pallet_ajuna_nft_transfer::Config for Test {
 	type ItemId = H256;
 	type ItemConfig = pallet_nfts::ItemConfig;
 	type NftHelper = Nft;
+	type NftUploader = AAvatars;
+	type MaxUploadsPerBlock = frame_support::traits::ConstU32<1>;
+	type UploadOrigin = EnsureRoot<MockAccountId>;
+	type WeightInfo = ();
 }
 
 pub struct ExtBuilder {
@@ -334,6 +442,7 @@ pub fn run_to_block(n: u64) {
 		System::set_block_number(System::block_number() + 1);
 		System::on_initialize(System::block_number());
 		AAvatars::on_initialize(System::block_number());
+		NftTransfer::on_initialize(System::block_number());
 	}
 }
 
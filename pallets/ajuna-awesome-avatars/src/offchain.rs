@@ -0,0 +1,329 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Offchain worker pinning the metadata of prepared avatars to IPFS.
+//!
+//! Avatars prepared via `prepare_avatar` are picked up while their IPFS URL is still empty, their
+//! metadata is posted to the IPFS HTTP endpoint of the node and the resulting URL is submitted
+//! through a signed `prepare_ipfs` transaction. Locked avatars handed over for upload are pinned
+//! the same way along with their owner, and confirmed through a signed `confirm_upload`
+//! transaction. For this to succeed, the keystore of the node must hold the key of the service
+//! account under [`KEY_TYPE`], other keys of that type are never used.
+//!
+//! The endpoint is read from the persistent offchain storage under [`IPFS_ENDPOINT_KEY`], which
+//! can be set with the `offchain_localStorageSet` RPC, and defaults to [`DEFAULT_IPFS_ENDPOINT`].
+
+use crate::{types::*, *};
+use frame_system::offchain::{SendSignedTransaction, Signer, SigningTypes};
+use pallet_ajuna_nft_transfer::traits::NftConvertible;
+use sp_runtime::{
+	offchain::{http, storage::StorageValueRef, Duration, StorageKind},
+	traits::IdentifyAccount,
+	KeyTypeId, RuntimeAppPublic,
+};
+use sp_std::prelude::*;
+
+/// Key type of the service account used by the offchain worker.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"aaav");
+
+/// Offchain storage key holding the IPFS HTTP endpoint.
+pub const IPFS_ENDPOINT_KEY: &[u8] = b"awesome-avatars::ipfs-endpoint";
+
+/// IPFS HTTP endpoint used when none is set in the offchain storage.
+pub const DEFAULT_IPFS_ENDPOINT: &[u8] = b"http://127.0.0.1:5001";
+
+/// Path of the IPFS HTTP API adding and pinning a file.
+pub(crate) const IPFS_ADD_PATH: &[u8] = b"/api/v0/add";
+
+/// Boundary separating the parts of the multipart request sent to IPFS.
+pub(crate) const MULTIPART_BOUNDARY: &[u8] = b"awesome-avatars-metadata";
+pub(crate) const MULTIPART_CONTENT_TYPE: &str =
+	"multipart/form-data; boundary=awesome-avatars-metadata";
+
+/// Maximum number of avatars pinned in a single offchain worker run.
+pub(crate) const MAX_PINS_PER_BLOCK: usize = 5;
+
+/// Number of blocks to wait before pinning an avatar again, e.g. if its transaction got dropped.
+pub(crate) const PIN_RETRY_BLOCKS: u32 = 10;
+
+const PIN_LOCK_PREFIX: &[u8] = b"awesome-avatars::pinned::";
+const UPLOAD_LOCK_PREFIX: &[u8] = b"awesome-avatars::uploaded::";
+const HTTP_DEADLINE_MS: u64 = 5_000;
+const LOG_TARGET: &str = "runtime::awesome-avatars";
+
+type RuntimeAppPublicOf<T> = <<T as Config>::AuthorityId as AppCrypto<
+	<T as SigningTypes>::Public,
+	<T as SigningTypes>::Signature,
+>>::RuntimeAppPublic;
+type GenericPublicOf<T> = <<T as Config>::AuthorityId as AppCrypto<
+	<T as SigningTypes>::Public,
+	<T as SigningTypes>::Signature,
+>>::GenericPublic;
+
+pub mod crypto {
+	use super::KEY_TYPE;
+	use sp_runtime::{
+		app_crypto::{app_crypto, sr25519},
+		MultiSignature, MultiSigner,
+	};
+
+	app_crypto!(sr25519, KEY_TYPE);
+
+	/// Identity of the service account signing the transactions of the offchain worker.
+	pub struct AuthorityId;
+
+	impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for AuthorityId {
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+		type GenericPublic = sp_core::sr25519::Public;
+	}
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum PinError {
+	UnknownAvatar,
+	InvalidEndpoint,
+	Http(http::Error),
+	UnexpectedStatus(u16),
+	InvalidResponse,
+	UrlTooLong,
+}
+
+impl<T: Config> Pallet<T> {
+	/// Pins the avatars waiting for an IPFS URL and submits their URLs.
+	pub(crate) fn pin_prepared_avatars(now: T::BlockNumber) {
+		if !Self::global_configs().nft_transfer.open {
+			return
+		}
+
+		let signer = Self::service_signer();
+		if !signer.can_sign() {
+			log::debug!(target: LOG_TARGET, "No service account key available to pin avatars");
+			return
+		}
+
+		let endpoint = Self::ipfs_endpoint();
+		Preparation::<T>::iter()
+			.filter(|(_, url)| url.is_empty())
+			.filter(|(avatar_id, _)| Self::try_lock_pin(PIN_LOCK_PREFIX, avatar_id, now))
+			.take(MAX_PINS_PER_BLOCK)
+			.for_each(|(avatar_id, _)| match Self::pin_avatar(&endpoint, &avatar_id) {
+				Ok(url) => {
+					let result = signer.send_signed_transaction(|_| Call::prepare_ipfs {
+						avatar_id,
+						url: url.clone(),
+					});
+					if let Some((_, Err(()))) = result {
+						log::error!(
+							target: LOG_TARGET,
+							"Failed to submit IPFS URL of {:?}",
+							avatar_id
+						);
+					}
+				},
+				Err(error) => {
+					log::error!(target: LOG_TARGET, "Failed to pin {:?}: {:?}", avatar_id, error);
+				},
+			});
+	}
+
+	/// Pins the avatars handed over for upload and confirms their uploads.
+	pub(crate) fn pin_pending_uploads(now: T::BlockNumber) {
+		if !Self::global_configs().nft_transfer.open {
+			return
+		}
+
+		let signer = Self::service_signer();
+		if !signer.can_sign() {
+			log::debug!(target: LOG_TARGET, "No service account key available to upload avatars");
+			return
+		}
+
+		let endpoint = Self::ipfs_endpoint();
+		PendingUploads::<T>::iter()
+			.filter(|(avatar_id, _)| Self::try_lock_pin(UPLOAD_LOCK_PREFIX, avatar_id, now))
+			.take(MAX_PINS_PER_BLOCK)
+			.for_each(|(avatar_id, owner)| match Self::pin_upload(&endpoint, &avatar_id, &owner) {
+				Ok(url) => {
+					let result = signer.send_signed_transaction(|_| Call::confirm_upload {
+						avatar_id,
+						url: url.clone(),
+					});
+					if let Some((_, Err(()))) = result {
+						log::error!(
+							target: LOG_TARGET,
+							"Failed to confirm the upload of {:?}",
+							avatar_id
+						);
+					}
+				},
+				Err(error) => {
+					log::error!(
+						target: LOG_TARGET,
+						"Failed to upload {:?}: {:?}",
+						avatar_id,
+						error
+					);
+				},
+			});
+	}
+
+	/// Signer restricted to the key of the service account, so that other keys held under
+	/// [`KEY_TYPE`] never sign the transactions of the offchain worker.
+	fn service_signer() -> Signer<T, T::AuthorityId> {
+		let keys = Self::service_account()
+			.map(|service_account| {
+				RuntimeAppPublicOf::<T>::all()
+					.into_iter()
+					.map(|key| GenericPublicOf::<T>::from(key).into())
+					.filter(|key: &T::Public| key.clone().into_account() == service_account)
+					.collect::<Vec<_>>()
+			})
+			.unwrap_or_default();
+		Signer::any_account().with_filter(keys)
+	}
+
+	/// Posts the metadata of an avatar to IPFS, returning the URL it is pinned under.
+	pub(crate) fn pin_avatar(
+		endpoint: &[u8],
+		avatar_id: &AvatarIdOf<T>,
+	) -> Result<IpfsUrl, PinError> {
+		let (_, avatar) = Self::avatars(avatar_id).ok_or(PinError::UnknownAvatar)?;
+		Self::pin(endpoint, &Self::render_metadata(avatar_id, &avatar))
+	}
+
+	/// Posts an uploaded avatar along with its owner to IPFS, returning the URL it is pinned
+	/// under.
+	pub(crate) fn pin_upload(
+		endpoint: &[u8],
+		avatar_id: &AvatarIdOf<T>,
+		owner: &T::AccountId,
+	) -> Result<IpfsUrl, PinError> {
+		let (_, avatar) = Self::avatars(avatar_id).ok_or(PinError::UnknownAvatar)?;
+		Self::pin(endpoint, &Self::render_upload(avatar_id, &avatar, owner))
+	}
+
+	fn pin(endpoint: &[u8], document: &[u8]) -> Result<IpfsUrl, PinError> {
+		let body = Self::multipart_body(document);
+
+		let url = [endpoint, IPFS_ADD_PATH].concat();
+		let url = sp_std::str::from_utf8(&url).map_err(|_| PinError::InvalidEndpoint)?;
+		let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(HTTP_DEADLINE_MS));
+		let response = http::Request::post(url, vec![body])
+			.add_header("Content-Type", MULTIPART_CONTENT_TYPE)
+			.deadline(deadline)
+			.send()
+			.map_err(|_| PinError::Http(http::Error::IoError))?
+			.try_wait(deadline)
+			.map_err(|_| PinError::Http(http::Error::DeadlineReached))?
+			.map_err(PinError::Http)?;
+		ensure!(response.code == 200, PinError::UnexpectedStatus(response.code));
+
+		let response = response.body().collect::<Vec<u8>>();
+		let hash = Self::parse_ipfs_hash(&response).ok_or(PinError::InvalidResponse)?;
+		let url: [&[u8]; 2] = [b"ipfs://", hash];
+		IpfsUrl::try_from(url.concat()).map_err(|_| PinError::UrlTooLong)
+	}
+
+	/// Renders the JSON metadata of an avatar out of its NFT attributes.
+	pub(crate) fn render_metadata(avatar_id: &AvatarIdOf<T>, avatar: &Avatar) -> Vec<u8> {
+		let mut json = b"{\"avatar_id\":\"".to_vec();
+		push_hex(&mut json, avatar_id.as_ref());
+		json.extend_from_slice(b"\",\"attributes\":[");
+		for (i, (code, value)) in avatar.get_encoded_attributes().iter().enumerate() {
+			if i > 0 {
+				json.push(b',');
+			}
+			json.extend_from_slice(b"{\"code\":");
+			push_decimal(&mut json, *code as u64);
+			json.extend_from_slice(b",\"value\":\"");
+			push_hex(&mut json, value);
+			json.extend_from_slice(b"\"}");
+		}
+		json.extend_from_slice(b"]}");
+		json
+	}
+
+	/// Renders the JSON document of an uploaded avatar, holding its owner and metadata.
+	pub(crate) fn render_upload(
+		avatar_id: &AvatarIdOf<T>,
+		avatar: &Avatar,
+		owner: &T::AccountId,
+	) -> Vec<u8> {
+		let mut json = b"{\"owner\":\"".to_vec();
+		push_hex(&mut json, &owner.encode());
+		json.extend_from_slice(b"\",\"avatar\":");
+		json.extend_from_slice(&Self::render_metadata(avatar_id, avatar));
+		json.push(b'}');
+		json
+	}
+
+	pub(crate) fn multipart_body(metadata: &[u8]) -> Vec<u8> {
+		let parts: [&[u8]; 8] = [
+			b"--",
+			MULTIPART_BOUNDARY,
+			b"\r\nContent-Disposition: form-data; name=\"file\"; filename=\"avatar.json\"\r\n",
+			b"Content-Type: application/json\r\n\r\n",
+			metadata,
+			b"\r\n--",
+			MULTIPART_BOUNDARY,
+			b"--\r\n",
+		];
+		parts.concat()
+	}
+
+	/// Extracts the content identifier out of the response of the IPFS add endpoint.
+	pub(crate) fn parse_ipfs_hash(response: &[u8]) -> Option<&[u8]> {
+		const HASH_KEY: &[u8] = b"\"Hash\":\"";
+		let start = response.windows(HASH_KEY.len()).position(|window| window == HASH_KEY)? +
+			HASH_KEY.len();
+		let len = response[start..].iter().position(|byte| *byte == b'"')?;
+		Some(&response[start..start + len]).filter(|hash| !hash.is_empty())
+	}
+
+	pub(crate) fn ipfs_endpoint() -> Vec<u8> {
+		sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, IPFS_ENDPOINT_KEY)
+			.unwrap_or_else(|| DEFAULT_IPFS_ENDPOINT.to_vec())
+	}
+
+	/// Marks an avatar as being pinned, unless it has been pinned recently.
+	fn try_lock_pin(prefix: &[u8], avatar_id: &AvatarIdOf<T>, now: T::BlockNumber) -> bool {
+		let key = (prefix, avatar_id).encode();
+		StorageValueRef::persistent(&key)
+			.mutate(|pinned_at: Result<Option<T::BlockNumber>, _>| match pinned_at {
+				Ok(Some(pinned_at)) if now < pinned_at.saturating_add(PIN_RETRY_BLOCKS.into()) =>
+					Err(()),
+				_ => Ok(now),
+			})
+			.is_ok()
+	}
+}
+
+fn push_hex(buffer: &mut Vec<u8>, bytes: &[u8]) {
+	const DIGITS: &[u8; 16] = b"0123456789abcdef";
+	buffer.extend_from_slice(b"0x");
+	for byte in bytes {
+		buffer.push(DIGITS[(byte >> 4) as usize]);
+		buffer.push(DIGITS[(byte & 0x0f) as usize]);
+	}
+}
+
+fn push_decimal(buffer: &mut Vec<u8>, number: u64) {
+	if number >= 10 {
+		push_decimal(buffer, number / 10);
+	}
+	buffer.push(b'0' + (number % 10) as u8);
+}
@@ -0,0 +1,75 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{Config, RevealedRandomness};
+use frame_support::{pallet_prelude::*, traits::Randomness};
+use sp_runtime::traits::{Hash, TrailingZeroInput};
+
+/// Source of the entropy used to generate avatars and to roll forges.
+///
+/// Every output is turned directly into DNA and forge rolls, so implementations should be backed
+/// by a source that neither block authors nor callers can predict or influence, such as a VRF
+/// output.
+pub trait RandomnessSource<AccountId, Hash> {
+	/// Returns a random hash for `who`. Consecutive calls must never return the same output, as
+	/// the result is also used to identify newly minted avatars.
+	fn random_hash(subject: &[u8], who: &AccountId) -> Hash;
+}
+
+/// Derives random hashes from a [`Randomness`] implementation combined with the nonce of the
+/// account, which is incremented on each call.
+///
+/// The nonce is public, so the quality of the output is that of `R`. If `R` returns a value the
+/// caller already knows when signing, the caller can compute the outcome offline and spend nonces
+/// on cheap calls until it suits them. `R` must therefore change with every block in a way that
+/// nobody can know before the block is built, such as the VRF output of the relay parent.
+pub struct RandomnessWithNonce<T, R>(PhantomData<(T, R)>);
+
+impl<T, R> RandomnessSource<T::AccountId, T::Hash> for RandomnessWithNonce<T, R>
+where
+	T: frame_system::Config,
+	R: Randomness<T::Hash, T::BlockNumber>,
+{
+	fn random_hash(subject: &[u8], who: &T::AccountId) -> T::Hash {
+		let (seed, _) = R::random(subject);
+		let seed = T::Hash::decode(&mut TrailingZeroInput::new(seed.as_ref()))
+			.expect("input is padded with zeroes; qed");
+		let nonce = frame_system::Pallet::<T>::account_nonce(who);
+		frame_system::Pallet::<T>::inc_account_nonce(who);
+		(seed, &who, nonce.encode()).using_encoded(T::Hashing::hash)
+	}
+}
+
+/// Randomness seeded by a commit-reveal scheme run by the service account of the pallet.
+///
+/// The service account commits to the last hash of a chain `hash(hash(..hash(secret)))` through
+/// `commit_randomness` and reveals the chain backwards through `reveal_randomness`, each value
+/// being checked against the previous one. This keeps block authors from choosing the seed, but
+/// it is NOT unbiasable:
+/// - each revealed seed stays public until the next reveal, so players can precompute their
+///   outcomes with [`RandomnessWithNonce`] in the meantime;
+/// - the service account knows the whole chain in advance.
+///
+/// Only use it on networks without a VRF, where players are trusted, such as test networks.
+/// Until the first reveal, the output only depends on `subject`.
+pub struct CommitRevealRandomness<T>(PhantomData<T>);
+
+impl<T: Config> Randomness<T::Hash, T::BlockNumber> for CommitRevealRandomness<T> {
+	fn random(subject: &[u8]) -> (T::Hash, T::BlockNumber) {
+		let (seed, revealed_at) = RevealedRandomness::<T>::get().unwrap_or_default();
+		((subject, seed).using_encoded(T::Hashing::hash), revealed_at)
+	}
+}
@@ -25,12 +25,36 @@ fn create_avatars(season_id: SeasonId, account: MockAccountId, n: u8) -> Vec<Ava
 			let avatar = Avatar::default().season_id(season_id).dna(&[i; 32]);
 			let avatar_id = H256::random();
 			Avatars::<Test>::insert(avatar_id, (account, avatar));
-			Owners::<Test>::try_append(account, avatar_id).unwrap();
+			AAvatars::add_ownership(&account, &avatar_id);
 			avatar_id
 		})
 		.collect()
 }
 
+/// Returns the avatars still owned by `account` in the order they were minted, as `owners`
+/// orders them by id.
+fn minted_avatar_ids(account: MockAccountId) -> Vec<AvatarIdOf<Test>> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			mock::RuntimeEvent::AAvatars(crate::Event::AvatarsMinted { avatar_ids }) =>
+				Some(avatar_ids),
+			_ => None,
+		})
+		.flatten()
+		.filter(|avatar_id| OwnedAvatars::<Test>::contains_key(account, avatar_id))
+		.collect()
+}
+
+const ASSET_ID: MockAssetId = 7;
+
+fn create_asset(holders: &[(MockAccountId, MockBalance)]) {
+	assert_ok!(Assets::force_create(RuntimeOrigin::root(), ASSET_ID, ALICE, true, 1));
+	for (holder, amount) in holders {
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), ASSET_ID, *holder, *amount));
+	}
+}
+
 mod pallet_accounts {
 	use super::*;
 
@@ -59,6 +83,80 @@ mod pallet_accounts {
 	}
 }
 
+mod genesis {
+	use super::*;
+	use frame_support::traits::GenesisBuild;
+
+	fn build_genesis(config: GenesisConfig<Test>) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+		config.assimilate_storage(&mut t).unwrap();
+		sp_io::TestExternalities::new(t)
+	}
+
+	#[test]
+	fn genesis_config_works() {
+		let season_1 = Season::default().early_start(2).start(3).end(4);
+		let season_2 = Season::default().early_start(5).start(6).end(7);
+		let config = GenesisConfig::<Test> {
+			organizer: Some(ALICE),
+			service_account: Some(BOB),
+			treasurers: vec![(1, CHARLIE), (2, DAVE)],
+			// seasons are sorted before being validated
+			seasons: vec![(2, season_2.clone()), (1, season_1.clone())],
+			free_mints: vec![(BOB, 7), (CHARLIE, 3)],
+			collection_id: Some(369),
+			..Default::default()
+		};
+		let global_config = config.global_config.clone();
+
+		build_genesis(config).execute_with(|| {
+			assert_eq!(AAvatars::current_season_id(), 1);
+			assert_eq!(AAvatars::global_configs(), global_config);
+			assert_eq!(AAvatars::organizer(), Some(ALICE));
+			assert_eq!(AAvatars::service_account(), Some(BOB));
+			assert_eq!(AAvatars::treasurer(1), Some(CHARLIE));
+			assert_eq!(AAvatars::treasurer(2), Some(DAVE));
+			assert_eq!(AAvatars::seasons(1), Some(season_1));
+			assert_eq!(AAvatars::seasons(2), Some(season_2));
+			assert_eq!(AAvatars::accounts(BOB).free_mints, 7);
+			assert_eq!(AAvatars::accounts(CHARLIE).free_mints, 3);
+			assert_eq!(AAvatars::collection_id(), Some(369));
+		});
+	}
+
+	#[test]
+	fn genesis_config_defaults_to_an_empty_game() {
+		build_genesis(GenesisConfig::<Test>::default()).execute_with(|| {
+			assert_eq!(AAvatars::current_season_id(), 1);
+			assert_eq!(AAvatars::organizer(), None);
+			assert_eq!(AAvatars::service_account(), None);
+			assert_eq!(AAvatars::seasons(1), None);
+			assert_eq!(AAvatars::collection_id(), None);
+		});
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid genesis season 2")]
+	fn genesis_config_rejects_overlapping_seasons() {
+		let _ = build_genesis(GenesisConfig::<Test> {
+			seasons: vec![
+				(1, Season::default().early_start(2).start(3).end(10)),
+				(2, Season::default().early_start(5).start(6).end(7)),
+			],
+			..Default::default()
+		});
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid genesis season 1")]
+	fn genesis_config_rejects_invalid_seasons() {
+		let _ = build_genesis(GenesisConfig::<Test> {
+			seasons: vec![(1, Season::default().early_start(3).start(3))],
+			..Default::default()
+		});
+	}
+}
+
 mod organizer {
 	use super::*;
 
@@ -122,6 +220,50 @@ mod organizer {
 			assert_ok!(AAvatars::ensure_organizer(RuntimeOrigin::signed(CHARLIE)));
 		});
 	}
+
+	#[test]
+	fn admin_origins_should_accept_governance_without_organizer() {
+		ExtBuilder::default().existential_deposit(1).build().execute_with(|| {
+			assert_eq!(AAvatars::organizer(), None);
+			assert_ok!(AAvatars::set_season(RuntimeOrigin::root(), 1, Season::default()));
+			assert!(AAvatars::seasons(1).is_some());
+
+			let config = GlobalConfigOf::<Test>::default()
+				.mint_fees_one(2)
+				.mint_fees_three(2)
+				.mint_fees_six(2)
+				.transfer_avatar_transfer_fee(2)
+				.trade_min_fee(2)
+				.account_storage_upgrade_fe(2);
+			assert_ok!(AAvatars::update_global_config(RuntimeOrigin::root(), config.clone()));
+			assert_eq!(AAvatars::global_configs(), config);
+
+			assert_ok!(AAvatars::set_free_mints(RuntimeOrigin::root(), BOB, 7));
+			assert_eq!(AAvatars::accounts(BOB).free_mints, 7);
+		});
+	}
+
+	#[test]
+	fn admin_origins_should_reject_other_accounts() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			assert_ok!(AAvatars::set_season(RuntimeOrigin::signed(ALICE), 1, Season::default()));
+			assert_noop!(
+				AAvatars::set_season(RuntimeOrigin::signed(BOB), 1, Season::default()),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				AAvatars::update_global_config(
+					RuntimeOrigin::signed(BOB),
+					AAvatars::global_configs()
+				),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				AAvatars::set_free_mints(RuntimeOrigin::signed(BOB), BOB, 7),
+				DispatchError::BadOrigin
+			);
+		});
+	}
 }
 
 mod treasury {
@@ -373,3090 +515,6745 @@ mod treasury {
 	}
 }
 
-mod season {
+mod season_rewards {
 	use super::*;
 
-	#[test]
-	fn season_hook_should_work() {
-		let season_1 = Season::default().early_start(2).start(3).end(4);
-		let season_2 = Season::default().early_start(5).start(7).end(10);
-		let season_3 = Season::default().early_start(23).start(37).end(53);
-		let seasons = &[(1, season_1.clone()), (2, season_2.clone()), (3, season_3.clone())];
-
-		ExtBuilder::default().seasons(seasons).build().execute_with(|| {
-			// Check default values at block 1
-			run_to_block(1);
-			assert_eq!(System::block_number(), 1);
-			assert_eq!(AAvatars::current_season_id(), 1);
-			assert_eq!(
-				AAvatars::current_season_status(),
-				SeasonStatus {
-					early: false,
-					active: false,
-					early_ended: false,
-					max_tier_avatars: 0
-				}
-			);
-			assert!(AAvatars::seasons(1).is_some());
-			assert!(AAvatars::seasons(2).is_some());
-			assert!(AAvatars::seasons(3).is_some());
-
-			// Season 1 early start (block 2..3)
-			for n in season_1.early_start..season_1.start {
-				run_to_block(n);
-				assert_eq!(AAvatars::current_season_id(), 1);
-				assert_eq!(
-					AAvatars::current_season_status(),
-					SeasonStatus {
-						early: true,
-						active: false,
-						early_ended: false,
-						max_tier_avatars: 0
-					}
-				);
-			}
-			// Season 1 start (block 3..4)
-			for n in season_1.start..season_2.early_start {
-				run_to_block(n);
-				assert_eq!(AAvatars::current_season_id(), 1);
-				assert_eq!(
-					AAvatars::current_season_status(),
-					SeasonStatus {
-						early: false,
-						active: true,
-						early_ended: false,
-						max_tier_avatars: 0
-					}
-				);
-			}
-
-			// Season 2 early start (block 5..6)
-			for n in season_2.early_start..season_2.start {
-				run_to_block(n);
-				assert_eq!(AAvatars::current_season_id(), 2);
-				assert_eq!(
-					AAvatars::current_season_status(),
-					SeasonStatus {
-						early: true,
-						active: false,
-						early_ended: false,
-						max_tier_avatars: 0
-					}
-				);
-			}
-			// Season 2 start (block 7..9)
-			for n in season_2.start..season_2.end {
-				run_to_block(n);
-				assert_eq!(AAvatars::current_season_id(), 2);
-				assert_eq!(
-					AAvatars::current_season_status(),
-					SeasonStatus {
-						early: false,
-						active: true,
-						early_ended: false,
-						max_tier_avatars: 0
-					}
-				);
-			}
-			// Season 2 end (block 10..22)
-			for n in (season_2.end + 1)..season_3.early_start {
-				run_to_block(n);
-				assert_eq!(AAvatars::current_season_id(), 3);
-				assert_eq!(
-					AAvatars::current_season_status(),
-					SeasonStatus {
-						early: false,
-						active: false,
-						early_ended: false,
-						max_tier_avatars: 0
-					}
-				);
-			}
-
-			// Season 3 early start (block 23..36)
-			for n in season_3.early_start..season_3.start {
-				run_to_block(n);
-				assert_eq!(AAvatars::current_season_id(), 3);
-				assert_eq!(
-					AAvatars::current_season_status(),
-					SeasonStatus {
-						early: true,
-						active: false,
-						early_ended: false,
-						max_tier_avatars: 0
-					}
-				);
-			}
-			// Season 3 start (block 37..53)
-			for n in season_3.start..=season_3.end {
-				run_to_block(n);
-				assert_eq!(AAvatars::current_season_id(), 3);
-				assert_eq!(
-					AAvatars::current_season_status(),
-					SeasonStatus {
-						early: false,
-						active: true,
-						early_ended: false,
-						max_tier_avatars: 0
-					}
-				);
-			}
-			// Season 3 end (block 54..63)
-			for n in (season_3.end + 1)..=(season_3.end + 10) {
-				run_to_block(n);
-				assert_eq!(AAvatars::current_season_id(), 4);
-				assert_eq!(
-					AAvatars::current_season_status(),
-					SeasonStatus {
-						early: false,
-						active: false,
-						early_ended: false,
-						max_tier_avatars: 0
-					}
-				);
-			}
-
-			// No further seasons exist
-			assert!(AAvatars::seasons(AAvatars::current_season_id()).is_none());
-		})
-	}
-
-	#[test]
-	fn season_validate_should_mutate_correctly() {
-		let mut season = Season::default()
-			.tiers(&[RarityTier::Rare, RarityTier::Common, RarityTier::Epic])
-			.single_mint_probs(&[20, 80])
-			.batch_mint_probs(&[60, 40]);
-		assert_ok!(season.validate::<Test>());
-
-		// check for ascending order sort
-		assert_eq!(
-			season.tiers.to_vec(),
-			vec![RarityTier::Common, RarityTier::Rare, RarityTier::Epic]
-		);
-
-		// check for descending order sort
-		assert_eq!(season.single_mint_probs.to_vec(), vec![80, 20]);
-		assert_eq!(season.batch_mint_probs.to_vec(), vec![60, 40]);
+	fn rewards(forge_shares: &[RewardShare], max_tier_shares: &[RewardShare]) -> SeasonRewards {
+		SeasonRewards {
+			forge_shares: forge_shares.to_vec().try_into().unwrap(),
+			max_tier_shares: max_tier_shares.to_vec().try_into().unwrap(),
+		}
 	}
 
 	#[test]
-	fn set_season_should_work() {
-		ExtBuilder::default()
-			.organizer(ALICE)
-			.seasons(&[(1, Season::default())])
-			.build()
-			.execute_with(|| {
-				let season_1 = Season::default().early_start(1).start(5).end(10);
-				assert_ok!(AAvatars::set_season(RuntimeOrigin::signed(ALICE), 1, season_1.clone()));
-				assert_eq!(AAvatars::seasons(1), Some(season_1.clone()));
-				System::assert_last_event(mock::RuntimeEvent::AAvatars(
-					crate::Event::UpdatedSeason { season_id: 1, season: season_1 },
-				));
-
-				let season_2 = Season::default().early_start(11).start(12).end(13);
-				assert_ok!(AAvatars::set_season(RuntimeOrigin::signed(ALICE), 2, season_2.clone()));
-				assert_eq!(AAvatars::seasons(2), Some(season_2.clone()));
-				System::assert_last_event(mock::RuntimeEvent::AAvatars(
-					crate::Event::UpdatedSeason { season_id: 2, season: season_2 },
-				));
-			});
+	fn set_season_rewards_should_work() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			let rewards = rewards(&[30, 20, 10], &[40]);
+			assert_ok!(AAvatars::set_season_rewards(
+				RuntimeOrigin::signed(ALICE),
+				1,
+				rewards.clone()
+			));
+			assert_eq!(AAvatars::season_rewards(1), Some(rewards.clone()));
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(
+				crate::Event::SeasonRewardsSet { season_id: 1, rewards },
+			));
+		});
 	}
 
 	#[test]
-	fn set_season_should_reject_non_organizer_calls() {
+	fn set_season_rewards_should_reject_non_organizer_calls() {
 		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
 			assert_noop!(
-				AAvatars::set_season(RuntimeOrigin::signed(BOB), 7357, Season::default()),
+				AAvatars::set_season_rewards(RuntimeOrigin::signed(BOB), 1, rewards(&[], &[])),
 				DispatchError::BadOrigin
 			);
 		});
 	}
 
 	#[test]
-	fn set_season_should_reject_when_early_start_is_earlier_than_previous_season_end() {
-		let season_1 = Season::default();
-		ExtBuilder::default()
-			.organizer(ALICE)
-			.seasons(&[(1, season_1.clone())])
-			.build()
-			.execute_with(|| {
-				for i in 0..season_1.end {
-					let season_2 = Season::default().early_start(i).start(i + 1).end(i + 2);
-					assert!(season_2.early_start <= season_1.end);
-					assert_noop!(
-						AAvatars::set_season(RuntimeOrigin::signed(ALICE), 2, season_2),
-						Error::<Test>::EarlyStartTooEarly
-					);
-				}
-			});
-	}
-
-	#[test]
-	fn set_season_should_reject_when_early_start_is_earlier_than_or_equal_to_start() {
+	fn set_season_rewards_should_reject_incorrect_shares() {
 		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
-			for i in 3..6 {
-				let new_season = Season::default().early_start(i).start(3).end(10);
-				assert!(new_season.early_start >= new_season.start);
-				assert_noop!(
-					AAvatars::set_season(RuntimeOrigin::signed(ALICE), 1, new_season),
-					Error::<Test>::EarlyStartTooLate
-				);
-			}
+			assert_noop!(
+				AAvatars::set_season_rewards(
+					RuntimeOrigin::signed(ALICE),
+					1,
+					rewards(&[50, 30], &[21])
+				),
+				Error::<Test>::IncorrectRewardShares
+			);
 		});
 	}
 
 	#[test]
-	fn set_season_should_reject_when_start_is_later_than_end() {
+	fn set_season_rewards_should_reject_finished_seasons() {
 		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
-			let new_season = Season::default().early_start(11).start(12).end(10);
-			assert!(new_season.early_start < new_season.start);
+			CurrentSeasonId::<Test>::put(3);
 			assert_noop!(
-				AAvatars::set_season(RuntimeOrigin::signed(ALICE), 1, new_season),
-				Error::<Test>::SeasonStartTooLate
+				AAvatars::set_season_rewards(RuntimeOrigin::signed(ALICE), 2, rewards(&[], &[])),
+				Error::<Test>::SeasonAlreadyFinished
 			);
 		});
 	}
 
 	#[test]
-	fn set_season_should_reject_when_rarity_tier_is_duplicated() {
-		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
-			for duplicated_rarity_tiers in [
-				vec![RarityTier::Common, RarityTier::Common],
-				vec![RarityTier::Common, RarityTier::Common, RarityTier::Legendary],
-			] {
-				assert_noop!(
-					AAvatars::set_season(
-						RuntimeOrigin::signed(ALICE),
-						1,
-						Season::default().tiers(&duplicated_rarity_tiers)
-					),
-					Error::<Test>::DuplicatedRarityTier
-				);
+	fn rank_forger_should_keep_the_top_forgers() {
+		ExtBuilder::default().build().execute_with(|| {
+			let size = MaxLeaderboardSize::get() as MockAccountId;
+			for player in 1..=size {
+				AAvatars::rank_forger(1, &player, player as Stat);
 			}
+			let leaderboard = AAvatars::forge_leaderboard(1);
+			assert_eq!(leaderboard.len() as MockAccountId, size);
+			assert_eq!(leaderboard[0], (size, size as Stat));
+			assert_eq!(leaderboard[leaderboard.len() - 1], (1, 1));
+
+			// a player below the leaderboard is left out
+			AAvatars::rank_forger(1, &(size + 1), 0);
+			assert!(!AAvatars::forge_leaderboard(1).iter().any(|(p, _)| *p == size + 1));
+
+			// a player forging again moves up without duplicates, pushing out the last one
+			AAvatars::rank_forger(1, &(size + 1), 5);
+			AAvatars::rank_forger(1, &(size + 1), 6);
+			let leaderboard = AAvatars::forge_leaderboard(1);
+			assert_eq!(leaderboard.len() as MockAccountId, size);
+			assert_eq!(leaderboard.iter().filter(|(p, _)| *p == size + 1).count(), 1);
+			assert!(!leaderboard.iter().any(|(p, _)| *p == 1));
+			assert!(leaderboard.windows(2).all(|pair| pair[0].1 >= pair[1].1));
 		});
 	}
 
 	#[test]
-	fn set_season_should_reject_when_sum_of_rarity_chance_is_incorrect() {
-		let tiers = &[RarityTier::Common, RarityTier::Uncommon, RarityTier::Legendary];
-		let season_0 = Season::default().tiers(tiers);
-		let season_1 = Season::default().tiers(tiers);
-		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
-			for incorrect_percentages in [vec![12, 39], vec![123, 10], vec![83, 1, 43]] {
-				for season in [
-					season_0.clone().single_mint_probs(&incorrect_percentages),
-					season_1.clone().single_mint_probs(&incorrect_percentages),
-				] {
-					assert_noop!(
-						AAvatars::set_season(RuntimeOrigin::signed(ALICE), 1, season),
-						Error::<Test>::IncorrectRarityPercentages
-					);
-				}
+	fn rank_max_tier_forger_should_keep_the_first_players() {
+		ExtBuilder::default().build().execute_with(|| {
+			let size = MaxLeaderboardSize::get() as MockAccountId;
+			for player in 1..=size + 1 {
+				AAvatars::rank_max_tier_forger(1, &player, player as MockBlockNumber);
 			}
+			AAvatars::rank_max_tier_forger(1, &1, 100);
+
+			let leaderboard = AAvatars::max_tier_leaderboard(1);
+			assert_eq!(
+				leaderboard.to_vec(),
+				(1..=size).map(|player| (player, player as MockBlockNumber)).collect::<Vec<_>>()
+			);
 		});
 	}
 
 	#[test]
-	fn set_season_should_reject_when_season_to_update_ends_after_next_season_start() {
-		let season_1 = Season::default().early_start(1).start(5).end(10);
-		let season_2 = Season::default().early_start(11).start(15).end(20);
+	fn forge_should_rank_players_on_leaderboards() {
+		let season = Season::default()
+			.tiers(&[RarityTier::Common, RarityTier::Legendary])
+			.max_components(8)
+			.max_variations(6)
+			.max_tier_forges(5);
+
+		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
+			run_to_block(season.early_start);
+
+			let avatar_ids = [0x02, 0x03, 0x03, 0x03].map(|last| {
+				let dna = [0x41, 0x42, 0x43, 0x44, 0x45, 0x44, 0x43, last];
+				let avatar_id = H256::random();
+				Avatars::<Test>::insert(avatar_id, (BOB, Avatar::default().season_id(1).dna(&dna)));
+				AAvatars::add_ownership(&BOB, &avatar_id);
+				avatar_id
+			});
+
+			assert_ok!(AAvatars::forge(
+				RuntimeOrigin::signed(BOB),
+				avatar_ids[0],
+				avatar_ids[1..].to_vec()
+			));
+			assert_eq!(AAvatars::forge_leaderboard(1).to_vec(), vec![(BOB, 1)]);
+			assert_eq!(AAvatars::max_tier_leaderboard(1).to_vec(), vec![(BOB, season.early_start)]);
+		});
+	}
+
+	#[test]
+	fn season_rewards_should_be_awarded_and_claimed() {
+		let season = Season::default().early_start(5).start(10).end(15);
+		let pot = 10_000;
 
 		ExtBuilder::default()
 			.organizer(ALICE)
-			.seasons(&[(1, season_1), (2, season_2.clone())])
+			.seasons(&[(1, season.clone())])
+			.balances(&[
+				(ALICE, MockExistentialDeposit::get()),
+				(BOB, MockExistentialDeposit::get()),
+				(CHARLIE, MockExistentialDeposit::get()),
+				(DAVE, MockExistentialDeposit::get()),
+			])
 			.build()
 			.execute_with(|| {
-				let season_1_update = Season::default().early_start(1).start(5).end(14);
-				assert!(season_1_update.end > season_2.early_start);
+				Treasurer::<Test>::insert(1, DAVE);
+				Treasury::<Test>::insert(1, pot);
+				let _ = Balances::deposit_creating(&AAvatars::treasury_account_id(), pot);
+
+				assert_ok!(AAvatars::set_season_rewards(
+					RuntimeOrigin::signed(ALICE),
+					1,
+					rewards(&[30, 20, 10], &[25])
+				));
+				AAvatars::rank_forger(1, &BOB, 3);
+				AAvatars::rank_forger(1, &CHARLIE, 2);
+				AAvatars::rank_max_tier_forger(1, &CHARLIE, 7);
+
+				run_to_block(season.start);
 				assert_noop!(
-					AAvatars::set_season(RuntimeOrigin::signed(ALICE), 1, season_1_update),
-					Error::<Test>::SeasonEndTooLate
+					AAvatars::claim_season_reward(RuntimeOrigin::signed(BOB), 1),
+					Error::<Test>::CannotClaimDuringSeason
+				);
+
+				// the third forge share is left unawarded with only two players ranked
+				run_to_block(season.end + 1);
+				assert_eq!(AAvatars::unclaimed_rewards(1, BOB), 3_000);
+				assert_eq!(AAvatars::unclaimed_rewards(1, CHARLIE), 2_000 + 2_500);
+				assert_eq!(AAvatars::treasury(1), pot - 7_500);
+				System::assert_has_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::SeasonRewardsAwarded { season_id: 1, amount: 7_500 },
+				));
+
+				assert_ok!(AAvatars::claim_season_reward(RuntimeOrigin::signed(CHARLIE), 1));
+				assert_eq!(Balances::free_balance(CHARLIE), MockExistentialDeposit::get() + 4_500);
+				assert_eq!(AAvatars::unclaimed_rewards(1, CHARLIE), 0);
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::SeasonRewardClaimed {
+						season_id: 1,
+						player: CHARLIE,
+						amount: 4_500,
+					},
+				));
+				assert_noop!(
+					AAvatars::claim_season_reward(RuntimeOrigin::signed(CHARLIE), 1),
+					Error::<Test>::CannotClaimZero
 				);
+
+				// the treasurer can only claim what's left
+				assert_ok!(AAvatars::claim_treasury(RuntimeOrigin::signed(DAVE), 1));
+				assert_eq!(Balances::free_balance(DAVE), MockExistentialDeposit::get() + 2_500);
+				assert_ok!(AAvatars::claim_season_reward(RuntimeOrigin::signed(BOB), 1));
+				assert_eq!(Balances::free_balance(BOB), MockExistentialDeposit::get() + 3_000);
 			});
 	}
+}
+
+mod payment_asset {
+	use super::*;
 
 	#[test]
-	fn set_season_should_reject_season_id_underflow() {
+	fn set_payment_asset_works() {
 		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
-			assert_noop!(
-				AAvatars::set_season(
-					RuntimeOrigin::signed(ALICE),
-					SeasonId::MIN,
-					Season::default()
-				),
-				ArithmeticError::Underflow
-			);
+			let rate = ConversionRate { native: 10, asset: 3 };
+			assert_ok!(AAvatars::set_payment_asset(
+				RuntimeOrigin::signed(ALICE),
+				ASSET_ID,
+				Some(rate)
+			));
+			assert_eq!(AAvatars::payment_assets(ASSET_ID), Some(rate));
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(
+				crate::Event::PaymentAssetSet { asset_id: ASSET_ID, rate: Some(rate) },
+			));
+
+			assert_ok!(AAvatars::set_payment_asset(RuntimeOrigin::signed(ALICE), ASSET_ID, None));
+			assert_eq!(AAvatars::payment_assets(ASSET_ID), None);
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(
+				crate::Event::PaymentAssetSet { asset_id: ASSET_ID, rate: None },
+			));
 		});
 	}
 
 	#[test]
-	fn set_season_should_reject_season_id_overflow() {
+	fn set_payment_asset_rejects_non_organizer_calls() {
 		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
 			assert_noop!(
-				AAvatars::set_season(
-					RuntimeOrigin::signed(ALICE),
-					SeasonId::MAX,
-					Season::default()
+				AAvatars::set_payment_asset(
+					RuntimeOrigin::signed(BOB),
+					ASSET_ID,
+					Some(ConversionRate { native: 1, asset: 1 })
 				),
-				ArithmeticError::Overflow
+				DispatchError::BadOrigin
 			);
 		});
 	}
 
 	#[test]
-	fn set_season_should_reject_out_of_bound_variations() {
+	fn set_payment_asset_rejects_zero_rates() {
 		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
-			for (season, error) in [
-				(Season::default().max_variations(0), Error::<Test>::MaxVariationsTooLow),
-				(Season::default().max_variations(1), Error::<Test>::MaxVariationsTooLow),
-				(Season::default().max_variations(16), Error::<Test>::MaxVariationsTooHigh),
-				(Season::default().max_variations(100), Error::<Test>::MaxVariationsTooHigh),
-			] {
-				assert_noop!(AAvatars::set_season(RuntimeOrigin::signed(ALICE), 1, season), error);
+			for rate in
+				[ConversionRate { native: 0, asset: 1 }, ConversionRate { native: 1, asset: 0 }]
+			{
+				assert_noop!(
+					AAvatars::set_payment_asset(RuntimeOrigin::signed(ALICE), ASSET_ID, Some(rate)),
+					Error::<Test>::InvalidConversionRate
+				);
 			}
 		});
 	}
 
 	#[test]
-	fn set_season_should_reject_out_of_bound_components_bounds() {
-		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
-			for (season, error) in [
-				(Season::default().max_components(0), Error::<Test>::MaxComponentsTooLow),
-				(Season::default().max_components(1), Error::<Test>::MaxComponentsTooLow),
-				(Season::default().max_components(33), Error::<Test>::MaxComponentsTooHigh),
-				(Season::default().max_components(100), Error::<Test>::MaxComponentsTooHigh),
-			] {
-				assert_noop!(AAvatars::set_season(RuntimeOrigin::signed(ALICE), 1, season), error);
-			}
+	fn set_fee_asset_works() {
+		ExtBuilder::default().build().execute_with(|| {
+			PaymentAssets::<Test>::insert(ASSET_ID, ConversionRate { native: 1, asset: 1 });
+			assert_ok!(AAvatars::set_fee_asset(RuntimeOrigin::signed(BOB), Some(ASSET_ID)));
+			assert_eq!(AAvatars::fee_assets(BOB), Some(ASSET_ID));
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(crate::Event::FeeAssetSet {
+				player: BOB,
+				asset_id: Some(ASSET_ID),
+			}));
+
+			assert_ok!(AAvatars::set_fee_asset(RuntimeOrigin::signed(BOB), None));
+			assert_eq!(AAvatars::fee_assets(BOB), None);
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(crate::Event::FeeAssetSet {
+				player: BOB,
+				asset_id: None,
+			}));
 		});
 	}
 
 	#[test]
-	fn set_season_should_reject_when_season_ids_are_not_sequential() {
+	fn set_fee_asset_rejects_unsupported_assets() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_noop!(
+				AAvatars::set_fee_asset(RuntimeOrigin::signed(BOB), Some(ASSET_ID)),
+				Error::<Test>::UnsupportedPaymentAsset
+			);
+		});
+	}
+
+	#[test]
+	fn mint_pays_fees_in_the_fee_asset() {
+		let season = Season::default().end(20);
+		let mint_fees = MintFees { one: 1_000, three: 2_000, six: 3_000 };
+		let native_balance = MockExistentialDeposit::get() + 10_000;
 		ExtBuilder::default()
 			.organizer(ALICE)
-			.seasons(&[(1, Season::default())])
+			.seasons(&[(1, season.clone())])
+			.mint_fees(mint_fees)
+			.balances(&[(BOB, native_balance)])
 			.build()
 			.execute_with(|| {
+				run_to_block(season.start);
+				create_asset(&[(BOB, 1_000)]);
+				let rate = ConversionRate { native: 10, asset: 3 };
+				assert_ok!(AAvatars::set_payment_asset(
+					RuntimeOrigin::signed(ALICE),
+					ASSET_ID,
+					Some(rate)
+				));
+				assert_ok!(AAvatars::set_fee_asset(RuntimeOrigin::signed(BOB), Some(ASSET_ID)));
+
+				assert_ok!(AAvatars::mint(
+					RuntimeOrigin::signed(BOB),
+					MintOption { mint_type: MintType::Normal, count: MintPackSize::Three }
+				));
+				assert_eq!(Balances::free_balance(BOB), native_balance);
+				assert_eq!(Assets::balance(ASSET_ID, BOB), 1_000 - 300);
+				assert_eq!(Assets::balance(ASSET_ID, AAvatars::treasury_account_id()), 600);
+				assert_eq!(AAvatars::asset_treasury(1, ASSET_ID), 300);
+				assert_eq!(AAvatars::treasury(1), 0);
+
+				// the fee asset is no longer accepted
+				assert_ok!(AAvatars::set_payment_asset(
+					RuntimeOrigin::signed(ALICE),
+					ASSET_ID,
+					None
+				));
+				run_to_block(season.start + 5);
 				assert_noop!(
-					AAvatars::set_season(RuntimeOrigin::signed(ALICE), 3, Season::default()),
-					Error::<Test>::NonSequentialSeasonId,
+					AAvatars::mint(
+						RuntimeOrigin::signed(BOB),
+						MintOption { mint_type: MintType::Normal, count: MintPackSize::One }
+					),
+					Error::<Test>::UnsupportedPaymentAsset
 				);
+
+				// paying in the native currency again
+				assert_ok!(AAvatars::set_fee_asset(RuntimeOrigin::signed(BOB), None));
+				assert_ok!(AAvatars::mint(
+					RuntimeOrigin::signed(BOB),
+					MintOption { mint_type: MintType::Normal, count: MintPackSize::One }
+				));
+				assert_eq!(Balances::free_balance(BOB), native_balance - 1_000);
+				assert_eq!(AAvatars::treasury(1), 1_000);
 			});
 	}
-}
-
-mod config {
-	use super::*;
 
 	#[test]
-	fn update_global_config_should_work() {
+	fn mint_rounds_fee_asset_amounts_up() {
+		let season = Season::default();
 		ExtBuilder::default()
-			.existential_deposit(1)
 			.organizer(ALICE)
+			.seasons(&[(1, season.clone())])
+			.mint_fees(MintFees { one: 1_000, three: 2_000, six: 3_000 })
 			.build()
 			.execute_with(|| {
-				let config = GlobalConfigOf::<Test>::default()
-					.mint_fees_one(2)
-					.mint_fees_three(2)
-					.mint_fees_six(2)
-					.transfer_avatar_transfer_fee(2)
-					.trade_min_fee(2)
-					.account_storage_upgrade_fe(2);
-
-				assert_ok!(AAvatars::update_global_config(
-					RuntimeOrigin::signed(ALICE),
-					config.clone()
-				));
-				System::assert_last_event(mock::RuntimeEvent::AAvatars(
-					crate::Event::UpdatedGlobalConfig(config),
+				run_to_block(season.start);
+				create_asset(&[(BOB, 10)]);
+				PaymentAssets::<Test>::insert(ASSET_ID, ConversionRate { native: 3_000, asset: 1 });
+				FeeAssets::<Test>::insert(BOB, ASSET_ID);
+				assert_ok!(AAvatars::mint(
+					RuntimeOrigin::signed(BOB),
+					MintOption { mint_type: MintType::Normal, count: MintPackSize::One }
 				));
+				assert_eq!(Assets::balance(ASSET_ID, BOB), 10 - 1);
+				assert_eq!(AAvatars::asset_treasury(1, ASSET_ID), 1);
 			});
 	}
 
 	#[test]
-	fn update_global_config_should_reject_non_organizer_calls() {
-		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
-			assert_noop!(
-				AAvatars::update_global_config(
-					RuntimeOrigin::signed(BOB),
-					GlobalConfigOf::<Test>::default()
-				),
-				DispatchError::BadOrigin
-			);
-		});
+	fn mint_rejects_insufficient_fee_asset_balance() {
+		let season = Season::default();
+		ExtBuilder::default()
+			.organizer(ALICE)
+			.seasons(&[(1, season.clone())])
+			.mint_fees(MintFees { one: 1_000, three: 2_000, six: 3_000 })
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				create_asset(&[(BOB, 10)]);
+				PaymentAssets::<Test>::insert(ASSET_ID, ConversionRate { native: 1, asset: 1 });
+				FeeAssets::<Test>::insert(BOB, ASSET_ID);
+				assert_noop!(
+					AAvatars::mint(
+						RuntimeOrigin::signed(BOB),
+						MintOption { mint_type: MintType::Normal, count: MintPackSize::One }
+					),
+					pallet_assets::Error::<Test>::BalanceLow
+				);
+			});
 	}
 
 	#[test]
-	fn update_global_config_should_reject_fees_lower_than_existential_deposit() {
+	fn claim_asset_treasury_works() {
+		let season = Season::default();
 		ExtBuilder::default()
-			.existential_deposit(333)
-			.organizer(CHARLIE)
+			.seasons(&[(1, season.clone())])
+			.balances(&[(BOB, MockExistentialDeposit::get())])
 			.build()
 			.execute_with(|| {
-				for config in [
-					GlobalConfigOf::<Test>::default().mint_fees_one(12),
-					GlobalConfigOf::<Test>::default().mint_fees_three(34),
-					GlobalConfigOf::<Test>::default().mint_fees_six(56),
-					GlobalConfigOf::<Test>::default().transfer_avatar_transfer_fee(78),
-					GlobalConfigOf::<Test>::default().trade_min_fee(91),
-					GlobalConfigOf::<Test>::default().account_storage_upgrade_fe(99),
-					GlobalConfigOf::<Test>::default()
-						.mint_fees_one(999)
-						.mint_fees_three(999)
-						.mint_fees_six(1)
-						.transfer_avatar_transfer_fee(999)
-						.trade_min_fee(2)
-						.account_storage_upgrade_fe(999),
-				] {
-					assert_noop!(
-						AAvatars::update_global_config(RuntimeOrigin::signed(CHARLIE), config),
-						Error::<Test>::TooLowFees
-					);
-				}
+				create_asset(&[(AAvatars::treasury_account_id(), 333)]);
+				AssetTreasury::<Test>::insert(1, ASSET_ID, 333);
+				Treasurer::<Test>::insert(1, BOB);
+				assert_noop!(
+					AAvatars::claim_asset_treasury(RuntimeOrigin::signed(BOB), 1, ASSET_ID),
+					Error::<Test>::CannotClaimDuringSeason
+				);
+
+				run_to_block(season.end + 1);
+				assert_noop!(
+					AAvatars::claim_asset_treasury(RuntimeOrigin::signed(CHARLIE), 1, ASSET_ID),
+					DispatchError::BadOrigin
+				);
+				assert_ok!(AAvatars::claim_asset_treasury(RuntimeOrigin::signed(BOB), 1, ASSET_ID));
+				assert_eq!(AAvatars::asset_treasury(1, ASSET_ID), 0);
+				assert_eq!(Assets::balance(ASSET_ID, BOB), 333);
+				assert_eq!(Assets::balance(ASSET_ID, AAvatars::treasury_account_id()), 0);
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::AssetTreasuryClaimed {
+						season_id: 1,
+						asset_id: ASSET_ID,
+						treasurer: BOB,
+						amount: 333,
+					},
+				));
+
+				assert_noop!(
+					AAvatars::claim_asset_treasury(RuntimeOrigin::signed(BOB), 1, ASSET_ID),
+					Error::<Test>::CannotClaimZero
+				);
 			});
 	}
 }
 
-mod minting {
+mod season {
 	use super::*;
-	use frame_support::traits::Currency;
 
 	#[test]
-	fn ensure_for_mint_works() {
-		let season = Season::default().early_start(10).start(20).end(30);
+	fn season_hook_should_work() {
+		let season_1 = Season::default().early_start(2).start(3).end(4);
+		let season_2 = Season::default().early_start(5).start(7).end(10);
+		let season_3 = Season::default().early_start(23).start(37).end(53);
+		let seasons = &[(1, season_1.clone()), (2, season_2.clone()), (3, season_3.clone())];
 
-		ExtBuilder::default()
-			.seasons(&[(1, season.clone())])
-			.free_mints(&[(ALICE, 42)])
-			.build()
-			.execute_with(|| {
-				// Outside a season, both mints are unavailable.
-				for n in 0..season.early_start {
-					run_to_block(n);
-					assert_eq!(
-						AAvatars::current_season_status(),
-						SeasonStatus {
-							active: false,
-							early: false,
-							early_ended: false,
-							max_tier_avatars: 0
-						}
-					);
-					for mint_type in [MintType::Normal, MintType::Free] {
-						assert_noop!(
-							AAvatars::ensure_for_mint(&ALICE, &mint_type),
-							Error::<Test>::SeasonClosed
-						);
-					}
+		ExtBuilder::default().seasons(seasons).build().execute_with(|| {
+			// Check default values at block 1
+			run_to_block(1);
+			assert_eq!(System::block_number(), 1);
+			assert_eq!(AAvatars::current_season_id(), 1);
+			assert_eq!(
+				AAvatars::current_season_status(),
+				SeasonStatus {
+					early: false,
+					active: false,
+					early_ended: false,
+					max_tier_avatars: 0
 				}
+			);
+			assert!(AAvatars::seasons(1).is_some());
+			assert!(AAvatars::seasons(2).is_some());
+			assert!(AAvatars::seasons(3).is_some());
 
-				// At early start, both mints are available for whitelisted accounts.
-				for n in season.early_start..season.start {
-					run_to_block(n);
-					assert!(AAvatars::current_season_status().early);
-					for mint_type in [MintType::Normal, MintType::Free] {
-						assert_ok!(AAvatars::ensure_for_mint(&ALICE, &mint_type), 42);
+			// Season 1 early start (block 2..3)
+			for n in season_1.early_start..season_1.start {
+				run_to_block(n);
+				assert_eq!(AAvatars::current_season_id(), 1);
+				assert_eq!(
+					AAvatars::current_season_status(),
+					SeasonStatus {
+						early: true,
+						active: false,
+						early_ended: false,
+						max_tier_avatars: 0
 					}
-				}
-				// At early start, only free mint is available for non-whitelisted accounts.
-				for n in season.early_start..season.start {
-					run_to_block(n);
-					assert!(AAvatars::current_season_status().early);
-					assert_noop!(
-						AAvatars::ensure_for_mint(&BOB, &MintType::Normal),
-						Error::<Test>::SeasonClosed
-					);
-					assert_ok!(AAvatars::ensure_for_mint(&BOB, &MintType::Free), 0);
-				}
-
-				// At official start, both mints are available for all accounts.
-				for n in season.start..=season.end {
-					run_to_block(n);
-					assert!(AAvatars::current_season_status().active);
-					for mint_type in [MintType::Normal, MintType::Free] {
-						assert_ok!(AAvatars::ensure_for_mint(&ALICE, &mint_type), 42);
-						assert_ok!(AAvatars::ensure_for_mint(&BOB, &mint_type), 0);
+				);
+			}
+			// Season 1 start (block 3..4)
+			for n in season_1.start..season_2.early_start {
+				run_to_block(n);
+				assert_eq!(AAvatars::current_season_id(), 1);
+				assert_eq!(
+					AAvatars::current_season_status(),
+					SeasonStatus {
+						early: false,
+						active: true,
+						early_ended: false,
+						max_tier_avatars: 0
 					}
-				}
+				);
+			}
 
-				// At premature end, only free mint is available for all accounts.
-				for n in season.start..=season.end {
-					run_to_block(n);
-					CurrentSeasonStatus::<Test>::mutate(|status| status.early_ended = true);
-					assert_noop!(
-						AAvatars::ensure_for_mint(&ALICE, &MintType::Normal),
-						Error::<Test>::PrematureSeasonEnd
-					);
-					assert_ok!(AAvatars::ensure_for_mint(&ALICE, &MintType::Free), 42);
-					assert_noop!(
-						AAvatars::ensure_for_mint(&BOB, &MintType::Normal),
-						Error::<Test>::PrematureSeasonEnd
-					);
-					assert_ok!(AAvatars::ensure_for_mint(&BOB, &MintType::Free), 0);
-					CurrentSeasonStatus::<Test>::mutate(|status| status.early_ended = false);
-				}
+			// Season 2 early start (block 5..6)
+			for n in season_2.early_start..season_2.start {
+				run_to_block(n);
+				assert_eq!(AAvatars::current_season_id(), 2);
+				assert_eq!(
+					AAvatars::current_season_status(),
+					SeasonStatus {
+						early: true,
+						active: false,
+						early_ended: false,
+						max_tier_avatars: 0
+					}
+				);
+			}
+			// Season 2 start (block 7..9)
+			for n in season_2.start..season_2.end {
+				run_to_block(n);
+				assert_eq!(AAvatars::current_season_id(), 2);
+				assert_eq!(
+					AAvatars::current_season_status(),
+					SeasonStatus {
+						early: false,
+						active: true,
+						early_ended: false,
+						max_tier_avatars: 0
+					}
+				);
+			}
+			// Season 2 end (block 10..22)
+			for n in (season_2.end + 1)..season_3.early_start {
+				run_to_block(n);
+				assert_eq!(AAvatars::current_season_id(), 3);
+				assert_eq!(
+					AAvatars::current_season_status(),
+					SeasonStatus {
+						early: false,
+						active: false,
+						early_ended: false,
+						max_tier_avatars: 0
+					}
+				);
+			}
 
-				// At season end, both mints are unavailable for all accounts.
-				for n in season.end + 1..(season.end + 5) {
-					run_to_block(n);
-					assert_eq!(
-						AAvatars::current_season_status(),
-						SeasonStatus {
-							active: false,
-							early: false,
-							early_ended: false,
-							max_tier_avatars: 0
-						}
-					);
-					for mint_type in [MintType::Normal, MintType::Free] {
-						assert_noop!(
-							AAvatars::ensure_for_mint(&ALICE, &mint_type),
-							Error::<Test>::SeasonClosed
-						);
-						assert_noop!(
-							AAvatars::ensure_for_mint(&BOB, &mint_type),
-							Error::<Test>::SeasonClosed
-						);
+			// Season 3 early start (block 23..36)
+			for n in season_3.early_start..season_3.start {
+				run_to_block(n);
+				assert_eq!(AAvatars::current_season_id(), 3);
+				assert_eq!(
+					AAvatars::current_season_status(),
+					SeasonStatus {
+						early: true,
+						active: false,
+						early_ended: false,
+						max_tier_avatars: 0
 					}
-				}
-			});
+				);
+			}
+			// Season 3 start (block 37..53)
+			for n in season_3.start..=season_3.end {
+				run_to_block(n);
+				assert_eq!(AAvatars::current_season_id(), 3);
+				assert_eq!(
+					AAvatars::current_season_status(),
+					SeasonStatus {
+						early: false,
+						active: true,
+						early_ended: false,
+						max_tier_avatars: 0
+					}
+				);
+			}
+			// Season 3 end (block 54..63)
+			for n in (season_3.end + 1)..=(season_3.end + 10) {
+				run_to_block(n);
+				assert_eq!(AAvatars::current_season_id(), 4);
+				assert_eq!(
+					AAvatars::current_season_status(),
+					SeasonStatus {
+						early: false,
+						active: false,
+						early_ended: false,
+						max_tier_avatars: 0
+					}
+				);
+			}
+
+			// No further seasons exist
+			assert!(AAvatars::seasons(AAvatars::current_season_id()).is_none());
+		})
 	}
 
 	#[test]
-	fn mint_should_work() {
-		let season_1 = Season::default().early_start(3).start(5).end(20).max_components(7);
-		let season_2 = Season::default().early_start(23).start(35).end(40).max_components(17);
+	fn season_validate_should_mutate_correctly() {
+		let mut season = Season::default()
+			.tiers(&[RarityTier::Rare, RarityTier::Common, RarityTier::Epic])
+			.single_mint_probs(&[20, 80])
+			.batch_mint_probs(&[60, 40]);
+		assert_ok!(season.validate::<Test>());
 
-		let expected_nonce_increment = 1 as MockIndex;
-		let fees = MintFees { one: 12, three: 34, six: 56 };
-		let mint_cooldown = 1;
+		// check for ascending order sort
+		assert_eq!(
+			season.tiers.to_vec(),
+			vec![RarityTier::Common, RarityTier::Rare, RarityTier::Epic]
+		);
 
-		let mut initial_balance = fees.one + fees.three + fees.six + MockExistentialDeposit::get();
-		let mut initial_treasury_balance = 0;
-		let mut initial_free_mints = 12;
+		// check for descending order sort
+		assert_eq!(season.single_mint_probs.to_vec(), vec![80, 20]);
+		assert_eq!(season.batch_mint_probs.to_vec(), vec![60, 40]);
+	}
 
+	#[test]
+	fn set_season_should_work() {
 		ExtBuilder::default()
-			.seasons(&[(1, season_1.clone()), (2, season_2)])
-			.mint_fees(fees)
-			.mint_cooldown(mint_cooldown)
-			.balances(&[(ALICE, initial_balance)])
-			.free_mints(&[(ALICE, initial_free_mints)])
+			.organizer(ALICE)
+			.seasons(&[(1, Season::default())])
 			.build()
 			.execute_with(|| {
-				for mint_type in [MintType::Normal, MintType::Free] {
-					let mut expected_nonce = 0;
-					let mut owned_avatar_count = 0;
-					let mut season_minted_count = 0;
-					let mut minted_count = 0;
+				let season_1 = Season::default().early_start(1).start(5).end(10);
+				assert_ok!(AAvatars::set_season(RuntimeOrigin::signed(ALICE), 1, season_1.clone()));
+				assert_eq!(AAvatars::seasons(1), Some(season_1.clone()));
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::UpdatedSeason { season_id: 1, season: season_1 },
+				));
 
-					System::set_block_number(1);
-					CurrentSeasonId::<Test>::set(1);
-					SeasonStats::<Test>::mutate(1, ALICE, |info| info.minted = 0);
-					SeasonStats::<Test>::mutate(2, ALICE, |info| info.minted = 0);
-					Owners::<Test>::remove(ALICE);
-					Accounts::<Test>::mutate(ALICE, |account| {
-						account.stats.mint.first = 0;
-						account.stats.mint.last = 0;
-					});
+				let season_2 = Season::default().early_start(11).start(12).end(13);
+				assert_ok!(AAvatars::set_season(RuntimeOrigin::signed(ALICE), 2, season_2.clone()));
+				assert_eq!(AAvatars::seasons(2), Some(season_2.clone()));
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::UpdatedSeason { season_id: 2, season: season_2 },
+				));
+			});
+	}
 
-					// initial checks
-					match mint_type {
-						MintType::Normal =>
-							assert_eq!(Balances::total_balance(&ALICE), initial_balance),
-						MintType::Free =>
-							assert_eq!(AAvatars::accounts(ALICE).free_mints, initial_free_mints),
-					}
-					assert_eq!(System::account_nonce(ALICE), expected_nonce);
-					assert_eq!(AAvatars::owners(ALICE).len(), owned_avatar_count);
-					assert!(!AAvatars::current_season_status().active);
+	#[test]
+	fn set_season_should_reject_non_organizer_calls() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			assert_noop!(
+				AAvatars::set_season(RuntimeOrigin::signed(BOB), 7357, Season::default()),
+				DispatchError::BadOrigin
+			);
+		});
+	}
 
-					// single mint
-					run_to_block(season_1.start);
-					assert_ok!(AAvatars::mint(
-						RuntimeOrigin::signed(ALICE),
-						MintOption { count: MintPackSize::One, mint_type: mint_type.clone() }
-					));
-					match mint_type {
-						MintType::Normal => {
-							initial_balance -= fees.fee_for(&MintPackSize::One);
-							initial_treasury_balance += fees.fee_for(&MintPackSize::One);
+	#[test]
+	fn set_season_should_reject_when_early_start_is_earlier_than_previous_season_end() {
+		let season_1 = Season::default();
+		ExtBuilder::default()
+			.organizer(ALICE)
+			.seasons(&[(1, season_1.clone())])
+			.build()
+			.execute_with(|| {
+				for i in 0..season_1.end {
+					let season_2 = Season::default().early_start(i).start(i + 1).end(i + 2);
+					assert!(season_2.early_start <= season_1.end);
+					assert_noop!(
+						AAvatars::set_season(RuntimeOrigin::signed(ALICE), 2, season_2),
+						Error::<Test>::EarlyStartTooEarly
+					);
+				}
+			});
+	}
 
-							assert_eq!(Balances::total_balance(&ALICE), initial_balance);
-							assert_eq!(AAvatars::treasury(1), initial_treasury_balance);
-						},
-						MintType::Free => {
-							initial_free_mints -= MintPackSize::One as MintCount;
-							assert_eq!(AAvatars::accounts(ALICE).free_mints, initial_free_mints);
-						},
-					}
-					expected_nonce += expected_nonce_increment;
-					owned_avatar_count += 1;
-					minted_count += 1;
-					season_minted_count += 1;
-					assert_eq!(System::account_nonce(ALICE), expected_nonce);
-					assert_eq!(AAvatars::owners(ALICE).len(), owned_avatar_count);
-					assert_eq!(AAvatars::season_stats(1, ALICE).minted, season_minted_count);
-					assert!(AAvatars::current_season_status().active);
-					assert_eq!(AAvatars::accounts(ALICE).stats.mint.first, season_1.start);
-					System::assert_has_event(mock::RuntimeEvent::AAvatars(
-						crate::Event::SeasonStarted(1),
-					));
-					System::assert_last_event(mock::RuntimeEvent::AAvatars(
-						crate::Event::AvatarsMinted {
-							avatar_ids: vec![AAvatars::owners(ALICE)[0]],
-						},
-					));
+	#[test]
+	fn set_season_should_reject_when_early_start_is_earlier_than_or_equal_to_start() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			for i in 3..6 {
+				let new_season = Season::default().early_start(i).start(3).end(10);
+				assert!(new_season.early_start >= new_season.start);
+				assert_noop!(
+					AAvatars::set_season(RuntimeOrigin::signed(ALICE), 1, new_season),
+					Error::<Test>::EarlyStartTooLate
+				);
+			}
+		});
+	}
 
-					// batch mint: three
-					run_to_block(System::block_number() + 1 + mint_cooldown);
-					assert_ok!(AAvatars::mint(
+	#[test]
+	fn set_season_should_reject_when_start_is_later_than_end() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			let new_season = Season::default().early_start(11).start(12).end(10);
+			assert!(new_season.early_start < new_season.start);
+			assert_noop!(
+				AAvatars::set_season(RuntimeOrigin::signed(ALICE), 1, new_season),
+				Error::<Test>::SeasonStartTooLate
+			);
+		});
+	}
+
+	#[test]
+	fn set_season_should_reject_when_rarity_tier_is_duplicated() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			for duplicated_rarity_tiers in [
+				vec![RarityTier::Common, RarityTier::Common],
+				vec![RarityTier::Common, RarityTier::Common, RarityTier::Legendary],
+			] {
+				assert_noop!(
+					AAvatars::set_season(
 						RuntimeOrigin::signed(ALICE),
-						MintOption { count: MintPackSize::Three, mint_type: mint_type.clone() }
-					));
-					match mint_type {
-						MintType::Normal => {
-							initial_balance -= fees.fee_for(&MintPackSize::Three);
-							initial_treasury_balance += fees.fee_for(&MintPackSize::Three);
+						1,
+						Season::default().tiers(&duplicated_rarity_tiers)
+					),
+					Error::<Test>::DuplicatedRarityTier
+				);
+			}
+		});
+	}
 
-							assert_eq!(Balances::total_balance(&ALICE), initial_balance);
-							assert_eq!(AAvatars::treasury(1), initial_treasury_balance);
-						},
-						MintType::Free => {
-							initial_free_mints -= MintPackSize::Three as MintCount;
-							assert_eq!(AAvatars::accounts(ALICE).free_mints, initial_free_mints);
-						},
-					}
-					expected_nonce += expected_nonce_increment * 3;
-					owned_avatar_count += 3;
-					minted_count += 3;
-					season_minted_count += 3;
-					assert_eq!(System::account_nonce(ALICE), expected_nonce);
-					assert_eq!(AAvatars::owners(ALICE).len(), owned_avatar_count);
-					assert_eq!(AAvatars::season_stats(1, ALICE).minted, season_minted_count);
-					assert!(AAvatars::current_season_status().active);
-					System::assert_last_event(mock::RuntimeEvent::AAvatars(
-						crate::Event::AvatarsMinted {
-							avatar_ids: AAvatars::owners(ALICE)[1..=3].to_vec(),
-						},
-					));
+	#[test]
+	fn set_season_should_reject_when_sum_of_rarity_chance_is_incorrect() {
+		let tiers = &[RarityTier::Common, RarityTier::Uncommon, RarityTier::Legendary];
+		let season_0 = Season::default().tiers(tiers);
+		let season_1 = Season::default().tiers(tiers);
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			for incorrect_percentages in [vec![12, 39], vec![123, 10], vec![83, 1, 43]] {
+				for season in [
+					season_0.clone().single_mint_probs(&incorrect_percentages),
+					season_1.clone().single_mint_probs(&incorrect_percentages),
+				] {
+					assert_noop!(
+						AAvatars::set_season(RuntimeOrigin::signed(ALICE), 1, season),
+						Error::<Test>::IncorrectRarityPercentages
+					);
+				}
+			}
+		});
+	}
 
-					// batch mint: six
-					run_to_block(System::block_number() + 1 + mint_cooldown);
-					assert_eq!(System::account_nonce(ALICE), expected_nonce);
-					assert_ok!(AAvatars::mint(
-						RuntimeOrigin::signed(ALICE),
-						MintOption { count: MintPackSize::Six, mint_type: mint_type.clone() }
-					));
-					match mint_type {
-						MintType::Normal => {
-							initial_balance -= fees.fee_for(&MintPackSize::Six);
-							initial_treasury_balance += fees.fee_for(&MintPackSize::Six);
+	#[test]
+	fn set_season_should_reject_when_season_to_update_ends_after_next_season_start() {
+		let season_1 = Season::default().early_start(1).start(5).end(10);
+		let season_2 = Season::default().early_start(11).start(15).end(20);
+
+		ExtBuilder::default()
+			.organizer(ALICE)
+			.seasons(&[(1, season_1), (2, season_2.clone())])
+			.build()
+			.execute_with(|| {
+				let season_1_update = Season::default().early_start(1).start(5).end(14);
+				assert!(season_1_update.end > season_2.early_start);
+				assert_noop!(
+					AAvatars::set_season(RuntimeOrigin::signed(ALICE), 1, season_1_update),
+					Error::<Test>::SeasonEndTooLate
+				);
+			});
+	}
+
+	#[test]
+	fn set_season_should_reject_season_id_underflow() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			assert_noop!(
+				AAvatars::set_season(
+					RuntimeOrigin::signed(ALICE),
+					SeasonId::MIN,
+					Season::default()
+				),
+				ArithmeticError::Underflow
+			);
+		});
+	}
+
+	#[test]
+	fn set_season_should_reject_season_id_overflow() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			assert_noop!(
+				AAvatars::set_season(
+					RuntimeOrigin::signed(ALICE),
+					SeasonId::MAX,
+					Season::default()
+				),
+				ArithmeticError::Overflow
+			);
+		});
+	}
+
+	#[test]
+	fn set_season_should_reject_out_of_bound_variations() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			for (season, error) in [
+				(Season::default().max_variations(0), Error::<Test>::MaxVariationsTooLow),
+				(Season::default().max_variations(1), Error::<Test>::MaxVariationsTooLow),
+				(Season::default().max_variations(16), Error::<Test>::MaxVariationsTooHigh),
+				(Season::default().max_variations(100), Error::<Test>::MaxVariationsTooHigh),
+			] {
+				assert_noop!(AAvatars::set_season(RuntimeOrigin::signed(ALICE), 1, season), error);
+			}
+		});
+	}
+
+	#[test]
+	fn set_season_should_reject_out_of_bound_components_bounds() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			for (season, error) in [
+				(Season::default().max_components(0), Error::<Test>::MaxComponentsTooLow),
+				(Season::default().max_components(1), Error::<Test>::MaxComponentsTooLow),
+				(Season::default().max_components(33), Error::<Test>::MaxComponentsTooHigh),
+				(Season::default().max_components(100), Error::<Test>::MaxComponentsTooHigh),
+			] {
+				assert_noop!(AAvatars::set_season(RuntimeOrigin::signed(ALICE), 1, season), error);
+			}
+		});
+	}
+
+	#[test]
+	fn set_season_should_reject_when_season_ids_are_not_sequential() {
+		ExtBuilder::default()
+			.organizer(ALICE)
+			.seasons(&[(1, Season::default())])
+			.build()
+			.execute_with(|| {
+				assert_noop!(
+					AAvatars::set_season(RuntimeOrigin::signed(ALICE), 3, Season::default()),
+					Error::<Test>::NonSequentialSeasonId,
+				);
+			});
+	}
+
+	#[test]
+	fn add_to_allowlist_should_work() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			let accounts = vec![BOB, CHARLIE];
+			assert_ok!(AAvatars::add_to_allowlist(
+				RuntimeOrigin::signed(ALICE),
+				2,
+				accounts.clone().try_into().unwrap(),
+				5
+			));
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(
+				crate::Event::AddedToAllowlist { season_id: 2, accounts, early_mints: 5 },
+			));
+			assert_eq!(AAvatars::allowlist(2, BOB), Some(5));
+			assert_eq!(AAvatars::allowlist(2, CHARLIE), Some(5));
+			assert_eq!(AAvatars::allowlist(1, BOB), None);
+
+			// adding again replaces the remaining early mints
+			assert_ok!(AAvatars::add_to_allowlist(
+				RuntimeOrigin::signed(ALICE),
+				2,
+				vec![BOB].try_into().unwrap(),
+				1
+			));
+			assert_eq!(AAvatars::allowlist(2, BOB), Some(1));
+		});
+	}
+
+	#[test]
+	fn remove_from_allowlist_should_work() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			Allowlists::<Test>::insert(1, BOB, 3);
+			Allowlists::<Test>::insert(1, CHARLIE, 3);
+
+			let accounts = vec![BOB, DAVE];
+			assert_ok!(AAvatars::remove_from_allowlist(
+				RuntimeOrigin::signed(ALICE),
+				1,
+				accounts.clone().try_into().unwrap()
+			));
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(
+				crate::Event::RemovedFromAllowlist { season_id: 1, accounts },
+			));
+			assert_eq!(AAvatars::allowlist(1, BOB), None);
+			assert_eq!(AAvatars::allowlist(1, CHARLIE), Some(3));
+		});
+	}
+
+	#[test]
+	fn allowlist_should_reject_non_organizer_calls() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			assert_noop!(
+				AAvatars::add_to_allowlist(
+					RuntimeOrigin::signed(BOB),
+					1,
+					vec![BOB].try_into().unwrap(),
+					5
+				),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				AAvatars::remove_from_allowlist(
+					RuntimeOrigin::signed(BOB),
+					1,
+					vec![BOB].try_into().unwrap()
+				),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+}
+
+mod config {
+	use super::*;
+
+	#[test]
+	fn update_global_config_should_work() {
+		ExtBuilder::default()
+			.existential_deposit(1)
+			.organizer(ALICE)
+			.build()
+			.execute_with(|| {
+				let config = GlobalConfigOf::<Test>::default()
+					.mint_fees_one(2)
+					.mint_fees_three(2)
+					.mint_fees_six(2)
+					.transfer_avatar_transfer_fee(2)
+					.trade_min_fee(2)
+					.account_storage_upgrade_fe(2);
+
+				assert_ok!(AAvatars::update_global_config(
+					RuntimeOrigin::signed(ALICE),
+					config.clone()
+				));
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::UpdatedGlobalConfig(config),
+				));
+			});
+	}
+
+	#[test]
+	fn update_global_config_should_reject_non_organizer_calls() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			assert_noop!(
+				AAvatars::update_global_config(
+					RuntimeOrigin::signed(BOB),
+					GlobalConfigOf::<Test>::default()
+				),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn update_global_config_should_reject_fees_lower_than_existential_deposit() {
+		ExtBuilder::default()
+			.existential_deposit(333)
+			.organizer(CHARLIE)
+			.build()
+			.execute_with(|| {
+				for config in [
+					GlobalConfigOf::<Test>::default().mint_fees_one(12),
+					GlobalConfigOf::<Test>::default().mint_fees_three(34),
+					GlobalConfigOf::<Test>::default().mint_fees_six(56),
+					GlobalConfigOf::<Test>::default().transfer_avatar_transfer_fee(78),
+					GlobalConfigOf::<Test>::default().trade_min_fee(91),
+					GlobalConfigOf::<Test>::default().account_storage_upgrade_fe(99),
+					GlobalConfigOf::<Test>::default()
+						.mint_fees_one(999)
+						.mint_fees_three(999)
+						.mint_fees_six(1)
+						.transfer_avatar_transfer_fee(999)
+						.trade_min_fee(2)
+						.account_storage_upgrade_fe(999),
+				] {
+					assert_noop!(
+						AAvatars::update_global_config(RuntimeOrigin::signed(CHARLIE), config),
+						Error::<Test>::TooLowFees
+					);
+				}
+			});
+	}
+
+	#[test]
+	fn patch_global_config_should_only_change_patched_fields() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			let mut expected_config = AAvatars::global_configs();
+			expected_config.trade.percent_fee = 5;
+			expected_config.mint.open = false;
+
+			assert_ok!(AAvatars::patch_global_config(
+				RuntimeOrigin::signed(ALICE),
+				GlobalConfigPatch {
+					trade_percent_fee: Some(5),
+					mint_open: Some(false),
+					..Default::default()
+				}
+			));
+			assert_eq!(AAvatars::global_configs(), expected_config);
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(
+				crate::Event::UpdatedGlobalConfig(expected_config),
+			));
+		});
+	}
+
+	#[test]
+	fn patch_global_config_should_reject_fees_lower_than_existential_deposit() {
+		ExtBuilder::default()
+			.existential_deposit(333)
+			.organizer(ALICE)
+			.build()
+			.execute_with(|| {
+				for patch in [
+					GlobalConfigPatch {
+						mint_fees: Some(MintFees { one: 999, three: 999, six: 1 }),
+						..Default::default()
+					},
+					GlobalConfigPatch { trade_min_fee: Some(2), ..Default::default() },
+					GlobalConfigPatch { storage_upgrade_fee: Some(333), ..Default::default() },
+				] {
+					assert_noop!(
+						AAvatars::patch_global_config(RuntimeOrigin::signed(ALICE), patch.clone()),
+						Error::<Test>::TooLowFees
+					);
+					assert_noop!(
+						AAvatars::schedule_config_patch(RuntimeOrigin::signed(ALICE), 10, patch),
+						Error::<Test>::TooLowFees
+					);
+				}
+			});
+	}
+
+	#[test]
+	fn patch_global_config_should_reject_non_organizer_calls() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			assert_noop!(
+				AAvatars::patch_global_config(RuntimeOrigin::signed(BOB), Default::default()),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				AAvatars::schedule_config_patch(RuntimeOrigin::signed(BOB), 10, Default::default()),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				AAvatars::cancel_config_patch(RuntimeOrigin::signed(BOB), 10, 0),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn scheduled_config_patches_should_take_effect_at_their_block() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			let close_trade = GlobalConfigPatch { trade_open: Some(false), ..Default::default() };
+			let close_forge = GlobalConfigPatch { forge_open: Some(false), ..Default::default() };
+			let reopen_trade = GlobalConfigPatch { trade_open: Some(true), ..Default::default() };
+			for (at, patch) in [(5, close_trade.clone()), (5, close_forge), (8, reopen_trade)] {
+				assert_ok!(AAvatars::schedule_config_patch(
+					RuntimeOrigin::signed(ALICE),
+					at,
+					patch
+				));
+			}
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(
+				crate::Event::ConfigPatchScheduled {
+					patch_id: 2,
+					at: 8,
+					patch: GlobalConfigPatch { trade_open: Some(true), ..Default::default() },
+				},
+			));
+			assert_eq!(AAvatars::scheduled_config_patches(5).first(), Some(&(0, close_trade)));
+
+			run_to_block(4);
+			assert!(AAvatars::global_configs().trade.open);
+			assert!(AAvatars::global_configs().forge.open);
+
+			run_to_block(5);
+			let config = AAvatars::global_configs();
+			assert!(!config.trade.open);
+			assert!(!config.forge.open);
+			System::assert_has_event(mock::RuntimeEvent::AAvatars(
+				crate::Event::ConfigPatchApplied { patch_id: 1, config },
+			));
+			assert!(AAvatars::scheduled_config_patches(5).is_empty());
+
+			run_to_block(8);
+			assert!(AAvatars::global_configs().trade.open);
+			assert!(!AAvatars::global_configs().forge.open);
+		});
+	}
+
+	#[test]
+	fn schedule_config_patch_should_reject_blocks_not_in_the_future() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			run_to_block(3);
+			for at in [1, 3] {
+				assert_noop!(
+					AAvatars::schedule_config_patch(
+						RuntimeOrigin::signed(ALICE),
+						at,
+						Default::default()
+					),
+					Error::<Test>::ConfigPatchInThePast
+				);
+			}
+		});
+	}
+
+	#[test]
+	fn schedule_config_patch_should_reject_too_many_patches_per_block() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			for _ in 0..MaxConfigPatchesPerBlock::get() {
+				assert_ok!(AAvatars::schedule_config_patch(
+					RuntimeOrigin::signed(ALICE),
+					10,
+					Default::default()
+				));
+			}
+			assert_noop!(
+				AAvatars::schedule_config_patch(
+					RuntimeOrigin::signed(ALICE),
+					10,
+					Default::default()
+				),
+				Error::<Test>::TooManyConfigPatches
+			);
+			assert_ok!(AAvatars::schedule_config_patch(
+				RuntimeOrigin::signed(ALICE),
+				11,
+				Default::default()
+			));
+		});
+	}
+
+	#[test]
+	fn cancel_config_patch_should_work() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			let close_mint = GlobalConfigPatch { mint_open: Some(false), ..Default::default() };
+			for _ in 0..2 {
+				assert_ok!(AAvatars::schedule_config_patch(
+					RuntimeOrigin::signed(ALICE),
+					5,
+					close_mint.clone()
+				));
+			}
+
+			assert_ok!(AAvatars::cancel_config_patch(RuntimeOrigin::signed(ALICE), 5, 0));
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(
+				crate::Event::ConfigPatchCancelled { patch_id: 0, at: 5 },
+			));
+			assert_eq!(AAvatars::scheduled_config_patches(5).to_vec(), vec![(1, close_mint)]);
+
+			assert_ok!(AAvatars::cancel_config_patch(RuntimeOrigin::signed(ALICE), 5, 1));
+			assert!(!ScheduledConfigPatches::<Test>::contains_key(5));
+
+			run_to_block(5);
+			assert!(AAvatars::global_configs().mint.open);
+		});
+	}
+
+	#[test]
+	fn cancel_config_patch_should_reject_unknown_patches() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			assert_ok!(AAvatars::schedule_config_patch(
+				RuntimeOrigin::signed(ALICE),
+				5,
+				Default::default()
+			));
+			for (at, patch_id) in [(5, 1), (6, 0)] {
+				assert_noop!(
+					AAvatars::cancel_config_patch(RuntimeOrigin::signed(ALICE), at, patch_id),
+					Error::<Test>::UnknownConfigPatch
+				);
+			}
+		});
+	}
+}
+
+mod minting {
+	use super::*;
+	use frame_support::traits::Currency;
+
+	#[test]
+	fn ensure_for_mint_works() {
+		let season = Season::default().early_start(10).start(20).end(30);
+
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.free_mints(&[(ALICE, 42)])
+			.build()
+			.execute_with(|| {
+				Allowlists::<Test>::insert(1, ALICE, 1);
+
+				// Outside a season, both mints are unavailable.
+				for n in 0..season.early_start {
+					run_to_block(n);
+					assert_eq!(
+						AAvatars::current_season_status(),
+						SeasonStatus {
+							active: false,
+							early: false,
+							early_ended: false,
+							max_tier_avatars: 0
+						}
+					);
+					for mint_type in [MintType::Normal, MintType::Free] {
+						assert_noop!(
+							AAvatars::ensure_for_mint(&ALICE, &mint_type),
+							Error::<Test>::SeasonClosed
+						);
+					}
+				}
+
+				// At early start, both mints are available for allowlisted accounts.
+				for n in season.early_start..season.start {
+					run_to_block(n);
+					assert!(AAvatars::current_season_status().early);
+					for mint_type in [MintType::Normal, MintType::Free] {
+						assert_ok!(AAvatars::ensure_for_mint(&ALICE, &mint_type), 42);
+					}
+				}
+				// At early start, only free mint is available for accounts not allowlisted, even
+				// with free mints.
+				Accounts::<Test>::mutate(BOB, |account| account.free_mints = 1);
+				for n in season.early_start..season.start {
+					run_to_block(n);
+					assert!(AAvatars::current_season_status().early);
+					assert_noop!(
+						AAvatars::ensure_for_mint(&BOB, &MintType::Normal),
+						Error::<Test>::SeasonClosed
+					);
+					assert_ok!(AAvatars::ensure_for_mint(&BOB, &MintType::Free), 1);
+				}
+				Accounts::<Test>::mutate(BOB, |account| account.free_mints = 0);
+
+				// At official start, both mints are available for all accounts.
+				for n in season.start..=season.end {
+					run_to_block(n);
+					assert!(AAvatars::current_season_status().active);
+					for mint_type in [MintType::Normal, MintType::Free] {
+						assert_ok!(AAvatars::ensure_for_mint(&ALICE, &mint_type), 42);
+						assert_ok!(AAvatars::ensure_for_mint(&BOB, &mint_type), 0);
+					}
+				}
+
+				// At premature end, only free mint is available for all accounts.
+				for n in season.start..=season.end {
+					run_to_block(n);
+					CurrentSeasonStatus::<Test>::mutate(|status| status.early_ended = true);
+					assert_noop!(
+						AAvatars::ensure_for_mint(&ALICE, &MintType::Normal),
+						Error::<Test>::PrematureSeasonEnd
+					);
+					assert_ok!(AAvatars::ensure_for_mint(&ALICE, &MintType::Free), 42);
+					assert_noop!(
+						AAvatars::ensure_for_mint(&BOB, &MintType::Normal),
+						Error::<Test>::PrematureSeasonEnd
+					);
+					assert_ok!(AAvatars::ensure_for_mint(&BOB, &MintType::Free), 0);
+					CurrentSeasonStatus::<Test>::mutate(|status| status.early_ended = false);
+				}
+
+				// At season end, both mints are unavailable for all accounts.
+				for n in season.end + 1..(season.end + 5) {
+					run_to_block(n);
+					assert_eq!(
+						AAvatars::current_season_status(),
+						SeasonStatus {
+							active: false,
+							early: false,
+							early_ended: false,
+							max_tier_avatars: 0
+						}
+					);
+					for mint_type in [MintType::Normal, MintType::Free] {
+						assert_noop!(
+							AAvatars::ensure_for_mint(&ALICE, &mint_type),
+							Error::<Test>::SeasonClosed
+						);
+						assert_noop!(
+							AAvatars::ensure_for_mint(&BOB, &mint_type),
+							Error::<Test>::SeasonClosed
+						);
+					}
+				}
+			});
+	}
+
+	#[test]
+	fn mint_should_work() {
+		let season_1 = Season::default().early_start(3).start(5).end(20).max_components(7);
+		let season_2 = Season::default().early_start(23).start(35).end(40).max_components(17);
+
+		let expected_nonce_increment = 1 as MockIndex;
+		let fees = MintFees { one: 12, three: 34, six: 56 };
+		let mint_cooldown = 1;
+
+		let mut initial_balance = fees.one + fees.three + fees.six + MockExistentialDeposit::get();
+		let mut initial_treasury_balance = 0;
+		let mut initial_free_mints = 12;
+
+		ExtBuilder::default()
+			.seasons(&[(1, season_1.clone()), (2, season_2)])
+			.mint_fees(fees)
+			.mint_cooldown(mint_cooldown)
+			.balances(&[(ALICE, initial_balance)])
+			.free_mints(&[(ALICE, initial_free_mints)])
+			.build()
+			.execute_with(|| {
+				for mint_type in [MintType::Normal, MintType::Free] {
+					let mut expected_nonce = 0;
+					let mut owned_avatar_count = 0;
+					let mut season_minted_count = 0;
+					let mut minted_count = 0;
+
+					System::set_block_number(1);
+					CurrentSeasonId::<Test>::set(1);
+					SeasonStats::<Test>::mutate(1, ALICE, |info| info.minted = 0);
+					SeasonStats::<Test>::mutate(2, ALICE, |info| info.minted = 0);
+					let _ = OwnedAvatars::<Test>::clear_prefix(ALICE, u32::MAX, None);
+					OwnedAvatarCounts::<Test>::remove(ALICE);
+					Accounts::<Test>::mutate(ALICE, |account| {
+						account.stats.mint.first = 0;
+						account.stats.mint.last = 0;
+					});
+
+					// initial checks
+					match mint_type {
+						MintType::Normal =>
+							assert_eq!(Balances::total_balance(&ALICE), initial_balance),
+						MintType::Free =>
+							assert_eq!(AAvatars::accounts(ALICE).free_mints, initial_free_mints),
+					}
+					assert_eq!(System::account_nonce(ALICE), expected_nonce);
+					assert_eq!(AAvatars::owners(&ALICE).len(), owned_avatar_count);
+					assert!(!AAvatars::current_season_status().active);
+
+					// single mint
+					run_to_block(season_1.start);
+					assert_ok!(AAvatars::mint(
+						RuntimeOrigin::signed(ALICE),
+						MintOption { count: MintPackSize::One, mint_type: mint_type.clone() }
+					));
+					match mint_type {
+						MintType::Normal => {
+							initial_balance -= fees.fee_for(&MintPackSize::One);
+							initial_treasury_balance += fees.fee_for(&MintPackSize::One);
+
+							assert_eq!(Balances::total_balance(&ALICE), initial_balance);
+							assert_eq!(AAvatars::treasury(1), initial_treasury_balance);
+						},
+						MintType::Free => {
+							initial_free_mints -= MintPackSize::One as MintCount;
+							assert_eq!(AAvatars::accounts(ALICE).free_mints, initial_free_mints);
+						},
+					}
+					expected_nonce += expected_nonce_increment;
+					owned_avatar_count += 1;
+					minted_count += 1;
+					season_minted_count += 1;
+					assert_eq!(System::account_nonce(ALICE), expected_nonce);
+					assert_eq!(AAvatars::owners(&ALICE).len(), owned_avatar_count);
+					assert_eq!(AAvatars::season_stats(1, ALICE).minted, season_minted_count);
+					assert!(AAvatars::current_season_status().active);
+					assert_eq!(AAvatars::accounts(ALICE).stats.mint.first, season_1.start);
+					System::assert_has_event(mock::RuntimeEvent::AAvatars(
+						crate::Event::SeasonStarted(1),
+					));
+					System::assert_last_event(mock::RuntimeEvent::AAvatars(
+						crate::Event::AvatarsMinted {
+							avatar_ids: vec![minted_avatar_ids(ALICE)[0]],
+						},
+					));
+
+					// batch mint: three
+					run_to_block(System::block_number() + 1 + mint_cooldown);
+					assert_ok!(AAvatars::mint(
+						RuntimeOrigin::signed(ALICE),
+						MintOption { count: MintPackSize::Three, mint_type: mint_type.clone() }
+					));
+					match mint_type {
+						MintType::Normal => {
+							initial_balance -= fees.fee_for(&MintPackSize::Three);
+							initial_treasury_balance += fees.fee_for(&MintPackSize::Three);
+
+							assert_eq!(Balances::total_balance(&ALICE), initial_balance);
+							assert_eq!(AAvatars::treasury(1), initial_treasury_balance);
+						},
+						MintType::Free => {
+							initial_free_mints -= MintPackSize::Three as MintCount;
+							assert_eq!(AAvatars::accounts(ALICE).free_mints, initial_free_mints);
+						},
+					}
+					expected_nonce += expected_nonce_increment * 3;
+					owned_avatar_count += 3;
+					minted_count += 3;
+					season_minted_count += 3;
+					assert_eq!(System::account_nonce(ALICE), expected_nonce);
+					assert_eq!(AAvatars::owners(&ALICE).len(), owned_avatar_count);
+					assert_eq!(AAvatars::season_stats(1, ALICE).minted, season_minted_count);
+					assert!(AAvatars::current_season_status().active);
+					System::assert_last_event(mock::RuntimeEvent::AAvatars(
+						crate::Event::AvatarsMinted {
+							avatar_ids: minted_avatar_ids(ALICE)[1..=3].to_vec(),
+						},
+					));
+
+					// batch mint: six
+					run_to_block(System::block_number() + 1 + mint_cooldown);
+					assert_eq!(System::account_nonce(ALICE), expected_nonce);
+					assert_ok!(AAvatars::mint(
+						RuntimeOrigin::signed(ALICE),
+						MintOption { count: MintPackSize::Six, mint_type: mint_type.clone() }
+					));
+					match mint_type {
+						MintType::Normal => {
+							initial_balance -= fees.fee_for(&MintPackSize::Six);
+							initial_treasury_balance += fees.fee_for(&MintPackSize::Six);
+
+							assert_eq!(Balances::total_balance(&ALICE), initial_balance);
+							assert_eq!(AAvatars::treasury(1), initial_treasury_balance);
+						},
+						MintType::Free => {
+							initial_free_mints -= MintPackSize::Six as MintCount;
+							assert_eq!(AAvatars::accounts(ALICE).free_mints, initial_free_mints);
+						},
+					}
+					expected_nonce += expected_nonce_increment * 6;
+					owned_avatar_count += 6;
+					minted_count += 6;
+					season_minted_count += 6;
+					assert_eq!(System::account_nonce(ALICE), expected_nonce);
+					assert_eq!(AAvatars::owners(&ALICE).len(), owned_avatar_count);
+					assert_eq!(AAvatars::season_stats(1, ALICE).minted, season_minted_count);
+					assert!(AAvatars::current_season_status().active);
+					System::assert_last_event(mock::RuntimeEvent::AAvatars(
+						crate::Event::AvatarsMinted {
+							avatar_ids: minted_avatar_ids(ALICE)[4..=9].to_vec(),
+						},
+					));
+
+					match mint_type {
+						MintType::Normal => {
+							// mint one more avatar to trigger reaping
+							assert_eq!(
+								Balances::total_balance(&ALICE),
+								MockExistentialDeposit::get()
+							);
+							run_to_block(System::block_number() + mint_cooldown);
+							assert_ok!(AAvatars::mint(
+								RuntimeOrigin::signed(ALICE),
+								MintOption {
+									count: MintPackSize::One,
+									mint_type: mint_type.clone()
+								}
+							));
+							minted_count += 1;
+
+							// account is reaped, nonce and balance are reset to 0
+							assert_eq!(System::account_nonce(ALICE), 0);
+							assert_eq!(Balances::total_balance(&ALICE), 0);
+						},
+						MintType::Free => {
+							assert_eq!(System::account_nonce(ALICE), expected_nonce);
+						},
+					}
+
+					// check for season ending
+					run_to_block(season_1.end + 1);
+					assert_noop!(
+						AAvatars::mint(
+							RuntimeOrigin::signed(ALICE),
+							MintOption { count: MintPackSize::One, mint_type: mint_type.clone() }
+						),
+						Error::<Test>::SeasonClosed
+					);
+
+					// total minted count updates
+					let seasons_participated =
+						AAvatars::accounts(ALICE).stats.mint.seasons_participated;
+					assert_eq!(
+						seasons_participated
+							.iter()
+							.map(|season_id| AAvatars::season_stats(season_id, ALICE).minted)
+							.sum::<u32>(),
+						minted_count as u32
+					);
+
+					// check participation
+					assert_eq!(seasons_participated.into_iter().collect::<Vec<_>>(), vec![1]);
+
+					// current season minted count resets
+					assert_eq!(AAvatars::current_season_id(), 2);
+					assert_eq!(AAvatars::season_stats(2, ALICE).minted, 0);
+
+					// check for minted avatars
+					let minted = AAvatars::owners(&ALICE)
+						.into_iter()
+						.map(|avatar_id| AAvatars::avatars(avatar_id).unwrap())
+						.collect::<Vec<_>>();
+					assert!(minted.iter().all(|(owner, avatar)| {
+						owner == &ALICE &&
+							(avatar.souls >= 1 && avatar.souls <= 100) &&
+							avatar.season_id == 1
+					}));
+				}
+			});
+	}
+
+	#[test]
+	fn mint_should_reject_when_minting_is_closed() {
+		let season = Season::default();
+
+		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
+			GlobalConfigs::<Test>::mutate(|config| config.mint.open = false);
+			run_to_block(season.start);
+			for count in [MintPackSize::One, MintPackSize::Three, MintPackSize::Six] {
+				for mint_type in [MintType::Normal, MintType::Free] {
+					assert_noop!(
+						AAvatars::mint(
+							RuntimeOrigin::signed(ALICE),
+							MintOption { count, mint_type }
+						),
+						Error::<Test>::MintClosed
+					);
+				}
+			}
+		});
+	}
+
+	#[test]
+	fn mint_should_reject_unsigned_calls() {
+		ExtBuilder::default().build().execute_with(|| {
+			for count in [MintPackSize::One, MintPackSize::Three, MintPackSize::Six] {
+				for mint_type in [MintType::Normal, MintType::Free] {
+					assert_noop!(
+						AAvatars::mint(RuntimeOrigin::none(), MintOption { count, mint_type }),
+						DispatchError::BadOrigin
+					);
+				}
+			}
+		});
+	}
+
+	#[test]
+	fn mint_should_spend_early_access_of_allowlisted_accounts() {
+		let season = Season::default().early_start(5).start(10).end(20);
+		let fees = MintFees { one: 12, three: 34, six: 56 };
+
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.mint_fees(fees)
+			.mint_cooldown(0)
+			.balances(&[(ALICE, 1_234_567_890_123_456)])
+			.build()
+			.execute_with(|| {
+				Allowlists::<Test>::insert(1, ALICE, 4);
+				run_to_block(season.early_start);
+
+				assert_ok!(AAvatars::mint(
+					RuntimeOrigin::signed(ALICE),
+					MintOption { count: MintPackSize::Three, mint_type: MintType::Normal }
+				));
+				assert_eq!(AAvatars::allowlist(1, ALICE), Some(1));
+				assert_noop!(
+					AAvatars::mint(
+						RuntimeOrigin::signed(ALICE),
+						MintOption { count: MintPackSize::Three, mint_type: MintType::Normal }
+					),
+					Error::<Test>::InsufficientEarlyAccessMints
+				);
+				assert_ok!(AAvatars::mint(
+					RuntimeOrigin::signed(ALICE),
+					MintOption { count: MintPackSize::One, mint_type: MintType::Normal }
+				));
+				assert_eq!(AAvatars::allowlist(1, ALICE), Some(0));
+				assert_eq!(AAvatars::owners(&ALICE).len(), 4);
+
+				// early access isn't spent once the season has started
+				run_to_block(season.start);
+				assert_ok!(AAvatars::mint(
+					RuntimeOrigin::signed(ALICE),
+					MintOption { count: MintPackSize::Six, mint_type: MintType::Normal }
+				));
+				assert_eq!(AAvatars::allowlist(1, ALICE), Some(0));
+			});
+	}
+
+	#[test]
+	fn mint_should_reject_non_whitelisted_accounts_when_season_is_inactive() {
+		ExtBuilder::default()
+			.balances(&[(ALICE, 1_234_567_890_123_456)])
+			.free_mints(&[(ALICE, 0)])
+			.build()
+			.execute_with(|| {
+				for count in [MintPackSize::One, MintPackSize::Three, MintPackSize::Six] {
+					for mint_type in [MintType::Normal, MintType::Free] {
+						assert_noop!(
+							AAvatars::mint(
+								RuntimeOrigin::signed(ALICE),
+								MintOption { count, mint_type }
+							),
+							Error::<Test>::SeasonClosed
+						);
+					}
+				}
+			});
+	}
+
+	#[test]
+	fn mint_should_reject_when_max_ownership_has_reached() {
+		let season = Season::default();
+
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.balances(&[(ALICE, 1_234_567_890_123_456)])
+			.free_mints(&[(ALICE, 10)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let quota = AAvatars::accounts(ALICE).storage_tier.quota().unwrap();
+				OwnedAvatarCounts::<Test>::insert(ALICE, quota);
+				for count in [MintPackSize::One, MintPackSize::Three, MintPackSize::Six] {
+					for mint_type in [MintType::Normal, MintType::Free] {
+						assert_noop!(
+							AAvatars::mint(
+								RuntimeOrigin::signed(ALICE),
+								MintOption { count, mint_type }
+							),
+							Error::<Test>::MaxOwnershipReached
+						);
+					}
+				}
+			});
+	}
+
+	#[test]
+	fn mint_should_not_limit_the_max_storage_tier() {
+		let season = Season::default();
+
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.free_mints(&[(ALICE, 10)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				OwnedAvatarCounts::<Test>::insert(ALICE, 1_000);
+				Accounts::<Test>::mutate(ALICE, |account| account.storage_tier = StorageTier::Max);
+				assert_ok!(AAvatars::mint(
+					RuntimeOrigin::signed(ALICE),
+					MintOption { count: MintPackSize::Six, mint_type: MintType::Free }
+				));
+				assert_eq!(AAvatars::owned_avatar_count(ALICE), 1_006);
+			});
+	}
+
+	#[test]
+	fn mint_should_wait_for_cooldown() {
+		let season = Season::default().early_start(1).start(3).end(20);
+		let mint_cooldown = 7;
+
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.mint_cooldown(mint_cooldown)
+			.balances(&[(ALICE, 1_234_567_890_123_456)])
+			.free_mints(&[(ALICE, 10)])
+			.build()
+			.execute_with(|| {
+				for mint_type in [MintType::Normal, MintType::Free] {
+					run_to_block(season.start + 1);
+					assert_ok!(AAvatars::mint(
+						RuntimeOrigin::signed(ALICE),
+						MintOption { count: MintPackSize::One, mint_type: mint_type.clone() }
+					));
+
+					for _ in 1..mint_cooldown {
+						run_to_block(System::block_number() + 1);
+						assert_noop!(
+							AAvatars::mint(
+								RuntimeOrigin::signed(ALICE),
+								MintOption {
+									count: MintPackSize::One,
+									mint_type: mint_type.clone()
+								}
+							),
+							Error::<Test>::MintCooldown
+						);
+					}
+
+					run_to_block(System::block_number() + 1);
+					assert_eq!(System::block_number(), (season.start + 1) + mint_cooldown);
+					assert_ok!(AAvatars::mint(
+						RuntimeOrigin::signed(ALICE),
+						MintOption { count: MintPackSize::One, mint_type: MintType::Normal }
+					));
+
+					// reset for next iteration
+					System::set_block_number(0);
+					Accounts::<Test>::mutate(ALICE, |account| account.stats.mint.last = 0);
+				}
+			});
+	}
+
+	#[test]
+	fn mint_should_reject_when_balance_is_insufficient() {
+		let season = Season::default();
+
+		ExtBuilder::default()
+			.mint_fees(MintFees { one: 1, three: 2, six: 3 })
+			.seasons(&[(1, season.clone())])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+
+				for mint_count in [MintPackSize::One, MintPackSize::Three, MintPackSize::Six] {
+					assert_noop!(
+						AAvatars::mint(
+							RuntimeOrigin::signed(ALICE),
+							MintOption { count: mint_count, mint_type: MintType::Normal }
+						),
+						pallet_balances::Error::<Test>::InsufficientBalance
+					);
+				}
+
+				for mint_count in [MintPackSize::One, MintPackSize::Three, MintPackSize::Six] {
+					assert_noop!(
+						AAvatars::mint(
+							RuntimeOrigin::signed(ALICE),
+							MintOption { count: mint_count, mint_type: MintType::Free }
+						),
+						Error::<Test>::InsufficientFreeMints
+					);
+				}
+			});
+	}
+
+	#[test]
+	fn set_free_mints_works() {
+		ExtBuilder::default()
+			.organizer(ALICE)
+			.free_mints(&[(BOB, 123)])
+			.build()
+			.execute_with(|| {
+				assert_ok!(AAvatars::set_free_mints(RuntimeOrigin::signed(ALICE), BOB, 999));
+				assert_eq!(AAvatars::accounts(BOB).free_mints, 999);
+
+				assert_ok!(AAvatars::set_free_mints(RuntimeOrigin::signed(ALICE), BOB, 0));
+				assert_eq!(AAvatars::accounts(BOB).free_mints, 0);
+			})
+	}
+
+	#[test]
+	fn set_free_mints_rejects_non_organizer_calls() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			for not_organizer in [BOB, CHARLIE] {
+				assert_noop!(
+					AAvatars::set_free_mints(RuntimeOrigin::signed(not_organizer), ALICE, 123),
+					DispatchError::BadOrigin
+				);
+			}
+		})
+	}
+
+	#[test]
+	fn set_airdrop_budget_works() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			assert_ok!(AAvatars::set_airdrop_budget(RuntimeOrigin::signed(ALICE), 3, 1_000));
+			assert_eq!(AAvatars::airdrop_budget(3), 1_000);
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(
+				crate::Event::AirdropBudgetSet { season_id: 3, budget: 1_000 },
+			));
+			assert_noop!(
+				AAvatars::set_airdrop_budget(RuntimeOrigin::signed(BOB), 3, 1),
+				DispatchError::BadOrigin
+			);
+		})
+	}
+
+	#[test]
+	fn airdrop_free_mints_works() {
+		ExtBuilder::default()
+			.organizer(ALICE)
+			.free_mints(&[(BOB, 10), (CHARLIE, 20)])
+			.build()
+			.execute_with(|| {
+				CurrentSeasonId::<Test>::put(2);
+				AirdropBudgets::<Test>::insert(2, 100);
+
+				let airdrops = vec![(BOB, 5), (CHARLIE, 5), (DAVE, 7)];
+				assert_ok!(AAvatars::airdrop_free_mints(
+					RuntimeOrigin::signed(ALICE),
+					airdrops.clone().try_into().unwrap(),
+					AirdropMode::Add
+				));
+				assert_eq!(AAvatars::accounts(BOB).free_mints, 15);
+				assert_eq!(AAvatars::accounts(CHARLIE).free_mints, 25);
+				assert_eq!(AAvatars::accounts(DAVE).free_mints, 7);
+				assert_eq!(AAvatars::airdrop_budget(2), 100 - 17);
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::FreeMintsAirdropped {
+						season_id: 2,
+						recipients: 3,
+						spent: 17,
+						mode: AirdropMode::Add,
+					},
+				));
+
+				// only free mints gained are spent from the budget when overwriting
+				assert_ok!(AAvatars::airdrop_free_mints(
+					RuntimeOrigin::signed(ALICE),
+					airdrops.try_into().unwrap(),
+					AirdropMode::Overwrite
+				));
+				assert_eq!(AAvatars::accounts(BOB).free_mints, 5);
+				assert_eq!(AAvatars::accounts(CHARLIE).free_mints, 5);
+				assert_eq!(AAvatars::accounts(DAVE).free_mints, 7);
+				assert_eq!(AAvatars::airdrop_budget(2), 100 - 17);
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::FreeMintsAirdropped {
+						season_id: 2,
+						recipients: 3,
+						spent: 0,
+						mode: AirdropMode::Overwrite,
+					},
+				));
+			})
+	}
+
+	#[test]
+	fn airdrop_free_mints_rejects_exceeding_the_budget() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			AirdropBudgets::<Test>::insert(1, 10);
+			assert_noop!(
+				AAvatars::airdrop_free_mints(
+					RuntimeOrigin::signed(ALICE),
+					vec![(BOB, 5), (CHARLIE, 6)].try_into().unwrap(),
+					AirdropMode::Add
+				),
+				Error::<Test>::AirdropBudgetExceeded
+			);
+			assert_ok!(AAvatars::airdrop_free_mints(
+				RuntimeOrigin::signed(ALICE),
+				vec![(BOB, 5), (CHARLIE, 5)].try_into().unwrap(),
+				AirdropMode::Add
+			));
+			assert_eq!(AAvatars::airdrop_budget(1), 0);
+		})
+	}
+
+	#[test]
+	fn airdrop_free_mints_rejects_non_organizer_calls() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			assert_noop!(
+				AAvatars::airdrop_free_mints(
+					RuntimeOrigin::signed(BOB),
+					vec![(BOB, 5)].try_into().unwrap(),
+					AirdropMode::Add
+				),
+				DispatchError::BadOrigin
+			);
+		})
+	}
+}
+
+mod forging {
+	use super::*;
+	use sp_runtime::testing::H256;
+	use sp_std::collections::btree_set::BTreeSet;
+
+	fn create_avatar_for_bob(dna: &[u8]) -> AvatarIdOf<Test> {
+		let avatar = Avatar::default().season_id(1).dna(dna);
+		if avatar.min_tier() == RarityTier::Legendary as u8 {
+			CurrentSeasonStatus::<Test>::mutate(|status| status.max_tier_avatars += 1);
+		}
+
+		let avatar_id = H256::random();
+		Avatars::<Test>::insert(avatar_id, (BOB, avatar));
+		AAvatars::add_ownership(&BOB, &avatar_id);
+
+		avatar_id
+	}
+
+	#[test]
+	fn forge_works_for_season_1() {
+		let season = Season::default()
+			.early_start(100)
+			.start(200)
+			.end(150_000)
+			.max_tier_forges(100)
+			.max_variations(6)
+			.max_components(11)
+			.min_sacrifices(1)
+			.max_sacrifices(4)
+			.tiers(&[RarityTier::Common, RarityTier::Rare, RarityTier::Legendary])
+			.single_mint_probs(&[95, 5])
+			.batch_mint_probs(&[80, 20])
+			.base_prob(20)
+			.per_period(20)
+			.periods(12);
+
+		let expected_upgraded_components = |dna_1: &[u8], dna_2: &[u8]| -> usize {
+			dna_1.iter().zip(dna_2).filter(|(left, right)| left != right).count()
+		};
+
+		ExtBuilder::default()
+			.seasons(&[(1, season)])
+			.mint_cooldown(5)
+			.build()
+			.execute_with(|| {
+				run_to_block(15_792);
+
+				let dna_before = [0x23, 0x25, 0x24, 0x20, 0x05, 0x25, 0x01, 0x20, 0x02, 0x23, 0x23];
+				let dna_after = [0x23, 0x25, 0x24, 0x20, 0x05, 0x25, 0x21, 0x20, 0x22, 0x23, 0x23];
+				assert_eq!(expected_upgraded_components(&dna_before, &dna_after), 2);
+
+				let leader_id = create_avatar_for_bob(&dna_before);
+				let sacrifice_ids = [
+					create_avatar_for_bob(&[
+						0x00, 0x00, 0x04, 0x01, 0x03, 0x21, 0x00, 0x00, 0x03, 0x04, 0x04,
+					]),
+					create_avatar_for_bob(&[
+						0x04, 0x02, 0x04, 0x21, 0x02, 0x05, 0x02, 0x21, 0x02, 0x23, 0x00,
+					]),
+					create_avatar_for_bob(&[
+						0x02, 0x05, 0x22, 0x02, 0x23, 0x05, 0x02, 0x24, 0x05, 0x03, 0x03,
+					]),
+					create_avatar_for_bob(&[
+						0x23, 0x24, 0x23, 0x21, 0x25, 0x23, 0x00, 0x25, 0x01, 0x22, 0x05,
+					]),
+				];
+
+				assert_ok!(AAvatars::forge(
+					RuntimeOrigin::signed(BOB),
+					leader_id,
+					sacrifice_ids.to_vec()
+				));
+				let leader = AAvatars::avatars(leader_id).unwrap().1;
+				assert_eq!(leader.dna.to_vec(), dna_after.to_vec());
+				assert_eq!(leader.min_tier(), RarityTier::Common as u8);
+			});
+	}
+
+	#[test]
+	fn forge_should_work() {
+		let season = Season::default()
+			.tiers(&[RarityTier::Common, RarityTier::Uncommon, RarityTier::Legendary])
+			.single_mint_probs(&[100, 0])
+			.batch_mint_probs(&[100, 0])
+			.base_prob(30)
+			.per_period(1)
+			.periods(6)
+			.max_tier_forges(1)
+			.max_components(8)
+			.max_variations(6)
+			.min_sacrifices(1)
+			.max_sacrifices(4);
+
+		let mut forged_count = 0;
+		let mut assert_dna =
+			|leader_id: &AvatarIdOf<Test>, expected_dna: &[u8], insert_dna: Option<&[u8]>| {
+				assert_ok!(AAvatars::mint(
+					RuntimeOrigin::signed(BOB),
+					MintOption { count: MintPackSize::Three, mint_type: MintType::Normal }
+				));
+				assert_ok!(AAvatars::mint(
+					RuntimeOrigin::signed(BOB),
+					MintOption { count: MintPackSize::One, mint_type: MintType::Normal }
+				));
+
+				if let Some(dna) = insert_dna {
+					minted_avatar_ids(BOB)[1..=4].iter().for_each(|id| {
+						Avatars::<Test>::mutate(id, |maybe_avatar| {
+							if let Some((_, avatar)) = maybe_avatar {
+								avatar.dna = dna.to_vec().try_into().unwrap();
+							}
+						});
+					})
+				}
+
+				let original_leader_souls = AAvatars::avatars(leader_id).unwrap().1.souls;
+				let sacrifice_ids = minted_avatar_ids(BOB)[1..=4].to_vec();
+				let sacrifice_souls = sacrifice_ids
+					.iter()
+					.map(|id| AAvatars::avatars(id).unwrap().1.souls)
+					.sum::<SoulCount>();
+				assert_ne!(sacrifice_souls, 0);
+
+				assert_ok!(AAvatars::forge(RuntimeOrigin::signed(BOB), *leader_id, sacrifice_ids));
+				assert_eq!(
+					AAvatars::avatars(leader_id).unwrap().1.souls,
+					original_leader_souls + sacrifice_souls
+				);
+				assert_eq!(AAvatars::avatars(leader_id).unwrap().1.dna.to_vec(), expected_dna);
+
+				forged_count += 1;
+				assert_eq!(
+					AAvatars::accounts(BOB)
+						.stats
+						.forge
+						.seasons_participated
+						.iter()
+						.map(|season_id| AAvatars::season_stats(season_id, BOB).forged)
+						.sum::<u32>(),
+					forged_count as u32
+				);
+				assert_eq!(AAvatars::season_stats(1, BOB).forged, forged_count);
+				assert_eq!(AAvatars::accounts(BOB).stats.forge.first, season.start);
+				assert_eq!(AAvatars::accounts(BOB).stats.forge.last, System::block_number());
+			};
+
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.mint_cooldown(0)
+			.mint_fees(MintFees { one: 1, three: 3, six: 6 })
+			.balances(&[(BOB, MockBalance::max_value())])
+			.free_mints(&[(BOB, 0)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				assert_ok!(AAvatars::mint(
+					RuntimeOrigin::signed(BOB),
+					MintOption { count: MintPackSize::One, mint_type: MintType::Normal }
+				));
+				let leader_id = minted_avatar_ids(BOB)[0];
+				assert_eq!(
+					AAvatars::avatars(&leader_id).unwrap().1.dna.to_vec(),
+					&[0x01, 0x01, 0x03, 0x04, 0x05, 0x04, 0x04, 0x00]
+				);
+
+				// 1st mutation
+				assert_dna(&leader_id, &[0x11, 0x11, 0x13, 0x14, 0x05, 0x04, 0x04, 0x00], None);
+
+				// 2nd mutation
+				// NOTE: the byte of the 2nd roll (248) is rejected as biased, and the roll redrawn
+				// in its place (14) succeeds, so the last component is upgraded as well.
+				assert_dna(&leader_id, &[0x11, 0x11, 0x13, 0x14, 0x15, 0x14, 0x04, 0x10], None);
+
+				// 3rd mutation
+				// NOTE: unchanged from the 2nd mutation, which already upgraded the last component.
+				assert_dna(&leader_id, &[0x11, 0x11, 0x13, 0x14, 0x15, 0x14, 0x04, 0x10], None);
+
+				// 4th mutation
+				assert_dna(
+					&leader_id,
+					&[0x11, 0x11, 0x13, 0x14, 0x15, 0x14, 0x14, 0x10],
+					Some(&[0x11, 0x11, 0x13, 0x14, 0x15, 0x14, 0x05, 0x01]),
+				);
+
+				// 5th mutation
+				assert_dna(
+					&leader_id,
+					&[0x41, 0x41, 0x43, 0x44, 0x15, 0x14, 0x14, 0x10],
+					Some(&[0x12, 0x12, 0x14, 0x15, 0x15, 0x14, 0x14, 0x10]),
+				);
+
+				// 6th mutation
+				assert_dna(
+					&leader_id,
+					&[0x41, 0x41, 0x43, 0x44, 0x45, 0x44, 0x44, 0x10],
+					Some(&[0x41, 0x41, 0x43, 0x44, 0x16, 0x15, 0x15, 0x10]),
+				);
+
+				// force highest tier mint and assert for associated checks
+				assert_eq!(AAvatars::current_season_status().max_tier_avatars, 0);
+				assert_dna(
+					&leader_id,
+					&[0x41, 0x41, 0x43, 0x44, 0x45, 0x44, 0x44, 0x40],
+					Some(&[0x41, 0x41, 0x43, 0x44, 0x45, 0x44, 0x44, 0x11]),
+				);
+				assert_eq!(AAvatars::current_season_status().max_tier_avatars, 1);
+				assert!(AAvatars::current_season_status().early_ended);
+				assert_noop!(
+					AAvatars::mint(
+						RuntimeOrigin::signed(BOB),
+						MintOption { count: MintPackSize::One, mint_type: MintType::Normal }
+					),
+					Error::<Test>::PrematureSeasonEnd
+				);
+
+				// check stats for season 1
+				assert_eq!(AAvatars::season_stats(1, BOB).forged, 7);
+				assert_eq!(AAvatars::season_stats(1, BOB).minted, 29);
+
+				// trigger season end and assert for associated checks
+				run_to_block(season.end + 1);
+				assert_eq!(AAvatars::current_season_status().max_tier_avatars, 0);
+				assert!(!AAvatars::current_season_status().early_ended);
+
+				// check stats for season 2
+				assert_eq!(AAvatars::season_stats(2, BOB).forged, 0);
+				assert_eq!(AAvatars::season_stats(2, BOB).minted, 0);
+			});
+	}
+
+	#[test]
+	fn forge_should_update_max_tier_avatars() {
+		let season = Season::default()
+			.tiers(&[RarityTier::Common, RarityTier::Legendary])
+			.max_components(8)
+			.max_variations(6)
+			.max_tier_forges(5);
+
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.mint_cooldown(0)
+			.free_mints(&[(BOB, MintCount::MAX)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.early_start);
+
+				let mut max_tier_avatars = 0;
+				let common_avatar_ids = [
+					create_avatar_for_bob(&[0x41, 0x42, 0x43, 0x44, 0x45, 0x44, 0x43, 0x02]),
+					create_avatar_for_bob(&[0x41, 0x42, 0x43, 0x44, 0x45, 0x44, 0x43, 0x03]),
+					create_avatar_for_bob(&[0x41, 0x42, 0x43, 0x44, 0x45, 0x44, 0x43, 0x03]),
+					create_avatar_for_bob(&[0x41, 0x42, 0x43, 0x44, 0x45, 0x44, 0x43, 0x03]),
+				];
+
+				// `max_tier_avatars` increases when a legendary is forged
+				assert_eq!(AAvatars::current_season_status().max_tier_avatars, max_tier_avatars);
+				assert_ok!(AAvatars::forge(
+					RuntimeOrigin::signed(BOB),
+					common_avatar_ids[0],
+					common_avatar_ids[1..].to_vec()
+				));
+				max_tier_avatars += 1;
+				assert_eq!(AAvatars::current_season_status().max_tier_avatars, max_tier_avatars);
+				assert_eq!(AAvatars::owners(&BOB).len(), 4 - 3);
+
+				// `max_tier_avatars` decreases when legendaries are sacrificed
+				let legendary_avatar_ids = [
+					create_avatar_for_bob(&[0x41, 0x42, 0x43, 0x44, 0x45, 0x44, 0x43, 0x42]),
+					create_avatar_for_bob(&[0x41, 0x42, 0x43, 0x44, 0x45, 0x44, 0x43, 0x42]),
+					create_avatar_for_bob(&[0x41, 0x42, 0x43, 0x44, 0x45, 0x44, 0x43, 0x42]),
+					create_avatar_for_bob(&[0x41, 0x42, 0x43, 0x44, 0x45, 0x44, 0x43, 0x42]),
+				];
+				max_tier_avatars += 4;
+				assert_eq!(AAvatars::current_season_status().max_tier_avatars, max_tier_avatars);
+
+				// leader is already legendary so max_tier_avatars isn't incremented
+				assert_ok!(AAvatars::forge(
+					RuntimeOrigin::signed(BOB),
+					legendary_avatar_ids[0],
+					legendary_avatar_ids[1..].to_vec()
+				));
+				assert_eq!(AAvatars::current_season_status().max_tier_avatars, max_tier_avatars);
+				assert_eq!(AAvatars::owners(&BOB).len(), (4 - 3) + (4 - 3));
+				assert_eq!(
+					AAvatars::accounts(BOB)
+						.stats
+						.forge
+						.seasons_participated
+						.into_iter()
+						.collect::<Vec<_>>(),
+					vec![1]
+				);
+
+				// NOTE: BOB forged twice so the seasonal forged count is 2
+				assert_eq!(AAvatars::season_stats(1, BOB).forged, 2);
+			});
+	}
+
+	#[test]
+	fn forge_should_work_for_matches() {
+		let tiers = &[RarityTier::Common, RarityTier::Legendary];
+		let season = Season::default()
+			.tiers(tiers)
+			.batch_mint_probs(&[100])
+			.max_components(5)
+			.max_variations(3)
+			.min_sacrifices(1)
+			.max_sacrifices(2);
+
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.mint_cooldown(1)
+			.free_mints(&[(BOB, 10)])
+			.build()
+			.execute_with(|| {
+				// prepare avatars to forge
+				run_to_block(season.start);
+				assert_ok!(AAvatars::mint(
+					RuntimeOrigin::signed(BOB),
+					MintOption { count: MintPackSize::Six, mint_type: MintType::Free }
+				));
+
+				// forge
+				let owned_avatar_ids = minted_avatar_ids(BOB);
+				let leader_id = owned_avatar_ids[0];
+				let sacrifice_ids = &owned_avatar_ids[1..3];
+
+				let original_leader = AAvatars::avatars(leader_id).unwrap().1;
+				let original_sacrifices = sacrifice_ids
+					.iter()
+					.map(|id| AAvatars::avatars(id).unwrap().1)
+					.collect::<Vec<_>>();
+
+				assert_ok!(AAvatars::forge(
+					RuntimeOrigin::signed(BOB),
+					leader_id,
+					sacrifice_ids.to_vec()
+				));
+				let forged_leader = AAvatars::avatars(leader_id).unwrap().1;
+
+				// check the result of the compare method
+				let upgradable_indexes = original_leader.upgradable_indexes::<Test>().unwrap();
+				for (sacrifice, result) in original_sacrifices
+					.iter()
+					.zip([(true, BTreeSet::from([0, 1, 2, 3])), (true, BTreeSet::from([1]))])
+				{
+					assert_eq!(
+						original_leader.compare(
+							sacrifice,
+							&upgradable_indexes,
+							season.max_variations,
+							tiers[tiers.len() - 1].clone() as u8
+						),
+						result
+					)
+				}
+
+				// check all sacrifices are burned
+				for sacrifice_id in sacrifice_ids {
+					assert!(!Avatars::<Test>::contains_key(sacrifice_id));
+				}
+				// check for souls accumulation
+				assert_eq!(
+					forged_leader.souls,
+					original_leader.souls +
+						original_sacrifices.iter().map(|x| x.souls).sum::<SoulCount>(),
+				);
+
+				// check for the upgraded DNA
+				// NOTE: the byte of the 2nd roll (225) is rejected as biased, and the roll redrawn
+				// in its place (97) exceeds the forge probability (50), so only the 1st matched
+				// component is upgraded.
+				assert_ne!(original_leader.dna[0], forged_leader.dna[0]);
+				assert_eq!(original_leader.dna.to_vec()[0] >> 4, RarityTier::Common as u8);
+				assert_eq!(forged_leader.dna.to_vec()[0] >> 4, RarityTier::Legendary as u8);
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::AvatarForged { avatar_id: leader_id, upgraded_components: 1 },
+				));
+
+				// variations remain the same
+				assert_eq!(
+					original_leader.dna[0] & 0b0000_1111,
+					forged_leader.dna[0] & 0b0000_1111
+				);
+				// other components remain the same
+				assert_eq!(
+					original_leader.dna[1..season.max_components as usize],
+					forged_leader.dna[1..season.max_components as usize]
+				);
+			});
+	}
+
+	#[test]
+	fn forge_should_work_for_non_matches() {
+		let tiers =
+			&[RarityTier::Common, RarityTier::Uncommon, RarityTier::Rare, RarityTier::Legendary];
+		let season = Season::default()
+			.tiers(tiers)
+			.batch_mint_probs(&[33, 33, 34])
+			.max_components(10)
+			.max_variations(12)
+			.min_sacrifices(1)
+			.max_sacrifices(5);
+
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.mint_cooldown(1)
+			.free_mints(&[(BOB, 10)])
+			.build()
+			.execute_with(|| {
+				// prepare avatars to forge
+				run_to_block(season.start);
+				assert_ok!(AAvatars::mint(
+					RuntimeOrigin::signed(BOB),
+					MintOption { count: MintPackSize::Six, mint_type: MintType::Free }
+				));
+
+				// forge
+				let owned_avatar_ids = minted_avatar_ids(BOB);
+				let leader_id = owned_avatar_ids[0];
+				let sacrifice_id = owned_avatar_ids[1];
+
+				let original_leader = AAvatars::avatars(leader_id).unwrap().1;
+				let original_sacrifice = AAvatars::avatars(sacrifice_id).unwrap().1;
+
+				assert_ok!(AAvatars::forge(
+					RuntimeOrigin::signed(BOB),
+					leader_id,
+					vec![sacrifice_id]
+				));
+				let forged_leader = AAvatars::avatars(leader_id).unwrap().1;
+
+				// check the result of the compare method
+				let upgradable_indexes = original_leader.upgradable_indexes::<Test>().unwrap();
+				assert_eq!(
+					original_leader.compare(
+						&original_sacrifice,
+						&upgradable_indexes,
+						season.max_variations,
+						tiers[tiers.len() - 1].clone() as u8
+					),
+					(false, BTreeSet::new())
+				);
+				// check all sacrifices are burned
+				assert!(!Avatars::<Test>::contains_key(sacrifice_id));
+				// check for souls accumulation
+				assert_eq!(forged_leader.souls, original_leader.souls + original_sacrifice.souls);
+
+				// check DNAs are the same
+				assert_eq!(original_leader.dna, forged_leader.dna);
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::AvatarForged { avatar_id: leader_id, upgraded_components: 0 },
+				));
+			});
+	}
+
+	#[test]
+	fn forge_should_ignore_low_tier_sacrifices() {
+		let tiers = &[RarityTier::Common, RarityTier::Rare, RarityTier::Legendary];
+		let season = Season::default()
+			.tiers(tiers)
+			.single_mint_probs(&[100, 0])
+			.batch_mint_probs(&[100, 0])
+			.max_tier_forges(1)
+			.max_components(4)
+			.max_variations(6)
+			.min_sacrifices(1)
+			.max_sacrifices(4);
+
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.mint_cooldown(0)
+			.free_mints(&[(ALICE, MintCount::MAX)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				assert_ok!(AAvatars::mint(
+					RuntimeOrigin::signed(ALICE),
+					MintOption { count: MintPackSize::Six, mint_type: MintType::Free }
+				));
+				let leader_id = minted_avatar_ids(ALICE)[0];
+				assert_eq!(
+					AAvatars::avatars(&leader_id).unwrap().1.dna.to_vec(),
+					&[0x01, 0x05, 0x05, 0x04]
+				);
+				assert_eq!(
+					AAvatars::avatars(&leader_id).unwrap().1.min_tier(),
+					tiers[0].clone() as u8,
+				);
+
+				// mutate the DNA of leader to make it a tier higher
+				let mut leader_avatar = AAvatars::avatars(leader_id).unwrap();
+				leader_avatar.1.dna = leader_avatar
+					.1
+					.dna
+					.iter()
+					.map(|x| ((tiers[1].clone() as u8) << 4) | (x & 0b0000_1111))
+					.collect::<Vec<_>>()
+					.try_into()
+					.unwrap();
+				Avatars::<Test>::insert(leader_id, &leader_avatar);
+				assert_eq!(
+					AAvatars::avatars(&leader_id).unwrap().1.min_tier(),
+					tiers[1].clone() as u8
+				);
+
+				// forging doesn't take effect
+				let sacrifice_ids = &minted_avatar_ids(ALICE)[1..5];
+				assert_ok!(AAvatars::forge(
+					RuntimeOrigin::signed(ALICE),
+					leader_id,
+					sacrifice_ids.to_vec()
+				));
+				assert_eq!(AAvatars::avatars(leader_id).unwrap().1.dna, leader_avatar.1.dna);
+			});
+	}
+
+	#[test]
+	fn forge_should_reject_when_forging_is_closed() {
+		let season = Season::default().min_sacrifices(0);
+
+		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
+			run_to_block(season.start);
+			GlobalConfigs::<Test>::mutate(|config| config.forge.open = false);
+			assert_noop!(
+				AAvatars::forge(RuntimeOrigin::signed(ALICE), H256::default(), Vec::new()),
+				Error::<Test>::ForgeClosed,
+			);
+		});
+	}
+
+	#[test]
+	fn forge_should_reject_unsigned_calls() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_noop!(
+				AAvatars::forge(RuntimeOrigin::none(), H256::default(), Vec::new()),
+				DispatchError::BadOrigin,
+			);
+		});
+	}
+
+	#[test]
+	fn forge_should_reject_out_of_bound_sacrifices() {
+		let season = Season::default().min_sacrifices(3).max_sacrifices(5);
+
+		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
+			run_to_block(season.start);
+
+			for i in 0..season.min_sacrifices {
+				assert_noop!(
+					AAvatars::forge(
+						RuntimeOrigin::signed(ALICE),
+						H256::default(),
+						(0..i).map(|_| H256::default()).collect::<Vec<_>>(),
+					),
+					Error::<Test>::TooFewSacrifices,
+				);
+			}
+
+			for i in (season.max_sacrifices + 1)..(season.max_sacrifices + 5) {
+				assert_noop!(
+					AAvatars::forge(
+						RuntimeOrigin::signed(ALICE),
+						H256::default(),
+						(0..i).map(|_| H256::default()).collect::<Vec<_>>(),
+					),
+					Error::<Test>::TooManySacrifices,
+				);
+			}
+		});
+	}
+
+	#[test]
+	fn forge_should_not_be_interrupted_by_season_status() {
+		let season_1 = Season::default().early_start(5).start(10).end(20);
+		let season_2 = Season::default().early_start(30).start(40).end(50);
+		let seasons = &[(1, season_1.clone()), (2, season_2.clone())];
+
+		ExtBuilder::default()
+			.seasons(seasons)
+			.mint_cooldown(0)
+			.free_mints(&[(ALICE, 100)])
+			.build()
+			.execute_with(|| {
+				Accounts::<Test>::mutate(ALICE, |info| info.storage_tier = StorageTier::Four);
+
+				run_to_block(season_1.early_start);
+				for _ in 0..33 {
+					assert_ok!(AAvatars::mint(
+						RuntimeOrigin::signed(ALICE),
+						MintOption { count: MintPackSize::Three, mint_type: MintType::Free }
+					));
+				}
+
+				for iter in [
+					season_1.early_start..season_1.end, // block 5..19
+					season_1.end..season_2.early_start, // block 20..29
+				] {
+					for n in iter {
+						run_to_block(n);
+						assert_ok!(AAvatars::forge(
+							RuntimeOrigin::signed(ALICE),
+							minted_avatar_ids(ALICE)[0],
+							minted_avatar_ids(ALICE)[1..3].to_vec()
+						));
+					}
+				}
+			});
+	}
+
+	#[test]
+	fn forge_should_reject_unknown_season_calls() {
+		ExtBuilder::default().build().execute_with(|| {
+			CurrentSeasonId::<Test>::put(123);
+			CurrentSeasonStatus::<Test>::mutate(|status| status.active = true);
+			assert_noop!(
+				AAvatars::forge(
+					RuntimeOrigin::signed(ALICE),
+					H256::default(),
+					vec![H256::default()]
+				),
+				Error::<Test>::UnknownSeason,
+			);
+		});
+	}
+
+	#[test]
+	fn forge_should_reject_unknown_avatars() {
+		let season = Season::default().min_sacrifices(1).max_sacrifices(3);
+
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.mint_cooldown(0)
+			.free_mints(&[(ALICE, 10)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				for _ in 0..season.max_sacrifices {
+					assert_ok!(AAvatars::mint(
+						RuntimeOrigin::signed(ALICE),
+						MintOption { count: MintPackSize::One, mint_type: MintType::Free }
+					));
+				}
+
+				let owned_avatars = minted_avatar_ids(ALICE);
+				for (leader, sacrifices) in [
+					(H256::default(), vec![owned_avatars[0], owned_avatars[2]]),
+					(owned_avatars[1], vec![H256::default(), H256::default()]),
+					(owned_avatars[1], vec![H256::default(), owned_avatars[2]]),
+				] {
+					assert_noop!(
+						AAvatars::forge(RuntimeOrigin::signed(ALICE), leader, sacrifices),
+						Error::<Test>::UnknownAvatar,
+					);
+				}
+			});
+	}
+
+	#[test]
+	fn forge_should_reject_incorrect_ownership() {
+		let season = Season::default().min_sacrifices(1).max_sacrifices(3);
+
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.mint_cooldown(0)
+			.free_mints(&[(ALICE, 10), (BOB, 10)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				for player in [ALICE, BOB] {
+					for _ in 0..season.max_sacrifices {
+						assert_ok!(AAvatars::mint(
+							RuntimeOrigin::signed(player),
+							MintOption { count: MintPackSize::One, mint_type: MintType::Free }
+						));
+					}
+				}
+
+				for (player, leader, sacrifices) in [
+					(ALICE, minted_avatar_ids(ALICE)[0], minted_avatar_ids(BOB)[0..2].to_vec()),
+					(ALICE, minted_avatar_ids(BOB)[0], minted_avatar_ids(ALICE)[0..2].to_vec()),
+					(ALICE, minted_avatar_ids(BOB)[0], minted_avatar_ids(BOB)[1..2].to_vec()),
+				] {
+					assert_noop!(
+						AAvatars::forge(RuntimeOrigin::signed(player), leader, sacrifices),
+						Error::<Test>::Ownership,
+					);
+				}
+			});
+	}
+
+	#[test]
+	fn forge_should_reject_leader_in_sacrifice() {
+		let season = Season::default().min_sacrifices(1).max_sacrifices(3);
+
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.mint_cooldown(0)
+			.free_mints(&[(ALICE, 10)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				for _ in 0..season.max_sacrifices {
+					assert_ok!(AAvatars::mint(
+						RuntimeOrigin::signed(ALICE),
+						MintOption { count: MintPackSize::One, mint_type: MintType::Free }
+					));
+				}
+
+				for (player, leader, sacrifices) in [
+					(ALICE, minted_avatar_ids(ALICE)[0], minted_avatar_ids(ALICE)[0..2].to_vec()),
+					(ALICE, minted_avatar_ids(ALICE)[1], minted_avatar_ids(ALICE)[0..2].to_vec()),
+				] {
+					assert_noop!(
+						AAvatars::forge(RuntimeOrigin::signed(player), leader, sacrifices),
+						Error::<Test>::LeaderSacrificed,
+					);
+				}
+			});
+	}
+
+	#[test]
+	fn forge_should_reject_avatars_in_trade() {
+		let season = Season::default();
+		let price = 321;
+		let initial_balance = 6 + MockExistentialDeposit::get();
+
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.balances(&[(ALICE, initial_balance), (BOB, 6 + initial_balance)])
+			.mint_fees(MintFees { one: 1, three: 1, six: 1 })
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				assert_ok!(AAvatars::mint(
+					RuntimeOrigin::signed(ALICE),
+					MintOption { count: MintPackSize::Six, mint_type: MintType::Normal }
+				));
+				assert_ok!(AAvatars::mint(
+					RuntimeOrigin::signed(BOB),
+					MintOption { count: MintPackSize::Six, mint_type: MintType::Normal }
+				));
+
+				let leader = minted_avatar_ids(ALICE)[0];
+				let sacrifices = minted_avatar_ids(ALICE)[1..3].to_vec();
+
+				assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(ALICE), leader, price));
+				assert_noop!(
+					AAvatars::forge(RuntimeOrigin::signed(ALICE), leader, sacrifices.clone()),
+					Error::<Test>::AvatarInTrade
+				);
+
+				assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(ALICE), sacrifices[1], price));
+				assert_noop!(
+					AAvatars::forge(RuntimeOrigin::signed(ALICE), leader, sacrifices.clone()),
+					Error::<Test>::AvatarInTrade
+				);
+
+				assert_ok!(AAvatars::remove_price(RuntimeOrigin::signed(ALICE), leader));
+				assert_noop!(
+					AAvatars::forge(RuntimeOrigin::signed(ALICE), leader, sacrifices),
+					Error::<Test>::AvatarInTrade
+				);
+			});
+	}
+
+	#[test]
+	fn forge_should_reject_avatars_from_different_seasons() {
+		let min_sacrifices = 1;
+		let max_sacrifices = 3;
+		let season1 = Season::default()
+			.early_start(5)
+			.start(45)
+			.end(98)
+			.min_sacrifices(min_sacrifices)
+			.max_sacrifices(max_sacrifices);
+		let season2 = Season::default()
+			.early_start(100)
+			.start(101)
+			.end(199)
+			.min_sacrifices(min_sacrifices)
+			.max_sacrifices(max_sacrifices);
+
+		ExtBuilder::default()
+			.seasons(&[(1, season1.clone()), (2, season2.clone())])
+			.mint_cooldown(0)
+			.free_mints(&[(ALICE, 10)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season1.early_start + 1);
+				for _ in 0..max_sacrifices {
+					assert_ok!(AAvatars::mint(
+						RuntimeOrigin::signed(ALICE),
+						MintOption { count: MintPackSize::One, mint_type: MintType::Free }
+					));
+					run_to_block(System::block_number() + 1);
+				}
+				run_to_block(season2.start + 2);
+
+				for _ in 0..max_sacrifices {
+					assert_ok!(AAvatars::mint(
+						RuntimeOrigin::signed(ALICE),
+						MintOption { count: MintPackSize::One, mint_type: MintType::Free }
+					));
+					run_to_block(System::block_number() + 1);
+				}
+				run_to_block(season2.end + 1);
+
+				for (player, leader, sacrifices) in [
+					(ALICE, minted_avatar_ids(ALICE)[0], minted_avatar_ids(ALICE)[2..5].to_vec()),
+					(ALICE, minted_avatar_ids(ALICE)[0], minted_avatar_ids(ALICE)[3..6].to_vec()),
+					(ALICE, minted_avatar_ids(ALICE)[5], minted_avatar_ids(ALICE)[0..2].to_vec()),
+				] {
+					assert_noop!(
+						AAvatars::forge(RuntimeOrigin::signed(player), leader, sacrifices),
+						Error::<Test>::IncorrectAvatarSeason,
+					);
+				}
+			});
+	}
+
+	fn forge_batch_of(
+		forges: &[(AvatarIdOf<Test>, Vec<AvatarIdOf<Test>>)],
+	) -> BoundedVec<(AvatarIdOf<Test>, Vec<AvatarIdOf<Test>>), MaxForgesPerBatch> {
+		forges.to_vec().try_into().unwrap()
+	}
+
+	#[test]
+	fn forge_batch_works() {
+		let season = Season::default();
+
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.free_mints(&[(BOB, 6)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				assert_ok!(AAvatars::mint(
+					RuntimeOrigin::signed(BOB),
+					MintOption { count: MintPackSize::Six, mint_type: MintType::Free }
+				));
+
+				let ids = minted_avatar_ids(BOB);
+				let nonce = System::account_nonce(BOB);
+				assert_ok!(AAvatars::forge_batch(
+					RuntimeOrigin::signed(BOB),
+					forge_batch_of(&[
+						(ids[0], vec![ids[1], ids[2]]),
+						(ids[3], vec![ids[4], ids[5]])
+					])
+				));
+
+				assert_eq!(minted_avatar_ids(BOB), vec![ids[0], ids[3]]);
+				for sacrifice_id in [ids[1], ids[2], ids[4], ids[5]] {
+					assert!(!Avatars::<Test>::contains_key(sacrifice_id));
+				}
+				// each forge draws its own random hash
+				assert_eq!(System::account_nonce(BOB), nonce + 2);
+				assert_eq!(AAvatars::season_stats(1, BOB).forged, 2);
+
+				let forged_leaders = System::events()
+					.into_iter()
+					.filter_map(|record| match record.event {
+						mock::RuntimeEvent::AAvatars(crate::Event::AvatarForged {
+							avatar_id,
+							..
+						}) => Some(avatar_id),
+						_ => None,
+					})
+					.collect::<Vec<_>>();
+				assert_eq!(forged_leaders, vec![ids[0], ids[3]]);
+			});
+	}
+
+	#[test]
+	fn forge_batch_should_reject_overlapping_forges() {
+		let season = Season::default();
+
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.free_mints(&[(BOB, 6)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				assert_ok!(AAvatars::mint(
+					RuntimeOrigin::signed(BOB),
+					MintOption { count: MintPackSize::Six, mint_type: MintType::Free }
+				));
+
+				let ids = minted_avatar_ids(BOB);
+				for forges in [
+					// shared sacrifice
+					vec![(ids[0], vec![ids[1], ids[2]]), (ids[3], vec![ids[2], ids[4]])],
+					// leader sacrificed by another forge
+					vec![(ids[0], vec![ids[1], ids[2]]), (ids[3], vec![ids[0], ids[4]])],
+					// leader forged twice
+					vec![(ids[0], vec![ids[1], ids[2]]), (ids[0], vec![ids[3], ids[4]])],
+				] {
+					assert_noop!(
+						AAvatars::forge_batch(RuntimeOrigin::signed(BOB), forge_batch_of(&forges)),
+						Error::<Test>::OverlappingForges
+					);
+				}
+			});
+	}
+
+	#[test]
+	fn forge_batch_should_validate_all_forges_up_front() {
+		let season = Season::default();
+
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.free_mints(&[(BOB, 6)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				assert_ok!(AAvatars::mint(
+					RuntimeOrigin::signed(BOB),
+					MintOption { count: MintPackSize::Six, mint_type: MintType::Free }
+				));
+
+				let ids = minted_avatar_ids(BOB);
+				assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(BOB), ids[5], 321));
+				assert_noop!(
+					AAvatars::forge_batch(
+						RuntimeOrigin::signed(BOB),
+						forge_batch_of(&[
+							(ids[0], vec![ids[1], ids[2]]),
+							(ids[3], vec![ids[4], ids[5]])
+						])
+					),
+					Error::<Test>::AvatarInTrade
+				);
+				assert_noop!(
+					AAvatars::forge_batch(
+						RuntimeOrigin::signed(BOB),
+						forge_batch_of(&[(ids[0], vec![ids[1]]), (ids[3], Vec::new())])
+					),
+					Error::<Test>::TooFewSacrifices
+				);
+			});
+	}
+
+	#[test]
+	fn forge_batch_should_reject_empty_batches() {
+		let season = Season::default();
+
+		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
+			run_to_block(season.start);
+			assert_noop!(
+				AAvatars::forge_batch(RuntimeOrigin::signed(BOB), forge_batch_of(&[])),
+				Error::<Test>::EmptyForgeBatch
+			);
+		});
+	}
+
+	#[test]
+	fn forge_batch_should_reject_when_forging_is_closed() {
+		let season = Season::default();
+
+		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
+			run_to_block(season.start);
+			GlobalConfigs::<Test>::mutate(|config| config.forge.open = false);
+			assert_noop!(
+				AAvatars::forge_batch(
+					RuntimeOrigin::signed(ALICE),
+					forge_batch_of(&[(H256::default(), vec![H256::random()])])
+				),
+				Error::<Test>::ForgeClosed,
+			);
+		});
+	}
+}
+
+mod carry_over {
+	use super::*;
+	use sp_runtime::testing::H256;
+	use sp_std::collections::btree_set::BTreeSet;
+
+	fn carry_over(
+		sacrifice_seasons: &[SeasonId],
+		ascension_seasons: &[SeasonId],
+		ascension_fee: MockBalance,
+	) -> CarryOverOf<Test> {
+		CarryOver {
+			sacrifice_seasons: BTreeSet::from_iter(sacrifice_seasons.iter().copied())
+				.try_into()
+				.unwrap(),
+			ascension_seasons: BTreeSet::from_iter(ascension_seasons.iter().copied())
+				.try_into()
+				.unwrap(),
+			ascension_fee,
+		}
+	}
+
+	fn create_avatar(owner: MockAccountId, season_id: SeasonId) -> AvatarIdOf<Test> {
+		let avatar = Avatar::default().season_id(season_id).dna(&[0x11, 0x21]);
+		let avatar_id = H256::random();
+		Avatars::<Test>::insert(avatar_id, (owner, avatar));
+		AAvatars::add_ownership(&owner, &avatar_id);
+		avatar_id
+	}
+
+	fn seasons() -> [(SeasonId, Season<MockBlockNumber>); 2] {
+		[
+			(1, Season::default().early_start(2).start(3).end(4)),
+			(2, Season::default().early_start(5).start(6).end(10).max_components(4)),
+		]
+	}
+
+	#[test]
+	fn set_carry_over_should_work() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			let carry_over = carry_over(&[1, 2], &[1], 123);
+			assert_ok!(AAvatars::set_carry_over(
+				RuntimeOrigin::signed(ALICE),
+				3,
+				carry_over.clone()
+			));
+			assert_eq!(AAvatars::carry_overs(3), carry_over.clone());
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(crate::Event::CarryOverSet {
+				season_id: 3,
+				carry_over,
+			}));
+		});
+	}
+
+	#[test]
+	fn set_carry_over_should_reject_non_organizer_calls() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			assert_noop!(
+				AAvatars::set_carry_over(RuntimeOrigin::signed(BOB), 2, carry_over(&[1], &[], 0)),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn set_carry_over_should_reject_seasons_not_before_the_season() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			for carry_over in [carry_over(&[1, 2], &[], 0), carry_over(&[1], &[3], 0)] {
+				assert_noop!(
+					AAvatars::set_carry_over(RuntimeOrigin::signed(ALICE), 2, carry_over),
+					Error::<Test>::IncorrectCarryOverSeason
+				);
+			}
+		});
+	}
+
+	#[test]
+	fn forge_should_accept_sacrifices_of_carried_over_seasons() {
+		let seasons = seasons();
+		ExtBuilder::default()
+			.organizer(ALICE)
+			.seasons(&seasons)
+			.build()
+			.execute_with(|| {
+				run_to_block(seasons[1].1.start);
+				let leader = create_avatar(BOB, 2);
+				let sacrifice = create_avatar(BOB, 1);
+
+				assert_noop!(
+					AAvatars::forge(RuntimeOrigin::signed(BOB), leader, vec![sacrifice]),
+					Error::<Test>::IncorrectAvatarSeason
+				);
+
+				assert_ok!(AAvatars::set_carry_over(
+					RuntimeOrigin::signed(ALICE),
+					2,
+					carry_over(&[1], &[], 0)
+				));
+				// carried over avatars can't lead a forge
+				assert_noop!(
+					AAvatars::forge(RuntimeOrigin::signed(BOB), sacrifice, vec![leader]),
+					Error::<Test>::IncorrectAvatarSeason
+				);
+				assert_ok!(AAvatars::forge(RuntimeOrigin::signed(BOB), leader, vec![sacrifice]));
+				assert!(!Avatars::<Test>::contains_key(sacrifice));
+				assert_eq!(AAvatars::owners(&BOB).to_vec(), vec![leader]);
+			});
+	}
+
+	#[test]
+	fn ascend_avatar_should_work() {
+		let seasons = seasons();
+		let fee = 333;
+		let initial_balance = fee + MockExistentialDeposit::get();
+
+		ExtBuilder::default()
+			.organizer(ALICE)
+			.seasons(&seasons)
+			.balances(&[(BOB, initial_balance)])
+			.build()
+			.execute_with(|| {
+				run_to_block(seasons[1].1.start);
+				let avatar_id = create_avatar(BOB, 1);
+				let original_avatar = AAvatars::avatars(avatar_id).unwrap().1;
+				assert_ok!(AAvatars::set_carry_over(
+					RuntimeOrigin::signed(ALICE),
+					2,
+					carry_over(&[], &[1], fee)
+				));
+
+				assert_ok!(AAvatars::ascend_avatar(RuntimeOrigin::signed(BOB), avatar_id));
+				let (owner, ascended_avatar) = AAvatars::avatars(avatar_id).unwrap();
+				assert_eq!(owner, BOB);
+				assert_eq!(ascended_avatar.season_id, 2);
+				assert_eq!(ascended_avatar.souls, original_avatar.souls);
+				assert_eq!(ascended_avatar.dna.len(), seasons[1].1.max_components as usize);
+				assert_eq!(Balances::free_balance(BOB), initial_balance - fee);
+				assert_eq!(AAvatars::treasury(2), fee);
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::AvatarAscended { avatar_id, from_season_id: 1, season_id: 2 },
+				));
+
+				// ascended avatars lead forges of the season
+				let sacrifice = create_avatar(BOB, 2);
+				assert_ok!(AAvatars::forge(RuntimeOrigin::signed(BOB), avatar_id, vec![sacrifice]));
+			});
+	}
+
+	#[test]
+	fn ascend_avatar_should_reject_seasons_not_carried_over() {
+		let seasons = seasons();
+		ExtBuilder::default()
+			.organizer(ALICE)
+			.seasons(&seasons)
+			.build()
+			.execute_with(|| {
+				run_to_block(seasons[1].1.start);
+				let old_avatar_id = create_avatar(BOB, 1);
+				let new_avatar_id = create_avatar(BOB, 2);
+
+				for avatar_id in [old_avatar_id, new_avatar_id] {
+					assert_noop!(
+						AAvatars::ascend_avatar(RuntimeOrigin::signed(BOB), avatar_id),
+						Error::<Test>::AscensionNotAllowed
+					);
+				}
+			});
+	}
+
+	#[test]
+	fn ascend_avatar_should_reject_when_season_is_closed() {
+		let seasons = seasons();
+		ExtBuilder::default()
+			.organizer(ALICE)
+			.seasons(&seasons)
+			.build()
+			.execute_with(|| {
+				run_to_block(seasons[1].1.end + 1);
+				let avatar_id = create_avatar(BOB, 1);
+				assert_noop!(
+					AAvatars::ascend_avatar(RuntimeOrigin::signed(BOB), avatar_id),
+					Error::<Test>::SeasonClosed
+				);
+			});
+	}
+
+	#[test]
+	fn ascend_avatar_should_reject_unowned_and_traded_avatars() {
+		let seasons = seasons();
+		ExtBuilder::default()
+			.organizer(ALICE)
+			.seasons(&seasons)
+			.build()
+			.execute_with(|| {
+				run_to_block(seasons[1].1.start);
+				assert_ok!(AAvatars::set_carry_over(
+					RuntimeOrigin::signed(ALICE),
+					2,
+					carry_over(&[], &[1], 0)
+				));
+
+				let avatar_id = create_avatar(BOB, 1);
+				assert_noop!(
+					AAvatars::ascend_avatar(RuntimeOrigin::signed(CHARLIE), avatar_id),
+					Error::<Test>::Ownership
+				);
+
+				assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(BOB), avatar_id, 321));
+				assert_noop!(
+					AAvatars::ascend_avatar(RuntimeOrigin::signed(BOB), avatar_id),
+					Error::<Test>::AvatarInTrade
+				);
+			});
+	}
+}
+
+mod souls {
+	use super::*;
+	use sp_runtime::testing::H256;
+
+	fn create_avatar(owner: MockAccountId, souls: SoulCount) -> AvatarIdOf<Test> {
+		let avatar = Avatar::default().season_id(1).dna(&[0x11, 0x21, 0x31]).souls(souls);
+		let avatar_id = H256::random();
+		Avatars::<Test>::insert(avatar_id, (owner, avatar));
+		AAvatars::add_ownership(&owner, &avatar_id);
+		avatar_id
+	}
+
+	fn exchange(
+		reroll_price: Option<SoulCount>,
+		free_mint_price: Option<SoulCount>,
+		forge_boost_price: Option<SoulCount>,
+		forge_boost: RarityPercent,
+	) -> SoulExchange {
+		SoulExchange { reroll_price, free_mint_price, forge_boost_price, forge_boost }
+	}
+
+	#[test]
+	fn set_soul_exchange_should_work() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			let exchange = exchange(Some(1), Some(2), Some(3), 4);
+			assert_ok!(AAvatars::set_soul_exchange(
+				RuntimeOrigin::signed(ALICE),
+				1,
+				exchange.clone()
+			));
+			assert_eq!(AAvatars::soul_exchanges(1), exchange.clone());
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(
+				crate::Event::SoulExchangeSet { season_id: 1, exchange },
+			));
+		});
+	}
+
+	#[test]
+	fn set_soul_exchange_should_reject_non_organizer_calls() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			assert_noop!(
+				AAvatars::set_soul_exchange(RuntimeOrigin::signed(BOB), 1, SoulExchange::default()),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn set_soul_exchange_should_reject_incorrect_forge_boosts() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			assert_noop!(
+				AAvatars::set_soul_exchange(
+					RuntimeOrigin::signed(ALICE),
+					1,
+					exchange(None, None, Some(1), 101)
+				),
+				Error::<Test>::IncorrectForgeBoost
+			);
+		});
+	}
+
+	#[test]
+	fn extract_souls_should_work() {
+		ExtBuilder::default().build().execute_with(|| {
+			let avatar_ids = [create_avatar(BOB, 12), create_avatar(BOB, 34)];
+			for (avatar_id, (souls, total)) in avatar_ids.into_iter().zip([(12, 12), (34, 46)]) {
+				assert_ok!(AAvatars::extract_souls(RuntimeOrigin::signed(BOB), avatar_id));
+				assert_eq!(AAvatars::souls(BOB), total);
+				assert!(!Avatars::<Test>::contains_key(avatar_id));
+				assert!(!AAvatars::owners(&BOB).contains(&avatar_id));
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::SoulsExtracted { avatar_id, player: BOB, souls },
+				));
+			}
+		});
+	}
+
+	#[test]
+	fn extract_souls_should_reject_unowned_and_traded_avatars() {
+		ExtBuilder::default().build().execute_with(|| {
+			let avatar_id = create_avatar(BOB, 12);
+			assert_noop!(
+				AAvatars::extract_souls(RuntimeOrigin::signed(CHARLIE), avatar_id),
+				Error::<Test>::Ownership
+			);
+
+			assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(BOB), avatar_id, 321));
+			assert_noop!(
+				AAvatars::extract_souls(RuntimeOrigin::signed(BOB), avatar_id),
+				Error::<Test>::AvatarInTrade
+			);
+		});
+	}
+
+	#[test]
+	fn reroll_component_should_work() {
+		let season = Season::default().max_components(3);
+		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
+			run_to_block(season.start);
+			SoulExchanges::<Test>::insert(1, exchange(Some(4), None, None, 0));
+			Souls::<Test>::insert(BOB, 10);
+			let avatar_id = create_avatar(BOB, 1);
+			let original_dna = AAvatars::avatars(avatar_id).unwrap().1.dna;
+
+			assert_ok!(AAvatars::reroll_component(RuntimeOrigin::signed(BOB), avatar_id, 1));
+			let rerolled_dna = AAvatars::avatars(avatar_id).unwrap().1.dna;
+			assert_eq!(rerolled_dna[0], original_dna[0]);
+			assert_eq!(rerolled_dna[2], original_dna[2]);
+			assert_eq!(rerolled_dna[1] >> 4, original_dna[1] >> 4);
+			assert!(rerolled_dna[1] & 0b0000_1111 < season.max_variations);
+			assert_eq!(AAvatars::souls(BOB), 6);
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(
+				crate::Event::ComponentRerolled { avatar_id, component: 1, souls: 4 },
+			));
+		});
+	}
+
+	#[test]
+	fn reroll_component_should_reject_invalid_rerolls() {
+		let season = Season::default().max_components(3);
+		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
+			run_to_block(season.start);
+			let avatar_id = create_avatar(BOB, 1);
+			Souls::<Test>::insert(BOB, 3);
+
+			assert_noop!(
+				AAvatars::reroll_component(RuntimeOrigin::signed(BOB), avatar_id, 0),
+				Error::<Test>::UnavailableForSouls
+			);
+
+			SoulExchanges::<Test>::insert(1, exchange(Some(4), None, None, 0));
+			assert_noop!(
+				AAvatars::reroll_component(RuntimeOrigin::signed(BOB), avatar_id, 0),
+				Error::<Test>::InsufficientSouls
+			);
+			assert_noop!(
+				AAvatars::reroll_component(RuntimeOrigin::signed(BOB), avatar_id, 3),
+				Error::<Test>::UnknownComponent
+			);
+			assert_noop!(
+				AAvatars::reroll_component(RuntimeOrigin::signed(CHARLIE), avatar_id, 0),
+				Error::<Test>::Ownership
+			);
+
+			Avatars::<Test>::mutate(avatar_id, |maybe_avatar| {
+				maybe_avatar.as_mut().unwrap().1.season_id = 2;
+			});
+			assert_noop!(
+				AAvatars::reroll_component(RuntimeOrigin::signed(BOB), avatar_id, 0),
+				Error::<Test>::IncorrectAvatarSeason
+			);
+		});
+	}
+
+	#[test]
+	fn buy_free_mints_should_work() {
+		let season = Season::default();
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.free_mints(&[(BOB, 1)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				SoulExchanges::<Test>::insert(1, exchange(None, Some(5), None, 0));
+				Souls::<Test>::insert(BOB, 17);
+
+				assert_ok!(AAvatars::buy_free_mints(RuntimeOrigin::signed(BOB), 3));
+				assert_eq!(AAvatars::accounts(BOB).free_mints, 4);
+				assert_eq!(AAvatars::souls(BOB), 2);
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::FreeMintsBought { player: BOB, how_many: 3, souls: 15 },
+				));
+
+				assert_noop!(
+					AAvatars::buy_free_mints(RuntimeOrigin::signed(BOB), 1),
+					Error::<Test>::InsufficientSouls
+				);
+			});
+	}
+
+	#[test]
+	fn buy_free_mints_should_reject_when_unavailable() {
+		let season = Season::default();
+		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
+			run_to_block(season.start);
+			Souls::<Test>::insert(BOB, 17);
+			assert_noop!(
+				AAvatars::buy_free_mints(RuntimeOrigin::signed(BOB), 1),
+				Error::<Test>::UnavailableForSouls
+			);
+		});
+	}
+
+	#[test]
+	fn buy_forge_boost_should_work() {
+		let season = Season::default();
+		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
+			run_to_block(season.start);
+			SoulExchanges::<Test>::insert(1, exchange(None, None, Some(2), 40));
+			Souls::<Test>::insert(BOB, 7);
+
+			for expected_boost in [40, 80, 100] {
+				assert_ok!(AAvatars::buy_forge_boost(RuntimeOrigin::signed(BOB)));
+				assert_eq!(AAvatars::forge_boosts(BOB), expected_boost);
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::ForgeBoostBought { player: BOB, boost: 40, souls: 2 },
+				));
+			}
+			assert_eq!(AAvatars::souls(BOB), 1);
+			assert_noop!(
+				AAvatars::buy_forge_boost(RuntimeOrigin::signed(BOB)),
+				Error::<Test>::InsufficientSouls
+			);
+		});
+	}
+
+	#[test]
+	fn forge_should_consume_forge_boost_only_when_it_applies() {
+		let season = Season::default().max_components(3);
+		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
+			run_to_block(season.start);
+			ForgeBoosts::<Test>::insert(BOB, 50);
+			let create_avatar_with_dna = |dna: &[u8]| {
+				let avatar_id = H256::random();
+				Avatars::<Test>::insert(avatar_id, (BOB, Avatar::default().season_id(1).dna(dna)));
+				AAvatars::add_ownership(&BOB, &avatar_id);
+				avatar_id
+			};
+
+			// a forge without matches keeps the boost
+			let leader = create_avatar(BOB, 1);
+			let sacrifice = create_avatar(BOB, 1);
+			assert_ok!(AAvatars::forge(RuntimeOrigin::signed(BOB), leader, vec![sacrifice]));
+			assert_eq!(AAvatars::forge_boosts(BOB), 50);
+
+			// a forge with matches uses it up
+			let leader = create_avatar_with_dna(&[0x10, 0x10, 0x10]);
+			let sacrifice = create_avatar_with_dna(&[0x11, 0x11, 0x11]);
+			assert_ok!(AAvatars::forge(RuntimeOrigin::signed(BOB), leader, vec![sacrifice]));
+			assert_eq!(AAvatars::forge_boosts(BOB), 0);
+		});
+	}
+}
+
+mod transferring {
+	use super::*;
+
+	#[test]
+	fn transfer_free_mints_should_work() {
+		ExtBuilder::default()
+			.free_mints(&[(ALICE, 17), (BOB, 4)])
+			.build()
+			.execute_with(|| {
+				assert_ok!(AAvatars::transfer_free_mints(RuntimeOrigin::signed(ALICE), BOB, 10));
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::FreeMintsTransferred { from: ALICE, to: BOB, how_many: 10 },
+				));
+				assert_eq!(AAvatars::accounts(ALICE).free_mints, 6);
+				assert_eq!(AAvatars::accounts(BOB).free_mints, 14);
+
+				assert_ok!(AAvatars::transfer_free_mints(RuntimeOrigin::signed(ALICE), CHARLIE, 2));
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::FreeMintsTransferred { from: ALICE, to: CHARLIE, how_many: 2 },
+				));
+
+				assert_eq!(AAvatars::accounts(ALICE).free_mints, 3);
+				assert_eq!(AAvatars::accounts(CHARLIE).free_mints, 2);
+			});
+	}
+
+	#[test]
+	fn transfer_free_mints_should_reject_when_amount_is_lower_than_minimum_allowed() {
+		ExtBuilder::default().free_mints(&[(ALICE, 11)]).build().execute_with(|| {
+			let transfer = 5;
+			GlobalConfigs::<Test>::mutate(|cfg| cfg.transfer.min_free_mint_transfer = transfer + 1);
+			assert_noop!(
+				AAvatars::transfer_free_mints(RuntimeOrigin::signed(ALICE), BOB, transfer),
+				Error::<Test>::TooLowFreeMints
+			);
+		});
+	}
+
+	#[test]
+	fn transfer_free_mints_should_reject_when_balance_is_insufficient() {
+		ExtBuilder::default().free_mints(&[(ALICE, 7)]).build().execute_with(|| {
+			assert_noop!(
+				AAvatars::transfer_free_mints(RuntimeOrigin::signed(ALICE), BOB, 10),
+				Error::<Test>::InsufficientFreeMints
+			);
+		});
+	}
+
+	#[test]
+	fn transfer_free_mints_should_reject_transferring_to_self() {
+		ExtBuilder::default().free_mints(&[(ALICE, 7)]).build().execute_with(|| {
+			assert_noop!(
+				AAvatars::transfer_free_mints(RuntimeOrigin::signed(ALICE), ALICE, 1),
+				Error::<Test>::CannotTransferToSelf
+			);
+		});
+	}
+
+	#[test]
+	fn transfer_avatar_works() {
+		let avatar_transfer_fee = 888;
+		let initial_balance = MockExistentialDeposit::get() + avatar_transfer_fee;
+		let total_supply = initial_balance;
+		let season_id = 999;
+
+		ExtBuilder::default()
+			.balances(&[(ALICE, initial_balance)])
+			.avatar_transfer_fee(avatar_transfer_fee)
+			.build()
+			.execute_with(|| {
+				let treasury_account = &AAvatars::treasury_account_id();
+				let treasury_balance = 0;
+				assert_eq!(Balances::free_balance(treasury_account), treasury_balance);
+				assert_eq!(Balances::total_issuance(), total_supply);
+
+				let mut alice_avatar_ids = create_avatars(1, ALICE, 3);
+				let mut bob_avatar_ids = create_avatars(1, BOB, 6);
+				let avatar_id = alice_avatar_ids[0];
+				Avatars::<Test>::mutate(avatar_id, |maybe_avatar| {
+					let (_, avatar) = maybe_avatar.as_mut().unwrap();
+					avatar.season_id = season_id;
+				});
+
+				assert_ok!(AAvatars::transfer_avatar(RuntimeOrigin::signed(ALICE), BOB, avatar_id));
+				alice_avatar_ids.retain(|x| x != &avatar_id);
+				bob_avatar_ids.push(avatar_id);
+
+				// transfer checks
+				alice_avatar_ids.sort();
+				bob_avatar_ids.sort();
+				assert_eq!(AAvatars::owners(&ALICE).len(), 3 - 1);
+				assert_eq!(AAvatars::owners(&ALICE), alice_avatar_ids);
+				assert_eq!(AAvatars::owned_avatar_count(ALICE), 3 - 1);
+				assert_eq!(AAvatars::owners(&BOB).len(), 6 + 1);
+				assert_eq!(AAvatars::owners(&BOB), bob_avatar_ids);
+				assert_eq!(AAvatars::owned_avatar_count(BOB), 6 + 1);
+				assert_eq!(AAvatars::avatars(avatar_id).unwrap().0, BOB);
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::AvatarTransferred { from: ALICE, to: BOB, avatar_id },
+				));
+
+				// balance checks
+				assert_eq!(Balances::free_balance(ALICE), initial_balance - avatar_transfer_fee);
+				assert_eq!(AAvatars::treasury(season_id), avatar_transfer_fee);
+				assert_eq!(
+					Balances::free_balance(treasury_account),
+					treasury_balance + avatar_transfer_fee
+				);
+				assert_eq!(Balances::total_issuance(), total_supply);
+
+				// check organizer transfer
+				GlobalConfigs::<Test>::mutate(|config| config.trade.open = false);
+				Balances::make_free_balance_be(&BOB, avatar_transfer_fee);
+				assert_ok!(AAvatars::set_organizer(RuntimeOrigin::root(), BOB));
+				assert_ok!(AAvatars::transfer_avatar(
+					RuntimeOrigin::signed(BOB),
+					CHARLIE,
+					bob_avatar_ids[0]
+				));
+				assert_eq!(AAvatars::owners(&BOB).len(), 6);
+				assert_eq!(AAvatars::owners(&CHARLIE).len(), 1);
+			});
+	}
+
+	#[test]
+	fn transfer_avatar_rejects_on_transfer_closed() {
+		ExtBuilder::default().build().execute_with(|| {
+			GlobalConfigs::<Test>::mutate(|config| config.transfer.open = false);
+			assert_noop!(
+				AAvatars::transfer_avatar(RuntimeOrigin::signed(BOB), CHARLIE, H256::random()),
+				Error::<Test>::TransferClosed
+			);
+		});
+	}
+
+	#[test]
+	fn transfer_avatar_works_on_transfer_closed_with_organizer() {
+		let avatar_transfer_fee = 135;
+		ExtBuilder::default()
+			.organizer(BOB)
+			.balances(&[(BOB, MockExistentialDeposit::get() + avatar_transfer_fee)])
+			.avatar_transfer_fee(avatar_transfer_fee)
+			.build()
+			.execute_with(|| {
+				GlobalConfigs::<Test>::mutate(|config| config.transfer.open = false);
+				let avatar_id = create_avatars(1, BOB, 1)[0];
+				assert_ok!(AAvatars::transfer_avatar(RuntimeOrigin::signed(BOB), DAVE, avatar_id));
+			});
+	}
+
+	#[test]
+	fn transfer_avatar_rejects_transferring_to_self() {
+		ExtBuilder::default().build().execute_with(|| {
+			for who in [ALICE, BOB] {
+				assert_noop!(
+					AAvatars::transfer_avatar(RuntimeOrigin::signed(who), who, H256::random()),
+					Error::<Test>::CannotTransferToSelf
+				);
+			}
+		});
+	}
+
+	#[test]
+	fn transfer_avatar_rejects_avatar_in_trade() {
+		ExtBuilder::default().build().execute_with(|| {
+			let avatar_id = create_avatars(1, CHARLIE, 1)[0];
+			assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(CHARLIE), avatar_id, 999));
+			assert_noop!(
+				AAvatars::transfer_avatar(RuntimeOrigin::signed(CHARLIE), DAVE, avatar_id),
+				Error::<Test>::AvatarInTrade
+			);
+		});
+	}
+
+	#[test]
+	fn transfer_avatar_rejects_unowned_avatars() {
+		ExtBuilder::default().build().execute_with(|| {
+			let avatar_id = create_avatars(1, CHARLIE, 1)[0];
+			assert_noop!(
+				AAvatars::transfer_avatar(RuntimeOrigin::signed(ALICE), BOB, avatar_id),
+				Error::<Test>::Ownership
+			);
+		});
+	}
+
+	#[test]
+	fn transfer_avatar_rejects_unknown_avatars() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_noop!(
+				AAvatars::transfer_avatar(RuntimeOrigin::signed(ALICE), BOB, H256::random()),
+				Error::<Test>::UnknownAvatar
+			);
+		});
+	}
+
+	#[test]
+	fn transfer_avatar_rejects_on_max_ownership() {
+		let avatar_transfer_fee = 369;
+		ExtBuilder::default()
+			.balances(&[(ALICE, MockExistentialDeposit::get() + avatar_transfer_fee)])
+			.avatar_transfer_fee(avatar_transfer_fee)
+			.build()
+			.execute_with(|| {
+				Accounts::<Test>::mutate(BOB, |info| info.storage_tier = StorageTier::Three);
+
+				let avatar_id = create_avatars(1, ALICE, 1)[0];
+				let _ = create_avatars(1, BOB, StorageTier::Three as u8);
+
+				assert_noop!(
+					AAvatars::transfer_avatar(RuntimeOrigin::signed(ALICE), BOB, avatar_id),
+					Error::<Test>::MaxOwnershipReached
+				);
+			});
+	}
+}
+
+mod trading {
+	use super::*;
+
+	#[test]
+	fn set_price_should_work() {
+		let season = Season::default();
+
+		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
+			run_to_block(season.start);
+			let avatar_for_sale = create_avatars(1, BOB, 1)[0];
+			let price = 7357;
+
+			assert_eq!(AAvatars::trade(avatar_for_sale), None);
+			assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(BOB), avatar_for_sale, price));
+			assert_eq!(AAvatars::trade(avatar_for_sale), Some(price));
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(crate::Event::AvatarPriceSet {
+				avatar_id: avatar_for_sale,
+				price,
+			}));
+		});
+	}
+
+	#[test]
+	fn set_price_should_reject_when_trading_is_closed() {
+		ExtBuilder::default().build().execute_with(|| {
+			GlobalConfigs::<Test>::mutate(|config| config.trade.open = false);
+			assert_noop!(
+				AAvatars::set_price(RuntimeOrigin::signed(ALICE), sp_core::H256::default(), 1),
+				Error::<Test>::TradeClosed,
+			);
+		});
+	}
+
+	#[test]
+	fn set_price_should_reject_unsigned_calls() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_noop!(
+				AAvatars::set_price(RuntimeOrigin::none(), sp_core::H256::default(), 1),
+				DispatchError::BadOrigin,
+			);
+		});
+	}
+
+	#[test]
+	fn set_price_should_reject_incorrect_ownership() {
+		let season = Season::default();
+
+		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
+			run_to_block(season.start);
+			let avatar_ids = create_avatars(1, BOB, 2);
+
+			assert_noop!(
+				AAvatars::set_price(RuntimeOrigin::signed(CHARLIE), avatar_ids[0], 101),
+				Error::<Test>::Ownership
+			);
+		});
+	}
+
+	#[test]
+	fn remove_price_should_work() {
+		let season = Season::default();
+
+		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
+			run_to_block(season.start);
+			let avatar_ids = create_avatars(1, BOB, 2);
+			let avatar_for_sale = avatar_ids[0];
+			let price = 101;
+
+			assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(BOB), avatar_for_sale, price));
+
+			assert_eq!(AAvatars::trade(avatar_for_sale), Some(101));
+			assert_ok!(AAvatars::remove_price(RuntimeOrigin::signed(BOB), avatar_for_sale));
+			assert_eq!(AAvatars::trade(avatar_for_sale), None);
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(
+				crate::Event::AvatarPriceUnset { avatar_id: avatar_for_sale },
+			));
+		});
+	}
+
+	#[test]
+	fn remove_price_should_reject_when_trading_is_closed() {
+		ExtBuilder::default().build().execute_with(|| {
+			GlobalConfigs::<Test>::mutate(|config| config.trade.open = false);
+			assert_noop!(
+				AAvatars::remove_price(RuntimeOrigin::signed(ALICE), sp_core::H256::default()),
+				Error::<Test>::TradeClosed,
+			);
+		});
+	}
+
+	#[test]
+	fn remove_price_should_reject_unsigned_calls() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_noop!(
+				AAvatars::remove_price(RuntimeOrigin::none(), sp_core::H256::default()),
+				DispatchError::BadOrigin,
+			);
+		});
+	}
+
+	#[test]
+	fn remove_price_should_reject_incorrect_ownership() {
+		let season = Season::default();
+
+		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
+			run_to_block(season.start);
+			let avatar_ids = create_avatars(1, BOB, 3);
+			let avatar_for_sale = avatar_ids[0];
+
+			assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(BOB), avatar_for_sale, 123));
+			assert_noop!(
+				AAvatars::remove_price(RuntimeOrigin::signed(CHARLIE), avatar_for_sale),
+				Error::<Test>::Ownership
+			);
+		});
+	}
+
+	#[test]
+	fn remove_price_should_reject_unlisted_avatar() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_noop!(
+				AAvatars::remove_price(RuntimeOrigin::signed(CHARLIE), sp_core::H256::default()),
+				Error::<Test>::UnknownAvatarForSale,
+			);
+		});
+	}
+
+	#[test]
+	fn buy_should_work() {
+		let season = Season::default();
+		let price = 310_984;
+		let min_fee = 54_321;
+		let alice_initial_bal = price + min_fee + 20_849;
+		let bob_initial_bal = 103_598;
+		let charlie_initial_bal = MockExistentialDeposit::get() + min_fee + 1357;
+		let total_supply = alice_initial_bal + bob_initial_bal + charlie_initial_bal;
+
+		ExtBuilder::default()
+			.existential_deposit(0)
+			.seasons(&[(1, season.clone())])
+			.balances(&[
+				(ALICE, alice_initial_bal),
+				(BOB, bob_initial_bal),
+				(CHARLIE, charlie_initial_bal),
+			])
+			.trade_min_fee(min_fee)
+			.build()
+			.execute_with(|| {
+				let mut treasury_balance_season_1 = 0;
+				let treasury_account = AAvatars::treasury_account_id();
+
+				assert_eq!(AAvatars::treasury(1), treasury_balance_season_1);
+				assert_eq!(Balances::free_balance(&treasury_account), treasury_balance_season_1);
+				assert_eq!(Balances::total_issuance(), total_supply);
+
+				run_to_block(season.start);
+				let avatar_ids = create_avatars(1, BOB, 3);
+				assert_eq!(AAvatars::treasury(1), treasury_balance_season_1);
+				assert_eq!(Balances::free_balance(&treasury_account), treasury_balance_season_1);
+
+				let owned_by_alice = AAvatars::owners(&ALICE);
+				let owned_by_bob = AAvatars::owners(&BOB);
+
+				let avatar_for_sale = avatar_ids[0];
+				assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(BOB), avatar_for_sale, price));
+				assert_ok!(AAvatars::buy(RuntimeOrigin::signed(ALICE), avatar_for_sale));
+				treasury_balance_season_1 += min_fee;
+
+				// check for balance transfer
+				assert_eq!(Balances::free_balance(ALICE), alice_initial_bal - price - min_fee);
+				assert_eq!(Balances::free_balance(BOB), bob_initial_bal + price);
+				assert_eq!(AAvatars::treasury(1), treasury_balance_season_1);
+				assert_eq!(Balances::free_balance(&treasury_account), treasury_balance_season_1);
+				assert_eq!(Balances::total_issuance(), total_supply);
+
+				// check for ownership transfer
+				assert_eq!(AAvatars::owners(&ALICE).len(), owned_by_alice.len() + 1);
+				assert_eq!(AAvatars::owners(&BOB).len(), owned_by_bob.len() - 1);
+				assert!(AAvatars::owners(&ALICE).contains(&avatar_for_sale));
+				assert!(!AAvatars::owners(&BOB).contains(&avatar_for_sale));
+				assert_eq!(AAvatars::avatars(avatar_for_sale).unwrap().0, ALICE);
+
+				// check for removal from trade storage
+				assert_eq!(AAvatars::trade(avatar_for_sale), None);
+
+				// check for account stats
+				assert_eq!(AAvatars::accounts(ALICE).stats.trade.bought, 1);
+				assert_eq!(AAvatars::accounts(BOB).stats.trade.sold, 1);
+
+				// check events
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::AvatarTraded { avatar_id: avatar_for_sale, from: BOB, to: ALICE },
+				));
+
+				// charlie buys from bob
+				let avatar_for_sale = avatar_ids[1];
+				assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(BOB), avatar_for_sale, 1357));
+				assert_ok!(AAvatars::buy(RuntimeOrigin::signed(CHARLIE), avatar_for_sale));
+				treasury_balance_season_1 += min_fee;
+				assert_eq!(AAvatars::accounts(CHARLIE).stats.trade.bought, 1);
+				assert_eq!(AAvatars::accounts(BOB).stats.trade.sold, 2);
+
+				// check season id
+				let avatar_on_sale = create_avatars(33, ALICE, 1)[0];
+				assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(ALICE), avatar_on_sale, 369));
+				assert_ok!(AAvatars::buy(RuntimeOrigin::signed(BOB), avatar_on_sale));
+				assert_eq!(AAvatars::treasury(33), min_fee);
+				assert_eq!(AAvatars::treasury(1), treasury_balance_season_1);
+			});
+	}
+
+	#[test]
+	fn buy_fee_should_be_calculated_correctly() {
+		let season = Season::default();
+		let min_fee = 123;
+		let percent_fee = 30;
+		let mut alice_balance = 999_999;
+		let mut bob_balance = 999_999;
+		let mut treasury_balance = 0;
+
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.balances(&[(ALICE, alice_balance), (BOB, bob_balance)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_ids = create_avatars(1, ALICE, 2);
+
+				GlobalConfigs::<Test>::mutate(|cfg| {
+					cfg.trade.min_fee = min_fee;
+					cfg.trade.percent_fee = percent_fee;
+				});
+
+				// when price is much greater (> 30%) than min_fee, percent_fee should be charged
+				let price = 9_999;
+				assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(ALICE), avatar_ids[0], price));
+				assert_ok!(AAvatars::buy(RuntimeOrigin::signed(BOB), avatar_ids[0]));
+				let expected_fee = price * percent_fee as u64 / 100_u64;
+				bob_balance -= price + expected_fee;
+				alice_balance += price;
+				treasury_balance += expected_fee;
+				assert_eq!(Balances::free_balance(BOB), bob_balance);
+				assert_eq!(Balances::free_balance(ALICE), alice_balance);
+				assert_eq!(AAvatars::treasury(1), treasury_balance);
+
+				// when price is less than min_fee, min_fee should be charged
+				let price = 100;
+				assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(ALICE), avatar_ids[1], price));
+				assert_ok!(AAvatars::buy(RuntimeOrigin::signed(BOB), avatar_ids[1]));
+				bob_balance -= price + min_fee;
+				alice_balance += price;
+				treasury_balance += min_fee;
+				assert_eq!(Balances::free_balance(BOB), bob_balance);
+				assert_eq!(Balances::free_balance(ALICE), alice_balance);
+				assert_eq!(AAvatars::treasury(1), treasury_balance);
+			});
+	}
+
+	#[test]
+	fn buy_should_reject_when_trading_is_closed() {
+		ExtBuilder::default().build().execute_with(|| {
+			GlobalConfigs::<Test>::mutate(|config| config.trade.open = false);
+			assert_noop!(
+				AAvatars::buy(RuntimeOrigin::signed(ALICE), sp_core::H256::default()),
+				Error::<Test>::TradeClosed,
+			);
+		});
+	}
+
+	#[test]
+	fn buy_should_reject_unsigned_calls() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_noop!(
+				AAvatars::buy(RuntimeOrigin::none(), sp_core::H256::default()),
+				DispatchError::BadOrigin,
+			);
+		});
+	}
+
+	#[test]
+	fn buy_should_reject_unlisted_avatar() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_noop!(
+				AAvatars::buy(RuntimeOrigin::signed(BOB), sp_core::H256::default()),
+				Error::<Test>::UnknownAvatarForSale,
+			);
+		});
+	}
+
+	#[test]
+	fn buy_should_reject_insufficient_balance() {
+		let season = Season::default();
+		let price = 310_984;
+
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.balances(&[(ALICE, price - 1)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_ids = create_avatars(1, BOB, 3);
+				let avatar_for_sale = avatar_ids[0];
+
+				assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(BOB), avatar_for_sale, price));
+				assert_noop!(
+					AAvatars::buy(RuntimeOrigin::signed(ALICE), avatar_for_sale),
+					pallet_balances::Error::<Test>::InsufficientBalance
+				);
+			});
+	}
+
+	#[test]
+	fn buy_should_reject_when_buyer_buys_its_own_avatar() {
+		let season = Season::default();
+
+		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
+			run_to_block(season.start);
+			let avatar_ids = create_avatars(1, BOB, 3);
+			let avatar_for_sale = avatar_ids[0];
+
+			assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(BOB), avatar_for_sale, 123));
+			assert_noop!(
+				AAvatars::buy(RuntimeOrigin::signed(BOB), avatar_for_sale),
+				Error::<Test>::AlreadyOwned
+			);
+		});
+	}
+}
 
-							assert_eq!(Balances::total_balance(&ALICE), initial_balance);
-							assert_eq!(AAvatars::treasury(1), initial_treasury_balance);
-						},
-						MintType::Free => {
-							initial_free_mints -= MintPackSize::Six as MintCount;
-							assert_eq!(AAvatars::accounts(ALICE).free_mints, initial_free_mints);
-						},
-					}
-					expected_nonce += expected_nonce_increment * 6;
-					owned_avatar_count += 6;
-					minted_count += 6;
-					season_minted_count += 6;
-					assert_eq!(System::account_nonce(ALICE), expected_nonce);
-					assert_eq!(AAvatars::owners(ALICE).len(), owned_avatar_count);
-					assert_eq!(AAvatars::season_stats(1, ALICE).minted, season_minted_count);
-					assert!(AAvatars::current_season_status().active);
-					System::assert_last_event(mock::RuntimeEvent::AAvatars(
-						crate::Event::AvatarsMinted {
-							avatar_ids: AAvatars::owners(ALICE)[4..=9].to_vec(),
-						},
-					));
+mod auction {
+	use super::*;
 
-					match mint_type {
-						MintType::Normal => {
-							// mint one more avatar to trigger reaping
-							assert_eq!(
-								Balances::total_balance(&ALICE),
-								MockExistentialDeposit::get()
-							);
-							run_to_block(System::block_number() + mint_cooldown);
-							assert_ok!(AAvatars::mint(
-								RuntimeOrigin::signed(ALICE),
-								MintOption {
-									count: MintPackSize::One,
-									mint_type: mint_type.clone()
-								}
-							));
-							minted_count += 1;
+	#[test]
+	fn create_auction_should_work() {
+		let season = Season::default();
 
-							// account is reaped, nonce and balance are reset to 0
-							assert_eq!(System::account_nonce(ALICE), 0);
-							assert_eq!(Balances::total_balance(&ALICE), 0);
-						},
-						MintType::Free => {
-							assert_eq!(System::account_nonce(ALICE), expected_nonce);
-						},
-					}
+		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
+			run_to_block(season.start);
+			let avatar_id = create_avatars(1, BOB, 1)[0];
+			let (reserve_price, end) = (1_000, season.start + 5);
 
-					// check for season ending
-					run_to_block(season_1.end + 1);
-					assert_noop!(
-						AAvatars::mint(
-							RuntimeOrigin::signed(ALICE),
-							MintOption { count: MintPackSize::One, mint_type: mint_type.clone() }
-						),
-						Error::<Test>::SeasonClosed
-					);
+			assert_ok!(AAvatars::create_auction(
+				RuntimeOrigin::signed(BOB),
+				avatar_id,
+				reserve_price,
+				end
+			));
+			assert_eq!(
+				AAvatars::auctions(avatar_id),
+				Some(Auction { seller: BOB, reserve_price, end, highest_bid: None })
+			);
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(crate::Event::AuctionCreated {
+				avatar_id,
+				seller: BOB,
+				reserve_price,
+				end,
+			}));
+		});
+	}
 
-					// total minted count updates
-					let seasons_participated =
-						AAvatars::accounts(ALICE).stats.mint.seasons_participated;
-					assert_eq!(
-						seasons_participated
-							.iter()
-							.map(|season_id| AAvatars::season_stats(season_id, ALICE).minted)
-							.sum::<u32>(),
-						minted_count as u32
-					);
+	#[test]
+	fn create_auction_should_reject_when_trading_is_closed() {
+		ExtBuilder::default().build().execute_with(|| {
+			GlobalConfigs::<Test>::mutate(|config| config.trade.open = false);
+			assert_noop!(
+				AAvatars::create_auction(
+					RuntimeOrigin::signed(ALICE),
+					sp_core::H256::default(),
+					1,
+					10
+				),
+				Error::<Test>::TradeClosed,
+			);
+		});
+	}
 
-					// check participation
-					assert_eq!(seasons_participated.into_iter().collect::<Vec<_>>(), vec![1]);
+	#[test]
+	fn create_auction_should_reject_incorrect_ownership() {
+		let season = Season::default();
 
-					// current season minted count resets
-					assert_eq!(AAvatars::current_season_id(), 2);
-					assert_eq!(AAvatars::season_stats(2, ALICE).minted, 0);
+		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
+			run_to_block(season.start);
+			let avatar_id = create_avatars(1, BOB, 1)[0];
+			assert_noop!(
+				AAvatars::create_auction(RuntimeOrigin::signed(CHARLIE), avatar_id, 1, 10),
+				Error::<Test>::Ownership
+			);
+		});
+	}
 
-					// check for minted avatars
-					let minted = AAvatars::owners(ALICE)
-						.into_iter()
-						.map(|avatar_id| AAvatars::avatars(avatar_id).unwrap())
-						.collect::<Vec<_>>();
-					assert!(minted.iter().all(|(owner, avatar)| {
-						owner == &ALICE &&
-							(avatar.souls >= 1 && avatar.souls <= 100) &&
-							avatar.season_id == 1
-					}));
-				}
-			});
+	#[test]
+	fn create_auction_should_reject_past_end() {
+		let season = Season::default();
+
+		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
+			run_to_block(season.start);
+			let avatar_id = create_avatars(1, BOB, 1)[0];
+			assert_noop!(
+				AAvatars::create_auction(RuntimeOrigin::signed(BOB), avatar_id, 1, season.start),
+				Error::<Test>::InvalidAuctionEnd
+			);
+		});
 	}
 
 	#[test]
-	fn mint_should_reject_when_minting_is_closed() {
+	fn create_auction_should_reject_avatars_in_trade() {
 		let season = Season::default();
 
 		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
-			GlobalConfigs::<Test>::mutate(|config| config.mint.open = false);
 			run_to_block(season.start);
-			for count in [MintPackSize::One, MintPackSize::Three, MintPackSize::Six] {
-				for mint_type in [MintType::Normal, MintType::Free] {
-					assert_noop!(
-						AAvatars::mint(
-							RuntimeOrigin::signed(ALICE),
-							MintOption { count, mint_type }
-						),
-						Error::<Test>::MintClosed
-					);
-				}
-			}
+			let avatar_ids = create_avatars(1, BOB, 2);
+
+			assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(BOB), avatar_ids[0], 123));
+			assert_noop!(
+				AAvatars::create_auction(RuntimeOrigin::signed(BOB), avatar_ids[0], 1, 10),
+				Error::<Test>::AvatarInTrade
+			);
+
+			assert_ok!(AAvatars::create_auction(RuntimeOrigin::signed(BOB), avatar_ids[1], 1, 10));
+			assert_noop!(
+				AAvatars::create_auction(RuntimeOrigin::signed(BOB), avatar_ids[1], 1, 10),
+				Error::<Test>::AvatarInTrade
+			);
+			assert_noop!(
+				AAvatars::set_price(RuntimeOrigin::signed(BOB), avatar_ids[1], 123),
+				Error::<Test>::AvatarInTrade
+			);
+			assert_noop!(
+				AAvatars::transfer_avatar(RuntimeOrigin::signed(BOB), ALICE, avatar_ids[1]),
+				Error::<Test>::AvatarInTrade
+			);
+			assert_noop!(
+				AAvatars::lock_avatar(RuntimeOrigin::signed(BOB), avatar_ids[1]),
+				Error::<Test>::AvatarInTrade
+			);
+			assert_noop!(
+				AAvatars::forge(RuntimeOrigin::signed(BOB), avatar_ids[1], vec![avatar_ids[0]]),
+				Error::<Test>::AvatarInTrade
+			);
 		});
 	}
 
 	#[test]
-	fn mint_should_reject_unsigned_calls() {
+	fn bid_should_work() {
+		let season = Season::default();
+		let min_fee = 100;
+		let (alice_initial_bal, charlie_initial_bal) = (10_000, 10_000);
+
+		ExtBuilder::default()
+			.existential_deposit(0)
+			.seasons(&[(1, season.clone())])
+			.balances(&[(ALICE, alice_initial_bal), (CHARLIE, charlie_initial_bal)])
+			.trade_min_fee(min_fee)
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_id = create_avatars(1, BOB, 1)[0];
+				let end = season.start + 5;
+				assert_ok!(AAvatars::create_auction(
+					RuntimeOrigin::signed(BOB),
+					avatar_id,
+					1_000,
+					end
+				));
+
+				assert_ok!(AAvatars::bid(RuntimeOrigin::signed(ALICE), avatar_id, 1_000));
+				assert_eq!(Balances::reserved_balance(ALICE), 1_000 + min_fee);
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(crate::Event::AuctionBid {
+					avatar_id,
+					bidder: ALICE,
+					amount: 1_000,
+				}));
+
+				// outbidding releases the previous bid
+				assert_ok!(AAvatars::bid(RuntimeOrigin::signed(CHARLIE), avatar_id, 2_000));
+				assert_eq!(Balances::reserved_balance(ALICE), 0);
+				assert_eq!(Balances::free_balance(ALICE), alice_initial_bal);
+				assert_eq!(Balances::reserved_balance(CHARLIE), 2_000 + min_fee);
+				assert_eq!(
+					AAvatars::auctions(avatar_id).unwrap().highest_bid,
+					Some(Bid { bidder: CHARLIE, amount: 2_000, fee: min_fee, fee_asset: None })
+				);
+			});
+	}
+
+	#[test]
+	fn bid_holds_fee_in_the_fee_asset() {
+		let season = Season::default();
+		let min_fee = 100;
+
+		ExtBuilder::default()
+			.existential_deposit(0)
+			.seasons(&[(1, season.clone())])
+			.balances(&[(ALICE, 10_000), (BOB, 10_000), (CHARLIE, 10_000)])
+			.trade_min_fee(min_fee)
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				create_asset(&[(ALICE, 1_000), (CHARLIE, 1_000)]);
+				PaymentAssets::<Test>::insert(ASSET_ID, ConversionRate { native: 10, asset: 3 });
+				FeeAssets::<Test>::insert(ALICE, ASSET_ID);
+				FeeAssets::<Test>::insert(CHARLIE, ASSET_ID);
+				let treasury_account = AAvatars::treasury_account_id();
+				let avatar_id = create_avatars(1, BOB, 1)[0];
+				let end = season.start + 5;
+				assert_ok!(AAvatars::create_auction(
+					RuntimeOrigin::signed(BOB),
+					avatar_id,
+					1_000,
+					end
+				));
+
+				assert_ok!(AAvatars::bid(RuntimeOrigin::signed(ALICE), avatar_id, 1_000));
+				assert_eq!(Balances::reserved_balance(ALICE), 1_000);
+				assert_eq!(Assets::balance(ASSET_ID, ALICE), 1_000 - 30);
+				assert_eq!(Assets::balance(ASSET_ID, treasury_account), 30);
+
+				// outbidding returns the fee held in the asset
+				assert_ok!(AAvatars::bid(RuntimeOrigin::signed(CHARLIE), avatar_id, 2_000));
+				assert_eq!(Balances::reserved_balance(ALICE), 0);
+				assert_eq!(Assets::balance(ASSET_ID, ALICE), 1_000);
+				assert_eq!(Assets::balance(ASSET_ID, CHARLIE), 1_000 - 30);
+				assert_eq!(
+					AAvatars::auctions(avatar_id).unwrap().highest_bid,
+					Some(Bid {
+						bidder: CHARLIE,
+						amount: 2_000,
+						fee: 30,
+						fee_asset: Some(ASSET_ID)
+					})
+				);
+
+				run_to_block(end);
+				assert_ok!(AAvatars::settle_auction(RuntimeOrigin::signed(BOB), avatar_id));
+				assert_eq!(AAvatars::avatars(avatar_id).unwrap().0, CHARLIE);
+				assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+				assert_eq!(Balances::free_balance(CHARLIE), 10_000 - 2_000);
+				assert_eq!(Balances::free_balance(BOB), 10_000 + 2_000);
+				assert_eq!(Assets::balance(ASSET_ID, treasury_account), 30);
+				assert_eq!(AAvatars::asset_treasury(1, ASSET_ID), 30);
+				assert_eq!(AAvatars::treasury(1), 0);
+			});
+	}
+
+	#[test]
+	fn bid_should_reject_unknown_auction() {
 		ExtBuilder::default().build().execute_with(|| {
-			for count in [MintPackSize::One, MintPackSize::Three, MintPackSize::Six] {
-				for mint_type in [MintType::Normal, MintType::Free] {
-					assert_noop!(
-						AAvatars::mint(RuntimeOrigin::none(), MintOption { count, mint_type }),
-						DispatchError::BadOrigin
-					);
-				}
-			}
+			assert_noop!(
+				AAvatars::bid(RuntimeOrigin::signed(ALICE), sp_core::H256::default(), 1),
+				Error::<Test>::UnknownAuction,
+			);
 		});
 	}
 
 	#[test]
-	fn mint_should_reject_non_whitelisted_accounts_when_season_is_inactive() {
+	fn bid_should_reject_low_bids() {
+		let season = Season::default();
+
 		ExtBuilder::default()
-			.balances(&[(ALICE, 1_234_567_890_123_456)])
-			.free_mints(&[(ALICE, 0)])
+			.seasons(&[(1, season.clone())])
+			.balances(&[(ALICE, 10_000), (CHARLIE, 10_000)])
 			.build()
 			.execute_with(|| {
-				for count in [MintPackSize::One, MintPackSize::Three, MintPackSize::Six] {
-					for mint_type in [MintType::Normal, MintType::Free] {
-						assert_noop!(
-							AAvatars::mint(
-								RuntimeOrigin::signed(ALICE),
-								MintOption { count, mint_type }
-							),
-							Error::<Test>::SeasonClosed
-						);
-					}
-				}
+				run_to_block(season.start);
+				let avatar_id = create_avatars(1, BOB, 1)[0];
+				assert_ok!(AAvatars::create_auction(
+					RuntimeOrigin::signed(BOB),
+					avatar_id,
+					1_000,
+					10
+				));
+
+				assert_noop!(
+					AAvatars::bid(RuntimeOrigin::signed(ALICE), avatar_id, 999),
+					Error::<Test>::BidTooLow
+				);
+				assert_ok!(AAvatars::bid(RuntimeOrigin::signed(ALICE), avatar_id, 1_500));
+				assert_noop!(
+					AAvatars::bid(RuntimeOrigin::signed(CHARLIE), avatar_id, 1_500),
+					Error::<Test>::BidTooLow
+				);
+			});
+	}
+
+	#[test]
+	fn bid_should_reject_seller_and_ended_auctions() {
+		let season = Season::default();
+
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.balances(&[(ALICE, 10_000), (BOB, 10_000)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_id = create_avatars(1, BOB, 1)[0];
+				let end = season.start + 5;
+				assert_ok!(AAvatars::create_auction(RuntimeOrigin::signed(BOB), avatar_id, 1, end));
+
+				assert_noop!(
+					AAvatars::bid(RuntimeOrigin::signed(BOB), avatar_id, 100),
+					Error::<Test>::AlreadyOwned
+				);
+
+				run_to_block(end);
+				assert_noop!(
+					AAvatars::bid(RuntimeOrigin::signed(ALICE), avatar_id, 100),
+					Error::<Test>::AuctionEnded
+				);
 			});
 	}
 
 	#[test]
-	fn mint_should_reject_when_max_ownership_has_reached() {
-		use sp_runtime::traits::Get;
-
+	fn bid_should_reject_insufficient_balance() {
 		let season = Season::default();
-		let avatar_ids = BoundedAvatarIdsOf::<Test>::try_from(
-			(0..MaxAvatarsPerPlayer::get() as usize)
-				.map(|_| sp_core::H256::default())
-				.collect::<Vec<_>>(),
-		)
-		.unwrap();
-		assert_eq!(avatar_ids.len(), MaxAvatarsPerPlayer::get() as usize);
 
 		ExtBuilder::default()
 			.seasons(&[(1, season.clone())])
-			.balances(&[(ALICE, 1_234_567_890_123_456)])
-			.free_mints(&[(ALICE, 10)])
+			.balances(&[(ALICE, 500)])
 			.build()
 			.execute_with(|| {
 				run_to_block(season.start);
-				Owners::<Test>::insert(ALICE, avatar_ids);
-				for count in [MintPackSize::One, MintPackSize::Three, MintPackSize::Six] {
-					for mint_type in [MintType::Normal, MintType::Free] {
-						assert_noop!(
-							AAvatars::mint(
-								RuntimeOrigin::signed(ALICE),
-								MintOption { count, mint_type }
-							),
-							Error::<Test>::MaxOwnershipReached
-						);
-					}
-				}
+				let avatar_id = create_avatars(1, BOB, 1)[0];
+				assert_ok!(AAvatars::create_auction(RuntimeOrigin::signed(BOB), avatar_id, 1, 10));
+				assert_noop!(
+					AAvatars::bid(RuntimeOrigin::signed(ALICE), avatar_id, 1_000),
+					pallet_balances::Error::<Test>::InsufficientBalance
+				);
 			});
 	}
 
 	#[test]
-	fn mint_should_wait_for_cooldown() {
-		let season = Season::default().early_start(1).start(3).end(20);
-		let mint_cooldown = 7;
+	fn bid_should_reject_bidders_with_full_storage() {
+		let season = Season::default();
 
 		ExtBuilder::default()
 			.seasons(&[(1, season.clone())])
-			.mint_cooldown(mint_cooldown)
-			.balances(&[(ALICE, 1_234_567_890_123_456)])
-			.free_mints(&[(ALICE, 10)])
+			.balances(&[(ALICE, 10_000)])
 			.build()
 			.execute_with(|| {
-				for mint_type in [MintType::Normal, MintType::Free] {
-					run_to_block(season.start + 1);
-					assert_ok!(AAvatars::mint(
-						RuntimeOrigin::signed(ALICE),
-						MintOption { count: MintPackSize::One, mint_type: mint_type.clone() }
-					));
-
-					for _ in 1..mint_cooldown {
-						run_to_block(System::block_number() + 1);
-						assert_noop!(
-							AAvatars::mint(
-								RuntimeOrigin::signed(ALICE),
-								MintOption {
-									count: MintPackSize::One,
-									mint_type: mint_type.clone()
-								}
-							),
-							Error::<Test>::MintCooldown
-						);
-					}
-
-					run_to_block(System::block_number() + 1);
-					assert_eq!(System::block_number(), (season.start + 1) + mint_cooldown);
-					assert_ok!(AAvatars::mint(
-						RuntimeOrigin::signed(ALICE),
-						MintOption { count: MintPackSize::One, mint_type: MintType::Normal }
-					));
+				run_to_block(season.start);
+				let avatar_id = create_avatars(1, BOB, 1)[0];
+				assert_ok!(AAvatars::create_auction(RuntimeOrigin::signed(BOB), avatar_id, 1, 10));
 
-					// reset for next iteration
-					System::set_block_number(0);
-					Accounts::<Test>::mutate(ALICE, |account| account.stats.mint.last = 0);
-				}
+				create_avatars(1, ALICE, StorageTier::One as u8);
+				assert_noop!(
+					AAvatars::bid(RuntimeOrigin::signed(ALICE), avatar_id, 100),
+					Error::<Test>::MaxOwnershipReached
+				);
 			});
 	}
 
 	#[test]
-	fn mint_should_reject_when_balance_is_insufficient() {
+	fn settle_auction_should_work() {
 		let season = Season::default();
+		let min_fee = 100;
+		let price = 2_000;
+		let (alice_initial_bal, bob_initial_bal) = (10_000, 5_000);
 
 		ExtBuilder::default()
-			.mint_fees(MintFees { one: 1, three: 2, six: 3 })
+			.existential_deposit(0)
 			.seasons(&[(1, season.clone())])
+			.balances(&[(ALICE, alice_initial_bal), (BOB, bob_initial_bal)])
+			.trade_min_fee(min_fee)
 			.build()
 			.execute_with(|| {
 				run_to_block(season.start);
+				let avatar_id = create_avatars(1, BOB, 1)[0];
+				let end = season.start + 5;
+				assert_ok!(AAvatars::create_auction(
+					RuntimeOrigin::signed(BOB),
+					avatar_id,
+					1_000,
+					end
+				));
+				assert_ok!(AAvatars::bid(RuntimeOrigin::signed(ALICE), avatar_id, price));
 
-				for mint_count in [MintPackSize::One, MintPackSize::Three, MintPackSize::Six] {
-					assert_noop!(
-						AAvatars::mint(
-							RuntimeOrigin::signed(ALICE),
-							MintOption { count: mint_count, mint_type: MintType::Normal }
-						),
-						pallet_balances::Error::<Test>::InsufficientBalance
-					);
-				}
+				assert_noop!(
+					AAvatars::settle_auction(RuntimeOrigin::signed(CHARLIE), avatar_id),
+					Error::<Test>::AuctionNotEnded
+				);
 
-				for mint_count in [MintPackSize::One, MintPackSize::Three, MintPackSize::Six] {
-					assert_noop!(
-						AAvatars::mint(
-							RuntimeOrigin::signed(ALICE),
-							MintOption { count: mint_count, mint_type: MintType::Free }
-						),
-						Error::<Test>::InsufficientFreeMints
-					);
-				}
+				run_to_block(end);
+				assert_ok!(AAvatars::settle_auction(RuntimeOrigin::signed(CHARLIE), avatar_id));
+
+				assert_eq!(AAvatars::auctions(avatar_id), None);
+				assert_eq!(AAvatars::avatars(avatar_id).unwrap().0, ALICE);
+				assert_eq!(Balances::reserved_balance(ALICE), 0);
+				assert_eq!(Balances::free_balance(ALICE), alice_initial_bal - price - min_fee);
+				assert_eq!(Balances::free_balance(BOB), bob_initial_bal + price);
+				assert_eq!(AAvatars::treasury(1), min_fee);
+				assert_eq!(AAvatars::accounts(ALICE).stats.trade.bought, 1);
+				assert_eq!(AAvatars::accounts(BOB).stats.trade.sold, 1);
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::AuctionSettled {
+						avatar_id,
+						seller: BOB,
+						winner: Some(ALICE),
+						price: Some(price),
+					},
+				));
 			});
 	}
 
 	#[test]
-	fn set_free_mints_works() {
+	fn settle_auction_should_charge_the_fee_fixed_at_bid_time() {
+		let season = Season::default();
+		let min_fee = 100;
+		let price = 2_000;
+		let alice_initial_bal = 10_000;
+
 		ExtBuilder::default()
-			.organizer(ALICE)
-			.free_mints(&[(BOB, 123)])
+			.existential_deposit(0)
+			.seasons(&[(1, season.clone())])
+			.balances(&[(ALICE, alice_initial_bal)])
+			.trade_min_fee(min_fee)
 			.build()
 			.execute_with(|| {
-				assert_ok!(AAvatars::set_free_mints(RuntimeOrigin::signed(ALICE), BOB, 999));
-				assert_eq!(AAvatars::accounts(BOB).free_mints, 999);
-
-				assert_ok!(AAvatars::set_free_mints(RuntimeOrigin::signed(ALICE), BOB, 0));
-				assert_eq!(AAvatars::accounts(BOB).free_mints, 0);
-			})
-	}
-
-	#[test]
-	fn set_free_mints_rejects_non_organizer_calls() {
-		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
-			for not_organizer in [BOB, CHARLIE] {
-				assert_noop!(
-					AAvatars::set_free_mints(RuntimeOrigin::signed(not_organizer), ALICE, 123),
-					DispatchError::BadOrigin
-				);
-			}
-		})
-	}
-}
-
-mod forging {
-	use super::*;
-	use sp_runtime::testing::H256;
-	use sp_std::collections::btree_set::BTreeSet;
-
-	fn create_avatar_for_bob(dna: &[u8]) -> AvatarIdOf<Test> {
-		let avatar = Avatar::default().season_id(1).dna(dna);
-		if avatar.min_tier() == RarityTier::Legendary as u8 {
-			CurrentSeasonStatus::<Test>::mutate(|status| status.max_tier_avatars += 1);
-		}
+				run_to_block(season.start);
+				let avatar_id = create_avatars(1, BOB, 1)[0];
+				let end = season.start + 5;
+				assert_ok!(AAvatars::create_auction(RuntimeOrigin::signed(BOB), avatar_id, 1, end));
+				assert_ok!(AAvatars::bid(RuntimeOrigin::signed(ALICE), avatar_id, price));
 
-		let avatar_id = H256::random();
-		Avatars::<Test>::insert(avatar_id, (BOB, avatar));
-		Owners::<Test>::try_append(BOB, avatar_id).unwrap();
+				GlobalConfigs::<Test>::mutate(|config| config.trade.min_fee = 5_000);
+				run_to_block(end);
+				assert_ok!(AAvatars::settle_auction(RuntimeOrigin::signed(CHARLIE), avatar_id));
 
-		avatar_id
+				assert_eq!(AAvatars::avatars(avatar_id).unwrap().0, ALICE);
+				assert_eq!(Balances::free_balance(ALICE), alice_initial_bal - price - min_fee);
+				assert_eq!(AAvatars::treasury(1), min_fee);
+			});
 	}
 
 	#[test]
-	fn forge_works_for_season_1() {
-		let season = Season::default()
-			.early_start(100)
-			.start(200)
-			.end(150_000)
-			.max_tier_forges(100)
-			.max_variations(6)
-			.max_components(11)
-			.min_sacrifices(1)
-			.max_sacrifices(4)
-			.tiers(&[RarityTier::Common, RarityTier::Rare, RarityTier::Legendary])
-			.single_mint_probs(&[95, 5])
-			.batch_mint_probs(&[80, 20])
-			.base_prob(20)
-			.per_period(20)
-			.periods(12);
-
-		let expected_upgraded_components = |dna_1: &[u8], dna_2: &[u8]| -> usize {
-			dna_1.iter().zip(dna_2).filter(|(left, right)| left != right).count()
-		};
+	fn settle_auction_should_refund_bids_when_the_avatar_cannot_be_handed_over() {
+		let season = Season::default();
+		let alice_initial_bal = 10_000;
 
 		ExtBuilder::default()
-			.seasons(&[(1, season)])
-			.mint_cooldown(5)
+			.existential_deposit(0)
+			.seasons(&[(1, season.clone())])
+			.balances(&[(ALICE, alice_initial_bal)])
 			.build()
 			.execute_with(|| {
-				run_to_block(15_792);
-
-				let dna_before = [0x23, 0x25, 0x24, 0x20, 0x05, 0x25, 0x01, 0x20, 0x02, 0x23, 0x23];
-				let dna_after = [0x23, 0x25, 0x24, 0x20, 0x05, 0x25, 0x21, 0x20, 0x22, 0x23, 0x23];
-				assert_eq!(expected_upgraded_components(&dna_before, &dna_after), 2);
+				run_to_block(season.start);
+				let avatar_id = create_avatars(1, BOB, 1)[0];
+				let end = season.start + 5;
+				assert_ok!(AAvatars::create_auction(RuntimeOrigin::signed(BOB), avatar_id, 1, end));
+				assert_ok!(AAvatars::bid(RuntimeOrigin::signed(ALICE), avatar_id, 1_000));
 
-				let leader_id = create_avatar_for_bob(&dna_before);
-				let sacrifice_ids = [
-					create_avatar_for_bob(&[
-						0x00, 0x00, 0x04, 0x01, 0x03, 0x21, 0x00, 0x00, 0x03, 0x04, 0x04,
-					]),
-					create_avatar_for_bob(&[
-						0x04, 0x02, 0x04, 0x21, 0x02, 0x05, 0x02, 0x21, 0x02, 0x23, 0x00,
-					]),
-					create_avatar_for_bob(&[
-						0x02, 0x05, 0x22, 0x02, 0x23, 0x05, 0x02, 0x24, 0x05, 0x03, 0x03,
-					]),
-					create_avatar_for_bob(&[
-						0x23, 0x24, 0x23, 0x21, 0x25, 0x23, 0x00, 0x25, 0x01, 0x22, 0x05,
-					]),
-				];
+				// the storage of the bidder fills up before the auction ends
+				create_avatars(1, ALICE, StorageTier::One as u8);
+				run_to_block(end);
+				assert_ok!(AAvatars::settle_auction(RuntimeOrigin::signed(CHARLIE), avatar_id));
 
-				assert_ok!(AAvatars::forge(
-					RuntimeOrigin::signed(BOB),
-					leader_id,
-					sacrifice_ids.to_vec()
+				assert_eq!(AAvatars::auctions(avatar_id), None);
+				assert_eq!(AAvatars::avatars(avatar_id).unwrap().0, BOB);
+				assert_eq!(Balances::reserved_balance(ALICE), 0);
+				assert_eq!(Balances::free_balance(ALICE), alice_initial_bal);
+				assert_eq!(AAvatars::treasury(1), 0);
+				System::assert_has_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::AuctionBidRefunded { avatar_id, bidder: ALICE, amount: 1_000 },
+				));
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::AuctionSettled {
+						avatar_id,
+						seller: BOB,
+						winner: None,
+						price: None,
+					},
 				));
-				let leader = AAvatars::avatars(leader_id).unwrap().1;
-				assert_eq!(leader.dna.to_vec(), dna_after.to_vec());
-				assert_eq!(leader.min_tier(), RarityTier::Common as u8);
 			});
 	}
 
 	#[test]
-	fn forge_should_work() {
-		let season = Season::default()
-			.tiers(&[RarityTier::Common, RarityTier::Uncommon, RarityTier::Legendary])
-			.single_mint_probs(&[100, 0])
-			.batch_mint_probs(&[100, 0])
-			.base_prob(30)
-			.per_period(1)
-			.periods(6)
-			.max_tier_forges(1)
-			.max_components(8)
-			.max_variations(6)
-			.min_sacrifices(1)
-			.max_sacrifices(4);
+	fn settle_auction_without_bids_should_keep_avatar() {
+		let season = Season::default();
 
-		let mut forged_count = 0;
-		let mut assert_dna =
-			|leader_id: &AvatarIdOf<Test>, expected_dna: &[u8], insert_dna: Option<&[u8]>| {
-				assert_ok!(AAvatars::mint(
-					RuntimeOrigin::signed(BOB),
-					MintOption { count: MintPackSize::Three, mint_type: MintType::Normal }
-				));
-				assert_ok!(AAvatars::mint(
-					RuntimeOrigin::signed(BOB),
-					MintOption { count: MintPackSize::One, mint_type: MintType::Normal }
-				));
+		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
+			run_to_block(season.start);
+			let avatar_id = create_avatars(1, BOB, 1)[0];
+			let end = season.start + 1;
+			assert_ok!(AAvatars::create_auction(RuntimeOrigin::signed(BOB), avatar_id, 1, end));
+
+			run_to_block(end);
+			assert_ok!(AAvatars::settle_auction(RuntimeOrigin::signed(BOB), avatar_id));
+			assert_eq!(AAvatars::auctions(avatar_id), None);
+			assert_eq!(AAvatars::avatars(avatar_id).unwrap().0, BOB);
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(crate::Event::AuctionSettled {
+				avatar_id,
+				seller: BOB,
+				winner: None,
+				price: None,
+			}));
+		});
+	}
 
-				if let Some(dna) = insert_dna {
-					AAvatars::owners(BOB)[1..=4].iter().for_each(|id| {
-						Avatars::<Test>::mutate(id, |maybe_avatar| {
-							if let Some((_, avatar)) = maybe_avatar {
-								avatar.dna = dna.to_vec().try_into().unwrap();
-							}
-						});
-					})
-				}
+	#[test]
+	fn cancel_auction_should_work() {
+		let season = Season::default();
+
+		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
+			run_to_block(season.start);
+			let avatar_id = create_avatars(1, BOB, 1)[0];
+			assert_ok!(AAvatars::create_auction(RuntimeOrigin::signed(BOB), avatar_id, 1, 10));
+
+			assert_noop!(
+				AAvatars::cancel_auction(RuntimeOrigin::signed(ALICE), avatar_id),
+				Error::<Test>::Ownership
+			);
+			assert_ok!(AAvatars::cancel_auction(RuntimeOrigin::signed(BOB), avatar_id));
+			assert_eq!(AAvatars::auctions(avatar_id), None);
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(
+				crate::Event::AuctionCancelled { avatar_id },
+			));
+		});
+	}
 
-				let original_leader_souls = AAvatars::avatars(leader_id).unwrap().1.souls;
-				let sacrifice_ids = AAvatars::owners(BOB)[1..=4].to_vec();
-				let sacrifice_souls = sacrifice_ids
-					.iter()
-					.map(|id| AAvatars::avatars(id).unwrap().1.souls)
-					.sum::<SoulCount>();
-				assert_ne!(sacrifice_souls, 0);
+	#[test]
+	fn cancel_auction_should_reject_auctions_with_bids() {
+		let season = Season::default();
 
-				assert_ok!(AAvatars::forge(RuntimeOrigin::signed(BOB), *leader_id, sacrifice_ids));
-				assert_eq!(
-					AAvatars::avatars(leader_id).unwrap().1.souls,
-					original_leader_souls + sacrifice_souls
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.balances(&[(ALICE, 10_000)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_id = create_avatars(1, BOB, 1)[0];
+				assert_ok!(AAvatars::create_auction(RuntimeOrigin::signed(BOB), avatar_id, 1, 10));
+				assert_ok!(AAvatars::bid(RuntimeOrigin::signed(ALICE), avatar_id, 100));
+				assert_noop!(
+					AAvatars::cancel_auction(RuntimeOrigin::signed(BOB), avatar_id),
+					Error::<Test>::AuctionHasBids
 				);
-				assert_eq!(AAvatars::avatars(leader_id).unwrap().1.dna.to_vec(), expected_dna);
+			});
+	}
+}
 
-				forged_count += 1;
-				assert_eq!(
-					AAvatars::accounts(BOB)
-						.stats
-						.forge
-						.seasons_participated
-						.iter()
-						.map(|season_id| AAvatars::season_stats(season_id, BOB).forged)
-						.sum::<u32>(),
-					forged_count as u32
-				);
-				assert_eq!(AAvatars::season_stats(1, BOB).forged, forged_count);
-				assert_eq!(AAvatars::accounts(BOB).stats.forge.first, season.start);
-				assert_eq!(AAvatars::accounts(BOB).stats.forge.last, System::block_number());
-			};
+mod offer {
+	use super::*;
+
+	#[test]
+	fn make_offer_should_work() {
+		let season = Season::default();
+		let min_fee = 100;
 
 		ExtBuilder::default()
 			.seasons(&[(1, season.clone())])
-			.mint_cooldown(0)
-			.mint_fees(MintFees { one: 1, three: 3, six: 6 })
-			.balances(&[(BOB, MockBalance::max_value())])
-			.free_mints(&[(BOB, 0)])
+			.balances(&[(ALICE, 10_000)])
+			.trade_min_fee(min_fee)
 			.build()
 			.execute_with(|| {
 				run_to_block(season.start);
-				assert_ok!(AAvatars::mint(
-					RuntimeOrigin::signed(BOB),
-					MintOption { count: MintPackSize::One, mint_type: MintType::Normal }
-				));
-				let leader_id = AAvatars::owners(BOB)[0];
+				let avatar_id = create_avatars(1, BOB, 1)[0];
+
+				assert_ok!(AAvatars::make_offer(RuntimeOrigin::signed(ALICE), avatar_id, 1_000));
 				assert_eq!(
-					AAvatars::avatars(&leader_id).unwrap().1.dna.to_vec(),
-					&[0x01, 0x01, 0x03, 0x04, 0x05, 0x04, 0x04, 0x00]
+					AAvatars::offers(avatar_id, ALICE),
+					Some(Offer { amount: 1_000, fee: min_fee, fee_asset: None })
 				);
+				assert_eq!(Balances::reserved_balance(ALICE), 1_000 + min_fee);
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(crate::Event::OfferMade {
+					avatar_id,
+					buyer: ALICE,
+					amount: 1_000,
+				}));
 
-				// 1st mutation
-				assert_dna(&leader_id, &[0x11, 0x11, 0x13, 0x14, 0x05, 0x04, 0x04, 0x00], None);
+				// a new offer replaces the previous one
+				assert_ok!(AAvatars::make_offer(RuntimeOrigin::signed(ALICE), avatar_id, 500));
+				assert_eq!(Balances::reserved_balance(ALICE), 500 + min_fee);
+			});
+	}
 
-				// 2nd mutation
-				assert_dna(&leader_id, &[0x11, 0x11, 0x13, 0x14, 0x15, 0x14, 0x04, 0x00], None);
+	#[test]
+	fn make_offer_should_reject_invalid_offers() {
+		let season = Season::default();
 
-				// 3rd mutation
-				assert_dna(&leader_id, &[0x11, 0x11, 0x13, 0x14, 0x15, 0x14, 0x04, 0x00], None);
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.balances(&[(ALICE, 1_000), (BOB, 1_000)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_id = create_avatars(1, BOB, 1)[0];
 
-				// 4th mutation
-				assert_dna(
-					&leader_id,
-					&[0x11, 0x11, 0x13, 0x14, 0x15, 0x14, 0x14, 0x10],
-					Some(&[0x11, 0x11, 0x13, 0x14, 0x15, 0x14, 0x05, 0x01]),
+				assert_noop!(
+					AAvatars::make_offer(RuntimeOrigin::signed(ALICE), avatar_id, 0),
+					Error::<Test>::InvalidOffer
 				);
-
-				// 5th mutation
-				assert_dna(
-					&leader_id,
-					&[0x41, 0x41, 0x43, 0x44, 0x15, 0x14, 0x14, 0x10],
-					Some(&[0x12, 0x12, 0x14, 0x15, 0x15, 0x14, 0x14, 0x10]),
+				assert_noop!(
+					AAvatars::make_offer(RuntimeOrigin::signed(BOB), avatar_id, 100),
+					Error::<Test>::AlreadyOwned
 				);
-
-				// 6th mutation
-				assert_dna(
-					&leader_id,
-					&[0x41, 0x41, 0x43, 0x44, 0x45, 0x44, 0x44, 0x10],
-					Some(&[0x41, 0x41, 0x43, 0x44, 0x16, 0x15, 0x15, 0x10]),
+				assert_noop!(
+					AAvatars::make_offer(
+						RuntimeOrigin::signed(ALICE),
+						sp_core::H256::default(),
+						100
+					),
+					Error::<Test>::UnknownAvatar
+				);
+				assert_noop!(
+					AAvatars::make_offer(RuntimeOrigin::signed(ALICE), avatar_id, 2_000),
+					pallet_balances::Error::<Test>::InsufficientBalance
 				);
 
-				// force highest tier mint and assert for associated checks
-				assert_eq!(AAvatars::current_season_status().max_tier_avatars, 0);
-				assert_dna(
-					&leader_id,
-					&[0x41, 0x41, 0x43, 0x44, 0x45, 0x44, 0x44, 0x40],
-					Some(&[0x41, 0x41, 0x43, 0x44, 0x45, 0x44, 0x44, 0x11]),
+				GlobalConfigs::<Test>::mutate(|config| config.trade.open = false);
+				assert_noop!(
+					AAvatars::make_offer(RuntimeOrigin::signed(ALICE), avatar_id, 100),
+					Error::<Test>::TradeClosed
 				);
-				assert_eq!(AAvatars::current_season_status().max_tier_avatars, 1);
-				assert!(AAvatars::current_season_status().early_ended);
+			});
+	}
+
+	#[test]
+	fn make_offer_should_reject_too_many_offers() {
+		use frame_support::traits::Get;
+		let season = Season::default();
+
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.balances(&[(ALICE, 10_000), (CHARLIE, 10_000), (DAVE, 10_000), (5, 10_000)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_id = create_avatars(1, BOB, 1)[0];
+				let max_offers = <Test as crate::Config>::MaxOffersPerAvatar::get();
+				assert_eq!(max_offers, 3);
+
+				for buyer in [ALICE, CHARLIE, DAVE] {
+					assert_ok!(AAvatars::make_offer(RuntimeOrigin::signed(buyer), avatar_id, 100));
+				}
 				assert_noop!(
-					AAvatars::mint(
-						RuntimeOrigin::signed(BOB),
-						MintOption { count: MintPackSize::One, mint_type: MintType::Normal }
-					),
-					Error::<Test>::PrematureSeasonEnd
+					AAvatars::make_offer(RuntimeOrigin::signed(5), avatar_id, 100),
+					Error::<Test>::TooManyOffers
 				);
 
-				// check stats for season 1
-				assert_eq!(AAvatars::season_stats(1, BOB).forged, 7);
-				assert_eq!(AAvatars::season_stats(1, BOB).minted, 29);
+				// replacing an existing offer is still allowed
+				assert_ok!(AAvatars::make_offer(RuntimeOrigin::signed(ALICE), avatar_id, 200));
+			});
+	}
 
-				// trigger season end and assert for associated checks
-				run_to_block(season.end + 1);
-				assert_eq!(AAvatars::current_season_status().max_tier_avatars, 0);
-				assert!(!AAvatars::current_season_status().early_ended);
+	#[test]
+	fn offers_should_be_refunded_when_the_avatar_is_burned() {
+		let season = Season::default();
 
-				// check stats for season 2
-				assert_eq!(AAvatars::season_stats(2, BOB).forged, 0);
-				assert_eq!(AAvatars::season_stats(2, BOB).minted, 0);
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.balances(&[(ALICE, 10_000), (CHARLIE, 10_000)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_id = create_avatars(1, BOB, 1)[0];
+				assert_ok!(AAvatars::make_offer(RuntimeOrigin::signed(ALICE), avatar_id, 1_000));
+				assert_ok!(AAvatars::make_offer(RuntimeOrigin::signed(CHARLIE), avatar_id, 2_000));
+
+				assert_ok!(AAvatars::extract_souls(RuntimeOrigin::signed(BOB), avatar_id));
+				assert_eq!(Offers::<Test>::iter_prefix(avatar_id).count(), 0);
+				assert_eq!(Balances::reserved_balance(ALICE), 0);
+				assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+				assert_eq!(Balances::free_balance(ALICE), 10_000);
+				assert_eq!(Balances::free_balance(CHARLIE), 10_000);
 			});
 	}
 
 	#[test]
-	fn forge_should_update_max_tier_avatars() {
-		let season = Season::default()
-			.tiers(&[RarityTier::Common, RarityTier::Legendary])
-			.max_components(8)
-			.max_variations(6)
-			.max_tier_forges(5);
+	fn offer_of_the_new_owner_should_be_refunded_on_transfer() {
+		let season = Season::default();
 
 		ExtBuilder::default()
 			.seasons(&[(1, season.clone())])
-			.mint_cooldown(0)
-			.free_mints(&[(BOB, MintCount::MAX)])
+			.balances(&[(ALICE, 10_000), (BOB, 10_000), (CHARLIE, 10_000)])
 			.build()
 			.execute_with(|| {
-				run_to_block(season.early_start);
+				run_to_block(season.start);
+				let avatar_id = create_avatars(1, BOB, 1)[0];
+				assert_ok!(AAvatars::make_offer(RuntimeOrigin::signed(ALICE), avatar_id, 1_000));
+				assert_ok!(AAvatars::make_offer(RuntimeOrigin::signed(CHARLIE), avatar_id, 2_000));
 
-				let mut max_tier_avatars = 0;
-				let common_avatar_ids = [
-					create_avatar_for_bob(&[0x41, 0x42, 0x43, 0x44, 0x45, 0x44, 0x43, 0x02]),
-					create_avatar_for_bob(&[0x41, 0x42, 0x43, 0x44, 0x45, 0x44, 0x43, 0x03]),
-					create_avatar_for_bob(&[0x41, 0x42, 0x43, 0x44, 0x45, 0x44, 0x43, 0x03]),
-					create_avatar_for_bob(&[0x41, 0x42, 0x43, 0x44, 0x45, 0x44, 0x43, 0x03]),
-				];
+				assert_ok!(AAvatars::transfer_avatar(RuntimeOrigin::signed(BOB), ALICE, avatar_id));
+				assert_eq!(AAvatars::offers(avatar_id, ALICE), None);
+				assert_eq!(Balances::reserved_balance(ALICE), 0);
+				assert_eq!(Balances::free_balance(ALICE), 10_000);
 
-				// `max_tier_avatars` increases when a legendary is forged
-				assert_eq!(AAvatars::current_season_status().max_tier_avatars, max_tier_avatars);
-				assert_ok!(AAvatars::forge(
-					RuntimeOrigin::signed(BOB),
-					common_avatar_ids[0],
-					common_avatar_ids[1..].to_vec()
+				// offers of others carry over to the new owner
+				assert!(AAvatars::offers(avatar_id, CHARLIE).is_some());
+				assert_ok!(AAvatars::accept_offer(
+					RuntimeOrigin::signed(ALICE),
+					avatar_id,
+					CHARLIE
 				));
-				max_tier_avatars += 1;
-				assert_eq!(AAvatars::current_season_status().max_tier_avatars, max_tier_avatars);
-				assert_eq!(AAvatars::owners(BOB).len(), 4 - 3);
+				assert_eq!(AAvatars::avatars(avatar_id).unwrap().0, CHARLIE);
+			});
+	}
 
-				// `max_tier_avatars` decreases when legendaries are sacrificed
-				let legendary_avatar_ids = [
-					create_avatar_for_bob(&[0x41, 0x42, 0x43, 0x44, 0x45, 0x44, 0x43, 0x42]),
-					create_avatar_for_bob(&[0x41, 0x42, 0x43, 0x44, 0x45, 0x44, 0x43, 0x42]),
-					create_avatar_for_bob(&[0x41, 0x42, 0x43, 0x44, 0x45, 0x44, 0x43, 0x42]),
-					create_avatar_for_bob(&[0x41, 0x42, 0x43, 0x44, 0x45, 0x44, 0x43, 0x42]),
-				];
-				max_tier_avatars += 4;
-				assert_eq!(AAvatars::current_season_status().max_tier_avatars, max_tier_avatars);
+	#[test]
+	fn withdraw_offer_should_work() {
+		let season = Season::default();
 
-				// leader is already legendary so max_tier_avatars isn't incremented
-				assert_ok!(AAvatars::forge(
-					RuntimeOrigin::signed(BOB),
-					legendary_avatar_ids[0],
-					legendary_avatar_ids[1..].to_vec()
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.balances(&[(ALICE, 10_000)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_id = create_avatars(1, BOB, 1)[0];
+
+				assert_noop!(
+					AAvatars::withdraw_offer(RuntimeOrigin::signed(ALICE), avatar_id),
+					Error::<Test>::UnknownOffer
+				);
+				assert_ok!(AAvatars::make_offer(RuntimeOrigin::signed(ALICE), avatar_id, 1_000));
+				assert_ok!(AAvatars::withdraw_offer(RuntimeOrigin::signed(ALICE), avatar_id));
+				assert_eq!(AAvatars::offers(avatar_id, ALICE), None);
+				assert_eq!(Balances::reserved_balance(ALICE), 0);
+				assert_eq!(Balances::free_balance(ALICE), 10_000);
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::OfferWithdrawn { avatar_id, buyer: ALICE },
 				));
-				assert_eq!(AAvatars::current_season_status().max_tier_avatars, max_tier_avatars);
-				assert_eq!(AAvatars::owners(BOB).len(), (4 - 3) + (4 - 3));
+			});
+	}
+
+	#[test]
+	fn accept_offer_should_work() {
+		let season = Season::default();
+		let min_fee = 100;
+		let price = 1_000;
+		let (alice_initial_bal, bob_initial_bal) = (10_000, 5_000);
+
+		ExtBuilder::default()
+			.existential_deposit(0)
+			.seasons(&[(1, season.clone())])
+			.balances(&[(ALICE, alice_initial_bal), (BOB, bob_initial_bal)])
+			.trade_min_fee(min_fee)
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_id = create_avatars(1, BOB, 1)[0];
+				assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(BOB), avatar_id, 5_000));
+				assert_ok!(AAvatars::make_offer(RuntimeOrigin::signed(ALICE), avatar_id, price));
+
+				assert_ok!(AAvatars::accept_offer(RuntimeOrigin::signed(BOB), avatar_id, ALICE));
+
+				assert_eq!(AAvatars::offers(avatar_id, ALICE), None);
+				assert_eq!(AAvatars::trade(avatar_id), None);
+				assert_eq!(AAvatars::avatars(avatar_id).unwrap().0, ALICE);
+				assert_eq!(Balances::reserved_balance(ALICE), 0);
+				assert_eq!(Balances::free_balance(ALICE), alice_initial_bal - price - min_fee);
+				assert_eq!(Balances::free_balance(BOB), bob_initial_bal + price);
+				assert_eq!(AAvatars::treasury(1), min_fee);
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::OfferAccepted { avatar_id, from: BOB, to: ALICE, amount: price },
+				));
+			});
+	}
+
+	#[test]
+	fn offers_hold_fees_in_the_fee_asset() {
+		let season = Season::default();
+		let min_fee = 100;
+
+		ExtBuilder::default()
+			.existential_deposit(0)
+			.seasons(&[(1, season.clone())])
+			.balances(&[(ALICE, 10_000), (BOB, 10_000)])
+			.trade_min_fee(min_fee)
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				create_asset(&[(ALICE, 1_000)]);
+				PaymentAssets::<Test>::insert(ASSET_ID, ConversionRate { native: 10, asset: 3 });
+				FeeAssets::<Test>::insert(ALICE, ASSET_ID);
+				let treasury_account = AAvatars::treasury_account_id();
+				let avatar_id = create_avatars(1, BOB, 1)[0];
+
+				assert_ok!(AAvatars::make_offer(RuntimeOrigin::signed(ALICE), avatar_id, 1_000));
 				assert_eq!(
-					AAvatars::accounts(BOB)
-						.stats
-						.forge
-						.seasons_participated
-						.into_iter()
-						.collect::<Vec<_>>(),
-					vec![1]
+					AAvatars::offers(avatar_id, ALICE),
+					Some(Offer { amount: 1_000, fee: 30, fee_asset: Some(ASSET_ID) })
 				);
+				assert_eq!(Balances::reserved_balance(ALICE), 1_000);
+				assert_eq!(Assets::balance(ASSET_ID, ALICE), 1_000 - 30);
+
+				// withdrawing returns the fee held in the asset
+				assert_ok!(AAvatars::withdraw_offer(RuntimeOrigin::signed(ALICE), avatar_id));
+				assert_eq!(Balances::reserved_balance(ALICE), 0);
+				assert_eq!(Assets::balance(ASSET_ID, ALICE), 1_000);
+				assert_eq!(Assets::balance(ASSET_ID, treasury_account), 0);
+
+				assert_ok!(AAvatars::make_offer(RuntimeOrigin::signed(ALICE), avatar_id, 2_000));
+				assert_ok!(AAvatars::accept_offer(RuntimeOrigin::signed(BOB), avatar_id, ALICE));
+				assert_eq!(AAvatars::avatars(avatar_id).unwrap().0, ALICE);
+				assert_eq!(Balances::reserved_balance(ALICE), 0);
+				assert_eq!(Balances::free_balance(ALICE), 10_000 - 2_000);
+				assert_eq!(Balances::free_balance(BOB), 10_000 + 2_000);
+				assert_eq!(Assets::balance(ASSET_ID, ALICE), 1_000 - 30);
+				assert_eq!(Assets::balance(ASSET_ID, treasury_account), 30);
+				assert_eq!(AAvatars::asset_treasury(1, ASSET_ID), 30);
+				assert_eq!(AAvatars::treasury(1), 0);
+			});
+	}
+
+	#[test]
+	fn accept_offer_should_reject_incorrect_ownership_and_unknown_offers() {
+		let season = Season::default();
 
-				// NOTE: BOB forged twice so the seasonal forged count is 2
-				assert_eq!(AAvatars::season_stats(1, BOB).forged, 2);
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.balances(&[(ALICE, 10_000)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_id = create_avatars(1, BOB, 1)[0];
+				assert_ok!(AAvatars::make_offer(RuntimeOrigin::signed(ALICE), avatar_id, 1_000));
+
+				assert_noop!(
+					AAvatars::accept_offer(RuntimeOrigin::signed(CHARLIE), avatar_id, ALICE),
+					Error::<Test>::Ownership
+				);
+				assert_noop!(
+					AAvatars::accept_offer(RuntimeOrigin::signed(BOB), avatar_id, CHARLIE),
+					Error::<Test>::UnknownOffer
+				);
 			});
 	}
 
 	#[test]
-	fn forge_should_work_for_matches() {
-		let tiers = &[RarityTier::Common, RarityTier::Legendary];
-		let season = Season::default()
-			.tiers(tiers)
-			.batch_mint_probs(&[100])
-			.max_components(5)
-			.max_variations(3)
-			.min_sacrifices(1)
-			.max_sacrifices(2);
+	fn accept_offer_should_reject_auctioned_avatars() {
+		let season = Season::default();
 
 		ExtBuilder::default()
 			.seasons(&[(1, season.clone())])
-			.mint_cooldown(1)
-			.free_mints(&[(BOB, 10)])
+			.balances(&[(ALICE, 10_000)])
 			.build()
 			.execute_with(|| {
-				// prepare avatars to forge
 				run_to_block(season.start);
-				assert_ok!(AAvatars::mint(
-					RuntimeOrigin::signed(BOB),
-					MintOption { count: MintPackSize::Six, mint_type: MintType::Free }
-				));
+				let avatar_id = create_avatars(1, BOB, 1)[0];
+				assert_ok!(AAvatars::make_offer(RuntimeOrigin::signed(ALICE), avatar_id, 1_000));
+				assert_ok!(AAvatars::create_auction(RuntimeOrigin::signed(BOB), avatar_id, 1, 10));
 
-				// forge
-				let owned_avatar_ids = AAvatars::owners(BOB);
-				let leader_id = owned_avatar_ids[0];
-				let sacrifice_ids = &owned_avatar_ids[1..3];
+				assert_noop!(
+					AAvatars::accept_offer(RuntimeOrigin::signed(BOB), avatar_id, ALICE),
+					Error::<Test>::AvatarInTrade
+				);
+			});
+	}
+}
 
-				let original_leader = AAvatars::avatars(leader_id).unwrap().1;
-				let original_sacrifices = sacrifice_ids
-					.iter()
-					.map(|id| AAvatars::avatars(id).unwrap().1)
-					.collect::<Vec<_>>();
+mod rental {
+	use super::*;
 
-				assert_ok!(AAvatars::forge(
-					RuntimeOrigin::signed(BOB),
-					leader_id,
-					sacrifice_ids.to_vec()
-				));
-				let forged_leader = AAvatars::avatars(leader_id).unwrap().1;
+	#[test]
+	fn list_for_rent_should_work() {
+		let season = Season::default();
 
-				// check the result of the compare method
-				let upgradable_indexes = original_leader.upgradable_indexes::<Test>().unwrap();
-				for (sacrifice, result) in original_sacrifices
-					.iter()
-					.zip([(true, BTreeSet::from([0, 1, 2, 3])), (true, BTreeSet::from([1]))])
-				{
-					assert_eq!(
-						original_leader.compare(
-							sacrifice,
-							&upgradable_indexes,
-							season.max_variations,
-							tiers[tiers.len() - 1].clone() as u8
-						),
-						result
-					)
-				}
+		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
+			run_to_block(season.start);
+			let avatar_id = create_avatars(1, BOB, 1)[0];
 
-				// check all sacrifices are burned
-				for sacrifice_id in sacrifice_ids {
-					assert!(!Avatars::<Test>::contains_key(sacrifice_id));
-				}
-				// check for souls accumulation
-				assert_eq!(
-					forged_leader.souls,
-					original_leader.souls +
-						original_sacrifices.iter().map(|x| x.souls).sum::<SoulCount>(),
-				);
+			assert_ok!(AAvatars::list_for_rent(RuntimeOrigin::signed(BOB), avatar_id, 123, 10));
+			assert_eq!(
+				AAvatars::rental_listings(avatar_id),
+				Some(RentalListing { price: 123, duration: 10 })
+			);
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(
+				crate::Event::AvatarListedForRent { avatar_id, price: 123, duration: 10 },
+			));
 
-				// check for the upgraded DNA
-				assert_ne!(original_leader.dna[0..=1], forged_leader.dna[0..=1]);
-				assert_eq!(original_leader.dna.to_vec()[0] >> 4, RarityTier::Common as u8);
-				assert_eq!(original_leader.dna.to_vec()[1] >> 4, RarityTier::Common as u8);
-				assert_eq!(forged_leader.dna.to_vec()[0] >> 4, RarityTier::Legendary as u8);
-				assert_eq!(forged_leader.dna.to_vec()[1] >> 4, RarityTier::Legendary as u8);
-				System::assert_last_event(mock::RuntimeEvent::AAvatars(
-					crate::Event::AvatarForged { avatar_id: leader_id, upgraded_components: 2 },
-				));
+			assert_noop!(
+				AAvatars::set_price(RuntimeOrigin::signed(BOB), avatar_id, 123),
+				Error::<Test>::AvatarInRental
+			);
+			assert_noop!(
+				AAvatars::create_auction(RuntimeOrigin::signed(BOB), avatar_id, 1, 10),
+				Error::<Test>::AvatarInRental
+			);
+		});
+	}
 
-				// variations remain the same
-				assert_eq!(
-					original_leader.dna[0..=1].iter().map(|x| x & 0b0000_1111).collect::<Vec<_>>(),
-					forged_leader.dna[0..=1].iter().map(|x| x & 0b0000_1111).collect::<Vec<_>>(),
-				);
-				// other components remain the same
-				assert_eq!(
-					original_leader.dna[2..season.max_components as usize],
-					forged_leader.dna[2..season.max_components as usize]
-				);
-			});
+	#[test]
+	fn list_for_rent_should_reject_invalid_listings() {
+		let season = Season::default();
+
+		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
+			run_to_block(season.start);
+			let avatar_ids = create_avatars(1, BOB, 2);
+
+			assert_noop!(
+				AAvatars::list_for_rent(RuntimeOrigin::signed(CHARLIE), avatar_ids[0], 1, 10),
+				Error::<Test>::Ownership
+			);
+			assert_noop!(
+				AAvatars::list_for_rent(RuntimeOrigin::signed(BOB), avatar_ids[0], 1, 0),
+				Error::<Test>::InvalidRentalDuration
+			);
+
+			assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(BOB), avatar_ids[1], 123));
+			assert_noop!(
+				AAvatars::list_for_rent(RuntimeOrigin::signed(BOB), avatar_ids[1], 1, 10),
+				Error::<Test>::AvatarInTrade
+			);
+
+			GlobalConfigs::<Test>::mutate(|config| config.trade.open = false);
+			assert_noop!(
+				AAvatars::list_for_rent(RuntimeOrigin::signed(BOB), avatar_ids[0], 1, 10),
+				Error::<Test>::TradeClosed
+			);
+		});
 	}
 
 	#[test]
-	fn forge_should_work_for_non_matches() {
-		let tiers =
-			&[RarityTier::Common, RarityTier::Uncommon, RarityTier::Rare, RarityTier::Legendary];
-		let season = Season::default()
-			.tiers(tiers)
-			.batch_mint_probs(&[33, 33, 34])
-			.max_components(10)
-			.max_variations(12)
-			.min_sacrifices(1)
-			.max_sacrifices(5);
+	fn unlist_from_rent_should_work() {
+		let season = Season::default();
+
+		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
+			run_to_block(season.start);
+			let avatar_id = create_avatars(1, BOB, 1)[0];
+
+			assert_noop!(
+				AAvatars::unlist_from_rent(RuntimeOrigin::signed(BOB), avatar_id),
+				Error::<Test>::UnknownRentalListing
+			);
+			assert_ok!(AAvatars::list_for_rent(RuntimeOrigin::signed(BOB), avatar_id, 123, 10));
+			assert_noop!(
+				AAvatars::unlist_from_rent(RuntimeOrigin::signed(CHARLIE), avatar_id),
+				Error::<Test>::Ownership
+			);
+			assert_ok!(AAvatars::unlist_from_rent(RuntimeOrigin::signed(BOB), avatar_id));
+			assert_eq!(AAvatars::rental_listings(avatar_id), None);
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(
+				crate::Event::AvatarUnlistedFromRent { avatar_id },
+			));
+		});
+	}
+
+	#[test]
+	fn rent_should_work() {
+		let season = Season::default();
+		let (price, min_fee, duration) = (1_000, 100, 5);
+		let (alice_initial_bal, bob_initial_bal) = (10_000, 5_000);
 
 		ExtBuilder::default()
+			.existential_deposit(0)
 			.seasons(&[(1, season.clone())])
-			.mint_cooldown(1)
-			.free_mints(&[(BOB, 10)])
+			.balances(&[(ALICE, alice_initial_bal), (BOB, bob_initial_bal)])
+			.trade_min_fee(min_fee)
 			.build()
 			.execute_with(|| {
-				// prepare avatars to forge
 				run_to_block(season.start);
-				assert_ok!(AAvatars::mint(
+				let avatar_id = create_avatars(1, BOB, 1)[0];
+				assert_ok!(AAvatars::list_for_rent(
 					RuntimeOrigin::signed(BOB),
-					MintOption { count: MintPackSize::Six, mint_type: MintType::Free }
+					avatar_id,
+					price,
+					duration
 				));
 
-				// forge
-				let owned_avatar_ids = AAvatars::owners(BOB);
-				let leader_id = owned_avatar_ids[0];
-				let sacrifice_id = owned_avatar_ids[1];
-
-				let original_leader = AAvatars::avatars(leader_id).unwrap().1;
-				let original_sacrifice = AAvatars::avatars(sacrifice_id).unwrap().1;
+				assert_ok!(AAvatars::rent(RuntimeOrigin::signed(ALICE), avatar_id));
+				let expiry = season.start + duration;
 
-				assert_ok!(AAvatars::forge(
-					RuntimeOrigin::signed(BOB),
-					leader_id,
-					vec![sacrifice_id]
+				assert_eq!(AAvatars::rental_listings(avatar_id), None);
+				assert_eq!(AAvatars::rentals(avatar_id), Some(Rental { renter: ALICE, expiry }));
+				assert_eq!(AAvatars::rental_expiries(expiry).to_vec(), vec![avatar_id]);
+				assert_eq!(AAvatars::avatars(avatar_id).unwrap().0, BOB);
+				assert_eq!(Balances::free_balance(ALICE), alice_initial_bal - price - min_fee);
+				assert_eq!(Balances::free_balance(BOB), bob_initial_bal + price);
+				assert_eq!(AAvatars::treasury(1), min_fee);
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::AvatarRented { avatar_id, owner: BOB, renter: ALICE, expiry },
 				));
-				let forged_leader = AAvatars::avatars(leader_id).unwrap().1;
 
-				// check the result of the compare method
-				let upgradable_indexes = original_leader.upgradable_indexes::<Test>().unwrap();
-				assert_eq!(
-					original_leader.compare(
-						&original_sacrifice,
-						&upgradable_indexes,
-						season.max_variations,
-						tiers[tiers.len() - 1].clone() as u8
-					),
-					(false, BTreeSet::new())
+				// neither the owner nor the renter can trade a rented avatar
+				assert_noop!(
+					AAvatars::set_price(RuntimeOrigin::signed(BOB), avatar_id, 123),
+					Error::<Test>::AvatarInRental
+				);
+				assert_noop!(
+					AAvatars::set_price(RuntimeOrigin::signed(ALICE), avatar_id, 123),
+					Error::<Test>::Ownership
+				);
+				assert_noop!(
+					AAvatars::list_for_rent(RuntimeOrigin::signed(BOB), avatar_id, 1, 1),
+					Error::<Test>::AvatarInRental
 				);
-				// check all sacrifices are burned
-				assert!(!Avatars::<Test>::contains_key(sacrifice_id));
-				// check for souls accumulation
-				assert_eq!(forged_leader.souls, original_leader.souls + original_sacrifice.souls);
-
-				// check DNAs are the same
-				assert_eq!(original_leader.dna, forged_leader.dna);
-				System::assert_last_event(mock::RuntimeEvent::AAvatars(
-					crate::Event::AvatarForged { avatar_id: leader_id, upgraded_components: 0 },
-				));
 			});
 	}
 
 	#[test]
-	fn forge_should_ignore_low_tier_sacrifices() {
-		let tiers = &[RarityTier::Common, RarityTier::Rare, RarityTier::Legendary];
-		let season = Season::default()
-			.tiers(tiers)
-			.single_mint_probs(&[100, 0])
-			.batch_mint_probs(&[100, 0])
-			.max_tier_forges(1)
-			.max_components(4)
-			.max_variations(6)
-			.min_sacrifices(1)
-			.max_sacrifices(4);
+	fn rent_should_reject_invalid_rentals() {
+		let season = Season::default();
 
 		ExtBuilder::default()
 			.seasons(&[(1, season.clone())])
-			.mint_cooldown(0)
-			.free_mints(&[(ALICE, MintCount::MAX)])
+			.balances(&[(ALICE, 500), (BOB, 500)])
 			.build()
 			.execute_with(|| {
 				run_to_block(season.start);
-				assert_ok!(AAvatars::mint(
-					RuntimeOrigin::signed(ALICE),
-					MintOption { count: MintPackSize::Six, mint_type: MintType::Free }
-				));
-				let leader_id = AAvatars::owners(ALICE)[0];
-				assert_eq!(
-					AAvatars::avatars(&leader_id).unwrap().1.dna.to_vec(),
-					&[0x01, 0x05, 0x05, 0x04]
-				);
-				assert_eq!(
-					AAvatars::avatars(&leader_id).unwrap().1.min_tier(),
-					tiers[0].clone() as u8,
-				);
+				let avatar_id = create_avatars(1, BOB, 1)[0];
 
-				// mutate the DNA of leader to make it a tier higher
-				let mut leader_avatar = AAvatars::avatars(leader_id).unwrap();
-				leader_avatar.1.dna = leader_avatar
-					.1
-					.dna
-					.iter()
-					.map(|x| ((tiers[1].clone() as u8) << 4) | (x & 0b0000_1111))
-					.collect::<Vec<_>>()
-					.try_into()
-					.unwrap();
-				Avatars::<Test>::insert(leader_id, &leader_avatar);
-				assert_eq!(
-					AAvatars::avatars(&leader_id).unwrap().1.min_tier(),
-					tiers[1].clone() as u8
+				assert_noop!(
+					AAvatars::rent(RuntimeOrigin::signed(ALICE), avatar_id),
+					Error::<Test>::UnknownRentalListing
 				);
 
-				// forging doesn't take effect
-				let sacrifice_ids = &AAvatars::owners(ALICE)[1..5];
-				assert_ok!(AAvatars::forge(
-					RuntimeOrigin::signed(ALICE),
-					leader_id,
-					sacrifice_ids.to_vec()
+				assert_ok!(AAvatars::list_for_rent(
+					RuntimeOrigin::signed(BOB),
+					avatar_id,
+					1_000,
+					10
 				));
-				assert_eq!(AAvatars::avatars(leader_id).unwrap().1.dna, leader_avatar.1.dna);
+				assert_noop!(
+					AAvatars::rent(RuntimeOrigin::signed(BOB), avatar_id),
+					Error::<Test>::AlreadyOwned
+				);
+				assert_noop!(
+					AAvatars::rent(RuntimeOrigin::signed(ALICE), avatar_id),
+					pallet_balances::Error::<Test>::InsufficientBalance
+				);
+
+				GlobalConfigs::<Test>::mutate(|config| config.trade.open = false);
+				assert_noop!(
+					AAvatars::rent(RuntimeOrigin::signed(ALICE), avatar_id),
+					Error::<Test>::TradeClosed
+				);
 			});
 	}
 
 	#[test]
-	fn forge_should_reject_when_forging_is_closed() {
-		let season = Season::default().min_sacrifices(0);
+	fn rent_should_reject_too_many_rentals_expiring_at_once() {
+		use frame_support::traits::Get;
+		let season = Season::default();
 
-		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
-			run_to_block(season.start);
-			GlobalConfigs::<Test>::mutate(|config| config.forge.open = false);
-			assert_noop!(
-				AAvatars::forge(RuntimeOrigin::signed(ALICE), H256::default(), Vec::new()),
-				Error::<Test>::ForgeClosed,
-			);
-		});
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.balances(&[(ALICE, 10_000)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let max_rentals = <Test as crate::Config>::MaxRentalsPerBlock::get();
+				let avatar_ids = create_avatars(1, BOB, max_rentals as u8 + 1);
+				for avatar_id in &avatar_ids {
+					assert_ok!(AAvatars::list_for_rent(
+						RuntimeOrigin::signed(BOB),
+						*avatar_id,
+						10,
+						5
+					));
+				}
+
+				for avatar_id in &avatar_ids[..max_rentals as usize] {
+					assert_ok!(AAvatars::rent(RuntimeOrigin::signed(ALICE), *avatar_id));
+				}
+				assert_noop!(
+					AAvatars::rent(RuntimeOrigin::signed(ALICE), avatar_ids[max_rentals as usize]),
+					Error::<Test>::TooManyRentals
+				);
+			});
 	}
 
 	#[test]
-	fn forge_should_reject_unsigned_calls() {
-		ExtBuilder::default().build().execute_with(|| {
-			assert_noop!(
-				AAvatars::forge(RuntimeOrigin::none(), H256::default(), Vec::new()),
-				DispatchError::BadOrigin,
-			);
-		});
+	fn rentals_should_expire() {
+		let season = Season::default();
+		let duration = 5;
+
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.balances(&[(ALICE, 10_000), (BOB, 10_000)])
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				let avatar_id = create_avatars(1, BOB, 1)[0];
+				assert_ok!(AAvatars::list_for_rent(
+					RuntimeOrigin::signed(BOB),
+					avatar_id,
+					10,
+					duration
+				));
+				assert_ok!(AAvatars::rent(RuntimeOrigin::signed(ALICE), avatar_id));
+
+				let expiry = season.start + duration;
+				run_to_block(expiry - 1);
+				assert!(AAvatars::rentals(avatar_id).is_some());
+
+				run_to_block(expiry);
+				assert_eq!(AAvatars::rentals(avatar_id), None);
+				assert!(AAvatars::rental_expiries(expiry).is_empty());
+				System::assert_has_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::RentalExpired { avatar_id, renter: ALICE },
+				));
+				assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(BOB), avatar_id, 123));
+			});
 	}
 
 	#[test]
-	fn forge_should_reject_out_of_bound_sacrifices() {
-		let season = Season::default().min_sacrifices(3).max_sacrifices(5);
+	fn forge_should_use_rented_sacrifices_without_consuming_them() {
+		let season = Season::default();
 
-		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
-			run_to_block(season.start);
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.balances(&[(ALICE, 10_000), (BOB, 10_000), (CHARLIE, 10_000)])
+			.mint_fees(MintFees { one: 1, three: 1, six: 1 })
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+				for player in [ALICE, BOB] {
+					assert_ok!(AAvatars::mint(
+						RuntimeOrigin::signed(player),
+						MintOption { count: MintPackSize::Six, mint_type: MintType::Normal }
+					));
+				}
 
-			for i in 0..season.min_sacrifices {
+				let leader = minted_avatar_ids(ALICE)[0];
+				let sacrifice = minted_avatar_ids(ALICE)[1];
+				let rented = minted_avatar_ids(BOB)[0];
+				assert_ok!(AAvatars::list_for_rent(RuntimeOrigin::signed(BOB), rented, 10, 5));
+
+				// an avatar only listed for rent can't be forged with
 				assert_noop!(
-					AAvatars::forge(
-						RuntimeOrigin::signed(ALICE),
-						H256::default(),
-						(0..i).map(|_| H256::default()).collect::<Vec<_>>(),
-					),
-					Error::<Test>::TooFewSacrifices,
+					AAvatars::forge(RuntimeOrigin::signed(ALICE), leader, vec![sacrifice, rented]),
+					Error::<Test>::AvatarInRental
 				);
-			}
 
-			for i in (season.max_sacrifices + 1)..(season.max_sacrifices + 5) {
+				assert_ok!(AAvatars::rent(RuntimeOrigin::signed(ALICE), rented));
+
+				// only the renter can forge with a rented avatar, and only as a sacrifice
+				let charlie_leader = create_avatars(1, CHARLIE, 1)[0];
+				assert_noop!(
+					AAvatars::forge(RuntimeOrigin::signed(CHARLIE), charlie_leader, vec![rented]),
+					Error::<Test>::AvatarInRental
+				);
+				assert_noop!(
+					AAvatars::forge(RuntimeOrigin::signed(ALICE), rented, vec![sacrifice]),
+					Error::<Test>::AvatarInRental
+				);
 				assert_noop!(
 					AAvatars::forge(
-						RuntimeOrigin::signed(ALICE),
-						H256::default(),
-						(0..i).map(|_| H256::default()).collect::<Vec<_>>(),
+						RuntimeOrigin::signed(BOB),
+						rented,
+						vec![minted_avatar_ids(BOB)[1]]
 					),
-					Error::<Test>::TooManySacrifices,
+					Error::<Test>::AvatarInRental
 				);
-			}
-		});
+
+				assert_ok!(AAvatars::forge(
+					RuntimeOrigin::signed(ALICE),
+					leader,
+					vec![sacrifice, rented]
+				));
+				assert!(AAvatars::avatars(sacrifice).is_none());
+				assert!(!AAvatars::owners(&ALICE).contains(&sacrifice));
+				assert_eq!(AAvatars::avatars(rented).unwrap().0, BOB);
+				assert!(AAvatars::owners(&BOB).contains(&rented));
+				assert!(!AAvatars::owners(&ALICE).contains(&rented));
+			});
 	}
 
 	#[test]
-	fn forge_should_not_be_interrupted_by_season_status() {
-		let season_1 = Season::default().early_start(5).start(10).end(20);
-		let season_2 = Season::default().early_start(30).start(40).end(50);
-		let seasons = &[(1, season_1.clone()), (2, season_2.clone())];
+	fn forge_should_not_take_souls_from_rented_sacrifices() {
+		let season = Season::default();
 
 		ExtBuilder::default()
-			.seasons(seasons)
-			.mint_cooldown(0)
-			.free_mints(&[(ALICE, 100)])
+			.seasons(&[(1, season.clone())])
+			.balances(&[(ALICE, 10_000), (BOB, 10_000)])
+			.mint_fees(MintFees { one: 1, three: 1, six: 1 })
 			.build()
 			.execute_with(|| {
-				Accounts::<Test>::mutate(ALICE, |info| info.storage_tier = StorageTier::Four);
-
-				run_to_block(season_1.early_start);
-				for _ in 0..33 {
+				run_to_block(season.start);
+				for player in [ALICE, BOB] {
 					assert_ok!(AAvatars::mint(
-						RuntimeOrigin::signed(ALICE),
-						MintOption { count: MintPackSize::Three, mint_type: MintType::Free }
+						RuntimeOrigin::signed(player),
+						MintOption { count: MintPackSize::Six, mint_type: MintType::Normal }
 					));
 				}
 
-				for iter in [
-					season_1.early_start..season_1.end, // block 5..19
-					season_1.end..season_2.early_start, // block 20..29
-				] {
-					for n in iter {
-						run_to_block(n);
-						assert_ok!(AAvatars::forge(
-							RuntimeOrigin::signed(ALICE),
-							AAvatars::owners(ALICE)[0],
-							AAvatars::owners(ALICE)[1..3].to_vec()
-						));
-					}
+				let rented = minted_avatar_ids(BOB)[0];
+				let rented_souls = AAvatars::avatars(rented).unwrap().1.souls;
+				assert_ok!(AAvatars::list_for_rent(RuntimeOrigin::signed(BOB), rented, 10, 5));
+				assert_ok!(AAvatars::rent(RuntimeOrigin::signed(ALICE), rented));
+
+				let alice_avatars = minted_avatar_ids(ALICE);
+				let leader = alice_avatars[0];
+				let mut expected_souls = AAvatars::avatars(leader).unwrap().1.souls;
+				for sacrifice in alice_avatars[1..4].iter().copied() {
+					expected_souls += AAvatars::avatars(sacrifice).unwrap().1.souls;
+					assert_ok!(AAvatars::forge(
+						RuntimeOrigin::signed(ALICE),
+						leader,
+						vec![sacrifice, rented]
+					));
+					assert_eq!(AAvatars::avatars(leader).unwrap().1.souls, expected_souls);
 				}
+				assert_eq!(AAvatars::avatars(rented).unwrap().1.souls, rented_souls);
+
+				assert_ok!(AAvatars::extract_souls(RuntimeOrigin::signed(ALICE), leader));
+				assert_eq!(AAvatars::souls(ALICE), expected_souls);
+			});
+	}
+}
+
+mod account {
+	use super::*;
+
+	#[test]
+	fn upgrade_storage_should_work() {
+		let upgrade_fee = 12_345 as MockBalance;
+		let num_storage_tiers = sp_std::mem::variant_count::<StorageTier>();
+		let alice_balance = num_storage_tiers as MockBalance * upgrade_fee;
+		let mut treasury_balance = 0;
+		let total_supply = treasury_balance + alice_balance;
+
+		ExtBuilder::default()
+			.balances(&[(ALICE, alice_balance)])
+			.build()
+			.execute_with(|| {
+				GlobalConfigs::<Test>::mutate(|cfg| cfg.account.storage_upgrade_fee = upgrade_fee);
+
+				assert_eq!(AAvatars::accounts(ALICE).storage_tier, StorageTier::One);
+				assert_eq!(AAvatars::accounts(ALICE).storage_tier as isize, 25);
+				assert_eq!(
+					Balances::free_balance(&AAvatars::treasury_account_id()),
+					treasury_balance
+				);
+				assert_eq!(Balances::total_issuance(), total_supply);
+
+				assert_ok!(AAvatars::upgrade_storage(RuntimeOrigin::signed(ALICE)));
+				assert_eq!(AAvatars::accounts(ALICE).storage_tier, StorageTier::Two);
+				assert_eq!(AAvatars::accounts(ALICE).storage_tier as isize, 50);
+				treasury_balance += upgrade_fee;
+				assert_eq!(
+					Balances::free_balance(&AAvatars::treasury_account_id()),
+					treasury_balance
+				);
+				assert_eq!(Balances::total_issuance(), total_supply);
+
+				assert_ok!(AAvatars::upgrade_storage(RuntimeOrigin::signed(ALICE)));
+				assert_eq!(AAvatars::accounts(ALICE).storage_tier, StorageTier::Three);
+				assert_eq!(AAvatars::accounts(ALICE).storage_tier as isize, 75);
+				treasury_balance += upgrade_fee;
+				assert_eq!(
+					Balances::free_balance(&AAvatars::treasury_account_id()),
+					treasury_balance
+				);
+				assert_eq!(Balances::total_issuance(), total_supply);
+
+				assert_ok!(AAvatars::upgrade_storage(RuntimeOrigin::signed(ALICE)));
+				assert_eq!(AAvatars::accounts(ALICE).storage_tier, StorageTier::Four);
+				assert_eq!(AAvatars::accounts(ALICE).storage_tier as isize, 100);
+				treasury_balance += upgrade_fee;
+				assert_eq!(
+					Balances::free_balance(&AAvatars::treasury_account_id()),
+					treasury_balance
+				);
+				assert_eq!(Balances::total_issuance(), total_supply);
+
+				assert_ok!(AAvatars::upgrade_storage(RuntimeOrigin::signed(ALICE)));
+				assert_eq!(AAvatars::accounts(ALICE).storage_tier, StorageTier::Five);
+				assert_eq!(AAvatars::accounts(ALICE).storage_tier as isize, 150);
+
+				assert_ok!(AAvatars::upgrade_storage(RuntimeOrigin::signed(ALICE)));
+				assert_eq!(AAvatars::accounts(ALICE).storage_tier, StorageTier::Max);
+				assert_eq!(AAvatars::accounts(ALICE).storage_tier as isize, 200);
+				assert_eq!(AAvatars::accounts(ALICE).storage_tier.quota(), None);
 			});
 	}
 
 	#[test]
-	fn forge_should_reject_unknown_season_calls() {
+	fn upgrade_storage_should_reject_insufficient_balance() {
 		ExtBuilder::default().build().execute_with(|| {
-			CurrentSeasonId::<Test>::put(123);
-			CurrentSeasonStatus::<Test>::mutate(|status| status.active = true);
 			assert_noop!(
-				AAvatars::forge(
-					RuntimeOrigin::signed(ALICE),
-					H256::default(),
-					vec![H256::default()]
-				),
-				Error::<Test>::UnknownSeason,
+				AAvatars::upgrade_storage(RuntimeOrigin::signed(ALICE)),
+				pallet_balances::Error::<Test>::InsufficientBalance
 			);
 		});
 	}
 
 	#[test]
-	fn forge_should_reject_unknown_avatars() {
-		let season = Season::default().min_sacrifices(1).max_sacrifices(3);
+	fn upgrade_storage_should_reject_fully_upgraded_storage() {
+		ExtBuilder::default().build().execute_with(|| {
+			GlobalConfigs::<Test>::mutate(|cfg| cfg.account.storage_upgrade_fee = 0);
+			Accounts::<Test>::mutate(ALICE, |account| account.storage_tier = StorageTier::Max);
 
-		ExtBuilder::default()
-			.seasons(&[(1, season.clone())])
-			.mint_cooldown(0)
-			.free_mints(&[(ALICE, 10)])
-			.build()
-			.execute_with(|| {
-				run_to_block(season.start);
-				for _ in 0..season.max_sacrifices {
-					assert_ok!(AAvatars::mint(
-						RuntimeOrigin::signed(ALICE),
-						MintOption { count: MintPackSize::One, mint_type: MintType::Free }
-					));
-				}
+			assert_noop!(
+				AAvatars::upgrade_storage(RuntimeOrigin::signed(ALICE)),
+				Error::<Test>::MaxStorageTierReached
+			);
+		});
+	}
+}
 
-				let owned_avatars = AAvatars::owners(ALICE);
-				for (leader, sacrifices) in [
-					(H256::default(), vec![owned_avatars[0], owned_avatars[2]]),
-					(owned_avatars[1], vec![H256::default(), H256::default()]),
-					(owned_avatars[1], vec![H256::default(), owned_avatars[2]]),
-				] {
-					assert_noop!(
-						AAvatars::forge(RuntimeOrigin::signed(ALICE), leader, sacrifices),
-						Error::<Test>::UnknownAvatar,
-					);
-				}
-			});
+mod nft_transfer {
+	use super::*;
+	use frame_support::{
+		bounded_vec,
+		traits::tokens::{nonfungibles_v2::Inspect, AttributeNamespace},
+	};
+	use pallet_ajuna_nft_staking::traits::NftDelegation;
+	use pallet_ajuna_nft_transfer::traits::{AttributeCode, NftConvertible};
+
+	#[test]
+	fn set_collection_id_works() {
+		ExtBuilder::default().organizer(CHARLIE).build().execute_with(|| {
+			let collection_id = 369;
+			assert_ok!(AAvatars::set_collection_id(RuntimeOrigin::signed(CHARLIE), collection_id));
+			assert_eq!(AAvatars::collection_id(), Some(collection_id));
+		});
 	}
 
 	#[test]
-	fn forge_should_reject_incorrect_ownership() {
-		let season = Season::default().min_sacrifices(1).max_sacrifices(3);
+	fn set_collection_id_rejects_non_organizer_calls() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			assert_noop!(
+				AAvatars::set_collection_id(RuntimeOrigin::signed(BOB), 333),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn can_lock_avatar_successfully() {
+		let season = Season::default().max_components(8).max_variations(5);
 
 		ExtBuilder::default()
 			.seasons(&[(1, season.clone())])
-			.mint_cooldown(0)
-			.free_mints(&[(ALICE, 10), (BOB, 10)])
+			.balances(&[(ALICE, 1_000_000_000_000)])
+			.create_nft_collection(true)
 			.build()
 			.execute_with(|| {
 				run_to_block(season.start);
-				for player in [ALICE, BOB] {
-					for _ in 0..season.max_sacrifices {
-						assert_ok!(AAvatars::mint(
-							RuntimeOrigin::signed(player),
-							MintOption { count: MintPackSize::One, mint_type: MintType::Free }
-						));
+				assert_ok!(AAvatars::mint(
+					RuntimeOrigin::signed(ALICE),
+					MintOption { count: MintPackSize::Three, mint_type: MintType::Normal }
+				));
+				let avatar_ids = minted_avatar_ids(ALICE);
+				let avatar_id = avatar_ids[0];
+
+				assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), ALICE));
+				assert_ok!(AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
+				assert_ok!(AAvatars::prepare_ipfs(
+					RuntimeOrigin::signed(ALICE),
+					avatar_id,
+					IpfsUrl::try_from(b"test".to_vec()).unwrap()
+				));
+				assert_ok!(AAvatars::lock_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
+				assert!(!Preparation::<Test>::contains_key(avatar_id));
+				assert!(LockedAvatars::<Test>::contains_key(avatar_id));
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::AvatarLocked { avatar_id },
+				));
+
+				// NOTE: the bytes drawn for the tier of the 1st component (253) and of the 6th
+				// component (227) are rejected as biased, so both tiers are drawn again.
+				let (_, avatar) = AAvatars::avatars(avatar_id).unwrap();
+				assert_eq!(
+					avatar,
+					Avatar {
+						season_id: 1,
+						dna: bounded_vec![0x44, 0x00, 0x13, 0x00, 0x02, 0x10, 0x01, 0x02],
+						souls: 9
 					}
-				}
+				);
 
-				for (player, leader, sacrifices) in [
-					(ALICE, AAvatars::owners(ALICE)[0], AAvatars::owners(BOB)[0..2].to_vec()),
-					(ALICE, AAvatars::owners(BOB)[0], AAvatars::owners(ALICE)[0..2].to_vec()),
-					(ALICE, AAvatars::owners(BOB)[0], AAvatars::owners(BOB)[1..2].to_vec()),
-				] {
-					assert_noop!(
-						AAvatars::forge(RuntimeOrigin::signed(player), leader, sacrifices),
-						Error::<Test>::Ownership,
+				// Ensure correct encoding
+				assert_eq!(
+					<Nft as Inspect<MockAccountId>>::typed_attribute::<
+						pallet_ajuna_nft_transfer::traits::AttributeCode,
+						Avatar,
+					>(
+						&AAvatars::collection_id().unwrap(),
+						&avatar_id,
+						&AttributeNamespace::Pallet,
+						&<Avatar as NftConvertible>::ITEM_CODE,
+					)
+					.unwrap(),
+					avatar,
+				);
+
+				// Ensure attributes encoding
+				for (attribute_code, encoded_attribute) in
+					Avatar::get_attribute_codes().iter().zip([
+						avatar.dna.encode(),
+						avatar.souls.encode(),
+						RarityTier::Common.encode(),
+						Force::Solar.encode(),
+					]) {
+					assert_eq!(
+						<Nft as Inspect<MockAccountId>>::typed_attribute::<AttributeCode, Vec<u8>>(
+							&AAvatars::collection_id().unwrap(),
+							&avatar_id,
+							&AttributeNamespace::Pallet,
+							attribute_code,
+						)
+						.unwrap(),
+						encoded_attribute
 					);
 				}
-			});
-	}
-
-	#[test]
-	fn forge_should_reject_leader_in_sacrifice() {
-		let season = Season::default().min_sacrifices(1).max_sacrifices(3);
 
-		ExtBuilder::default()
-			.seasons(&[(1, season.clone())])
-			.mint_cooldown(0)
-			.free_mints(&[(ALICE, 10)])
-			.build()
-			.execute_with(|| {
-				run_to_block(season.start);
-				for _ in 0..season.max_sacrifices {
-					assert_ok!(AAvatars::mint(
-						RuntimeOrigin::signed(ALICE),
-						MintOption { count: MintPackSize::One, mint_type: MintType::Free }
-					));
-				}
+				// Ensure ownership transferred to technical account
+				let technical_account = AAvatars::technical_account_id();
+				assert!(!AAvatars::owners(&ALICE).contains(&avatar_id));
+				assert_eq!(AAvatars::owners(&technical_account)[0], avatar_id);
+				assert_eq!(AAvatars::avatars(avatar_id).unwrap().0, technical_account);
 
-				for (player, leader, sacrifices) in [
-					(ALICE, AAvatars::owners(ALICE)[0], AAvatars::owners(ALICE)[0..2].to_vec()),
-					(ALICE, AAvatars::owners(ALICE)[1], AAvatars::owners(ALICE)[0..2].to_vec()),
+				// Ensure locked avatars cannot be used in trading, transferring and forging
+				for extrinsic in [
+					AAvatars::set_price(RuntimeOrigin::signed(technical_account), avatar_id, 1_000),
+					AAvatars::transfer_avatar(
+						RuntimeOrigin::signed(technical_account),
+						BOB,
+						avatar_id,
+					),
+					AAvatars::forge(
+						RuntimeOrigin::signed(technical_account),
+						avatar_id,
+						avatar_ids[1..3].to_vec(),
+					),
 				] {
-					assert_noop!(
-						AAvatars::forge(RuntimeOrigin::signed(player), leader, sacrifices),
-						Error::<Test>::LeaderSacrificed,
-					);
+					assert_noop!(extrinsic, Error::<Test>::AvatarLocked);
 				}
 			});
 	}
 
 	#[test]
-	fn forge_should_reject_avatars_in_trade() {
-		let season = Season::default();
-		let price = 321;
-		let initial_balance = 6 + MockExistentialDeposit::get();
-
+	fn cannot_lock_when_nft_transfer_is_closed() {
 		ExtBuilder::default()
-			.seasons(&[(1, season.clone())])
-			.balances(&[(ALICE, initial_balance), (BOB, 6 + initial_balance)])
-			.mint_fees(MintFees { one: 1, three: 1, six: 1 })
+			.balances(&[(ALICE, MockExistentialDeposit::get())])
+			.create_nft_collection(true)
 			.build()
 			.execute_with(|| {
-				run_to_block(season.start);
-				assert_ok!(AAvatars::mint(
-					RuntimeOrigin::signed(ALICE),
-					MintOption { count: MintPackSize::Six, mint_type: MintType::Normal }
-				));
-				assert_ok!(AAvatars::mint(
-					RuntimeOrigin::signed(BOB),
-					MintOption { count: MintPackSize::Six, mint_type: MintType::Normal }
-				));
-
-				let leader = AAvatars::owners(ALICE)[0];
-				let sacrifices = AAvatars::owners(ALICE)[1..3].to_vec();
-
-				assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(ALICE), leader, price));
+				let avatar_id = create_avatars(1, ALICE, 1)[0];
+				GlobalConfigs::<Test>::mutate(|config| config.nft_transfer.open = false);
 				assert_noop!(
-					AAvatars::forge(RuntimeOrigin::signed(ALICE), leader, sacrifices.clone()),
-					Error::<Test>::AvatarInTrade
+					AAvatars::lock_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
+					Error::<Test>::NftTransferClosed
 				);
+			})
+	}
 
-				assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(ALICE), sacrifices[1], price));
+	#[test]
+	fn cannot_lock_unowned_avatar() {
+		ExtBuilder::default()
+			.balances(&[(ALICE, 1_000_000_000_000), (BOB, 1_000_000_000_000)])
+			.create_nft_collection(true)
+			.build()
+			.execute_with(|| {
+				let avatar_id = create_avatars(1, BOB, 1)[0];
 				assert_noop!(
-					AAvatars::forge(RuntimeOrigin::signed(ALICE), leader, sacrifices.clone()),
-					Error::<Test>::AvatarInTrade
+					AAvatars::lock_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
+					Error::<Test>::Ownership
 				);
+			});
+	}
 
-				assert_ok!(AAvatars::remove_price(RuntimeOrigin::signed(ALICE), leader));
+	#[test]
+	fn cannot_lock_avatar_on_trade() {
+		ExtBuilder::default()
+			.balances(&[(ALICE, 1_000_000_000_000)])
+			.create_nft_collection(true)
+			.build()
+			.execute_with(|| {
+				let avatar_id = create_avatars(1, ALICE, 1)[0];
+				assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(ALICE), avatar_id, 1_000));
 				assert_noop!(
-					AAvatars::forge(RuntimeOrigin::signed(ALICE), leader, sacrifices),
+					AAvatars::lock_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
 					Error::<Test>::AvatarInTrade
 				);
 			});
 	}
 
 	#[test]
-	fn forge_should_reject_avatars_from_different_seasons() {
-		let min_sacrifices = 1;
-		let max_sacrifices = 3;
-		let season1 = Season::default()
-			.early_start(5)
-			.start(45)
-			.end(98)
-			.min_sacrifices(min_sacrifices)
-			.max_sacrifices(max_sacrifices);
-		let season2 = Season::default()
-			.early_start(100)
-			.start(101)
-			.end(199)
-			.min_sacrifices(min_sacrifices)
-			.max_sacrifices(max_sacrifices);
-
+	fn cannot_lock_already_locked_avatar() {
 		ExtBuilder::default()
-			.seasons(&[(1, season1.clone()), (2, season2.clone())])
-			.mint_cooldown(0)
-			.free_mints(&[(ALICE, 10)])
+			.balances(&[(ALICE, 1_000_000_000_000)])
+			.create_nft_collection(true)
 			.build()
 			.execute_with(|| {
-				run_to_block(season1.early_start + 1);
-				for _ in 0..max_sacrifices {
-					assert_ok!(AAvatars::mint(
-						RuntimeOrigin::signed(ALICE),
-						MintOption { count: MintPackSize::One, mint_type: MintType::Free }
-					));
-					run_to_block(System::block_number() + 1);
-				}
-				run_to_block(season2.start + 2);
-
-				for _ in 0..max_sacrifices {
-					assert_ok!(AAvatars::mint(
-						RuntimeOrigin::signed(ALICE),
-						MintOption { count: MintPackSize::One, mint_type: MintType::Free }
-					));
-					run_to_block(System::block_number() + 1);
-				}
-				run_to_block(season2.end + 1);
+				let avatar_id = create_avatars(1, ALICE, 1)[0];
+				assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), ALICE));
+				assert_ok!(AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
+				assert_ok!(AAvatars::prepare_ipfs(
+					RuntimeOrigin::signed(ALICE),
+					avatar_id,
+					IpfsUrl::try_from(b"test".to_vec()).unwrap()
+				));
+				assert_ok!(AAvatars::lock_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
+				assert_noop!(
+					AAvatars::lock_avatar(
+						RuntimeOrigin::signed(AAvatars::technical_account_id()),
+						avatar_id
+					),
+					Error::<Test>::AvatarLocked
+				);
+			});
+	}
 
-				for (player, leader, sacrifices) in [
-					(ALICE, AAvatars::owners(ALICE)[0], AAvatars::owners(ALICE)[2..5].to_vec()),
-					(ALICE, AAvatars::owners(ALICE)[0], AAvatars::owners(ALICE)[3..6].to_vec()),
-					(ALICE, AAvatars::owners(ALICE)[5], AAvatars::owners(ALICE)[0..2].to_vec()),
-				] {
-					assert_noop!(
-						AAvatars::forge(RuntimeOrigin::signed(player), leader, sacrifices),
-						Error::<Test>::IncorrectAvatarSeason,
-					);
-				}
+	#[test]
+	fn cannot_lock_unprepared_avatar() {
+		ExtBuilder::default()
+			.balances(&[(ALICE, 1_000_000_000_000)])
+			.create_nft_collection(true)
+			.build()
+			.execute_with(|| {
+				let avatar_id = create_avatars(1, ALICE, 1)[0];
+				assert_noop!(
+					AAvatars::lock_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
+					Error::<Test>::NotPrepared
+				);
 			});
 	}
-}
-
-mod transferring {
-	use super::*;
 
 	#[test]
-	fn transfer_free_mints_should_work() {
+	fn cannot_lock_prepared_avatar_with_empty_url() {
 		ExtBuilder::default()
-			.free_mints(&[(ALICE, 17), (BOB, 4)])
+			.balances(&[(ALICE, 1_000_000_000_000)])
+			.create_nft_collection(true)
 			.build()
 			.execute_with(|| {
-				assert_ok!(AAvatars::transfer_free_mints(RuntimeOrigin::signed(ALICE), BOB, 10));
-				System::assert_last_event(mock::RuntimeEvent::AAvatars(
-					crate::Event::FreeMintsTransferred { from: ALICE, to: BOB, how_many: 10 },
+				let avatar_id = create_avatars(1, ALICE, 1)[0];
+				assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), ALICE));
+				assert_ok!(AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
+				assert_ok!(AAvatars::prepare_ipfs(
+					RuntimeOrigin::signed(ALICE),
+					avatar_id,
+					IpfsUrl::try_from(b"test".to_vec()).unwrap()
 				));
-				assert_eq!(AAvatars::accounts(ALICE).free_mints, 6);
-				assert_eq!(AAvatars::accounts(BOB).free_mints, 14);
 
-				assert_ok!(AAvatars::transfer_free_mints(RuntimeOrigin::signed(ALICE), CHARLIE, 2));
-				System::assert_last_event(mock::RuntimeEvent::AAvatars(
-					crate::Event::FreeMintsTransferred { from: ALICE, to: CHARLIE, how_many: 2 },
-				));
+				// Technically this can't happen but testing just for the sake of completeness.
+				Preparation::<Test>::insert(avatar_id, IpfsUrl::default());
 
-				assert_eq!(AAvatars::accounts(ALICE).free_mints, 3);
-				assert_eq!(AAvatars::accounts(CHARLIE).free_mints, 2);
+				assert_noop!(
+					AAvatars::lock_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
+					pallet_ajuna_nft_transfer::Error::<Test>::EmptyIpfsUrl
+				);
 			});
 	}
 
 	#[test]
-	fn transfer_free_mints_should_reject_when_amount_is_lower_than_minimum_allowed() {
-		ExtBuilder::default().free_mints(&[(ALICE, 11)]).build().execute_with(|| {
-			let transfer = 5;
-			GlobalConfigs::<Test>::mutate(|cfg| cfg.transfer.min_free_mint_transfer = transfer + 1);
-			assert_noop!(
-				AAvatars::transfer_free_mints(RuntimeOrigin::signed(ALICE), BOB, transfer),
-				Error::<Test>::TooLowFreeMints
-			);
-		});
-	}
-
-	#[test]
-	fn transfer_free_mints_should_reject_when_balance_is_insufficient() {
-		ExtBuilder::default().free_mints(&[(ALICE, 7)]).build().execute_with(|| {
-			assert_noop!(
-				AAvatars::transfer_free_mints(RuntimeOrigin::signed(ALICE), BOB, 10),
-				Error::<Test>::InsufficientFreeMints
-			);
-		});
-	}
+	fn cannot_lock_avatars_above_value_limit() {
+		let value_limit = 3;
 
-	#[test]
-	fn transfer_free_mints_should_reject_transferring_to_self() {
-		ExtBuilder::default().free_mints(&[(ALICE, 7)]).build().execute_with(|| {
-			assert_noop!(
-				AAvatars::transfer_free_mints(RuntimeOrigin::signed(ALICE), ALICE, 1),
-				Error::<Test>::CannotTransferToSelf
-			);
-		});
+		ExtBuilder::default()
+			.balances(&[(ALICE, 1_000_000_000_000)])
+			.create_nft_collection(true)
+			.value_limit(value_limit)
+			.build()
+			.execute_with(|| {
+				let avatar_id = create_avatars(1, ALICE, 1)[0];
+				let (_, avatar) = AAvatars::avatars(avatar_id).unwrap();
+				assert!(avatar.encode().len() > value_limit as usize);
+				assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), ALICE));
+				assert_ok!(AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
+				assert_noop!(
+					AAvatars::lock_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
+					pallet_nfts::Error::<Test>::IncorrectData
+				);
+			});
 	}
 
 	#[test]
-	fn transfer_avatar_works() {
-		let avatar_transfer_fee = 888;
-		let initial_balance = MockExistentialDeposit::get() + avatar_transfer_fee;
-		let total_supply = initial_balance;
-		let season_id = 999;
-
+	fn can_unlock_avatar_successfully() {
 		ExtBuilder::default()
-			.balances(&[(ALICE, initial_balance)])
-			.avatar_transfer_fee(avatar_transfer_fee)
+			.balances(&[(ALICE, 1_000_000_000_000)])
+			.create_nft_collection(true)
 			.build()
 			.execute_with(|| {
-				let treasury_account = &AAvatars::treasury_account_id();
-				let treasury_balance = 0;
-				assert_eq!(Balances::free_balance(treasury_account), treasury_balance);
-				assert_eq!(Balances::total_issuance(), total_supply);
-
-				let mut alice_avatar_ids = create_avatars(1, ALICE, 3);
-				let mut bob_avatar_ids = create_avatars(1, BOB, 6);
-				let avatar_id = alice_avatar_ids[0];
-				Avatars::<Test>::mutate(avatar_id, |maybe_avatar| {
-					let (_, avatar) = maybe_avatar.as_mut().unwrap();
-					avatar.season_id = season_id;
-				});
-
-				assert_ok!(AAvatars::transfer_avatar(RuntimeOrigin::signed(ALICE), BOB, avatar_id));
-				alice_avatar_ids.retain(|x| x != &avatar_id);
-				bob_avatar_ids.push(avatar_id);
-
-				// transfer checks
-				assert_eq!(AAvatars::owners(ALICE).len(), 3 - 1);
-				assert_eq!(AAvatars::owners(ALICE), alice_avatar_ids);
-				assert_eq!(AAvatars::owners(BOB).len(), 6 + 1);
-				assert_eq!(AAvatars::owners(BOB), bob_avatar_ids);
-				assert_eq!(AAvatars::avatars(avatar_id).unwrap().0, BOB);
-				System::assert_last_event(mock::RuntimeEvent::AAvatars(
-					crate::Event::AvatarTransferred { from: ALICE, to: BOB, avatar_id },
+				let avatar_id = create_avatars(1, ALICE, 1)[0];
+				assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), ALICE));
+				assert_ok!(AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
+				assert_ok!(AAvatars::prepare_ipfs(
+					RuntimeOrigin::signed(ALICE),
+					avatar_id,
+					IpfsUrl::try_from(b"test".to_vec()).unwrap()
 				));
-
-				// balance checks
-				assert_eq!(Balances::free_balance(ALICE), initial_balance - avatar_transfer_fee);
-				assert_eq!(AAvatars::treasury(season_id), avatar_transfer_fee);
-				assert_eq!(
-					Balances::free_balance(treasury_account),
-					treasury_balance + avatar_transfer_fee
-				);
-				assert_eq!(Balances::total_issuance(), total_supply);
-
-				// check organizer transfer
-				GlobalConfigs::<Test>::mutate(|config| config.trade.open = false);
-				Balances::make_free_balance_be(&BOB, avatar_transfer_fee);
-				assert_ok!(AAvatars::set_organizer(RuntimeOrigin::root(), BOB));
-				assert_ok!(AAvatars::transfer_avatar(
-					RuntimeOrigin::signed(BOB),
-					CHARLIE,
-					bob_avatar_ids[0]
+				assert_ok!(AAvatars::lock_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
+				assert_ok!(AAvatars::unlock_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
+				assert_eq!(LockedAvatars::<Test>::get(avatar_id), None);
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::AvatarUnlocked { avatar_id },
 				));
-				assert_eq!(AAvatars::owners(BOB).len(), 6);
-				assert_eq!(AAvatars::owners(CHARLIE).len(), 1);
 			});
 	}
 
 	#[test]
-	fn transfer_avatar_rejects_on_transfer_closed() {
-		ExtBuilder::default().build().execute_with(|| {
-			GlobalConfigs::<Test>::mutate(|config| config.transfer.open = false);
-			assert_noop!(
-				AAvatars::transfer_avatar(RuntimeOrigin::signed(BOB), CHARLIE, H256::random()),
-				Error::<Test>::TransferClosed
-			);
-		});
+	fn cannot_unlock_when_nft_transfer_is_closed() {
+		ExtBuilder::default()
+			.balances(&[(ALICE, MockExistentialDeposit::get())])
+			.create_nft_collection(true)
+			.build()
+			.execute_with(|| {
+				let avatar_id = create_avatars(1, ALICE, 1)[0];
+				assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), ALICE));
+				assert_ok!(AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
+				assert_ok!(AAvatars::prepare_ipfs(
+					RuntimeOrigin::signed(ALICE),
+					avatar_id,
+					IpfsUrl::try_from(b"test".to_vec()).unwrap()
+				));
+				assert_ok!(AAvatars::lock_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
+				GlobalConfigs::<Test>::mutate(|config| config.nft_transfer.open = false);
+				assert_noop!(
+					AAvatars::unlock_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
+					Error::<Test>::NftTransferClosed
+				);
+			})
 	}
 
 	#[test]
-	fn transfer_avatar_works_on_transfer_closed_with_organizer() {
-		let avatar_transfer_fee = 135;
+	fn cannot_unlock_unowned_avatar() {
 		ExtBuilder::default()
-			.organizer(BOB)
-			.balances(&[(BOB, MockExistentialDeposit::get() + avatar_transfer_fee)])
-			.avatar_transfer_fee(avatar_transfer_fee)
+			.balances(&[(ALICE, 1_000_000_000_000), (BOB, 5_000_000_000_000)])
+			.create_nft_collection(true)
 			.build()
 			.execute_with(|| {
-				GlobalConfigs::<Test>::mutate(|config| config.transfer.open = false);
 				let avatar_id = create_avatars(1, BOB, 1)[0];
-				assert_ok!(AAvatars::transfer_avatar(RuntimeOrigin::signed(BOB), DAVE, avatar_id));
-			});
-	}
-
-	#[test]
-	fn transfer_avatar_rejects_transferring_to_self() {
-		ExtBuilder::default().build().execute_with(|| {
-			for who in [ALICE, BOB] {
+				assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), ALICE));
+				assert_ok!(AAvatars::prepare_avatar(RuntimeOrigin::signed(BOB), avatar_id));
+				assert_ok!(AAvatars::prepare_ipfs(
+					RuntimeOrigin::signed(ALICE),
+					avatar_id,
+					IpfsUrl::try_from(b"test".to_vec()).unwrap()
+				));
+				assert_ok!(AAvatars::lock_avatar(RuntimeOrigin::signed(BOB), avatar_id));
 				assert_noop!(
-					AAvatars::transfer_avatar(RuntimeOrigin::signed(who), who, H256::random()),
-					Error::<Test>::CannotTransferToSelf
+					AAvatars::unlock_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
+					pallet_nfts::Error::<Test>::NoPermission
 				);
-			}
-		});
+			});
 	}
 
 	#[test]
-	fn transfer_avatar_rejects_avatar_in_trade() {
-		ExtBuilder::default().build().execute_with(|| {
-			let avatar_id = create_avatars(1, CHARLIE, 1)[0];
-			assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(CHARLIE), avatar_id, 999));
-			assert_noop!(
-				AAvatars::transfer_avatar(RuntimeOrigin::signed(CHARLIE), DAVE, avatar_id),
-				Error::<Test>::AvatarInTrade
-			);
-		});
-	}
+	fn cannot_unlock_transferred_avatar() {
+		let season = Season::default();
 
-	#[test]
-	fn transfer_avatar_rejects_unowned_avatars() {
-		ExtBuilder::default().build().execute_with(|| {
-			let avatar_id = create_avatars(1, CHARLIE, 1)[0];
-			assert_noop!(
-				AAvatars::transfer_avatar(RuntimeOrigin::signed(ALICE), BOB, avatar_id),
-				Error::<Test>::Ownership
-			);
-		});
+		ExtBuilder::default()
+			.seasons(&[(1, season.clone())])
+			.balances(&[(ALICE, 1_000_000_000_000)])
+			.create_nft_collection(true)
+			.build()
+			.execute_with(|| {
+				run_to_block(season.start);
+
+				let avatar_id = create_avatars(1, ALICE, 1)[0];
+				assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), ALICE));
+				assert_ok!(AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
+				assert_ok!(AAvatars::prepare_ipfs(
+					RuntimeOrigin::signed(ALICE),
+					avatar_id,
+					IpfsUrl::try_from(b"test".to_vec()).unwrap()
+				));
+				assert_ok!(AAvatars::lock_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
+
+				pallet_ajuna_nft_transfer::NftStatuses::<Test>::insert(
+					AAvatars::collection_id().unwrap(),
+					avatar_id,
+					pallet_ajuna_nft_transfer::NftStatus::Uploaded,
+				);
+				assert_noop!(
+					AAvatars::unlock_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
+					pallet_ajuna_nft_transfer::Error::<Test>::NftOutsideOfChain
+				);
+			});
 	}
 
-	#[test]
-	fn transfer_avatar_rejects_unknown_avatars() {
-		ExtBuilder::default().build().execute_with(|| {
-			assert_noop!(
-				AAvatars::transfer_avatar(RuntimeOrigin::signed(ALICE), BOB, H256::random()),
-				Error::<Test>::UnknownAvatar
-			);
-		});
+	fn create_locked_avatar(player: MockAccountId) -> AvatarIdOf<Test> {
+		let avatar_id = create_avatars(1, player, 1)[0];
+		assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), ALICE));
+		assert_ok!(AAvatars::prepare_avatar(RuntimeOrigin::signed(player), avatar_id));
+		assert_ok!(AAvatars::prepare_ipfs(
+			RuntimeOrigin::signed(ALICE),
+			avatar_id,
+			IpfsUrl::try_from(b"test".to_vec()).unwrap()
+		));
+		assert_ok!(AAvatars::lock_avatar(RuntimeOrigin::signed(player), avatar_id));
+		avatar_id
 	}
 
 	#[test]
-	fn transfer_avatar_rejects_on_max_ownership() {
-		let avatar_transfer_fee = 369;
+	fn can_lend_locked_avatars_to_be_staked() {
 		ExtBuilder::default()
-			.balances(&[(ALICE, MockExistentialDeposit::get() + avatar_transfer_fee)])
-			.avatar_transfer_fee(avatar_transfer_fee)
+			.balances(&[(ALICE, 1_000_000_000_000), (BOB, 1_000_000_000_000)])
+			.create_nft_collection(true)
 			.build()
 			.execute_with(|| {
-				Accounts::<Test>::mutate(BOB, |info| info.storage_tier = StorageTier::Three);
-
-				let avatar_id = create_avatars(1, ALICE, 1)[0];
-				let _ = create_avatars(1, BOB, StorageTier::Three as u8);
+				let avatar_id = create_locked_avatar(ALICE);
+				let collection_id = AAvatars::collection_id().unwrap();
+				let (price, duration) = (123, 10);
 
+				// the holder of the NFT lends it out, rather than the technical account
 				assert_noop!(
-					AAvatars::transfer_avatar(RuntimeOrigin::signed(ALICE), BOB, avatar_id),
-					Error::<Test>::MaxOwnershipReached
+					AAvatars::list_for_rent(
+						RuntimeOrigin::signed(AAvatars::technical_account_id()),
+						avatar_id,
+						price,
+						duration
+					),
+					Error::<Test>::Ownership
+				);
+				assert_ok!(AAvatars::list_for_rent(
+					RuntimeOrigin::signed(ALICE),
+					avatar_id,
+					price,
+					duration
+				));
+				assert!(AAvatars::is_lent(&collection_id, &avatar_id));
+				assert_noop!(
+					AAvatars::upload_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
+					Error::<Test>::AvatarInRental
+				);
+				assert_noop!(
+					AAvatars::unlock_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
+					Error::<Test>::AvatarInRental
+				);
+
+				let alice_balance = Balances::free_balance(ALICE);
+				assert_ok!(AAvatars::rent(RuntimeOrigin::signed(BOB), avatar_id));
+				assert_eq!(Balances::free_balance(ALICE), alice_balance + price);
+
+				let expiry = System::block_number() + duration;
+				assert_eq!(
+					AAvatars::delegated_until(&BOB, &collection_id, &avatar_id),
+					Some(expiry)
 				);
+				assert_eq!(AAvatars::delegated_until(&ALICE, &collection_id, &avatar_id), None);
+				assert_eq!(AAvatars::delegated_until(&BOB, &(collection_id + 1), &avatar_id), None);
+				assert!(!AAvatars::is_lent(&(collection_id + 1), &avatar_id));
+
+				run_to_block(expiry);
+				assert_eq!(AAvatars::delegated_until(&BOB, &collection_id, &avatar_id), None);
+				assert!(!AAvatars::is_lent(&collection_id, &avatar_id));
 			});
 	}
-}
-
-mod trading {
-	use super::*;
 
 	#[test]
-	fn set_price_should_work() {
-		let season = Season::default();
+	fn can_upload_avatar_successfully() {
+		ExtBuilder::default()
+			.balances(&[(ALICE, 1_000_000_000_000)])
+			.create_nft_collection(true)
+			.build()
+			.execute_with(|| {
+				let avatar_id = create_locked_avatar(ALICE);
+				let collection_id = AAvatars::collection_id().unwrap();
+
+				assert_ok!(AAvatars::upload_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
+				assert_eq!(NftTransfer::upload_queue(collection_id, avatar_id), Some(ALICE));
+
+				// the queued upload is handed over to the offchain worker
+				run_to_block(System::block_number() + 1);
+				assert_eq!(NftTransfer::upload_queue(collection_id, avatar_id), None);
+				assert_eq!(AAvatars::pending_uploads(avatar_id), Some(ALICE));
+				System::assert_has_event(mock::RuntimeEvent::NftTransfer(
+					pallet_ajuna_nft_transfer::Event::ItemUploaded {
+						collection_id,
+						item_id: avatar_id,
+						owner: ALICE,
+					},
+				));
 
-		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
-			run_to_block(season.start);
-			let avatar_for_sale = create_avatars(1, BOB, 1)[0];
-			let price = 7357;
+				let url = IpfsUrl::try_from(b"ipfs://QmUpload".to_vec()).unwrap();
+				assert_ok!(AAvatars::confirm_upload(
+					RuntimeOrigin::signed(ALICE),
+					avatar_id,
+					url.clone()
+				));
+				assert_eq!(AAvatars::pending_uploads(avatar_id), None);
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(
+					crate::Event::AvatarUploaded { avatar_id, url },
+				));
 
-			assert_eq!(AAvatars::trade(avatar_for_sale), None);
-			assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(BOB), avatar_for_sale, price));
-			assert_eq!(AAvatars::trade(avatar_for_sale), Some(price));
-			System::assert_last_event(mock::RuntimeEvent::AAvatars(crate::Event::AvatarPriceSet {
-				avatar_id: avatar_for_sale,
-				price,
-			}));
-		});
+				// the avatar stays outside of the chain until its return is confirmed
+				assert_noop!(
+					AAvatars::unlock_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
+					pallet_ajuna_nft_transfer::Error::<Test>::NftOutsideOfChain
+				);
+			});
 	}
 
 	#[test]
-	fn set_price_should_reject_when_trading_is_closed() {
-		ExtBuilder::default().build().execute_with(|| {
-			GlobalConfigs::<Test>::mutate(|config| config.trade.open = false);
+	fn cannot_upload_unlocked_avatar() {
+		ExtBuilder::default().create_nft_collection(true).build().execute_with(|| {
+			let avatar_id = create_avatars(1, ALICE, 1)[0];
 			assert_noop!(
-				AAvatars::set_price(RuntimeOrigin::signed(ALICE), sp_core::H256::default(), 1),
-				Error::<Test>::TradeClosed,
+				AAvatars::upload_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
+				Error::<Test>::AvatarUnlocked
 			);
 		});
 	}
 
 	#[test]
-	fn set_price_should_reject_unsigned_calls() {
-		ExtBuilder::default().build().execute_with(|| {
-			assert_noop!(
-				AAvatars::set_price(RuntimeOrigin::none(), sp_core::H256::default(), 1),
-				DispatchError::BadOrigin,
-			);
-		});
+	fn cannot_upload_when_nft_transfer_is_closed() {
+		ExtBuilder::default()
+			.balances(&[(ALICE, 1_000_000_000_000)])
+			.create_nft_collection(true)
+			.build()
+			.execute_with(|| {
+				let avatar_id = create_locked_avatar(ALICE);
+				GlobalConfigs::<Test>::mutate(|config| config.nft_transfer.open = false);
+				assert_noop!(
+					AAvatars::upload_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
+					Error::<Test>::NftTransferClosed
+				);
+			});
 	}
 
 	#[test]
-	fn set_price_should_reject_incorrect_ownership() {
-		let season = Season::default();
-
-		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
-			run_to_block(season.start);
-			let avatar_ids = create_avatars(1, BOB, 2);
-
-			assert_noop!(
-				AAvatars::set_price(RuntimeOrigin::signed(CHARLIE), avatar_ids[0], 101),
-				Error::<Test>::Ownership
-			);
-		});
+	fn cannot_upload_unowned_avatar() {
+		ExtBuilder::default()
+			.balances(&[(ALICE, 1_000_000_000_000)])
+			.create_nft_collection(true)
+			.build()
+			.execute_with(|| {
+				let avatar_id = create_locked_avatar(ALICE);
+				assert_noop!(
+					AAvatars::upload_avatar(RuntimeOrigin::signed(BOB), avatar_id),
+					pallet_ajuna_nft_transfer::Error::<Test>::NftNotOwned
+				);
+			});
 	}
 
 	#[test]
-	fn remove_price_should_work() {
-		let season = Season::default();
-
-		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
-			run_to_block(season.start);
-			let avatar_ids = create_avatars(1, BOB, 2);
-			let avatar_for_sale = avatar_ids[0];
-			let price = 101;
-
-			assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(BOB), avatar_for_sale, price));
-
-			assert_eq!(AAvatars::trade(avatar_for_sale), Some(101));
-			assert_ok!(AAvatars::remove_price(RuntimeOrigin::signed(BOB), avatar_for_sale));
-			assert_eq!(AAvatars::trade(avatar_for_sale), None);
-			System::assert_last_event(mock::RuntimeEvent::AAvatars(
-				crate::Event::AvatarPriceUnset { avatar_id: avatar_for_sale },
-			));
-		});
+	fn uploader_rejects_items_of_other_collections() {
+		ExtBuilder::default()
+			.balances(&[(ALICE, 1_000_000_000_000)])
+			.create_nft_collection(true)
+			.build()
+			.execute_with(|| {
+				let avatar_id = create_locked_avatar(ALICE);
+				let other_collection_id = AAvatars::collection_id().unwrap() + 1;
+				assert_noop!(
+					<AAvatars as NftUploader<_, _, _>>::upload(
+						&ALICE,
+						&other_collection_id,
+						&avatar_id
+					),
+					Error::<Test>::UnknownCollection
+				);
+			});
 	}
 
 	#[test]
-	fn remove_price_should_reject_when_trading_is_closed() {
+	fn confirm_upload_rejects_unknown_uploads() {
 		ExtBuilder::default().build().execute_with(|| {
-			GlobalConfigs::<Test>::mutate(|config| config.trade.open = false);
+			let avatar_id = create_avatars(1, ALICE, 1)[0];
+			assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), ALICE));
 			assert_noop!(
-				AAvatars::remove_price(RuntimeOrigin::signed(ALICE), sp_core::H256::default()),
-				Error::<Test>::TradeClosed,
+				AAvatars::confirm_upload(
+					RuntimeOrigin::signed(ALICE),
+					avatar_id,
+					IpfsUrl::try_from(b"ipfs://QmUpload".to_vec()).unwrap()
+				),
+				Error::<Test>::UnknownUpload
 			);
 		});
 	}
 
 	#[test]
-	fn remove_price_should_reject_unsigned_calls() {
+	fn confirm_upload_rejects_non_service_account_calls() {
 		ExtBuilder::default().build().execute_with(|| {
+			let avatar_id = create_avatars(1, ALICE, 1)[0];
+			assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), ALICE));
+			PendingUploads::<Test>::insert(avatar_id, ALICE);
 			assert_noop!(
-				AAvatars::remove_price(RuntimeOrigin::none(), sp_core::H256::default()),
-				DispatchError::BadOrigin,
-			);
-		});
-	}
-
-	#[test]
-	fn remove_price_should_reject_incorrect_ownership() {
-		let season = Season::default();
-
-		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
-			run_to_block(season.start);
-			let avatar_ids = create_avatars(1, BOB, 3);
-			let avatar_for_sale = avatar_ids[0];
-
-			assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(BOB), avatar_for_sale, 123));
-			assert_noop!(
-				AAvatars::remove_price(RuntimeOrigin::signed(CHARLIE), avatar_for_sale),
-				Error::<Test>::Ownership
+				AAvatars::confirm_upload(
+					RuntimeOrigin::signed(BOB),
+					avatar_id,
+					IpfsUrl::try_from(b"ipfs://QmUpload".to_vec()).unwrap()
+				),
+				DispatchError::BadOrigin
 			);
 		});
 	}
+}
 
-	#[test]
-	fn remove_price_should_reject_unlisted_avatar() {
-		ExtBuilder::default().build().execute_with(|| {
-			assert_noop!(
-				AAvatars::remove_price(RuntimeOrigin::signed(CHARLIE), sp_core::H256::default()),
-				Error::<Test>::UnknownAvatarForSale,
-			);
-		});
-	}
+mod fix_variation {
+	use super::*;
 
 	#[test]
-	fn buy_should_work() {
+	fn fix_variation_successfully() {
 		let season = Season::default();
-		let price = 310_984;
-		let min_fee = 54_321;
-		let alice_initial_bal = price + min_fee + 20_849;
-		let bob_initial_bal = 103_598;
-		let charlie_initial_bal = MockExistentialDeposit::get() + min_fee + 1357;
-		let total_supply = alice_initial_bal + bob_initial_bal + charlie_initial_bal;
 
 		ExtBuilder::default()
-			.existential_deposit(0)
 			.seasons(&[(1, season.clone())])
-			.balances(&[
-				(ALICE, alice_initial_bal),
-				(BOB, bob_initial_bal),
-				(CHARLIE, charlie_initial_bal),
-			])
-			.trade_min_fee(min_fee)
+			.balances(&[(ALICE, 1_000_000_000_000)])
 			.build()
 			.execute_with(|| {
-				let mut treasury_balance_season_1 = 0;
-				let treasury_account = AAvatars::treasury_account_id();
+				run_to_block(season.start);
 
-				assert_eq!(AAvatars::treasury(1), treasury_balance_season_1);
-				assert_eq!(Balances::free_balance(&treasury_account), treasury_balance_season_1);
-				assert_eq!(Balances::total_issuance(), total_supply);
+				let avatar_id = create_avatars(1, ALICE, 1)[0];
 
-				run_to_block(season.start);
-				let avatar_ids = create_avatars(1, BOB, 3);
-				assert_eq!(AAvatars::treasury(1), treasury_balance_season_1);
-				assert_eq!(Balances::free_balance(&treasury_account), treasury_balance_season_1);
+				let (_, mut avatar_before) = Avatars::<Test>::get(avatar_id).unwrap();
 
-				let owned_by_alice = AAvatars::owners(ALICE);
-				let owned_by_bob = AAvatars::owners(BOB);
+				avatar_before.dna[1] = 0b0001_1100;
+				avatar_before.dna[2] = 0b0100_0010;
 
-				let avatar_for_sale = avatar_ids[0];
-				assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(BOB), avatar_for_sale, price));
-				assert_ok!(AAvatars::buy(RuntimeOrigin::signed(ALICE), avatar_for_sale));
-				treasury_balance_season_1 += min_fee;
+				Avatars::<Test>::insert(avatar_id, (ALICE, avatar_before));
 
-				// check for balance transfer
-				assert_eq!(Balances::free_balance(ALICE), alice_initial_bal - price - min_fee);
-				assert_eq!(Balances::free_balance(BOB), bob_initial_bal + price);
-				assert_eq!(AAvatars::treasury(1), treasury_balance_season_1);
-				assert_eq!(Balances::free_balance(&treasury_account), treasury_balance_season_1);
-				assert_eq!(Balances::total_issuance(), total_supply);
+				assert_ok!(AAvatars::fix_variation(RuntimeOrigin::signed(ALICE), avatar_id));
 
-				// check for ownership transfer
-				assert_eq!(AAvatars::owners(ALICE).len(), owned_by_alice.len() + 1);
-				assert_eq!(AAvatars::owners(BOB).len(), owned_by_bob.len() - 1);
-				assert!(AAvatars::owners(ALICE).contains(&avatar_for_sale));
-				assert!(!AAvatars::owners(BOB).contains(&avatar_for_sale));
-				assert_eq!(AAvatars::avatars(avatar_for_sale).unwrap().0, ALICE);
+				let (_, avatar_after) = Avatars::<Test>::get(avatar_id).unwrap();
+				assert_eq!(avatar_after.dna[1], 0b0001_1100);
+				assert_eq!(avatar_after.dna[2], 0b0100_1100);
+			});
+	}
+}
+
+mod ipfs {
+	use super::*;
+
+	#[test]
+	fn set_service_account_works() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_eq!(AAvatars::service_account(), None);
+			assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), ALICE));
+			assert_eq!(AAvatars::service_account(), Some(ALICE));
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(
+				crate::Event::ServiceAccountSet { service_account: ALICE },
+			));
+		});
+	}
 
-				// check for removal from trade storage
-				assert_eq!(AAvatars::trade(avatar_for_sale), None);
+	#[test]
+	fn set_service_account_rejects_non_root_calls() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_noop!(
+				AAvatars::set_service_account(RuntimeOrigin::signed(BOB), ALICE),
+				DispatchError::BadOrigin
+			);
+		});
+	}
 
-				// check for account stats
-				assert_eq!(AAvatars::accounts(ALICE).stats.trade.bought, 1);
-				assert_eq!(AAvatars::accounts(BOB).stats.trade.sold, 1);
+	#[test]
+	fn prepare_avatar_works() {
+		let prepare_fee = 999;
+		let initial_balance = prepare_fee + MockExistentialDeposit::get();
 
-				// check events
+		ExtBuilder::default()
+			.nft_prepare_fee(prepare_fee)
+			.balances(&[(ALICE, initial_balance)])
+			.build()
+			.execute_with(|| {
+				let avatar_id = create_avatars(1, ALICE, 1)[0];
+				assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), BOB));
+				assert_eq!(Balances::free_balance(ALICE), initial_balance);
+				assert_ok!(AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
+				assert_eq!(Balances::free_balance(ALICE), initial_balance - prepare_fee);
+				assert_eq!(AAvatars::preparation(avatar_id).unwrap().to_vec(), Vec::<u8>::new());
 				System::assert_last_event(mock::RuntimeEvent::AAvatars(
-					crate::Event::AvatarTraded { avatar_id: avatar_for_sale, from: BOB, to: ALICE },
+					crate::Event::PreparedAvatar { avatar_id },
 				));
-
-				// charlie buys from bob
-				let avatar_for_sale = avatar_ids[1];
-				assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(BOB), avatar_for_sale, 1357));
-				assert_ok!(AAvatars::buy(RuntimeOrigin::signed(CHARLIE), avatar_for_sale));
-				treasury_balance_season_1 += min_fee;
-				assert_eq!(AAvatars::accounts(CHARLIE).stats.trade.bought, 1);
-				assert_eq!(AAvatars::accounts(BOB).stats.trade.sold, 2);
-
-				// check season id
-				let avatar_on_sale = create_avatars(33, ALICE, 1)[0];
-				assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(ALICE), avatar_on_sale, 369));
-				assert_ok!(AAvatars::buy(RuntimeOrigin::signed(BOB), avatar_on_sale));
-				assert_eq!(AAvatars::treasury(33), min_fee);
-				assert_eq!(AAvatars::treasury(1), treasury_balance_season_1);
 			});
 	}
 
 	#[test]
-	fn buy_fee_should_be_calculated_correctly() {
-		let season = Season::default();
-		let min_fee = 123;
-		let percent_fee = 30;
-		let mut alice_balance = 999_999;
-		let mut bob_balance = 999_999;
-		let mut treasury_balance = 0;
-
+	fn prepare_avatar_rejects_forging_trading_and_transferring() {
 		ExtBuilder::default()
-			.seasons(&[(1, season.clone())])
-			.balances(&[(ALICE, alice_balance), (BOB, bob_balance)])
+			.seasons(&[(1, Season::default())])
 			.build()
 			.execute_with(|| {
-				run_to_block(season.start);
-				let avatar_ids = create_avatars(1, ALICE, 2);
-
-				GlobalConfigs::<Test>::mutate(|cfg| {
-					cfg.trade.min_fee = min_fee;
-					cfg.trade.percent_fee = percent_fee;
-				});
-
-				// when price is much greater (> 30%) than min_fee, percent_fee should be charged
-				let price = 9_999;
-				assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(ALICE), avatar_ids[0], price));
-				assert_ok!(AAvatars::buy(RuntimeOrigin::signed(BOB), avatar_ids[0]));
-				let expected_fee = price * percent_fee as u64 / 100_u64;
-				bob_balance -= price + expected_fee;
-				alice_balance += price;
-				treasury_balance += expected_fee;
-				assert_eq!(Balances::free_balance(BOB), bob_balance);
-				assert_eq!(Balances::free_balance(ALICE), alice_balance);
-				assert_eq!(AAvatars::treasury(1), treasury_balance);
+				let avatar_ids = create_avatars(1, ALICE, 5);
+				let avatar_id = avatar_ids[0];
+				assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), ALICE));
+				assert_ok!(AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
 
-				// when price is less than min_fee, min_fee should be charged
-				let price = 100;
-				assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(ALICE), avatar_ids[1], price));
-				assert_ok!(AAvatars::buy(RuntimeOrigin::signed(BOB), avatar_ids[1]));
-				bob_balance -= price + min_fee;
-				alice_balance += price;
-				treasury_balance += min_fee;
-				assert_eq!(Balances::free_balance(BOB), bob_balance);
-				assert_eq!(Balances::free_balance(ALICE), alice_balance);
-				assert_eq!(AAvatars::treasury(1), treasury_balance);
+				for extrinsic in [
+					AAvatars::set_price(RuntimeOrigin::signed(ALICE), avatar_id, 1_000),
+					AAvatars::transfer_avatar(RuntimeOrigin::signed(ALICE), BOB, avatar_id),
+					AAvatars::forge(
+						RuntimeOrigin::signed(ALICE),
+						avatar_id,
+						avatar_ids[1..3].to_vec(),
+					),
+				] {
+					assert_noop!(extrinsic, Error::<Test>::AlreadyPrepared);
+				}
 			});
 	}
 
 	#[test]
-	fn buy_should_reject_when_trading_is_closed() {
+	fn prepare_avatar_rejects_unsigned_calls() {
 		ExtBuilder::default().build().execute_with(|| {
-			GlobalConfigs::<Test>::mutate(|config| config.trade.open = false);
+			let avatar_id = create_avatars(1, ALICE, 1)[0];
 			assert_noop!(
-				AAvatars::buy(RuntimeOrigin::signed(ALICE), sp_core::H256::default()),
-				Error::<Test>::TradeClosed,
+				AAvatars::prepare_avatar(RuntimeOrigin::none(), avatar_id),
+				DispatchError::BadOrigin
 			);
 		});
 	}
 
 	#[test]
-	fn buy_should_reject_unsigned_calls() {
+	fn prepare_avatar_rejects_unowned_avatars() {
 		ExtBuilder::default().build().execute_with(|| {
+			let avatar_id = create_avatars(1, ALICE, 1)[0];
 			assert_noop!(
-				AAvatars::buy(RuntimeOrigin::none(), sp_core::H256::default()),
-				DispatchError::BadOrigin,
+				AAvatars::prepare_avatar(RuntimeOrigin::signed(BOB), avatar_id),
+				Error::<Test>::Ownership
 			);
 		});
 	}
 
 	#[test]
-	fn buy_should_reject_unlisted_avatar() {
+	fn prepare_avatar_rejects_avatars_in_trade() {
 		ExtBuilder::default().build().execute_with(|| {
+			let avatar_id = create_avatars(1, ALICE, 1)[0];
+			assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(ALICE), avatar_id, 1));
 			assert_noop!(
-				AAvatars::buy(RuntimeOrigin::signed(BOB), sp_core::H256::default()),
-				Error::<Test>::UnknownAvatarForSale,
+				AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
+				Error::<Test>::AvatarInTrade
 			);
 		});
 	}
 
 	#[test]
-	fn buy_should_reject_insufficient_balance() {
-		let season = Season::default();
-		let price = 310_984;
-
-		ExtBuilder::default()
-			.seasons(&[(1, season.clone())])
-			.balances(&[(ALICE, price - 1)])
-			.build()
-			.execute_with(|| {
-				run_to_block(season.start);
-				let avatar_ids = create_avatars(1, BOB, 3);
-				let avatar_for_sale = avatar_ids[0];
-
-				assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(BOB), avatar_for_sale, price));
-				assert_noop!(
-					AAvatars::buy(RuntimeOrigin::signed(ALICE), avatar_for_sale),
-					pallet_balances::Error::<Test>::InsufficientBalance
-				);
-			});
+	fn prepare_avatar_rejects_when_closed() {
+		ExtBuilder::default().build().execute_with(|| {
+			let avatar_id = create_avatars(1, ALICE, 1)[0];
+			GlobalConfigs::<Test>::mutate(|config| config.nft_transfer.open = false);
+			assert_noop!(
+				AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
+				Error::<Test>::NftTransferClosed
+			);
+		});
 	}
 
 	#[test]
-	fn buy_should_reject_when_buyer_buys_its_own_avatar() {
-		let season = Season::default();
-
-		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
-			run_to_block(season.start);
-			let avatar_ids = create_avatars(1, BOB, 3);
-			let avatar_for_sale = avatar_ids[0];
-
-			assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(BOB), avatar_for_sale, 123));
+	fn prepare_avatar_rejects_locked_avatars() {
+		ExtBuilder::default().build().execute_with(|| {
+			let avatar_id = create_avatars(1, ALICE, 1)[0];
+			LockedAvatars::<Test>::insert(avatar_id, ());
 			assert_noop!(
-				AAvatars::buy(RuntimeOrigin::signed(BOB), avatar_for_sale),
-				Error::<Test>::AlreadyOwned
+				AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
+				Error::<Test>::AvatarLocked
 			);
 		});
 	}
-}
-
-mod account {
-	use super::*;
 
 	#[test]
-	fn upgrade_storage_should_work() {
-		let upgrade_fee = 12_345 as MockBalance;
-		let num_storage_tiers = sp_std::mem::variant_count::<StorageTier>();
-		let alice_balance = num_storage_tiers as MockBalance * upgrade_fee;
-		let mut treasury_balance = 0;
-		let total_supply = treasury_balance + alice_balance;
+	fn prepare_avatar_rejects_already_prepared_avatars() {
+		ExtBuilder::default().build().execute_with(|| {
+			let avatar_id = create_avatars(1, ALICE, 1)[0];
+			let ipfs_url = IpfsUrl::try_from(Vec::new()).unwrap();
+			Preparation::<Test>::insert(avatar_id, ipfs_url);
+			assert_noop!(
+				AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
+				Error::<Test>::AlreadyPrepared
+			);
+		});
+	}
 
+	#[test]
+	fn prepare_avatar_rejects_insufficient_balance() {
 		ExtBuilder::default()
-			.balances(&[(ALICE, alice_balance)])
+			.nft_prepare_fee(333)
+			.balances(&[(ALICE, MockExistentialDeposit::get())])
 			.build()
 			.execute_with(|| {
-				GlobalConfigs::<Test>::mutate(|cfg| cfg.account.storage_upgrade_fee = upgrade_fee);
-
-				assert_eq!(AAvatars::accounts(ALICE).storage_tier, StorageTier::One);
-				assert_eq!(AAvatars::accounts(ALICE).storage_tier as isize, 25);
-				assert_eq!(
-					Balances::free_balance(&AAvatars::treasury_account_id()),
-					treasury_balance
-				);
-				assert_eq!(Balances::total_issuance(), total_supply);
-
-				assert_ok!(AAvatars::upgrade_storage(RuntimeOrigin::signed(ALICE)));
-				assert_eq!(AAvatars::accounts(ALICE).storage_tier, StorageTier::Two);
-				assert_eq!(AAvatars::accounts(ALICE).storage_tier as isize, 50);
-				treasury_balance += upgrade_fee;
-				assert_eq!(
-					Balances::free_balance(&AAvatars::treasury_account_id()),
-					treasury_balance
-				);
-				assert_eq!(Balances::total_issuance(), total_supply);
-
-				assert_ok!(AAvatars::upgrade_storage(RuntimeOrigin::signed(ALICE)));
-				assert_eq!(AAvatars::accounts(ALICE).storage_tier, StorageTier::Three);
-				assert_eq!(AAvatars::accounts(ALICE).storage_tier as isize, 75);
-				treasury_balance += upgrade_fee;
-				assert_eq!(
-					Balances::free_balance(&AAvatars::treasury_account_id()),
-					treasury_balance
-				);
-				assert_eq!(Balances::total_issuance(), total_supply);
-
-				assert_ok!(AAvatars::upgrade_storage(RuntimeOrigin::signed(ALICE)));
-				assert_eq!(AAvatars::accounts(ALICE).storage_tier, StorageTier::Four);
-				assert_eq!(AAvatars::accounts(ALICE).storage_tier as isize, 100);
-				treasury_balance += upgrade_fee;
-				assert_eq!(
-					Balances::free_balance(&AAvatars::treasury_account_id()),
-					treasury_balance
+				let avatar_id = create_avatars(1, ALICE, 1)[0];
+				assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), BOB));
+				assert_noop!(
+					AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
+					pallet_balances::Error::<Test>::InsufficientBalance
 				);
-				assert_eq!(Balances::total_issuance(), total_supply);
-
-				assert_ok!(AAvatars::upgrade_storage(RuntimeOrigin::signed(ALICE)));
-				assert_eq!(AAvatars::accounts(ALICE).storage_tier, StorageTier::Five);
-				assert_eq!(AAvatars::accounts(ALICE).storage_tier as isize, 150);
-
-				assert_ok!(AAvatars::upgrade_storage(RuntimeOrigin::signed(ALICE)));
-				assert_eq!(AAvatars::accounts(ALICE).storage_tier, StorageTier::Max);
-				assert_eq!(AAvatars::accounts(ALICE).storage_tier as isize, 200);
 			});
 	}
 
 	#[test]
-	fn upgrade_storage_should_reject_insufficient_balance() {
+	fn unprepare_avatar_works() {
+		ExtBuilder::default().build().execute_with(|| {
+			let avatar_id = create_avatars(1, ALICE, 1)[0];
+			assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), ALICE));
+			assert_ok!(AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
+			assert_ok!(AAvatars::unprepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
+			assert!(!Preparation::<Test>::contains_key(avatar_id));
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(
+				crate::Event::UnpreparedAvatar { avatar_id },
+			));
+		});
+	}
+
+	#[test]
+	fn unprepare_avatar_rejects_unsigned_calls() {
 		ExtBuilder::default().build().execute_with(|| {
 			assert_noop!(
-				AAvatars::upgrade_storage(RuntimeOrigin::signed(ALICE)),
-				pallet_balances::Error::<Test>::InsufficientBalance
+				AAvatars::unprepare_avatar(RuntimeOrigin::none(), H256::random()),
+				DispatchError::BadOrigin
 			);
 		});
 	}
 
 	#[test]
-	fn upgrade_storage_should_reject_fully_upgraded_storage() {
+	fn unprepare_avatar_rejects_unowned_avatars() {
 		ExtBuilder::default().build().execute_with(|| {
-			GlobalConfigs::<Test>::mutate(|cfg| cfg.account.storage_upgrade_fee = 0);
-			Accounts::<Test>::mutate(ALICE, |account| account.storage_tier = StorageTier::Max);
-
+			let avatar_id = create_avatars(1, ALICE, 1)[0];
 			assert_noop!(
-				AAvatars::upgrade_storage(RuntimeOrigin::signed(ALICE)),
-				Error::<Test>::MaxStorageTierReached
+				AAvatars::unprepare_avatar(RuntimeOrigin::signed(BOB), avatar_id),
+				Error::<Test>::Ownership
 			);
 		});
 	}
-}
-
-mod nft_transfer {
-	use super::*;
-	use frame_support::{
-		bounded_vec,
-		traits::tokens::{nonfungibles_v2::Inspect, AttributeNamespace},
-	};
-	use pallet_ajuna_nft_transfer::traits::{AttributeCode, NftConvertible};
 
 	#[test]
-	fn set_collection_id_works() {
-		ExtBuilder::default().organizer(CHARLIE).build().execute_with(|| {
-			let collection_id = 369;
-			assert_ok!(AAvatars::set_collection_id(RuntimeOrigin::signed(CHARLIE), collection_id));
-			assert_eq!(AAvatars::collection_id(), Some(collection_id));
+	fn unprepare_avatar_rejects_when_closed() {
+		ExtBuilder::default().build().execute_with(|| {
+			let avatar_id = create_avatars(1, ALICE, 1)[0];
+			GlobalConfigs::<Test>::mutate(|config| config.nft_transfer.open = false);
+			assert_noop!(
+				AAvatars::unprepare_avatar(RuntimeOrigin::signed(BOB), avatar_id),
+				Error::<Test>::Ownership
+			);
 		});
 	}
 
 	#[test]
-	fn set_collection_id_rejects_non_organizer_calls() {
-		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+	fn unprepare_avatar_rejects_unprepared_avatars() {
+		ExtBuilder::default().build().execute_with(|| {
+			let avatar_id = create_avatars(1, ALICE, 1)[0];
 			assert_noop!(
-				AAvatars::set_collection_id(RuntimeOrigin::signed(BOB), 333),
-				DispatchError::BadOrigin
+				AAvatars::unprepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
+				Error::<Test>::NotPrepared
 			);
 		});
 	}
 
 	#[test]
-	fn can_lock_avatar_successfully() {
-		let season = Season::default().max_components(8).max_variations(5);
-
-		ExtBuilder::default()
-			.seasons(&[(1, season.clone())])
-			.balances(&[(ALICE, 1_000_000_000_000)])
-			.create_nft_collection(true)
-			.build()
-			.execute_with(|| {
-				run_to_block(season.start);
-				assert_ok!(AAvatars::mint(
-					RuntimeOrigin::signed(ALICE),
-					MintOption { count: MintPackSize::Three, mint_type: MintType::Normal }
-				));
-				let avatar_ids = AAvatars::owners(ALICE);
-				let avatar_id = avatar_ids[0];
-
-				assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), ALICE));
-				assert_ok!(AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
-				assert_ok!(AAvatars::prepare_ipfs(
-					RuntimeOrigin::signed(ALICE),
-					avatar_id,
-					IpfsUrl::try_from(b"test".to_vec()).unwrap()
-				));
-				assert_ok!(AAvatars::lock_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
-				assert!(!Preparation::<Test>::contains_key(avatar_id));
-				assert!(LockedAvatars::<Test>::contains_key(avatar_id));
-				System::assert_last_event(mock::RuntimeEvent::AAvatars(
-					crate::Event::AvatarLocked { avatar_id },
-				));
-
-				let (_, avatar) = AAvatars::avatars(avatar_id).unwrap();
-				assert_eq!(
-					avatar,
-					Avatar {
-						season_id: 1,
-						dna: bounded_vec![0x14, 0x00, 0x13, 0x00, 0x02, 0x00, 0x01, 0x02],
-						souls: 45
-					}
-				);
-
-				// Ensure correct encoding
-				assert_eq!(
-					<Nft as Inspect<MockAccountId>>::typed_attribute::<
-						pallet_ajuna_nft_transfer::traits::AttributeCode,
-						Avatar,
-					>(
-						&AAvatars::collection_id().unwrap(),
-						&avatar_id,
-						&AttributeNamespace::Pallet,
-						&<Avatar as NftConvertible>::ITEM_CODE,
-					)
-					.unwrap(),
-					avatar,
-				);
-
-				// Ensure attributes encoding
-				for (attribute_code, encoded_attribute) in
-					Avatar::get_attribute_codes().iter().zip([
-						avatar.dna.encode(),
-						avatar.souls.encode(),
-						RarityTier::Common.encode(),
-						Force::Solar.encode(),
-					]) {
-					assert_eq!(
-						<Nft as Inspect<MockAccountId>>::typed_attribute::<AttributeCode, Vec<u8>>(
-							&AAvatars::collection_id().unwrap(),
-							&avatar_id,
-							&AttributeNamespace::Pallet,
-							attribute_code,
-						)
-						.unwrap(),
-						encoded_attribute
-					);
-				}
-
-				// Ensure ownership transferred to technical account
-				let technical_account = AAvatars::technical_account_id();
-				assert!(!AAvatars::owners(ALICE).contains(&avatar_id));
-				assert_eq!(AAvatars::owners(technical_account)[0], avatar_id);
-				assert_eq!(AAvatars::avatars(avatar_id).unwrap().0, technical_account);
-
-				// Ensure locked avatars cannot be used in trading, transferring and forging
-				for extrinsic in [
-					AAvatars::set_price(RuntimeOrigin::signed(technical_account), avatar_id, 1_000),
-					AAvatars::transfer_avatar(
-						RuntimeOrigin::signed(technical_account),
-						BOB,
-						avatar_id,
-					),
-					AAvatars::forge(
-						RuntimeOrigin::signed(technical_account),
-						avatar_id,
-						avatar_ids[1..3].to_vec(),
-					),
-				] {
-					assert_noop!(extrinsic, Error::<Test>::AvatarLocked);
-				}
-			});
-	}
+	fn prepare_ipfs_works() {
+		ExtBuilder::default().build().execute_with(|| {
+			let avatar_id = create_avatars(1, ALICE, 1)[0];
+			assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), ALICE));
+			assert_ok!(AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
+			ServiceAccount::<Test>::put(BOB);
 
-	#[test]
-	fn cannot_lock_when_nft_transfer_is_closed() {
-		ExtBuilder::default()
-			.balances(&[(ALICE, MockExistentialDeposit::get())])
-			.create_nft_collection(true)
-			.build()
-			.execute_with(|| {
-				let avatar_id = create_avatars(1, ALICE, 1)[0];
-				GlobalConfigs::<Test>::mutate(|config| config.nft_transfer.open = false);
-				assert_noop!(
-					AAvatars::lock_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
-					Error::<Test>::NftTransferClosed
-				);
-			})
-	}
+			let ipfs_url = b"ipfs://{CID}/{optional path to resource}".to_vec();
+			let ipfs_url = IpfsUrl::try_from(ipfs_url).unwrap();
+			assert_ok!(AAvatars::prepare_ipfs(
+				RuntimeOrigin::signed(BOB),
+				avatar_id,
+				ipfs_url.clone()
+			));
+			assert_eq!(AAvatars::preparation(avatar_id).unwrap(), ipfs_url);
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(
+				crate::Event::PreparedIpfsUrl { url: ipfs_url },
+			));
 
-	#[test]
-	fn cannot_lock_unowned_avatar() {
-		ExtBuilder::default()
-			.balances(&[(ALICE, 1_000_000_000_000), (BOB, 1_000_000_000_000)])
-			.create_nft_collection(true)
-			.build()
-			.execute_with(|| {
-				let avatar_id = create_avatars(1, BOB, 1)[0];
-				assert_noop!(
-					AAvatars::lock_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
-					Error::<Test>::Ownership
-				);
-			});
+			let ipfs_url = b"ipfs://123".to_vec();
+			let ipfs_url = IpfsUrl::try_from(ipfs_url).unwrap();
+			assert_ok!(AAvatars::prepare_ipfs(
+				RuntimeOrigin::signed(BOB),
+				avatar_id,
+				ipfs_url.clone()
+			));
+			assert_eq!(AAvatars::preparation(avatar_id).unwrap(), ipfs_url);
+			System::assert_last_event(mock::RuntimeEvent::AAvatars(
+				crate::Event::PreparedIpfsUrl { url: ipfs_url },
+			));
+		});
 	}
 
 	#[test]
-	fn cannot_lock_avatar_on_trade() {
-		ExtBuilder::default()
-			.balances(&[(ALICE, 1_000_000_000_000)])
-			.create_nft_collection(true)
-			.build()
-			.execute_with(|| {
-				let avatar_id = create_avatars(1, ALICE, 1)[0];
-				assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(ALICE), avatar_id, 1_000));
-				assert_noop!(
-					AAvatars::lock_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
-					Error::<Test>::AvatarInTrade
-				);
-			});
-	}
+	fn prepare_ipfs_rejects_empty_url() {
+		ExtBuilder::default().build().execute_with(|| {
+			let avatar_id = create_avatars(1, ALICE, 1)[0];
+			assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), ALICE));
+			assert_ok!(AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
+			ServiceAccount::<Test>::put(BOB);
 
-	#[test]
-	fn cannot_lock_already_locked_avatar() {
-		ExtBuilder::default()
-			.balances(&[(ALICE, 1_000_000_000_000)])
-			.create_nft_collection(true)
-			.build()
-			.execute_with(|| {
-				let avatar_id = create_avatars(1, ALICE, 1)[0];
-				assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), ALICE));
-				assert_ok!(AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
-				assert_ok!(AAvatars::prepare_ipfs(
-					RuntimeOrigin::signed(ALICE),
-					avatar_id,
-					IpfsUrl::try_from(b"test".to_vec()).unwrap()
-				));
-				assert_ok!(AAvatars::lock_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
-				assert_noop!(
-					AAvatars::lock_avatar(
-						RuntimeOrigin::signed(AAvatars::technical_account_id()),
-						avatar_id
-					),
-					Error::<Test>::AvatarLocked
-				);
-			});
+			assert_noop!(
+				AAvatars::prepare_ipfs(RuntimeOrigin::signed(BOB), avatar_id, IpfsUrl::default()),
+				Error::<Test>::EmptyIpfsUrl
+			);
+		});
 	}
+}
+
+mod query {
+	use super::*;
 
 	#[test]
-	fn cannot_lock_unprepared_avatar() {
-		ExtBuilder::default()
-			.balances(&[(ALICE, 1_000_000_000_000)])
-			.create_nft_collection(true)
-			.build()
-			.execute_with(|| {
-				let avatar_id = create_avatars(1, ALICE, 1)[0];
-				assert_noop!(
-					AAvatars::lock_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
-					Error::<Test>::NotPrepared
-				);
+	fn avatars_of_works() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert!(AAvatars::avatars_of(&ALICE).is_empty());
+
+			let avatar_ids = create_avatars(1, ALICE, 3);
+			Avatars::<Test>::mutate(avatar_ids[1], |maybe_avatar| {
+				let (_, avatar) = maybe_avatar.as_mut().unwrap();
+				avatar.dna = Dna::try_from([0x24, 0x53, 0x35, 0x42].to_vec()).unwrap();
+				avatar.souls = 123;
 			});
+			Trade::<Test>::insert(avatar_ids[1], 999);
+			LockedAvatars::<Test>::insert(avatar_ids[2], ());
+
+			let avatars = AAvatars::avatars_of(&ALICE);
+			assert_eq!(avatars.len(), 3);
+			assert_eq!(
+				avatars[0],
+				AvatarInfo {
+					avatar_id: avatar_ids[0],
+					season_id: 1,
+					dna: Dna::try_from([0; 32].to_vec()).unwrap(),
+					souls: 0,
+					rarity: RarityTier::Common,
+					force: Force::Kinetic,
+					price: None,
+					locked: false,
+				}
+			);
+			assert_eq!(
+				avatars[1],
+				AvatarInfo {
+					avatar_id: avatar_ids[1],
+					season_id: 1,
+					dna: Dna::try_from([0x24, 0x53, 0x35, 0x42].to_vec()).unwrap(),
+					souls: 123,
+					rarity: RarityTier::Rare,
+					force: Force::Solar,
+					price: Some(999),
+					locked: false,
+				}
+			);
+			assert_eq!(avatars[2].force, Force::Solar);
+			assert!(avatars[2].locked);
+			assert!(AAvatars::avatars_of(&BOB).is_empty());
+		});
 	}
 
 	#[test]
-	fn cannot_lock_prepared_avatar_with_empty_url() {
-		ExtBuilder::default()
-			.balances(&[(ALICE, 1_000_000_000_000)])
-			.create_nft_collection(true)
-			.build()
-			.execute_with(|| {
-				let avatar_id = create_avatars(1, ALICE, 1)[0];
-				assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), ALICE));
-				assert_ok!(AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
-				assert_ok!(AAvatars::prepare_ipfs(
-					RuntimeOrigin::signed(ALICE),
-					avatar_id,
-					IpfsUrl::try_from(b"test".to_vec()).unwrap()
-				));
+	fn current_season_works() {
+		let season = Season::default();
 
-				// Technically this can't happen but testing just for the sake of completeness.
-				Preparation::<Test>::insert(avatar_id, IpfsUrl::default());
+		ExtBuilder::default().build().execute_with(|| {
+			assert_eq!(AAvatars::current_season(), None);
+		});
 
-				assert_noop!(
-					AAvatars::lock_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
-					pallet_ajuna_nft_transfer::Error::<Test>::EmptyIpfsUrl
-				);
-			});
+		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
+			run_to_block(season.start);
+			assert_eq!(
+				AAvatars::current_season(),
+				Some(CurrentSeason {
+					season_id: 1,
+					season: season.clone(),
+					status: SeasonStatus {
+						early: false,
+						active: true,
+						early_ended: false,
+						max_tier_avatars: 0
+					},
+				})
+			);
+		});
 	}
 
 	#[test]
-	fn cannot_lock_avatars_above_value_limit() {
-		let value_limit = 3;
+	fn listed_avatars_works() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert!(AAvatars::listed_avatars().is_empty());
 
-		ExtBuilder::default()
-			.balances(&[(ALICE, 1_000_000_000_000)])
-			.create_nft_collection(true)
-			.value_limit(value_limit)
-			.build()
-			.execute_with(|| {
-				let avatar_id = create_avatars(1, ALICE, 1)[0];
-				let (_, avatar) = AAvatars::avatars(avatar_id).unwrap();
-				assert!(avatar.encode().len() > value_limit as usize);
-				assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), ALICE));
-				assert_ok!(AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
-				assert_noop!(
-					AAvatars::lock_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
-					pallet_nfts::Error::<Test>::IncorrectData
-				);
-			});
-	}
+			let alice_avatar_ids = create_avatars(1, ALICE, 2);
+			let bob_avatar_ids = create_avatars(1, BOB, 1);
+			Trade::<Test>::insert(alice_avatar_ids[1], 123);
+			Trade::<Test>::insert(bob_avatar_ids[0], 456);
 
-	#[test]
-	fn can_unlock_avatar_successfully() {
-		ExtBuilder::default()
-			.balances(&[(ALICE, 1_000_000_000_000)])
-			.create_nft_collection(true)
-			.build()
-			.execute_with(|| {
-				let avatar_id = create_avatars(1, ALICE, 1)[0];
-				assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), ALICE));
-				assert_ok!(AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
-				assert_ok!(AAvatars::prepare_ipfs(
-					RuntimeOrigin::signed(ALICE),
-					avatar_id,
-					IpfsUrl::try_from(b"test".to_vec()).unwrap()
-				));
-				assert_ok!(AAvatars::lock_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
-				assert_ok!(AAvatars::unlock_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
-				assert_eq!(LockedAvatars::<Test>::get(avatar_id), None);
-				System::assert_last_event(mock::RuntimeEvent::AAvatars(
-					crate::Event::AvatarUnlocked { avatar_id },
-				));
-			});
+			let mut listed_avatars = AAvatars::listed_avatars();
+			listed_avatars.sort_by_key(|listed| listed.price);
+			assert_eq!(
+				listed_avatars,
+				vec![
+					ListedAvatar { avatar_id: alice_avatar_ids[1], owner: ALICE, price: 123 },
+					ListedAvatar { avatar_id: bob_avatar_ids[0], owner: BOB, price: 456 },
+				]
+			);
+		});
 	}
 
 	#[test]
-	fn cannot_unlock_when_nft_transfer_is_closed() {
-		ExtBuilder::default()
-			.balances(&[(ALICE, MockExistentialDeposit::get())])
-			.create_nft_collection(true)
-			.build()
-			.execute_with(|| {
-				let avatar_id = create_avatars(1, ALICE, 1)[0];
-				assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), ALICE));
-				assert_ok!(AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
-				assert_ok!(AAvatars::prepare_ipfs(
-					RuntimeOrigin::signed(ALICE),
-					avatar_id,
-					IpfsUrl::try_from(b"test".to_vec()).unwrap()
-				));
-				assert_ok!(AAvatars::lock_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
-				GlobalConfigs::<Test>::mutate(|config| config.nft_transfer.open = false);
-				assert_noop!(
-					AAvatars::unlock_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
-					Error::<Test>::NftTransferClosed
-				);
-			})
-	}
+	fn preview_forge_works() {
+		let season = Season::default()
+			.early_start(100)
+			.start(200)
+			.end(150_000)
+			.max_tier_forges(100)
+			.max_variations(6)
+			.max_components(11)
+			.min_sacrifices(1)
+			.max_sacrifices(4)
+			.tiers(&[RarityTier::Common, RarityTier::Rare, RarityTier::Legendary])
+			.single_mint_probs(&[95, 5])
+			.batch_mint_probs(&[80, 20])
+			.base_prob(20)
+			.per_period(20)
+			.periods(12);
 
-	#[test]
-	fn cannot_unlock_unowned_avatar() {
-		ExtBuilder::default()
-			.balances(&[(ALICE, 1_000_000_000_000), (BOB, 5_000_000_000_000)])
-			.create_nft_collection(true)
-			.build()
-			.execute_with(|| {
-				let avatar_id = create_avatars(1, BOB, 1)[0];
-				assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), ALICE));
-				assert_ok!(AAvatars::prepare_avatar(RuntimeOrigin::signed(BOB), avatar_id));
-				assert_ok!(AAvatars::prepare_ipfs(
-					RuntimeOrigin::signed(ALICE),
-					avatar_id,
-					IpfsUrl::try_from(b"test".to_vec()).unwrap()
-				));
-				assert_ok!(AAvatars::lock_avatar(RuntimeOrigin::signed(BOB), avatar_id));
-				assert_noop!(
-					AAvatars::unlock_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
-					pallet_nfts::Error::<Test>::NoPermission
-				);
-			});
+		ExtBuilder::default().seasons(&[(1, season)]).build().execute_with(|| {
+			let leader = Avatar::default()
+				.season_id(1)
+				.dna(&[0x21, 0x05, 0x23, 0x24, 0x20, 0x22, 0x25, 0x23, 0x05, 0x04, 0x02]);
+			let sacrifice = Avatar::default()
+				.season_id(1)
+				.dna(&[0x04, 0x00, 0x00, 0x04, 0x02, 0x04, 0x02, 0x00, 0x05, 0x05, 0x04]);
+			let (leader_id, sacrifice_id) = (H256::random(), H256::random());
+			for (avatar_id, avatar) in [(leader_id, leader), (sacrifice_id, sacrifice)] {
+				Avatars::<Test>::insert(avatar_id, (BOB, avatar));
+				AAvatars::add_ownership(&BOB, &avatar_id);
+			}
+
+			// not in the period of the leader's last variation
+			run_to_block(200);
+			let expected = ForgePreview {
+				matched_components: vec![1, 9],
+				matches: 1,
+				rolls: 1,
+				probability: 30,
+				period_multiplier: 2,
+			};
+			assert_eq!(AAvatars::preview_forge(&BOB, &leader_id, &[sacrifice_id]), Ok(expected));
+
+			// in the period of the leader's last variation
+			run_to_block(280);
+			let expected = ForgePreview {
+				matched_components: vec![1, 9],
+				matches: 1,
+				rolls: 1,
+				probability: 40,
+				period_multiplier: 1,
+			};
+			assert_eq!(AAvatars::preview_forge(&BOB, &leader_id, &[sacrifice_id]), Ok(expected));
+
+			// with a forge boost
+			ForgeBoosts::<Test>::insert(BOB, 15);
+			let expected = ForgePreview {
+				matched_components: vec![1, 9],
+				matches: 1,
+				rolls: 1,
+				probability: 55,
+				period_multiplier: 1,
+			};
+			assert_eq!(AAvatars::preview_forge(&BOB, &leader_id, &[sacrifice_id]), Ok(expected));
+			assert_eq!(AAvatars::forge_boosts(BOB), 15);
+
+			// nothing is written
+			let mut expected_avatar_ids = vec![leader_id, sacrifice_id];
+			expected_avatar_ids.sort();
+			assert_eq!(AAvatars::owners(&BOB), expected_avatar_ids);
+			assert_eq!(AAvatars::season_stats(1, BOB), SeasonInfo::default());
+		});
 	}
 
 	#[test]
-	fn cannot_unlock_transferred_avatar() {
+	fn preview_forge_returns_validation_errors() {
 		let season = Season::default();
 
-		ExtBuilder::default()
-			.seasons(&[(1, season.clone())])
-			.balances(&[(ALICE, 1_000_000_000_000)])
-			.create_nft_collection(true)
-			.build()
-			.execute_with(|| {
-				run_to_block(season.start);
+		ExtBuilder::default().seasons(&[(1, season.clone())]).build().execute_with(|| {
+			run_to_block(season.start);
+			let avatar_ids = create_avatars(1, BOB, 3);
 
-				let avatar_id = create_avatars(1, ALICE, 1)[0];
-				assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), ALICE));
-				assert_ok!(AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
-				assert_ok!(AAvatars::prepare_ipfs(
-					RuntimeOrigin::signed(ALICE),
-					avatar_id,
-					IpfsUrl::try_from(b"test".to_vec()).unwrap()
-				));
-				assert_ok!(AAvatars::lock_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
+			assert_eq!(
+				AAvatars::preview_forge(&BOB, &avatar_ids[0], &avatar_ids[0..2]),
+				Err(Error::<Test>::LeaderSacrificed.into())
+			);
+			assert_eq!(
+				AAvatars::preview_forge(&ALICE, &avatar_ids[0], &avatar_ids[1..3]),
+				Err(Error::<Test>::Ownership.into())
+			);
 
-				pallet_ajuna_nft_transfer::NftStatuses::<Test>::insert(
-					AAvatars::collection_id().unwrap(),
-					avatar_id,
-					pallet_ajuna_nft_transfer::NftStatus::Uploaded,
-				);
-				assert_noop!(
-					AAvatars::unlock_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
-					pallet_ajuna_nft_transfer::Error::<Test>::NftOutsideOfChain
-				);
-			});
+			GlobalConfigs::<Test>::mutate(|config| config.forge.open = false);
+			assert_eq!(
+				AAvatars::preview_forge(&BOB, &avatar_ids[0], &avatar_ids[1..3]),
+				Err(Error::<Test>::ForgeClosed.into())
+			);
+		});
 	}
 }
 
-mod fix_variation {
+mod randomness {
 	use super::*;
+	use crate::randomness::CommitRevealRandomness;
+	use frame_support::traits::Randomness;
+	use sp_runtime::traits::{BlakeTwo256, Hash};
+
+	const SAMPLES: u32 = 10_000;
+
+	fn tier_frequencies(season: &Season<MockBlockNumber>, batched_mint: bool) -> Vec<u32> {
+		let mut frequencies = vec![0; season.tiers.len()];
+		for i in 0..SAMPLES {
+			let hash = BlakeTwo256::hash_of(&i);
+			let (tier, _) = AAvatars::random_component(season, &hash, 0, batched_mint);
+			let index = season.tiers.iter().position(|x| x.clone() as u8 == tier).unwrap();
+			frequencies[index] += 1;
+		}
+		frequencies
+	}
 
-	#[test]
-	fn fix_variation_successfully() {
-		let season = Season::default();
-
-		ExtBuilder::default()
-			.seasons(&[(1, season.clone())])
-			.balances(&[(ALICE, 1_000_000_000_000)])
-			.build()
-			.execute_with(|| {
-				run_to_block(season.start);
-
-				let avatar_id = create_avatars(1, ALICE, 1)[0];
-
-				let (_, mut avatar_before) = Avatars::<Test>::get(avatar_id).unwrap();
-
-				avatar_before.dna[1] = 0b0001_1100;
-				avatar_before.dna[2] = 0b0100_0010;
+	fn assert_within_one_percent(frequencies: &[u32], probs: &[u8]) {
+		for (frequency, prob) in frequencies.iter().zip(probs) {
+			let expected = SAMPLES * *prob as u32 / MAX_PERCENTAGE as u32;
+			assert!(
+				frequency.abs_diff(expected) <= SAMPLES / 100,
+				"{frequencies:?} deviates from {probs:?}"
+			);
+		}
+	}
 
-				Avatars::<Test>::insert(avatar_id, (ALICE, avatar_before));
+	#[test]
+	fn random_component_follows_single_mint_probs() {
+		for probs in [&[50, 30, 15, 4, 1][..], &[20, 20, 20, 20, 20], &[95, 5]] {
+			let season = Season::default()
+				.tiers(
+					&[
+						RarityTier::Common,
+						RarityTier::Uncommon,
+						RarityTier::Rare,
+						RarityTier::Epic,
+						RarityTier::Legendary,
+						RarityTier::Mythical,
+					][..=probs.len()],
+				)
+				.single_mint_probs(probs);
+			assert_within_one_percent(&tier_frequencies(&season, false), probs);
+		}
+	}
 
-				assert_ok!(AAvatars::fix_variation(RuntimeOrigin::signed(ALICE), avatar_id));
+	#[test]
+	fn random_component_follows_batch_mint_probs() {
+		for probs in [&[40, 30, 20, 10][..], &[80, 20], &[33, 33, 34]] {
+			let season = Season::default()
+				.tiers(
+					&[
+						RarityTier::Common,
+						RarityTier::Uncommon,
+						RarityTier::Rare,
+						RarityTier::Epic,
+						RarityTier::Legendary,
+					][..=probs.len()],
+				)
+				.batch_mint_probs(probs);
+			assert_within_one_percent(&tier_frequencies(&season, true), probs);
+		}
+	}
 
-				let (_, avatar_after) = Avatars::<Test>::get(avatar_id).unwrap();
-				assert_eq!(avatar_after.dna[1], 0b0001_1100);
-				assert_eq!(avatar_after.dna[2], 0b0100_1100);
-			});
+	#[test]
+	fn random_below_rejects_biased_bytes() {
+		for bound in [MAX_PERCENTAGE, 3, 6, 12] {
+			let zone = 256 - 256 % bound as u16;
+			for byte in 0..=u8::MAX {
+				let hash = H256::repeat_byte(byte);
+				let value = AAvatars::random_below(&hash, 0, bound);
+				assert!(value < bound);
+				if (byte as u16) < zone {
+					assert_eq!(value, byte % bound);
+				}
+			}
+		}
 	}
-}
 
-mod ipfs {
-	use super::*;
+	#[test]
+	fn random_below_is_uniform() {
+		for bound in [MAX_PERCENTAGE, 6, 12] {
+			let mut frequencies = vec![0_u32; bound as usize];
+			for i in 0..SAMPLES {
+				let hash = BlakeTwo256::hash_of(&i);
+				frequencies[AAvatars::random_below(&hash, 0, bound) as usize] += 1;
+			}
+			let expected = SAMPLES / bound as u32;
+			assert!(frequencies.iter().all(|x| x.abs_diff(expected) <= expected / 2));
+		}
+	}
 
 	#[test]
-	fn set_service_account_works() {
-		ExtBuilder::default().build().execute_with(|| {
-			assert_eq!(AAvatars::service_account(), None);
-			assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), ALICE));
-			assert_eq!(AAvatars::service_account(), Some(ALICE));
+	fn commit_randomness_works() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			let commitment = BlakeTwo256::hash(b"commitment");
+			assert_ok!(AAvatars::commit_randomness(RuntimeOrigin::signed(ALICE), commitment));
+			assert_eq!(AAvatars::randomness_commitment(), Some(commitment));
 			System::assert_last_event(mock::RuntimeEvent::AAvatars(
-				crate::Event::ServiceAccountSet { service_account: ALICE },
+				crate::Event::RandomnessCommitted { commitment },
 			));
 		});
 	}
 
 	#[test]
-	fn set_service_account_rejects_non_root_calls() {
-		ExtBuilder::default().build().execute_with(|| {
+	fn commit_randomness_rejects_non_config_origin() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
 			assert_noop!(
-				AAvatars::set_service_account(RuntimeOrigin::signed(BOB), ALICE),
+				AAvatars::commit_randomness(RuntimeOrigin::signed(BOB), H256::default()),
 				DispatchError::BadOrigin
 			);
 		});
 	}
 
 	#[test]
-	fn prepare_avatar_works() {
-		let prepare_fee = 999;
-		let initial_balance = prepare_fee + MockExistentialDeposit::get();
-
-		ExtBuilder::default()
-			.nft_prepare_fee(prepare_fee)
-			.balances(&[(ALICE, initial_balance)])
-			.build()
-			.execute_with(|| {
-				let avatar_id = create_avatars(1, ALICE, 1)[0];
-				assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), BOB));
-				assert_eq!(Balances::free_balance(ALICE), initial_balance);
-				assert_ok!(AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
-				assert_eq!(Balances::free_balance(ALICE), initial_balance - prepare_fee);
-				assert_eq!(AAvatars::preparation(avatar_id).unwrap().to_vec(), Vec::<u8>::new());
+	fn reveal_randomness_walks_the_committed_chain() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			let chain = (0..3).fold(vec![BlakeTwo256::hash(b"secret")], |mut chain, _| {
+				chain.push(BlakeTwo256::hash(chain.last().unwrap().as_ref()));
+				chain
+			});
+			assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), CHARLIE));
+			assert_ok!(AAvatars::commit_randomness(RuntimeOrigin::signed(ALICE), chain[3]));
+
+			let subject = b"create_avatar";
+			let mut outputs = vec![CommitRevealRandomness::<Test>::random(subject).0];
+			for (block, value) in chain[..3].iter().rev().enumerate() {
+				run_to_block(block as MockBlockNumber + 2);
+				assert_ok!(AAvatars::reveal_randomness(RuntimeOrigin::signed(CHARLIE), *value));
+				assert_eq!(AAvatars::randomness_commitment(), Some(*value));
 				System::assert_last_event(mock::RuntimeEvent::AAvatars(
-					crate::Event::PreparedAvatar { avatar_id },
+					crate::Event::RandomnessRevealed { value: *value },
 				));
-			});
-	}
-
-	#[test]
-	fn prepare_avatar_rejects_forging_trading_and_transferring() {
-		ExtBuilder::default()
-			.seasons(&[(1, Season::default())])
-			.build()
-			.execute_with(|| {
-				let avatar_ids = create_avatars(1, ALICE, 5);
-				let avatar_id = avatar_ids[0];
-				assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), ALICE));
-				assert_ok!(AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
 
-				for extrinsic in [
-					AAvatars::set_price(RuntimeOrigin::signed(ALICE), avatar_id, 1_000),
-					AAvatars::transfer_avatar(RuntimeOrigin::signed(ALICE), BOB, avatar_id),
-					AAvatars::forge(
-						RuntimeOrigin::signed(ALICE),
-						avatar_id,
-						avatar_ids[1..3].to_vec(),
-					),
-				] {
-					assert_noop!(extrinsic, Error::<Test>::AlreadyPrepared);
-				}
-			});
+				let (output, revealed_at) = CommitRevealRandomness::<Test>::random(subject);
+				assert_eq!(revealed_at, System::block_number());
+				assert!(!outputs.contains(&output));
+				outputs.push(output);
+			}
+		});
 	}
 
 	#[test]
-	fn prepare_avatar_rejects_unsigned_calls() {
-		ExtBuilder::default().build().execute_with(|| {
-			let avatar_id = create_avatars(1, ALICE, 1)[0];
+	fn reveal_randomness_rejects_values_not_matching_the_commitment() {
+		ExtBuilder::default().organizer(ALICE).build().execute_with(|| {
+			let value = BlakeTwo256::hash(b"secret");
+			assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), CHARLIE));
 			assert_noop!(
-				AAvatars::prepare_avatar(RuntimeOrigin::none(), avatar_id),
+				AAvatars::reveal_randomness(RuntimeOrigin::signed(CHARLIE), value),
+				Error::<Test>::NoRandomnessCommitment
+			);
+
+			assert_ok!(AAvatars::commit_randomness(
+				RuntimeOrigin::signed(ALICE),
+				BlakeTwo256::hash(value.as_ref())
+			));
+			assert_noop!(
+				AAvatars::reveal_randomness(RuntimeOrigin::signed(CHARLIE), H256::default()),
+				Error::<Test>::InvalidRandomnessReveal
+			);
+			assert_noop!(
+				AAvatars::reveal_randomness(RuntimeOrigin::signed(BOB), value),
 				DispatchError::BadOrigin
 			);
 		});
 	}
+}
+
+mod offchain_worker {
+	use super::*;
+	use crate::offchain::*;
+	use codec::Decode;
+	use frame_support::traits::Hooks;
+	use parking_lot::RwLock;
+	use sp_core::offchain::{
+		testing::{
+			OffchainState, PendingRequest, PoolState, TestOffchainExt, TestTransactionPoolExt,
+		},
+		OffchainDbExt, OffchainWorkerExt, StorageKind, TransactionPoolExt,
+	};
+	use sp_runtime::testing::UintAuthorityId;
+	use std::sync::Arc;
+
+	const SERVICE_KEY: u64 = 7;
+	const OTHER_KEY: u64 = 8;
+	const IPFS_RESPONSE: &[u8] = br#"{"Name":"avatar.json","Hash":"QmAvatar","Size":"128"}"#;
+
+	fn build_with_offchain(
+		service_keys: Vec<u64>,
+	) -> (sp_io::TestExternalities, Arc<RwLock<OffchainState>>, Arc<RwLock<PoolState>>) {
+		let mut ext = ExtBuilder::default().build();
+		let (offchain, offchain_state) = TestOffchainExt::new();
+		let (pool, pool_state) = TestTransactionPoolExt::new();
+		ext.register_extension(OffchainDbExt::new(offchain.clone()));
+		ext.register_extension(OffchainWorkerExt::new(offchain));
+		ext.register_extension(TransactionPoolExt::new(pool));
+		ext.execute_with(|| ServiceAccount::<Test>::put(SERVICE_KEY));
+		UintAuthorityId::set_all_keys(service_keys);
+		(ext, offchain_state, pool_state)
+	}
+
+	fn prepare_avatar(account: MockAccountId) -> AvatarIdOf<Test> {
+		let avatar_id = create_avatars(1, account, 1)[0];
+		Preparation::<Test>::insert(avatar_id, IpfsUrl::default());
+		avatar_id
+	}
+
+	fn expect_pin(
+		state: &Arc<RwLock<OffchainState>>,
+		endpoint: &str,
+		avatar_id: &AvatarIdOf<Test>,
+	) {
+		let (_, avatar) = AAvatars::avatars(avatar_id).unwrap();
+		state.write().expect_request(PendingRequest {
+			method: "POST".into(),
+			uri: format!("{}/api/v0/add", endpoint),
+			headers: vec![("Content-Type".into(), MULTIPART_CONTENT_TYPE.into())],
+			body: AAvatars::multipart_body(&AAvatars::render_metadata(avatar_id, &avatar)),
+			response: Some(IPFS_RESPONSE.to_vec()),
+			sent: true,
+			..Default::default()
+		});
+	}
 
 	#[test]
-	fn prepare_avatar_rejects_unowned_avatars() {
-		ExtBuilder::default().build().execute_with(|| {
-			let avatar_id = create_avatars(1, ALICE, 1)[0];
-			assert_noop!(
-				AAvatars::prepare_avatar(RuntimeOrigin::signed(BOB), avatar_id),
-				Error::<Test>::Ownership
+	fn pins_prepared_avatars_and_submits_urls() {
+		let (mut ext, offchain_state, pool_state) = build_with_offchain(vec![SERVICE_KEY]);
+		ext.execute_with(|| {
+			let avatar_id = prepare_avatar(ALICE);
+			expect_pin(&offchain_state, "http://127.0.0.1:5001", &avatar_id);
+
+			AAvatars::offchain_worker(1);
+
+			let tx = pool_state.write().transactions.pop().unwrap();
+			assert!(pool_state.read().transactions.is_empty());
+			let tx = MockExtrinsic::decode(&mut &*tx).unwrap();
+			assert_eq!(tx.signature, Some((SERVICE_KEY, ())));
+			assert_eq!(
+				tx.call,
+				RuntimeCall::AAvatars(crate::Call::prepare_ipfs {
+					avatar_id,
+					url: IpfsUrl::try_from(b"ipfs://QmAvatar".to_vec()).unwrap(),
+				})
 			);
 		});
 	}
 
 	#[test]
-	fn prepare_avatar_rejects_avatars_in_trade() {
-		ExtBuilder::default().build().execute_with(|| {
-			let avatar_id = create_avatars(1, ALICE, 1)[0];
-			assert_ok!(AAvatars::set_price(RuntimeOrigin::signed(ALICE), avatar_id, 1));
-			assert_noop!(
-				AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
-				Error::<Test>::AvatarInTrade
+	fn uses_configured_ipfs_endpoint() {
+		let (mut ext, offchain_state, pool_state) = build_with_offchain(vec![SERVICE_KEY]);
+		ext.execute_with(|| {
+			sp_io::offchain::local_storage_set(
+				StorageKind::PERSISTENT,
+				IPFS_ENDPOINT_KEY,
+				b"http://ipfs.local:5001",
 			);
+			let avatar_id = prepare_avatar(ALICE);
+			expect_pin(&offchain_state, "http://ipfs.local:5001", &avatar_id);
+
+			AAvatars::offchain_worker(1);
+			assert_eq!(pool_state.read().transactions.len(), 1);
 		});
 	}
 
 	#[test]
-	fn prepare_avatar_rejects_when_closed() {
-		ExtBuilder::default().build().execute_with(|| {
+	fn skips_avatars_with_ipfs_url() {
+		let (mut ext, _, pool_state) = build_with_offchain(vec![SERVICE_KEY]);
+		ext.execute_with(|| {
 			let avatar_id = create_avatars(1, ALICE, 1)[0];
-			GlobalConfigs::<Test>::mutate(|config| config.nft_transfer.open = false);
-			assert_noop!(
-				AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
-				Error::<Test>::NftTransferClosed
+			Preparation::<Test>::insert(
+				avatar_id,
+				IpfsUrl::try_from(b"ipfs://QmAvatar".to_vec()).unwrap(),
 			);
+
+			AAvatars::offchain_worker(1);
+			assert!(pool_state.read().transactions.is_empty());
 		});
 	}
 
 	#[test]
-	fn prepare_avatar_rejects_locked_avatars() {
-		ExtBuilder::default().build().execute_with(|| {
-			let avatar_id = create_avatars(1, ALICE, 1)[0];
-			LockedAvatars::<Test>::insert(avatar_id, ());
-			assert_noop!(
-				AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
-				Error::<Test>::AvatarLocked
-			);
+	fn does_not_pin_recently_pinned_avatars() {
+		let (mut ext, offchain_state, pool_state) = build_with_offchain(vec![SERVICE_KEY]);
+		ext.execute_with(|| {
+			let avatar_id = prepare_avatar(ALICE);
+			expect_pin(&offchain_state, "http://127.0.0.1:5001", &avatar_id);
+			AAvatars::offchain_worker(1);
+			assert_eq!(pool_state.read().transactions.len(), 1);
+
+			AAvatars::offchain_worker(PIN_RETRY_BLOCKS as MockBlockNumber);
+			assert_eq!(pool_state.read().transactions.len(), 1);
+
+			expect_pin(&offchain_state, "http://127.0.0.1:5001", &avatar_id);
+			AAvatars::offchain_worker(1 + PIN_RETRY_BLOCKS as MockBlockNumber);
+			assert_eq!(pool_state.read().transactions.len(), 2);
 		});
 	}
 
 	#[test]
-	fn prepare_avatar_rejects_already_prepared_avatars() {
-		ExtBuilder::default().build().execute_with(|| {
-			let avatar_id = create_avatars(1, ALICE, 1)[0];
-			let ipfs_url = IpfsUrl::try_from(Vec::new()).unwrap();
-			Preparation::<Test>::insert(avatar_id, ipfs_url);
-			assert_noop!(
-				AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
-				Error::<Test>::AlreadyPrepared
-			);
+	fn does_not_pin_without_service_key() {
+		let (mut ext, _, pool_state) = build_with_offchain(vec![]);
+		ext.execute_with(|| {
+			let _ = prepare_avatar(ALICE);
+
+			AAvatars::offchain_worker(1);
+			assert!(pool_state.read().transactions.is_empty());
 		});
 	}
 
 	#[test]
-	fn prepare_avatar_rejects_insufficient_balance() {
-		ExtBuilder::default()
-			.nft_prepare_fee(333)
-			.balances(&[(ALICE, MockExistentialDeposit::get())])
-			.build()
-			.execute_with(|| {
-				let avatar_id = create_avatars(1, ALICE, 1)[0];
-				assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), BOB));
-				assert_noop!(
-					AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
-					pallet_balances::Error::<Test>::InsufficientBalance
-				);
-			});
+	fn only_signs_with_the_service_account_key() {
+		let (mut ext, offchain_state, pool_state) =
+			build_with_offchain(vec![OTHER_KEY, SERVICE_KEY]);
+		ext.execute_with(|| {
+			let avatar_id = prepare_avatar(ALICE);
+			expect_pin(&offchain_state, "http://127.0.0.1:5001", &avatar_id);
+
+			AAvatars::offchain_worker(1);
+
+			let tx = pool_state.write().transactions.pop().unwrap();
+			assert!(pool_state.read().transactions.is_empty());
+			let tx = MockExtrinsic::decode(&mut &*tx).unwrap();
+			assert_eq!(tx.signature, Some((SERVICE_KEY, ())));
+		});
 	}
 
 	#[test]
-	fn unprepare_avatar_works() {
-		ExtBuilder::default().build().execute_with(|| {
-			let avatar_id = create_avatars(1, ALICE, 1)[0];
-			assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), ALICE));
-			assert_ok!(AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
-			assert_ok!(AAvatars::unprepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
-			assert!(!Preparation::<Test>::contains_key(avatar_id));
-			System::assert_last_event(mock::RuntimeEvent::AAvatars(
-				crate::Event::UnpreparedAvatar { avatar_id },
-			));
+	fn does_not_pin_with_keys_other_than_the_service_account() {
+		let (mut ext, _, pool_state) = build_with_offchain(vec![OTHER_KEY]);
+		ext.execute_with(|| {
+			let _ = prepare_avatar(ALICE);
+
+			AAvatars::offchain_worker(1);
+			assert!(pool_state.read().transactions.is_empty());
 		});
 	}
 
 	#[test]
-	fn unprepare_avatar_rejects_unsigned_calls() {
-		ExtBuilder::default().build().execute_with(|| {
-			assert_noop!(
-				AAvatars::unprepare_avatar(RuntimeOrigin::none(), H256::random()),
-				DispatchError::BadOrigin
-			);
+	fn does_not_pin_without_service_account() {
+		let (mut ext, _, pool_state) = build_with_offchain(vec![SERVICE_KEY]);
+		ext.execute_with(|| {
+			let _ = prepare_avatar(ALICE);
+			ServiceAccount::<Test>::kill();
+
+			AAvatars::offchain_worker(1);
+			assert!(pool_state.read().transactions.is_empty());
 		});
 	}
 
 	#[test]
-	fn unprepare_avatar_rejects_unowned_avatars() {
-		ExtBuilder::default().build().execute_with(|| {
-			let avatar_id = create_avatars(1, ALICE, 1)[0];
-			assert_noop!(
-				AAvatars::unprepare_avatar(RuntimeOrigin::signed(BOB), avatar_id),
-				Error::<Test>::Ownership
-			);
+	fn does_not_pin_while_nft_transfer_is_closed() {
+		let (mut ext, _, pool_state) = build_with_offchain(vec![SERVICE_KEY]);
+		ext.execute_with(|| {
+			let _ = prepare_avatar(ALICE);
+			GlobalConfigs::<Test>::mutate(|config| config.nft_transfer.open = false);
+
+			AAvatars::offchain_worker(1);
+			assert!(pool_state.read().transactions.is_empty());
 		});
 	}
 
 	#[test]
-	fn unprepare_avatar_rejects_when_closed() {
-		ExtBuilder::default().build().execute_with(|| {
+	fn pins_pending_uploads_and_confirms_them() {
+		let (mut ext, offchain_state, pool_state) = build_with_offchain(vec![SERVICE_KEY]);
+		ext.execute_with(|| {
 			let avatar_id = create_avatars(1, ALICE, 1)[0];
-			GlobalConfigs::<Test>::mutate(|config| config.nft_transfer.open = false);
-			assert_noop!(
-				AAvatars::unprepare_avatar(RuntimeOrigin::signed(BOB), avatar_id),
-				Error::<Test>::Ownership
+			PendingUploads::<Test>::insert(avatar_id, ALICE);
+			let (_, avatar) = AAvatars::avatars(avatar_id).unwrap();
+			offchain_state.write().expect_request(PendingRequest {
+				method: "POST".into(),
+				uri: "http://127.0.0.1:5001/api/v0/add".into(),
+				headers: vec![("Content-Type".into(), MULTIPART_CONTENT_TYPE.into())],
+				body: AAvatars::multipart_body(&AAvatars::render_upload(
+					&avatar_id, &avatar, &ALICE,
+				)),
+				response: Some(IPFS_RESPONSE.to_vec()),
+				sent: true,
+				..Default::default()
+			});
+
+			AAvatars::offchain_worker(1);
+
+			let tx = pool_state.write().transactions.pop().unwrap();
+			assert!(pool_state.read().transactions.is_empty());
+			let tx = MockExtrinsic::decode(&mut &*tx).unwrap();
+			assert_eq!(tx.signature, Some((SERVICE_KEY, ())));
+			assert_eq!(
+				tx.call,
+				RuntimeCall::AAvatars(crate::Call::confirm_upload {
+					avatar_id,
+					url: IpfsUrl::try_from(b"ipfs://QmAvatar".to_vec()).unwrap(),
+				})
 			);
+
+			// the upload isn't pinned again until it's time to retry
+			AAvatars::offchain_worker(2);
+			assert!(pool_state.read().transactions.is_empty());
 		});
 	}
 
 	#[test]
-	fn unprepare_avatar_rejects_unprepared_avatars() {
+	fn render_upload_works() {
+		let avatar_id = H256::repeat_byte(0xab);
+		let avatar =
+			Avatar { season_id: 1, dna: Dna::try_from(vec![0x13, 0x22]).unwrap(), souls: 258 };
+
+		let mut expected = b"{\"owner\":\"0x0100000000000000\",\"avatar\":".to_vec();
+		expected.extend(AAvatars::render_metadata(&avatar_id, &avatar));
+		expected.push(b'}');
+		assert_eq!(AAvatars::render_upload(&avatar_id, &avatar, &ALICE), expected);
+	}
+
+	#[test]
+	fn render_metadata_works() {
+		let avatar_id = H256::repeat_byte(0xab);
+		let avatar =
+			Avatar { season_id: 1, dna: Dna::try_from(vec![0x13, 0x22]).unwrap(), souls: 258 };
+
+		let expected = format!(
+			"{{\"avatar_id\":\"0x{}\",\"attributes\":[\
+			{{\"code\":10,\"value\":\"0x081322\"}},\
+			{{\"code\":11,\"value\":\"0x02010000\"}},\
+			{{\"code\":12,\"value\":\"0x01\"}},\
+			{{\"code\":13,\"value\":\"0x02\"}}]}}",
+			"ab".repeat(32)
+		);
+		assert_eq!(AAvatars::render_metadata(&avatar_id, &avatar), expected.into_bytes());
+	}
+
+	#[test]
+	fn parse_ipfs_hash_works() {
+		assert_eq!(AAvatars::parse_ipfs_hash(IPFS_RESPONSE), Some(&b"QmAvatar"[..]));
+		assert_eq!(AAvatars::parse_ipfs_hash(br#"{"Name":"avatar.json","Hash":""}"#), None);
+		assert_eq!(AAvatars::parse_ipfs_hash(br#"{"Name":"avatar.json"}"#), None);
+		assert_eq!(AAvatars::parse_ipfs_hash(b"invalid"), None);
+	}
+}
+
+mod migration {
+	use super::*;
+	use crate::migration::{
+		v3::{self, MigrateToV3},
+		v4,
+		v5::{MaxAvatarsPerPlayer, MigrateToV5, Owners},
+	};
+	use frame_support::{
+		traits::{OnRuntimeUpgrade, StorageVersion},
+		weights::{constants::RocksDbWeight, Weight},
+	};
+	use sp_runtime::traits::Get;
+
+	#[test]
+	fn migrate_owners_should_convert_owners_over_several_blocks() {
 		ExtBuilder::default().build().execute_with(|| {
-			let avatar_id = create_avatars(1, ALICE, 1)[0];
-			assert_noop!(
-				AAvatars::unprepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id),
-				Error::<Test>::NotPrepared
-			);
+			// steps are bounded by their weight, which is zero by default
+			MockDbWeight::set(RocksDbWeight::get());
+			StorageVersion::new(4).put::<AAvatars>();
+			for account in [ALICE, BOB, CHARLIE] {
+				let avatar_ids = (0..3)
+					.map(|_| {
+						let avatar_id = H256::random();
+						Avatars::<Test>::insert(avatar_id, (account, Avatar::default()));
+						avatar_id
+					})
+					.collect::<Vec<_>>();
+				Owners::<Test>::insert(account, BoundedVec::try_from(avatar_ids).unwrap());
+			}
+
+			// transferred before its previous owner is converted
+			let transferred_avatar_id = Owners::<Test>::get(ALICE)[0];
+			Avatars::<Test>::mutate(transferred_avatar_id, |maybe_avatar| {
+				maybe_avatar.as_mut().unwrap().0 = DAVE;
+			});
+			AAvatars::add_ownership(&DAVE, &transferred_avatar_id);
+
+			MigrateToV5::<Test>::on_runtime_upgrade();
+			assert!(AAvatars::ongoing_migration().is_some());
+
+			// a step only converts as many accounts as fit in its limit
+			let max_avatars = MaxAvatarsPerPlayer::get() as u64;
+			let limit = RocksDbWeight::get().reads_writes(2, 2) +
+				RocksDbWeight::get().reads_writes(1 + 2 * max_avatars, 1 + 2 * max_avatars);
+			for remaining_accounts in [2, 1, 0] {
+				crate::migration::step::<Test>(limit);
+				assert_eq!(Owners::<Test>::iter().count(), remaining_accounts);
+				assert_eq!(StorageVersion::get::<AAvatars>(), 4);
+				assert!(AAvatars::ongoing_migration().is_some());
+			}
+			crate::migration::step::<Test>(limit);
+			assert_eq!(StorageVersion::get::<AAvatars>(), 5);
+			assert_eq!(AAvatars::ongoing_migration(), None);
+
+			assert_eq!(AAvatars::owned_avatar_count(ALICE), 2);
+			assert!(!AAvatars::owners(&ALICE).contains(&transferred_avatar_id));
+			assert_eq!(AAvatars::owners(&DAVE), vec![transferred_avatar_id]);
+			for account in [BOB, CHARLIE] {
+				assert_eq!(AAvatars::owned_avatar_count(account), 3);
+				assert!(AAvatars::owners(&account).into_iter().all(|avatar_id| AAvatars::avatars(
+					avatar_id
+				)
+				.unwrap()
+				.0 == account));
+			}
+
+			// nothing is left to convert
+			assert_eq!(crate::migration::step::<Test>(limit), RocksDbWeight::get().reads(1));
 		});
 	}
 
 	#[test]
-	fn prepare_ipfs_works() {
+	fn migrations_should_follow_each_other_up_to_the_current_version() {
 		ExtBuilder::default().build().execute_with(|| {
-			let avatar_id = create_avatars(1, ALICE, 1)[0];
-			assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), ALICE));
-			assert_ok!(AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
-			ServiceAccount::<Test>::put(BOB);
+			StorageVersion::new(2).put::<AAvatars>();
+			for (account, storage_tier) in
+				[(ALICE, v3::OldStorageTier::One), (BOB, v3::OldStorageTier::Four)]
+			{
+				let old_account =
+					v3::OldAccountInfo { free_mints: 1, storage_tier, stats: Stats::default() };
+				v3::Accounts::<Test>::insert(account, old_account);
+			}
+			let GlobalConfig { mint, forge, transfer, trade, account, .. } =
+				AAvatars::global_configs();
+			frame_support::storage::unhashed::put(
+				&GlobalConfigs::<Test>::hashed_key(),
+				&v4::OldGlobalConfig::<Test> { mint, forge, transfer, trade, account },
+			);
 
-			let ipfs_url = b"ipfs://{CID}/{optional path to resource}".to_vec();
-			let ipfs_url = IpfsUrl::try_from(ipfs_url).unwrap();
-			assert_ok!(AAvatars::prepare_ipfs(
-				RuntimeOrigin::signed(BOB),
-				avatar_id,
-				ipfs_url.clone()
-			));
-			assert_eq!(AAvatars::preparation(avatar_id).unwrap(), ipfs_url);
-			System::assert_last_event(mock::RuntimeEvent::AAvatars(
-				crate::Event::PreparedIpfsUrl { url: ipfs_url },
-			));
+			MigrateToV3::<Test>::on_runtime_upgrade();
+			for version in [3, 4, 5] {
+				assert!(AAvatars::ongoing_migration().is_some());
+				crate::migration::step::<Test>(Weight::MAX);
+				assert_eq!(StorageVersion::get::<AAvatars>(), version);
+			}
+			assert_eq!(AAvatars::ongoing_migration(), None);
 
-			let ipfs_url = b"ipfs://123".to_vec();
-			let ipfs_url = IpfsUrl::try_from(ipfs_url).unwrap();
-			assert_ok!(AAvatars::prepare_ipfs(
-				RuntimeOrigin::signed(BOB),
-				avatar_id,
-				ipfs_url.clone()
-			));
-			assert_eq!(AAvatars::preparation(avatar_id).unwrap(), ipfs_url);
-			System::assert_last_event(mock::RuntimeEvent::AAvatars(
-				crate::Event::PreparedIpfsUrl { url: ipfs_url },
-			));
+			assert_eq!(AAvatars::accounts(ALICE).storage_tier, StorageTier::One);
+			assert_eq!(AAvatars::accounts(BOB).storage_tier, StorageTier::Four);
+			assert!(AAvatars::global_configs().nft_transfer.open);
+			assert_eq!(AAvatars::global_configs().nft_transfer.prepare_fee, 5_000_000_000_000);
 		});
 	}
 
 	#[test]
-	fn prepare_ipfs_rejects_empty_url() {
+	fn calls_should_be_paused_during_migration() {
+		ExtBuilder::default()
+			.organizer(ALICE)
+			.balances(&[(BOB, MockBalance::max_value())])
+			.build()
+			.execute_with(|| {
+				OngoingMigration::<Test>::put(crate::migration::MigrationCursor::default());
+				assert_noop!(
+					AAvatars::set_season(RuntimeOrigin::signed(ALICE), 1, Season::default()),
+					Error::<Test>::MigrationInProgress
+				);
+				assert_noop!(
+					AAvatars::mint(
+						RuntimeOrigin::signed(BOB),
+						MintOption { count: MintPackSize::One, mint_type: MintType::Normal }
+					),
+					Error::<Test>::MigrationInProgress
+				);
+
+				// resumed once the migration completes
+				OngoingMigration::<Test>::kill();
+				assert_ok!(AAvatars::set_season(
+					RuntimeOrigin::signed(ALICE),
+					1,
+					Season::default()
+				));
+			});
+	}
+
+	#[test]
+	fn owners_should_include_unconverted_avatars_during_migration() {
 		ExtBuilder::default().build().execute_with(|| {
-			let avatar_id = create_avatars(1, ALICE, 1)[0];
-			assert_ok!(AAvatars::set_service_account(RuntimeOrigin::root(), ALICE));
-			assert_ok!(AAvatars::prepare_avatar(RuntimeOrigin::signed(ALICE), avatar_id));
-			ServiceAccount::<Test>::put(BOB);
+			StorageVersion::new(4).put::<AAvatars>();
+			let avatar_ids = (0..3)
+				.map(|_| {
+					let avatar_id = H256::random();
+					Avatars::<Test>::insert(avatar_id, (ALICE, Avatar::default()));
+					avatar_id
+				})
+				.collect::<Vec<_>>();
+			Owners::<Test>::insert(ALICE, BoundedVec::try_from(avatar_ids.clone()).unwrap());
+			Avatars::<Test>::mutate(avatar_ids[2], |maybe_avatar| {
+				maybe_avatar.as_mut().unwrap().0 = BOB;
+			});
+			AAvatars::add_ownership(&BOB, &avatar_ids[2]);
 
-			assert_noop!(
-				AAvatars::prepare_ipfs(RuntimeOrigin::signed(BOB), avatar_id, IpfsUrl::default()),
-				Error::<Test>::EmptyIpfsUrl
-			);
+			MigrateToV5::<Test>::on_runtime_upgrade();
+			assert_eq!(AAvatars::owners(&ALICE), avatar_ids[..2].to_vec());
+			assert_eq!(AAvatars::owners(&BOB), vec![avatar_ids[2]]);
+			assert_eq!(AAvatars::avatars_of(&ALICE).len(), 2);
 		});
 	}
 }
@@ -0,0 +1,94 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use frame_support::{
+	pallet_prelude::*,
+	traits::tokens::fungibles::{Create, Inspect, Mutate, Transfer},
+};
+use sp_std::marker::PhantomData;
+
+/// Trait to define the payment of fees in assets other than the native currency.
+pub trait AssetPayment<Account, Balance> {
+	type AssetId: Member + Parameter + Copy + MaxEncodedLen;
+
+	/// Whether fees can be paid in assets at all.
+	fn is_available() -> bool;
+
+	/// Transfers `amount` of the asset `asset_id` from `source` to `dest`.
+	fn transfer(
+		asset_id: Self::AssetId,
+		source: &Account,
+		dest: &Account,
+		amount: Balance,
+	) -> DispatchResult;
+
+	/// Creates the asset `asset_id` and mints `amount` of it into `owner`.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_asset(asset_id: Self::AssetId, owner: &Account, amount: Balance) -> DispatchResult;
+}
+
+impl<Account, Balance> AssetPayment<Account, Balance> for () {
+	type AssetId = u32;
+
+	fn is_available() -> bool {
+		false
+	}
+
+	fn transfer(_: Self::AssetId, _: &Account, _: &Account, _: Balance) -> DispatchResult {
+		Err(DispatchError::Other("No asset payment available"))
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_asset(_: Self::AssetId, _: &Account, _: Balance) -> DispatchResult {
+		Err(DispatchError::Other("No asset payment available"))
+	}
+}
+
+/// Pays fees in the assets of a `fungibles` implementation, such as `pallet_assets`.
+pub struct FungiblesPayment<Fungibles>(PhantomData<Fungibles>);
+
+impl<Account, Fungibles> AssetPayment<Account, <Fungibles as Inspect<Account>>::Balance>
+	for FungiblesPayment<Fungibles>
+where
+	Account: Clone,
+	Fungibles: Create<Account> + Mutate<Account> + Transfer<Account>,
+	<Fungibles as Inspect<Account>>::AssetId: Member + Parameter + Copy + MaxEncodedLen,
+{
+	type AssetId = <Fungibles as Inspect<Account>>::AssetId;
+
+	fn is_available() -> bool {
+		true
+	}
+
+	fn transfer(
+		asset_id: Self::AssetId,
+		source: &Account,
+		dest: &Account,
+		amount: <Fungibles as Inspect<Account>>::Balance,
+	) -> DispatchResult {
+		Fungibles::transfer(asset_id, source, dest, amount, false).map(|_| ())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_asset(
+		asset_id: Self::AssetId,
+		owner: &Account,
+		amount: <Fungibles as Inspect<Account>>::Balance,
+	) -> DispatchResult {
+		Fungibles::create(asset_id, owner.clone(), true, 1_u32.into())?;
+		Fungibles::mint_into(asset_id, owner, amount)
+	}
+}
@@ -16,8 +16,12 @@
 
 use super::{MintCount, SeasonId};
 use frame_support::pallet_prelude::*;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_runtime::{traits::Get, BoundedBTreeSet};
 
+/// The quota of avatars a player can own. The discriminants give the quota of each tier, except
+/// for `Max`, which lifts the quota altogether.
 #[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, PartialEq)]
 pub enum StorageTier {
 	One = 25,
@@ -45,12 +49,21 @@ impl StorageTier {
 			Self::Max => Self::Max,
 		}
 	}
+
+	/// The number of avatars a player of the tier can own, or `None` if unlimited.
+	pub fn quota(&self) -> Option<u32> {
+		match self {
+			Self::Max => None,
+			tier => Some(tier.clone() as u32),
+		}
+	}
 }
 
-pub struct MaxAvatarsPerPlayer;
-impl Get<u32> for MaxAvatarsPerPlayer {
+/// Upper bound on the number of forges in a batch.
+pub struct MaxForgesPerBatch;
+impl Get<u32> for MaxForgesPerBatch {
 	fn get() -> u32 {
-		StorageTier::Max as u32
+		100
 	}
 }
 
@@ -90,7 +103,8 @@ pub struct AccountInfo<BlockNumber> {
 	pub stats: Stats<BlockNumber>,
 }
 
-#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Default)]
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct SeasonInfo {
 	pub minted: Stat,
 	pub forged: Stat,
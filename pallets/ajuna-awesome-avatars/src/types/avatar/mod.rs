@@ -68,7 +68,6 @@ impl Avatar {
 						matched_components.extend(matching_components.iter());
 					}
 				}
-				self.souls.saturating_accrue(other.souls);
 				Ok((matched_components, matches))
 			},
 		)
@@ -149,7 +148,11 @@ impl Avatar {
 				period_multiplier
 	}
 
-	fn forge_multiplier<T: Config>(&self, season: &SeasonOf<T>, now: &T::BlockNumber) -> u8 {
+	pub(crate) fn forge_multiplier<T: Config>(
+		&self,
+		season: &SeasonOf<T>,
+		now: &T::BlockNumber,
+	) -> u8 {
 		let mut current_period = season.current_period(now);
 		let mut last_variation = self.last_variation() as u16;
 
@@ -189,6 +192,10 @@ mod test {
 			self.dna = Dna::try_from(dna.to_vec()).unwrap();
 			self
 		}
+		pub(crate) fn souls(mut self, souls: SoulCount) -> Self {
+			self.souls = souls;
+			self
+		}
 	}
 
 	#[test]
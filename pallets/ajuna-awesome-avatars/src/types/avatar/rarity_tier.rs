@@ -16,11 +16,14 @@
 
 use crate::*;
 use codec::alloc::string::ToString;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_std::{fmt, prelude::*};
 
 #[derive(
 	Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, Clone, PartialEq, Eq, PartialOrd, Ord,
 )]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum RarityTier {
 	Common = 0,
 	Uncommon = 1,
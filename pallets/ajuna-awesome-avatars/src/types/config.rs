@@ -15,6 +15,9 @@
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
 use frame_support::pallet_prelude::*;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::traits::{AtLeast32BitUnsigned, One, Zero};
 
 /// Number of avatars to be minted.
 #[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, Debug, Eq, PartialEq)]
@@ -38,6 +41,7 @@ impl MintPackSize {
 
 /// Minting fee per pack of avatars.
 #[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct MintFees<Balance> {
 	pub one: Balance,
 	pub three: Balance,
@@ -79,7 +83,17 @@ pub struct MintOption {
 
 pub type MintCount = u16;
 
+/// How an airdrop changes the free mints of its recipients.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AirdropMode {
+	/// Add the airdropped free mints to those the recipient already has.
+	Add,
+	/// Replace the free mints of the recipient with the airdropped ones.
+	Overwrite,
+}
+
 #[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct MintConfig<Balance, BlockNumber> {
 	pub open: bool,
 	pub fees: MintFees<Balance>,
@@ -88,11 +102,13 @@ pub struct MintConfig<Balance, BlockNumber> {
 }
 
 #[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct ForgeConfig {
 	pub open: bool,
 }
 
 #[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct TransferConfig<Balance> {
 	pub open: bool,
 	pub free_mint_transfer_fee: MintCount,
@@ -101,6 +117,7 @@ pub struct TransferConfig<Balance> {
 }
 
 #[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct TradeConfig<Balance> {
 	pub open: bool,
 	pub min_fee: Balance,
@@ -108,17 +125,20 @@ pub struct TradeConfig<Balance> {
 }
 
 #[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct AccountConfig<Balance> {
 	pub storage_upgrade_fee: Balance,
 }
 
 #[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct NftTransferConfig<Balance> {
 	pub open: bool,
 	pub prepare_fee: Balance,
 }
 
 #[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct GlobalConfig<Balance, BlockNumber> {
 	pub mint: MintConfig<Balance, BlockNumber>,
 	pub forge: ForgeConfig,
@@ -127,3 +147,124 @@ pub struct GlobalConfig<Balance, BlockNumber> {
 	pub account: AccountConfig<Balance>,
 	pub nft_transfer: NftTransferConfig<Balance>,
 }
+
+/// Rate at which fees are converted into a payment asset, as the amount of the asset worth
+/// `native` units of the native currency.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, Debug, PartialEq)]
+pub struct ConversionRate<Balance> {
+	pub native: Balance,
+	pub asset: Balance,
+}
+
+impl<Balance: AtLeast32BitUnsigned + Copy> ConversionRate<Balance> {
+	pub(crate) fn is_valid(&self) -> bool {
+		!self.native.is_zero() && !self.asset.is_zero()
+	}
+
+	/// Converts an amount of the native currency into the asset, rounding up so that no
+	/// non-zero amount converts to zero.
+	pub fn convert(&self, amount: Balance) -> Balance {
+		if self.native.is_zero() {
+			return Zero::zero()
+		}
+		let scaled = amount.saturating_mul(self.asset);
+		let converted = scaled / self.native;
+		if (scaled % self.native).is_zero() {
+			converted
+		} else {
+			converted.saturating_add(One::one())
+		}
+	}
+}
+
+pub type ConfigPatchId = u32;
+
+/// Upper bound on the number of config patches scheduled for the same block.
+pub struct MaxConfigPatchesPerBlock;
+impl Get<u32> for MaxConfigPatchesPerBlock {
+	fn get() -> u32 {
+		8
+	}
+}
+
+/// Partial update of the global configuration, changing only the fields that are set.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, Default, PartialEq)]
+pub struct GlobalConfigPatch<Balance, BlockNumber> {
+	pub mint_open: Option<bool>,
+	pub mint_fees: Option<MintFees<Balance>>,
+	pub mint_cooldown: Option<BlockNumber>,
+	pub free_mint_fee_multiplier: Option<MintCount>,
+	pub forge_open: Option<bool>,
+	pub transfer_open: Option<bool>,
+	pub free_mint_transfer_fee: Option<MintCount>,
+	pub min_free_mint_transfer: Option<MintCount>,
+	pub avatar_transfer_fee: Option<Balance>,
+	pub trade_open: Option<bool>,
+	pub trade_min_fee: Option<Balance>,
+	pub trade_percent_fee: Option<u8>,
+	pub storage_upgrade_fee: Option<Balance>,
+	pub nft_transfer_open: Option<bool>,
+	pub prepare_fee: Option<Balance>,
+}
+
+impl<Balance: Copy, BlockNumber> GlobalConfigPatch<Balance, BlockNumber> {
+	/// The fees the patch sets, which are subject to the same minimum as in a full update.
+	pub(crate) fn fees(&self) -> impl Iterator<Item = Balance> {
+		let mint_fees = self.mint_fees.map(|fees| [fees.one, fees.three, fees.six]);
+		mint_fees
+			.into_iter()
+			.flatten()
+			.chain(self.avatar_transfer_fee)
+			.chain(self.trade_min_fee)
+			.chain(self.storage_upgrade_fee)
+	}
+
+	/// Applies the fields that are set to `config`.
+	pub(crate) fn apply(self, config: &mut GlobalConfig<Balance, BlockNumber>) {
+		if let Some(mint_open) = self.mint_open {
+			config.mint.open = mint_open;
+		}
+		if let Some(mint_fees) = self.mint_fees {
+			config.mint.fees = mint_fees;
+		}
+		if let Some(mint_cooldown) = self.mint_cooldown {
+			config.mint.cooldown = mint_cooldown;
+		}
+		if let Some(free_mint_fee_multiplier) = self.free_mint_fee_multiplier {
+			config.mint.free_mint_fee_multiplier = free_mint_fee_multiplier;
+		}
+		if let Some(forge_open) = self.forge_open {
+			config.forge.open = forge_open;
+		}
+		if let Some(transfer_open) = self.transfer_open {
+			config.transfer.open = transfer_open;
+		}
+		if let Some(free_mint_transfer_fee) = self.free_mint_transfer_fee {
+			config.transfer.free_mint_transfer_fee = free_mint_transfer_fee;
+		}
+		if let Some(min_free_mint_transfer) = self.min_free_mint_transfer {
+			config.transfer.min_free_mint_transfer = min_free_mint_transfer;
+		}
+		if let Some(avatar_transfer_fee) = self.avatar_transfer_fee {
+			config.transfer.avatar_transfer_fee = avatar_transfer_fee;
+		}
+		if let Some(trade_open) = self.trade_open {
+			config.trade.open = trade_open;
+		}
+		if let Some(trade_min_fee) = self.trade_min_fee {
+			config.trade.min_fee = trade_min_fee;
+		}
+		if let Some(trade_percent_fee) = self.trade_percent_fee {
+			config.trade.percent_fee = trade_percent_fee;
+		}
+		if let Some(storage_upgrade_fee) = self.storage_upgrade_fee {
+			config.account.storage_upgrade_fee = storage_upgrade_fee;
+		}
+		if let Some(nft_transfer_open) = self.nft_transfer_open {
+			config.nft_transfer.open = nft_transfer_open;
+		}
+		if let Some(prepare_fee) = self.prepare_fee {
+			config.nft_transfer.prepare_fee = prepare_fee;
+		}
+	}
+}
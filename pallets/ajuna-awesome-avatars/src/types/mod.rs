@@ -17,9 +17,15 @@
 mod account;
 mod avatar;
 mod config;
+mod query;
+mod rental;
 mod season;
+mod trade;
 
 pub use account::*;
 pub use avatar::*;
 pub use config::*;
+pub use query::*;
+pub use rental::*;
 pub use season::*;
+pub use trade::*;
@@ -0,0 +1,91 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::types::{Avatar, Dna, Force, RarityTier, Season, SeasonId, SeasonStatus, SoulCount};
+use frame_support::pallet_prelude::*;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_std::vec::Vec;
+
+/// An avatar as exposed by the runtime API, with its DNA already decoded.
+#[derive(Encode, Decode, TypeInfo, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AvatarInfo<AvatarId, Balance> {
+	pub avatar_id: AvatarId,
+	pub season_id: SeasonId,
+	pub dna: Dna,
+	pub souls: SoulCount,
+	pub rarity: RarityTier,
+	pub force: Force,
+	pub price: Option<Balance>,
+	pub locked: bool,
+}
+
+impl<AvatarId, Balance> AvatarInfo<AvatarId, Balance> {
+	pub(crate) fn new(
+		avatar_id: AvatarId,
+		avatar: Avatar,
+		price: Option<Balance>,
+		locked: bool,
+	) -> Self {
+		let rarity = RarityTier::try_from(avatar.min_tier()).unwrap_or_default();
+		let force = Force::try_from(avatar.last_variation()).unwrap_or_default();
+		Self {
+			avatar_id,
+			season_id: avatar.season_id,
+			dna: avatar.dna,
+			souls: avatar.souls,
+			rarity,
+			force,
+			price,
+			locked,
+		}
+	}
+}
+
+/// The season currently in use along with its status.
+#[derive(Encode, Decode, TypeInfo, Debug, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct CurrentSeason<BlockNumber> {
+	pub season_id: SeasonId,
+	pub season: Season<BlockNumber>,
+	pub status: SeasonStatus,
+}
+
+/// An avatar listed for trade.
+#[derive(Encode, Decode, TypeInfo, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ListedAvatar<AccountId, AvatarId, Balance> {
+	pub avatar_id: AvatarId,
+	pub owner: AccountId,
+	pub price: Balance,
+}
+
+/// The outcome a forge would have if it were submitted now.
+#[derive(Encode, Decode, TypeInfo, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ForgePreview {
+	/// Indexes of the leader's components that can be upgraded by the sacrifices.
+	pub matched_components: Vec<u8>,
+	/// Number of sacrifices matching the leader.
+	pub matches: u8,
+	/// Number of rolls, one for each sacrifice.
+	pub rolls: u8,
+	/// Probability in percent of each roll to upgrade a component.
+	pub probability: u8,
+	/// Divisor applied to the probability for the current period.
+	pub period_multiplier: u8,
+}
@@ -16,10 +16,16 @@
 
 use crate::*;
 use frame_support::pallet_prelude::*;
-use sp_runtime::traits::{AtLeast32Bit, UniqueSaturatedInto, Zero};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::{
+	traits::{AtLeast32Bit, UniqueSaturatedInto, Zero},
+	BoundedBTreeSet,
+};
 use sp_std::vec::Vec;
 
 #[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct SeasonStatus {
 	pub early: bool,
 	pub active: bool,
@@ -36,6 +42,7 @@ pub type RarityPercent = u8;
 pub type SacrificeCount = u8;
 
 #[derive(Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, Clone, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct Season<BlockNumber> {
 	pub name: BoundedVec<u8, ConstU32<100>>,
 	pub description: BoundedVec<u8, ConstU32<1_000>>,
@@ -153,6 +160,102 @@ impl<BlockNumber: AtLeast32Bit + Copy> Season<BlockNumber> {
 	}
 }
 
+/// Number of players ranked on each season leaderboard.
+pub struct MaxLeaderboardSize;
+impl Get<u32> for MaxLeaderboardSize {
+	fn get() -> u32 {
+		10
+	}
+}
+
+pub type RewardShare = u8;
+
+/// Shares of a season's treasury awarded to the players ranked on its leaderboards, by rank.
+///
+/// Whatever isn't awarded remains claimable by the season's treasurer.
+#[derive(Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, Clone, Default, PartialEq)]
+pub struct SeasonRewards {
+	/// Percentages awarded to the players that forged the most.
+	pub forge_shares: BoundedVec<RewardShare, MaxLeaderboardSize>,
+	/// Percentages awarded to the players that reached a max tier avatar first.
+	pub max_tier_shares: BoundedVec<RewardShare, MaxLeaderboardSize>,
+}
+
+impl SeasonRewards {
+	pub(crate) fn validate<T: Config>(&self) -> DispatchResult {
+		let total = self
+			.forge_shares
+			.iter()
+			.chain(self.max_tier_shares.iter())
+			.fold(0_u16, |total, share| total.saturating_add(*share as u16));
+		ensure!(total <= MAX_PERCENTAGE as u16, Error::<T>::IncorrectRewardShares);
+		Ok(())
+	}
+}
+
+/// Upper bound on the number of earlier seasons whose avatars a season takes in.
+pub struct MaxCarryOverSeasons;
+impl Get<u32> for MaxCarryOverSeasons {
+	fn get() -> u32 {
+		10
+	}
+}
+
+/// Upper bound on the number of accounts added to or removed from an allowlist at once.
+pub struct MaxAllowlistBatch;
+impl Get<u32> for MaxAllowlistBatch {
+	fn get() -> u32 {
+		100
+	}
+}
+
+/// Rules under which the avatars of earlier seasons take part in a season.
+///
+/// Avatars of seasons that aren't listed can't be used in the season.
+#[derive(Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, Clone, Default, PartialEq)]
+pub struct CarryOver<Balance> {
+	/// Earlier seasons whose avatars can be sacrificed when forging.
+	pub sacrifice_seasons: BoundedBTreeSet<SeasonId, MaxCarryOverSeasons>,
+	/// Earlier seasons whose avatars can ascend into the season.
+	pub ascension_seasons: BoundedBTreeSet<SeasonId, MaxCarryOverSeasons>,
+	/// The fee to ascend an avatar into the season.
+	pub ascension_fee: Balance,
+}
+
+impl<Balance> CarryOver<Balance> {
+	pub(crate) fn validate<T: Config>(&self, season_id: &SeasonId) -> DispatchResult {
+		ensure!(
+			self.sacrifice_seasons
+				.iter()
+				.chain(self.ascension_seasons.iter())
+				.all(|carried_over| carried_over < season_id),
+			Error::<T>::IncorrectCarryOverSeason
+		);
+		Ok(())
+	}
+}
+
+/// Prices in souls of what players can spend their souls on during a season. Purchases without a
+/// price aren't available.
+#[derive(Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, Clone, Default, PartialEq)]
+pub struct SoulExchange {
+	/// Souls to reroll a single DNA component of an avatar.
+	pub reroll_price: Option<SoulCount>,
+	/// Souls to buy a single free mint.
+	pub free_mint_price: Option<SoulCount>,
+	/// Souls to buy a forge boost.
+	pub forge_boost_price: Option<SoulCount>,
+	/// Percentage points a forge boost adds to the probability of the next forge.
+	pub forge_boost: RarityPercent,
+}
+
+impl SoulExchange {
+	pub(crate) fn validate<T: Config>(&self) -> DispatchResult {
+		ensure!(self.forge_boost <= MAX_PERCENTAGE, Error::<T>::IncorrectForgeBoost);
+		Ok(())
+	}
+}
+
 #[cfg(test)]
 mod test {
 	use super::*;
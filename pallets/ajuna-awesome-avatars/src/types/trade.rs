@@ -0,0 +1,69 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use frame_support::pallet_prelude::*;
+use sp_runtime::traits::Saturating;
+
+/// A bid placed in an auction, along with the trade fee fixed when it was placed.
+///
+/// The fee is reserved along with the bid, unless the bidder pays fees in an asset, in which case
+/// the treasury holds `fee` of `fee_asset` until the auction is settled.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, PartialEq)]
+pub struct Bid<AccountId, Balance, AssetId> {
+	pub bidder: AccountId,
+	pub amount: Balance,
+	pub fee: Balance,
+	pub fee_asset: Option<AssetId>,
+}
+
+impl<AccountId, Balance: Saturating + Copy, AssetId> Bid<AccountId, Balance, AssetId> {
+	/// The balance reserved from the bidder to cover the bid and its fee.
+	pub fn reserved(&self) -> Balance {
+		match self.fee_asset {
+			Some(_) => self.amount,
+			None => self.amount.saturating_add(self.fee),
+		}
+	}
+}
+
+/// An English auction of an avatar, won by the highest bid placed before its end.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, PartialEq)]
+pub struct Auction<AccountId, Balance, BlockNumber, AssetId> {
+	pub seller: AccountId,
+	pub reserve_price: Balance,
+	pub end: BlockNumber,
+	pub highest_bid: Option<Bid<AccountId, Balance, AssetId>>,
+}
+
+/// An offer to buy an avatar, along with the trade fee fixed when it was made.
+///
+/// Like for bids, a fee paid in an asset is held by the treasury rather than reserved.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, PartialEq)]
+pub struct Offer<Balance, AssetId> {
+	pub amount: Balance,
+	pub fee: Balance,
+	pub fee_asset: Option<AssetId>,
+}
+
+impl<Balance: Saturating + Copy, AssetId> Offer<Balance, AssetId> {
+	/// The balance reserved from the buyer to cover the offer and its fee.
+	pub fn reserved(&self) -> Balance {
+		match self.fee_asset {
+			Some(_) => self.amount,
+			None => self.amount.saturating_add(self.fee),
+		}
+	}
+}
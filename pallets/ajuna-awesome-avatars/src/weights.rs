@@ -20,6 +20,10 @@
 //! DATE: 2023-04-05, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! HOSTNAME: `weight-calculation`, CPU: `DO-Regular`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! Entries marked `Estimate, not benchmarked yet.` were written by hand for extrinsics added
+//! after this file was generated and are NOT benchmark output. Re-run the command below to
+//! replace them with measured weights.
 
 // Executed Command:
 // ./target/release/bajun-para
@@ -45,15 +49,15 @@ use sp_std::marker::PhantomData;
 
 /// Weight functions needed for pallet_ajuna_awesome_avatars.
 pub trait WeightInfo {
-	fn mint_free(n: u32, ) -> Weight;
-	fn mint_normal(n: u32, ) -> Weight;
-	fn forge(n: u32, ) -> Weight;
-	fn transfer_avatar_normal(n: u32, ) -> Weight;
-	fn transfer_avatar_organizer(n: u32, ) -> Weight;
+	fn mint_free() -> Weight;
+	fn mint_normal() -> Weight;
+	fn forge(o: u32, ) -> Weight;
+	fn transfer_avatar_normal() -> Weight;
+	fn transfer_avatar_organizer() -> Weight;
 	fn transfer_free_mints() -> Weight;
 	fn set_price() -> Weight;
 	fn remove_price() -> Weight;
-	fn buy(n: u32, ) -> Weight;
+	fn buy() -> Weight;
 	fn upgrade_storage() -> Weight;
 	fn set_organizer() -> Weight;
 	fn set_collection_id() -> Weight;
@@ -62,13 +66,47 @@ pub trait WeightInfo {
 	fn set_season() -> Weight;
 	fn update_global_config() -> Weight;
 	fn set_free_mints() -> Weight;
-	fn lock_avatar(n: u32, ) -> Weight;
-	fn unlock_avatar(n: u32, ) -> Weight;
+	fn lock_avatar() -> Weight;
+	fn unlock_avatar() -> Weight;
 	fn fix_variation() -> Weight;
 	fn set_service_account() -> Weight;
 	fn prepare_avatar() -> Weight;
 	fn unprepare_avatar() -> Weight;
 	fn prepare_ipfs() -> Weight;
+	fn create_auction() -> Weight;
+	fn bid() -> Weight;
+	fn settle_auction() -> Weight;
+	fn cancel_auction() -> Weight;
+	fn make_offer() -> Weight;
+	fn withdraw_offer() -> Weight;
+	fn accept_offer() -> Weight;
+	fn list_for_rent() -> Weight;
+	fn unlist_from_rent() -> Weight;
+	fn rent() -> Weight;
+	fn set_season_rewards() -> Weight;
+	fn claim_season_reward() -> Weight;
+	fn set_payment_asset() -> Weight;
+	fn set_fee_asset() -> Weight;
+	fn claim_asset_treasury() -> Weight;
+	fn forge_batch(n: u32, s: u32, o: u32, ) -> Weight;
+	fn set_carry_over() -> Weight;
+	fn ascend_avatar() -> Weight;
+	fn set_soul_exchange() -> Weight;
+	fn extract_souls(o: u32, ) -> Weight;
+	fn reroll_component() -> Weight;
+	fn buy_free_mints() -> Weight;
+	fn buy_forge_boost() -> Weight;
+	fn patch_global_config() -> Weight;
+	fn schedule_config_patch() -> Weight;
+	fn cancel_config_patch() -> Weight;
+	fn add_to_allowlist(n: u32, ) -> Weight;
+	fn remove_from_allowlist(n: u32, ) -> Weight;
+	fn set_airdrop_budget() -> Weight;
+	fn airdrop_free_mints(n: u32, ) -> Weight;
+	fn commit_randomness() -> Weight;
+	fn reveal_randomness() -> Weight;
+	fn upload_avatar() -> Weight;
+	fn confirm_upload() -> Weight;
 }
 
 /// Weights for pallet_ajuna_awesome_avatars using the Substrate node and recommended hardware.
@@ -84,12 +122,9 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 	// Storage: AwesomeAvatars Owners (r:1 w:1)
 	// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
 	// Storage: AwesomeAvatars Avatars (r:0 w:6)
-	/// The range of component `n` is `[0, 194]`.
-	fn mint_free(n: u32, ) -> Weight {
+	fn mint_free() -> Weight {
 		// Minimum execution time: 193_893 nanoseconds.
 		Weight::from_ref_time(424_149_063 as u64)
-			// Standard Error: 92_425
-			.saturating_add(Weight::from_ref_time(444_555 as u64).saturating_mul(n as u64))
 			.saturating_add(T::DbWeight::get().reads(9 as u64))
 			.saturating_add(T::DbWeight::get().writes(10 as u64))
 	}
@@ -104,12 +139,9 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 	// Storage: AwesomeAvatars Treasury (r:1 w:1)
 	// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
 	// Storage: AwesomeAvatars Avatars (r:0 w:6)
-	/// The range of component `n` is `[0, 194]`.
-	fn mint_normal(n: u32, ) -> Weight {
+	fn mint_normal() -> Weight {
 		// Minimum execution time: 291_653 nanoseconds.
 		Weight::from_ref_time(518_159_547 as u64)
-			// Standard Error: 116_680
-			.saturating_add(Weight::from_ref_time(1_314_299 as u64).saturating_mul(n as u64))
 			.saturating_add(T::DbWeight::get().reads(11 as u64))
 			.saturating_add(T::DbWeight::get().writes(12 as u64))
 	}
@@ -123,17 +155,22 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 	// Storage: AwesomeAvatars Avatars (r:5 w:5)
 	// Storage: Randomness RandomMaterial (r:1 w:0)
 	// Storage: System Account (r:1 w:1)
-	// Storage: AwesomeAvatars Owners (r:1 w:1)
+	// Storage: AwesomeAvatars OwnedAvatars (r:0 w:1)
+	// Storage: AwesomeAvatars OwnedAvatarCounts (r:1 w:1)
 	// Storage: AwesomeAvatars Accounts (r:1 w:1)
 	// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
-	/// The range of component `n` is `[5, 200]`.
-	fn forge(n: u32, ) -> Weight {
+	// Storage: AwesomeAvatars Offers (r:80 w:80)
+	// Storage: System Account (r:80 w:80)
+	/// The range of component `o` is `[0, 80]`.
+	fn forge(o: u32, ) -> Weight {
 		// Minimum execution time: 175_382 nanoseconds.
 		Weight::from_ref_time(454_407_530 as u64)
-			// Standard Error: 90_949
-			.saturating_add(Weight::from_ref_time(319_643 as u64).saturating_mul(n as u64))
+			// Estimate, not benchmarked yet.
+			.saturating_add(Weight::from_ref_time(15_000_000 as u64).saturating_mul(o as u64))
 			.saturating_add(T::DbWeight::get().reads(29 as u64))
-			.saturating_add(T::DbWeight::get().writes(9 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(o as u64)))
+			.saturating_add(T::DbWeight::get().writes(10 as u64))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(o as u64)))
 	}
 	// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
 	// Storage: AwesomeAvatars Organizer (r:1 w:0)
@@ -145,8 +182,7 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 	// Storage: AwesomeAvatars Treasury (r:1 w:1)
 	// Storage: AwesomeAvatars Owners (r:2 w:2)
 	// Storage: AwesomeAvatars Accounts (r:1 w:0)
-	/// The range of component `n` is `[1, 200]`.
-	fn transfer_avatar_normal(_n: u32, ) -> Weight {
+	fn transfer_avatar_normal() -> Weight {
 		// Minimum execution time: 265_571 nanoseconds.
 		Weight::from_ref_time(685_516_509 as u64)
 			.saturating_add(T::DbWeight::get().reads(12 as u64))
@@ -162,8 +198,7 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 	// Storage: AwesomeAvatars Treasury (r:1 w:1)
 	// Storage: AwesomeAvatars Owners (r:2 w:2)
 	// Storage: AwesomeAvatars Accounts (r:1 w:0)
-	/// The range of component `n` is `[1, 200]`.
-	fn transfer_avatar_organizer(_n: u32, ) -> Weight {
+	fn transfer_avatar_organizer() -> Weight {
 		// Minimum execution time: 293_746 nanoseconds.
 		Weight::from_ref_time(715_105_343 as u64)
 			.saturating_add(T::DbWeight::get().reads(12 as u64))
@@ -204,12 +239,9 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 	// Storage: AwesomeAvatars Treasury (r:1 w:1)
 	// Storage: AwesomeAvatars Owners (r:2 w:2)
 	// Storage: AwesomeAvatars Accounts (r:2 w:2)
-	/// The range of component `n` is `[1, 200]`.
-	fn buy(n: u32, ) -> Weight {
+	fn buy() -> Weight {
 		// Minimum execution time: 300_579 nanoseconds.
 		Weight::from_ref_time(396_334_464 as u64)
-			// Standard Error: 112_303
-			.saturating_add(Weight::from_ref_time(1_868_731 as u64).saturating_mul(n as u64))
 			.saturating_add(T::DbWeight::get().reads(11 as u64))
 			.saturating_add(T::DbWeight::get().writes(10 as u64))
 	}
@@ -296,12 +328,9 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 	// Storage: Nft Attribute (r:6 w:6)
 	// Storage: NftTransfer NftStatuses (r:0 w:1)
 	// Storage: Nft Account (r:0 w:1)
-	/// The range of component `n` is `[1, 200]`.
-	fn lock_avatar(n: u32, ) -> Weight {
+	fn lock_avatar() -> Weight {
 		// Minimum execution time: 340_925 nanoseconds.
 		Weight::from_ref_time(735_001_912 as u64)
-			// Standard Error: 175_980
-			.saturating_add(Weight::from_ref_time(963_703 as u64).saturating_mul(n as u64))
 			.saturating_add(T::DbWeight::get().reads(20 as u64))
 			.saturating_add(T::DbWeight::get().writes(17 as u64))
 	}
@@ -322,12 +351,9 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 	// Storage: Nft ItemPriceOf (r:0 w:1)
 	// Storage: Nft ItemAttributesApprovalsOf (r:0 w:1)
 	// Storage: Nft PendingSwapOf (r:0 w:1)
-	/// The range of component `n` is `[1, 200]`.
-	fn unlock_avatar(n: u32, ) -> Weight {
+	fn unlock_avatar() -> Weight {
 		// Minimum execution time: 341_415 nanoseconds.
 		Weight::from_ref_time(697_884_972 as u64)
-			// Standard Error: 172_147
-			.saturating_add(Weight::from_ref_time(964_454 as u64).saturating_mul(n as u64))
 			.saturating_add(T::DbWeight::get().reads(19 as u64))
 			.saturating_add(T::DbWeight::get().writes(19 as u64))
 	}
@@ -375,6 +401,467 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	// Storage: AwesomeAvatars Avatars (r:1 w:0)
+	// Storage: AwesomeAvatars Trade (r:1 w:0)
+	// Storage: AwesomeAvatars Auctions (r:1 w:1)
+	// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
+	// Storage: AwesomeAvatars Preparation (r:1 w:0)
+	fn create_auction() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(160_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	// Storage: AwesomeAvatars Auctions (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: AwesomeAvatars FeeAssets (r:1 w:0)
+	// Storage: AwesomeAvatars PaymentAssets (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:3 w:3)
+	fn bid() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(210_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(11 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars Auctions (r:1 w:1)
+	// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: AwesomeAvatars Avatars (r:1 w:1)
+	// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	// Storage: AwesomeAvatars OwnedAvatars (r:0 w:2)
+	// Storage: AwesomeAvatars OwnedAvatarCounts (r:2 w:2)
+	// Storage: AwesomeAvatars Trade (r:0 w:1)
+	// Storage: AwesomeAvatars Accounts (r:2 w:2)
+	// Storage: AwesomeAvatars AssetTreasury (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn settle_auction() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(440_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(16 as u64))
+			.saturating_add(T::DbWeight::get().writes(17 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars Auctions (r:1 w:1)
+	fn cancel_auction() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(57_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	// Storage: AwesomeAvatars Avatars (r:1 w:0)
+	// Storage: AwesomeAvatars Offers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: AwesomeAvatars FeeAssets (r:1 w:0)
+	// Storage: AwesomeAvatars PaymentAssets (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:3 w:3)
+	fn make_offer() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(190_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(11 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars Offers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn withdraw_offer() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(130_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	// Storage: AwesomeAvatars Avatars (r:1 w:1)
+	// Storage: AwesomeAvatars Auctions (r:1 w:0)
+	// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
+	// Storage: AwesomeAvatars Preparation (r:1 w:0)
+	// Storage: AwesomeAvatars Offers (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	// Storage: AwesomeAvatars OwnedAvatars (r:0 w:2)
+	// Storage: AwesomeAvatars OwnedAvatarCounts (r:2 w:2)
+	// Storage: AwesomeAvatars Trade (r:0 w:1)
+	// Storage: AwesomeAvatars Accounts (r:2 w:2)
+	// Storage: AwesomeAvatars AssetTreasury (r:1 w:1)
+	fn accept_offer() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(415_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(16 as u64))
+			.saturating_add(T::DbWeight::get().writes(14 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	// Storage: AwesomeAvatars Avatars (r:1 w:0)
+	// Storage: AwesomeAvatars Trade (r:1 w:0)
+	// Storage: AwesomeAvatars Auctions (r:1 w:0)
+	// Storage: AwesomeAvatars RentalListings (r:1 w:1)
+	// Storage: AwesomeAvatars Rentals (r:1 w:0)
+	// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
+	// Storage: AwesomeAvatars Preparation (r:1 w:0)
+	// Storage: AwesomeAvatars CollectionId (r:1 w:0)
+	// Storage: Nft Item (r:1 w:0)
+	fn list_for_rent() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(170_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(11 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars Avatars (r:1 w:0)
+	// Storage: AwesomeAvatars RentalListings (r:1 w:1)
+	// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
+	// Storage: AwesomeAvatars CollectionId (r:1 w:0)
+	// Storage: Nft Item (r:1 w:0)
+	fn unlist_from_rent() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(66_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	// Storage: AwesomeAvatars RentalListings (r:1 w:1)
+	// Storage: AwesomeAvatars Avatars (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	// Storage: AwesomeAvatars RentalExpiries (r:1 w:1)
+	// Storage: AwesomeAvatars Rentals (r:0 w:1)
+	// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
+	// Storage: AwesomeAvatars CollectionId (r:1 w:0)
+	// Storage: Nft Item (r:1 w:0)
+	fn rent() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(240_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(12 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	// Storage: AwesomeAvatars Organizer (r:1 w:0)
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars CurrentSeasonId (r:1 w:0)
+	// Storage: AwesomeAvatars SeasonRewardConfigs (r:0 w:1)
+	fn set_season_rewards() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(64_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars CurrentSeasonId (r:1 w:0)
+	// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	// Storage: AwesomeAvatars UnclaimedRewards (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn claim_season_reward() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(130_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars PaymentAssets (r:0 w:1)
+	fn set_payment_asset() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(57_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars PaymentAssets (r:1 w:0)
+	// Storage: AwesomeAvatars FeeAssets (r:0 w:1)
+	fn set_fee_asset() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(54_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars Treasurer (r:1 w:0)
+	// Storage: AwesomeAvatars CurrentSeasonId (r:1 w:0)
+	// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	// Storage: AwesomeAvatars AssetTreasury (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	fn claim_asset_treasury() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(160_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	// Storage: AwesomeAvatars CurrentSeasonId (r:1 w:0)
+	// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:1)
+	// Storage: AwesomeAvatars CarryOvers (r:1 w:0)
+	// Storage: AwesomeAvatars Trade (r:200 w:0)
+	// Storage: AwesomeAvatars Auctions (r:200 w:0)
+	// Storage: AwesomeAvatars RentalListings (r:200 w:0)
+	// Storage: AwesomeAvatars Rentals (r:200 w:0)
+	// Storage: AwesomeAvatars LockedAvatars (r:200 w:0)
+	// Storage: AwesomeAvatars Preparation (r:200 w:0)
+	// Storage: AwesomeAvatars Avatars (r:200 w:200)
+	// Storage: AwesomeAvatars ForgeBoosts (r:1 w:1)
+	// Storage: Randomness RandomMaterial (r:1 w:0)
+	// Storage: AwesomeAvatars OwnedAvatars (r:150 w:150)
+	// Storage: AwesomeAvatars OwnedAvatarCounts (r:150 w:150)
+	// Storage: AwesomeAvatars Offers (r:170 w:20)
+	// Storage: System Account (r:20 w:20)
+	// Storage: AwesomeAvatars Accounts (r:1 w:1)
+	// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
+	// Storage: AwesomeAvatars ForgeLeaderboards (r:1 w:1)
+	/// The range of component `n` is `[1, 50]`.
+	/// The range of component `s` is `[0, 100]`.
+	/// The range of component `o` is `[0, 20]`.
+	fn forge_batch(n: u32, s: u32, o: u32, ) -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(120_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(110_000_000 as u64).saturating_mul(n as u64))
+			.saturating_add(Weight::from_ref_time(12_000_000 as u64).saturating_mul(s as u64))
+			.saturating_add(Weight::from_ref_time(15_000_000 as u64).saturating_mul(o as u64))
+			.saturating_add(T::DbWeight::get().reads(12 as u64))
+			.saturating_add(T::DbWeight::get().reads((17 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().reads((10 as u64).saturating_mul(s as u64)))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(o as u64)))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+			.saturating_add(T::DbWeight::get().writes((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((3 as u64).saturating_mul(s as u64)))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(o as u64)))
+	}
+	// Storage: AwesomeAvatars Organizer (r:1 w:0)
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars CarryOvers (r:0 w:1)
+	fn set_carry_over() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(55_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	// Storage: AwesomeAvatars CurrentSeasonId (r:1 w:0)
+	// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	// Storage: AwesomeAvatars Avatars (r:1 w:1)
+	// Storage: AwesomeAvatars Trade (r:1 w:0)
+	// Storage: AwesomeAvatars Auctions (r:1 w:0)
+	// Storage: AwesomeAvatars RentalListings (r:1 w:0)
+	// Storage: AwesomeAvatars Rentals (r:1 w:0)
+	// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
+	// Storage: AwesomeAvatars Preparation (r:1 w:0)
+	// Storage: AwesomeAvatars CarryOvers (r:1 w:0)
+	// Storage: AwesomeAvatars FeeAssets (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	// Storage: Randomness RandomMaterial (r:1 w:0)
+	fn ascend_avatar() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(150_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(16 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: AwesomeAvatars Organizer (r:1 w:0)
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars SoulExchanges (r:0 w:1)
+	fn set_soul_exchange() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(54_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars Avatars (r:1 w:1)
+	// Storage: AwesomeAvatars Trade (r:1 w:0)
+	// Storage: AwesomeAvatars Auctions (r:1 w:0)
+	// Storage: AwesomeAvatars RentalListings (r:1 w:0)
+	// Storage: AwesomeAvatars Rentals (r:1 w:0)
+	// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
+	// Storage: AwesomeAvatars Preparation (r:1 w:0)
+	// Storage: AwesomeAvatars Souls (r:1 w:1)
+	// Storage: AwesomeAvatars OwnedAvatars (r:0 w:1)
+	// Storage: AwesomeAvatars OwnedAvatarCounts (r:1 w:1)
+	// Storage: AwesomeAvatars Offers (r:20 w:20)
+	// Storage: System Account (r:20 w:20)
+	/// The range of component `o` is `[0, 20]`.
+	fn extract_souls(o: u32, ) -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(97_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(15_000_000 as u64).saturating_mul(o as u64))
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(o as u64)))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(o as u64)))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars CurrentSeasonId (r:1 w:0)
+	// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	// Storage: AwesomeAvatars Avatars (r:1 w:1)
+	// Storage: AwesomeAvatars Trade (r:1 w:0)
+	// Storage: AwesomeAvatars Auctions (r:1 w:0)
+	// Storage: AwesomeAvatars RentalListings (r:1 w:0)
+	// Storage: AwesomeAvatars Rentals (r:1 w:0)
+	// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
+	// Storage: AwesomeAvatars Preparation (r:1 w:0)
+	// Storage: AwesomeAvatars SoulExchanges (r:1 w:0)
+	// Storage: AwesomeAvatars Souls (r:1 w:1)
+	// Storage: Randomness RandomMaterial (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn reroll_component() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(110_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(15 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars CurrentSeasonId (r:1 w:0)
+	// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	// Storage: AwesomeAvatars SoulExchanges (r:1 w:0)
+	// Storage: AwesomeAvatars Souls (r:1 w:1)
+	// Storage: AwesomeAvatars Accounts (r:1 w:1)
+	fn buy_free_mints() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(68_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars CurrentSeasonId (r:1 w:0)
+	// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	// Storage: AwesomeAvatars SoulExchanges (r:1 w:0)
+	// Storage: AwesomeAvatars Souls (r:1 w:1)
+	// Storage: AwesomeAvatars ForgeBoosts (r:1 w:1)
+	fn buy_forge_boost() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(66_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars GlobalConfigs (r:1 w:1)
+	fn patch_global_config() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(55_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars NextConfigPatchId (r:1 w:1)
+	// Storage: AwesomeAvatars ScheduledConfigPatches (r:1 w:1)
+	fn schedule_config_patch() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(66_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars ScheduledConfigPatches (r:1 w:1)
+	fn cancel_config_patch() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(62_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: AwesomeAvatars Organizer (r:1 w:0)
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars Allowlists (r:0 w:100)
+	/// The range of component `n` is `[1, 100]`.
+	fn add_to_allowlist(n: u32, ) -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(50_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_000_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: AwesomeAvatars Organizer (r:1 w:0)
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars Allowlists (r:0 w:100)
+	/// The range of component `n` is `[1, 100]`.
+	fn remove_from_allowlist(n: u32, ) -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(49_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_900_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: AwesomeAvatars Organizer (r:1 w:0)
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars AirdropBudgets (r:0 w:1)
+	fn set_airdrop_budget() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(47_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: AwesomeAvatars Organizer (r:1 w:0)
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars CurrentSeasonId (r:1 w:0)
+	// Storage: AwesomeAvatars Accounts (r:1000 w:1000)
+	// Storage: AwesomeAvatars AirdropBudgets (r:1 w:1)
+	/// The range of component `n` is `[1, 1000]`.
+	fn airdrop_free_mints(n: u32, ) -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(61_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(8_100_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars RandomnessCommitment (r:0 w:1)
+	fn commit_randomness() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(45_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars ServiceAccount (r:1 w:0)
+	// Storage: AwesomeAvatars RandomnessCommitment (r:1 w:1)
+	// Storage: AwesomeAvatars RevealedRandomness (r:1 w:1)
+	fn reveal_randomness() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(55_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
+	// Storage: AwesomeAvatars CollectionId (r:1 w:0)
+	// Storage: Nft Item (r:1 w:0)
+	// Storage: NftTransfer NftStatuses (r:1 w:1)
+	// Storage: NftTransfer UploadQueue (r:0 w:1)
+	// Storage: AwesomeAvatars RentalListings (r:1 w:0)
+	// Storage: AwesomeAvatars Rentals (r:1 w:0)
+	fn upload_avatar() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(70_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars ServiceAccount (r:1 w:0)
+	// Storage: AwesomeAvatars PendingUploads (r:1 w:1)
+	fn confirm_upload() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(50_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -389,12 +876,9 @@ impl WeightInfo for () {
 	// Storage: AwesomeAvatars Owners (r:1 w:1)
 	// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
 	// Storage: AwesomeAvatars Avatars (r:0 w:6)
-	/// The range of component `n` is `[0, 194]`.
-	fn mint_free(n: u32, ) -> Weight {
+	fn mint_free() -> Weight {
 		// Minimum execution time: 193_893 nanoseconds.
 		Weight::from_ref_time(424_149_063 as u64)
-			// Standard Error: 92_425
-			.saturating_add(Weight::from_ref_time(444_555 as u64).saturating_mul(n as u64))
 			.saturating_add(RocksDbWeight::get().reads(9 as u64))
 			.saturating_add(RocksDbWeight::get().writes(10 as u64))
 	}
@@ -409,12 +893,9 @@ impl WeightInfo for () {
 	// Storage: AwesomeAvatars Treasury (r:1 w:1)
 	// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
 	// Storage: AwesomeAvatars Avatars (r:0 w:6)
-	/// The range of component `n` is `[0, 194]`.
-	fn mint_normal(n: u32, ) -> Weight {
+	fn mint_normal() -> Weight {
 		// Minimum execution time: 291_653 nanoseconds.
 		Weight::from_ref_time(518_159_547 as u64)
-			// Standard Error: 116_680
-			.saturating_add(Weight::from_ref_time(1_314_299 as u64).saturating_mul(n as u64))
 			.saturating_add(RocksDbWeight::get().reads(11 as u64))
 			.saturating_add(RocksDbWeight::get().writes(12 as u64))
 	}
@@ -428,17 +909,22 @@ impl WeightInfo for () {
 	// Storage: AwesomeAvatars Avatars (r:5 w:5)
 	// Storage: Randomness RandomMaterial (r:1 w:0)
 	// Storage: System Account (r:1 w:1)
-	// Storage: AwesomeAvatars Owners (r:1 w:1)
+	// Storage: AwesomeAvatars OwnedAvatars (r:0 w:1)
+	// Storage: AwesomeAvatars OwnedAvatarCounts (r:1 w:1)
 	// Storage: AwesomeAvatars Accounts (r:1 w:1)
 	// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
-	/// The range of component `n` is `[5, 200]`.
-	fn forge(n: u32, ) -> Weight {
+	// Storage: AwesomeAvatars Offers (r:80 w:80)
+	// Storage: System Account (r:80 w:80)
+	/// The range of component `o` is `[0, 80]`.
+	fn forge(o: u32, ) -> Weight {
 		// Minimum execution time: 175_382 nanoseconds.
 		Weight::from_ref_time(454_407_530 as u64)
-			// Standard Error: 90_949
-			.saturating_add(Weight::from_ref_time(319_643 as u64).saturating_mul(n as u64))
+			// Estimate, not benchmarked yet.
+			.saturating_add(Weight::from_ref_time(15_000_000 as u64).saturating_mul(o as u64))
 			.saturating_add(RocksDbWeight::get().reads(29 as u64))
-			.saturating_add(RocksDbWeight::get().writes(9 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(o as u64)))
+			.saturating_add(RocksDbWeight::get().writes(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(o as u64)))
 	}
 	// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
 	// Storage: AwesomeAvatars Organizer (r:1 w:0)
@@ -450,8 +936,7 @@ impl WeightInfo for () {
 	// Storage: AwesomeAvatars Treasury (r:1 w:1)
 	// Storage: AwesomeAvatars Owners (r:2 w:2)
 	// Storage: AwesomeAvatars Accounts (r:1 w:0)
-	/// The range of component `n` is `[1, 200]`.
-	fn transfer_avatar_normal(_n: u32, ) -> Weight {
+	fn transfer_avatar_normal() -> Weight {
 		// Minimum execution time: 265_571 nanoseconds.
 		Weight::from_ref_time(685_516_509 as u64)
 			.saturating_add(RocksDbWeight::get().reads(12 as u64))
@@ -467,8 +952,7 @@ impl WeightInfo for () {
 	// Storage: AwesomeAvatars Treasury (r:1 w:1)
 	// Storage: AwesomeAvatars Owners (r:2 w:2)
 	// Storage: AwesomeAvatars Accounts (r:1 w:0)
-	/// The range of component `n` is `[1, 200]`.
-	fn transfer_avatar_organizer(_n: u32, ) -> Weight {
+	fn transfer_avatar_organizer() -> Weight {
 		// Minimum execution time: 293_746 nanoseconds.
 		Weight::from_ref_time(715_105_343 as u64)
 			.saturating_add(RocksDbWeight::get().reads(12 as u64))
@@ -509,12 +993,9 @@ impl WeightInfo for () {
 	// Storage: AwesomeAvatars Treasury (r:1 w:1)
 	// Storage: AwesomeAvatars Owners (r:2 w:2)
 	// Storage: AwesomeAvatars Accounts (r:2 w:2)
-	/// The range of component `n` is `[1, 200]`.
-	fn buy(n: u32, ) -> Weight {
+	fn buy() -> Weight {
 		// Minimum execution time: 300_579 nanoseconds.
 		Weight::from_ref_time(396_334_464 as u64)
-			// Standard Error: 112_303
-			.saturating_add(Weight::from_ref_time(1_868_731 as u64).saturating_mul(n as u64))
 			.saturating_add(RocksDbWeight::get().reads(11 as u64))
 			.saturating_add(RocksDbWeight::get().writes(10 as u64))
 	}
@@ -601,12 +1082,9 @@ impl WeightInfo for () {
 	// Storage: Nft Attribute (r:6 w:6)
 	// Storage: NftTransfer NftStatuses (r:0 w:1)
 	// Storage: Nft Account (r:0 w:1)
-	/// The range of component `n` is `[1, 200]`.
-	fn lock_avatar(n: u32, ) -> Weight {
+	fn lock_avatar() -> Weight {
 		// Minimum execution time: 340_925 nanoseconds.
 		Weight::from_ref_time(735_001_912 as u64)
-			// Standard Error: 175_980
-			.saturating_add(Weight::from_ref_time(963_703 as u64).saturating_mul(n as u64))
 			.saturating_add(RocksDbWeight::get().reads(20 as u64))
 			.saturating_add(RocksDbWeight::get().writes(17 as u64))
 	}
@@ -627,12 +1105,9 @@ impl WeightInfo for () {
 	// Storage: Nft ItemPriceOf (r:0 w:1)
 	// Storage: Nft ItemAttributesApprovalsOf (r:0 w:1)
 	// Storage: Nft PendingSwapOf (r:0 w:1)
-	/// The range of component `n` is `[1, 200]`.
-	fn unlock_avatar(n: u32, ) -> Weight {
+	fn unlock_avatar() -> Weight {
 		// Minimum execution time: 341_415 nanoseconds.
 		Weight::from_ref_time(697_884_972 as u64)
-			// Standard Error: 172_147
-			.saturating_add(Weight::from_ref_time(964_454 as u64).saturating_mul(n as u64))
 			.saturating_add(RocksDbWeight::get().reads(19 as u64))
 			.saturating_add(RocksDbWeight::get().writes(19 as u64))
 	}
@@ -680,4 +1155,465 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3 as u64))
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	// Storage: AwesomeAvatars Avatars (r:1 w:0)
+	// Storage: AwesomeAvatars Trade (r:1 w:0)
+	// Storage: AwesomeAvatars Auctions (r:1 w:1)
+	// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
+	// Storage: AwesomeAvatars Preparation (r:1 w:0)
+	fn create_auction() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(160_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	// Storage: AwesomeAvatars Auctions (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: AwesomeAvatars FeeAssets (r:1 w:0)
+	// Storage: AwesomeAvatars PaymentAssets (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:3 w:3)
+	fn bid() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(210_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(11 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars Auctions (r:1 w:1)
+	// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: AwesomeAvatars Avatars (r:1 w:1)
+	// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	// Storage: AwesomeAvatars OwnedAvatars (r:0 w:2)
+	// Storage: AwesomeAvatars OwnedAvatarCounts (r:2 w:2)
+	// Storage: AwesomeAvatars Trade (r:0 w:1)
+	// Storage: AwesomeAvatars Accounts (r:2 w:2)
+	// Storage: AwesomeAvatars AssetTreasury (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn settle_auction() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(440_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(16 as u64))
+			.saturating_add(RocksDbWeight::get().writes(17 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars Auctions (r:1 w:1)
+	fn cancel_auction() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(57_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	// Storage: AwesomeAvatars Avatars (r:1 w:0)
+	// Storage: AwesomeAvatars Offers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: AwesomeAvatars FeeAssets (r:1 w:0)
+	// Storage: AwesomeAvatars PaymentAssets (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:3 w:3)
+	fn make_offer() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(190_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(11 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars Offers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn withdraw_offer() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(130_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	// Storage: AwesomeAvatars Avatars (r:1 w:1)
+	// Storage: AwesomeAvatars Auctions (r:1 w:0)
+	// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
+	// Storage: AwesomeAvatars Preparation (r:1 w:0)
+	// Storage: AwesomeAvatars Offers (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	// Storage: AwesomeAvatars OwnedAvatars (r:0 w:2)
+	// Storage: AwesomeAvatars OwnedAvatarCounts (r:2 w:2)
+	// Storage: AwesomeAvatars Trade (r:0 w:1)
+	// Storage: AwesomeAvatars Accounts (r:2 w:2)
+	// Storage: AwesomeAvatars AssetTreasury (r:1 w:1)
+	fn accept_offer() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(415_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(16 as u64))
+			.saturating_add(RocksDbWeight::get().writes(14 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	// Storage: AwesomeAvatars Avatars (r:1 w:0)
+	// Storage: AwesomeAvatars Trade (r:1 w:0)
+	// Storage: AwesomeAvatars Auctions (r:1 w:0)
+	// Storage: AwesomeAvatars RentalListings (r:1 w:1)
+	// Storage: AwesomeAvatars Rentals (r:1 w:0)
+	// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
+	// Storage: AwesomeAvatars Preparation (r:1 w:0)
+	// Storage: AwesomeAvatars CollectionId (r:1 w:0)
+	// Storage: Nft Item (r:1 w:0)
+	fn list_for_rent() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(170_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(11 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars Avatars (r:1 w:0)
+	// Storage: AwesomeAvatars RentalListings (r:1 w:1)
+	// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
+	// Storage: AwesomeAvatars CollectionId (r:1 w:0)
+	// Storage: Nft Item (r:1 w:0)
+	fn unlist_from_rent() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(66_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	// Storage: AwesomeAvatars RentalListings (r:1 w:1)
+	// Storage: AwesomeAvatars Avatars (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	// Storage: AwesomeAvatars RentalExpiries (r:1 w:1)
+	// Storage: AwesomeAvatars Rentals (r:0 w:1)
+	// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
+	// Storage: AwesomeAvatars CollectionId (r:1 w:0)
+	// Storage: Nft Item (r:1 w:0)
+	fn rent() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(240_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(12 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+	}
+	// Storage: AwesomeAvatars Organizer (r:1 w:0)
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars CurrentSeasonId (r:1 w:0)
+	// Storage: AwesomeAvatars SeasonRewardConfigs (r:0 w:1)
+	fn set_season_rewards() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(64_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars CurrentSeasonId (r:1 w:0)
+	// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	// Storage: AwesomeAvatars UnclaimedRewards (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn claim_season_reward() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(130_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars PaymentAssets (r:0 w:1)
+	fn set_payment_asset() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(57_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars PaymentAssets (r:1 w:0)
+	// Storage: AwesomeAvatars FeeAssets (r:0 w:1)
+	fn set_fee_asset() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(54_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars Treasurer (r:1 w:0)
+	// Storage: AwesomeAvatars CurrentSeasonId (r:1 w:0)
+	// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	// Storage: AwesomeAvatars AssetTreasury (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	fn claim_asset_treasury() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(160_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(9 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	// Storage: AwesomeAvatars CurrentSeasonId (r:1 w:0)
+	// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:1)
+	// Storage: AwesomeAvatars CarryOvers (r:1 w:0)
+	// Storage: AwesomeAvatars Trade (r:200 w:0)
+	// Storage: AwesomeAvatars Auctions (r:200 w:0)
+	// Storage: AwesomeAvatars RentalListings (r:200 w:0)
+	// Storage: AwesomeAvatars Rentals (r:200 w:0)
+	// Storage: AwesomeAvatars LockedAvatars (r:200 w:0)
+	// Storage: AwesomeAvatars Preparation (r:200 w:0)
+	// Storage: AwesomeAvatars Avatars (r:200 w:200)
+	// Storage: AwesomeAvatars ForgeBoosts (r:1 w:1)
+	// Storage: Randomness RandomMaterial (r:1 w:0)
+	// Storage: AwesomeAvatars OwnedAvatars (r:150 w:150)
+	// Storage: AwesomeAvatars OwnedAvatarCounts (r:150 w:150)
+	// Storage: AwesomeAvatars Offers (r:170 w:20)
+	// Storage: System Account (r:20 w:20)
+	// Storage: AwesomeAvatars Accounts (r:1 w:1)
+	// Storage: AwesomeAvatars SeasonStats (r:1 w:1)
+	// Storage: AwesomeAvatars ForgeLeaderboards (r:1 w:1)
+	/// The range of component `n` is `[1, 50]`.
+	/// The range of component `s` is `[0, 100]`.
+	/// The range of component `o` is `[0, 20]`.
+	fn forge_batch(n: u32, s: u32, o: u32, ) -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(120_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(110_000_000 as u64).saturating_mul(n as u64))
+			.saturating_add(Weight::from_ref_time(12_000_000 as u64).saturating_mul(s as u64))
+			.saturating_add(Weight::from_ref_time(15_000_000 as u64).saturating_mul(o as u64))
+			.saturating_add(RocksDbWeight::get().reads(12 as u64))
+			.saturating_add(RocksDbWeight::get().reads((17 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().reads((10 as u64).saturating_mul(s as u64)))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(o as u64)))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(s as u64)))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(o as u64)))
+	}
+	// Storage: AwesomeAvatars Organizer (r:1 w:0)
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars CarryOvers (r:0 w:1)
+	fn set_carry_over() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(55_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	// Storage: AwesomeAvatars CurrentSeasonId (r:1 w:0)
+	// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	// Storage: AwesomeAvatars Avatars (r:1 w:1)
+	// Storage: AwesomeAvatars Trade (r:1 w:0)
+	// Storage: AwesomeAvatars Auctions (r:1 w:0)
+	// Storage: AwesomeAvatars RentalListings (r:1 w:0)
+	// Storage: AwesomeAvatars Rentals (r:1 w:0)
+	// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
+	// Storage: AwesomeAvatars Preparation (r:1 w:0)
+	// Storage: AwesomeAvatars CarryOvers (r:1 w:0)
+	// Storage: AwesomeAvatars FeeAssets (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: AwesomeAvatars Treasury (r:1 w:1)
+	// Storage: Randomness RandomMaterial (r:1 w:0)
+	fn ascend_avatar() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(150_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(16 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: AwesomeAvatars Organizer (r:1 w:0)
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars SoulExchanges (r:0 w:1)
+	fn set_soul_exchange() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(54_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars Avatars (r:1 w:1)
+	// Storage: AwesomeAvatars Trade (r:1 w:0)
+	// Storage: AwesomeAvatars Auctions (r:1 w:0)
+	// Storage: AwesomeAvatars RentalListings (r:1 w:0)
+	// Storage: AwesomeAvatars Rentals (r:1 w:0)
+	// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
+	// Storage: AwesomeAvatars Preparation (r:1 w:0)
+	// Storage: AwesomeAvatars Souls (r:1 w:1)
+	// Storage: AwesomeAvatars OwnedAvatars (r:0 w:1)
+	// Storage: AwesomeAvatars OwnedAvatarCounts (r:1 w:1)
+	// Storage: AwesomeAvatars Offers (r:20 w:20)
+	// Storage: System Account (r:20 w:20)
+	/// The range of component `o` is `[0, 20]`.
+	fn extract_souls(o: u32, ) -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(97_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(15_000_000 as u64).saturating_mul(o as u64))
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(o as u64)))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(o as u64)))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars CurrentSeasonId (r:1 w:0)
+	// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	// Storage: AwesomeAvatars Avatars (r:1 w:1)
+	// Storage: AwesomeAvatars Trade (r:1 w:0)
+	// Storage: AwesomeAvatars Auctions (r:1 w:0)
+	// Storage: AwesomeAvatars RentalListings (r:1 w:0)
+	// Storage: AwesomeAvatars Rentals (r:1 w:0)
+	// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
+	// Storage: AwesomeAvatars Preparation (r:1 w:0)
+	// Storage: AwesomeAvatars SoulExchanges (r:1 w:0)
+	// Storage: AwesomeAvatars Souls (r:1 w:1)
+	// Storage: Randomness RandomMaterial (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn reroll_component() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(110_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(15 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars CurrentSeasonId (r:1 w:0)
+	// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	// Storage: AwesomeAvatars SoulExchanges (r:1 w:0)
+	// Storage: AwesomeAvatars Souls (r:1 w:1)
+	// Storage: AwesomeAvatars Accounts (r:1 w:1)
+	fn buy_free_mints() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(68_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars CurrentSeasonId (r:1 w:0)
+	// Storage: AwesomeAvatars Seasons (r:1 w:0)
+	// Storage: AwesomeAvatars CurrentSeasonStatus (r:1 w:0)
+	// Storage: AwesomeAvatars SoulExchanges (r:1 w:0)
+	// Storage: AwesomeAvatars Souls (r:1 w:1)
+	// Storage: AwesomeAvatars ForgeBoosts (r:1 w:1)
+	fn buy_forge_boost() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(66_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars GlobalConfigs (r:1 w:1)
+	fn patch_global_config() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(55_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars NextConfigPatchId (r:1 w:1)
+	// Storage: AwesomeAvatars ScheduledConfigPatches (r:1 w:1)
+	fn schedule_config_patch() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(66_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars ScheduledConfigPatches (r:1 w:1)
+	fn cancel_config_patch() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(62_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: AwesomeAvatars Organizer (r:1 w:0)
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars Allowlists (r:0 w:100)
+	/// The range of component `n` is `[1, 100]`.
+	fn add_to_allowlist(n: u32, ) -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(50_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_000_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: AwesomeAvatars Organizer (r:1 w:0)
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars Allowlists (r:0 w:100)
+	/// The range of component `n` is `[1, 100]`.
+	fn remove_from_allowlist(n: u32, ) -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(49_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_900_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: AwesomeAvatars Organizer (r:1 w:0)
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars AirdropBudgets (r:0 w:1)
+	fn set_airdrop_budget() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(47_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: AwesomeAvatars Organizer (r:1 w:0)
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars CurrentSeasonId (r:1 w:0)
+	// Storage: AwesomeAvatars Accounts (r:1000 w:1000)
+	// Storage: AwesomeAvatars AirdropBudgets (r:1 w:1)
+	/// The range of component `n` is `[1, 1000]`.
+	fn airdrop_free_mints(n: u32, ) -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(61_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(8_100_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars RandomnessCommitment (r:0 w:1)
+	fn commit_randomness() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(45_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars ServiceAccount (r:1 w:0)
+	// Storage: AwesomeAvatars RandomnessCommitment (r:1 w:1)
+	// Storage: AwesomeAvatars RevealedRandomness (r:1 w:1)
+	fn reveal_randomness() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(55_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+	// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
+	// Storage: AwesomeAvatars CollectionId (r:1 w:0)
+	// Storage: Nft Item (r:1 w:0)
+	// Storage: NftTransfer NftStatuses (r:1 w:1)
+	// Storage: NftTransfer UploadQueue (r:0 w:1)
+	// Storage: AwesomeAvatars RentalListings (r:1 w:0)
+	// Storage: AwesomeAvatars Rentals (r:1 w:0)
+	fn upload_avatar() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(70_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: AwesomeAvatars OngoingMigration (r:1 w:0)
+	// Storage: AwesomeAvatars ServiceAccount (r:1 w:0)
+	// Storage: AwesomeAvatars PendingUploads (r:1 w:1)
+	fn confirm_upload() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(50_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
 }
@@ -18,13 +18,19 @@ use super::*;
 
 #[allow(unused)]
 use crate::Pallet as NftStake;
-use frame_benchmarking::{benchmarks, whitelist_account};
-use frame_support::traits::tokens::{
-	nonfungibles_v2::{Create, Mutate},
-	AttributeNamespace,
+use frame_benchmarking::{benchmarks, whitelist_account, BenchmarkError};
+use frame_support::traits::{
+	tokens::{
+		nonfungibles_v2::{Create, Mutate},
+		AttributeNamespace,
+	},
+	Hooks,
 };
 use frame_system::RawOrigin;
-use sp_runtime::traits::Bounded;
+use sp_runtime::{
+	traits::{Bounded, TrailingZeroInput},
+	Perbill,
+};
 
 pub fn prepare_account<T: Config>(name: &'static str) -> T::AccountId {
 	let account: T::AccountId = get_account::<T>(name);
@@ -49,7 +55,7 @@ fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 fn create_staking_contract<T: Config>(
 	reward: StakingRewardOf<T>,
 	duration: BlockNumberOf<T>,
-	clause: ContractClause<AccountIdOf<T>, ContractAttributeKeyOf<T>, ContractAttributeValueOf<T>>,
+	clause: ContractClauseOf<T>,
 ) -> StakingContractOf<T> {
 	StakingContractOf::<T>::new(reward, duration)
 		.with_clause(clause.clone())
@@ -78,7 +84,11 @@ fn create_random_nft_batch<T: Config>(
 	let mut nft_vec = Vec::with_capacity(amount as usize);
 
 	for item_id in 0..amount {
-		nft_vec.push(create_random_nft::<T>(owner, collection_id, item_id.into()));
+		nft_vec.push(create_random_nft::<T>(
+			owner,
+			collection_id,
+			T::BenchmarkHelper::item_id(item_id as u16),
+		));
 	}
 
 	nft_vec
@@ -127,20 +137,67 @@ fn set_attribute_for_nft<T: Config>(
 	nft_attr_key: u32,
 	nft_attr_value: u64,
 ) {
-	T::NftHelper::set_typed_attribute::<u32, u64>(
+	T::NftHelper::set_typed_attribute::<ContractAttributeKeyOf<T>, ContractAttributeValueOf<T>>(
 		&nft_addr.0,
 		&nft_addr.1,
-		&nft_attr_key,
-		&nft_attr_value,
+		&T::BenchmarkHelper::contract_key(nft_attr_key),
+		&T::BenchmarkHelper::contract_value(nft_attr_value),
 	)
 	.expect("Should add attribute Nft");
 }
 
 fn create_contract_clause<T: Config>(attr_key: u32, attr_value: u64) -> ContractClauseOf<T> {
-	ContractClauseOf::<T>::HasAttributeWithValue(
+	ContractCondition::HasAttributeWithValue(
 		AttributeNamespace::Pallet,
-		attr_key.into(),
-		attr_value.into(),
+		T::BenchmarkHelper::contract_key(attr_key),
+		T::BenchmarkHelper::contract_value(attr_value),
+	)
+	.into()
+}
+
+/// Attribute keys checked by [`create_worst_case_contract_clause`], one per condition.
+fn worst_case_attribute_keys(attr_key: u32) -> sp_std::ops::Range<u32> {
+	attr_key..(attr_key + MAXIMUM_CONDITIONS_PER_CLAUSE)
+}
+
+/// Clause requiring every condition to be checked, each against a different attribute, so that
+/// each clause reads as many attributes as possible when matched against the staked assets.
+fn create_worst_case_contract_clause<T: Config>(
+	attr_key: u32,
+	attr_value: u64,
+) -> ContractClauseOf<T> {
+	let conditions = worst_case_attribute_keys(attr_key)
+		.map(|key| {
+			ContractCondition::HasAttributeWithValue(
+				AttributeNamespace::Pallet,
+				T::BenchmarkHelper::contract_key(key),
+				T::BenchmarkHelper::contract_value(attr_value),
+			)
+		})
+		.collect::<Vec<_>>();
+	ContractClauseOf::<T>::All(conditions.try_into().expect("Should fit conditions"))
+}
+
+/// Lets any account submit contracts, since the benchmarks submit them from unregistered accounts.
+fn open_contract_submission<T: Config>() {
+	CreatorConfiguration::<T>::put(CreatorConfig {
+		registration_required: false,
+		fee_share: Perbill::zero(),
+	});
+}
+
+fn reward_asset<T: Config>() -> AssetIdOf<T> {
+	AssetIdOf::<T>::decode(&mut TrailingZeroInput::zeroes()).unwrap()
+}
+
+/// Reward bundling tokens with as many Nfts as possible.
+fn create_bundle_reward<T: Config>(owner: &AccountIdOf<T>) -> StakingRewardOf<T> {
+	let collection_id = create_random_nft_collection::<T>(owner.clone());
+	let nft_batch =
+		create_random_nft_batch::<T>(owner, collection_id, MAXIMUM_NFTS_PER_REWARD_BUNDLE);
+	StakingRewardOf::<T>::Bundle(
+		1_000_u32.into(),
+		nft_batch.try_into().expect("Should fit bundle Nfts"),
 	)
 }
 
@@ -150,12 +207,6 @@ fn create_staking_contract_collection<T: Config>(account: &T::AccountId) -> T::C
 		.expect("Should have create contract collection")
 }
 
-type ContractClauseOf<T> = ContractClause<
-	<T as frame_system::Config>::AccountId,
-	<T as Config>::ContractAttributeKey,
-	<T as Config>::ContractAttributeValue,
->;
-
 benchmarks! {
 	set_organizer {
 		let organizer = prepare_account::<T>("ALICE");
@@ -193,6 +244,7 @@ benchmarks! {
 		let account = NftStake::<T>::treasury_account_id();
 		let collection_id = create_staking_contract_collection::<T>(&account);
 		ContractCollectionId::<T>::put(collection_id);
+		open_contract_submission::<T>();
 
 		let caller = prepare_account::<T>("ALICE");
 		let reward_amt: BalanceOf<T> = 1_000_u32.into();
@@ -209,10 +261,11 @@ benchmarks! {
 		let account = NftStake::<T>::treasury_account_id();
 		let collection_id = create_staking_contract_collection::<T>(&account);
 		ContractCollectionId::<T>::put(collection_id);
+		open_contract_submission::<T>();
 
 		let caller = prepare_account::<T>("ALICE");
 		let collection_id = create_random_nft_collection::<T>(caller.clone());
-		let nft_addr = create_random_nft::<T>(&caller, collection_id, 0_u32.into());
+		let nft_addr = create_random_nft::<T>(&caller, collection_id, T::BenchmarkHelper::item_id(0));
 		let reward = StakingRewardOf::<T>::Nft(nft_addr);
 		let clause = create_contract_clause::<T>(10, 10);
 		let contract = create_staking_contract::<T>(reward, 10_u32.into(), clause);
@@ -222,24 +275,64 @@ benchmarks! {
 		assert_last_event::<T>(Event::StakingContractCreated { creator: caller, contract: expected_id }.into())
 	}
 
-	take_staking_contract {
+	submit_staking_contract_asset_reward {
 		let account = NftStake::<T>::treasury_account_id();
 		let collection_id = create_staking_contract_collection::<T>(&account);
 		ContractCollectionId::<T>::put(collection_id);
+		open_contract_submission::<T>();
 
 		let caller = prepare_account::<T>("ALICE");
+		let asset_id = reward_asset::<T>();
 		let reward_amt: BalanceOf<T> = 1_000_u32.into();
-		let reward = StakingRewardOf::<T>::Tokens(reward_amt);
+		T::RewardAssets::create_asset(asset_id, &caller, reward_amt * 2_u32.into())
+			.map_err(|_| BenchmarkError::Skip)?;
+		let reward = StakingRewardOf::<T>::Assets(asset_id, reward_amt);
 		let clause = create_contract_clause::<T>(10, 10);
 		let contract = create_staking_contract::<T>(reward, 10_u32.into(), clause);
+		let expected_id = NftStake::<T>::next_contract_id();
+	}: submit_staking_contract(RawOrigin::Signed(caller.clone()), contract)
+	verify {
+		assert_last_event::<T>(Event::StakingContractCreated { creator: caller, contract: expected_id }.into())
+	}
+
+	submit_staking_contract_bundle_reward {
+		let account = NftStake::<T>::treasury_account_id();
+		let collection_id = create_staking_contract_collection::<T>(&account);
+		ContractCollectionId::<T>::put(collection_id);
+		open_contract_submission::<T>();
+
+		let caller = prepare_account::<T>("ALICE");
+		let reward = create_bundle_reward::<T>(&caller);
+		let clause = create_contract_clause::<T>(10, 10);
+		let contract = create_staking_contract::<T>(reward, 10_u32.into(), clause);
+		let expected_id = NftStake::<T>::next_contract_id();
+	}: submit_staking_contract(RawOrigin::Signed(caller.clone()), contract)
+	verify {
+		assert_last_event::<T>(Event::StakingContractCreated { creator: caller, contract: expected_id }.into())
+	}
+
+	take_staking_contract {
+		let account = NftStake::<T>::treasury_account_id();
+		let collection_id = create_staking_contract_collection::<T>(&account);
+		ContractCollectionId::<T>::put(collection_id);
+
+		let caller = prepare_account::<T>("ALICE");
+		let reward_amt: BalanceOf<T> = 1_000_u32.into();
+		let reward = StakingRewardOf::<T>::Tokens(reward_amt);
+		let clause = create_worst_case_contract_clause::<T>(10, 10);
+		let contract = create_staking_contract::<T>(reward, 10_u32.into(), clause)
+			.with_taking_fee(1_000_u32.into());
 		let contract_id = NftStake::<T>::next_contract_id();
+		CreatorConfiguration::<T>::put(CreatorConfig { registration_required: false, fee_share: Perbill::from_percent(50) });
 
 		NftStake::<T>::submit_staking_contract(RawOrigin::Signed(caller).into(), contract)?;
 
 		let taker_caller = prepare_account::<T>("BOB");
 		let collection_id = create_random_nft_collection::<T>(taker_caller.clone());
 		let nft_batch = create_random_nft_batch::<T>(&taker_caller, collection_id, MAXIMUM_CLAUSES_PER_CONTRACT);
-		set_attribute_for_nft_batch::<T>(&nft_batch, 10_u32, 10_u64);
+		for attr_key in worst_case_attribute_keys(10_u32) {
+			set_attribute_for_nft_batch::<T>(&nft_batch, attr_key, 10_u64);
+		}
 		let staking_vec = create_staking_vector_from::<T>(nft_batch);
 	}: _(RawOrigin::Signed(taker_caller.clone()), contract_id, staking_vec)
 	verify {
@@ -250,6 +343,7 @@ benchmarks! {
 		let account = NftStake::<T>::treasury_account_id();
 		let collection_id = create_staking_contract_collection::<T>(&account);
 		ContractCollectionId::<T>::put(collection_id);
+		open_contract_submission::<T>();
 
 		let caller = prepare_account::<T>("ALICE");
 		let reward_amt: BalanceOf<T> = 1_000_u32.into();
@@ -277,10 +371,11 @@ benchmarks! {
 		let account = NftStake::<T>::treasury_account_id();
 		let collection_id = create_staking_contract_collection::<T>(&account);
 		ContractCollectionId::<T>::put(collection_id);
+		open_contract_submission::<T>();
 
 		let caller = prepare_account::<T>("ALICE");
 		let collection_id = create_random_nft_collection::<T>(caller.clone());
-		let reward_nft_addr = create_random_nft::<T>(&caller, collection_id, 0_u32.into());
+		let reward_nft_addr = create_random_nft::<T>(&caller, collection_id, T::BenchmarkHelper::item_id(0));
 		let reward = StakingRewardOf::<T>::Nft(reward_nft_addr);
 		let clause = create_contract_clause::<T>(10, 10);
 		let contract = create_staking_contract::<T>(reward.clone(), 0_u32.into(), clause);
@@ -301,6 +396,286 @@ benchmarks! {
 		assert_last_event::<T>(Event::StakingContractRedeemed { redeemed_by: taker_caller, contract: contract_id, reward }.into())
 	}
 
+	redeem_staking_contract_bundle_reward {
+		let account = NftStake::<T>::treasury_account_id();
+		let collection_id = create_staking_contract_collection::<T>(&account);
+		ContractCollectionId::<T>::put(collection_id);
+		open_contract_submission::<T>();
+
+		let caller = prepare_account::<T>("ALICE");
+		let reward = create_bundle_reward::<T>(&caller);
+		let clause = create_contract_clause::<T>(10, 10);
+		let contract = create_staking_contract::<T>(reward.clone(), 0_u32.into(), clause);
+		let contract_id = NftStake::<T>::next_contract_id();
+
+		NftStake::<T>::submit_staking_contract(RawOrigin::Signed(caller).into(), contract)?;
+
+		let taker_caller = prepare_account::<T>("BOB");
+		let collection_id = create_random_nft_collection::<T>(taker_caller.clone());
+		let nft_batch = create_random_nft_batch::<T>(&taker_caller, collection_id, MAXIMUM_CLAUSES_PER_CONTRACT);
+		set_attribute_for_nft_batch::<T>(&nft_batch, 10_u32, 10_u64);
+		let staking_vec = create_staking_vector_from::<T>(nft_batch);
+
+		NftStake::<T>::take_staking_contract(RawOrigin::Signed(taker_caller.clone()).into(), contract_id, staking_vec)?;
+
+	}: redeem_staking_contract(RawOrigin::Signed(taker_caller.clone()), contract_id)
+	verify {
+		assert_last_event::<T>(Event::StakingContractRedeemed { redeemed_by: taker_caller, contract: contract_id, reward }.into())
+	}
+
+	cancel_staking_contract_token_reward {
+		let account = NftStake::<T>::treasury_account_id();
+		let collection_id = create_staking_contract_collection::<T>(&account);
+		ContractCollectionId::<T>::put(collection_id);
+		open_contract_submission::<T>();
+
+		let caller = prepare_account::<T>("ALICE");
+		let reward_amt: BalanceOf<T> = 1_000_u32.into();
+		let reward = StakingRewardOf::<T>::Tokens(reward_amt);
+		let clause = create_contract_clause::<T>(10, 10);
+		let contract = create_staking_contract::<T>(reward, 10_u32.into(), clause)
+			.with_availability_period(10_u32.into());
+		let contract_id = NftStake::<T>::next_contract_id();
+
+		NftStake::<T>::submit_staking_contract(RawOrigin::Signed(caller.clone()).into(), contract)?;
+	}: cancel_staking_contract(RawOrigin::Signed(caller.clone()), contract_id)
+	verify {
+		assert_last_event::<T>(Event::StakingContractCancelled { creator: caller, contract: contract_id }.into())
+	}
+
+	cancel_staking_contract_nft_reward {
+		let account = NftStake::<T>::treasury_account_id();
+		let collection_id = create_staking_contract_collection::<T>(&account);
+		ContractCollectionId::<T>::put(collection_id);
+		open_contract_submission::<T>();
+
+		let caller = prepare_account::<T>("ALICE");
+		let collection_id = create_random_nft_collection::<T>(caller.clone());
+		let nft_addr = create_random_nft::<T>(&caller, collection_id, T::BenchmarkHelper::item_id(0));
+		let reward = StakingRewardOf::<T>::Nft(nft_addr);
+		let clause = create_contract_clause::<T>(10, 10);
+		let contract = create_staking_contract::<T>(reward, 10_u32.into(), clause)
+			.with_availability_period(10_u32.into());
+		let contract_id = NftStake::<T>::next_contract_id();
+
+		NftStake::<T>::submit_staking_contract(RawOrigin::Signed(caller.clone()).into(), contract)?;
+	}: cancel_staking_contract(RawOrigin::Signed(caller.clone()), contract_id)
+	verify {
+		assert_last_event::<T>(Event::StakingContractCancelled { creator: caller, contract: contract_id }.into())
+	}
+
+	cancel_staking_contract_bundle_reward {
+		let account = NftStake::<T>::treasury_account_id();
+		let collection_id = create_staking_contract_collection::<T>(&account);
+		ContractCollectionId::<T>::put(collection_id);
+		open_contract_submission::<T>();
+
+		let caller = prepare_account::<T>("ALICE");
+		let reward = create_bundle_reward::<T>(&caller);
+		let clause = create_contract_clause::<T>(10, 10);
+		let contract = create_staking_contract::<T>(reward, 10_u32.into(), clause)
+			.with_availability_period(10_u32.into());
+		let contract_id = NftStake::<T>::next_contract_id();
+
+		NftStake::<T>::submit_staking_contract(RawOrigin::Signed(caller.clone()).into(), contract)?;
+	}: cancel_staking_contract(RawOrigin::Signed(caller.clone()), contract_id)
+	verify {
+		assert_last_event::<T>(Event::StakingContractCancelled { creator: caller, contract: contract_id }.into())
+	}
+
+	withdraw_staking_contract_token_reward {
+		let account = NftStake::<T>::treasury_account_id();
+		let collection_id = create_staking_contract_collection::<T>(&account);
+		ContractCollectionId::<T>::put(collection_id);
+		open_contract_submission::<T>();
+
+		let caller = prepare_account::<T>("ALICE");
+		let reward_amt: BalanceOf<T> = 1_000_u32.into();
+		let reward = StakingRewardOf::<T>::Tokens(reward_amt);
+		let clause = create_contract_clause::<T>(10, 10);
+		let contract = create_staking_contract::<T>(reward, 10_u32.into(), clause)
+			.with_early_withdrawal_penalty(Perbill::from_percent(10));
+		let contract_id = NftStake::<T>::next_contract_id();
+
+		NftStake::<T>::submit_staking_contract(RawOrigin::Signed(caller).into(), contract)?;
+
+		let taker_caller = prepare_account::<T>("BOB");
+		let collection_id = create_random_nft_collection::<T>(taker_caller.clone());
+		let nft_batch = create_random_nft_batch::<T>(&taker_caller, collection_id, MAXIMUM_CLAUSES_PER_CONTRACT);
+		set_attribute_for_nft_batch::<T>(&nft_batch, 10_u32, 10_u64);
+		let staking_vec = create_staking_vector_from::<T>(nft_batch);
+
+		NftStake::<T>::take_staking_contract(RawOrigin::Signed(taker_caller.clone()).into(), contract_id, staking_vec)?;
+
+		let reward = Some(StakingRewardOf::<T>::Tokens(900_u32.into()));
+	}: withdraw_staking_contract(RawOrigin::Signed(taker_caller.clone()), contract_id)
+	verify {
+		assert_last_event::<T>(Event::StakingContractWithdrawn { withdrawn_by: taker_caller, contract: contract_id, reward }.into())
+	}
+
+	withdraw_staking_contract_nft_reward {
+		let account = NftStake::<T>::treasury_account_id();
+		let collection_id = create_staking_contract_collection::<T>(&account);
+		ContractCollectionId::<T>::put(collection_id);
+		open_contract_submission::<T>();
+
+		let caller = prepare_account::<T>("ALICE");
+		let collection_id = create_random_nft_collection::<T>(caller.clone());
+		let reward_nft_addr = create_random_nft::<T>(&caller, collection_id, T::BenchmarkHelper::item_id(0));
+		let reward = StakingRewardOf::<T>::Nft(reward_nft_addr);
+		let clause = create_contract_clause::<T>(10, 10);
+		let contract = create_staking_contract::<T>(reward, 10_u32.into(), clause)
+			.with_early_withdrawal_penalty(Perbill::from_percent(10));
+		let contract_id = NftStake::<T>::next_contract_id();
+
+		NftStake::<T>::submit_staking_contract(RawOrigin::Signed(caller).into(), contract)?;
+
+		let taker_caller = prepare_account::<T>("BOB");
+		let collection_id = create_random_nft_collection::<T>(taker_caller.clone());
+		let nft_batch = create_random_nft_batch::<T>(&taker_caller, collection_id, MAXIMUM_CLAUSES_PER_CONTRACT);
+		set_attribute_for_nft_batch::<T>(&nft_batch, 10_u32, 10_u64);
+		let staking_vec = create_staking_vector_from::<T>(nft_batch);
+
+		NftStake::<T>::take_staking_contract(RawOrigin::Signed(taker_caller.clone()).into(), contract_id, staking_vec)?;
+	}: withdraw_staking_contract(RawOrigin::Signed(taker_caller.clone()), contract_id)
+	verify {
+		assert_last_event::<T>(Event::StakingContractWithdrawn { withdrawn_by: taker_caller, contract: contract_id, reward: None }.into())
+	}
+
+	withdraw_staking_contract_bundle_reward {
+		let account = NftStake::<T>::treasury_account_id();
+		let collection_id = create_staking_contract_collection::<T>(&account);
+		ContractCollectionId::<T>::put(collection_id);
+		open_contract_submission::<T>();
+
+		let caller = prepare_account::<T>("ALICE");
+		let reward = create_bundle_reward::<T>(&caller);
+		let clause = create_contract_clause::<T>(10, 10);
+		let contract = create_staking_contract::<T>(reward, 10_u32.into(), clause)
+			.with_early_withdrawal_penalty(Perbill::from_percent(10))
+			.with_streamed_reward();
+		let contract_id = NftStake::<T>::next_contract_id();
+
+		NftStake::<T>::submit_staking_contract(RawOrigin::Signed(caller).into(), contract)?;
+
+		let taker_caller = prepare_account::<T>("BOB");
+		let collection_id = create_random_nft_collection::<T>(taker_caller.clone());
+		let nft_batch = create_random_nft_batch::<T>(&taker_caller, collection_id, MAXIMUM_CLAUSES_PER_CONTRACT);
+		set_attribute_for_nft_batch::<T>(&nft_batch, 10_u32, 10_u64);
+		let staking_vec = create_staking_vector_from::<T>(nft_batch);
+
+		NftStake::<T>::take_staking_contract(RawOrigin::Signed(taker_caller.clone()).into(), contract_id, staking_vec)?;
+
+		let reward = Some(StakingRewardOf::<T>::Tokens(900_u32.into()));
+	}: withdraw_staking_contract(RawOrigin::Signed(taker_caller.clone()), contract_id)
+	verify {
+		assert_last_event::<T>(Event::StakingContractWithdrawn { withdrawn_by: taker_caller, contract: contract_id, reward }.into())
+	}
+
+	claim_staking_contract_reward {
+		let account = NftStake::<T>::treasury_account_id();
+		let collection_id = create_staking_contract_collection::<T>(&account);
+		ContractCollectionId::<T>::put(collection_id);
+		open_contract_submission::<T>();
+
+		let caller = prepare_account::<T>("ALICE");
+		let reward_amt: BalanceOf<T> = 1_000_u32.into();
+		let reward = StakingRewardOf::<T>::Tokens(reward_amt);
+		let clause = create_contract_clause::<T>(10, 10);
+		let contract = create_staking_contract::<T>(reward, 10_u32.into(), clause)
+			.with_streamed_reward();
+		let contract_id = NftStake::<T>::next_contract_id();
+
+		NftStake::<T>::submit_staking_contract(RawOrigin::Signed(caller).into(), contract)?;
+
+		let taker_caller = prepare_account::<T>("BOB");
+		let collection_id = create_random_nft_collection::<T>(taker_caller.clone());
+		let nft_batch = create_random_nft_batch::<T>(&taker_caller, collection_id, MAXIMUM_CLAUSES_PER_CONTRACT);
+		set_attribute_for_nft_batch::<T>(&nft_batch, 10_u32, 10_u64);
+		let staking_vec = create_staking_vector_from::<T>(nft_batch);
+
+		NftStake::<T>::take_staking_contract(RawOrigin::Signed(taker_caller.clone()).into(), contract_id, staking_vec)?;
+
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now + 5_u32.into());
+		let reward = StakingRewardOf::<T>::Tokens(500_u32.into());
+	}: _(RawOrigin::Signed(taker_caller.clone()), contract_id)
+	verify {
+		assert_last_event::<T>(Event::StakingRewardClaimed { claimed_by: taker_caller, contract: contract_id, reward }.into())
+	}
+
+	expire_staking_contracts {
+		let n in 0 .. MAXIMUM_EXPIRING_CONTRACTS_PER_BLOCK;
+
+		let account = NftStake::<T>::treasury_account_id();
+		let collection_id = create_staking_contract_collection::<T>(&account);
+		ContractCollectionId::<T>::put(collection_id);
+		open_contract_submission::<T>();
+
+		let caller = prepare_account::<T>("ALICE");
+		for _ in 0..n {
+			let reward = create_bundle_reward::<T>(&caller);
+			let clause = create_contract_clause::<T>(10, 10);
+			let contract = create_staking_contract::<T>(reward, 10_u32.into(), clause)
+				.with_availability_period(10_u32.into());
+			NftStake::<T>::submit_staking_contract(RawOrigin::Signed(caller.clone()).into(), contract)?;
+		}
+
+		let expiry = frame_system::Pallet::<T>::block_number() + 10_u32.into();
+	}: {
+		NftStake::<T>::on_initialize(expiry);
+	}
+	verify {
+		assert!(ContractExpiries::<T>::get(expiry).is_empty());
+		assert_eq!(ActiveContracts::<T>::iter().count(), 0);
+	}
+
+	set_creator_config {
+		let organizer = prepare_account::<T>("ALICE");
+		Organizer::<T>::put(&organizer);
+		let config = CreatorConfig { registration_required: true, fee_share: Perbill::from_percent(50) };
+	}: _(RawOrigin::Signed(organizer), config)
+	verify {
+		assert_last_event::<T>(Event::CreatorConfigSet { config }.into())
+	}
+
+	register_creator {
+		let organizer = prepare_account::<T>("ALICE");
+		Organizer::<T>::put(&organizer);
+		let creator = prepare_account::<T>("BOB");
+		let collections = CreatorCollectionsOf::<T>::try_from(
+			(0..MAXIMUM_COLLECTIONS_PER_CREATOR).map(|_| create_random_nft_collection::<T>(creator.clone())).collect::<Vec<_>>()
+		).expect("Should fit collections");
+	}: _(RawOrigin::Signed(organizer), creator.clone(), collections.clone())
+	verify {
+		assert_last_event::<T>(Event::CreatorRegistered { creator, collections }.into())
+	}
+
+	unregister_creator {
+		let organizer = prepare_account::<T>("ALICE");
+		Organizer::<T>::put(&organizer);
+		let creator = prepare_account::<T>("BOB");
+		let collection_id = create_random_nft_collection::<T>(creator.clone());
+		Creators::<T>::insert(&creator, CreatorCollectionsOf::<T>::try_from(sp_std::vec![collection_id]).expect("Should fit collections"));
+	}: _(RawOrigin::Signed(organizer), creator.clone())
+	verify {
+		assert_last_event::<T>(Event::CreatorUnregistered { creator }.into())
+	}
+
+	claim_collected_fees {
+		let organizer = prepare_account::<T>("ALICE");
+		Organizer::<T>::put(&organizer);
+		let fees: BalanceOf<T> = 1_000_u32.into();
+		T::Currency::make_free_balance_be(
+			&NftStake::<T>::treasury_account_id(),
+			T::Currency::minimum_balance().saturating_add(fees),
+		);
+		CollectedFees::<T>::put(fees);
+	}: _(RawOrigin::Signed(organizer.clone()))
+	verify {
+		assert_last_event::<T>(Event::FeesClaimed { organizer, fees }.into())
+	}
+
 	impl_benchmark_test_suite!(
 		NftStake, crate::mock::ExtBuilder::default().create_collection(true).build(), crate::mock::Test
 	);
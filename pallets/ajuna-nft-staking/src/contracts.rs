@@ -20,8 +20,8 @@ use frame_support::traits::{
 	ConstU32,
 };
 use scale_info::TypeInfo;
-use sp_runtime::BoundedVec;
-use sp_std::fmt::Debug;
+use sp_runtime::{traits::Zero, BoundedVec, Perbill};
+use sp_std::{fmt::Debug, vec, vec::Vec};
 
 /// Struct that represents a combination of an Nft collection id and item id.
 /// Used in combination of an [`Inspect`] capable provider.
@@ -36,22 +36,77 @@ where
 pub type StakedAssetsVec<CollectionId, ItemId, const N: u32> =
 	BoundedVec<NftAddress<CollectionId, ItemId>, ConstU32<N>>;
 
+/// Maximum number of Nfts a single [`StakingReward::Bundle`] can hold.
+pub const MAXIMUM_NFTS_PER_REWARD_BUNDLE: u32 = 5;
+
+/// List of Nfts awarded together by a [`StakingReward::Bundle`].
+pub type RewardNfts<CollectionId, ItemId> =
+	BoundedVec<NftAddress<CollectionId, ItemId>, ConstU32<MAXIMUM_NFTS_PER_REWARD_BUNDLE>>;
+
 #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
-pub enum StakingReward<Balance, CollectionId, ItemId>
+pub enum StakingReward<Balance, AssetId, CollectionId, ItemId>
 where
 	Balance: RewardBalance,
+	AssetId: Debug + Copy,
 	CollectionId: Debug + Copy,
 	ItemId: Debug + Copy,
 {
+	/// An amount of the native currency.
 	Tokens(Balance),
+	/// A single Nft.
 	Nft(NftAddress<CollectionId, ItemId>),
+	/// An amount of a fungible asset other than the native currency.
+	Assets(AssetId, Balance),
+	/// An amount of the native currency together with several Nfts.
+	Bundle(Balance, RewardNfts<CollectionId, ItemId>),
+}
+
+impl<Balance, AssetId, CollectionId, ItemId> StakingReward<Balance, AssetId, CollectionId, ItemId>
+where
+	Balance: RewardBalance,
+	AssetId: Debug + Copy,
+	CollectionId: Debug + Copy,
+	ItemId: Debug + Copy,
+{
+	/// Returns the fungible part of the reward, either in tokens or in assets.
+	pub fn fungible_amount(&self) -> Balance {
+		match self {
+			StakingReward::Tokens(amount) |
+			StakingReward::Assets(_, amount) |
+			StakingReward::Bundle(amount, _) => *amount,
+			StakingReward::Nft(_) => Balance::zero(),
+		}
+	}
+
+	/// Returns the reward with its fungible part replaced by `amount`.
+	pub fn with_fungible_amount(self, amount: Balance) -> Self {
+		match self {
+			StakingReward::Tokens(_) => StakingReward::Tokens(amount),
+			StakingReward::Assets(asset_id, _) => StakingReward::Assets(asset_id, amount),
+			StakingReward::Bundle(_, nfts) => StakingReward::Bundle(amount, nfts),
+			StakingReward::Nft(address) => StakingReward::Nft(address),
+		}
+	}
+
+	/// Returns the fungible part of the reward alone, leaving any Nfts out.
+	pub fn without_nfts(self) -> Option<Self> {
+		match self {
+			StakingReward::Bundle(amount, _) => Some(StakingReward::Tokens(amount)),
+			StakingReward::Nft(_) => None,
+			fungible_reward => Some(fungible_reward),
+		}
+	}
 }
 
 /// Specification for a staking contract, in short it's a list of criteria to be fulfilled,
-/// with a given reward after the duration is complete.
+/// with a given reward after the duration is complete. Optionally, the contract can only be
+/// taken for a limited period after its submission, and can be left early in exchange for part
+/// of its reward, or stream the fungible part of its reward to the taker while it's active. It
+/// can also charge its taker a fee in tokens.
 #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
 pub struct StakingContract<
 	Balance,
+	AssetId,
 	CollectionId,
 	ItemId,
 	AccountId,
@@ -61,20 +116,28 @@ pub struct StakingContract<
 	const N: u32,
 > where
 	Balance: RewardBalance,
-	CollectionId: Debug + Copy,
+	AssetId: Debug + Copy,
+	CollectionId: Debug + Copy + PartialEq,
 	ItemId: Debug + Copy,
 	BlockNumber: Debug + Copy,
 	AttributeKey: Debug + Clone + Encode + Decode + Eq + PartialEq + Ord + PartialOrd,
 	AttributeValue: Debug + Clone + Encode + Decode + Eq + PartialEq + Ord + PartialOrd,
 {
-	staking_reward: StakingReward<Balance, CollectionId, ItemId>,
-	contract_clauses:
-		BoundedVec<ContractClause<AccountId, AttributeKey, AttributeValue>, ConstU32<N>>,
+	staking_reward: StakingReward<Balance, AssetId, CollectionId, ItemId>,
+	contract_clauses: BoundedVec<
+		ContractClause<AccountId, CollectionId, AttributeKey, AttributeValue>,
+		ConstU32<N>,
+	>,
 	contract_block_duration: BlockNumber,
+	availability_period: Option<BlockNumber>,
+	early_withdrawal_penalty: Option<Perbill>,
+	streamed_reward: bool,
+	taking_fee: Balance,
 }
 
 impl<
 		Balance,
+		AssetId,
 		CollectionId,
 		ItemId,
 		AccountId,
@@ -85,6 +148,7 @@ impl<
 	>
 	StakingContract<
 		Balance,
+		AssetId,
 		CollectionId,
 		ItemId,
 		AccountId,
@@ -94,32 +158,76 @@ impl<
 		N,
 	> where
 	Balance: RewardBalance,
-	CollectionId: Debug + Copy,
+	AssetId: Debug + Copy,
+	CollectionId: Debug + Copy + PartialEq,
 	ItemId: Debug + Copy,
 	BlockNumber: Debug + Copy,
 	AttributeKey: Debug + Clone + Encode + Decode + Eq + PartialEq + Ord + PartialOrd,
 	AttributeValue: Debug + Clone + Encode + Decode + Eq + PartialEq + Ord + PartialOrd,
 {
 	pub fn new(
-		reward: StakingReward<Balance, CollectionId, ItemId>,
+		reward: StakingReward<Balance, AssetId, CollectionId, ItemId>,
 		duration: BlockNumber,
 	) -> Self {
 		Self {
 			staking_reward: reward,
 			contract_block_duration: duration,
 			contract_clauses: BoundedVec::default(),
+			availability_period: None,
+			early_withdrawal_penalty: None,
+			streamed_reward: false,
+			taking_fee: Balance::zero(),
 		}
 	}
 
 	pub fn with_clause(
 		mut self,
-		clause: ContractClause<AccountId, AttributeKey, AttributeValue>,
+		clause: impl Into<ContractClause<AccountId, CollectionId, AttributeKey, AttributeValue>>,
 	) -> Self {
-		let _ = self.contract_clauses.try_push(clause);
+		let _ = self.contract_clauses.try_push(clause.into());
+
+		self
+	}
+
+	/// Limits the number of blocks the contract can be taken for once submitted, after which
+	/// it expires and its reward is returned to the creator.
+	pub fn with_availability_period(mut self, period: BlockNumber) -> Self {
+		self.availability_period = Some(period);
+
+		self
+	}
+
+	/// Allows the taker to withdraw before the contract's duration elapses, forfeiting the
+	/// given share of the reward to the creator.
+	pub fn with_early_withdrawal_penalty(mut self, penalty: Perbill) -> Self {
+		self.early_withdrawal_penalty = Some(penalty);
+
+		self
+	}
+
+	/// Lets the taker claim the fungible part of the reward block by block while the contract
+	/// is active, instead of all at once when redeeming it.
+	pub fn with_streamed_reward(mut self) -> Self {
+		self.streamed_reward = true;
 
 		self
 	}
 
+	/// Charges the taker the given amount of tokens when taking the contract, shared between the
+	/// creator and the treasury.
+	pub fn with_taking_fee(mut self, fee: Balance) -> Self {
+		self.taking_fee = fee;
+
+		self
+	}
+
+	/// Checks whether every clause can only be fulfilled by assets from the given collections.
+	pub fn is_bound_to(&self, collections: &[CollectionId]) -> bool {
+		self.contract_clauses.iter().all(|clause| clause.is_bound_to(collections))
+	}
+
+	/// Checks whether the staked assets fulfill the contract's clauses. Each clause must be
+	/// fulfilled by a different asset, regardless of the order in which they were staked.
 	pub fn evaluate_for<NftInspector>(
 		&self,
 		staked_assets: &StakedAssetsVec<CollectionId, ItemId, N>,
@@ -127,69 +235,214 @@ impl<
 	where
 		NftInspector: Inspect<AccountId, CollectionId = CollectionId, ItemId = ItemId>,
 	{
-		(self.contract_clauses.len() == staked_assets.len())
-			.then(|| {
-				self.contract_clauses.iter().zip(staked_assets.iter()).all(|(clause, asset)| {
-					clause.evaluate_for::<NftInspector, CollectionId, ItemId>(asset)
-				})
+		if self.contract_clauses.len() != staked_assets.len() {
+			return false
+		}
+
+		let fulfills = self
+			.contract_clauses
+			.iter()
+			.map(|clause| {
+				staked_assets
+					.iter()
+					.map(|asset| clause.evaluate_for::<NftInspector, ItemId>(asset))
+					.collect::<Vec<_>>()
 			})
-			.unwrap_or(false)
+			.collect::<Vec<_>>();
+
+		let mut matched_clauses = vec![None; staked_assets.len()];
+		(0..fulfills.len()).all(|clause| {
+			let mut visited_assets = vec![false; staked_assets.len()];
+			Self::match_clause(clause, &fulfills, &mut visited_assets, &mut matched_clauses)
+		})
+	}
+
+	/// Tries to assign an asset to `clause`, reassigning the clauses already matched with other
+	/// assets when needed.
+	fn match_clause(
+		clause: usize,
+		fulfills: &[Vec<bool>],
+		visited_assets: &mut [bool],
+		matched_clauses: &mut [Option<usize>],
+	) -> bool {
+		for asset in 0..visited_assets.len() {
+			if !fulfills[clause][asset] || visited_assets[asset] {
+				continue
+			}
+			visited_assets[asset] = true;
+
+			let can_reassign = match matched_clauses[asset] {
+				Some(other_clause) =>
+					Self::match_clause(other_clause, fulfills, visited_assets, matched_clauses),
+				None => true,
+			};
+			if can_reassign {
+				matched_clauses[asset] = Some(clause);
+				return true
+			}
+		}
+		false
 	}
 
-	pub fn get_reward(&self) -> StakingReward<Balance, CollectionId, ItemId> {
+	pub fn get_reward(&self) -> StakingReward<Balance, AssetId, CollectionId, ItemId> {
 		self.staking_reward.clone()
 	}
 
 	pub fn get_duration(&self) -> BlockNumber {
 		self.contract_block_duration
 	}
+
+	pub fn get_availability_period(&self) -> Option<BlockNumber> {
+		self.availability_period
+	}
+
+	pub fn get_early_withdrawal_penalty(&self) -> Option<Perbill> {
+		self.early_withdrawal_penalty
+	}
+
+	pub fn is_reward_streamed(&self) -> bool {
+		self.streamed_reward
+	}
+
+	pub fn get_taking_fee(&self) -> Balance {
+		self.taking_fee
+	}
 }
 
+/// Maximum number of conditions a single [`ContractClause::Any`] or [`ContractClause::All`] can
+/// combine.
+pub const MAXIMUM_CONDITIONS_PER_CLAUSE: u32 = 5;
+
+/// List of conditions combined by a [`ContractClause`].
+pub type ClauseConditions<AccountId, CollectionId, AttributeKey, AttributeValue> = BoundedVec<
+	ContractCondition<AccountId, CollectionId, AttributeKey, AttributeValue>,
+	ConstU32<MAXIMUM_CONDITIONS_PER_CLAUSE>,
+>;
+
+/// A single criterion checked against a staked asset, which a [`ContractClause`] requires alone
+/// or in combination with others. Attribute ranges are inclusive on both ends.
 #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
-pub enum ContractClause<AccountId, AttributeKey, AttributeValue>
+pub enum ContractCondition<AccountId, CollectionId, AttributeKey, AttributeValue>
 where
+	CollectionId: Debug + Copy + PartialEq,
 	AttributeKey: Debug + Clone + Encode + Decode + Eq + PartialEq + Ord + PartialOrd,
 	AttributeValue: Debug + Clone + Encode + Decode + Eq + PartialEq + Ord + PartialOrd,
 {
 	HasAttribute(AttributeNamespace<AccountId>, AttributeKey),
 	HasAttributeWithValue(AttributeNamespace<AccountId>, AttributeKey, AttributeValue),
+	AttributeGreaterThan(AttributeNamespace<AccountId>, AttributeKey, AttributeValue),
+	AttributeInRange(AttributeNamespace<AccountId>, AttributeKey, AttributeValue, AttributeValue),
+	InCollection(CollectionId),
 }
 
-impl<AccountId, AttributeKey, AttributeValue>
-	ContractClause<AccountId, AttributeKey, AttributeValue>
+impl<AccountId, CollectionId, AttributeKey, AttributeValue>
+	ContractCondition<AccountId, CollectionId, AttributeKey, AttributeValue>
 where
+	CollectionId: Debug + Copy + PartialEq,
 	AttributeKey: Debug + Clone + Encode + Decode + Eq + PartialEq + Ord + PartialOrd,
 	AttributeValue: Debug + Clone + Encode + Decode + Eq + PartialEq + Ord + PartialOrd,
 {
-	pub fn evaluate_for<NftInspector, CollectionId, ItemId>(
+	pub fn evaluate_for<NftInspector, ItemId>(
 		&self,
 		asset: &NftAddress<CollectionId, ItemId>,
 	) -> bool
 	where
 		NftInspector: Inspect<AccountId, CollectionId = CollectionId, ItemId = ItemId>,
-		CollectionId: Debug + Copy,
 		ItemId: Debug + Copy,
 	{
 		let NftAddress(collection_id, item_id) = asset;
+		let attribute = |ns: &AttributeNamespace<AccountId>, key: &AttributeKey| {
+			NftInspector::typed_attribute::<AttributeKey, AttributeValue>(
+				collection_id,
+				item_id,
+				ns,
+				key,
+			)
+		};
+
+		match self {
+			ContractCondition::HasAttribute(ns, key) => attribute(ns, key).is_some(),
+			ContractCondition::HasAttributeWithValue(ns, key, expected_value) =>
+				attribute(ns, key).map_or(false, |value| value.eq(expected_value)),
+			ContractCondition::AttributeGreaterThan(ns, key, lower_bound) =>
+				attribute(ns, key).map_or(false, |value| value.gt(lower_bound)),
+			ContractCondition::AttributeInRange(ns, key, min, max) =>
+				attribute(ns, key).map_or(false, |value| value.ge(min) && value.le(max)),
+			ContractCondition::InCollection(expected_collection_id) =>
+				collection_id.eq(expected_collection_id),
+		}
+	}
+}
+
+/// A criterion a staked asset must fulfill for a contract to be taken, either a single condition
+/// or a combination of them.
+#[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub enum ContractClause<AccountId, CollectionId, AttributeKey, AttributeValue>
+where
+	CollectionId: Debug + Copy + PartialEq,
+	AttributeKey: Debug + Clone + Encode + Decode + Eq + PartialEq + Ord + PartialOrd,
+	AttributeValue: Debug + Clone + Encode + Decode + Eq + PartialEq + Ord + PartialOrd,
+{
+	/// Fulfilled if the condition holds.
+	Condition(ContractCondition<AccountId, CollectionId, AttributeKey, AttributeValue>),
+	/// Fulfilled if at least one of the conditions holds.
+	Any(ClauseConditions<AccountId, CollectionId, AttributeKey, AttributeValue>),
+	/// Fulfilled if all of the conditions hold.
+	All(ClauseConditions<AccountId, CollectionId, AttributeKey, AttributeValue>),
+	/// Fulfilled if the condition does not hold.
+	Not(ContractCondition<AccountId, CollectionId, AttributeKey, AttributeValue>),
+}
 
+impl<AccountId, CollectionId, AttributeKey, AttributeValue>
+	From<ContractCondition<AccountId, CollectionId, AttributeKey, AttributeValue>>
+	for ContractClause<AccountId, CollectionId, AttributeKey, AttributeValue>
+where
+	CollectionId: Debug + Copy + PartialEq,
+	AttributeKey: Debug + Clone + Encode + Decode + Eq + PartialEq + Ord + PartialOrd,
+	AttributeValue: Debug + Clone + Encode + Decode + Eq + PartialEq + Ord + PartialOrd,
+{
+	fn from(
+		condition: ContractCondition<AccountId, CollectionId, AttributeKey, AttributeValue>,
+	) -> Self {
+		ContractClause::Condition(condition)
+	}
+}
+
+impl<AccountId, CollectionId, AttributeKey, AttributeValue>
+	ContractClause<AccountId, CollectionId, AttributeKey, AttributeValue>
+where
+	CollectionId: Debug + Copy + PartialEq,
+	AttributeKey: Debug + Clone + Encode + Decode + Eq + PartialEq + Ord + PartialOrd,
+	AttributeValue: Debug + Clone + Encode + Decode + Eq + PartialEq + Ord + PartialOrd,
+{
+	/// Checks whether the clause can only be fulfilled by assets from the given collections.
+	pub fn is_bound_to(&self, collections: &[CollectionId]) -> bool {
+		let is_bound = |condition: &ContractCondition<_, _, _, _>| matches!(condition, ContractCondition::InCollection(id) if collections.contains(id));
+		match self {
+			ContractClause::Condition(condition) => is_bound(condition),
+			ContractClause::All(conditions) => conditions.iter().any(is_bound),
+			ContractClause::Any(conditions) =>
+				!conditions.is_empty() && conditions.iter().all(is_bound),
+			ContractClause::Not(_) => false,
+		}
+	}
+
+	pub fn evaluate_for<NftInspector, ItemId>(
+		&self,
+		asset: &NftAddress<CollectionId, ItemId>,
+	) -> bool
+	where
+		NftInspector: Inspect<AccountId, CollectionId = CollectionId, ItemId = ItemId>,
+		ItemId: Debug + Copy,
+	{
+		let holds = |condition: &ContractCondition<_, _, _, _>| {
+			condition.evaluate_for::<NftInspector, ItemId>(asset)
+		};
 		match self {
-			ContractClause::HasAttribute(ns, key) => NftInspector::typed_attribute::<
-				AttributeKey,
-				AttributeValue,
-			>(collection_id, item_id, ns, key)
-			.is_some(),
-			ContractClause::HasAttributeWithValue(ns, key, expected_value) => {
-				if let Some(value) = NftInspector::typed_attribute::<AttributeKey, AttributeValue>(
-					collection_id,
-					item_id,
-					ns,
-					key,
-				) {
-					expected_value.eq(&value)
-				} else {
-					false
-				}
-			},
+			ContractClause::Condition(condition) => holds(condition),
+			ContractClause::Any(conditions) => conditions.iter().any(holds),
+			ContractClause::All(conditions) => conditions.iter().all(holds),
+			ContractClause::Not(condition) => !holds(condition),
 		}
 	}
 }
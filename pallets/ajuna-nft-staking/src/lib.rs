@@ -26,9 +26,14 @@ mod tests;
 mod benchmarking;
 
 pub mod contracts;
+pub mod migration;
+pub mod traits;
 pub mod weights;
 
-use sp_runtime::traits::{AccountIdConversion, Saturating};
+use sp_runtime::{
+	traits::{AccountIdConversion, Saturating, UniqueSaturatedInto, Zero},
+	PerThing, Perbill,
+};
 use sp_std::prelude::*;
 
 use frame_support::{
@@ -39,6 +44,7 @@ use frame_support::{
 
 pub use contracts::*;
 pub use pallet::*;
+pub use traits::*;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -49,7 +55,7 @@ pub mod pallet {
 		BalanceStatus, ExistenceRequirement,
 	};
 	use frame_system::pallet_prelude::*;
-	use sp_runtime::traits::{AtLeast32BitUnsigned, CheckedAdd, One};
+	use sp_runtime::traits::AtLeast32BitUnsigned;
 
 	pub(crate) type CollectionIdOf<T> = <T as Config>::CollectionId;
 	pub(crate) type ItemIdOf<T> = <T as Config>::ItemId;
@@ -61,11 +67,15 @@ pub mod pallet {
 	pub(crate) type BalanceOf<T> =
 		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 	pub(crate) type BlockNumberOf<T> = <T as frame_system::Config>::BlockNumber;
+	pub(crate) type AssetIdOf<T> =
+		<<T as Config>::RewardAssets as RewardAssets<AccountIdOf<T>, BalanceOf<T>>>::AssetId;
 
 	pub const MAXIMUM_CLAUSES_PER_CONTRACT: u32 = 10;
+	pub const MAXIMUM_EXPIRING_CONTRACTS_PER_BLOCK: u32 = 20;
 
 	pub(crate) type StakingContractOf<T> = StakingContract<
 		BalanceOf<T>,
+		AssetIdOf<T>,
 		CollectionIdOf<T>,
 		ItemIdOf<T>,
 		AccountIdOf<T>,
@@ -78,7 +88,13 @@ pub mod pallet {
 		StakedAssetsVec<CollectionIdOf<T>, ItemIdOf<T>, MAXIMUM_CLAUSES_PER_CONTRACT>;
 	pub(crate) type NftAddressOf<T> = NftAddress<CollectionIdOf<T>, ItemIdOf<T>>;
 	pub(crate) type StakingRewardOf<T> =
-		StakingReward<BalanceOf<T>, CollectionIdOf<T>, ItemIdOf<T>>;
+		StakingReward<BalanceOf<T>, AssetIdOf<T>, CollectionIdOf<T>, ItemIdOf<T>>;
+	pub(crate) type ContractClauseOf<T> = ContractClause<
+		AccountIdOf<T>,
+		CollectionIdOf<T>,
+		ContractAttributeKeyOf<T>,
+		ContractAttributeValueOf<T>,
+	>;
 
 	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Copy, Clone, Debug, Eq, PartialEq)]
 	pub enum PalletLockedState {
@@ -94,8 +110,31 @@ pub mod pallet {
 		}
 	}
 
+	pub const MAXIMUM_COLLECTIONS_PER_CREATOR: u32 = 10;
+
+	pub(crate) type CreatorCollectionsOf<T> =
+		BoundedVec<CollectionIdOf<T>, ConstU32<MAXIMUM_COLLECTIONS_PER_CREATOR>>;
+
+	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Copy, Clone, Debug, Eq, PartialEq)]
+	pub struct CreatorConfig {
+		/// Whether accounts other than the organizer must be registered to submit contracts,
+		/// in which case they can only submit contracts for their registered collections
+		pub registration_required: bool,
+		/// The share of a contract's taking fee paid to its creator, the rest goes to the treasury
+		pub fee_share: Perbill,
+	}
+
+	impl Default for CreatorConfig {
+		fn default() -> Self {
+			// Submission is open to any account passing `StakingOrigin`, so it is limited to
+			// registered creators until the organizer decides otherwise
+			Self { registration_required: true, fee_share: Perbill::zero() }
+		}
+	}
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(migration::STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -106,6 +145,9 @@ pub mod pallet {
 		/// The staking balance.
 		type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
 
+		/// The fungible assets, other than the native currency, contracts can be rewarded in.
+		type RewardAssets: RewardAssets<Self::AccountId, BalanceOf<Self>>;
+
 		/// Identifier for the collection of an Nft.
 		type CollectionId: Member
 			+ Parameter
@@ -135,11 +177,10 @@ pub mod pallet {
 			+ Parameter
 			+ Default
 			+ Copy
-			+ HasCompact
 			+ MaybeSerializeDeserialize
 			+ MaxEncodedLen
 			+ TypeInfo
-			+ AtLeast32BitUnsigned;
+			+ Incrementable;
 
 		/// Type that holds the specific configurations for an item.
 		type ItemConfig: Copy
@@ -160,6 +201,14 @@ pub mod pallet {
 		/// The origin which may interact with the staking logic.
 		type StakingOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
 
+		/// Lets accounts take contracts with NFTs lent to them, such as rented avatars.
+		type NftDelegation: NftDelegation<
+			Self::AccountId,
+			Self::CollectionId,
+			Self::ItemId,
+			Self::BlockNumber,
+		>;
+
 		/// The treasury's pallet id, used for deriving its sovereign account identifier.
 		#[pallet::constant]
 		type TreasuryPalletId: Get<PalletId>;
@@ -168,6 +217,11 @@ pub mod pallet {
 		#[pallet::constant]
 		type MinimumStakingTokenReward: Get<BalanceOf<Self>>;
 
+		/// The maximum number of contracts a single account can have open at the same time,
+		/// whether they have been taken or not.
+		#[pallet::constant]
+		type MaxOpenContractsPerCreator: Get<u32>;
+
 		/// The configuration for the contract Nft collection
 		#[pallet::constant]
 		type ContractCollectionConfig: Get<Self::CollectionConfig>;
@@ -177,7 +231,6 @@ pub mod pallet {
 		type ContractCollectionItemConfig: Get<Self::ItemConfig>;
 
 		/// Type of the contract attributes keys, used on contract condition evaluation
-		#[cfg(not(feature = "frame-benchmarking"))]
 		type ContractAttributeKey: Member
 			+ Encode
 			+ Decode
@@ -187,20 +240,7 @@ pub mod pallet {
 			+ TypeInfo
 			+ sp_std::fmt::Display;
 
-		/// Type of the contract attributes keys, used on contract condition evaluation
-		#[cfg(feature = "frame-benchmarking")]
-		type ContractAttributeKey: Member
-			+ Encode
-			+ Decode
-			+ Ord
-			+ PartialOrd
-			+ MaxEncodedLen
-			+ TypeInfo
-			+ sp_std::fmt::Display
-			+ From<u32>;
-
 		/// Type of the contract attributes values, used on contract condition evaluation
-		#[cfg(not(feature = "frame-benchmarking"))]
 		type ContractAttributeValue: Member
 			+ Encode
 			+ Decode
@@ -209,16 +249,13 @@ pub mod pallet {
 			+ MaxEncodedLen
 			+ TypeInfo;
 
-		/// Type of the contract attributes values, used on contract condition evaluation
-		#[cfg(feature = "frame-benchmarking")]
-		type ContractAttributeValue: Member
-			+ Encode
-			+ Decode
-			+ Ord
-			+ PartialOrd
-			+ MaxEncodedLen
-			+ TypeInfo
-			+ From<u64>;
+		/// Helper to build the contract attributes and items used in benchmarks
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<
+			Self::ContractAttributeKey,
+			Self::ContractAttributeValue,
+			Self::ItemId,
+		>;
 
 		/// The weight calculations
 		type WeightInfo: WeightInfo;
@@ -252,15 +289,70 @@ pub mod pallet {
 	pub type ContractStakedAssets<T: Config> =
 		StorageMap<_, Identity, ContractItemIdOf<T>, StakedAssetsVecOf<T>, OptionQuery>;
 
+	/// The holders of staked assets that were lent to the taker of their contract, who get them
+	/// back once the contract ends.
+	#[pallet::storage]
+	#[pallet::getter(fn staked_asset_lenders)]
+	pub type StakedAssetLenders<T: Config> =
+		StorageMap<_, Blake2_128Concat, NftAddressOf<T>, AccountIdOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn contract_creators)]
+	pub type ContractCreators<T: Config> =
+		StorageMap<_, Identity, ContractItemIdOf<T>, AccountIdOf<T>, OptionQuery>;
+
+	/// The block at which a contract that hasn't been taken yet expires.
+	#[pallet::storage]
+	#[pallet::getter(fn contract_availabilities)]
+	pub type ContractAvailabilities<T: Config> =
+		StorageMap<_, Identity, ContractItemIdOf<T>, T::BlockNumber, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn contract_expiries)]
+	pub type ContractExpiries<T: Config> = StorageMap<
+		_,
+		Identity,
+		T::BlockNumber,
+		BoundedVec<ContractItemIdOf<T>, ConstU32<MAXIMUM_EXPIRING_CONTRACTS_PER_BLOCK>>,
+		ValueQuery,
+	>;
+
+	/// The part of a streamed contract reward its taker has already claimed.
+	#[pallet::storage]
+	#[pallet::getter(fn contract_claimed_rewards)]
+	pub type ContractClaimedRewards<T: Config> =
+		StorageMap<_, Identity, ContractItemIdOf<T>, BalanceOf<T>, ValueQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn treasury_account)]
 	pub type TreasuryAccount<T: Config> = StorageValue<_, AccountIdOf<T>, OptionQuery>;
 
+	/// The taking fees collected by the treasury, kept apart from the reserved contract rewards.
+	#[pallet::storage]
+	#[pallet::getter(fn collected_fees)]
+	pub type CollectedFees<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn contract_collection_id)]
 	pub type ContractCollectionId<T: Config> =
 		StorageValue<_, CollectionIdOf<T>, ResultQuery<Error<T>::ContractCollectionNotSet>>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn creator_configuration)]
+	pub type CreatorConfiguration<T: Config> = StorageValue<_, CreatorConfig, ValueQuery>;
+
+	/// The accounts registered to submit contracts, along with the collections they can submit
+	/// contracts for.
+	#[pallet::storage]
+	#[pallet::getter(fn creators)]
+	pub type Creators<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, CreatorCollectionsOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn open_contracts)]
+	pub type OpenContracts<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, u32, ValueQuery>;
+
 	#[pallet::type_value]
 	pub fn DefaultContractId<T: Config>() -> ContractItemIdOf<T> {
 		ContractItemIdOf::<T>::default()
@@ -272,17 +364,28 @@ pub mod pallet {
 		StorageValue<_, ContractItemIdOf<T>, ValueQuery, DefaultContractId<T>>;
 
 	#[pallet::genesis_config]
-	pub struct GenesisConfig;
+	pub struct GenesisConfig<T: Config> {
+		/// The account managing the pallet, which the settings below depend on.
+		pub organizer: Option<T::AccountId>,
+		/// Whether to create the collection holding the staking contracts, owned by the organizer.
+		pub create_contract_collection: bool,
+		/// The funds the organizer moves into the treasury pot to reward contracts with.
+		pub treasury_funds: BalanceOf<T>,
+	}
 
 	#[cfg(feature = "std")]
-	impl Default for GenesisConfig {
+	impl<T: Config> Default for GenesisConfig<T> {
 		fn default() -> Self {
-			Self
+			Self {
+				organizer: None,
+				create_contract_collection: false,
+				treasury_funds: Zero::zero(),
+			}
 		}
 	}
 
 	#[pallet::genesis_build]
-	impl<T: Config> GenesisBuild<T> for GenesisConfig {
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
 		fn build(&self) {
 			// Create Treasury account
 			let account_id = <Pallet<T>>::treasury_account_id();
@@ -290,6 +393,36 @@ pub mod pallet {
 			if T::Currency::free_balance(&account_id) < min {
 				let _ = T::Currency::make_free_balance_be(&account_id, min);
 			}
+
+			if let Some(organizer) = &self.organizer {
+				Organizer::<T>::put(organizer);
+
+				if self.create_contract_collection {
+					let collection_id = T::NftHelper::create_collection(
+						organizer,
+						organizer,
+						&T::ContractCollectionConfig::get(),
+					)
+					.expect("Organizer should be able to create the contract collection");
+					ContractCollectionId::<T>::put(collection_id);
+				}
+
+				if !self.treasury_funds.is_zero() {
+					T::Currency::transfer(
+						organizer,
+						&account_id,
+						self.treasury_funds,
+						ExistenceRequirement::KeepAlive,
+					)
+					.and_then(|_| T::Currency::reserve(&account_id, self.treasury_funds))
+					.expect("Organizer should be able to fund the treasury");
+				}
+			} else {
+				assert!(
+					!self.create_contract_collection && self.treasury_funds.is_zero(),
+					"The contract collection and treasury funds require an organizer"
+				);
+			}
 		}
 	}
 
@@ -314,6 +447,32 @@ pub mod pallet {
 		},
 		/// The treasury has received additional funds
 		TreasuryFunded { funding_account: AccountIdOf<T>, funds: BalanceOf<T> },
+		/// A staking contract has been cancelled before being taken, by its creator or by the
+		/// organizer for contracts without a recorded creator
+		StakingContractCancelled { creator: AccountIdOf<T>, contract: ContractItemIdOf<T> },
+		/// A staking contract hasn't been taken in time, its reward returned to its creator
+		StakingContractExpired { creator: AccountIdOf<T>, contract: ContractItemIdOf<T> },
+		/// A staking contract has been left before finishing, with the part of the reward
+		/// left after the penalty, if any
+		StakingContractWithdrawn {
+			withdrawn_by: AccountIdOf<T>,
+			contract: ContractItemIdOf<T>,
+			reward: Option<StakingRewardOf<T>>,
+		},
+		/// Part of a streamed contract reward has been claimed by the contract taker
+		StakingRewardClaimed {
+			claimed_by: AccountIdOf<T>,
+			contract: ContractItemIdOf<T>,
+			reward: StakingRewardOf<T>,
+		},
+		/// The configuration of the contract creators has been set
+		CreatorConfigSet { config: CreatorConfig },
+		/// An account has been registered to submit contracts for the given collections
+		CreatorRegistered { creator: AccountIdOf<T>, collections: CreatorCollectionsOf<T> },
+		/// An account is no longer registered to submit contracts
+		CreatorUnregistered { creator: AccountIdOf<T> },
+		/// The organizer has claimed the taking fees collected by the treasury
+		FeesClaimed { organizer: AccountIdOf<T>, fees: BalanceOf<T> },
 	}
 
 	/// Error for the treasury pallet.
@@ -338,6 +497,10 @@ pub mod pallet {
 		/// The account that tried to take a staking contract didn't own one or more of the
 		/// staked assets.
 		StakedAssetNotOwned,
+		/// One or more of the staked assets are lent out, so only their renters can stake them.
+		StakedAssetLent,
+		/// One or more of the staked assets are lent to the taker for less than the contract runs.
+		StakedAssetLentTooShort,
 		/// The account that tried to create a contract didn't actually own it's reward.
 		ContractRewardNotOwned,
 		/// The account that tried to redeemed a contract didn't own it
@@ -350,6 +513,49 @@ pub mod pallet {
 		ContractStillActive,
 		/// The contract to be redeemed cannot be found
 		ContractNotFound,
+		/// The account that tried to cancel a contract didn't create it
+		NotContractCreator,
+		/// The contract has been taken, so it can no longer be cancelled
+		ContractInProgress,
+		/// The contract can no longer be taken
+		ContractExpired,
+		/// The contract availability period must be at least one block
+		InvalidAvailabilityPeriod,
+		/// Too many contracts expire at the same block
+		TooManyExpiringContracts,
+		/// The contract doesn't allow withdrawing before it finishes
+		EarlyWithdrawalNotAllowed,
+		/// The contract has already finished, so it can be redeemed instead
+		ContractAlreadyFinished,
+		/// The contract reward is handed over all at once when redeeming the contract
+		RewardNotStreamed,
+		/// The streamed contract reward has been claimed up to the current block
+		NoRewardToClaim,
+		/// The account isn't registered to submit contracts
+		CreatorNotRegistered,
+		/// The contract can be fulfilled by assets outside of the creator's collections
+		CollectionNotAllowed,
+		/// The account has too many open contracts to submit more
+		TooManyOpenContracts,
+		/// Contracts can't be rewarded in assets in this runtime
+		AssetRewardsUnavailable,
+		/// The treasury holds no collected taking fees to claim
+		NoFeesToClaim,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let expiring_contracts = ContractExpiries::<T>::take(now);
+			let expired_count = expiring_contracts.len() as u32;
+			for contract_id in expiring_contracts {
+				// contracts failing to expire remain untakeable, but can still be cancelled
+				let _ = frame_support::storage::with_storage_layer(|| {
+					Self::try_expiring_contract(&contract_id)
+				});
+			}
+			T::WeightInfo::expire_staking_contracts(expired_count)
+		}
 	}
 
 	#[pallet::call]
@@ -422,7 +628,12 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight(T::WeightInfo::submit_staking_contract_nft_reward())]
+		#[pallet::weight(match staking_contract.get_reward() {
+			StakingReward::Nft(_) => T::WeightInfo::submit_staking_contract_nft_reward(),
+			StakingReward::Assets(..) => T::WeightInfo::submit_staking_contract_asset_reward(),
+			StakingReward::Bundle(..) => T::WeightInfo::submit_staking_contract_bundle_reward(),
+			_ => T::WeightInfo::submit_staking_contract_token_reward(),
+		})]
 		#[pallet::call_index(4)]
 		pub fn submit_staking_contract(
 			origin: OriginFor<T>,
@@ -432,16 +643,53 @@ pub mod pallet {
 
 			let account = T::StakingOrigin::ensure_origin(origin)?;
 
-			match staking_contract.get_reward() {
+			Self::ensure_can_submit(&account, &staking_contract)?;
+
+			let contract_reward = staking_contract.get_reward();
+			ensure!(
+				!staking_contract.is_reward_streamed() ||
+					!contract_reward.fungible_amount().is_zero(),
+				Error::<T>::InvalidContractReward
+			);
+
+			match contract_reward {
 				StakingReward::Tokens(amount) => {
 					Self::try_transfer_funds_from_account_to_treasury(&account, amount)?;
 				},
 				StakingReward::Nft(address) => {
 					Self::try_taking_ownership_of_nft(&account, &address)?;
 				},
+				StakingReward::Assets(asset_id, amount) => {
+					ensure!(T::RewardAssets::is_available(), Error::<T>::AssetRewardsUnavailable);
+					ensure!(!amount.is_zero(), Error::<T>::InvalidContractReward);
+					T::RewardAssets::transfer(
+						asset_id,
+						&account,
+						&Self::treasury_account_id(),
+						amount,
+					)?;
+				},
+				StakingReward::Bundle(amount, addresses) => {
+					ensure!(
+						!amount.is_zero() || !addresses.is_empty(),
+						Error::<T>::InvalidContractReward
+					);
+					if !amount.is_zero() {
+						Self::try_transfer_funds_from_account_to_treasury(&account, amount)?;
+					}
+					for address in addresses.iter() {
+						Self::try_taking_ownership_of_nft(&account, address)?;
+					}
+				},
 			}
 
+			let availability_period = staking_contract.get_availability_period();
 			let contract_id = Self::try_creating_contract_nft_from(staking_contract)?;
+			ContractCreators::<T>::insert(contract_id, &account);
+			OpenContracts::<T>::mutate(&account, |count| count.saturating_inc());
+			if let Some(period) = availability_period {
+				Self::try_scheduling_contract_expiry(&contract_id, period)?;
+			}
 
 			Self::deposit_event(Event::<T>::StakingContractCreated {
 				creator: account,
@@ -467,17 +715,23 @@ pub mod pallet {
 			let contract: StakingContractOf<T> =
 				Self::active_contracts(contract_id).ok_or(Error::<T>::ContractNotFound)?;
 
+			if let Some(expiry) = Self::take_contract_expiry(&contract_id) {
+				ensure!(
+					<frame_system::Pallet<T>>::block_number() < expiry,
+					Error::<T>::ContractExpired
+				);
+			}
+
 			ensure!(
 				contract.evaluate_for::<T::NftHelper>(&staked_assets),
 				Error::<T>::ContractConditionsNotFulfilled
 			);
 
-			Self::try_transferring_staked_assets_ownership(
-				&staked_assets,
-				&account,
-				&Self::treasury_account_id(),
-				false,
-			)?;
+			Self::try_paying_taking_fee(&account, &contract_id, contract.get_taking_fee())?;
+
+			let runs_until =
+				<frame_system::Pallet<T>>::block_number().saturating_add(contract.get_duration());
+			Self::try_staking_assets(&staked_assets, &account, runs_until)?;
 
 			Self::try_taking_ownership_of_contract(
 				&contract_id,
@@ -494,7 +748,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight(T::WeightInfo::redeem_staking_contract_nft_reward())]
+		#[pallet::weight(T::WeightInfo::redeem_staking_contract_bundle_reward())]
 		#[pallet::call_index(6)]
 		pub fn redeem_staking_contract(
 			origin: OriginFor<T>,
@@ -509,16 +763,15 @@ pub mod pallet {
 			let staked_assets =
 				Self::contract_staked_assets(contract_id).ok_or(Error::<T>::ContractNotFound)?;
 
-			Self::try_transferring_staked_assets_ownership(
-				&staked_assets,
-				&Self::treasury_account_id(),
-				&account,
-				true,
-			)?;
+			Self::try_returning_staked_assets(&staked_assets, &account)?;
 
 			let contract_reward = Self::active_contracts(contract_id)
 				.ok_or(Error::<T>::ContractNotFound)?
 				.get_reward();
+			let claimed_amount = Self::contract_claimed_rewards(contract_id);
+			let contract_reward = contract_reward.clone().with_fungible_amount(
+				contract_reward.fungible_amount().saturating_sub(claimed_amount),
+			);
 
 			Self::try_handing_over_contract_reward_to(&account, &contract_reward)?;
 			Self::try_closing_redeemed_contract(&contract_id, &account)?;
@@ -531,6 +784,210 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		#[pallet::weight(T::WeightInfo::cancel_staking_contract_bundle_reward())]
+		#[pallet::call_index(7)]
+		pub fn cancel_staking_contract(
+			origin: OriginFor<T>,
+			contract_id: ContractItemIdOf<T>,
+		) -> DispatchResult {
+			Self::ensure_unlocked()?;
+
+			let account = T::StakingOrigin::ensure_origin(origin)?;
+
+			let contract_reward = Self::active_contracts(contract_id)
+				.ok_or(Error::<T>::ContractNotFound)?
+				.get_reward();
+			// Contracts submitted before creators were recorded can only be cancelled by the
+			// organizer, in which case their reward stays with the treasury
+			let creator = Self::contract_creators(contract_id);
+			ensure!(
+				creator.as_ref().or(Self::organizer().as_ref()) == Some(&account),
+				Error::<T>::NotContractCreator
+			);
+			ensure!(Self::contract_owners(contract_id).is_none(), Error::<T>::ContractInProgress);
+
+			Self::take_contract_expiry(&contract_id);
+			if creator.is_some() {
+				Self::try_handing_over_contract_reward_to(&account, &contract_reward)?;
+			}
+			Self::try_closing_untaken_contract(&contract_id)?;
+
+			Self::deposit_event(Event::<T>::StakingContractCancelled {
+				creator: account,
+				contract: contract_id,
+			});
+
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::withdraw_staking_contract_bundle_reward())]
+		#[pallet::call_index(8)]
+		pub fn withdraw_staking_contract(
+			origin: OriginFor<T>,
+			contract_id: ContractItemIdOf<T>,
+		) -> DispatchResult {
+			Self::ensure_unlocked()?;
+
+			let account = T::StakingOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				Self::contract_owners(contract_id).as_ref() == Some(&account),
+				Error::<T>::ContractNotOwned
+			);
+			let contract =
+				Self::active_contracts(contract_id).ok_or(Error::<T>::ContractNotFound)?;
+			let penalty = contract
+				.get_early_withdrawal_penalty()
+				.ok_or(Error::<T>::EarlyWithdrawalNotAllowed)?;
+			let contract_end =
+				Self::contract_durations(contract_id).ok_or(Error::<T>::ContractNotFound)?;
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() < contract_end,
+				Error::<T>::ContractAlreadyFinished
+			);
+			let creator = Self::contract_creators(contract_id);
+
+			let staked_assets =
+				Self::contract_staked_assets(contract_id).ok_or(Error::<T>::ContractNotFound)?;
+			Self::try_returning_staked_assets(&staked_assets, &account)?;
+
+			// Nfts are forfeited as a whole to the creator on any penalty, while the fungible part
+			// of the reward is split between both, minus what the taker has already claimed.
+			// Contracts submitted before creators were recorded forfeit to the treasury instead,
+			// which already holds the reward
+			let contract_reward = contract.get_reward();
+			let amount = contract_reward.fungible_amount();
+			let claimed_amount = Self::contract_claimed_rewards(contract_id);
+			let kept_amount =
+				amount.saturating_sub(penalty * amount).saturating_sub(claimed_amount);
+			let forfeited_amount =
+				amount.saturating_sub(claimed_amount).saturating_sub(kept_amount);
+			let (reward, forfeited_reward) = if penalty.is_zero() {
+				(Some(contract_reward.with_fungible_amount(kept_amount)), None)
+			} else {
+				(
+					contract_reward.clone().with_fungible_amount(kept_amount).without_nfts(),
+					Some(contract_reward.with_fungible_amount(forfeited_amount)),
+				)
+			};
+			if let Some(ref reward) = reward {
+				Self::try_handing_over_contract_reward_to(&account, reward)?;
+			}
+			if let (Some(ref creator), Some(ref forfeited_reward)) = (creator, forfeited_reward) {
+				Self::try_handing_over_contract_reward_to(creator, forfeited_reward)?;
+			}
+			Self::try_closing_redeemed_contract(&contract_id, &account)?;
+
+			Self::deposit_event(Event::<T>::StakingContractWithdrawn {
+				withdrawn_by: account,
+				contract: contract_id,
+				reward,
+			});
+
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::claim_staking_contract_reward())]
+		#[pallet::call_index(9)]
+		pub fn claim_staking_contract_reward(
+			origin: OriginFor<T>,
+			contract_id: ContractItemIdOf<T>,
+		) -> DispatchResult {
+			Self::ensure_unlocked()?;
+
+			let account = T::StakingOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				Self::contract_owners(contract_id).as_ref() == Some(&account),
+				Error::<T>::ContractNotOwned
+			);
+			let contract =
+				Self::active_contracts(contract_id).ok_or(Error::<T>::ContractNotFound)?;
+			ensure!(contract.is_reward_streamed(), Error::<T>::RewardNotStreamed);
+			let contract_end =
+				Self::contract_durations(contract_id).ok_or(Error::<T>::ContractNotFound)?;
+
+			let streamed_amount = Self::streamed_amount_of(&contract, contract_end);
+			let claimable_amount =
+				streamed_amount.saturating_sub(Self::contract_claimed_rewards(contract_id));
+			ensure!(!claimable_amount.is_zero(), Error::<T>::NoRewardToClaim);
+
+			let reward = contract
+				.get_reward()
+				.with_fungible_amount(claimable_amount)
+				.without_nfts()
+				.ok_or(Error::<T>::RewardNotStreamed)?;
+			Self::try_handing_over_contract_reward_to(&account, &reward)?;
+			ContractClaimedRewards::<T>::insert(contract_id, streamed_amount);
+
+			Self::deposit_event(Event::<T>::StakingRewardClaimed {
+				claimed_by: account,
+				contract: contract_id,
+				reward,
+			});
+
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::set_creator_config())]
+		#[pallet::call_index(10)]
+		pub fn set_creator_config(origin: OriginFor<T>, config: CreatorConfig) -> DispatchResult {
+			let _ = Self::ensure_organizer(origin)?;
+			CreatorConfiguration::<T>::put(config);
+			Self::deposit_event(Event::CreatorConfigSet { config });
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::register_creator())]
+		#[pallet::call_index(11)]
+		pub fn register_creator(
+			origin: OriginFor<T>,
+			creator: AccountIdOf<T>,
+			collections: CreatorCollectionsOf<T>,
+		) -> DispatchResult {
+			let _ = Self::ensure_organizer(origin)?;
+			Creators::<T>::insert(&creator, &collections);
+			Self::deposit_event(Event::CreatorRegistered { creator, collections });
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::unregister_creator())]
+		#[pallet::call_index(12)]
+		pub fn unregister_creator(origin: OriginFor<T>, creator: AccountIdOf<T>) -> DispatchResult {
+			let _ = Self::ensure_organizer(origin)?;
+			ensure!(Creators::<T>::contains_key(&creator), Error::<T>::CreatorNotRegistered);
+			Creators::<T>::remove(&creator);
+			Self::deposit_event(Event::CreatorUnregistered { creator });
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::claim_collected_fees())]
+		#[pallet::call_index(13)]
+		pub fn claim_collected_fees(origin: OriginFor<T>) -> DispatchResult {
+			let organizer = Self::ensure_organizer(origin)?;
+
+			// The treasury keeps its existential deposit, fees it can't spend stay collected
+			let treasury_account = Self::treasury_account_id();
+			let spendable = T::Currency::free_balance(&treasury_account).min(
+				T::Currency::total_balance(&treasury_account)
+					.saturating_sub(T::Currency::minimum_balance()),
+			);
+			let collected_fees = Self::collected_fees();
+			let fees = collected_fees.min(spendable);
+			ensure!(!fees.is_zero(), Error::<T>::NoFeesToClaim);
+
+			T::Currency::transfer(
+				&treasury_account,
+				&organizer,
+				fees,
+				ExistenceRequirement::KeepAlive,
+			)?;
+			CollectedFees::<T>::put(collected_fees.saturating_sub(fees));
+
+			Self::deposit_event(Event::FeesClaimed { organizer, fees });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -549,6 +1006,24 @@ pub mod pallet {
 			Ok(())
 		}
 
+		fn ensure_can_submit(
+			creator: &AccountIdOf<T>,
+			contract: &StakingContractOf<T>,
+		) -> DispatchResult {
+			if Self::creator_configuration().registration_required &&
+				Self::organizer().as_ref() != Some(creator)
+			{
+				let collections =
+					Self::creators(creator).ok_or(Error::<T>::CreatorNotRegistered)?;
+				ensure!(contract.is_bound_to(&collections), Error::<T>::CollectionNotAllowed);
+			}
+			ensure!(
+				Self::open_contracts(creator) < T::MaxOpenContractsPerCreator::get(),
+				Error::<T>::TooManyOpenContracts
+			);
+			Ok(())
+		}
+
 		/// The account identifier of the treasury pot.
 		pub fn treasury_account_id() -> AccountIdOf<T> {
 			if let Some(account) = Self::treasury_account() {
@@ -568,11 +1043,16 @@ pub mod pallet {
 			T::Currency::reserved_balance(&Self::treasury_account_id())
 		}
 
+		/// Return the amount of the given asset held by the treasury for contract rewards.
+		pub fn treasury_asset_reserve(asset_id: AssetIdOf<T>) -> BalanceOf<T> {
+			T::RewardAssets::balance(asset_id, &Self::treasury_account_id())
+		}
+
 		#[inline]
 		fn get_next_contract_id() -> ContractItemIdOf<T> {
 			let contract_id: ContractItemIdOf<T> = Self::next_contract_id();
 
-			if let Some(result) = contract_id.checked_add(&ContractItemIdOf::<T>::one()) {
+			if let Some(result) = contract_id.increment() {
 				NextContractId::<T>::put(result);
 			} else {
 				NextContractId::<T>::put(ContractItemIdOf::<T>::default())
@@ -622,21 +1102,46 @@ pub mod pallet {
 			}
 		}
 
+		/// Moves the assets staked by `staker` into the treasury, remembering the holders of those
+		/// lent to `staker` until at least `runs_until`.
 		#[inline]
-		fn try_transferring_staked_assets_ownership(
+		fn try_staking_assets(
 			assets: &StakedAssetsVecOf<T>,
-			from: &AccountIdOf<T>,
-			to: &AccountIdOf<T>,
-			skip_ownership_check: bool,
+			staker: &AccountIdOf<T>,
+			runs_until: T::BlockNumber,
 		) -> DispatchResult {
 			for asset in assets.iter() {
-				ensure!(
-					skip_ownership_check ||
-						(T::NftHelper::owner(&asset.0, &asset.1).as_ref() == Some(from)),
-					Error::<T>::StakedAssetNotOwned
-				);
+				let holder = T::NftHelper::owner(&asset.0, &asset.1)
+					.ok_or(Error::<T>::StakedAssetNotOwned)?;
+
+				if let Some(lent_until) =
+					T::NftDelegation::delegated_until(staker, &asset.0, &asset.1)
+				{
+					ensure!(runs_until <= lent_until, Error::<T>::StakedAssetLentTooShort);
+					StakedAssetLenders::<T>::insert(asset, holder);
+				} else {
+					ensure!(&holder == staker, Error::<T>::StakedAssetNotOwned);
+					ensure!(
+						!T::NftDelegation::is_lent(&asset.0, &asset.1),
+						Error::<T>::StakedAssetLent
+					);
+				}
 
-				T::NftHelper::transfer(&asset.0, &asset.1, to)?;
+				T::NftHelper::transfer(&asset.0, &asset.1, &Self::treasury_account_id())?;
+			}
+
+			Ok(())
+		}
+
+		/// Hands the staked assets back to their lenders, or else to `staker`.
+		#[inline]
+		fn try_returning_staked_assets(
+			assets: &StakedAssetsVecOf<T>,
+			staker: &AccountIdOf<T>,
+		) -> DispatchResult {
+			for asset in assets.iter() {
+				let holder = StakedAssetLenders::<T>::take(asset).unwrap_or_else(|| staker.clone());
+				T::NftHelper::transfer(&asset.0, &asset.1, &holder)?;
 			}
 
 			Ok(())
@@ -670,20 +1175,106 @@ pub mod pallet {
 		) -> DispatchResult {
 			match contract_reward {
 				StakingRewardOf::<T>::Tokens(amount) => {
-					ensure!(
-						Self::treasury_pot_reserve() >= *amount,
-						Error::<T>::TreasuryLacksFunds
-					);
-					T::Currency::repatriate_reserved(
-						&Self::treasury_account_id(),
-						account_to_reward,
-						*amount,
-						BalanceStatus::Free,
-					)?;
+					Self::try_handing_over_tokens_to(account_to_reward, *amount)?;
 				},
 				StakingRewardOf::<T>::Nft(asset) => {
 					T::NftHelper::transfer(&asset.0, &asset.1, account_to_reward)?;
 				},
+				StakingRewardOf::<T>::Assets(asset_id, amount) =>
+					if !amount.is_zero() {
+						ensure!(
+							Self::treasury_asset_reserve(*asset_id) >= *amount,
+							Error::<T>::TreasuryLacksFunds
+						);
+						T::RewardAssets::transfer(
+							*asset_id,
+							&Self::treasury_account_id(),
+							account_to_reward,
+							*amount,
+						)?;
+					},
+				StakingRewardOf::<T>::Bundle(amount, assets) => {
+					Self::try_handing_over_tokens_to(account_to_reward, *amount)?;
+					for asset in assets.iter() {
+						T::NftHelper::transfer(&asset.0, &asset.1, account_to_reward)?;
+					}
+				},
+			}
+
+			Ok(())
+		}
+
+		#[inline]
+		fn try_handing_over_tokens_to(
+			account_to_reward: &AccountIdOf<T>,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			ensure!(Self::treasury_pot_reserve() >= amount, Error::<T>::TreasuryLacksFunds);
+			T::Currency::repatriate_reserved(
+				&Self::treasury_account_id(),
+				account_to_reward,
+				amount,
+				BalanceStatus::Free,
+			)?;
+
+			Ok(())
+		}
+
+		/// The part of the contract's fungible reward streamed to its taker up to the current
+		/// block, given the block at which the contract finishes.
+		fn streamed_amount_of(
+			contract: &StakingContractOf<T>,
+			contract_end: T::BlockNumber,
+		) -> BalanceOf<T> {
+			let duration = contract.get_duration();
+			let remaining = contract_end.saturating_sub(<frame_system::Pallet<T>>::block_number());
+			let elapsed: u64 = duration.saturating_sub(remaining).unique_saturated_into();
+			let duration: u64 = duration.unique_saturated_into();
+
+			Perbill::from_rational(elapsed, duration)
+				.mul_floor(contract.get_reward().fungible_amount())
+		}
+
+		/// Charges the taker the contract's taking fee, sharing it between the contract creator and
+		/// the treasury. The treasury's share is left unreserved and tracked in
+		/// [`CollectedFees`], so that it never pays for contract rewards, until the organizer
+		/// claims it.
+		#[inline]
+		fn try_paying_taking_fee(
+			taker: &AccountIdOf<T>,
+			contract_id: &ContractItemIdOf<T>,
+			fee: BalanceOf<T>,
+		) -> DispatchResult {
+			if fee.is_zero() {
+				return Ok(())
+			}
+			ensure!(T::Currency::can_slash(taker, fee), Error::<T>::AccountLacksFunds);
+
+			let mut treasury_share = fee;
+			if let Some(creator) = Self::contract_creators(contract_id) {
+				let creator_share = Self::creator_configuration().fee_share * fee;
+				// A share too small to open the creator's account goes to the treasury instead
+				if T::Currency::total_balance(&creator).saturating_add(creator_share) >=
+					T::Currency::minimum_balance()
+				{
+					T::Currency::transfer(
+						taker,
+						&creator,
+						creator_share,
+						ExistenceRequirement::KeepAlive,
+					)?;
+					treasury_share = fee.saturating_sub(creator_share);
+				}
+			}
+
+			if !treasury_share.is_zero() {
+				T::Currency::transfer(
+					taker,
+					&Self::treasury_account_id(),
+					treasury_share,
+					ExistenceRequirement::KeepAlive,
+				)?;
+				CollectedFees::<T>::mutate(|fees| fees.saturating_accrue(treasury_share));
 			}
 
 			Ok(())
@@ -759,6 +1350,8 @@ pub mod pallet {
 			ContractStakedAssets::<T>::remove(contract_id);
 			ContractDurations::<T>::remove(contract_id);
 			ContractOwners::<T>::remove(contract_id);
+			Self::remove_contract_creator(contract_id);
+			ContractClaimedRewards::<T>::remove(contract_id);
 			ActiveContracts::<T>::remove(contract_id);
 
 			let contract_collection = ContractCollectionId::<T>::get()
@@ -767,5 +1360,81 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		#[inline]
+		fn try_closing_untaken_contract(contract_id: &ContractItemIdOf<T>) -> DispatchResult {
+			Self::remove_contract_creator(contract_id);
+			ActiveContracts::<T>::remove(contract_id);
+
+			let contract_collection = ContractCollectionId::<T>::get()
+				.expect("Contract collection id should not be empty");
+			T::NftHelper::burn(
+				&contract_collection,
+				contract_id,
+				Some(&Self::treasury_account_id()),
+			)?;
+
+			Ok(())
+		}
+
+		/// Removes the creator of a contract being closed, freeing one of their open contracts.
+		#[inline]
+		fn remove_contract_creator(contract_id: &ContractItemIdOf<T>) {
+			if let Some(creator) = ContractCreators::<T>::take(contract_id) {
+				let open_contracts = Self::open_contracts(&creator).saturating_sub(1);
+				if open_contracts.is_zero() {
+					OpenContracts::<T>::remove(&creator);
+				} else {
+					OpenContracts::<T>::insert(&creator, open_contracts);
+				}
+			}
+		}
+
+		#[inline]
+		fn try_scheduling_contract_expiry(
+			contract_id: &ContractItemIdOf<T>,
+			availability_period: T::BlockNumber,
+		) -> DispatchResult {
+			ensure!(!availability_period.is_zero(), Error::<T>::InvalidAvailabilityPeriod);
+
+			let expiry =
+				<frame_system::Pallet<T>>::block_number().saturating_add(availability_period);
+			ContractExpiries::<T>::try_mutate(expiry, |contract_ids| {
+				contract_ids.try_push(*contract_id)
+			})
+			.map_err(|_| Error::<T>::TooManyExpiringContracts)?;
+			ContractAvailabilities::<T>::insert(contract_id, expiry);
+
+			Ok(())
+		}
+
+		/// Stops a contract from expiring, returning the block it would have expired at.
+		#[inline]
+		fn take_contract_expiry(contract_id: &ContractItemIdOf<T>) -> Option<T::BlockNumber> {
+			let expiry = ContractAvailabilities::<T>::take(contract_id)?;
+			ContractExpiries::<T>::mutate(expiry, |contract_ids| {
+				contract_ids.retain(|id| id != contract_id)
+			});
+			Some(expiry)
+		}
+
+		fn try_expiring_contract(contract_id: &ContractItemIdOf<T>) -> DispatchResult {
+			let contract_reward = Self::active_contracts(contract_id)
+				.ok_or(Error::<T>::ContractNotFound)?
+				.get_reward();
+			let creator =
+				Self::contract_creators(contract_id).ok_or(Error::<T>::ContractNotFound)?;
+
+			ContractAvailabilities::<T>::remove(contract_id);
+			Self::try_handing_over_contract_reward_to(&creator, &contract_reward)?;
+			Self::try_closing_untaken_contract(contract_id)?;
+
+			Self::deposit_event(Event::<T>::StakingContractExpired {
+				creator,
+				contract: *contract_id,
+			});
+
+			Ok(())
+		}
 	}
 }
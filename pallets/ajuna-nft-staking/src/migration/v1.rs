@@ -0,0 +1,101 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_support::traits::tokens::AttributeNamespace;
+
+#[derive(Decode)]
+pub enum OldContractClause<T: Config> {
+	HasAttribute(AttributeNamespace<AccountIdOf<T>>, ContractAttributeKeyOf<T>),
+	HasAttributeWithValue(
+		AttributeNamespace<AccountIdOf<T>>,
+		ContractAttributeKeyOf<T>,
+		ContractAttributeValueOf<T>,
+	),
+}
+
+impl<T: Config> OldContractClause<T> {
+	fn migrate_to_v1(self) -> ContractClauseOf<T> {
+		match self {
+			OldContractClause::HasAttribute(ns, key) =>
+				ContractCondition::HasAttribute(ns, key).into(),
+			OldContractClause::HasAttributeWithValue(ns, key, value) =>
+				ContractCondition::HasAttributeWithValue(ns, key, value).into(),
+		}
+	}
+}
+
+#[derive(Decode)]
+pub struct OldStakingContract<T: Config> {
+	pub staking_reward: StakingRewardOf<T>,
+	pub contract_clauses: BoundedVec<OldContractClause<T>, ConstU32<MAXIMUM_CLAUSES_PER_CONTRACT>>,
+	pub contract_block_duration: BlockNumberOf<T>,
+}
+
+impl<T: Config> OldStakingContract<T> {
+	fn migrate_to_v1(self) -> StakingContractOf<T> {
+		self.contract_clauses.into_iter().fold(
+			StakingContractOf::<T>::new(self.staking_reward, self.contract_block_duration),
+			|contract, clause| contract.with_clause(clause.migrate_to_v1()),
+		)
+	}
+}
+
+/// Translates the stored contracts to the current format. Creators of the migrated contracts were
+/// never recorded and can't be recovered, so those contracts are left without one in
+/// `ContractCreators`. The organizer can cancel them, and their penalties go to the treasury.
+pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let current_version = Pallet::<T>::current_storage_version();
+		let onchain_version = Pallet::<T>::on_chain_storage_version();
+		if onchain_version == 0 && current_version == 1 {
+			let mut translated = 0_u64;
+			ActiveContracts::<T>::translate::<OldStakingContract<T>, _>(|_, old_contract| {
+				translated.saturating_inc();
+				Some(old_contract.migrate_to_v1())
+			});
+
+			current_version.put::<Pallet<T>>();
+			log::info!(target: LOG_TARGET, "Migrated {} staking contracts", translated);
+			log::info!(target: LOG_TARGET, "Upgraded storage to version {:?}", current_version);
+			T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+		} else {
+			log::info!(
+				target: LOG_TARGET,
+				"Migration did not execute. This probably should be removed"
+			);
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		Ok((ActiveContracts::<T>::iter_keys().count() as u32).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		let contracts = u32::decode(&mut &state[..]).map_err(|_| "Cannot decode contract count")?;
+		assert_eq!(Pallet::<T>::on_chain_storage_version(), 1);
+		assert_eq!(ActiveContracts::<T>::iter_values().count() as u32, contracts);
+		assert!(ActiveContracts::<T>::iter_values().all(|contract| {
+			contract.get_availability_period().is_none() &&
+				contract.get_early_withdrawal_penalty().is_none()
+		}));
+		Ok(())
+	}
+}
@@ -14,10 +14,12 @@
 // You should have received a copy of the GNU Affero General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{self as pallet_nft_staking, *};
+use crate::{self as pallet_nft_staking, traits::FungiblesRewards, *};
 use frame_support::{
 	parameter_types,
-	traits::{tokens::nonfungibles_v2::Create, AsEnsureOriginWithArg, ConstU16, ConstU64, Hooks},
+	traits::{
+		tokens::nonfungibles_v2::Create, AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64, Hooks,
+	},
 };
 use frame_system::{
 	mocking::{MockBlock, MockUncheckedExtrinsic},
@@ -34,6 +36,7 @@ pub type MockAccountId = u32;
 pub type MockBlockNumber = u64;
 pub type MockBalance = u64;
 pub type MockIndex = u64;
+pub type MockAssetId = u32;
 
 pub const ALICE: MockAccountId = 1;
 pub const BOB: MockAccountId = 2;
@@ -48,6 +51,7 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system,
 		Balances: pallet_balances,
+		Assets: pallet_assets,
 		Nft: pallet_nfts,
 		NftStake: pallet_nft_staking,
 	}
@@ -96,6 +100,27 @@ impl pallet_balances::Config for Test {
 	type ReserveIdentifier = [u8; 8];
 }
 
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = MockBalance;
+	type RemoveItemsLimit = ConstU32<1_000>;
+	type AssetId = MockAssetId;
+	type AssetIdParameter = MockAssetId;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<MockAccountId>>;
+	type ForceOrigin = EnsureRoot<MockAccountId>;
+	type AssetDeposit = ConstU64<1>;
+	type AssetAccountDeposit = ConstU64<1>;
+	type MetadataDepositBase = ConstU64<1>;
+	type MetadataDepositPerByte = ConstU64<1>;
+	type ApprovalDeposit = ConstU64<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+}
+
 pub type MockCollectionId = u32;
 pub type MockItemId = u128;
 
@@ -145,6 +170,8 @@ impl pallet_nfts::Config for Test {
 parameter_types! {
 	pub const TreasuryPalletId: PalletId = PalletId(*b"aj/nftst");
 	pub const MinimumStakingTokenReward: MockBalance = 100;
+	pub static MaxOpenContractsPerCreator: u32 = 50;
+	pub static Delegations: Vec<(MockAccountId, NftAddressOf<Test>, MockBlockNumber)> = vec![];
 	pub ContractCollectionConfig: CollectionConfig = CollectionConfig::default();
 	pub ContractCollectionItemConfig: pallet_nfts::ItemConfig = pallet_nfts::ItemConfig::default();
 }
@@ -155,27 +182,60 @@ pub type CollectionConfig =
 pub type ContractAttributeKey = u32;
 pub type ContractAttributeValue = u64;
 
+/// Lends the items in `Delegations` to their renters until the given block.
+pub struct MockDelegation;
+
+impl NftDelegation<MockAccountId, MockCollectionId, MockItemId, MockBlockNumber>
+	for MockDelegation
+{
+	fn delegated_until(
+		who: &MockAccountId,
+		collection_id: &MockCollectionId,
+		item_id: &MockItemId,
+	) -> Option<MockBlockNumber> {
+		Delegations::get()
+			.into_iter()
+			.find_map(|(renter, NftAddress(collection, item), until)| {
+				(renter == *who && collection == *collection_id && item == *item_id)
+					.then_some(until)
+			})
+	}
+
+	fn is_lent(collection_id: &MockCollectionId, item_id: &MockItemId) -> bool {
+		Delegations::get().into_iter().any(|(_, NftAddress(collection, item), _)| {
+			collection == *collection_id && item == *item_id
+		})
+	}
+}
+
 impl pallet_nft_staking::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
+	type RewardAssets = FungiblesRewards<Assets>;
 	type CollectionId = MockCollectionId;
 	type CollectionConfig = CollectionConfig;
 	type ItemId = MockItemId;
 	type ItemConfig = pallet_nfts::ItemConfig;
 	type NftHelper = Nft;
 	type StakingOrigin = EnsureSigned<MockAccountId>;
+	type NftDelegation = MockDelegation;
 	type TreasuryPalletId = TreasuryPalletId;
 	type MinimumStakingTokenReward = MinimumStakingTokenReward;
+	type MaxOpenContractsPerCreator = MaxOpenContractsPerCreator;
 	type ContractCollectionConfig = ContractCollectionConfig;
 	type ContractCollectionItemConfig = ContractCollectionItemConfig;
 	type ContractAttributeKey = ContractAttributeKey;
 	type ContractAttributeValue = ContractAttributeValue;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 	type WeightInfo = ();
 }
 
 pub struct ExtBuilder {
 	balances: Vec<(MockAccountId, MockBalance)>,
 	create_collection: bool,
+	creator_config: CreatorConfig,
+	nft_stake: pallet_nft_staking::GenesisConfig<Test>,
 }
 
 impl Default for ExtBuilder {
@@ -189,6 +249,12 @@ impl Default for ExtBuilder {
 				(CHARLIE, accounts_balance),
 			],
 			create_collection: true,
+			// Most tests submit contracts from accounts that aren't registered creators
+			creator_config: CreatorConfig {
+				registration_required: false,
+				fee_share: Default::default(),
+			},
+			nft_stake: Default::default(),
 		}
 	}
 }
@@ -204,15 +270,27 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn creator_config(mut self, creator_config: CreatorConfig) -> Self {
+		self.creator_config = creator_config;
+		self
+	}
+
+	pub fn nft_stake(mut self, nft_stake: pallet_nft_staking::GenesisConfig<Test>) -> Self {
+		self.nft_stake = nft_stake;
+		self
+	}
+
 	pub fn build(self) -> sp_io::TestExternalities {
 		let config = GenesisConfig {
 			system: Default::default(),
 			balances: BalancesConfig { balances: self.balances },
-			nft_stake: Default::default(),
+			assets: Default::default(),
+			nft_stake: self.nft_stake,
 		};
 
 		let mut ext: sp_io::TestExternalities = config.build_storage().unwrap().into();
 		ext.execute_with(|| System::set_block_number(1));
+		ext.execute_with(|| CreatorConfiguration::<Test>::put(self.creator_config));
 		if self.create_collection {
 			ext.execute_with(|| {
 				let account_id = <Pallet<Test>>::treasury_account_id();
@@ -17,12 +17,54 @@
 use crate::{mock::*, *};
 use frame_support::{
 	assert_noop, assert_ok,
-	traits::tokens::{
-		nonfungibles_v2::{Create, Mutate},
-		AttributeNamespace,
+	traits::{
+		tokens::{
+			nonfungibles_v2::{Create, Inspect, Mutate},
+			AttributeNamespace,
+		},
+		Currency, ReservableCurrency,
 	},
 };
-use sp_runtime::bounded_vec;
+use sp_runtime::{bounded_vec, Perbill};
+
+mod genesis {
+	use super::*;
+
+	#[test]
+	fn genesis_sets_up_the_organizer_contract_collection_and_treasury() {
+		let funds = 1_000;
+		ExtBuilder::default()
+			.create_collection(false)
+			.nft_stake(crate::GenesisConfig {
+				organizer: Some(ALICE),
+				create_contract_collection: true,
+				treasury_funds: funds,
+			})
+			.build()
+			.execute_with(|| {
+				assert_eq!(NftStake::organizer(), Some(ALICE));
+				let collection_id = NftStake::contract_collection_id().unwrap();
+				assert_eq!(
+					<Nft as Inspect<MockAccountId>>::collection_owner(&collection_id),
+					Some(ALICE)
+				);
+				assert_eq!(NftStake::treasury_pot_reserve(), funds);
+			});
+	}
+
+	#[test]
+	#[should_panic(expected = "The contract collection and treasury funds require an organizer")]
+	fn genesis_rejects_a_contract_collection_without_organizer() {
+		ExtBuilder::default()
+			.create_collection(false)
+			.nft_stake(crate::GenesisConfig {
+				organizer: None,
+				create_contract_collection: true,
+				treasury_funds: 0,
+			})
+			.build();
+	}
+}
 
 mod organizer {
 	use super::*;
@@ -232,8 +274,9 @@ mod submit_staking_contract {
 			.execute_with(|| {
 				let reward = 1_000;
 				let contract_reward = StakingRewardOf::<Test>::Tokens(reward);
-				let contract = StakingContractOf::<Test>::new(contract_reward, 10)
-					.with_clause(ContractClause::HasAttribute(AttributeNamespace::Pallet, 10_u32));
+				let contract = StakingContractOf::<Test>::new(contract_reward, 10).with_clause(
+					ContractCondition::HasAttribute(AttributeNamespace::Pallet, 10_u32),
+				);
 				let treasury_account = NftStake::treasury_account().unwrap();
 				let base_reserves = Balances::reserved_balance(treasury_account);
 
@@ -274,7 +317,7 @@ mod submit_staking_contract {
 
 			let contract_reward = StakingRewardOf::<Test>::Nft(nft_addr.clone());
 			let contract = StakingContractOf::<Test>::new(contract_reward, 10)
-				.with_clause(ContractClause::HasAttribute(AttributeNamespace::Pallet, 10_u32));
+				.with_clause(ContractCondition::HasAttribute(AttributeNamespace::Pallet, 10_u32));
 
 			let expected_contract_id = NftStake::next_contract_id();
 
@@ -311,8 +354,9 @@ mod submit_staking_contract {
 			.execute_with(|| {
 				let reward = 2_000_000;
 				let contract_reward = StakingRewardOf::<Test>::Tokens(reward);
-				let contract = StakingContractOf::<Test>::new(contract_reward, 10)
-					.with_clause(ContractClause::HasAttribute(AttributeNamespace::Pallet, 10_u32));
+				let contract = StakingContractOf::<Test>::new(contract_reward, 10).with_clause(
+					ContractCondition::HasAttribute(AttributeNamespace::Pallet, 10_u32),
+				);
 
 				assert_noop!(
 					NftStake::submit_staking_contract(RuntimeOrigin::signed(account), contract),
@@ -330,7 +374,7 @@ mod submit_staking_contract {
 
 			let contract_reward = StakingRewardOf::<Test>::Nft(nft_addr);
 			let contract = StakingContractOf::<Test>::new(contract_reward, 10)
-				.with_clause(ContractClause::HasAttribute(AttributeNamespace::Pallet, 10_u32));
+				.with_clause(ContractCondition::HasAttribute(AttributeNamespace::Pallet, 10_u32));
 
 			assert_noop!(
 				NftStake::submit_staking_contract(RuntimeOrigin::signed(account), contract),
@@ -349,7 +393,7 @@ mod submit_staking_contract {
 
 			let contract_reward = StakingRewardOf::<Test>::Nft(nft_addr);
 			let contract = StakingContractOf::<Test>::new(contract_reward, 10)
-				.with_clause(ContractClause::HasAttribute(AttributeNamespace::Pallet, 10_u32));
+				.with_clause(ContractCondition::HasAttribute(AttributeNamespace::Pallet, 10_u32));
 
 			assert_noop!(
 				NftStake::submit_staking_contract(RuntimeOrigin::signed(account), contract),
@@ -372,7 +416,7 @@ mod take_staking_contract {
 				StakingRewardOf::<Test>::Tokens(1_000),
 				contract_duration,
 			)
-			.with_clause(ContractClause::HasAttribute(AttributeNamespace::Pallet, attr_key));
+			.with_clause(ContractCondition::HasAttribute(AttributeNamespace::Pallet, attr_key));
 			let expected_contract_id = NftStake::next_contract_id();
 			let contract_addr = create_and_submit_random_staking_contract_nft(account, contract);
 
@@ -429,13 +473,16 @@ mod take_staking_contract {
 				StakingRewardOf::<Test>::Tokens(1_000),
 				contract_duration,
 			)
-			.with_clause(ContractClause::HasAttribute(AttributeNamespace::Pallet, attr_key_set[0]))
-			.with_clause(ContractClause::HasAttributeWithValue(
+			.with_clause(ContractCondition::HasAttribute(
+				AttributeNamespace::Pallet,
+				attr_key_set[0],
+			))
+			.with_clause(ContractCondition::HasAttributeWithValue(
 				AttributeNamespace::Pallet,
 				attr_key_set[1],
 				attr_value_set[1],
 			))
-			.with_clause(ContractClause::HasAttributeWithValue(
+			.with_clause(ContractCondition::HasAttributeWithValue(
 				AttributeNamespace::Pallet,
 				attr_key_set[2],
 				attr_value_set[2],
@@ -479,7 +526,7 @@ mod take_staking_contract {
 			let attr_key = 10_u32;
 			let contract =
 				StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), 10)
-					.with_clause(ContractClause::HasAttribute(
+					.with_clause(ContractCondition::HasAttribute(
 						AttributeNamespace::Pallet,
 						attr_key,
 					));
@@ -531,7 +578,7 @@ mod take_staking_contract {
 			let attr_key = 10_u32;
 			let contract =
 				StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), 10)
-					.with_clause(ContractClause::HasAttribute(
+					.with_clause(ContractCondition::HasAttribute(
 						AttributeNamespace::Pallet,
 						attr_key,
 					));
@@ -572,7 +619,7 @@ mod take_staking_contract {
 			let attr_key = 10_u32;
 			let contract =
 				StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), 10)
-					.with_clause(ContractClause::HasAttribute(
+					.with_clause(ContractCondition::HasAttribute(
 						AttributeNamespace::Pallet,
 						attr_key,
 					));
@@ -609,13 +656,16 @@ mod take_staking_contract {
 				StakingRewardOf::<Test>::Tokens(5_000),
 				contract_duration,
 			)
-			.with_clause(ContractClause::HasAttribute(AttributeNamespace::Pallet, attr_key_set[0]))
-			.with_clause(ContractClause::HasAttributeWithValue(
+			.with_clause(ContractCondition::HasAttribute(
+				AttributeNamespace::Pallet,
+				attr_key_set[0],
+			))
+			.with_clause(ContractCondition::HasAttributeWithValue(
 				AttributeNamespace::Pallet,
 				attr_key_set[1],
 				attr_value_set[1],
 			))
-			.with_clause(ContractClause::HasAttributeWithValue(
+			.with_clause(ContractCondition::HasAttributeWithValue(
 				AttributeNamespace::Pallet,
 				attr_key_set[2],
 				attr_value_set[2],
@@ -654,7 +704,7 @@ mod take_staking_contract {
 			let attr_key = 10_u32;
 			let contract =
 				StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), 10)
-					.with_clause(ContractClause::HasAttribute(
+					.with_clause(ContractCondition::HasAttribute(
 						AttributeNamespace::Pallet,
 						attr_key,
 					));
@@ -684,6 +734,304 @@ mod take_staking_contract {
 	}
 }
 
+mod lent_assets {
+	use super::*;
+
+	fn submit_contract(duration: MockBlockNumber) -> ContractItemIdOf<Test> {
+		let contract =
+			StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), duration)
+				.with_clause(ContractCondition::HasAttribute(AttributeNamespace::Pallet, 10));
+		create_and_submit_random_staking_contract_nft(ALICE, contract).1
+	}
+
+	fn create_lent_nft(
+		lender: MockAccountId,
+		renter: MockAccountId,
+		until: MockBlockNumber,
+	) -> NftAddressOf<Test> {
+		let nft = create_random_mock_nft_for(lender);
+		set_attribute_for_nft(&nft, 10, 42_u64);
+		Delegations::set(vec![(renter, nft.clone(), until)]);
+		nft
+	}
+
+	#[test]
+	fn renters_stake_lent_assets_which_return_to_their_lenders() {
+		ExtBuilder::default().build().execute_with(|| {
+			let contract_duration = 10;
+			let contract_id = submit_contract(contract_duration);
+			let current_block = <frame_system::Pallet<Test>>::block_number();
+			let nft = create_lent_nft(CHARLIE, BOB, current_block + contract_duration);
+
+			assert_ok!(NftStake::take_staking_contract(
+				RuntimeOrigin::signed(BOB),
+				contract_id,
+				bounded_vec![nft.clone()],
+			));
+			assert_eq!(Nft::owner(nft.0, nft.1), Some(NftStake::treasury_account_id()));
+			assert_eq!(NftStake::staked_asset_lenders(&nft), Some(CHARLIE));
+
+			run_to_block(current_block + contract_duration);
+			assert_ok!(NftStake::redeem_staking_contract(RuntimeOrigin::signed(BOB), contract_id));
+			assert_eq!(Nft::owner(nft.0, nft.1), Some(CHARLIE));
+			assert_eq!(NftStake::staked_asset_lenders(&nft), None);
+		});
+	}
+
+	#[test]
+	fn withdrawing_returns_lent_assets_to_their_lenders() {
+		ExtBuilder::default().build().execute_with(|| {
+			let contract_duration = 10;
+			let contract = StakingContractOf::<Test>::new(
+				StakingRewardOf::<Test>::Tokens(1_000),
+				contract_duration,
+			)
+			.with_clause(ContractCondition::HasAttribute(AttributeNamespace::Pallet, 10))
+			.with_early_withdrawal_penalty(Perbill::zero());
+			let contract_id = create_and_submit_random_staking_contract_nft(ALICE, contract).1;
+			let current_block = <frame_system::Pallet<Test>>::block_number();
+			let nft = create_lent_nft(CHARLIE, BOB, current_block + contract_duration);
+
+			assert_ok!(NftStake::take_staking_contract(
+				RuntimeOrigin::signed(BOB),
+				contract_id,
+				bounded_vec![nft.clone()],
+			));
+			assert_ok!(NftStake::withdraw_staking_contract(
+				RuntimeOrigin::signed(BOB),
+				contract_id
+			));
+			assert_eq!(Nft::owner(nft.0, nft.1), Some(CHARLIE));
+			assert_eq!(NftStake::staked_asset_lenders(&nft), None);
+		});
+	}
+
+	#[test]
+	fn rejects_contracts_outlasting_the_loan() {
+		ExtBuilder::default().build().execute_with(|| {
+			let contract_duration = 10;
+			let contract_id = submit_contract(contract_duration);
+			let current_block = <frame_system::Pallet<Test>>::block_number();
+			let nft = create_lent_nft(CHARLIE, BOB, current_block + contract_duration - 1);
+
+			assert_noop!(
+				NftStake::take_staking_contract(
+					RuntimeOrigin::signed(BOB),
+					contract_id,
+					bounded_vec![nft],
+				),
+				Error::<Test>::StakedAssetLentTooShort
+			);
+		});
+	}
+
+	#[test]
+	fn lenders_cannot_stake_lent_assets() {
+		ExtBuilder::default().build().execute_with(|| {
+			let contract_id = submit_contract(10);
+			let nft = create_lent_nft(CHARLIE, BOB, 100);
+
+			assert_noop!(
+				NftStake::take_staking_contract(
+					RuntimeOrigin::signed(CHARLIE),
+					contract_id,
+					bounded_vec![nft],
+				),
+				Error::<Test>::StakedAssetLent
+			);
+		});
+	}
+
+	#[test]
+	fn others_cannot_stake_assets_lent_to_someone_else() {
+		ExtBuilder::default().build().execute_with(|| {
+			let contract_id = submit_contract(10);
+			let nft = create_lent_nft(CHARLIE, BOB, 100);
+
+			assert_noop!(
+				NftStake::take_staking_contract(
+					RuntimeOrigin::signed(ALICE),
+					contract_id,
+					bounded_vec![nft],
+				),
+				Error::<Test>::StakedAssetNotOwned
+			);
+		});
+	}
+}
+
+mod contract_clauses {
+	use super::*;
+
+	const RARITY: u32 = 10;
+	const LEVEL: u32 = 15;
+
+	fn evaluate(
+		contract: &StakingContractOf<Test>,
+		staked_assets: Vec<NftAddressOf<Test>>,
+	) -> bool {
+		let staked_assets: StakedAssetsVecOf<Test> = staked_assets.try_into().unwrap();
+		contract.evaluate_for::<<Test as Config>::NftHelper>(&staked_assets)
+	}
+
+	fn create_nft_with(owner: MockAccountId, attributes: &[(u32, u64)]) -> NftAddressOf<Test> {
+		let nft = create_random_mock_nft_for(owner);
+		for (key, value) in attributes {
+			set_attribute_for_nft(&nft, *key, *value);
+		}
+		nft
+	}
+
+	fn contract_with(clauses: Vec<impl Into<ContractClauseOf<Test>>>) -> StakingContractOf<Test> {
+		clauses.into_iter().fold(
+			StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), 10),
+			|contract, clause| contract.with_clause(clause),
+		)
+	}
+
+	#[test]
+	fn attribute_greater_than_works() {
+		ExtBuilder::default().build().execute_with(|| {
+			let contract = contract_with(vec![ContractCondition::AttributeGreaterThan(
+				AttributeNamespace::Pallet,
+				RARITY,
+				3,
+			)]);
+
+			assert!(evaluate(&contract, vec![create_nft_with(BOB, &[(RARITY, 4)])]));
+			assert!(!evaluate(&contract, vec![create_nft_with(BOB, &[(RARITY, 3)])]));
+			assert!(!evaluate(&contract, vec![create_nft_with(BOB, &[(LEVEL, 4)])]));
+		});
+	}
+
+	#[test]
+	fn attribute_in_range_works() {
+		ExtBuilder::default().build().execute_with(|| {
+			let contract = contract_with(vec![ContractCondition::AttributeInRange(
+				AttributeNamespace::Pallet,
+				LEVEL,
+				5,
+				10,
+			)]);
+
+			for (level, expected) in [(4, false), (5, true), (7, true), (10, true), (11, false)] {
+				assert_eq!(
+					evaluate(&contract, vec![create_nft_with(BOB, &[(LEVEL, level)])]),
+					expected
+				);
+			}
+		});
+	}
+
+	#[test]
+	fn in_collection_works() {
+		ExtBuilder::default().build().execute_with(|| {
+			let nft = create_random_mock_nft_for(BOB);
+			let other_nft = create_random_mock_nft_for(BOB);
+			let contract = contract_with(vec![ContractCondition::InCollection(nft.0)]);
+
+			assert!(evaluate(&contract, vec![nft]));
+			assert!(!evaluate(&contract, vec![other_nft]));
+		});
+	}
+
+	#[test]
+	fn combinators_work() {
+		ExtBuilder::default().build().execute_with(|| {
+			let rare =
+				ContractCondition::AttributeGreaterThan(AttributeNamespace::Pallet, RARITY, 3);
+			let leveled =
+				ContractCondition::AttributeInRange(AttributeNamespace::Pallet, LEVEL, 5, 10);
+
+			let any = contract_with(vec![ContractClause::Any(bounded_vec![
+				rare.clone(),
+				leveled.clone()
+			])]);
+			let all = contract_with(vec![ContractClause::All(bounded_vec![
+				rare.clone(),
+				leveled.clone()
+			])]);
+			let not = contract_with(vec![ContractClause::Not(rare)]);
+
+			let rare_nft = create_nft_with(BOB, &[(RARITY, 4), (LEVEL, 1)]);
+			let leveled_nft = create_nft_with(BOB, &[(RARITY, 1), (LEVEL, 5)]);
+			let rare_and_leveled_nft = create_nft_with(BOB, &[(RARITY, 4), (LEVEL, 5)]);
+			let plain_nft = create_nft_with(BOB, &[(RARITY, 1), (LEVEL, 1)]);
+
+			assert!(evaluate(&any, vec![rare_nft.clone()]));
+			assert!(evaluate(&any, vec![leveled_nft.clone()]));
+			assert!(!evaluate(&any, vec![plain_nft.clone()]));
+
+			assert!(!evaluate(&all, vec![rare_nft.clone()]));
+			assert!(!evaluate(&all, vec![leveled_nft.clone()]));
+			assert!(evaluate(&all, vec![rare_and_leveled_nft.clone()]));
+
+			assert!(!evaluate(&not, vec![rare_nft]));
+			assert!(!evaluate(&not, vec![rare_and_leveled_nft]));
+			assert!(evaluate(&not, vec![leveled_nft]));
+			assert!(evaluate(&not, vec![plain_nft]));
+		});
+	}
+
+	#[test]
+	fn clauses_are_matched_regardless_of_staking_order() {
+		ExtBuilder::default().build().execute_with(|| {
+			let rare_nft = create_nft_with(BOB, &[(RARITY, 4)]);
+			let leveled_nft = create_nft_with(BOB, &[(LEVEL, 5)]);
+			let contract = contract_with(vec![
+				ContractCondition::HasAttribute(AttributeNamespace::Pallet, RARITY),
+				ContractCondition::HasAttribute(AttributeNamespace::Pallet, LEVEL),
+			]);
+
+			assert!(evaluate(&contract, vec![rare_nft.clone(), leveled_nft.clone()]));
+			assert!(evaluate(&contract, vec![leveled_nft.clone(), rare_nft.clone()]));
+
+			// the first clause is fulfilled by both assets, but only the second asset leaves
+			// the rare one available for the second clause
+			let contract = contract_with(vec![
+				ContractClause::Any(bounded_vec![
+					ContractCondition::HasAttribute(AttributeNamespace::Pallet, RARITY),
+					ContractCondition::HasAttribute(AttributeNamespace::Pallet, LEVEL),
+				]),
+				ContractClause::Condition(ContractCondition::HasAttribute(
+					AttributeNamespace::Pallet,
+					RARITY,
+				)),
+			]);
+			assert!(evaluate(&contract, vec![rare_nft.clone(), leveled_nft.clone()]));
+			assert!(evaluate(&contract, vec![leveled_nft.clone(), rare_nft.clone()]));
+
+			// each asset can only fulfill a single clause
+			assert!(!evaluate(&contract, vec![rare_nft.clone(), rare_nft]));
+			assert!(!evaluate(&contract, vec![leveled_nft.clone(), leveled_nft]));
+		});
+	}
+
+	#[test]
+	fn take_a_staking_contract_for_any_assets_above_a_rarity() {
+		ExtBuilder::default().build().execute_with(|| {
+			let epic_or_higher =
+				ContractCondition::AttributeGreaterThan(AttributeNamespace::Pallet, RARITY, 3);
+			let contract = contract_with(vec![epic_or_higher; 3]);
+			let expected_contract_id = NftStake::next_contract_id();
+			let contract_addr = create_and_submit_random_staking_contract_nft(ALICE, contract);
+
+			let staked_assets: StakedAssetsVecOf<Test> = bounded_vec![
+				create_nft_with(BOB, &[(RARITY, 6)]),
+				create_nft_with(BOB, &[(RARITY, 4)]),
+				create_nft_with(BOB, &[(RARITY, 5)]),
+			];
+			assert_ok!(NftStake::take_staking_contract(
+				RuntimeOrigin::signed(BOB),
+				contract_addr.1,
+				staked_assets.clone(),
+			));
+			assert_eq!(NftStake::contract_owners(expected_contract_id), Some(BOB));
+			assert_eq!(NftStake::contract_staked_assets(expected_contract_id), Some(staked_assets));
+		});
+	}
+}
+
 mod redeem_staking_contract {
 	use super::*;
 
@@ -699,7 +1047,7 @@ mod redeem_staking_contract {
 				let account = ALICE;
 				let contract =
 					StakingContractOf::<Test>::new(contract_reward.clone(), contract_duration)
-						.with_clause(ContractClause::HasAttribute(
+						.with_clause(ContractCondition::HasAttribute(
 							AttributeNamespace::Pallet,
 							attr_key,
 						));
@@ -768,8 +1116,10 @@ mod redeem_staking_contract {
 			let contract_reward = StakingRewardOf::<Test>::Nft(nft_reward_addr.clone());
 
 			let contract_addr = {
-				let contract = StakingContractOf::<Test>::new(contract_reward.clone(), 10)
-					.with_clause(ContractClause::HasAttribute(AttributeNamespace::Pallet, 10_u32));
+				let contract =
+					StakingContractOf::<Test>::new(contract_reward.clone(), 10).with_clause(
+						ContractCondition::HasAttribute(AttributeNamespace::Pallet, 10_u32),
+					);
 
 				create_and_submit_random_staking_contract_nft(creator_account, contract)
 			};
@@ -900,6 +1250,1326 @@ mod redeem_staking_contract {
 	}
 }
 
+mod cancel_staking_contract {
+	use super::*;
+
+	#[test]
+	fn cancel_a_staking_contract_successfully_with_token_reward() {
+		ExtBuilder::default().build().execute_with(|| {
+			let initial_balance = Balances::free_balance(ALICE);
+			let initial_reserve = NftStake::treasury_pot_reserve();
+			let contract =
+				StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), 10)
+					.with_availability_period(5);
+			let contract_id = create_and_submit_random_staking_contract_nft(ALICE, contract).1;
+			assert_eq!(Balances::free_balance(ALICE), initial_balance - 1_000);
+			assert_eq!(NftStake::contract_creators(contract_id), Some(ALICE));
+
+			assert_ok!(NftStake::cancel_staking_contract(
+				RuntimeOrigin::signed(ALICE),
+				contract_id
+			));
+			System::assert_last_event(mock::RuntimeEvent::NftStake(
+				crate::Event::StakingContractCancelled { creator: ALICE, contract: contract_id },
+			));
+
+			assert_eq!(Balances::free_balance(ALICE), initial_balance);
+			assert_eq!(NftStake::treasury_pot_reserve(), initial_reserve);
+			assert_eq!(Nft::owner(contract_collection_id(), contract_id), None);
+			assert_eq!(NftStake::active_contracts(contract_id), None);
+			assert_eq!(NftStake::contract_creators(contract_id), None);
+			assert_eq!(NftStake::contract_availabilities(contract_id), None);
+			assert!(NftStake::contract_expiries(6).is_empty());
+		});
+	}
+
+	#[test]
+	fn cancel_a_staking_contract_successfully_with_nft_reward() {
+		ExtBuilder::default().build().execute_with(|| {
+			let collection_id = create_random_mock_nft_collection(ALICE);
+			let nft_reward_addr = create_random_mock_nft(ALICE, collection_id, 1);
+			let contract = StakingContractOf::<Test>::new(
+				StakingRewardOf::<Test>::Nft(nft_reward_addr.clone()),
+				10,
+			);
+			let contract_id = create_and_submit_random_staking_contract_nft(ALICE, contract).1;
+			assert_eq!(
+				Nft::owner(nft_reward_addr.0, nft_reward_addr.1),
+				Some(NftStake::treasury_account_id())
+			);
+
+			assert_ok!(NftStake::cancel_staking_contract(
+				RuntimeOrigin::signed(ALICE),
+				contract_id
+			));
+			System::assert_last_event(mock::RuntimeEvent::NftStake(
+				crate::Event::StakingContractCancelled { creator: ALICE, contract: contract_id },
+			));
+
+			assert_eq!(Nft::owner(nft_reward_addr.0, nft_reward_addr.1), Some(ALICE));
+			assert_eq!(Nft::owner(contract_collection_id(), contract_id), None);
+			assert_eq!(NftStake::active_contracts(contract_id), None);
+			assert_eq!(NftStake::contract_creators(contract_id), None);
+		});
+	}
+
+	#[test]
+	fn cancel_a_staking_contract_without_creator_as_organizer() {
+		ExtBuilder::default().build().execute_with(|| {
+			Organizer::<Test>::put(CHARLIE);
+			let contract =
+				StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), 10);
+			let contract_id = create_and_submit_random_staking_contract_nft(ALICE, contract).1;
+			// Contracts submitted before the upgrade have no creator recorded
+			ContractCreators::<Test>::remove(contract_id);
+			let creator_balance = Balances::free_balance(ALICE);
+			let reserve = NftStake::treasury_pot_reserve();
+
+			assert_noop!(
+				NftStake::cancel_staking_contract(RuntimeOrigin::signed(ALICE), contract_id),
+				Error::<Test>::NotContractCreator
+			);
+			assert_ok!(NftStake::cancel_staking_contract(
+				RuntimeOrigin::signed(CHARLIE),
+				contract_id
+			));
+			System::assert_last_event(mock::RuntimeEvent::NftStake(
+				crate::Event::StakingContractCancelled { creator: CHARLIE, contract: contract_id },
+			));
+
+			assert_eq!(Balances::free_balance(ALICE), creator_balance);
+			assert_eq!(NftStake::treasury_pot_reserve(), reserve);
+			assert_eq!(Nft::owner(contract_collection_id(), contract_id), None);
+			assert_eq!(NftStake::active_contracts(contract_id), None);
+		});
+	}
+
+	#[test]
+	fn cannot_cancel_a_staking_contract_created_by_another_account() {
+		ExtBuilder::default().build().execute_with(|| {
+			let contract =
+				StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), 10);
+			let contract_id = create_and_submit_random_staking_contract_nft(ALICE, contract).1;
+
+			assert_noop!(
+				NftStake::cancel_staking_contract(RuntimeOrigin::signed(BOB), contract_id),
+				Error::<Test>::NotContractCreator
+			);
+		});
+	}
+
+	#[test]
+	fn cannot_cancel_a_taken_staking_contract() {
+		ExtBuilder::default().build().execute_with(|| {
+			let contract =
+				StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), 10);
+			let contract_id = create_and_submit_random_staking_contract_nft(ALICE, contract).1;
+			assert_ok!(NftStake::take_staking_contract(
+				RuntimeOrigin::signed(BOB),
+				contract_id,
+				bounded_vec![],
+			));
+
+			assert_noop!(
+				NftStake::cancel_staking_contract(RuntimeOrigin::signed(ALICE), contract_id),
+				Error::<Test>::ContractInProgress
+			);
+		});
+	}
+
+	#[test]
+	fn cannot_cancel_a_non_existing_staking_contract() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_noop!(
+				NftStake::cancel_staking_contract(RuntimeOrigin::signed(ALICE), 42),
+				Error::<Test>::ContractNotFound
+			);
+		});
+	}
+}
+
+mod contract_expiry {
+	use super::*;
+
+	#[test]
+	fn untaken_staking_contracts_expire_after_their_availability_period() {
+		ExtBuilder::default().build().execute_with(|| {
+			let initial_balance = Balances::free_balance(ALICE);
+			let contract =
+				StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), 10)
+					.with_availability_period(5);
+			let contract_id = create_and_submit_random_staking_contract_nft(ALICE, contract).1;
+			assert_eq!(NftStake::contract_availabilities(contract_id), Some(6));
+			assert_eq!(NftStake::contract_expiries(6).into_inner(), vec![contract_id]);
+
+			run_to_block(5);
+			assert!(NftStake::active_contracts(contract_id).is_some());
+
+			run_to_block(6);
+			System::assert_last_event(mock::RuntimeEvent::NftStake(
+				crate::Event::StakingContractExpired { creator: ALICE, contract: contract_id },
+			));
+			assert_eq!(Balances::free_balance(ALICE), initial_balance);
+			assert_eq!(Nft::owner(contract_collection_id(), contract_id), None);
+			assert_eq!(NftStake::active_contracts(contract_id), None);
+			assert_eq!(NftStake::contract_creators(contract_id), None);
+			assert_eq!(NftStake::contract_availabilities(contract_id), None);
+			assert!(NftStake::contract_expiries(6).is_empty());
+		});
+	}
+
+	#[test]
+	fn taken_staking_contracts_do_not_expire() {
+		ExtBuilder::default().build().execute_with(|| {
+			let contract =
+				StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), 10)
+					.with_availability_period(5);
+			let contract_id = create_and_submit_random_staking_contract_nft(ALICE, contract).1;
+
+			run_to_block(5);
+			assert_ok!(NftStake::take_staking_contract(
+				RuntimeOrigin::signed(BOB),
+				contract_id,
+				bounded_vec![],
+			));
+			assert_eq!(NftStake::contract_availabilities(contract_id), None);
+			assert!(NftStake::contract_expiries(6).is_empty());
+
+			run_to_block(6);
+			assert!(NftStake::active_contracts(contract_id).is_some());
+			assert_eq!(NftStake::contract_owners(contract_id), Some(BOB));
+		});
+	}
+
+	#[test]
+	fn cannot_take_an_expired_staking_contract() {
+		ExtBuilder::default().build().execute_with(|| {
+			let contract =
+				StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), 10)
+					.with_availability_period(5);
+			let contract_id = create_and_submit_random_staking_contract_nft(ALICE, contract).1;
+
+			// the contract is left in place, as if its expiry had failed
+			ContractExpiries::<Test>::remove(6);
+			run_to_block(6);
+
+			assert_noop!(
+				NftStake::take_staking_contract(
+					RuntimeOrigin::signed(BOB),
+					contract_id,
+					bounded_vec![]
+				),
+				Error::<Test>::ContractExpired
+			);
+			assert_ok!(NftStake::cancel_staking_contract(
+				RuntimeOrigin::signed(ALICE),
+				contract_id
+			));
+		});
+	}
+
+	#[test]
+	fn cannot_submit_a_staking_contract_with_an_empty_availability_period() {
+		ExtBuilder::default().build().execute_with(|| {
+			let contract =
+				StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), 10)
+					.with_availability_period(0);
+
+			assert_noop!(
+				NftStake::submit_staking_contract(RuntimeOrigin::signed(ALICE), contract),
+				Error::<Test>::InvalidAvailabilityPeriod
+			);
+		});
+	}
+
+	#[test]
+	fn cannot_submit_too_many_staking_contracts_expiring_at_the_same_block() {
+		ExtBuilder::default().build().execute_with(|| {
+			let contract =
+				StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), 10)
+					.with_availability_period(5);
+			for _ in 0..MAXIMUM_EXPIRING_CONTRACTS_PER_BLOCK {
+				create_and_submit_random_staking_contract_nft(ALICE, contract.clone());
+			}
+
+			assert_noop!(
+				NftStake::submit_staking_contract(RuntimeOrigin::signed(ALICE), contract),
+				Error::<Test>::TooManyExpiringContracts
+			);
+		});
+	}
+}
+
+mod withdraw_staking_contract {
+	use super::*;
+
+	fn submit_and_take_contract(
+		contract: StakingContractOf<Test>,
+	) -> (ContractItemIdOf<Test>, NftAddressOf<Test>) {
+		let contract_id = create_and_submit_random_staking_contract_nft(
+			ALICE,
+			contract
+				.with_clause(ContractCondition::HasAttribute(AttributeNamespace::Pallet, 10_u32)),
+		)
+		.1;
+
+		let staked_nft = create_random_mock_nft_for(BOB);
+		set_attribute_for_nft(&staked_nft, 10_u32, 42_u64);
+		assert_ok!(NftStake::take_staking_contract(
+			RuntimeOrigin::signed(BOB),
+			contract_id,
+			bounded_vec![staked_nft.clone()],
+		));
+
+		(contract_id, staked_nft)
+	}
+
+	#[test]
+	fn withdraw_from_a_staking_contract_successfully_with_token_reward() {
+		ExtBuilder::default().build().execute_with(|| {
+			let creator_balance = Balances::free_balance(ALICE);
+			let taker_balance = Balances::free_balance(BOB);
+			let initial_reserve = NftStake::treasury_pot_reserve();
+			let (contract_id, staked_nft) = submit_and_take_contract(
+				StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), 10)
+					.with_early_withdrawal_penalty(Perbill::from_percent(15)),
+			);
+
+			run_to_block(5);
+			assert_ok!(NftStake::withdraw_staking_contract(
+				RuntimeOrigin::signed(BOB),
+				contract_id
+			));
+			System::assert_last_event(mock::RuntimeEvent::NftStake(
+				crate::Event::StakingContractWithdrawn {
+					withdrawn_by: BOB,
+					contract: contract_id,
+					reward: Some(StakingRewardOf::<Test>::Tokens(850)),
+				},
+			));
+
+			assert_eq!(Balances::free_balance(BOB), taker_balance + 850);
+			assert_eq!(Balances::free_balance(ALICE), creator_balance - 1_000 + 150);
+			assert_eq!(NftStake::treasury_pot_reserve(), initial_reserve);
+			assert_eq!(Nft::owner(staked_nft.0, staked_nft.1), Some(BOB));
+			assert_eq!(Nft::owner(contract_collection_id(), contract_id), None);
+			assert_eq!(NftStake::active_contracts(contract_id), None);
+			assert_eq!(NftStake::contract_creators(contract_id), None);
+			assert_eq!(NftStake::contract_owners(contract_id), None);
+			assert_eq!(NftStake::contract_durations(contract_id), None);
+			assert_eq!(NftStake::contract_staked_assets(contract_id), None);
+		});
+	}
+
+	#[test]
+	fn withdraw_from_a_staking_contract_forfeits_a_penalized_nft_reward() {
+		ExtBuilder::default().build().execute_with(|| {
+			let collection_id = create_random_mock_nft_collection(ALICE);
+			let nft_reward_addr = create_random_mock_nft(ALICE, collection_id, 1);
+			let (contract_id, staked_nft) = submit_and_take_contract(
+				StakingContractOf::<Test>::new(
+					StakingRewardOf::<Test>::Nft(nft_reward_addr.clone()),
+					10,
+				)
+				.with_early_withdrawal_penalty(Perbill::from_percent(1)),
+			);
+
+			assert_ok!(NftStake::withdraw_staking_contract(
+				RuntimeOrigin::signed(BOB),
+				contract_id
+			));
+			System::assert_last_event(mock::RuntimeEvent::NftStake(
+				crate::Event::StakingContractWithdrawn {
+					withdrawn_by: BOB,
+					contract: contract_id,
+					reward: None,
+				},
+			));
+
+			assert_eq!(Nft::owner(nft_reward_addr.0, nft_reward_addr.1), Some(ALICE));
+			assert_eq!(Nft::owner(staked_nft.0, staked_nft.1), Some(BOB));
+			assert_eq!(NftStake::active_contracts(contract_id), None);
+		});
+	}
+
+	#[test]
+	fn withdraw_from_a_staking_contract_keeps_an_unpenalized_nft_reward() {
+		ExtBuilder::default().build().execute_with(|| {
+			let collection_id = create_random_mock_nft_collection(ALICE);
+			let nft_reward_addr = create_random_mock_nft(ALICE, collection_id, 1);
+			let contract_reward = StakingRewardOf::<Test>::Nft(nft_reward_addr.clone());
+			let (contract_id, _) = submit_and_take_contract(
+				StakingContractOf::<Test>::new(contract_reward.clone(), 10)
+					.with_early_withdrawal_penalty(Perbill::zero()),
+			);
+
+			assert_ok!(NftStake::withdraw_staking_contract(
+				RuntimeOrigin::signed(BOB),
+				contract_id
+			));
+			System::assert_last_event(mock::RuntimeEvent::NftStake(
+				crate::Event::StakingContractWithdrawn {
+					withdrawn_by: BOB,
+					contract: contract_id,
+					reward: Some(contract_reward),
+				},
+			));
+
+			assert_eq!(Nft::owner(nft_reward_addr.0, nft_reward_addr.1), Some(BOB));
+		});
+	}
+
+	#[test]
+	fn withdraw_from_a_staking_contract_without_creator_forfeits_to_the_treasury() {
+		ExtBuilder::default().build().execute_with(|| {
+			let (contract_id, staked_nft) = submit_and_take_contract(
+				StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), 10)
+					.with_early_withdrawal_penalty(Perbill::from_percent(15)),
+			);
+			// Contracts submitted before the upgrade have no creator recorded
+			ContractCreators::<Test>::remove(contract_id);
+			let creator_balance = Balances::free_balance(ALICE);
+			let taker_balance = Balances::free_balance(BOB);
+			let reserve = NftStake::treasury_pot_reserve();
+
+			assert_ok!(NftStake::withdraw_staking_contract(
+				RuntimeOrigin::signed(BOB),
+				contract_id
+			));
+			System::assert_last_event(mock::RuntimeEvent::NftStake(
+				crate::Event::StakingContractWithdrawn {
+					withdrawn_by: BOB,
+					contract: contract_id,
+					reward: Some(StakingRewardOf::<Test>::Tokens(850)),
+				},
+			));
+
+			assert_eq!(Balances::free_balance(BOB), taker_balance + 850);
+			assert_eq!(Balances::free_balance(ALICE), creator_balance);
+			assert_eq!(NftStake::treasury_pot_reserve(), reserve - 850);
+			assert_eq!(Nft::owner(staked_nft.0, staked_nft.1), Some(BOB));
+			assert_eq!(NftStake::active_contracts(contract_id), None);
+		});
+	}
+
+	#[test]
+	fn cannot_withdraw_from_a_staking_contract_without_early_withdrawal() {
+		ExtBuilder::default().build().execute_with(|| {
+			let (contract_id, _) = submit_and_take_contract(StakingContractOf::<Test>::new(
+				StakingRewardOf::<Test>::Tokens(1_000),
+				10,
+			));
+
+			assert_noop!(
+				NftStake::withdraw_staking_contract(RuntimeOrigin::signed(BOB), contract_id),
+				Error::<Test>::EarlyWithdrawalNotAllowed
+			);
+		});
+	}
+
+	#[test]
+	fn cannot_withdraw_from_a_finished_staking_contract() {
+		ExtBuilder::default().build().execute_with(|| {
+			let (contract_id, _) = submit_and_take_contract(
+				StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), 10)
+					.with_early_withdrawal_penalty(Perbill::from_percent(15)),
+			);
+
+			run_to_block(11);
+			assert_noop!(
+				NftStake::withdraw_staking_contract(RuntimeOrigin::signed(BOB), contract_id),
+				Error::<Test>::ContractAlreadyFinished
+			);
+		});
+	}
+
+	#[test]
+	fn cannot_withdraw_from_a_non_owned_staking_contract() {
+		ExtBuilder::default().build().execute_with(|| {
+			let (contract_id, _) = submit_and_take_contract(
+				StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), 10)
+					.with_early_withdrawal_penalty(Perbill::from_percent(15)),
+			);
+
+			assert_noop!(
+				NftStake::withdraw_staking_contract(RuntimeOrigin::signed(CHARLIE), contract_id),
+				Error::<Test>::ContractNotOwned
+			);
+		});
+	}
+}
+
+mod staking_rewards {
+	use super::*;
+
+	const ASSET_ID: MockAssetId = 7;
+
+	fn create_asset_for(owner: MockAccountId, amount: MockBalance) {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ASSET_ID, owner, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(owner), ASSET_ID, owner, amount));
+	}
+
+	fn take_and_redeem_contract(contract_id: ContractItemIdOf<Test>, duration: MockBlockNumber) {
+		assert_ok!(NftStake::take_staking_contract(
+			RuntimeOrigin::signed(BOB),
+			contract_id,
+			bounded_vec![],
+		));
+		run_to_block(System::block_number() + duration);
+		assert_ok!(NftStake::redeem_staking_contract(RuntimeOrigin::signed(BOB), contract_id));
+	}
+
+	#[test]
+	fn redeem_a_staking_contract_with_asset_reward() {
+		ExtBuilder::default().build().execute_with(|| {
+			create_asset_for(ALICE, 10_000);
+			let contract_reward = StakingRewardOf::<Test>::Assets(ASSET_ID, 1_000);
+			let contract = StakingContractOf::<Test>::new(contract_reward.clone(), 10);
+			let contract_id = create_and_submit_random_staking_contract_nft(ALICE, contract).1;
+			assert_eq!(Assets::balance(ASSET_ID, ALICE), 9_000);
+			assert_eq!(NftStake::treasury_asset_reserve(ASSET_ID), 1_000);
+
+			take_and_redeem_contract(contract_id, 10);
+			System::assert_last_event(mock::RuntimeEvent::NftStake(
+				crate::Event::StakingContractRedeemed {
+					redeemed_by: BOB,
+					contract: contract_id,
+					reward: contract_reward,
+				},
+			));
+
+			assert_eq!(Assets::balance(ASSET_ID, BOB), 1_000);
+			assert_eq!(NftStake::treasury_asset_reserve(ASSET_ID), 0);
+		});
+	}
+
+	#[test]
+	fn redeem_a_staking_contract_with_bundle_reward() {
+		ExtBuilder::default().build().execute_with(|| {
+			let taker_balance = Balances::free_balance(BOB);
+			let initial_reserve = NftStake::treasury_pot_reserve();
+			let collection_id = create_random_mock_nft_collection(ALICE);
+			let nft_1 = create_random_mock_nft(ALICE, collection_id, 1);
+			let nft_2 = create_random_mock_nft(ALICE, collection_id, 2);
+			let contract_reward =
+				StakingRewardOf::<Test>::Bundle(1_000, bounded_vec![nft_1.clone(), nft_2.clone()]);
+			let contract = StakingContractOf::<Test>::new(contract_reward.clone(), 10);
+			let contract_id = create_and_submit_random_staking_contract_nft(ALICE, contract).1;
+			assert_eq!(NftStake::treasury_pot_reserve(), initial_reserve + 1_000);
+			for nft in [&nft_1, &nft_2] {
+				assert_eq!(Nft::owner(nft.0, nft.1), Some(NftStake::treasury_account_id()));
+			}
+
+			take_and_redeem_contract(contract_id, 10);
+			System::assert_last_event(mock::RuntimeEvent::NftStake(
+				crate::Event::StakingContractRedeemed {
+					redeemed_by: BOB,
+					contract: contract_id,
+					reward: contract_reward,
+				},
+			));
+
+			assert_eq!(Balances::free_balance(BOB), taker_balance + 1_000);
+			assert_eq!(NftStake::treasury_pot_reserve(), initial_reserve);
+			for nft in [&nft_1, &nft_2] {
+				assert_eq!(Nft::owner(nft.0, nft.1), Some(BOB));
+			}
+		});
+	}
+
+	#[test]
+	fn cancel_a_staking_contract_with_bundle_reward() {
+		ExtBuilder::default().build().execute_with(|| {
+			let nft = create_random_mock_nft_for(ALICE);
+			let creator_balance = Balances::free_balance(ALICE);
+			let contract = StakingContractOf::<Test>::new(
+				StakingRewardOf::<Test>::Bundle(1_000, bounded_vec![nft.clone()]),
+				10,
+			);
+			let contract_id = create_and_submit_random_staking_contract_nft(ALICE, contract).1;
+
+			assert_ok!(NftStake::cancel_staking_contract(
+				RuntimeOrigin::signed(ALICE),
+				contract_id
+			));
+			assert_eq!(Balances::free_balance(ALICE), creator_balance);
+			assert_eq!(Nft::owner(nft.0, nft.1), Some(ALICE));
+		});
+	}
+
+	#[test]
+	fn withdraw_from_a_staking_contract_with_bundle_reward() {
+		ExtBuilder::default().build().execute_with(|| {
+			let taker_balance = Balances::free_balance(BOB);
+			let nft = create_random_mock_nft_for(ALICE);
+			let creator_balance = Balances::free_balance(ALICE);
+			let contract = StakingContractOf::<Test>::new(
+				StakingRewardOf::<Test>::Bundle(1_000, bounded_vec![nft.clone()]),
+				10,
+			)
+			.with_early_withdrawal_penalty(Perbill::from_percent(10));
+			let contract_id = create_and_submit_random_staking_contract_nft(ALICE, contract).1;
+			assert_ok!(NftStake::take_staking_contract(
+				RuntimeOrigin::signed(BOB),
+				contract_id,
+				bounded_vec![],
+			));
+
+			assert_ok!(NftStake::withdraw_staking_contract(
+				RuntimeOrigin::signed(BOB),
+				contract_id
+			));
+			System::assert_last_event(mock::RuntimeEvent::NftStake(
+				crate::Event::StakingContractWithdrawn {
+					withdrawn_by: BOB,
+					contract: contract_id,
+					reward: Some(StakingRewardOf::<Test>::Tokens(900)),
+				},
+			));
+
+			assert_eq!(Balances::free_balance(BOB), taker_balance + 900);
+			assert_eq!(Balances::free_balance(ALICE), creator_balance - 900);
+			assert_eq!(Nft::owner(nft.0, nft.1), Some(ALICE));
+		});
+	}
+
+	#[test]
+	fn cannot_submit_a_staking_contract_with_an_empty_reward() {
+		ExtBuilder::default().build().execute_with(|| {
+			create_asset_for(ALICE, 10_000);
+			for contract_reward in [
+				StakingRewardOf::<Test>::Bundle(0, bounded_vec![]),
+				StakingRewardOf::<Test>::Assets(ASSET_ID, 0),
+			] {
+				assert_noop!(
+					NftStake::submit_staking_contract(
+						RuntimeOrigin::signed(ALICE),
+						StakingContractOf::<Test>::new(contract_reward, 10),
+					),
+					Error::<Test>::InvalidContractReward
+				);
+			}
+		});
+	}
+
+	#[test]
+	fn cannot_submit_a_staking_contract_without_enough_assets() {
+		ExtBuilder::default().build().execute_with(|| {
+			create_asset_for(ALICE, 10_000);
+
+			assert_noop!(
+				NftStake::submit_staking_contract(
+					RuntimeOrigin::signed(ALICE),
+					StakingContractOf::<Test>::new(
+						StakingRewardOf::<Test>::Assets(ASSET_ID, 10_001),
+						10
+					),
+				),
+				pallet_assets::Error::<Test>::BalanceLow
+			);
+		});
+	}
+}
+
+mod streamed_rewards {
+	use super::*;
+
+	fn submit_and_take_streamed_contract(
+		contract_reward: StakingRewardOf<Test>,
+		penalty: Option<Perbill>,
+	) -> ContractItemIdOf<Test> {
+		let mut contract =
+			StakingContractOf::<Test>::new(contract_reward, 10).with_streamed_reward();
+		if let Some(penalty) = penalty {
+			contract = contract.with_early_withdrawal_penalty(penalty);
+		}
+		let contract_id = create_and_submit_random_staking_contract_nft(ALICE, contract).1;
+		assert_ok!(NftStake::take_staking_contract(
+			RuntimeOrigin::signed(BOB),
+			contract_id,
+			bounded_vec![],
+		));
+
+		contract_id
+	}
+
+	#[test]
+	fn claim_a_streamed_reward_successfully() {
+		ExtBuilder::default().build().execute_with(|| {
+			let taker_balance = Balances::free_balance(BOB);
+			let contract_id =
+				submit_and_take_streamed_contract(StakingRewardOf::<Test>::Tokens(1_000), None);
+
+			run_to_block(4);
+			assert_ok!(NftStake::claim_staking_contract_reward(
+				RuntimeOrigin::signed(BOB),
+				contract_id
+			));
+			System::assert_last_event(mock::RuntimeEvent::NftStake(
+				crate::Event::StakingRewardClaimed {
+					claimed_by: BOB,
+					contract: contract_id,
+					reward: StakingRewardOf::<Test>::Tokens(300),
+				},
+			));
+			assert_eq!(Balances::free_balance(BOB), taker_balance + 300);
+			assert_eq!(NftStake::contract_claimed_rewards(contract_id), 300);
+
+			assert_noop!(
+				NftStake::claim_staking_contract_reward(RuntimeOrigin::signed(BOB), contract_id),
+				Error::<Test>::NoRewardToClaim
+			);
+
+			run_to_block(20);
+			assert_ok!(NftStake::claim_staking_contract_reward(
+				RuntimeOrigin::signed(BOB),
+				contract_id
+			));
+			assert_eq!(Balances::free_balance(BOB), taker_balance + 1_000);
+			assert_eq!(NftStake::contract_claimed_rewards(contract_id), 1_000);
+		});
+	}
+
+	#[test]
+	fn redeem_a_partially_claimed_streamed_reward() {
+		ExtBuilder::default().build().execute_with(|| {
+			let taker_balance = Balances::free_balance(BOB);
+			let contract_id =
+				submit_and_take_streamed_contract(StakingRewardOf::<Test>::Tokens(1_000), None);
+
+			run_to_block(6);
+			assert_ok!(NftStake::claim_staking_contract_reward(
+				RuntimeOrigin::signed(BOB),
+				contract_id
+			));
+
+			run_to_block(11);
+			assert_ok!(NftStake::redeem_staking_contract(RuntimeOrigin::signed(BOB), contract_id));
+			System::assert_last_event(mock::RuntimeEvent::NftStake(
+				crate::Event::StakingContractRedeemed {
+					redeemed_by: BOB,
+					contract: contract_id,
+					reward: StakingRewardOf::<Test>::Tokens(500),
+				},
+			));
+			assert_eq!(Balances::free_balance(BOB), taker_balance + 1_000);
+			assert_eq!(NftStake::contract_claimed_rewards(contract_id), 0);
+		});
+	}
+
+	#[test]
+	fn claim_only_the_fungible_part_of_a_streamed_bundle_reward() {
+		ExtBuilder::default().build().execute_with(|| {
+			let nft = create_random_mock_nft_for(ALICE);
+			let contract_id = submit_and_take_streamed_contract(
+				StakingRewardOf::<Test>::Bundle(1_000, bounded_vec![nft.clone()]),
+				None,
+			);
+
+			run_to_block(11);
+			assert_ok!(NftStake::claim_staking_contract_reward(
+				RuntimeOrigin::signed(BOB),
+				contract_id
+			));
+			System::assert_last_event(mock::RuntimeEvent::NftStake(
+				crate::Event::StakingRewardClaimed {
+					claimed_by: BOB,
+					contract: contract_id,
+					reward: StakingRewardOf::<Test>::Tokens(1_000),
+				},
+			));
+			assert_eq!(Nft::owner(nft.0, nft.1), Some(NftStake::treasury_account_id()));
+
+			assert_ok!(NftStake::redeem_staking_contract(RuntimeOrigin::signed(BOB), contract_id));
+			assert_eq!(Nft::owner(nft.0, nft.1), Some(BOB));
+		});
+	}
+
+	#[test]
+	fn withdraw_from_a_partially_claimed_streamed_reward() {
+		ExtBuilder::default().build().execute_with(|| {
+			let creator_balance = Balances::free_balance(ALICE);
+			let taker_balance = Balances::free_balance(BOB);
+			let contract_id = submit_and_take_streamed_contract(
+				StakingRewardOf::<Test>::Tokens(1_000),
+				Some(Perbill::from_percent(20)),
+			);
+
+			run_to_block(6);
+			assert_ok!(NftStake::claim_staking_contract_reward(
+				RuntimeOrigin::signed(BOB),
+				contract_id
+			));
+			assert_ok!(NftStake::withdraw_staking_contract(
+				RuntimeOrigin::signed(BOB),
+				contract_id
+			));
+			System::assert_last_event(mock::RuntimeEvent::NftStake(
+				crate::Event::StakingContractWithdrawn {
+					withdrawn_by: BOB,
+					contract: contract_id,
+					reward: Some(StakingRewardOf::<Test>::Tokens(300)),
+				},
+			));
+
+			assert_eq!(Balances::free_balance(BOB), taker_balance + 800);
+			assert_eq!(Balances::free_balance(ALICE), creator_balance - 800);
+		});
+	}
+
+	#[test]
+	fn cannot_claim_a_non_streamed_reward() {
+		ExtBuilder::default().build().execute_with(|| {
+			let contract =
+				StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), 10);
+			let contract_id = create_and_submit_random_staking_contract_nft(ALICE, contract).1;
+			assert_ok!(NftStake::take_staking_contract(
+				RuntimeOrigin::signed(BOB),
+				contract_id,
+				bounded_vec![],
+			));
+
+			run_to_block(5);
+			assert_noop!(
+				NftStake::claim_staking_contract_reward(RuntimeOrigin::signed(BOB), contract_id),
+				Error::<Test>::RewardNotStreamed
+			);
+		});
+	}
+
+	#[test]
+	fn cannot_claim_a_streamed_reward_of_a_non_owned_contract() {
+		ExtBuilder::default().build().execute_with(|| {
+			let contract_id =
+				submit_and_take_streamed_contract(StakingRewardOf::<Test>::Tokens(1_000), None);
+
+			run_to_block(5);
+			assert_noop!(
+				NftStake::claim_staking_contract_reward(
+					RuntimeOrigin::signed(CHARLIE),
+					contract_id
+				),
+				Error::<Test>::ContractNotOwned
+			);
+		});
+	}
+
+	#[test]
+	fn cannot_submit_a_streamed_contract_without_fungible_reward() {
+		ExtBuilder::default().build().execute_with(|| {
+			let nft = create_random_mock_nft_for(ALICE);
+			let contract = StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Nft(nft), 10)
+				.with_streamed_reward();
+
+			assert_noop!(
+				NftStake::submit_staking_contract(RuntimeOrigin::signed(ALICE), contract),
+				Error::<Test>::InvalidContractReward
+			);
+		});
+	}
+}
+
+mod creator_registry {
+	use super::*;
+
+	fn registration_required() -> CreatorConfig {
+		CreatorConfig { registration_required: true, fee_share: Perbill::zero() }
+	}
+
+	#[test]
+	fn registration_is_required_by_default() {
+		ExtBuilder::default()
+			.creator_config(CreatorConfig::default())
+			.build()
+			.execute_with(|| {
+				assert_ok!(NftStake::set_organizer(RuntimeOrigin::root(), ALICE));
+				assert!(NftStake::creator_configuration().registration_required);
+
+				let contract =
+					StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), 10);
+				assert_noop!(
+					NftStake::submit_staking_contract(RuntimeOrigin::signed(BOB), contract.clone()),
+					Error::<Test>::CreatorNotRegistered
+				);
+				assert_ok!(NftStake::submit_staking_contract(
+					RuntimeOrigin::signed(ALICE),
+					contract
+				));
+			});
+	}
+
+	#[test]
+	fn set_creator_config_successfully() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(NftStake::set_organizer(RuntimeOrigin::root(), ALICE));
+			let config =
+				CreatorConfig { registration_required: true, fee_share: Perbill::from_percent(30) };
+
+			assert_ok!(NftStake::set_creator_config(RuntimeOrigin::signed(ALICE), config));
+			assert_eq!(NftStake::creator_configuration(), config);
+			System::assert_last_event(mock::RuntimeEvent::NftStake(
+				crate::Event::CreatorConfigSet { config },
+			));
+		});
+	}
+
+	#[test]
+	fn register_and_unregister_creator_successfully() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(NftStake::set_organizer(RuntimeOrigin::root(), ALICE));
+			let collections: CreatorCollectionsOf<Test> = bounded_vec![3, 5];
+
+			assert_ok!(NftStake::register_creator(
+				RuntimeOrigin::signed(ALICE),
+				BOB,
+				collections.clone()
+			));
+			assert_eq!(NftStake::creators(BOB), Some(collections.clone()));
+			System::assert_last_event(mock::RuntimeEvent::NftStake(
+				crate::Event::CreatorRegistered { creator: BOB, collections },
+			));
+
+			assert_ok!(NftStake::unregister_creator(RuntimeOrigin::signed(ALICE), BOB));
+			assert_eq!(NftStake::creators(BOB), None);
+			System::assert_last_event(mock::RuntimeEvent::NftStake(
+				crate::Event::CreatorUnregistered { creator: BOB },
+			));
+		});
+	}
+
+	#[test]
+	fn only_the_organizer_can_manage_creators() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(NftStake::set_organizer(RuntimeOrigin::root(), ALICE));
+
+			assert_noop!(
+				NftStake::set_creator_config(RuntimeOrigin::signed(BOB), registration_required()),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				NftStake::register_creator(RuntimeOrigin::signed(BOB), BOB, bounded_vec![]),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				NftStake::unregister_creator(RuntimeOrigin::signed(BOB), BOB),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn cannot_unregister_a_non_registered_creator() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(NftStake::set_organizer(RuntimeOrigin::root(), ALICE));
+
+			assert_noop!(
+				NftStake::unregister_creator(RuntimeOrigin::signed(ALICE), BOB),
+				Error::<Test>::CreatorNotRegistered
+			);
+		});
+	}
+
+	#[test]
+	fn registered_creators_can_submit_contracts_for_their_collections() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(NftStake::set_organizer(RuntimeOrigin::root(), ALICE));
+			assert_ok!(NftStake::set_creator_config(
+				RuntimeOrigin::signed(ALICE),
+				registration_required()
+			));
+			let collection_id = create_random_mock_nft_collection(BOB);
+			assert_ok!(NftStake::register_creator(
+				RuntimeOrigin::signed(ALICE),
+				BOB,
+				bounded_vec![collection_id]
+			));
+
+			let contract =
+				StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), 10)
+					.with_clause(ContractCondition::InCollection(collection_id))
+					.with_clause(ContractClause::All(bounded_vec![
+						ContractCondition::InCollection(collection_id),
+						ContractCondition::HasAttribute(AttributeNamespace::Pallet, 10_u32),
+					]));
+			create_and_submit_random_staking_contract_nft(BOB, contract);
+			assert_eq!(NftStake::open_contracts(BOB), 1);
+		});
+	}
+
+	#[test]
+	fn the_organizer_can_submit_any_contract() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(NftStake::set_organizer(RuntimeOrigin::root(), ALICE));
+			assert_ok!(NftStake::set_creator_config(
+				RuntimeOrigin::signed(ALICE),
+				registration_required()
+			));
+
+			let contract =
+				StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), 10)
+					.with_clause(ContractCondition::HasAttribute(
+						AttributeNamespace::Pallet,
+						10_u32,
+					));
+			create_and_submit_random_staking_contract_nft(ALICE, contract);
+		});
+	}
+
+	#[test]
+	fn cannot_submit_contracts_without_being_registered() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(NftStake::set_organizer(RuntimeOrigin::root(), ALICE));
+			assert_ok!(NftStake::set_creator_config(
+				RuntimeOrigin::signed(ALICE),
+				registration_required()
+			));
+
+			assert_noop!(
+				NftStake::submit_staking_contract(
+					RuntimeOrigin::signed(BOB),
+					StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), 10)
+				),
+				Error::<Test>::CreatorNotRegistered
+			);
+		});
+	}
+
+	#[test]
+	fn cannot_submit_contracts_for_other_collections() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(NftStake::set_organizer(RuntimeOrigin::root(), ALICE));
+			assert_ok!(NftStake::set_creator_config(
+				RuntimeOrigin::signed(ALICE),
+				registration_required()
+			));
+			let collection_id = create_random_mock_nft_collection(BOB);
+			let other_collection_id = create_random_mock_nft_collection(CHARLIE);
+			assert_ok!(NftStake::register_creator(
+				RuntimeOrigin::signed(ALICE),
+				BOB,
+				bounded_vec![collection_id]
+			));
+
+			for clause in [
+				ContractClause::Condition(ContractCondition::InCollection(other_collection_id)),
+				ContractClause::Condition(ContractCondition::HasAttribute(
+					AttributeNamespace::Pallet,
+					10_u32,
+				)),
+				ContractClause::Any(bounded_vec![
+					ContractCondition::InCollection(collection_id),
+					ContractCondition::InCollection(other_collection_id),
+				]),
+				ContractClause::Not(ContractCondition::InCollection(collection_id)),
+			] {
+				let contract =
+					StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), 10)
+						.with_clause(ContractCondition::InCollection(collection_id))
+						.with_clause(clause);
+				assert_noop!(
+					NftStake::submit_staking_contract(RuntimeOrigin::signed(BOB), contract),
+					Error::<Test>::CollectionNotAllowed
+				);
+			}
+		});
+	}
+
+	#[test]
+	fn cannot_submit_more_than_the_maximum_open_contracts() {
+		ExtBuilder::default().build().execute_with(|| {
+			MaxOpenContractsPerCreator::set(2);
+			let contract =
+				StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), 10);
+			let contract_id =
+				create_and_submit_random_staking_contract_nft(ALICE, contract.clone()).1;
+			create_and_submit_random_staking_contract_nft(ALICE, contract.clone());
+			assert_eq!(NftStake::open_contracts(ALICE), 2);
+
+			assert_noop!(
+				NftStake::submit_staking_contract(RuntimeOrigin::signed(ALICE), contract.clone()),
+				Error::<Test>::TooManyOpenContracts
+			);
+			create_and_submit_random_staking_contract_nft(BOB, contract.clone());
+
+			assert_ok!(NftStake::cancel_staking_contract(
+				RuntimeOrigin::signed(ALICE),
+				contract_id
+			));
+			assert_eq!(NftStake::open_contracts(ALICE), 1);
+			create_and_submit_random_staking_contract_nft(ALICE, contract);
+		});
+	}
+
+	#[test]
+	fn redeeming_a_contract_frees_an_open_contract() {
+		ExtBuilder::default().build().execute_with(|| {
+			let contract =
+				StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), 10);
+			let contract_id = create_and_submit_random_staking_contract_nft(ALICE, contract).1;
+			assert_ok!(NftStake::take_staking_contract(
+				RuntimeOrigin::signed(BOB),
+				contract_id,
+				bounded_vec![],
+			));
+			assert_eq!(NftStake::open_contracts(ALICE), 1);
+
+			run_to_block(11);
+			assert_ok!(NftStake::redeem_staking_contract(RuntimeOrigin::signed(BOB), contract_id));
+			assert_eq!(NftStake::open_contracts(ALICE), 0);
+			assert!(!OpenContracts::<Test>::contains_key(ALICE));
+		});
+	}
+}
+
+mod taking_fees {
+	use super::*;
+
+	#[test]
+	fn take_a_staking_contract_paying_its_fee() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(NftStake::set_organizer(RuntimeOrigin::root(), CHARLIE));
+			assert_ok!(NftStake::set_creator_config(
+				RuntimeOrigin::signed(CHARLIE),
+				CreatorConfig {
+					registration_required: false,
+					fee_share: Perbill::from_percent(30)
+				}
+			));
+			let contract =
+				StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), 10)
+					.with_taking_fee(200);
+			let contract_id = create_and_submit_random_staking_contract_nft(ALICE, contract).1;
+			let creator_balance = Balances::free_balance(ALICE);
+			let taker_balance = Balances::free_balance(BOB);
+			let treasury_reserve = NftStake::treasury_pot_reserve();
+
+			assert_ok!(NftStake::take_staking_contract(
+				RuntimeOrigin::signed(BOB),
+				contract_id,
+				bounded_vec![],
+			));
+
+			assert_eq!(Balances::free_balance(BOB), taker_balance - 200);
+			assert_eq!(Balances::free_balance(ALICE), creator_balance + 60);
+			assert_eq!(NftStake::treasury_pot_reserve(), treasury_reserve);
+			assert_eq!(NftStake::collected_fees(), 140);
+		});
+	}
+
+	#[test]
+	fn taking_fees_never_pay_contract_rewards() {
+		ExtBuilder::default().build().execute_with(|| {
+			let treasury_reserve = NftStake::treasury_pot_reserve();
+			let contract =
+				StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), 10)
+					.with_taking_fee(1_000);
+			let contract_id = create_and_submit_random_staking_contract_nft(ALICE, contract).1;
+			assert_ok!(NftStake::take_staking_contract(
+				RuntimeOrigin::signed(BOB),
+				contract_id,
+				bounded_vec![],
+			));
+			assert_eq!(NftStake::treasury_pot_reserve(), treasury_reserve + 1_000);
+			assert_eq!(NftStake::collected_fees(), 1_000);
+
+			// The collected fees can't make up for a missing reward reserve
+			Balances::unreserve(&NftStake::treasury_account_id(), treasury_reserve + 1_000);
+			run_to_block(11);
+			assert_noop!(
+				NftStake::redeem_staking_contract(RuntimeOrigin::signed(BOB), contract_id),
+				Error::<Test>::TreasuryLacksFunds
+			);
+		});
+	}
+
+	#[test]
+	fn creator_share_below_the_existential_deposit_goes_to_the_treasury() {
+		ExtBuilder::default()
+			.balances(vec![(BOB, 1_000_000), (CHARLIE, 1_000_000)])
+			.build()
+			.execute_with(|| {
+				assert_ok!(NftStake::set_organizer(RuntimeOrigin::root(), CHARLIE));
+				assert_ok!(NftStake::set_creator_config(
+					RuntimeOrigin::signed(CHARLIE),
+					CreatorConfig {
+						registration_required: false,
+						fee_share: Perbill::from_percent(50)
+					}
+				));
+				// ALICE has no account, and half of the fee is below the existential deposit
+				let nft = create_random_mock_nft_for(ALICE);
+				let contract =
+					StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Nft(nft), 10)
+						.with_taking_fee(200);
+				let contract_id = create_and_submit_random_staking_contract_nft(ALICE, contract).1;
+				let taker_balance = Balances::free_balance(BOB);
+
+				assert_ok!(NftStake::take_staking_contract(
+					RuntimeOrigin::signed(BOB),
+					contract_id,
+					bounded_vec![],
+				));
+
+				assert_eq!(Balances::free_balance(BOB), taker_balance - 200);
+				assert_eq!(Balances::total_balance(&ALICE), 0);
+				assert_eq!(NftStake::collected_fees(), 200);
+			});
+	}
+
+	#[test]
+	fn cannot_take_a_staking_contract_without_funds_for_its_fee() {
+		ExtBuilder::default()
+			.balances(vec![(ALICE, 1_000_000), (BOB, 400)])
+			.build()
+			.execute_with(|| {
+				let contract =
+					StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), 10)
+						.with_taking_fee(500);
+				let contract_id = create_and_submit_random_staking_contract_nft(ALICE, contract).1;
+
+				assert_noop!(
+					NftStake::take_staking_contract(
+						RuntimeOrigin::signed(BOB),
+						contract_id,
+						bounded_vec![]
+					),
+					Error::<Test>::AccountLacksFunds
+				);
+			});
+	}
+
+	#[test]
+	fn organizer_claims_the_collected_fees() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(NftStake::set_organizer(RuntimeOrigin::root(), CHARLIE));
+			let contract =
+				StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Tokens(1_000), 10)
+					.with_taking_fee(200);
+			let contract_id = create_and_submit_random_staking_contract_nft(ALICE, contract).1;
+			assert_ok!(NftStake::take_staking_contract(
+				RuntimeOrigin::signed(BOB),
+				contract_id,
+				bounded_vec![],
+			));
+			let organizer_balance = Balances::free_balance(CHARLIE);
+			let treasury_reserve = NftStake::treasury_pot_reserve();
+
+			assert_ok!(NftStake::claim_collected_fees(RuntimeOrigin::signed(CHARLIE)));
+
+			assert_eq!(Balances::free_balance(CHARLIE), organizer_balance + 200);
+			assert_eq!(NftStake::treasury_pot_reserve(), treasury_reserve);
+			assert_eq!(NftStake::collected_fees(), 0);
+			System::assert_last_event(mock::RuntimeEvent::NftStake(crate::Event::FeesClaimed {
+				organizer: CHARLIE,
+				fees: 200,
+			}));
+
+			assert_noop!(
+				NftStake::claim_collected_fees(RuntimeOrigin::signed(CHARLIE)),
+				Error::<Test>::NoFeesToClaim
+			);
+		});
+	}
+
+	#[test]
+	fn only_the_organizer_can_claim_the_collected_fees() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_noop!(
+				NftStake::claim_collected_fees(RuntimeOrigin::signed(CHARLIE)),
+				Error::<Test>::OrganizerNotSet
+			);
+
+			assert_ok!(NftStake::set_organizer(RuntimeOrigin::root(), CHARLIE));
+			CollectedFees::<Test>::put(200);
+			assert_noop!(
+				NftStake::claim_collected_fees(RuntimeOrigin::signed(BOB)),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn claiming_the_collected_fees_keeps_the_treasury_alive() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(NftStake::set_organizer(RuntimeOrigin::root(), CHARLIE));
+			// Nft rewards leave the treasury with nothing but the fee
+			let nft = create_random_mock_nft_for(ALICE);
+			let contract = StakingContractOf::<Test>::new(StakingRewardOf::<Test>::Nft(nft), 10)
+				.with_taking_fee(500);
+			let contract_id = create_and_submit_random_staking_contract_nft(ALICE, contract).1;
+			assert_ok!(NftStake::take_staking_contract(
+				RuntimeOrigin::signed(BOB),
+				contract_id,
+				bounded_vec![],
+			));
+			let treasury_account = NftStake::treasury_account_id();
+			assert_eq!(Balances::total_balance(&treasury_account), 500);
+			let organizer_balance = Balances::free_balance(CHARLIE);
+
+			assert_ok!(NftStake::claim_collected_fees(RuntimeOrigin::signed(CHARLIE)));
+
+			assert_eq!(Balances::free_balance(CHARLIE), organizer_balance + 179);
+			assert_eq!(Balances::total_balance(&treasury_account), MockExistentialDeposit::get());
+			assert_eq!(NftStake::collected_fees(), 321);
+			assert_noop!(
+				NftStake::claim_collected_fees(RuntimeOrigin::signed(CHARLIE)),
+				Error::<Test>::NoFeesToClaim
+			);
+		});
+	}
+}
+
+mod migration {
+	use super::*;
+	use codec::Encode;
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	#[test]
+	fn migrate_to_v1_works() {
+		ExtBuilder::default().build().execute_with(|| {
+			StorageVersion::new(0).put::<NftStake>();
+
+			let reward = StakingRewardOf::<Test>::Tokens(1_000);
+			// conditions are encoded as the clauses stored before they could be combined
+			let clause = ContractCondition::HasAttribute(AttributeNamespace::Pallet, 10_u32);
+			let old_contract = (reward.clone(), vec![clause.clone()], 10_u64).encode();
+			frame_support::storage::unhashed::put_raw(
+				&ActiveContracts::<Test>::hashed_key_for(7),
+				&old_contract,
+			);
+
+			let _ = migration::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+			assert_eq!(NftStake::on_chain_storage_version(), 1);
+			assert_eq!(
+				NftStake::active_contracts(7),
+				Some(StakingContractOf::<Test>::new(reward, 10).with_clause(clause))
+			);
+		});
+	}
+}
+
+mod incrementable {
+	use super::*;
+	use sp_core::H256;
+
+	#[test]
+	fn increment_integers() {
+		assert_eq!(0_u32.increment(), Some(1));
+		assert_eq!(u128::MAX.increment(), None);
+	}
+
+	#[test]
+	fn increment_hashes() {
+		assert_eq!(H256::zero().increment(), Some(H256::from_low_u64_be(1)));
+		assert_eq!(
+			H256::from_low_u64_be(u64::MAX).increment(),
+			Some(H256::from_slice(&[&[0; 23][..], &[1], &[0; 8]].concat()))
+		);
+		assert_eq!(H256::repeat_byte(0xff).increment(), None);
+	}
+}
+
 fn contract_collection_id() -> MockCollectionId {
 	ContractCollectionId::<Test>::get().expect("Should get contract collection id")
 }
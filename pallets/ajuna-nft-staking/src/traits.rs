@@ -0,0 +1,192 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use frame_support::{
+	pallet_prelude::*,
+	traits::tokens::fungibles::{Create, Inspect, Mutate, Transfer},
+};
+use sp_core::{H256, U256};
+use sp_std::marker::PhantomData;
+
+/// Identifiers that can be issued in sequence, used to mint the staking contract NFTs.
+pub trait Incrementable: Sized {
+	/// Returns the identifier following `self`, or `None` if it would overflow.
+	fn increment(&self) -> Option<Self>;
+}
+
+macro_rules! impl_incrementable {
+	($($type:ty),+) => {
+		$(
+			impl Incrementable for $type {
+				fn increment(&self) -> Option<Self> {
+					self.checked_add(1)
+				}
+			}
+		)+
+	};
+}
+
+impl_incrementable!(u8, u16, u32, u64, u128);
+
+impl Incrementable for H256 {
+	fn increment(&self) -> Option<Self> {
+		let next = U256::from_big_endian(self.as_bytes()).checked_add(U256::one())?;
+		let mut bytes = [0_u8; 32];
+		next.to_big_endian(&mut bytes);
+		Some(H256(bytes))
+	}
+}
+
+/// Custody of the fungible assets, other than the native currency, used as contract rewards.
+pub trait RewardAssets<Account, Balance> {
+	type AssetId: Member + Parameter + Copy + MaxEncodedLen;
+
+	/// Whether contracts can be rewarded in assets at all.
+	fn is_available() -> bool;
+
+	/// Returns the amount of the asset `asset_id` held by `who`.
+	fn balance(asset_id: Self::AssetId, who: &Account) -> Balance;
+
+	/// Transfers `amount` of the asset `asset_id` from `source` to `dest`.
+	fn transfer(
+		asset_id: Self::AssetId,
+		source: &Account,
+		dest: &Account,
+		amount: Balance,
+	) -> DispatchResult;
+
+	/// Creates the asset `asset_id` and mints `amount` of it into `owner`.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_asset(asset_id: Self::AssetId, owner: &Account, amount: Balance) -> DispatchResult;
+}
+
+impl<Account, Balance: Default> RewardAssets<Account, Balance> for () {
+	type AssetId = u32;
+
+	fn is_available() -> bool {
+		false
+	}
+
+	fn balance(_: Self::AssetId, _: &Account) -> Balance {
+		Balance::default()
+	}
+
+	fn transfer(_: Self::AssetId, _: &Account, _: &Account, _: Balance) -> DispatchResult {
+		Err(DispatchError::Other("No reward assets available"))
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_asset(_: Self::AssetId, _: &Account, _: Balance) -> DispatchResult {
+		Err(DispatchError::Other("No reward assets available"))
+	}
+}
+
+/// Rewards in the assets of a `fungibles` implementation, such as `pallet_assets`.
+pub struct FungiblesRewards<Fungibles>(PhantomData<Fungibles>);
+
+impl<Account, Fungibles> RewardAssets<Account, <Fungibles as Inspect<Account>>::Balance>
+	for FungiblesRewards<Fungibles>
+where
+	Account: Clone,
+	Fungibles: Create<Account> + Mutate<Account> + Transfer<Account>,
+	<Fungibles as Inspect<Account>>::AssetId: Member + Parameter + Copy + MaxEncodedLen,
+{
+	type AssetId = <Fungibles as Inspect<Account>>::AssetId;
+
+	fn is_available() -> bool {
+		true
+	}
+
+	fn balance(asset_id: Self::AssetId, who: &Account) -> <Fungibles as Inspect<Account>>::Balance {
+		Fungibles::balance(asset_id, who)
+	}
+
+	fn transfer(
+		asset_id: Self::AssetId,
+		source: &Account,
+		dest: &Account,
+		amount: <Fungibles as Inspect<Account>>::Balance,
+	) -> DispatchResult {
+		Fungibles::transfer(asset_id, source, dest, amount, false).map(|_| ())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_asset(
+		asset_id: Self::AssetId,
+		owner: &Account,
+		amount: <Fungibles as Inspect<Account>>::Balance,
+	) -> DispatchResult {
+		Fungibles::create(asset_id, owner.clone(), true, 1_u32.into())?;
+		Fungibles::mint_into(asset_id, owner, amount)
+	}
+}
+
+/// Lends accounts the use of NFTs held by others, such as rented ones, to take contracts with.
+pub trait NftDelegation<Account, CollectionId, ItemId, BlockNumber> {
+	/// Returns the block until which `who` may stake the item `item_id` of `collection_id` on
+	/// behalf of its holder, or `None` if the item isn't lent to `who`.
+	fn delegated_until(
+		who: &Account,
+		collection_id: &CollectionId,
+		item_id: &ItemId,
+	) -> Option<BlockNumber>;
+
+	/// Whether the item `item_id` of `collection_id` is lent out or offered to be, in which case
+	/// its holder can't stake it.
+	fn is_lent(collection_id: &CollectionId, item_id: &ItemId) -> bool;
+}
+
+impl<Account, CollectionId, ItemId, BlockNumber>
+	NftDelegation<Account, CollectionId, ItemId, BlockNumber> for ()
+{
+	fn delegated_until(_: &Account, _: &CollectionId, _: &ItemId) -> Option<BlockNumber> {
+		None
+	}
+
+	fn is_lent(_: &CollectionId, _: &ItemId) -> bool {
+		false
+	}
+}
+
+/// Provides the runtime specific values required to benchmark the pallet.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<ContractAttributeKey, ContractAttributeValue, ItemId> {
+	/// Returns a contract attribute key built from `i`.
+	fn contract_key(i: u32) -> ContractAttributeKey;
+	/// Returns a contract attribute value built from `i`.
+	fn contract_value(i: u64) -> ContractAttributeValue;
+	/// Returns an item identifier built from `i`.
+	fn item_id(i: u16) -> ItemId;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<ContractAttributeKey, ContractAttributeValue, ItemId>
+	BenchmarkHelper<ContractAttributeKey, ContractAttributeValue, ItemId> for ()
+where
+	ContractAttributeKey: From<u32>,
+	ContractAttributeValue: From<u64>,
+	ItemId: From<u16>,
+{
+	fn contract_key(i: u32) -> ContractAttributeKey {
+		i.into()
+	}
+	fn contract_value(i: u64) -> ContractAttributeValue {
+		i.into()
+	}
+	fn item_id(i: u16) -> ItemId {
+		i.into()
+	}
+}
@@ -20,6 +20,11 @@
 //! DATE: 2023-03-22, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! HOSTNAME: `weight-calculation-didac`, CPU: `DO-Regular`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! Only the measured timings come from that run. Entries marked `Estimate, not benchmarked yet.`
+//! are hand-written, and the storage counts of the measured entries were extended by hand when
+//! contracts gained creators and open contract limits. Regenerate the file with
+//! `.maintain/weights.sh bajun` before release.
 
 // Executed Command:
 // ./target/release/bajun-para
@@ -54,6 +59,21 @@ pub trait WeightInfo {
 	fn take_staking_contract() -> Weight;
 	fn redeem_staking_contract_token_reward() -> Weight;
 	fn redeem_staking_contract_nft_reward() -> Weight;
+	fn cancel_staking_contract_token_reward() -> Weight;
+	fn cancel_staking_contract_nft_reward() -> Weight;
+	fn withdraw_staking_contract_token_reward() -> Weight;
+	fn withdraw_staking_contract_nft_reward() -> Weight;
+	fn expire_staking_contracts(n: u32, ) -> Weight;
+	fn submit_staking_contract_bundle_reward() -> Weight;
+	fn redeem_staking_contract_bundle_reward() -> Weight;
+	fn cancel_staking_contract_bundle_reward() -> Weight;
+	fn withdraw_staking_contract_bundle_reward() -> Weight;
+	fn claim_staking_contract_reward() -> Weight;
+	fn set_creator_config() -> Weight;
+	fn register_creator() -> Weight;
+	fn unregister_creator() -> Weight;
+	fn submit_staking_contract_asset_reward() -> Weight;
+	fn claim_collected_fees() -> Weight;
 }
 
 /// Weights for pallet_ajuna_nft_staking using the Substrate node and recommended hardware.
@@ -101,11 +121,15 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 	// Storage: Nft ItemConfigOf (r:1 w:1)
 	// Storage: NftStake ActiveContracts (r:0 w:1)
 	// Storage: Nft Account (r:0 w:1)
+	// Storage: NftStake CreatorConfiguration (r:1 w:0)
+	// Storage: NftStake Organizer (r:1 w:0)
+	// Storage: NftStake Creators (r:1 w:0)
+	// Storage: NftStake OpenContracts (r:1 w:1)
 	fn submit_staking_contract_token_reward() -> Weight {
 		// Minimum execution time: 139_906 nanoseconds.
 		Weight::from_ref_time(144_343_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(9 as u64))
-			.saturating_add(T::DbWeight::get().writes(7 as u64))
+			.saturating_add(T::DbWeight::get().reads(13 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
 	}
 	// Storage: NftStake LockedState (r:1 w:0)
 	// Storage: Nft Item (r:2 w:2)
@@ -120,33 +144,44 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 	// Storage: Nft Account (r:0 w:3)
 	// Storage: Nft ItemPriceOf (r:0 w:1)
 	// Storage: Nft PendingSwapOf (r:0 w:1)
+	// Storage: NftStake CreatorConfiguration (r:1 w:0)
+	// Storage: NftStake Organizer (r:1 w:0)
+	// Storage: NftStake Creators (r:1 w:0)
+	// Storage: NftStake OpenContracts (r:1 w:1)
 	fn submit_staking_contract_nft_reward() -> Weight {
 		// Minimum execution time: 178_955 nanoseconds.
 		Weight::from_ref_time(198_565_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(13 as u64))
-			.saturating_add(T::DbWeight::get().writes(12 as u64))
+			.saturating_add(T::DbWeight::get().reads(17 as u64))
+			.saturating_add(T::DbWeight::get().writes(13 as u64))
 	}
 	// Storage: NftStake LockedState (r:1 w:0)
 	// Storage: NftStake ContractOwners (r:1 w:1)
 	// Storage: NftStake ActiveContracts (r:1 w:0)
-	// Storage: Nft Attribute (r:10 w:0)
+	// Storage: Nft Attribute (r:500 w:0)
 	// Storage: NftStake TreasuryAccount (r:1 w:0)
 	// Storage: Nft Item (r:11 w:11)
 	// Storage: Nft Collection (r:2 w:0)
 	// Storage: Nft CollectionConfigOf (r:2 w:0)
 	// Storage: Nft ItemConfigOf (r:11 w:0)
-	// Storage: System Account (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
 	// Storage: NftStake ContractCollectionId (r:1 w:0)
 	// Storage: NftStake ContractDurations (r:0 w:1)
 	// Storage: NftStake ContractStakedAssets (r:0 w:1)
 	// Storage: Nft Account (r:0 w:22)
 	// Storage: Nft ItemPriceOf (r:0 w:11)
 	// Storage: Nft PendingSwapOf (r:0 w:11)
+	// Storage: NftStake ContractCreators (r:1 w:0)
+	// Storage: NftStake CreatorConfiguration (r:1 w:0)
+	// Storage: NftStake CollectedFees (r:1 w:1)
+	// Storage: AwesomeAvatars CollectionId (r:1 w:0)
+	// Storage: AwesomeAvatars Rentals (r:10 w:0)
+	// Storage: AwesomeAvatars RentalListings (r:10 w:0)
+	// Storage: NftStake StakedAssetLenders (r:0 w:10)
 	fn take_staking_contract() -> Weight {
-		// Minimum execution time: 789_389 nanoseconds.
-		Weight::from_ref_time(842_530_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(42 as u64))
-			.saturating_add(T::DbWeight::get().writes(59 as u64))
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(2_500_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(558 as u64))
+			.saturating_add(T::DbWeight::get().writes(72 as u64))
 	}
 	// Storage: NftStake LockedState (r:1 w:0)
 	// Storage: NftStake ContractOwners (r:1 w:1)
@@ -164,11 +199,13 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 	// Storage: Nft ItemPriceOf (r:0 w:11)
 	// Storage: Nft ItemAttributesApprovalsOf (r:0 w:1)
 	// Storage: Nft PendingSwapOf (r:0 w:11)
+	// Storage: NftStake OpenContracts (r:1 w:1)
+	// Storage: NftStake StakedAssetLenders (r:10 w:10)
 	fn redeem_staking_contract_token_reward() -> Weight {
 		// Minimum execution time: 544_620 nanoseconds.
 		Weight::from_ref_time(602_590_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(33 as u64))
-			.saturating_add(T::DbWeight::get().writes(62 as u64))
+			.saturating_add(T::DbWeight::get().reads(44 as u64))
+			.saturating_add(T::DbWeight::get().writes(73 as u64))
 	}
 	// Storage: NftStake LockedState (r:1 w:0)
 	// Storage: NftStake ContractOwners (r:1 w:1)
@@ -186,11 +223,303 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 	// Storage: Nft ItemPriceOf (r:0 w:12)
 	// Storage: Nft ItemAttributesApprovalsOf (r:0 w:1)
 	// Storage: Nft PendingSwapOf (r:0 w:12)
+	// Storage: NftStake OpenContracts (r:1 w:1)
+	// Storage: NftStake StakedAssetLenders (r:10 w:10)
 	fn redeem_staking_contract_nft_reward() -> Weight {
 		// Minimum execution time: 565_593 nanoseconds.
 		Weight::from_ref_time(621_952_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(37 as u64))
-			.saturating_add(T::DbWeight::get().writes(67 as u64))
+			.saturating_add(T::DbWeight::get().reads(48 as u64))
+			.saturating_add(T::DbWeight::get().writes(78 as u64))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake ActiveContracts (r:1 w:1)
+	// Storage: NftStake ContractCreators (r:1 w:1)
+	// Storage: NftStake ContractOwners (r:1 w:0)
+	// Storage: NftStake ContractAvailabilities (r:1 w:1)
+	// Storage: NftStake ContractExpiries (r:1 w:1)
+	// Storage: NftStake TreasuryAccount (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: NftStake ContractCollectionId (r:1 w:0)
+	// Storage: Nft Collection (r:1 w:1)
+	// Storage: Nft Item (r:1 w:1)
+	// Storage: Nft ItemConfigOf (r:1 w:1)
+	// Storage: Nft Account (r:0 w:1)
+	// Storage: Nft ItemPriceOf (r:0 w:1)
+	// Storage: Nft ItemAttributesApprovalsOf (r:0 w:1)
+	// Storage: Nft PendingSwapOf (r:0 w:1)
+	// Storage: NftStake OpenContracts (r:1 w:1)
+	fn cancel_staking_contract_token_reward() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(160_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(14 as u64))
+			.saturating_add(T::DbWeight::get().writes(15 as u64))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake ActiveContracts (r:1 w:1)
+	// Storage: NftStake ContractCreators (r:1 w:1)
+	// Storage: NftStake ContractOwners (r:1 w:0)
+	// Storage: NftStake ContractAvailabilities (r:1 w:1)
+	// Storage: NftStake ContractExpiries (r:1 w:1)
+	// Storage: NftStake TreasuryAccount (r:1 w:0)
+	// Storage: Nft Collection (r:2 w:2)
+	// Storage: Nft CollectionConfigOf (r:1 w:0)
+	// Storage: Nft Item (r:2 w:2)
+	// Storage: Nft ItemConfigOf (r:2 w:1)
+	// Storage: NftStake ContractCollectionId (r:1 w:0)
+	// Storage: Nft Account (r:0 w:3)
+	// Storage: Nft ItemPriceOf (r:0 w:2)
+	// Storage: Nft ItemAttributesApprovalsOf (r:0 w:1)
+	// Storage: Nft PendingSwapOf (r:0 w:2)
+	// Storage: NftStake OpenContracts (r:1 w:1)
+	fn cancel_staking_contract_nft_reward() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(190_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(16 as u64))
+			.saturating_add(T::DbWeight::get().writes(20 as u64))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake ContractOwners (r:1 w:1)
+	// Storage: NftStake ActiveContracts (r:1 w:1)
+	// Storage: NftStake ContractDurations (r:1 w:1)
+	// Storage: NftStake ContractCreators (r:1 w:1)
+	// Storage: NftStake ContractStakedAssets (r:1 w:1)
+	// Storage: NftStake TreasuryAccount (r:1 w:0)
+	// Storage: Nft Collection (r:2 w:1)
+	// Storage: Nft CollectionConfigOf (r:1 w:0)
+	// Storage: Nft ItemConfigOf (r:11 w:1)
+	// Storage: Nft Item (r:11 w:11)
+	// Storage: System Account (r:2 w:2)
+	// Storage: NftStake ContractCollectionId (r:1 w:0)
+	// Storage: Nft Account (r:0 w:21)
+	// Storage: Nft ItemPriceOf (r:0 w:11)
+	// Storage: Nft ItemAttributesApprovalsOf (r:0 w:1)
+	// Storage: Nft PendingSwapOf (r:0 w:11)
+	// Storage: NftStake OpenContracts (r:1 w:1)
+	// Storage: NftStake StakedAssetLenders (r:10 w:10)
+	fn withdraw_staking_contract_token_reward() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(620_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(46 as u64))
+			.saturating_add(T::DbWeight::get().writes(75 as u64))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake ContractOwners (r:1 w:1)
+	// Storage: NftStake ActiveContracts (r:1 w:1)
+	// Storage: NftStake ContractDurations (r:1 w:1)
+	// Storage: NftStake ContractCreators (r:1 w:1)
+	// Storage: NftStake ContractStakedAssets (r:1 w:1)
+	// Storage: NftStake TreasuryAccount (r:1 w:0)
+	// Storage: Nft Collection (r:3 w:1)
+	// Storage: Nft CollectionConfigOf (r:2 w:0)
+	// Storage: Nft ItemConfigOf (r:12 w:1)
+	// Storage: Nft Item (r:12 w:12)
+	// Storage: NftStake ContractCollectionId (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Nft Account (r:0 w:23)
+	// Storage: Nft ItemPriceOf (r:0 w:12)
+	// Storage: Nft ItemAttributesApprovalsOf (r:0 w:1)
+	// Storage: Nft PendingSwapOf (r:0 w:12)
+	// Storage: NftStake OpenContracts (r:1 w:1)
+	// Storage: NftStake StakedAssetLenders (r:10 w:10)
+	fn withdraw_staking_contract_nft_reward() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(650_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(49 as u64))
+			.saturating_add(T::DbWeight::get().writes(79 as u64))
+	}
+	// Storage: NftStake ContractExpiries (r:1 w:1)
+	// Storage: NftStake ActiveContracts (r:20 w:20)
+	// Storage: NftStake ContractCreators (r:20 w:20)
+	// Storage: NftStake ContractAvailabilities (r:0 w:20)
+	// Storage: NftStake OpenContracts (r:20 w:20)
+	// Storage: NftStake TreasuryAccount (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Nft Collection (r:21 w:21)
+	// Storage: Nft CollectionConfigOf (r:21 w:0)
+	// Storage: Nft Item (r:120 w:120)
+	// Storage: Nft ItemConfigOf (r:120 w:20)
+	// Storage: NftStake ContractCollectionId (r:1 w:0)
+	// Storage: Nft Account (r:0 w:220)
+	// Storage: Nft ItemPriceOf (r:0 w:120)
+	// Storage: Nft ItemAttributesApprovalsOf (r:0 w:20)
+	// Storage: Nft PendingSwapOf (r:0 w:120)
+	/// The range of component `n` is `[0, 20]`.
+	fn expire_staking_contracts(n: u32, ) -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(13_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(420_000_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().reads((17 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+			.saturating_add(T::DbWeight::get().writes((36 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake TreasuryAccount (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Nft Item (r:6 w:6)
+	// Storage: NftStake ContractCollectionId (r:1 w:0)
+	// Storage: Nft Collection (r:2 w:1)
+	// Storage: Nft CollectionConfigOf (r:2 w:0)
+	// Storage: Nft ItemConfigOf (r:6 w:1)
+	// Storage: NftStake NextContractId (r:1 w:1)
+	// Storage: NftStake ActiveContracts (r:0 w:1)
+	// Storage: NftStake ContractCreators (r:0 w:1)
+	// Storage: Nft Account (r:0 w:11)
+	// Storage: Nft ItemPriceOf (r:0 w:5)
+	// Storage: Nft PendingSwapOf (r:0 w:5)
+	// Storage: NftStake CreatorConfiguration (r:1 w:0)
+	// Storage: NftStake Organizer (r:1 w:0)
+	// Storage: NftStake Creators (r:1 w:0)
+	// Storage: NftStake OpenContracts (r:1 w:1)
+	fn submit_staking_contract_bundle_reward() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(440_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(25 as u64))
+			.saturating_add(T::DbWeight::get().writes(34 as u64))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake ContractOwners (r:1 w:1)
+	// Storage: NftStake ContractDurations (r:1 w:1)
+	// Storage: NftStake ContractStakedAssets (r:1 w:1)
+	// Storage: NftStake TreasuryAccount (r:1 w:0)
+	// Storage: Nft Collection (r:3 w:1)
+	// Storage: Nft CollectionConfigOf (r:2 w:0)
+	// Storage: Nft ItemConfigOf (r:16 w:1)
+	// Storage: Nft Item (r:16 w:16)
+	// Storage: NftStake ActiveContracts (r:1 w:1)
+	// Storage: NftStake ContractClaimedRewards (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: NftStake ContractCollectionId (r:1 w:0)
+	// Storage: Nft Account (r:0 w:31)
+	// Storage: Nft ItemPriceOf (r:0 w:16)
+	// Storage: Nft ItemAttributesApprovalsOf (r:0 w:1)
+	// Storage: Nft PendingSwapOf (r:0 w:16)
+	// Storage: NftStake OpenContracts (r:1 w:1)
+	// Storage: NftStake StakedAssetLenders (r:10 w:10)
+	fn redeem_staking_contract_bundle_reward() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(790_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(58 as u64))
+			.saturating_add(T::DbWeight::get().writes(100 as u64))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake ActiveContracts (r:1 w:1)
+	// Storage: NftStake ContractCreators (r:1 w:1)
+	// Storage: NftStake ContractOwners (r:1 w:0)
+	// Storage: NftStake ContractAvailabilities (r:1 w:1)
+	// Storage: NftStake ContractExpiries (r:1 w:1)
+	// Storage: NftStake TreasuryAccount (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Nft Collection (r:2 w:2)
+	// Storage: Nft CollectionConfigOf (r:1 w:0)
+	// Storage: Nft Item (r:6 w:6)
+	// Storage: Nft ItemConfigOf (r:6 w:1)
+	// Storage: NftStake ContractCollectionId (r:1 w:0)
+	// Storage: Nft Account (r:0 w:11)
+	// Storage: Nft ItemPriceOf (r:0 w:6)
+	// Storage: Nft ItemAttributesApprovalsOf (r:0 w:1)
+	// Storage: Nft PendingSwapOf (r:0 w:6)
+	// Storage: NftStake OpenContracts (r:1 w:1)
+	fn cancel_staking_contract_bundle_reward() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(370_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(26 as u64))
+			.saturating_add(T::DbWeight::get().writes(40 as u64))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake ContractOwners (r:1 w:1)
+	// Storage: NftStake ActiveContracts (r:1 w:1)
+	// Storage: NftStake ContractDurations (r:1 w:1)
+	// Storage: NftStake ContractCreators (r:1 w:1)
+	// Storage: NftStake ContractClaimedRewards (r:1 w:1)
+	// Storage: NftStake ContractStakedAssets (r:1 w:1)
+	// Storage: NftStake TreasuryAccount (r:1 w:0)
+	// Storage: Nft Collection (r:3 w:1)
+	// Storage: Nft CollectionConfigOf (r:2 w:0)
+	// Storage: Nft ItemConfigOf (r:16 w:1)
+	// Storage: Nft Item (r:16 w:16)
+	// Storage: System Account (r:3 w:3)
+	// Storage: NftStake ContractCollectionId (r:1 w:0)
+	// Storage: Nft Account (r:0 w:31)
+	// Storage: Nft ItemPriceOf (r:0 w:16)
+	// Storage: Nft ItemAttributesApprovalsOf (r:0 w:1)
+	// Storage: Nft PendingSwapOf (r:0 w:16)
+	// Storage: NftStake OpenContracts (r:1 w:1)
+	// Storage: NftStake StakedAssetLenders (r:10 w:10)
+	fn withdraw_staking_contract_bundle_reward() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(810_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(61 as u64))
+			.saturating_add(T::DbWeight::get().writes(102 as u64))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake ContractOwners (r:1 w:0)
+	// Storage: NftStake ActiveContracts (r:1 w:0)
+	// Storage: NftStake ContractDurations (r:1 w:0)
+	// Storage: NftStake ContractClaimedRewards (r:1 w:1)
+	// Storage: NftStake TreasuryAccount (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn claim_staking_contract_reward() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(65_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: NftStake Organizer (r:1 w:0)
+	// Storage: NftStake CreatorConfiguration (r:0 w:1)
+	fn set_creator_config() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(34_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: NftStake Organizer (r:1 w:0)
+	// Storage: NftStake Creators (r:0 w:1)
+	fn register_creator() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(36_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: NftStake Organizer (r:1 w:0)
+	// Storage: NftStake Creators (r:1 w:1)
+	fn unregister_creator() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(38_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake TreasuryAccount (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: NftStake ContractCollectionId (r:1 w:0)
+	// Storage: NftStake NextContractId (r:1 w:1)
+	// Storage: Nft Item (r:1 w:1)
+	// Storage: Nft Collection (r:1 w:1)
+	// Storage: Nft CollectionConfigOf (r:1 w:0)
+	// Storage: Nft ItemConfigOf (r:1 w:1)
+	// Storage: NftStake ActiveContracts (r:0 w:1)
+	// Storage: Nft Account (r:0 w:1)
+	// Storage: NftStake CreatorConfiguration (r:1 w:0)
+	// Storage: NftStake Organizer (r:1 w:0)
+	// Storage: NftStake Creators (r:1 w:0)
+	// Storage: NftStake OpenContracts (r:1 w:1)
+	fn submit_staking_contract_asset_reward() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(170_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(15 as u64))
+			.saturating_add(T::DbWeight::get().writes(10 as u64))
+	}
+	// Storage: NftStake Organizer (r:1 w:0)
+	// Storage: NftStake TreasuryAccount (r:1 w:0)
+	// Storage: NftStake CollectedFees (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn claim_collected_fees() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(60_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 }
 
@@ -238,11 +567,15 @@ impl WeightInfo for () {
 	// Storage: Nft ItemConfigOf (r:1 w:1)
 	// Storage: NftStake ActiveContracts (r:0 w:1)
 	// Storage: Nft Account (r:0 w:1)
+	// Storage: NftStake CreatorConfiguration (r:1 w:0)
+	// Storage: NftStake Organizer (r:1 w:0)
+	// Storage: NftStake Creators (r:1 w:0)
+	// Storage: NftStake OpenContracts (r:1 w:1)
 	fn submit_staking_contract_token_reward() -> Weight {
 		// Minimum execution time: 139_906 nanoseconds.
 		Weight::from_ref_time(144_343_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(9 as u64))
-			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+			.saturating_add(RocksDbWeight::get().reads(13 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
 	}
 	// Storage: NftStake LockedState (r:1 w:0)
 	// Storage: Nft Item (r:2 w:2)
@@ -257,33 +590,44 @@ impl WeightInfo for () {
 	// Storage: Nft Account (r:0 w:3)
 	// Storage: Nft ItemPriceOf (r:0 w:1)
 	// Storage: Nft PendingSwapOf (r:0 w:1)
+	// Storage: NftStake CreatorConfiguration (r:1 w:0)
+	// Storage: NftStake Organizer (r:1 w:0)
+	// Storage: NftStake Creators (r:1 w:0)
+	// Storage: NftStake OpenContracts (r:1 w:1)
 	fn submit_staking_contract_nft_reward() -> Weight {
 		// Minimum execution time: 178_955 nanoseconds.
 		Weight::from_ref_time(198_565_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(13 as u64))
-			.saturating_add(RocksDbWeight::get().writes(12 as u64))
+			.saturating_add(RocksDbWeight::get().reads(17 as u64))
+			.saturating_add(RocksDbWeight::get().writes(13 as u64))
 	}
 	// Storage: NftStake LockedState (r:1 w:0)
 	// Storage: NftStake ContractOwners (r:1 w:1)
 	// Storage: NftStake ActiveContracts (r:1 w:0)
-	// Storage: Nft Attribute (r:10 w:0)
+	// Storage: Nft Attribute (r:500 w:0)
 	// Storage: NftStake TreasuryAccount (r:1 w:0)
 	// Storage: Nft Item (r:11 w:11)
 	// Storage: Nft Collection (r:2 w:0)
 	// Storage: Nft CollectionConfigOf (r:2 w:0)
 	// Storage: Nft ItemConfigOf (r:11 w:0)
-	// Storage: System Account (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
 	// Storage: NftStake ContractCollectionId (r:1 w:0)
 	// Storage: NftStake ContractDurations (r:0 w:1)
 	// Storage: NftStake ContractStakedAssets (r:0 w:1)
 	// Storage: Nft Account (r:0 w:22)
 	// Storage: Nft ItemPriceOf (r:0 w:11)
 	// Storage: Nft PendingSwapOf (r:0 w:11)
+	// Storage: NftStake ContractCreators (r:1 w:0)
+	// Storage: NftStake CreatorConfiguration (r:1 w:0)
+	// Storage: NftStake CollectedFees (r:1 w:1)
+	// Storage: AwesomeAvatars CollectionId (r:1 w:0)
+	// Storage: AwesomeAvatars Rentals (r:10 w:0)
+	// Storage: AwesomeAvatars RentalListings (r:10 w:0)
+	// Storage: NftStake StakedAssetLenders (r:0 w:10)
 	fn take_staking_contract() -> Weight {
-		// Minimum execution time: 789_389 nanoseconds.
-		Weight::from_ref_time(842_530_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(42 as u64))
-			.saturating_add(RocksDbWeight::get().writes(59 as u64))
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(2_500_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(558 as u64))
+			.saturating_add(RocksDbWeight::get().writes(72 as u64))
 	}
 	// Storage: NftStake LockedState (r:1 w:0)
 	// Storage: NftStake ContractOwners (r:1 w:1)
@@ -301,11 +645,13 @@ impl WeightInfo for () {
 	// Storage: Nft ItemPriceOf (r:0 w:11)
 	// Storage: Nft ItemAttributesApprovalsOf (r:0 w:1)
 	// Storage: Nft PendingSwapOf (r:0 w:11)
+	// Storage: NftStake OpenContracts (r:1 w:1)
+	// Storage: NftStake StakedAssetLenders (r:10 w:10)
 	fn redeem_staking_contract_token_reward() -> Weight {
 		// Minimum execution time: 544_620 nanoseconds.
 		Weight::from_ref_time(602_590_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(33 as u64))
-			.saturating_add(RocksDbWeight::get().writes(62 as u64))
+			.saturating_add(RocksDbWeight::get().reads(44 as u64))
+			.saturating_add(RocksDbWeight::get().writes(73 as u64))
 	}
 	// Storage: NftStake LockedState (r:1 w:0)
 	// Storage: NftStake ContractOwners (r:1 w:1)
@@ -323,10 +669,302 @@ impl WeightInfo for () {
 	// Storage: Nft ItemPriceOf (r:0 w:12)
 	// Storage: Nft ItemAttributesApprovalsOf (r:0 w:1)
 	// Storage: Nft PendingSwapOf (r:0 w:12)
+	// Storage: NftStake OpenContracts (r:1 w:1)
+	// Storage: NftStake StakedAssetLenders (r:10 w:10)
 	fn redeem_staking_contract_nft_reward() -> Weight {
 		// Minimum execution time: 565_593 nanoseconds.
 		Weight::from_ref_time(621_952_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(37 as u64))
-			.saturating_add(RocksDbWeight::get().writes(67 as u64))
+			.saturating_add(RocksDbWeight::get().reads(48 as u64))
+			.saturating_add(RocksDbWeight::get().writes(78 as u64))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake ActiveContracts (r:1 w:1)
+	// Storage: NftStake ContractCreators (r:1 w:1)
+	// Storage: NftStake ContractOwners (r:1 w:0)
+	// Storage: NftStake ContractAvailabilities (r:1 w:1)
+	// Storage: NftStake ContractExpiries (r:1 w:1)
+	// Storage: NftStake TreasuryAccount (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: NftStake ContractCollectionId (r:1 w:0)
+	// Storage: Nft Collection (r:1 w:1)
+	// Storage: Nft Item (r:1 w:1)
+	// Storage: Nft ItemConfigOf (r:1 w:1)
+	// Storage: Nft Account (r:0 w:1)
+	// Storage: Nft ItemPriceOf (r:0 w:1)
+	// Storage: Nft ItemAttributesApprovalsOf (r:0 w:1)
+	// Storage: Nft PendingSwapOf (r:0 w:1)
+	// Storage: NftStake OpenContracts (r:1 w:1)
+	fn cancel_staking_contract_token_reward() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(160_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(14 as u64))
+			.saturating_add(RocksDbWeight::get().writes(15 as u64))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake ActiveContracts (r:1 w:1)
+	// Storage: NftStake ContractCreators (r:1 w:1)
+	// Storage: NftStake ContractOwners (r:1 w:0)
+	// Storage: NftStake ContractAvailabilities (r:1 w:1)
+	// Storage: NftStake ContractExpiries (r:1 w:1)
+	// Storage: NftStake TreasuryAccount (r:1 w:0)
+	// Storage: Nft Collection (r:2 w:2)
+	// Storage: Nft CollectionConfigOf (r:1 w:0)
+	// Storage: Nft Item (r:2 w:2)
+	// Storage: Nft ItemConfigOf (r:2 w:1)
+	// Storage: NftStake ContractCollectionId (r:1 w:0)
+	// Storage: Nft Account (r:0 w:3)
+	// Storage: Nft ItemPriceOf (r:0 w:2)
+	// Storage: Nft ItemAttributesApprovalsOf (r:0 w:1)
+	// Storage: Nft PendingSwapOf (r:0 w:2)
+	// Storage: NftStake OpenContracts (r:1 w:1)
+	fn cancel_staking_contract_nft_reward() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(190_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(16 as u64))
+			.saturating_add(RocksDbWeight::get().writes(20 as u64))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake ContractOwners (r:1 w:1)
+	// Storage: NftStake ActiveContracts (r:1 w:1)
+	// Storage: NftStake ContractDurations (r:1 w:1)
+	// Storage: NftStake ContractCreators (r:1 w:1)
+	// Storage: NftStake ContractStakedAssets (r:1 w:1)
+	// Storage: NftStake TreasuryAccount (r:1 w:0)
+	// Storage: Nft Collection (r:2 w:1)
+	// Storage: Nft CollectionConfigOf (r:1 w:0)
+	// Storage: Nft ItemConfigOf (r:11 w:1)
+	// Storage: Nft Item (r:11 w:11)
+	// Storage: System Account (r:2 w:2)
+	// Storage: NftStake ContractCollectionId (r:1 w:0)
+	// Storage: Nft Account (r:0 w:21)
+	// Storage: Nft ItemPriceOf (r:0 w:11)
+	// Storage: Nft ItemAttributesApprovalsOf (r:0 w:1)
+	// Storage: Nft PendingSwapOf (r:0 w:11)
+	// Storage: NftStake OpenContracts (r:1 w:1)
+	// Storage: NftStake StakedAssetLenders (r:10 w:10)
+	fn withdraw_staking_contract_token_reward() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(620_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(46 as u64))
+			.saturating_add(RocksDbWeight::get().writes(75 as u64))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake ContractOwners (r:1 w:1)
+	// Storage: NftStake ActiveContracts (r:1 w:1)
+	// Storage: NftStake ContractDurations (r:1 w:1)
+	// Storage: NftStake ContractCreators (r:1 w:1)
+	// Storage: NftStake ContractStakedAssets (r:1 w:1)
+	// Storage: NftStake TreasuryAccount (r:1 w:0)
+	// Storage: Nft Collection (r:3 w:1)
+	// Storage: Nft CollectionConfigOf (r:2 w:0)
+	// Storage: Nft ItemConfigOf (r:12 w:1)
+	// Storage: Nft Item (r:12 w:12)
+	// Storage: NftStake ContractCollectionId (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Nft Account (r:0 w:23)
+	// Storage: Nft ItemPriceOf (r:0 w:12)
+	// Storage: Nft ItemAttributesApprovalsOf (r:0 w:1)
+	// Storage: Nft PendingSwapOf (r:0 w:12)
+	// Storage: NftStake OpenContracts (r:1 w:1)
+	// Storage: NftStake StakedAssetLenders (r:10 w:10)
+	fn withdraw_staking_contract_nft_reward() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(650_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(49 as u64))
+			.saturating_add(RocksDbWeight::get().writes(79 as u64))
+	}
+	// Storage: NftStake ContractExpiries (r:1 w:1)
+	// Storage: NftStake ActiveContracts (r:20 w:20)
+	// Storage: NftStake ContractCreators (r:20 w:20)
+	// Storage: NftStake ContractAvailabilities (r:0 w:20)
+	// Storage: NftStake OpenContracts (r:20 w:20)
+	// Storage: NftStake TreasuryAccount (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Nft Collection (r:21 w:21)
+	// Storage: Nft CollectionConfigOf (r:21 w:0)
+	// Storage: Nft Item (r:120 w:120)
+	// Storage: Nft ItemConfigOf (r:120 w:20)
+	// Storage: NftStake ContractCollectionId (r:1 w:0)
+	// Storage: Nft Account (r:0 w:220)
+	// Storage: Nft ItemPriceOf (r:0 w:120)
+	// Storage: Nft ItemAttributesApprovalsOf (r:0 w:20)
+	// Storage: Nft PendingSwapOf (r:0 w:120)
+	/// The range of component `n` is `[0, 20]`.
+	fn expire_staking_contracts(n: u32, ) -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(13_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(420_000_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().reads((17 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes((36 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake TreasuryAccount (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Nft Item (r:6 w:6)
+	// Storage: NftStake ContractCollectionId (r:1 w:0)
+	// Storage: Nft Collection (r:2 w:1)
+	// Storage: Nft CollectionConfigOf (r:2 w:0)
+	// Storage: Nft ItemConfigOf (r:6 w:1)
+	// Storage: NftStake NextContractId (r:1 w:1)
+	// Storage: NftStake ActiveContracts (r:0 w:1)
+	// Storage: NftStake ContractCreators (r:0 w:1)
+	// Storage: Nft Account (r:0 w:11)
+	// Storage: Nft ItemPriceOf (r:0 w:5)
+	// Storage: Nft PendingSwapOf (r:0 w:5)
+	// Storage: NftStake CreatorConfiguration (r:1 w:0)
+	// Storage: NftStake Organizer (r:1 w:0)
+	// Storage: NftStake Creators (r:1 w:0)
+	// Storage: NftStake OpenContracts (r:1 w:1)
+	fn submit_staking_contract_bundle_reward() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(440_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(25 as u64))
+			.saturating_add(RocksDbWeight::get().writes(34 as u64))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake ContractOwners (r:1 w:1)
+	// Storage: NftStake ContractDurations (r:1 w:1)
+	// Storage: NftStake ContractStakedAssets (r:1 w:1)
+	// Storage: NftStake TreasuryAccount (r:1 w:0)
+	// Storage: Nft Collection (r:3 w:1)
+	// Storage: Nft CollectionConfigOf (r:2 w:0)
+	// Storage: Nft ItemConfigOf (r:16 w:1)
+	// Storage: Nft Item (r:16 w:16)
+	// Storage: NftStake ActiveContracts (r:1 w:1)
+	// Storage: NftStake ContractClaimedRewards (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: NftStake ContractCollectionId (r:1 w:0)
+	// Storage: Nft Account (r:0 w:31)
+	// Storage: Nft ItemPriceOf (r:0 w:16)
+	// Storage: Nft ItemAttributesApprovalsOf (r:0 w:1)
+	// Storage: Nft PendingSwapOf (r:0 w:16)
+	// Storage: NftStake OpenContracts (r:1 w:1)
+	// Storage: NftStake StakedAssetLenders (r:10 w:10)
+	fn redeem_staking_contract_bundle_reward() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(790_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(58 as u64))
+			.saturating_add(RocksDbWeight::get().writes(100 as u64))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake ActiveContracts (r:1 w:1)
+	// Storage: NftStake ContractCreators (r:1 w:1)
+	// Storage: NftStake ContractOwners (r:1 w:0)
+	// Storage: NftStake ContractAvailabilities (r:1 w:1)
+	// Storage: NftStake ContractExpiries (r:1 w:1)
+	// Storage: NftStake TreasuryAccount (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Nft Collection (r:2 w:2)
+	// Storage: Nft CollectionConfigOf (r:1 w:0)
+	// Storage: Nft Item (r:6 w:6)
+	// Storage: Nft ItemConfigOf (r:6 w:1)
+	// Storage: NftStake ContractCollectionId (r:1 w:0)
+	// Storage: Nft Account (r:0 w:11)
+	// Storage: Nft ItemPriceOf (r:0 w:6)
+	// Storage: Nft ItemAttributesApprovalsOf (r:0 w:1)
+	// Storage: Nft PendingSwapOf (r:0 w:6)
+	// Storage: NftStake OpenContracts (r:1 w:1)
+	fn cancel_staking_contract_bundle_reward() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(370_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(26 as u64))
+			.saturating_add(RocksDbWeight::get().writes(40 as u64))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake ContractOwners (r:1 w:1)
+	// Storage: NftStake ActiveContracts (r:1 w:1)
+	// Storage: NftStake ContractDurations (r:1 w:1)
+	// Storage: NftStake ContractCreators (r:1 w:1)
+	// Storage: NftStake ContractClaimedRewards (r:1 w:1)
+	// Storage: NftStake ContractStakedAssets (r:1 w:1)
+	// Storage: NftStake TreasuryAccount (r:1 w:0)
+	// Storage: Nft Collection (r:3 w:1)
+	// Storage: Nft CollectionConfigOf (r:2 w:0)
+	// Storage: Nft ItemConfigOf (r:16 w:1)
+	// Storage: Nft Item (r:16 w:16)
+	// Storage: System Account (r:3 w:3)
+	// Storage: NftStake ContractCollectionId (r:1 w:0)
+	// Storage: Nft Account (r:0 w:31)
+	// Storage: Nft ItemPriceOf (r:0 w:16)
+	// Storage: Nft ItemAttributesApprovalsOf (r:0 w:1)
+	// Storage: Nft PendingSwapOf (r:0 w:16)
+	// Storage: NftStake OpenContracts (r:1 w:1)
+	// Storage: NftStake StakedAssetLenders (r:10 w:10)
+	fn withdraw_staking_contract_bundle_reward() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(810_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(61 as u64))
+			.saturating_add(RocksDbWeight::get().writes(102 as u64))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake ContractOwners (r:1 w:0)
+	// Storage: NftStake ActiveContracts (r:1 w:0)
+	// Storage: NftStake ContractDurations (r:1 w:0)
+	// Storage: NftStake ContractClaimedRewards (r:1 w:1)
+	// Storage: NftStake TreasuryAccount (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn claim_staking_contract_reward() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(65_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: NftStake Organizer (r:1 w:0)
+	// Storage: NftStake CreatorConfiguration (r:0 w:1)
+	fn set_creator_config() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(34_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: NftStake Organizer (r:1 w:0)
+	// Storage: NftStake Creators (r:0 w:1)
+	fn register_creator() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(36_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: NftStake Organizer (r:1 w:0)
+	// Storage: NftStake Creators (r:1 w:1)
+	fn unregister_creator() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(38_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake TreasuryAccount (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: NftStake ContractCollectionId (r:1 w:0)
+	// Storage: NftStake NextContractId (r:1 w:1)
+	// Storage: Nft Item (r:1 w:1)
+	// Storage: Nft Collection (r:1 w:1)
+	// Storage: Nft CollectionConfigOf (r:1 w:0)
+	// Storage: Nft ItemConfigOf (r:1 w:1)
+	// Storage: NftStake ActiveContracts (r:0 w:1)
+	// Storage: Nft Account (r:0 w:1)
+	// Storage: NftStake CreatorConfiguration (r:1 w:0)
+	// Storage: NftStake Organizer (r:1 w:0)
+	// Storage: NftStake Creators (r:1 w:0)
+	// Storage: NftStake OpenContracts (r:1 w:1)
+	fn submit_staking_contract_asset_reward() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(170_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(15 as u64))
+			.saturating_add(RocksDbWeight::get().writes(10 as u64))
+	}
+	// Storage: NftStake Organizer (r:1 w:0)
+	// Storage: NftStake TreasuryAccount (r:1 w:0)
+	// Storage: NftStake CollectedFees (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn claim_collected_fees() -> Weight {
+		// Estimate, not benchmarked yet.
+		Weight::from_ref_time(60_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
 	}
 }
@@ -0,0 +1,44 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as NftTransfer;
+use codec::Decode;
+use frame_benchmarking::benchmarks;
+use frame_support::traits::EnsureOrigin;
+use sp_runtime::traits::TrailingZeroInput;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+	confirm_return {
+		let origin = T::UploadOrigin::try_successful_origin()
+			.map_err(|_| "UploadOrigin has no successful origin")?;
+		let collection_id = T::CollectionId::from(0_u32);
+		let item_id = T::ItemId::decode(&mut TrailingZeroInput::zeroes())
+			.expect("Infinite input decodes an item id");
+		NftStatuses::<T>::insert(collection_id, item_id, NftStatus::Uploaded);
+	}: _<T::RuntimeOrigin>(origin, collection_id, item_id)
+	verify {
+		assert_last_event::<T>(Event::ItemReturned { collection_id, item_id }.into())
+	}
+
+	impl_benchmark_test_suite!(NftTransfer, crate::mock::ExtBuilder::default().build(), crate::mock::Test);
+}
@@ -24,11 +24,15 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 pub mod traits;
+pub mod weights;
 
 #[frame_support::pallet]
 pub mod pallet {
-	use crate::traits::*;
+	use crate::{traits::*, weights::WeightInfo};
 	use frame_support::{
 		pallet_prelude::*,
 		traits::{
@@ -40,7 +44,10 @@ pub mod pallet {
 		},
 		PalletId,
 	};
-	use sp_runtime::traits::{AccountIdConversion, AtLeast32BitUnsigned};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{
+		AccountIdConversion, AtLeast32BitUnsigned, MaybeSerializeDeserialize,
+	};
 
 	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, Eq, PartialEq)]
 	pub enum NftStatus {
@@ -64,7 +71,12 @@ pub mod pallet {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		/// Identifier for the collection of item.
-		type CollectionId: Member + Parameter + MaxEncodedLen + Copy + AtLeast32BitUnsigned;
+		type CollectionId: Member
+			+ Parameter
+			+ MaxEncodedLen
+			+ Copy
+			+ AtLeast32BitUnsigned
+			+ MaybeSerializeDeserialize;
 
 		/// The type used to identify a unique item within a collection.
 		type ItemId: Member + Parameter + MaxEncodedLen + Copy;
@@ -82,6 +94,19 @@ pub mod pallet {
 		/// An NFT helper for the management of collections and items.
 		type NftHelper: Inspect<Self::AccountId, CollectionId = Self::CollectionId, ItemId = Self::ItemId>
 			+ Mutate<Self::AccountId, Self::ItemConfig>;
+
+		/// The handler in charge of uploading NFTs outside of the chain.
+		type NftUploader: NftUploader<Self::AccountId, Self::CollectionId, Self::ItemId>;
+
+		/// The maximum number of queued uploads to be processed in a single block.
+		#[pallet::constant]
+		type MaxUploadsPerBlock: Get<u32>;
+
+		/// The origin allowed to confirm the return of uploaded NFTs back into the chain.
+		type UploadOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for the pallet's extrinsics.
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::storage]
@@ -89,6 +114,19 @@ pub mod pallet {
 	pub type NftStatuses<T: Config> =
 		StorageDoubleMap<_, Identity, T::CollectionId, Identity, T::ItemId, NftStatus, OptionQuery>;
 
+	/// Uploads waiting to be processed by the uploader, along with the owner requesting them.
+	#[pallet::storage]
+	#[pallet::getter(fn upload_queue)]
+	pub type UploadQueue<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		T::CollectionId,
+		Identity,
+		T::ItemId,
+		T::AccountId,
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -96,6 +134,20 @@ pub mod pallet {
 		ItemStored { collection_id: T::CollectionId, item_id: T::ItemId, owner: T::AccountId },
 		/// Item has been restored back from its NFT representation [collection_id, item_id, owner]
 		ItemRestored { collection_id: T::CollectionId, item_id: T::ItemId, owner: T::AccountId },
+		/// Item has been queued to be uploaded outside of the chain [collection_id, item_id,
+		/// owner]
+		ItemUploadScheduled {
+			collection_id: T::CollectionId,
+			item_id: T::ItemId,
+			owner: T::AccountId,
+		},
+		/// Item has been uploaded outside of the chain [collection_id, item_id, owner]
+		ItemUploaded { collection_id: T::CollectionId, item_id: T::ItemId, owner: T::AccountId },
+		/// Item could not be uploaded and remains stored in the chain [collection_id, item_id,
+		/// owner]
+		ItemUploadFailed { collection_id: T::CollectionId, item_id: T::ItemId, owner: T::AccountId },
+		/// Item has been returned back into the chain [collection_id, item_id]
+		ItemReturned { collection_id: T::CollectionId, item_id: T::ItemId },
 	}
 
 	#[pallet::error]
@@ -115,6 +167,52 @@ pub mod pallet {
 		NftOutsideOfChain,
 		/// The process of restoring an NFT into an item has failed.
 		ItemRestoreFailure,
+		/// The given NFT is not uploaded outside of the chain.
+		NftNotUploaded,
+		/// The given NFT is still waiting to be uploaded.
+		UploadPending,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_initialize(_: T::BlockNumber) -> Weight {
+			let mut weight = T::DbWeight::get().reads(1);
+
+			let max_uploads = T::MaxUploadsPerBlock::get() as usize;
+			for (collection_id, item_id, owner) in UploadQueue::<T>::drain().take(max_uploads) {
+				weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+				Self::process_upload(&mut weight, collection_id, item_id, owner);
+			}
+
+			weight
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Confirms that an uploaded NFT has been transported back into the chain, unlocking it.
+		#[pallet::weight(T::WeightInfo::confirm_return())]
+		#[pallet::call_index(0)]
+		pub fn confirm_return(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			item_id: T::ItemId,
+		) -> DispatchResult {
+			T::UploadOrigin::ensure_origin(origin)?;
+			ensure!(
+				NftStatuses::<T>::get(collection_id, item_id) == Some(NftStatus::Uploaded),
+				Error::<T>::NftNotUploaded
+			);
+			ensure!(
+				!UploadQueue::<T>::contains_key(collection_id, item_id),
+				Error::<T>::UploadPending
+			);
+
+			NftStatuses::<T>::insert(collection_id, item_id, NftStatus::Stored);
+
+			Self::deposit_event(Event::<T>::ItemReturned { collection_id, item_id });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -122,6 +220,27 @@ pub mod pallet {
 		pub fn account_id() -> T::AccountId {
 			T::PalletId::get().into_account_truncating()
 		}
+
+		fn process_upload(
+			weight: &mut Weight,
+			collection_id: T::CollectionId,
+			item_id: T::ItemId,
+			owner: T::AccountId,
+		) {
+			match T::NftUploader::upload(&owner, &collection_id, &item_id) {
+				Ok(()) =>
+					Self::deposit_event(Event::<T>::ItemUploaded { collection_id, item_id, owner }),
+				Err(_) => {
+					NftStatuses::<T>::insert(collection_id, item_id, NftStatus::Stored);
+					weight.saturating_accrue(T::DbWeight::get().writes(1));
+					Self::deposit_event(Event::<T>::ItemUploadFailed {
+						collection_id,
+						item_id,
+						owner,
+					});
+				},
+			}
+		}
 	}
 
 	impl<T: Config, Item: NftConvertible> NftHandler<T::AccountId, T::ItemId, Item> for Pallet<T> {
@@ -203,18 +322,36 @@ pub mod pallet {
 			Ok(item)
 		}
 
+		fn owner(collection_id: Self::CollectionId, item_id: T::ItemId) -> Option<T::AccountId> {
+			T::NftHelper::owner(&collection_id, &item_id)
+		}
+
 		fn schedule_upload(
-			_owner: T::AccountId,
-			_collection_id: Self::CollectionId,
-			_item_id: T::ItemId,
+			owner: T::AccountId,
+			collection_id: Self::CollectionId,
+			item_id: T::ItemId,
 		) -> DispatchResult {
-			todo!()
+			let nft_owner =
+				T::NftHelper::owner(&collection_id, &item_id).ok_or(Error::<T>::UnknownItem)?;
+			ensure!(nft_owner == owner, Error::<T>::NftNotOwned);
+			ensure!(
+				NftStatuses::<T>::get(collection_id, item_id) == Some(NftStatus::Stored),
+				Error::<T>::NftOutsideOfChain
+			);
+
+			NftStatuses::<T>::insert(collection_id, item_id, NftStatus::Uploaded);
+			UploadQueue::<T>::insert(collection_id, item_id, &owner);
+
+			Self::deposit_event(Event::<T>::ItemUploadScheduled { collection_id, item_id, owner });
+			Ok(())
 		}
 	}
 
+	/// Only NFTs uploaded outside of the chain are locked, stored NFTs can still be transferred,
+	/// e.g. to be staked.
 	impl<T: Config> Locker<T::CollectionId, T::ItemId> for Pallet<T> {
 		fn is_locked(collection_id: T::CollectionId, item_id: T::ItemId) -> bool {
-			NftStatuses::<T>::contains_key(collection_id, item_id)
+			NftStatuses::<T>::get(collection_id, item_id) == Some(NftStatus::Uploaded)
 		}
 	}
 }
@@ -14,10 +14,11 @@
 // You should have received a copy of the GNU Affero General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{self as pallet_ajuna_nft_transfer};
+use crate::{self as pallet_ajuna_nft_transfer, traits::NftUploader};
 use frame_support::{
+	dispatch::{DispatchError, DispatchResult},
 	parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU16, ConstU64},
+	traits::{AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64, Hooks},
 	PalletId,
 };
 use frame_system::{
@@ -30,6 +31,7 @@ use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
 	BuildStorage,
 };
+use std::cell::RefCell;
 
 pub type MockAccountId = u32;
 pub type MockBlockNumber = u64;
@@ -163,6 +165,37 @@ parameter_types! {
 	pub const NftTransferPalletId: PalletId = PalletId(*b"aj/nfttr");
 }
 
+thread_local! {
+	pub static UPLOADED_ITEMS: RefCell<Vec<(MockCollectionId, H256)>> = RefCell::new(Vec::new());
+	pub static UPLOADER_FAILS: RefCell<bool> = RefCell::new(false);
+}
+
+pub struct MockUploader;
+
+impl MockUploader {
+	pub fn uploaded_items() -> Vec<(MockCollectionId, H256)> {
+		UPLOADED_ITEMS.with(|items| items.borrow().clone())
+	}
+
+	pub fn set_failing(fails: bool) {
+		UPLOADER_FAILS.with(|flag| *flag.borrow_mut() = fails);
+	}
+}
+
+impl NftUploader<MockAccountId, MockCollectionId, H256> for MockUploader {
+	fn upload(
+		_owner: &MockAccountId,
+		collection_id: &MockCollectionId,
+		item_id: &H256,
+	) -> DispatchResult {
+		if UPLOADER_FAILS.with(|flag| *flag.borrow()) {
+			return Err(DispatchError::Other("Upload failed"))
+		}
+		UPLOADED_ITEMS.with(|items| items.borrow_mut().push((*collection_id, *item_id)));
+		Ok(())
+	}
+}
+
 impl pallet_ajuna_nft_transfer::Config for Test {
 	type PalletId = NftTransferPalletId;
 	type RuntimeEvent = RuntimeEvent;
@@ -170,6 +203,10 @@ impl pallet_ajuna_nft_transfer::Config for Test {
 	type ItemId = H256;
 	type ItemConfig = pallet_nfts::ItemConfig;
 	type NftHelper = Nft;
+	type NftUploader = MockUploader;
+	type MaxUploadsPerBlock = ConstU32<2>;
+	type UploadOrigin = EnsureRoot<MockAccountId>;
+	type WeightInfo = ();
 }
 
 pub struct ExtBuilder {
@@ -194,3 +231,11 @@ impl ExtBuilder {
 		ext
 	}
 }
+
+pub fn run_to_block(n: MockBlockNumber) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		System::on_initialize(System::block_number());
+		NftTransfer::on_initialize(System::block_number());
+	}
+}
@@ -18,9 +18,13 @@ use crate::{mock::*, traits::*, Error, *};
 use codec::{Decode, Encode};
 use frame_support::{
 	assert_err, assert_noop, assert_ok,
-	traits::tokens::{
-		nonfungibles_v2::{Create, Inspect},
-		AttributeNamespace,
+	dispatch::{DispatchError, DispatchResult},
+	traits::{
+		tokens::{
+			nonfungibles_v2::{Create, Inspect},
+			AttributeNamespace,
+		},
+		Get,
 	},
 };
 use sp_runtime::testing::H256;
@@ -67,6 +71,27 @@ fn create_collection(organizer: MockAccountId) -> MockCollectionId {
 	.expect("Should have create contract collection")
 }
 
+fn store_item(
+	owner: MockAccountId,
+	collection_id: MockCollectionId,
+	item_id: H256,
+	url: IpfsUrl,
+) -> DispatchResult {
+	NftTransfer::store_as_nft(owner, collection_id, item_id, MockItem::default(), url)
+}
+
+fn schedule_upload(
+	owner: MockAccountId,
+	collection_id: MockCollectionId,
+	item_id: H256,
+) -> DispatchResult {
+	<NftTransfer as NftHandler<MockAccountId, H256, MockItem>>::schedule_upload(
+		owner,
+		collection_id,
+		item_id,
+	)
+}
+
 mod store_as_nft {
 	use super::*;
 
@@ -87,6 +112,10 @@ mod store_as_nft {
 			));
 			assert_eq!(Nft::collection_owner(collection_id), Some(ALICE));
 			assert_eq!(Nft::owner(collection_id, item_id), Some(BOB));
+			assert_eq!(
+				<NftTransfer as NftHandler<_, _, MockItem>>::owner(collection_id, item_id),
+				Some(BOB)
+			);
 			assert_eq!(
 				Nft::typed_attribute::<AttributeCode, MockItem>(
 					&collection_id,
@@ -267,3 +296,293 @@ mod recover_from_nft {
 		});
 	}
 }
+
+mod schedule_upload {
+	use super::*;
+
+	#[test]
+	fn can_schedule_upload_successfully() {
+		ExtBuilder::default().build().execute_with(|| {
+			let collection_id = create_collection(ALICE);
+			let item_id = H256::random();
+			let url = b"ipfs://test".to_vec();
+
+			assert_ok!(store_item(BOB, collection_id, item_id, url));
+			assert_ok!(schedule_upload(BOB, collection_id, item_id));
+
+			assert_eq!(
+				NftTransfer::nft_statuses(collection_id, item_id),
+				Some(NftStatus::Uploaded)
+			);
+			assert_eq!(NftTransfer::upload_queue(collection_id, item_id), Some(BOB));
+
+			System::assert_last_event(mock::RuntimeEvent::NftTransfer(
+				crate::Event::ItemUploadScheduled { collection_id, item_id, owner: BOB },
+			));
+		});
+	}
+
+	#[test]
+	fn cannot_restore_scheduled_item() {
+		ExtBuilder::default().build().execute_with(|| {
+			let collection_id = create_collection(ALICE);
+			let item_id = H256::random();
+			let url = b"ipfs://test".to_vec();
+
+			assert_ok!(store_item(BOB, collection_id, item_id, url));
+			assert_ok!(schedule_upload(BOB, collection_id, item_id));
+
+			assert_noop!(
+				NftTransfer::recover_from_nft(BOB, collection_id, item_id) as Result<MockItem, _>,
+				Error::<Test>::NftOutsideOfChain
+			);
+		});
+	}
+
+	#[test]
+	fn cannot_schedule_unknown_item() {
+		ExtBuilder::default().build().execute_with(|| {
+			let collection_id = create_collection(ALICE);
+
+			assert_noop!(
+				schedule_upload(BOB, collection_id, H256::random()),
+				Error::<Test>::UnknownItem
+			);
+		});
+	}
+
+	#[test]
+	fn cannot_schedule_if_not_owned() {
+		ExtBuilder::default().build().execute_with(|| {
+			let collection_id = create_collection(ALICE);
+			let item_id = H256::random();
+			let url = b"ipfs://test".to_vec();
+
+			assert_ok!(store_item(BOB, collection_id, item_id, url));
+
+			assert_noop!(
+				schedule_upload(ALICE, collection_id, item_id),
+				Error::<Test>::NftNotOwned
+			);
+		});
+	}
+
+	#[test]
+	fn cannot_schedule_uploaded_item() {
+		ExtBuilder::default().build().execute_with(|| {
+			let collection_id = create_collection(ALICE);
+			let item_id = H256::random();
+			let url = b"ipfs://test".to_vec();
+
+			assert_ok!(store_item(BOB, collection_id, item_id, url));
+			assert_ok!(schedule_upload(BOB, collection_id, item_id));
+
+			assert_noop!(
+				schedule_upload(BOB, collection_id, item_id),
+				Error::<Test>::NftOutsideOfChain
+			);
+		});
+	}
+}
+
+mod process_uploads {
+	use super::*;
+
+	#[test]
+	fn uploads_queued_items() {
+		ExtBuilder::default().build().execute_with(|| {
+			let collection_id = create_collection(ALICE);
+			let item_id = H256::random();
+			let url = b"ipfs://test".to_vec();
+
+			assert_ok!(store_item(BOB, collection_id, item_id, url));
+			assert_ok!(schedule_upload(BOB, collection_id, item_id));
+			run_to_block(2);
+
+			assert_eq!(MockUploader::uploaded_items(), vec![(collection_id, item_id)]);
+			assert_eq!(NftTransfer::upload_queue(collection_id, item_id), None);
+			assert_eq!(
+				NftTransfer::nft_statuses(collection_id, item_id),
+				Some(NftStatus::Uploaded)
+			);
+
+			System::assert_last_event(mock::RuntimeEvent::NftTransfer(
+				crate::Event::ItemUploaded { collection_id, item_id, owner: BOB },
+			));
+		});
+	}
+
+	#[test]
+	fn uploads_are_limited_per_block() {
+		ExtBuilder::default().build().execute_with(|| {
+			let collection_id = create_collection(ALICE);
+			let max_uploads = <Test as Config>::MaxUploadsPerBlock::get();
+			let url = b"ipfs://test".to_vec();
+
+			for _ in 0..=max_uploads {
+				let item_id = H256::random();
+				assert_ok!(store_item(BOB, collection_id, item_id, url.clone()));
+				assert_ok!(schedule_upload(BOB, collection_id, item_id));
+			}
+
+			run_to_block(2);
+			assert_eq!(MockUploader::uploaded_items().len(), max_uploads as usize);
+			assert_eq!(UploadQueue::<Test>::iter().count(), 1);
+
+			run_to_block(3);
+			assert_eq!(MockUploader::uploaded_items().len(), max_uploads as usize + 1);
+			assert_eq!(UploadQueue::<Test>::iter().count(), 0);
+		});
+	}
+
+	#[test]
+	fn failed_uploads_remain_stored() {
+		ExtBuilder::default().build().execute_with(|| {
+			let collection_id = create_collection(ALICE);
+			let item_id = H256::random();
+			let url = b"ipfs://test".to_vec();
+
+			assert_ok!(store_item(BOB, collection_id, item_id, url));
+			assert_ok!(schedule_upload(BOB, collection_id, item_id));
+			MockUploader::set_failing(true);
+			run_to_block(2);
+
+			assert!(MockUploader::uploaded_items().is_empty());
+			assert_eq!(NftTransfer::upload_queue(collection_id, item_id), None);
+			assert_eq!(NftTransfer::nft_statuses(collection_id, item_id), Some(NftStatus::Stored));
+
+			System::assert_last_event(mock::RuntimeEvent::NftTransfer(
+				crate::Event::ItemUploadFailed { collection_id, item_id, owner: BOB },
+			));
+		});
+	}
+}
+
+mod confirm_return {
+	use super::*;
+
+	#[test]
+	fn can_confirm_return_successfully() {
+		ExtBuilder::default().build().execute_with(|| {
+			let collection_id = create_collection(ALICE);
+			let item_id = H256::random();
+			let item = MockItem::default();
+			let url = b"ipfs://test".to_vec();
+
+			assert_ok!(NftTransfer::store_as_nft(BOB, collection_id, item_id, item.clone(), url));
+			assert_ok!(schedule_upload(BOB, collection_id, item_id));
+			run_to_block(2);
+
+			assert_ok!(NftTransfer::confirm_return(RuntimeOrigin::root(), collection_id, item_id));
+			assert_eq!(NftTransfer::nft_statuses(collection_id, item_id), Some(NftStatus::Stored));
+			System::assert_last_event(mock::RuntimeEvent::NftTransfer(
+				crate::Event::ItemReturned { collection_id, item_id },
+			));
+
+			assert_eq!(NftTransfer::recover_from_nft(BOB, collection_id, item_id), Ok(item));
+		});
+	}
+
+	#[test]
+	fn rejects_non_privileged_calls() {
+		ExtBuilder::default().build().execute_with(|| {
+			let collection_id = create_collection(ALICE);
+			let item_id = H256::random();
+
+			assert_noop!(
+				NftTransfer::confirm_return(RuntimeOrigin::signed(BOB), collection_id, item_id),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn cannot_confirm_stored_item() {
+		ExtBuilder::default().build().execute_with(|| {
+			let collection_id = create_collection(ALICE);
+			let item_id = H256::random();
+			let url = b"ipfs://test".to_vec();
+
+			assert_ok!(store_item(BOB, collection_id, item_id, url));
+
+			assert_noop!(
+				NftTransfer::confirm_return(RuntimeOrigin::root(), collection_id, item_id),
+				Error::<Test>::NftNotUploaded
+			);
+		});
+	}
+
+	#[test]
+	fn cannot_confirm_pending_upload() {
+		ExtBuilder::default().build().execute_with(|| {
+			let collection_id = create_collection(ALICE);
+			let item_id = H256::random();
+			let url = b"ipfs://test".to_vec();
+
+			assert_ok!(store_item(BOB, collection_id, item_id, url));
+			assert_ok!(schedule_upload(BOB, collection_id, item_id));
+
+			assert_noop!(
+				NftTransfer::confirm_return(RuntimeOrigin::root(), collection_id, item_id),
+				Error::<Test>::UploadPending
+			);
+		});
+	}
+}
+
+mod locker {
+	use super::*;
+	use frame_support::traits::{tokens::nonfungibles_v2::Transfer, Locker};
+
+	#[test]
+	fn stored_item_can_be_transferred() {
+		ExtBuilder::default().build().execute_with(|| {
+			let collection_id = create_collection(ALICE);
+			let item_id = H256::random();
+			let url = b"ipfs://test".to_vec();
+
+			assert_ok!(NftTransfer::store_as_nft(
+				BOB,
+				collection_id,
+				item_id,
+				MockItem::default(),
+				url
+			));
+			assert!(!NftTransfer::is_locked(collection_id, item_id));
+
+			assert_ok!(<Nft as Transfer<MockAccountId>>::transfer(
+				&collection_id,
+				&item_id,
+				&ALICE
+			));
+			assert_eq!(
+				<Nft as Inspect<MockAccountId>>::owner(&collection_id, &item_id),
+				Some(ALICE)
+			);
+		});
+	}
+
+	#[test]
+	fn uploaded_item_cannot_be_transferred() {
+		ExtBuilder::default().build().execute_with(|| {
+			let collection_id = create_collection(ALICE);
+			let item_id = H256::random();
+			let url = b"ipfs://test".to_vec();
+
+			assert_ok!(NftTransfer::store_as_nft(
+				BOB,
+				collection_id,
+				item_id,
+				MockItem::default(),
+				url
+			));
+			NftStatuses::<Test>::insert(collection_id, item_id, NftStatus::Uploaded);
+			assert!(NftTransfer::is_locked(collection_id, item_id));
+
+			assert_noop!(
+				<Nft as Transfer<MockAccountId>>::transfer(&collection_id, &item_id, &ALICE),
+				pallet_nfts::Error::<Test>::ItemLocked
+			);
+		});
+	}
+}
@@ -3,7 +3,7 @@ use frame_support::{
 	dispatch::{DispatchError, DispatchResult},
 	Parameter,
 };
-use sp_runtime::traits::AtLeast32BitUnsigned;
+use sp_runtime::traits::{AtLeast32BitUnsigned, MaybeSerializeDeserialize};
 use sp_std::vec::Vec;
 
 /// Type used to differentiate attribute codes for each item.
@@ -28,7 +28,11 @@ pub trait NftConvertible: Codec {
 
 /// Trait to define the transformation and bridging of NFT items.
 pub trait NftHandler<Account, ItemId, Item: NftConvertible> {
-	type CollectionId: AtLeast32BitUnsigned + Codec + Parameter + MaxEncodedLen;
+	type CollectionId: AtLeast32BitUnsigned
+		+ Codec
+		+ Parameter
+		+ MaxEncodedLen
+		+ MaybeSerializeDeserialize;
 
 	/// Consumes the given `item` and its associated identifiers, and stores it as an NFT
 	/// owned by `owner`.
@@ -47,6 +51,9 @@ pub trait NftHandler<Account, ItemId, Item: NftConvertible> {
 		item_id: ItemId,
 	) -> Result<Item, DispatchError>;
 
+	/// Returns the owner of the NFT indexed by `collection_id` and `item_id`, if it exists.
+	fn owner(collection_id: Self::CollectionId, item_id: ItemId) -> Option<Account>;
+
 	/// Schedules the upload of a previously stored NFT item to be teleported out of the chain, into
 	/// an external source. Once this process completes the item is locked until transported back
 	/// from the external source into the chain.
@@ -56,3 +63,16 @@ pub trait NftHandler<Account, ItemId, Item: NftConvertible> {
 		item_id: ItemId,
 	) -> DispatchResult;
 }
+
+/// Trait to define the upload of NFTs outside of the chain.
+pub trait NftUploader<Account, CollectionId, ItemId> {
+	/// Uploads the NFT indexed by `collection_id` and `item_id`, owned by `owner`, into an
+	/// external source.
+	fn upload(owner: &Account, collection_id: &CollectionId, item_id: &ItemId) -> DispatchResult;
+}
+
+impl<Account, CollectionId, ItemId> NftUploader<Account, CollectionId, ItemId> for () {
+	fn upload(_: &Account, _: &CollectionId, _: &ItemId) -> DispatchResult {
+		Err(DispatchError::Other("No NFT uploader available"))
+	}
+}
@@ -0,0 +1,56 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Placeholder weights for pallet_ajuna_nft_transfer
+//!
+//! NOT GENERATED BY THE BENCHMARK CLI: the values below only account for the storage accesses of
+//! each extrinsic and must be replaced by running the pallet's benchmarks with:
+//!
+//! ./target/release/bajun-para benchmark pallet --chain=dev --steps=50 --repeat=20
+//! --pallet=pallet-ajuna-nft-transfer --extrinsic=* --execution=wasm --wasm-execution=compiled
+//! --heap-pages=4096 --template=./.maintain/frame-weight-template.hbs
+//! --output=./pallets/ajuna-nft-transfer/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_ajuna_nft_transfer.
+pub trait WeightInfo {
+	fn confirm_return() -> Weight;
+}
+
+/// Weights for pallet_ajuna_nft_transfer using the Substrate node and recommended hardware.
+pub struct AjunaWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
+	// Storage: NftTransfer NftStatuses (r:1 w:1)
+	// Storage: NftTransfer UploadQueue (r:1 w:0)
+	fn confirm_return() -> Weight {
+		T::DbWeight::get().reads_writes(2 as u64, 1 as u64)
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: NftTransfer NftStatuses (r:1 w:1)
+	// Storage: NftTransfer UploadQueue (r:1 w:0)
+	fn confirm_return() -> Weight {
+		RocksDbWeight::get().reads_writes(2 as u64, 1 as u64)
+	}
+}
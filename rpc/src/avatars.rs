@@ -0,0 +1,165 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC interface for the Awesome Avatars pallet.
+
+use std::sync::Arc;
+
+use ajuna_primitives::{AccountId, Balance, Block, BlockNumber, Hash};
+use jsonrpsee::{
+	core::{Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use pallet_ajuna_awesome_avatars::types::{
+	AvatarInfo, CurrentSeason, ForgePreview, ListedAvatar, SeasonId, SeasonInfo,
+};
+use pallet_ajuna_awesome_avatars_runtime_api::AwesomeAvatarsApi as AwesomeAvatarsRuntimeApi;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, DispatchError};
+
+/// Error code for failures when calling into the runtime.
+const RUNTIME_ERROR: i32 = 1;
+
+#[rpc(server)]
+pub trait AwesomeAvatarsApi {
+	/// Get the avatars owned by an account, with their DNA decoded.
+	#[method(name = "avatars_avatarsOf")]
+	fn avatars_of(
+		&self,
+		account: AccountId,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<AvatarInfo<Hash, Balance>>>;
+
+	/// Get the current season and its status.
+	#[method(name = "avatars_currentSeason")]
+	fn current_season(&self, at: Option<Hash>) -> RpcResult<Option<CurrentSeason<BlockNumber>>>;
+
+	/// Get the avatars listed for trade along with their prices.
+	#[method(name = "avatars_listedAvatars")]
+	fn listed_avatars(
+		&self,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<ListedAvatar<AccountId, Hash, Balance>>>;
+
+	/// Get the stats of an account for a season.
+	#[method(name = "avatars_playerSeasonStats")]
+	fn player_season_stats(
+		&self,
+		account: AccountId,
+		season_id: SeasonId,
+		at: Option<Hash>,
+	) -> RpcResult<SeasonInfo>;
+
+	/// Preview the outcome of forging a leader with the given sacrifices.
+	#[method(name = "avatars_previewForge")]
+	fn preview_forge(
+		&self,
+		account: AccountId,
+		leader_id: Hash,
+		sacrifice_ids: Vec<Hash>,
+		at: Option<Hash>,
+	) -> RpcResult<Result<ForgePreview, DispatchError>>;
+}
+
+/// Provides RPC methods to query the state of Awesome Avatars.
+pub struct AwesomeAvatars<C> {
+	client: Arc<C>,
+}
+
+impl<C> AwesomeAvatars<C> {
+	/// Create new `AwesomeAvatars` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client }
+	}
+
+	fn at(&self, at: Option<Hash>) -> BlockId<Block>
+	where
+		C: HeaderBackend<Block>,
+	{
+		BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash))
+	}
+}
+
+impl<C> AwesomeAvatarsApiServer for AwesomeAvatars<C>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: AwesomeAvatarsRuntimeApi<Block, AccountId, Hash, Balance, BlockNumber>,
+{
+	fn avatars_of(
+		&self,
+		account: AccountId,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<AvatarInfo<Hash, Balance>>> {
+		self.client
+			.runtime_api()
+			.avatars_of(&self.at(at), account)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn current_season(&self, at: Option<Hash>) -> RpcResult<Option<CurrentSeason<BlockNumber>>> {
+		self.client
+			.runtime_api()
+			.current_season(&self.at(at))
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn listed_avatars(
+		&self,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<ListedAvatar<AccountId, Hash, Balance>>> {
+		self.client
+			.runtime_api()
+			.listed_avatars(&self.at(at))
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn player_season_stats(
+		&self,
+		account: AccountId,
+		season_id: SeasonId,
+		at: Option<Hash>,
+	) -> RpcResult<SeasonInfo> {
+		self.client
+			.runtime_api()
+			.player_season_stats(&self.at(at), account, season_id)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn preview_forge(
+		&self,
+		account: AccountId,
+		leader_id: Hash,
+		sacrifice_ids: Vec<Hash>,
+		at: Option<Hash>,
+	) -> RpcResult<Result<ForgePreview, DispatchError>> {
+		self.client
+			.runtime_api()
+			.preview_forge(&self.at(at), account, leader_id, sacrifice_ids)
+			.map_err(runtime_error_into_rpc_err)
+	}
+}
+
+/// Converts a runtime trap into an RPC error.
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> JsonRpseeError {
+	CallError::Custom(ErrorObject::owned(
+		RUNTIME_ERROR,
+		"Runtime error",
+		Some(format!("{:?}", err)),
+	))
+	.into()
+}
@@ -26,13 +26,19 @@ mod proxy_type;
 mod weights;
 pub mod xcm_config;
 
+#[cfg(test)]
+mod tests;
+#[cfg(test)]
+mod xcm_tests;
+
+use codec::Encode;
 use cumulus_pallet_parachain_system::RelayNumberStrictlyIncreases;
 use smallvec::smallvec;
 use sp_api::impl_runtime_apis;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
-	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT},
+	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, SaturatedConversion, StaticLookup},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult,
 };
@@ -50,7 +56,7 @@ use frame_support::{
 		constants::WEIGHT_REF_TIME_PER_SECOND, ConstantMultiplier, Weight, WeightToFeeCoefficient,
 		WeightToFeeCoefficients, WeightToFeePolynomial,
 	},
-	PalletId,
+	BoundedVec, PalletId,
 };
 use frame_system::{
 	limits::{BlockLength, BlockWeights},
@@ -110,6 +116,9 @@ pub type UncheckedExtrinsic =
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra>;
 
+/// The payload being signed in transactions.
+pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -120,7 +129,11 @@ pub type Executive = frame_executive::Executive<
 	Migrations,
 >;
 
-type Migrations = (pallet_ajuna_awesome_avatars::migration::v4::MigrateToV4<Runtime>,);
+type Migrations = (
+	pallet_ajuna_awesome_avatars::migration::v4::MigrateToV4<Runtime>,
+	pallet_ajuna_awesome_avatars::migration::v5::MigrateToV5<Runtime>,
+	pallet_ajuna_nft_staking::migration::v1::MigrateToV1<Runtime>,
+);
 
 /// Handles converting a weight scalar to a fee value, based on the scale and granularity of the
 /// node's balance type.
@@ -262,7 +275,18 @@ parameter_types! {
 pub struct BaseCallFilter;
 impl Contains<RuntimeCall> for BaseCallFilter {
 	fn contains(call: &RuntimeCall) -> bool {
-		!matches!(call, RuntimeCall::Nft(NftsCall::set_attribute { .. }))
+		match call {
+			RuntimeCall::Nft(NftsCall::set_attribute { .. }) => false,
+			RuntimeCall::Nft(
+				NftsCall::transfer { collection, .. } | NftsCall::buy_item { collection, .. },
+			) => !AwesomeAvatars::is_avatar_collection(collection),
+			RuntimeCall::Nft(NftsCall::claim_swap {
+				send_collection, receive_collection, ..
+			}) =>
+				!AwesomeAvatars::is_avatar_collection(send_collection) &&
+					!AwesomeAvatars::is_avatar_collection(receive_collection),
+			_ => true,
+		}
 	}
 }
 
@@ -666,12 +690,129 @@ parameter_types! {
 	pub const AwesomeAvatarsPalletId: PalletId = PalletId(*b"aj/aaatr");
 }
 
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Runtime
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+		call: RuntimeCall,
+		public: <Signature as sp_runtime::traits::Verify>::Signer,
+		account: AccountId,
+		nonce: Index,
+	) -> Option<(
+		RuntimeCall,
+		<UncheckedExtrinsic as sp_runtime::traits::Extrinsic>::SignaturePayload,
+	)> {
+		let tip = 0;
+		// take the biggest period possible.
+		let period =
+			BlockHashCount::get().checked_next_power_of_two().map(|c| c / 2).unwrap_or(2) as u64;
+		let current_block = System::block_number()
+			.saturated_into::<u64>()
+			// The `System::block_number` is initialized with `n+1`,
+			// so the actual block number is `n`.
+			.saturating_sub(1);
+		let era = generic::Era::mortal(period, current_block);
+		let extra: SignedExtra = (
+			frame_system::CheckNonZeroSender::<Runtime>::new(),
+			frame_system::CheckSpecVersion::<Runtime>::new(),
+			frame_system::CheckTxVersion::<Runtime>::new(),
+			frame_system::CheckGenesis::<Runtime>::new(),
+			frame_system::CheckEra::<Runtime>::from(era),
+			frame_system::CheckNonce::<Runtime>::from(nonce),
+			frame_system::CheckWeight::<Runtime>::new(),
+			pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(tip),
+		);
+		let raw_payload = SignedPayload::new(call, extra)
+			.map_err(|e| {
+				log::warn!("Unable to create signed payload: {:?}", e);
+			})
+			.ok()?;
+		let signature = raw_payload.using_encoded(|payload| C::sign(payload, public))?;
+		let address = AccountIdLookup::unlookup(account);
+		let (call, extra, _) = raw_payload.deconstruct();
+		Some((call, (address, signature, extra)))
+	}
+}
+
+impl frame_system::offchain::SigningTypes for Runtime {
+	type Public = <Signature as sp_runtime::traits::Verify>::Signer;
+	type Signature = Signature;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+	RuntimeCall: From<C>,
+{
+	type Extrinsic = UncheckedExtrinsic;
+	type OverarchingCall = RuntimeCall;
+}
+
+type EnsureOrganizerOrMoreThanHalfCouncil = EitherOfDiverse<
+	pallet_ajuna_awesome_avatars::EnsureOrganizer<Runtime>,
+	EnsureRootOrMoreThanHalfCouncil,
+>;
+
+/// Randomness derived from the BABE VRF outputs of the relay chain, as read from the relay chain
+/// state proof included in each block.
+///
+/// The randomness of the epoch before last was fixed before collators could influence it, while
+/// the VRF output of the relay parent keeps players from precomputing the outcome of their mints
+/// and forges for a whole epoch.
+///
+/// The state proof is set by a mandatory inherent, so it is present whenever calls are dispatched.
+/// Rather than falling back to a constant seed, this panics if the proof or the epoch randomness
+/// is missing or can't be decoded, which invalidates the block.
+pub struct RelayChainRandomness;
+
+impl frame_support::traits::Randomness<Hash, BlockNumber> for RelayChainRandomness {
+	fn random(subject: &[u8]) -> (Hash, BlockNumber) {
+		use cumulus_primitives_core::relay_chain::v2::well_known_keys;
+		use sp_runtime::traits::Hash as _;
+
+		let validation_data =
+			ParachainSystem::validation_data().expect("validation data is set in every block; qed");
+		let relay_state_proof = cumulus_pallet_parachain_system::RelayChainStateProof::new(
+			ParachainInfo::parachain_id(),
+			validation_data.relay_parent_storage_root,
+			ParachainSystem::relay_state_proof()
+				.expect("relay state proof is set in every block; qed"),
+		)
+		.expect("relay state proof is checked by the parachain system inherent; qed");
+		let epoch_randomness = relay_state_proof
+			.read_entry::<[u8; 32]>(well_known_keys::ONE_EPOCH_AGO_RANDOMNESS, None)
+			.expect("the relay chain always provides the randomness of one epoch ago; qed");
+		// The relay parent only lacks a VRF output on the first block of the relay chain
+		let block_randomness = relay_state_proof
+			.read_optional_entry::<Option<[u8; 32]>>(well_known_keys::CURRENT_BLOCK_RANDOMNESS)
+			.expect("the current block randomness decodes as stored by the relay chain; qed")
+			.flatten();
+
+		(
+			BlakeTwo256::hash_of(&(subject, epoch_randomness, block_randomness)),
+			System::block_number(),
+		)
+	}
+}
+
 impl pallet_ajuna_awesome_avatars::Config for Runtime {
 	type PalletId = AwesomeAvatarsPalletId;
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
-	type Randomness = Randomness;
+	type AssetPayment = ();
+	type Randomness = pallet_ajuna_awesome_avatars::randomness::RandomnessWithNonce<
+		Runtime,
+		RelayChainRandomness,
+	>;
 	type NftHandler = NftTransfer;
+	type AuthorityId = pallet_ajuna_awesome_avatars::offchain::crypto::AuthorityId;
+	type SeasonOrigin = EnsureOrganizerOrMoreThanHalfCouncil;
+	// The organizer runs seasons, but the game configuration is left to the council.
+	type ConfigOrigin = EnsureRootOrAtLeastTwoThirdsCouncil;
+	type FreeMintOrigin = EnsureOrganizerOrMoreThanHalfCouncil;
+	type MaxAirdropBatch = frame_support::traits::ConstU32<1_000>;
+	type MaxOffersPerAvatar = frame_support::traits::ConstU32<20>;
+	type MaxRentalsPerBlock = frame_support::traits::ConstU32<100>;
 	type WeightInfo = pallet_ajuna_awesome_avatars::weights::AjunaWeight<Runtime>;
 }
 
@@ -730,6 +871,43 @@ impl pallet_ajuna_nft_transfer::Config for Runtime {
 	type ItemId = Hash;
 	type ItemConfig = pallet_nfts::ItemConfig;
 	type NftHelper = Nft;
+	type NftUploader = AwesomeAvatars;
+	type MaxUploadsPerBlock = frame_support::traits::ConstU32<10>;
+	type UploadOrigin = EnsureRootOrMoreThanHalfCouncil;
+	type WeightInfo = pallet_ajuna_nft_transfer::weights::AjunaWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const NftStakingPalletId: PalletId = PalletId(*b"aj/nftst");
+	pub const MinimumStakingTokenReward: Balance = MILLI_BAJUN;
+	pub const MaxOpenContractsPerCreator: u32 = 100;
+	pub ContractCollectionConfig: pallet_nfts::CollectionConfig<Balance, BlockNumber, CollectionId> =
+		Default::default();
+	pub ContractCollectionItemConfig: pallet_nfts::ItemConfig = Default::default();
+}
+
+impl pallet_ajuna_nft_staking::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	// Bajun has no assets pallet, so contracts can't be rewarded in assets.
+	type RewardAssets = ();
+	type CollectionId = CollectionId;
+	type CollectionConfig = pallet_nfts::CollectionConfig<Balance, BlockNumber, CollectionId>;
+	type ItemId = Hash;
+	type ItemConfig = pallet_nfts::ItemConfig;
+	type NftHelper = Nft;
+	type StakingOrigin = EnsureSigned<AccountId>;
+	type NftDelegation = AwesomeAvatars;
+	type TreasuryPalletId = NftStakingPalletId;
+	type MinimumStakingTokenReward = MinimumStakingTokenReward;
+	type MaxOpenContractsPerCreator = MaxOpenContractsPerCreator;
+	type ContractCollectionConfig = ContractCollectionConfig;
+	type ContractCollectionItemConfig = ContractCollectionItemConfig;
+	type ContractAttributeKey = pallet_ajuna_nft_transfer::traits::AttributeCode;
+	type ContractAttributeValue = BoundedVec<u8, MaxItemEncodedSize>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = NftStakingBenchmarkHelper;
+	type WeightInfo = pallet_ajuna_nft_staking::weights::AjunaWeight<Runtime>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -784,6 +962,7 @@ construct_runtime!(
 		// Indexes 60-69 should be reserved for NFT related pallets
 		Nft: pallet_nfts::{Pallet, Call, Storage, Event<T>} = 60,
 		NftTransfer: pallet_ajuna_nft_transfer = 61,
+		NftStaking: pallet_ajuna_nft_staking = 62,
 	}
 );
 
@@ -811,6 +990,8 @@ mod benches {
 		[pallet_scheduler, Scheduler]
 		[pallet_ajuna_awesome_avatars, AwesomeAvatarsBench::<Runtime>]
 		[pallet_nfts, Nft]
+		[pallet_ajuna_nft_staking, NftStaking]
+		[pallet_ajuna_nft_transfer, NftTransfer]
 	);
 }
 
@@ -832,6 +1013,29 @@ impl<CollectionId: From<u16>, ItemId: From<[u8; 32]>>
 	}
 }
 
+#[cfg(feature = "runtime-benchmarks")]
+pub struct NftStakingBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl
+	pallet_ajuna_nft_staking::BenchmarkHelper<
+		pallet_ajuna_nft_transfer::traits::AttributeCode,
+		BoundedVec<u8, MaxItemEncodedSize>,
+		Hash,
+	> for NftStakingBenchmarkHelper
+{
+	fn contract_key(i: u32) -> pallet_ajuna_nft_transfer::traits::AttributeCode {
+		i as pallet_ajuna_nft_transfer::traits::AttributeCode
+	}
+	fn contract_value(i: u64) -> BoundedVec<u8, MaxItemEncodedSize> {
+		codec::Encode::encode(&i)
+			.try_into()
+			.expect("encoded u64 fits in the value limit")
+	}
+	fn item_id(i: u16) -> Hash {
+		<NftBenchmarkHelper as pallet_nfts::BenchmarkHelper<CollectionId, Hash>>::item(i)
+	}
+}
+
 impl_runtime_apis! {
 	impl sp_consensus_aura::AuraApi<Block, AuraId> for Runtime {
 		fn slot_duration() -> sp_consensus_aura::SlotDuration {
@@ -933,6 +1137,37 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_ajuna_awesome_avatars_runtime_api::AwesomeAvatarsApi<Block, AccountId, Hash, Balance, BlockNumber> for Runtime {
+		fn avatars_of(
+			account: AccountId,
+		) -> Vec<pallet_ajuna_awesome_avatars::types::AvatarInfo<Hash, Balance>> {
+			AwesomeAvatars::avatars_of(&account)
+		}
+
+		fn current_season() -> Option<pallet_ajuna_awesome_avatars::types::CurrentSeason<BlockNumber>> {
+			AwesomeAvatars::current_season()
+		}
+
+		fn listed_avatars() -> Vec<pallet_ajuna_awesome_avatars::types::ListedAvatar<AccountId, Hash, Balance>> {
+			AwesomeAvatars::listed_avatars()
+		}
+
+		fn player_season_stats(
+			account: AccountId,
+			season_id: pallet_ajuna_awesome_avatars::types::SeasonId,
+		) -> pallet_ajuna_awesome_avatars::types::SeasonInfo {
+			AwesomeAvatars::season_stats(season_id, account)
+		}
+
+		fn preview_forge(
+			account: AccountId,
+			leader_id: Hash,
+			sacrifice_ids: Vec<Hash>,
+		) -> Result<pallet_ajuna_awesome_avatars::types::ForgePreview, sp_runtime::DispatchError> {
+			AwesomeAvatars::preview_forge(&account, &leader_id, &sacrifice_ids)
+		}
+	}
+
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
 		fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
 			ParachainSystem::collect_collation_info(header)
@@ -0,0 +1,332 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::*;
+use codec::Encode;
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{
+		tokens::{
+			nonfungibles_v2::{self, Create, Inspect, Mutate},
+			AttributeNamespace,
+		},
+		Contains, GenesisBuild,
+	},
+};
+use pallet_ajuna_awesome_avatars::types::{Avatar, Dna, RarityTier, RARITY};
+use pallet_ajuna_nft_staking::{ContractCondition, NftAddress, StakingContract, StakingReward};
+use pallet_ajuna_nft_transfer::traits::NftHandler;
+use sp_runtime::AccountId32;
+
+const ALICE: AccountId = AccountId32::new([1; 32]);
+const BOB: AccountId = AccountId32::new([2; 32]);
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	let mut storage = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+	pallet_balances::GenesisConfig::<Runtime> {
+		balances: vec![(ALICE, 1_000 * BAJUN), (BOB, 1_000 * BAJUN)],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	pallet_ajuna_nft_staking::GenesisConfig::<Runtime>::default()
+		.assimilate_storage(&mut storage)
+		.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(storage);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn create_collection(owner: &AccountId) -> CollectionId {
+	<Nft as Create<AccountId, _>>::create_collection(owner, owner, &ContractCollectionConfig::get())
+		.unwrap()
+}
+
+mod nft_staking {
+	use super::*;
+
+	#[test]
+	fn can_stake_an_avatar_and_redeem_the_reward() {
+		new_test_ext().execute_with(|| {
+			// BOB holds a rare avatar as an NFT
+			let avatar_collection_id = create_collection(&ALICE);
+			let avatar_id = Hash::repeat_byte(1);
+			let avatar = Avatar {
+				season_id: 1,
+				dna: Dna::try_from(vec![0x21, 0x23, 0x22, 0x24]).unwrap(),
+				souls: 10,
+			};
+			assert_ok!(NftTransfer::store_as_nft(
+				BOB,
+				avatar_collection_id,
+				avatar_id,
+				avatar,
+				b"ipfs://avatar".to_vec()
+			));
+
+			// ALICE organizes a contract rewarding the staking of a rare avatar
+			assert_ok!(NftStaking::set_organizer(RuntimeOrigin::root(), ALICE));
+			let contract_collection_id = create_collection(&ALICE);
+			assert_ok!(NftStaking::set_contract_collection_id(
+				RuntimeOrigin::signed(ALICE),
+				contract_collection_id
+			));
+
+			let reward = 10 * BAJUN;
+			let duration = 5;
+			let rarity = BoundedVec::try_from(RarityTier::Rare.encode()).unwrap();
+			let contract = StakingContract::new(StakingReward::Tokens(reward), duration)
+				.with_clause(ContractCondition::HasAttributeWithValue(
+					AttributeNamespace::Pallet,
+					RARITY,
+					rarity,
+				));
+			let contract_id = NftStaking::next_contract_id();
+			assert_ok!(NftStaking::submit_staking_contract(RuntimeOrigin::signed(ALICE), contract));
+
+			// BOB stakes the avatar
+			let staked_assets =
+				vec![NftAddress(avatar_collection_id, avatar_id)].try_into().unwrap();
+			assert_ok!(NftStaking::take_staking_contract(
+				RuntimeOrigin::signed(BOB),
+				contract_id,
+				staked_assets
+			));
+			assert_eq!(
+				Nft::owner(&avatar_collection_id, &avatar_id),
+				Some(NftStaking::treasury_account_id())
+			);
+			assert_eq!(Nft::owner(&contract_collection_id, &contract_id), Some(BOB));
+
+			// BOB redeems the reward once the contract expires
+			System::set_block_number(1 + duration);
+			let balance = Balances::free_balance(BOB);
+			assert_ok!(NftStaking::redeem_staking_contract(
+				RuntimeOrigin::signed(BOB),
+				contract_id
+			));
+			assert_eq!(Balances::free_balance(BOB), balance + reward);
+			assert_eq!(Nft::owner(&avatar_collection_id, &avatar_id), Some(BOB));
+			assert_eq!(Nft::owner(&contract_collection_id, &contract_id), None);
+		});
+	}
+
+	#[test]
+	fn cannot_submit_a_contract_rewarded_in_assets() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(NftStaking::set_organizer(RuntimeOrigin::root(), ALICE));
+			let contract_collection_id = create_collection(&ALICE);
+			assert_ok!(NftStaking::set_contract_collection_id(
+				RuntimeOrigin::signed(ALICE),
+				contract_collection_id
+			));
+
+			// Bajun has no assets to reward contracts with
+			let contract = StakingContract::new(StakingReward::Assets(1, 10 * BAJUN), 5);
+			assert_noop!(
+				NftStaking::submit_staking_contract(RuntimeOrigin::signed(ALICE), contract),
+				pallet_ajuna_nft_staking::Error::<Runtime>::AssetRewardsUnavailable
+			);
+		});
+	}
+}
+
+mod avatar_xcm {
+	use super::*;
+	use frame_support::{assert_noop, traits::Contains};
+	use xcm::latest::prelude::*;
+	use xcm_config::{AvatarsCheckingAccount, NftPalletLocation, OnlyAvatars, XcmConfig};
+	use xcm_executor::XcmExecutor;
+
+	const WEIGHT_LIMIT: u64 = 1_000_000_000;
+
+	fn account_location(account: &AccountId) -> MultiLocation {
+		MultiLocation::new(
+			0,
+			X1(Junction::AccountId32 { network: NetworkId::Any, id: account.clone().into() }),
+		)
+	}
+
+	fn nft(collection_id: CollectionId, item_id: Hash) -> MultiAsset {
+		MultiAsset {
+			id: Concrete(
+				NftPalletLocation::get()
+					.pushed_with_interior(GeneralIndex(collection_id.into()))
+					.unwrap(),
+			),
+			fun: NonFungible(AssetInstance::Array32(item_id.0)),
+		}
+	}
+
+	fn mint_nft(collection_id: CollectionId, item_id: Hash, owner: &AccountId) {
+		assert_ok!(<Nft as Mutate<_, _>>::mint_into(
+			&collection_id,
+			&item_id,
+			owner,
+			&pallet_nfts::ItemConfig::default(),
+			true,
+		));
+	}
+
+	fn execute(origin: &AccountId, message: Xcm<RuntimeCall>) -> Outcome {
+		XcmExecutor::<XcmConfig>::execute_xcm_in_credit(
+			account_location(origin),
+			message,
+			WEIGHT_LIMIT,
+			WEIGHT_LIMIT,
+		)
+	}
+
+	fn transfer_to(asset: MultiAsset, beneficiary: &AccountId) -> Xcm<RuntimeCall> {
+		Xcm(vec![
+			WithdrawAsset(vec![asset].into()),
+			DepositAsset {
+				assets: Wild(All),
+				max_assets: 1,
+				beneficiary: account_location(beneficiary),
+			},
+		])
+	}
+
+	#[test]
+	fn only_avatar_nfts_pass_the_reserve_transfer_filter() {
+		new_test_ext().execute_with(|| {
+			let avatar_collection_id = create_collection(&ALICE);
+			let other_collection_id = create_collection(&ALICE);
+			let avatar = nft(avatar_collection_id, Hash::repeat_byte(1));
+			let other = nft(other_collection_id, Hash::repeat_byte(1));
+			let origin = account_location(&ALICE);
+
+			// nothing passes until the avatar collection is set
+			assert!(!OnlyAvatars::contains(&(origin.clone(), vec![avatar.clone()])));
+
+			pallet_ajuna_awesome_avatars::CollectionId::<Runtime>::put(avatar_collection_id);
+			assert!(OnlyAvatars::contains(&(origin.clone(), vec![avatar.clone()])));
+			assert!(!OnlyAvatars::contains(&(origin.clone(), vec![other.clone()])));
+			assert!(!OnlyAvatars::contains(&(origin.clone(), vec![avatar, other])));
+			assert!(!OnlyAvatars::contains(&(origin.clone(), vec![])));
+			assert!(!OnlyAvatars::contains(&(
+				origin,
+				vec![MultiAsset { id: Concrete(Here.into()), fun: Fungible(1) }]
+			)));
+		});
+	}
+
+	#[test]
+	fn cannot_reserve_transfer_nfts_of_other_collections() {
+		new_test_ext().execute_with(|| {
+			let avatar_collection_id = create_collection(&ALICE);
+			let other_collection_id = create_collection(&ALICE);
+			pallet_ajuna_awesome_avatars::CollectionId::<Runtime>::put(avatar_collection_id);
+			let item_id = Hash::repeat_byte(1);
+			mint_nft(other_collection_id, item_id, &ALICE);
+
+			assert_noop!(
+				PolkadotXcm::reserve_transfer_assets(
+					RuntimeOrigin::signed(ALICE),
+					Box::new((Parent, Parachain(2)).into()),
+					Box::new(account_location(&BOB).into()),
+					Box::new(vec![nft(other_collection_id, item_id)].into()),
+					0,
+				),
+				pallet_xcm::Error::<Runtime>::Filtered
+			);
+			assert_eq!(Nft::owner(&other_collection_id, &item_id), Some(ALICE));
+		});
+	}
+
+	#[test]
+	fn avatar_nfts_are_transacted_through_the_checking_account() {
+		new_test_ext().execute_with(|| {
+			let avatar_collection_id = create_collection(&ALICE);
+			pallet_ajuna_awesome_avatars::CollectionId::<Runtime>::put(avatar_collection_id);
+			let avatar_id = Hash::repeat_byte(1);
+			mint_nft(avatar_collection_id, avatar_id, &ALICE);
+
+			// withdrawing parks the NFT in the checking account
+			let withdraw =
+				Xcm(vec![WithdrawAsset(vec![nft(avatar_collection_id, avatar_id)].into())]);
+			assert!(matches!(execute(&ALICE, withdraw), Outcome::Complete(_)));
+			assert_eq!(
+				Nft::owner(&avatar_collection_id, &avatar_id),
+				Some(AvatarsCheckingAccount::get())
+			);
+
+			// a transfer moves it through the checking account to the beneficiary
+			assert_ok!(<Nft as nonfungibles_v2::Transfer<_>>::transfer(
+				&avatar_collection_id,
+				&avatar_id,
+				&ALICE,
+			));
+			let outcome = execute(&ALICE, transfer_to(nft(avatar_collection_id, avatar_id), &BOB));
+			assert!(matches!(outcome, Outcome::Complete(_)));
+			assert_eq!(Nft::owner(&avatar_collection_id, &avatar_id), Some(BOB));
+		});
+	}
+
+	#[test]
+	fn cannot_transact_nfts_of_other_collections() {
+		new_test_ext().execute_with(|| {
+			let avatar_collection_id = create_collection(&ALICE);
+			let other_collection_id = create_collection(&ALICE);
+			pallet_ajuna_awesome_avatars::CollectionId::<Runtime>::put(avatar_collection_id);
+			let item_id = Hash::repeat_byte(1);
+			mint_nft(other_collection_id, item_id, &ALICE);
+
+			let outcome = execute(&ALICE, transfer_to(nft(other_collection_id, item_id), &BOB));
+			assert!(matches!(outcome, Outcome::Incomplete(_, XcmError::AssetNotFound)));
+			assert_eq!(Nft::owner(&other_collection_id, &item_id), Some(ALICE));
+		});
+	}
+
+	#[test]
+	fn cannot_withdraw_avatar_nfts_of_other_accounts() {
+		new_test_ext().execute_with(|| {
+			let avatar_collection_id = create_collection(&ALICE);
+			pallet_ajuna_awesome_avatars::CollectionId::<Runtime>::put(avatar_collection_id);
+			let avatar_id = Hash::repeat_byte(1);
+			mint_nft(avatar_collection_id, avatar_id, &ALICE);
+
+			let outcome = execute(&BOB, transfer_to(nft(avatar_collection_id, avatar_id), &BOB));
+			assert!(matches!(outcome, Outcome::Incomplete(_, XcmError::NotWithdrawable)));
+			assert_eq!(Nft::owner(&avatar_collection_id, &avatar_id), Some(ALICE));
+		});
+	}
+}
+
+mod call_filter {
+	use super::*;
+
+	fn transfer(collection: CollectionId) -> RuntimeCall {
+		RuntimeCall::Nft(NftsCall::transfer {
+			collection,
+			item: Hash::repeat_byte(1),
+			dest: BOB.into(),
+		})
+	}
+
+	#[test]
+	fn only_avatars_are_kept_from_being_transferred_directly() {
+		new_test_ext().execute_with(|| {
+			let avatar_collection_id = create_collection(&ALICE);
+			let other_collection_id = create_collection(&ALICE);
+			pallet_ajuna_awesome_avatars::CollectionId::<Runtime>::put(avatar_collection_id);
+
+			assert!(!BaseCallFilter::contains(&transfer(avatar_collection_id)));
+			assert!(BaseCallFilter::contains(&transfer(other_collection_id)));
+		});
+	}
+}
@@ -15,17 +15,20 @@
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
 use super::{
-	AccountId, Balances, ParachainInfo, ParachainSystem, PolkadotXcm, Runtime, RuntimeCall,
-	RuntimeEvent, RuntimeOrigin, WeightToFee, XcmpQueue,
+	AccountId, AwesomeAvatars, Balances, CollectionId, Hash, Nft, ParachainInfo, ParachainSystem,
+	PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, WeightToFee, XcmpQueue,
 };
 use core::marker::PhantomData;
 use frame_support::{
-	log, match_types, parameter_types,
-	traits::{Everything, Nothing},
+	ensure, log, match_types, parameter_types,
+	traits::{tokens::nonfungibles_v2, Contains, Everything, Get, Nothing, PalletInfoAccess},
+	PalletId,
 };
 use pallet_xcm::XcmPassthrough;
 use polkadot_parachain::primitives::Sibling;
 use polkadot_runtime_common::impls::ToAuthor;
+use sp_runtime::traits::AccountIdConversion;
+use sp_std::vec::Vec;
 use xcm::latest::{prelude::*, Weight as XCMWeight};
 use xcm_builder::{
 	AccountId32Aliases, AllowTopLevelPaidExecutionFrom, AllowUnpaidExecutionFrom, CurrencyAdapter,
@@ -34,7 +37,10 @@ use xcm_builder::{
 	SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit,
 	UsingComponents,
 };
-use xcm_executor::{traits::ShouldExecute, XcmExecutor};
+use xcm_executor::{
+	traits::{Convert, Error as MatchError, MatchesNonFungibles, ShouldExecute, TransactAsset},
+	Assets, XcmExecutor,
+};
 
 parameter_types! {
 	pub const RelayLocation: MultiLocation = MultiLocation::parent();
@@ -69,6 +75,118 @@ pub type LocalAssetTransactor = CurrencyAdapter<
 	(),
 >;
 
+parameter_types! {
+	pub const AvatarsXcmPalletId: PalletId = PalletId(*b"aj/avxcm");
+	pub AvatarsCheckingAccount: AccountId = AvatarsXcmPalletId::get().into_account_truncating();
+	pub NftPalletLocation: MultiLocation =
+		MultiLocation::new(0, X1(PalletInstance(<Nft as PalletInfoAccess>::index() as u8)));
+}
+
+/// The collection holding the avatars locked as NFTs, if set.
+pub struct AvatarCollectionId;
+impl Get<Option<CollectionId>> for AvatarCollectionId {
+	fn get() -> Option<CollectionId> {
+		AwesomeAvatars::collection_id()
+	}
+}
+
+/// Matches the NFTs of the collection given by `Collection`, located as
+/// `PalletLocation/GeneralIndex(collection id)` and identified by their 32-byte item id.
+pub struct CollectionNfts<PalletLocation, Collection>(PhantomData<(PalletLocation, Collection)>);
+
+impl<PalletLocation, Collection, CollectionIdOf, ItemId> MatchesNonFungibles<CollectionIdOf, ItemId>
+	for CollectionNfts<PalletLocation, Collection>
+where
+	PalletLocation: Get<MultiLocation>,
+	Collection: Get<Option<CollectionIdOf>>,
+	CollectionIdOf: Copy + Into<u128>,
+	ItemId: From<[u8; 32]>,
+{
+	fn matches_nonfungibles(asset: &MultiAsset) -> Result<(CollectionIdOf, ItemId), MatchError> {
+		let collection_id = Collection::get().ok_or(MatchError::AssetNotFound)?;
+		let (location, item_id) = match (&asset.id, &asset.fun) {
+			(Concrete(location), NonFungible(AssetInstance::Array32(item_id))) =>
+				(location, item_id),
+			_ => return Err(MatchError::AssetNotFound),
+		};
+		let collection_location = PalletLocation::get()
+			.pushed_with_interior(GeneralIndex(collection_id.into()))
+			.map_err(|_| MatchError::AssetIdConversionFailed)?;
+		ensure!(location == &collection_location, MatchError::AssetNotFound);
+		Ok((collection_id, ItemId::from(*item_id)))
+	}
+}
+
+/// Means for transacting the NFTs of a `nonfungibles_v2` implementation, such as `pallet_nfts`.
+///
+/// Unlike burning and minting them, NFTs withdrawn into the holding register are moved into
+/// `CheckingAccount` and deposited back out of it, so that they keep their attributes while
+/// abroad. This makes the chain the reserve of the NFTs it transfers out.
+pub struct NonFungiblesV2Adapter<Nfts, Matcher, AccountIdConverter, AccountId, CheckingAccount>(
+	PhantomData<(Nfts, Matcher, AccountIdConverter, AccountId, CheckingAccount)>,
+);
+
+impl<Nfts, Matcher, AccountIdConverter, AccountId, CheckingAccount> TransactAsset
+	for NonFungiblesV2Adapter<Nfts, Matcher, AccountIdConverter, AccountId, CheckingAccount>
+where
+	Nfts: nonfungibles_v2::Transfer<AccountId>,
+	Matcher: MatchesNonFungibles<Nfts::CollectionId, Nfts::ItemId>,
+	AccountIdConverter: Convert<MultiLocation, AccountId>,
+	AccountId: Clone + Eq,
+	CheckingAccount: Get<AccountId>,
+{
+	fn deposit_asset(what: &MultiAsset, who: &MultiLocation) -> XcmResult {
+		let (collection_id, item_id) = Matcher::matches_nonfungibles(what)?;
+		let who = AccountIdConverter::convert_ref(who)
+			.map_err(|()| MatchError::AccountIdConversionFailed)?;
+		ensure!(
+			Nfts::owner(&collection_id, &item_id) == Some(CheckingAccount::get()),
+			XcmError::FailedToTransactAsset("NFT not held by the checking account")
+		);
+		Nfts::transfer(&collection_id, &item_id, &who)
+			.map_err(|e| XcmError::FailedToTransactAsset(e.into()))
+	}
+
+	fn withdraw_asset(what: &MultiAsset, who: &MultiLocation) -> Result<Assets, XcmError> {
+		let (collection_id, item_id) = Matcher::matches_nonfungibles(what)?;
+		let who = AccountIdConverter::convert_ref(who)
+			.map_err(|()| MatchError::AccountIdConversionFailed)?;
+		ensure!(Nfts::owner(&collection_id, &item_id) == Some(who), XcmError::NotWithdrawable);
+		Nfts::transfer(&collection_id, &item_id, &CheckingAccount::get())
+			.map_err(|e| XcmError::FailedToTransactAsset(e.into()))?;
+		Ok(what.clone().into())
+	}
+}
+
+/// Means for transacting avatars locked as NFTs.
+pub type AvatarTransactor = NonFungiblesV2Adapter<
+	Nft,
+	CollectionNfts<NftPalletLocation, AvatarCollectionId>,
+	LocationToAccountId,
+	AccountId,
+	AvatarsCheckingAccount,
+>;
+
+/// Means for transacting assets on this chain, trying each transactor in turn.
+pub type AssetTransactors = (LocalAssetTransactor, AvatarTransactor);
+
+/// Allows only avatars locked as NFTs to be reserve transferred out of this chain. While abroad
+/// their NFTs are held by the sovereign account of the destination, so the avatars can't be
+/// unlocked and remain unusable for forging and trading until they are transferred back.
+pub struct OnlyAvatars;
+impl Contains<(MultiLocation, Vec<MultiAsset>)> for OnlyAvatars {
+	fn contains((_, assets): &(MultiLocation, Vec<MultiAsset>)) -> bool {
+		!assets.is_empty() &&
+			assets.iter().all(|asset| {
+				<CollectionNfts<NftPalletLocation, AvatarCollectionId> as MatchesNonFungibles<
+					CollectionId,
+					Hash,
+				>>::matches_nonfungibles(asset)
+				.is_ok()
+			})
+	}
+}
+
 /// This is the type we use to convert an (incoming) XCM origin into a local `Origin` instance,
 /// ready for dispatching a transaction with Xcm's `Transact`. There is an `OriginKind` which can
 /// biases the kind of local `Origin` it will become.
@@ -181,7 +299,7 @@ impl xcm_executor::Config for XcmConfig {
 	type RuntimeCall = RuntimeCall;
 	type XcmSender = XcmRouter;
 	// How to withdraw and deposit an asset.
-	type AssetTransactor = LocalAssetTransactor;
+	type AssetTransactor = AssetTransactors;
 	type OriginConverter = XcmOriginToTransactDispatchOrigin;
 	type IsReserve = NativeAsset;
 	type IsTeleporter = (); // Teleporting is disabled.
@@ -218,7 +336,7 @@ impl pallet_xcm::Config for Runtime {
 	// Needs to be `Everything` for local testing.
 	type XcmExecutor = XcmExecutor<XcmConfig>;
 	type XcmTeleportFilter = Everything;
-	type XcmReserveTransferFilter = Nothing;
+	type XcmReserveTransferFilter = OnlyAvatars;
 	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
 	type LocationInverter = LocationInverter<Ancestry>;
 	type RuntimeOrigin = RuntimeOrigin;
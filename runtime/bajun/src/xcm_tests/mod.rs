@@ -0,0 +1,273 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! XCM simulator tests of the cross-chain transfer of avatar NFTs.
+
+mod parachain;
+mod relay_chain;
+
+use frame_support::{
+	assert_ok,
+	traits::{
+		tokens::{
+			nonfungibles::Inspect as UniquesInspect,
+			nonfungibles_v2::{Create, Inspect as NftsInspect, Mutate},
+		},
+		GenesisBuild,
+	},
+};
+use polkadot_parachain::primitives::{Id as ParaId, Sibling};
+use sp_core::H256;
+use sp_runtime::{traits::AccountIdConversion, AccountId32};
+use xcm::{latest::prelude::*, VersionedXcm};
+use xcm_simulator::{decl_test_network, decl_test_parachain, decl_test_relay_chain, TestExt};
+
+const ALICE: AccountId32 = AccountId32::new([1; 32]);
+const BOB: AccountId32 = AccountId32::new([2; 32]);
+const INITIAL_BALANCE: u128 = 1_000_000_000;
+
+decl_test_parachain! {
+	pub struct ParaA {
+		Runtime = parachain::Runtime,
+		XcmpMessageHandler = parachain::MsgQueue,
+		DmpMessageHandler = parachain::MsgQueue,
+		new_ext = para_ext(parachain::AVATARS_PARA_ID),
+	}
+}
+
+decl_test_parachain! {
+	pub struct ParaB {
+		Runtime = parachain::Runtime,
+		XcmpMessageHandler = parachain::MsgQueue,
+		DmpMessageHandler = parachain::MsgQueue,
+		new_ext = para_ext(2),
+	}
+}
+
+decl_test_relay_chain! {
+	pub struct Relay {
+		Runtime = relay_chain::Runtime,
+		XcmConfig = relay_chain::XcmConfig,
+		new_ext = relay_ext(),
+	}
+}
+
+decl_test_network! {
+	pub struct MockNet {
+		relay_chain = Relay,
+		parachains = vec![
+			(1, ParaA),
+			(2, ParaB),
+		],
+	}
+}
+
+fn sibling_account_id(para_id: u32) -> parachain::AccountId {
+	Sibling::from(para_id).into_account_truncating()
+}
+
+fn para_ext(para_id: u32) -> sp_io::TestExternalities {
+	use parachain::{MsgQueue, Runtime, System};
+
+	let mut storage = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+	pallet_balances::GenesisConfig::<Runtime> {
+		balances: vec![(ALICE, INITIAL_BALANCE), (BOB, INITIAL_BALANCE)],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(storage);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		MsgQueue::set_para_id(para_id.into());
+	});
+	ext
+}
+
+fn relay_ext() -> sp_io::TestExternalities {
+	use relay_chain::{Runtime, System};
+
+	let mut storage = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+	let para_account_id: relay_chain::AccountId = ParaId::from(1).into_account_truncating();
+	pallet_balances::GenesisConfig::<Runtime> {
+		balances: vec![(ALICE, INITIAL_BALANCE), (para_account_id, INITIAL_BALANCE)],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	<pallet_xcm::GenesisConfig as GenesisBuild<Runtime>>::assimilate_storage(
+		&pallet_xcm::GenesisConfig { safe_xcm_version: Some(2) },
+		&mut storage,
+	)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(storage);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn account_location(account: &AccountId32) -> MultiLocation {
+	MultiLocation::new(
+		0,
+		X1(Junction::AccountId32 { network: NetworkId::Any, id: account.clone().into() }),
+	)
+}
+
+fn avatar_nft(collection_location: MultiLocation, avatar_id: H256) -> MultiAsset {
+	MultiAsset {
+		id: Concrete(collection_location),
+		fun: NonFungible(AssetInstance::Array32(avatar_id.0)),
+	}
+}
+
+mod avatar_nfts {
+	use super::*;
+	use parachain::{
+		ForeignAvatarsLocation, ForeignUniques, Nft, NftPalletLocation, PolkadotXcm, RuntimeOrigin,
+		FOREIGN_AVATARS,
+	};
+
+	fn nft_owner(avatar_id: &H256) -> Option<parachain::AccountId> {
+		<Nft as NftsInspect<_>>::owner(&0, avatar_id)
+	}
+
+	fn foreign_nft_owner(avatar_id: &H256) -> Option<parachain::AccountId> {
+		<ForeignUniques as UniquesInspect<_>>::owner(&FOREIGN_AVATARS, avatar_id)
+	}
+
+	#[test]
+	fn can_reserve_transfer_an_avatar_nft_to_a_sibling_and_back() {
+		MockNet::reset();
+		let avatar_id = H256::repeat_byte(7);
+
+		// ALICE holds an avatar locked as an NFT on the avatars chain
+		ParaA::execute_with(|| {
+			let collection_id = <Nft as Create<_, _>>::create_collection(
+				&ALICE,
+				&ALICE,
+				&pallet_nfts::CollectionConfig::default(),
+			)
+			.unwrap();
+			assert_eq!(Some(collection_id), parachain::AvatarCollectionId::get());
+			assert_ok!(<Nft as Mutate<_, _>>::mint_into(
+				&collection_id,
+				&avatar_id,
+				&ALICE,
+				&pallet_nfts::ItemConfig::default(),
+				true,
+			));
+		});
+
+		// the sibling holds the avatar NFTs of the avatars chain in a collection of its own
+		ParaB::execute_with(|| {
+			assert_ok!(ForeignUniques::force_create(
+				RuntimeOrigin::root(),
+				FOREIGN_AVATARS,
+				ALICE,
+				true
+			));
+		});
+
+		// ALICE transfers the avatar NFT to BOB on the sibling
+		ParaA::execute_with(|| {
+			let collection_location =
+				NftPalletLocation::get().pushed_with_interior(GeneralIndex(0)).unwrap();
+			assert_ok!(PolkadotXcm::reserve_transfer_assets(
+				RuntimeOrigin::signed(ALICE),
+				Box::new((Parent, Parachain(2)).into()),
+				Box::new(account_location(&BOB).into()),
+				Box::new(vec![avatar_nft(collection_location, avatar_id)].into()),
+				0,
+			));
+			assert_eq!(nft_owner(&avatar_id), Some(sibling_account_id(2)));
+		});
+
+		ParaB::execute_with(|| {
+			assert_eq!(foreign_nft_owner(&avatar_id), Some(BOB));
+		});
+
+		// BOB returns the avatar NFT to ALICE on the avatars chain
+		ParaB::execute_with(|| {
+			let message = Xcm(vec![
+				WithdrawAsset(vec![avatar_nft(ForeignAvatarsLocation::get(), avatar_id)].into()),
+				InitiateReserveWithdraw {
+					assets: Wild(All),
+					reserve: (Parent, Parachain(parachain::AVATARS_PARA_ID)).into(),
+					xcm: Xcm(vec![DepositAsset {
+						assets: Wild(All),
+						max_assets: 1,
+						beneficiary: account_location(&ALICE),
+					}]),
+				},
+			]);
+			assert_ok!(PolkadotXcm::execute(
+				RuntimeOrigin::signed(BOB),
+				Box::new(VersionedXcm::V2(message)),
+				1_000_000_000,
+			));
+			assert_eq!(foreign_nft_owner(&avatar_id), None);
+		});
+
+		ParaA::execute_with(|| {
+			assert_eq!(nft_owner(&avatar_id), Some(ALICE));
+		});
+	}
+
+	#[test]
+	fn cannot_withdraw_an_avatar_nft_held_by_another_account() {
+		MockNet::reset();
+		let avatar_id = H256::repeat_byte(7);
+
+		ParaA::execute_with(|| {
+			let collection_id = <Nft as Create<_, _>>::create_collection(
+				&ALICE,
+				&ALICE,
+				&pallet_nfts::CollectionConfig::default(),
+			)
+			.unwrap();
+			assert_ok!(<Nft as Mutate<_, _>>::mint_into(
+				&collection_id,
+				&avatar_id,
+				&ALICE,
+				&pallet_nfts::ItemConfig::default(),
+				true,
+			));
+		});
+
+		// the sibling can't withdraw an avatar NFT it doesn't hold
+		ParaB::execute_with(|| {
+			let collection_location =
+				NftPalletLocation::get().pushed_with_interior(GeneralIndex(0)).unwrap();
+			let avatar = avatar_nft(collection_location, avatar_id);
+			let reserve = (Parent, Parachain(parachain::AVATARS_PARA_ID)).into();
+			assert_ok!(PolkadotXcm::send_xcm(
+				Here,
+				reserve,
+				Xcm(vec![
+					WithdrawAsset(vec![avatar].into()),
+					DepositAsset {
+						assets: Wild(All),
+						max_assets: 1,
+						beneficiary: account_location(&BOB),
+					},
+				]),
+			));
+		});
+
+		ParaA::execute_with(|| {
+			assert_eq!(nft_owner(&avatar_id), Some(ALICE));
+		});
+	}
+}
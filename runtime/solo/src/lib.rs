@@ -25,12 +25,12 @@ include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 use codec::Encode;
 use frame_support::{
 	construct_runtime, parameter_types,
-	traits::{AsEnsureOriginWithArg, Contains, KeyOwnerProofSystem},
+	traits::{AsEnsureOriginWithArg, Contains, EitherOfDiverse, KeyOwnerProofSystem},
 	weights::{
 		constants::{RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND},
 		IdentityFee, Weight,
 	},
-	PalletId,
+	BoundedVec, PalletId,
 };
 use frame_system::{EnsureRoot, EnsureSigned};
 use pallet_asset_tx_payment::FungiblesAdapter;
@@ -60,6 +60,9 @@ mod consts;
 mod impls;
 mod types;
 
+#[cfg(test)]
+mod tests;
+
 use ajuna_primitives::{
 	AccountId, AssetId, Balance, BlockNumber, CollectionId, Hash, Index, Moment, Signature,
 };
@@ -119,13 +122,24 @@ parameter_types! {
 pub struct BaseCallFilter;
 impl Contains<RuntimeCall> for BaseCallFilter {
 	fn contains(call: &RuntimeCall) -> bool {
-		!matches!(call, RuntimeCall::Nft(NftsCall::set_attribute { .. }))
+		match call {
+			RuntimeCall::Nft(NftsCall::set_attribute { .. }) => false,
+			RuntimeCall::Nft(
+				NftsCall::transfer { collection, .. } | NftsCall::buy_item { collection, .. },
+			) => !AwesomeAvatars::is_avatar_collection(collection),
+			RuntimeCall::Nft(NftsCall::claim_swap {
+				send_collection, receive_collection, ..
+			}) =>
+				!AwesomeAvatars::is_avatar_collection(send_collection) &&
+					!AwesomeAvatars::is_avatar_collection(receive_collection),
+			_ => true,
+		}
 	}
 }
 
 impl frame_system::Config for Runtime {
 	/// The basic call filter to use in dispatchable.
-	type BaseCallFilter = frame_support::traits::Everything;
+	type BaseCallFilter = BaseCallFilter;
 	/// Block & extrinsics weights: base values and limits.
 	type BlockWeights = BlockWeights;
 	/// The maximum length of a block (in bytes).
@@ -523,12 +537,31 @@ parameter_types! {
 	pub const AwesomeAvatarsPalletId: PalletId = PalletId(*b"aj/aaatr");
 }
 
+type EnsureOrganizerOrMoreThanHalfCouncil = EitherOfDiverse<
+	pallet_ajuna_awesome_avatars::EnsureOrganizer<Runtime>,
+	EnsureRootOrMoreThanHalfCouncil,
+>;
+
 impl pallet_ajuna_awesome_avatars::Config for Runtime {
 	type PalletId = AwesomeAvatarsPalletId;
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
-	type Randomness = Randomness;
+	type AssetPayment = pallet_ajuna_awesome_avatars::traits::FungiblesPayment<Assets>;
+	// Aura doesn't produce any VRF output, so randomness is seeded through commit-reveal. Players
+	// can predict their outcomes between reveals, which is only acceptable on this test chain.
+	type Randomness = pallet_ajuna_awesome_avatars::randomness::RandomnessWithNonce<
+		Runtime,
+		pallet_ajuna_awesome_avatars::randomness::CommitRevealRandomness<Runtime>,
+	>;
 	type NftHandler = NftTransfer;
+	type AuthorityId = pallet_ajuna_awesome_avatars::offchain::crypto::AuthorityId;
+	type SeasonOrigin = EnsureOrganizerOrMoreThanHalfCouncil;
+	// The organizer runs seasons, but the game configuration is left to the council.
+	type ConfigOrigin = EnsureRootOrAtLeastTwoThirdsCouncil;
+	type FreeMintOrigin = EnsureOrganizerOrMoreThanHalfCouncil;
+	type MaxAirdropBatch = frame_support::traits::ConstU32<1_000>;
+	type MaxOffersPerAvatar = frame_support::traits::ConstU32<20>;
+	type MaxRentalsPerBlock = frame_support::traits::ConstU32<100>;
 	type WeightInfo = ();
 }
 
@@ -627,6 +660,42 @@ impl pallet_ajuna_nft_transfer::Config for Runtime {
 	type ItemId = Hash;
 	type ItemConfig = pallet_nfts::ItemConfig;
 	type NftHelper = Nft;
+	type NftUploader = AwesomeAvatars;
+	type MaxUploadsPerBlock = frame_support::traits::ConstU32<10>;
+	type UploadOrigin = EnsureRootOrMoreThanHalfCouncil;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const NftStakingPalletId: PalletId = PalletId(*b"aj/nftst");
+	pub const MinimumStakingTokenReward: Balance = MILLI_AJUNS;
+	pub const MaxOpenContractsPerCreator: u32 = 100;
+	pub ContractCollectionConfig: pallet_nfts::CollectionConfig<Balance, BlockNumber, CollectionId> =
+		Default::default();
+	pub ContractCollectionItemConfig: pallet_nfts::ItemConfig = Default::default();
+}
+
+impl pallet_ajuna_nft_staking::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type RewardAssets = pallet_ajuna_nft_staking::traits::FungiblesRewards<Assets>;
+	type CollectionId = CollectionId;
+	type CollectionConfig = pallet_nfts::CollectionConfig<Balance, BlockNumber, CollectionId>;
+	type ItemId = Hash;
+	type ItemConfig = pallet_nfts::ItemConfig;
+	type NftHelper = Nft;
+	type StakingOrigin = EnsureSigned<AccountId>;
+	type NftDelegation = AwesomeAvatars;
+	type TreasuryPalletId = NftStakingPalletId;
+	type MinimumStakingTokenReward = MinimumStakingTokenReward;
+	type MaxOpenContractsPerCreator = MaxOpenContractsPerCreator;
+	type ContractCollectionConfig = ContractCollectionConfig;
+	type ContractCollectionItemConfig = ContractCollectionItemConfig;
+	type ContractAttributeKey = pallet_ajuna_nft_transfer::traits::AttributeCode;
+	type ContractAttributeValue = BoundedVec<u8, ValueLimit>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = NftStakingBenchmarkHelper;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -660,6 +729,7 @@ construct_runtime!(
 		Randomness: pallet_randomness_collective_flip = 23,
 		Nft: pallet_nfts = 24,
 		NftTransfer: pallet_ajuna_nft_transfer = 25,
+		NftStaking: pallet_ajuna_nft_staking = 26,
 	}
 );
 
@@ -704,6 +774,7 @@ mod benches {
 	define_benchmarks!(
 		[frame_benchmarking, BaselineBench::<Runtime>]
 		[frame_system, SystemBench::<Runtime>]
+		[pallet_ajuna_nft_staking, NftStaking]
 	);
 }
 
@@ -725,6 +796,27 @@ impl<CollectionId: From<u16>, ItemId: From<[u8; 32]>>
 	}
 }
 
+#[cfg(feature = "runtime-benchmarks")]
+pub struct NftStakingBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl
+	pallet_ajuna_nft_staking::BenchmarkHelper<
+		pallet_ajuna_nft_transfer::traits::AttributeCode,
+		BoundedVec<u8, ValueLimit>,
+		Hash,
+	> for NftStakingBenchmarkHelper
+{
+	fn contract_key(i: u32) -> pallet_ajuna_nft_transfer::traits::AttributeCode {
+		i as pallet_ajuna_nft_transfer::traits::AttributeCode
+	}
+	fn contract_value(i: u64) -> BoundedVec<u8, ValueLimit> {
+		i.encode().try_into().expect("encoded u64 fits in the value limit")
+	}
+	fn item_id(i: u16) -> Hash {
+		<NftBenchmarkHelper as pallet_nfts::BenchmarkHelper<CollectionId, Hash>>::item(i)
+	}
+}
+
 impl_runtime_apis! {
 	impl sp_api::Core<Block> for Runtime {
 		fn version() -> RuntimeVersion {
@@ -856,6 +948,37 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_ajuna_awesome_avatars_runtime_api::AwesomeAvatarsApi<Block, AccountId, Hash, Balance, BlockNumber> for Runtime {
+		fn avatars_of(
+			account: AccountId,
+		) -> Vec<pallet_ajuna_awesome_avatars::types::AvatarInfo<Hash, Balance>> {
+			AwesomeAvatars::avatars_of(&account)
+		}
+
+		fn current_season() -> Option<pallet_ajuna_awesome_avatars::types::CurrentSeason<BlockNumber>> {
+			AwesomeAvatars::current_season()
+		}
+
+		fn listed_avatars() -> Vec<pallet_ajuna_awesome_avatars::types::ListedAvatar<AccountId, Hash, Balance>> {
+			AwesomeAvatars::listed_avatars()
+		}
+
+		fn player_season_stats(
+			account: AccountId,
+			season_id: pallet_ajuna_awesome_avatars::types::SeasonId,
+		) -> pallet_ajuna_awesome_avatars::types::SeasonInfo {
+			AwesomeAvatars::season_stats(season_id, account)
+		}
+
+		fn preview_forge(
+			account: AccountId,
+			leader_id: Hash,
+			sacrifice_ids: Vec<Hash>,
+		) -> Result<pallet_ajuna_awesome_avatars::types::ForgePreview, sp_runtime::DispatchError> {
+			AwesomeAvatars::preview_forge(&account, &leader_id, &sacrifice_ids)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
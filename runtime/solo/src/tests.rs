@@ -0,0 +1,121 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::{assert_ok, traits::GenesisBuild};
+use pallet_ajuna_awesome_avatars::types::{
+	ConversionRate, MintOption, MintPackSize, MintType, RarityTier, Season, SeasonStatus,
+};
+use sp_runtime::AccountId32;
+
+const ALICE: AccountId = AccountId32::new([1; 32]);
+const BOB: AccountId = AccountId32::new([2; 32]);
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	let mut storage = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+	pallet_balances::GenesisConfig::<Runtime> {
+		balances: vec![(ALICE, 1_000 * AJUNS), (BOB, 1_000 * AJUNS)],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	pallet_ajuna_awesome_avatars::GenesisConfig::<Runtime>::default()
+		.assimilate_storage(&mut storage)
+		.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(storage);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn start_season() {
+	let season = Season {
+		name: b"solo season".to_vec().try_into().unwrap(),
+		description: b"a season to test the solo runtime".to_vec().try_into().unwrap(),
+		early_start: 1,
+		start: 1,
+		end: 100,
+		max_tier_forges: 10,
+		max_variations: 2,
+		max_components: 2,
+		min_sacrifices: 1,
+		max_sacrifices: 4,
+		tiers: vec![RarityTier::Common, RarityTier::Uncommon, RarityTier::Legendary]
+			.try_into()
+			.unwrap(),
+		single_mint_probs: vec![70, 30].try_into().unwrap(),
+		batch_mint_probs: vec![70, 30].try_into().unwrap(),
+		base_prob: 0,
+		per_period: 10,
+		periods: 12,
+	};
+	pallet_ajuna_awesome_avatars::Seasons::<Runtime>::insert(1, season);
+	pallet_ajuna_awesome_avatars::CurrentSeasonStatus::<Runtime>::put(SeasonStatus {
+		early: false,
+		active: true,
+		early_ended: false,
+		max_tier_avatars: 0,
+	});
+}
+
+mod awesome_avatars {
+	use super::*;
+
+	#[test]
+	fn can_pay_a_mint_in_a_payment_asset() {
+		new_test_ext().execute_with(|| {
+			// ALICE organizes a season accepting an asset worth a thousandth of an AJUN
+			let asset_id: AssetId = 1;
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				asset_id.into(),
+				ALICE.into(),
+				true,
+				1
+			));
+			assert_ok!(Assets::mint(
+				RuntimeOrigin::signed(ALICE),
+				asset_id.into(),
+				BOB.into(),
+				1_000 * AJUNS
+			));
+			assert_ok!(AwesomeAvatars::set_organizer(RuntimeOrigin::root(), ALICE));
+			start_season();
+			let rate = ConversionRate { native: 1_000, asset: 1 };
+			assert_ok!(AwesomeAvatars::set_payment_asset(
+				RuntimeOrigin::signed(ALICE),
+				asset_id,
+				Some(rate)
+			));
+
+			// BOB mints an avatar paying the fee in the asset
+			assert_ok!(AwesomeAvatars::set_fee_asset(RuntimeOrigin::signed(BOB), Some(asset_id)));
+			let native_balance = Balances::free_balance(BOB);
+			let asset_balance = Assets::balance(asset_id, BOB);
+			assert_ok!(AwesomeAvatars::mint(
+				RuntimeOrigin::signed(BOB),
+				MintOption { mint_type: MintType::Normal, count: MintPackSize::One }
+			));
+			assert_eq!(AwesomeAvatars::owners(&BOB).len(), 1);
+
+			let fee = rate.convert(AwesomeAvatars::global_configs().mint.fees.one);
+			assert_eq!(Balances::free_balance(BOB), native_balance);
+			assert_eq!(Assets::balance(asset_id, BOB), asset_balance - fee);
+			assert_eq!(Assets::balance(asset_id, AwesomeAvatars::treasury_account_id()), fee);
+			assert_eq!(AwesomeAvatars::asset_treasury(1, asset_id), fee);
+			assert_eq!(AwesomeAvatars::treasury(1), 0);
+		});
+	}
+}